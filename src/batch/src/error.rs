@@ -35,6 +35,12 @@ pub enum BatchError {
     #[error("Failed to send result to channel")]
     SenderError,
 
+    #[error("Exchange spill IO error: {0}")]
+    SpillIo(#[from] std::io::Error),
+
+    #[error("Exchange spill size exceeds the cap of {0} bytes")]
+    ExchangeSpillCapExceeded(u64),
+
     #[error(transparent)]
     Internal(#[from] anyhow::Error),
 
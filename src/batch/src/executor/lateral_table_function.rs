@@ -0,0 +1,173 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use futures_async_stream::try_stream;
+use risingwave_common::array::DataChunk;
+use risingwave_common::catalog::{Field, Schema};
+use risingwave_common::error::{Result, RwError};
+use risingwave_common::row::{self, Row};
+use risingwave_common::util::chunk_coalesce::DataChunkBuilder;
+use risingwave_expr::table_function::{build_from_prost, BoxedTableFunction};
+use risingwave_pb::batch_plan::plan_node::NodeBody;
+
+use super::{BoxedExecutor, BoxedExecutorBuilder};
+use crate::executor::{BoxedDataChunkStream, Executor, ExecutorBuilder};
+use crate::task::BatchTaskContext;
+
+/// [`LateralTableFunctionExecutor`] evaluates a table function once per row of its child, whose
+/// columns the function arguments may reference, and outputs the child row joined with each
+/// element the function produced. Rows for which the function produces no element are dropped,
+/// matching the inner join semantics of `SELECT ... FROM t, unnest(t.arr)`.
+pub struct LateralTableFunctionExecutor {
+    child: BoxedExecutor,
+    schema: Schema,
+    identity: String,
+    table_function: BoxedTableFunction,
+    chunk_size: usize,
+}
+
+impl Executor for LateralTableFunctionExecutor {
+    fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    fn identity(&self) -> &str {
+        &self.identity
+    }
+
+    fn execute(self: Box<Self>) -> BoxedDataChunkStream {
+        self.do_execute()
+    }
+}
+
+impl LateralTableFunctionExecutor {
+    #[try_stream(boxed, ok = DataChunk, error = RwError)]
+    async fn do_execute(self: Box<Self>) {
+        let mut builder = DataChunkBuilder::new(self.schema.data_types(), self.chunk_size);
+
+        #[for_await]
+        for data_chunk in self.child.execute() {
+            let data_chunk = data_chunk?.compact();
+            // One output array per input row.
+            let results = self.table_function.eval(&data_chunk)?;
+            for (row_idx, tf_output) in results.iter().enumerate() {
+                let input_row = data_chunk.row_at_unchecked_vis(row_idx);
+                for value in tf_output.iter() {
+                    let output_row = input_row.chain(row::once(value));
+                    if let Some(chunk) = builder.append_one_row(output_row) {
+                        yield chunk;
+                    }
+                }
+            }
+        }
+        if let Some(chunk) = builder.consume_all() {
+            yield chunk;
+        }
+    }
+}
+
+pub struct LateralTableFunctionExecutorBuilder {}
+
+#[async_trait::async_trait]
+impl BoxedExecutorBuilder for LateralTableFunctionExecutorBuilder {
+    async fn new_boxed_executor<C: BatchTaskContext>(
+        source: &ExecutorBuilder<'_, C>,
+        inputs: Vec<BoxedExecutor>,
+    ) -> Result<BoxedExecutor> {
+        let [child]: [_; 1] = inputs.try_into().unwrap();
+
+        let node = try_match_expand!(
+            source.plan_node().get_node_body().unwrap(),
+            NodeBody::LateralTableFunction
+        )?;
+
+        let identity = source.plan_node().get_identity().clone();
+
+        let chunk_size = source.context.get_config().developer.batch_chunk_size;
+
+        let table_function = build_from_prost(node.table_function.as_ref().unwrap(), chunk_size)?;
+
+        let mut fields = child.schema().fields.clone();
+        fields.push(Field::unnamed(table_function.return_type()));
+
+        Ok(Box::new(LateralTableFunctionExecutor {
+            child,
+            schema: Schema { fields },
+            identity,
+            table_function,
+            chunk_size,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::stream::StreamExt;
+    use risingwave_common::catalog::{Field, Schema};
+    use risingwave_common::test_prelude::*;
+    use risingwave_common::types::DataType;
+    use risingwave_expr::expr::{Expression, InputRefExpression};
+    use risingwave_expr::table_function::repeat_tf;
+
+    use super::*;
+    use crate::executor::test_utils::MockExecutor;
+    use crate::executor::Executor;
+
+    const CHUNK_SIZE: usize = 1024;
+
+    #[tokio::test]
+    async fn test_lateral_table_function_executor() {
+        let chunk = DataChunk::from_pretty(
+            "i i
+             1 7
+             2 8",
+        );
+        let schema = Schema {
+            fields: vec![
+                Field::unnamed(DataType::Int32),
+                Field::unnamed(DataType::Int32),
+            ],
+        };
+        let mut mock_executor = MockExecutor::new(schema);
+        mock_executor.add(chunk);
+
+        // The table function references the second column of the child, i.e. it is invoked once
+        // per input row with that row's value.
+        let table_function = repeat_tf(InputRefExpression::new(DataType::Int32, 1).boxed(), 2);
+        let fields = vec![
+            Field::unnamed(DataType::Int32),
+            Field::unnamed(DataType::Int32),
+            Field::unnamed(DataType::Int32),
+        ];
+        let executor = Box::new(LateralTableFunctionExecutor {
+            child: Box::new(mock_executor),
+            schema: Schema { fields },
+            identity: "LateralTableFunctionExecutor".to_string(),
+            table_function,
+            chunk_size: CHUNK_SIZE,
+        });
+
+        let mut stream = executor.execute();
+        let result = stream.next().await.unwrap().unwrap();
+        let expected = DataChunk::from_pretty(
+            "i i i
+             1 7 7
+             1 7 7
+             2 8 8
+             2 8 8",
+        );
+        assert_eq!(result, expected);
+        assert!(stream.next().await.is_none());
+    }
+}
@@ -0,0 +1,139 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::Arc;
+
+use futures::stream::StreamExt;
+use futures_async_stream::try_stream;
+use parking_lot::Mutex;
+use risingwave_common::array::DataChunk;
+use risingwave_common::catalog::Schema;
+use risingwave_common::error::RwError;
+use task_stats_alloc::BYTES_ALLOCATED;
+
+use crate::executor::{BoxedDataChunkStream, BoxedExecutor, Executor};
+
+/// Per-operator memory accounting of one batch task.
+///
+/// Bytes are measured with the task-local allocation stats: each [`MemUsageTrackingExecutor`]
+/// attributes to its operator the bytes allocated while its child is polled, minus the bytes the
+/// descendants have already attributed to themselves during the nested polls. The net value can be
+/// negative, e.g. when an operator frees data built up by its descendants, hence the `i64`
+/// counters.
+#[derive(Default)]
+pub struct MemUsageRegistry {
+    /// Net bytes attributed to each operator, identified by the executor identity.
+    operators: Mutex<Vec<(String, Arc<AtomicI64>)>>,
+
+    /// Sum of the bytes attributed to all operators, used to exclude descendants' shares.
+    attributed_total: AtomicI64,
+}
+
+pub type MemUsageRegistryRef = Arc<MemUsageRegistry>;
+
+tokio::task_local! {
+    /// The [`MemUsageRegistry`] of the current batch task. It is scoped together with
+    /// [`BYTES_ALLOCATED`] in `allocation_stat_for_batch`, so it is absent for local execution
+    /// in the frontend, where the accounting is skipped.
+    pub static MEM_USAGE_REGISTRY: MemUsageRegistryRef;
+}
+
+impl MemUsageRegistry {
+    fn register(&self, identity: String) -> Arc<AtomicI64> {
+        let counter = Arc::new(AtomicI64::new(0));
+        self.operators.lock().push((identity, counter.clone()));
+        counter
+    }
+
+    fn attribute(&self, counter: &AtomicI64, bytes: i64) {
+        counter.fetch_add(bytes, Ordering::Relaxed);
+        self.attributed_total.fetch_add(bytes, Ordering::Relaxed);
+    }
+
+    fn attributed_total(&self) -> i64 {
+        self.attributed_total.load(Ordering::Relaxed)
+    }
+
+    /// Returns the identity and the net attributed bytes of the operator that holds the most
+    /// memory, or `None` if no operator has been polled yet.
+    pub fn top_consumer(&self) -> Option<(String, i64)> {
+        self.operators
+            .lock()
+            .iter()
+            .map(|(identity, counter)| (identity.clone(), counter.load(Ordering::Relaxed)))
+            .max_by_key(|(_, bytes)| *bytes)
+    }
+}
+
+/// Wraps every executor of a batch task to attribute the task's memory usage to operators, so
+/// that exceeding the task memory limit can report which operator holds the most memory.
+pub struct MemUsageTrackingExecutor {
+    child: BoxedExecutor,
+    /// Identity of the wrapped executor, under which the bytes are attributed.
+    child_identity: String,
+}
+
+impl MemUsageTrackingExecutor {
+    pub fn new(child: BoxedExecutor, child_identity: String) -> Self {
+        Self {
+            child,
+            child_identity,
+        }
+    }
+}
+
+impl Executor for MemUsageTrackingExecutor {
+    fn schema(&self) -> &Schema {
+        self.child.schema()
+    }
+
+    fn identity(&self) -> &str {
+        "MemUsageTrackingExecutor"
+    }
+
+    fn execute(self: Box<Self>) -> BoxedDataChunkStream {
+        self.do_execute()
+    }
+}
+
+impl MemUsageTrackingExecutor {
+    #[try_stream(boxed, ok = DataChunk, error = RwError)]
+    async fn do_execute(self: Box<Self>) {
+        let registry = MEM_USAGE_REGISTRY.try_with(Clone::clone).ok();
+        let counter = registry
+            .as_ref()
+            .map(|registry| registry.register(self.child_identity.clone()));
+        let mut child_stream = self.child.execute();
+
+        loop {
+            let chunk = if let (Some(registry), Some(counter)) = (&registry, &counter) {
+                let bytes_before = BYTES_ALLOCATED.with(|bytes| bytes.val()) as i64;
+                let attributed_before = registry.attributed_total();
+                let chunk = child_stream.next().await;
+                let bytes_diff = BYTES_ALLOCATED.with(|bytes| bytes.val()) as i64 - bytes_before;
+                let attributed_to_descendants = registry.attributed_total() - attributed_before;
+                registry.attribute(counter, bytes_diff - attributed_to_descendants);
+                chunk
+            } else {
+                child_stream.next().await
+            };
+
+            match chunk {
+                Some(chunk) => yield chunk?,
+                None => break,
+            }
+        }
+    }
+}
@@ -21,7 +21,9 @@ mod hash_agg;
 mod hop_window;
 mod insert;
 mod join;
+mod lateral_table_function;
 mod limit;
+mod mem_usage;
 mod merge_sort_exchange;
 pub mod monitor;
 mod order_by;
@@ -51,7 +53,9 @@ pub use hash_agg::*;
 pub use hop_window::*;
 pub use insert::*;
 pub use join::*;
+pub use lateral_table_function::*;
 pub use limit::*;
+pub use mem_usage::*;
 pub use merge_sort_exchange::*;
 pub use monitor::*;
 pub use order_by::*;
@@ -212,6 +216,7 @@ impl<'a, C: BatchTaskContext> ExecutorBuilder<'a, C> {
             NodeBody::HashAgg => HashAggExecutorBuilder,
             NodeBody::MergeSortExchange => MergeSortExchangeExecutorBuilder,
             NodeBody::TableFunction => TableFunctionExecutorBuilder,
+            NodeBody::LateralTableFunction => LateralTableFunctionExecutorBuilder,
             NodeBody::HopWindow => HopWindowExecutor,
             NodeBody::SysRowSeqScan => SysRowSeqScanExecutorBuilder,
             NodeBody::Expand => ExpandExecutor,
@@ -223,7 +228,11 @@ impl<'a, C: BatchTaskContext> ExecutorBuilder<'a, C> {
         }
         .await?;
         let input_desc = real_executor.identity().to_string();
-        Ok(Box::new(TraceExecutor::new(real_executor, input_desc)) as BoxedExecutor)
+        let mem_tracked_executor = Box::new(MemUsageTrackingExecutor::new(
+            real_executor,
+            input_desc.clone(),
+        )) as BoxedExecutor;
+        Ok(Box::new(TraceExecutor::new(mem_tracked_executor, input_desc)) as BoxedExecutor)
     }
 }
 
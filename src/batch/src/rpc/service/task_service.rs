@@ -15,6 +15,7 @@
 use std::convert::Into;
 use std::sync::Arc;
 
+use minitrace::prelude::*;
 use risingwave_pb::batch_plan::TaskOutputId;
 use risingwave_pb::task_service::task_service_server::TaskService;
 use risingwave_pb::task_service::{
@@ -59,10 +60,17 @@ impl TaskService for BatchServiceImpl {
             task_id,
             plan,
             epoch,
+            trace_id,
         } = request.into_inner();
 
         let (state_tx, state_rx) = tokio::sync::mpsc::channel(TASK_STATUS_BUFFER_SIZE);
         let state_reporter = StateReporter::new_with_dist_sender(state_tx);
+        // Report the spans of this task with the trace id assigned by the frontend, so that the
+        // whole distributed query shows up as a single trace.
+        let trace_span = self
+            .env
+            .tracing()
+            .new_tracer_with_trace_id("batch_execute", trace_id);
         let res = self
             .mgr
             .fire_task(
@@ -74,6 +82,7 @@ impl TaskService for BatchServiceImpl {
                     TaskId::from(task_id.as_ref().expect("no task id found")),
                 ),
                 state_reporter,
+                trace_span,
             )
             .await;
         match res {
@@ -126,7 +135,11 @@ impl TaskService for BatchServiceImpl {
         let task = Arc::new(task);
         let (tx, rx) = tokio::sync::mpsc::channel(LOCAL_EXECUTE_BUFFER_SIZE);
         let state_reporter = StateReporter::new_with_local_sender(tx.clone());
-        if let Err(e) = task.clone().async_execute(state_reporter).await {
+        if let Err(e) = task
+            .clone()
+            .async_execute(state_reporter, Span::new_noop())
+            .await
+        {
             error!(
                 "failed to build executors and trigger execution of Task {:?}: {}",
                 task_id, e
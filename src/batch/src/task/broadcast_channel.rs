@@ -20,17 +20,18 @@ use risingwave_common::error::ErrorCode::InternalError;
 use risingwave_common::error::Result;
 use risingwave_pb::batch_plan::exchange_info::BroadcastInfo;
 use risingwave_pb::batch_plan::*;
-use tokio::sync::mpsc;
 
-use crate::error::BatchError::SenderError;
 use crate::error::Result as BatchResult;
 use crate::task::channel::{ChanReceiver, ChanReceiverImpl, ChanSender, ChanSenderImpl};
 use crate::task::data_chunk_in_channel::DataChunkInChannel;
+use crate::task::spill_channel::{
+    spillable_channel, SpillConfig, SpillableReceiver, SpillableSender,
+};
 
 /// `BroadcastSender` sends the same chunk to a number of `BroadcastReceiver`s.
 #[derive(Clone)]
 pub struct BroadcastSender {
-    senders: Vec<mpsc::Sender<Option<DataChunkInChannel>>>,
+    senders: Vec<SpillableSender>,
     broadcast_info: BroadcastInfo,
 }
 
@@ -49,10 +50,7 @@ impl ChanSender for BroadcastSender {
         async move {
             let broadcast_data_chunk = chunk.map(DataChunkInChannel::new);
             for sender in &self.senders {
-                sender
-                    .send(broadcast_data_chunk.as_ref().cloned())
-                    .await
-                    .map_err(|_| SenderError)?
+                sender.send(broadcast_data_chunk.as_ref().cloned()).await?
             }
 
             Ok(())
@@ -62,7 +60,7 @@ impl ChanSender for BroadcastSender {
 
 /// One or more `BroadcastReceiver`s corresponds to a single `BroadcastReceiver`
 pub struct BroadcastReceiver {
-    receiver: mpsc::Receiver<Option<DataChunkInChannel>>,
+    receiver: SpillableReceiver,
 }
 
 impl ChanReceiver for BroadcastReceiver {
@@ -70,7 +68,7 @@ impl ChanReceiver for BroadcastReceiver {
 
     fn recv(&mut self) -> Self::RecvFuture<'_> {
         async move {
-            match self.receiver.recv().await {
+            match self.receiver.recv().await? {
                 Some(data_chunk) => Ok(data_chunk),
                 // Early close should be treated as an error.
                 None => Err(InternalError("broken broadcast_channel".to_string()).into()),
@@ -82,6 +80,7 @@ impl ChanReceiver for BroadcastReceiver {
 pub fn new_broadcast_channel(
     shuffle: &ExchangeInfo,
     output_channel_size: usize,
+    spill: Option<&SpillConfig>,
 ) -> (ChanSenderImpl, Vec<ChanReceiverImpl>) {
     let broadcast_info = match shuffle.distribution {
         Some(exchange_info::Distribution::BroadcastInfo(ref v)) => v.clone(),
@@ -91,8 +90,8 @@ pub fn new_broadcast_channel(
     let output_count = broadcast_info.count as usize;
     let mut senders = Vec::with_capacity(output_count);
     let mut receivers = Vec::with_capacity(output_count);
-    for _ in 0..output_count {
-        let (s, r) = mpsc::channel(output_channel_size);
+    for output_id in 0..output_count {
+        let (s, r) = spillable_channel(output_channel_size, spill, output_id);
         senders.push(s);
         receivers.push(r);
     }
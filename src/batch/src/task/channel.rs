@@ -29,6 +29,7 @@ use crate::task::fifo_channel::{new_fifo_channel, FifoReceiver, FifoSender};
 use crate::task::hash_shuffle_channel::{
     new_hash_shuffle_channel, HashShuffleReceiver, HashShuffleSender,
 };
+use crate::task::spill_channel::SpillConfig;
 
 pub(super) trait ChanSender: Send {
     type SendFuture<'a>: Future<Output = BatchResult<()>> + Send
@@ -93,15 +94,18 @@ impl ChanReceiverImpl {
 pub fn create_output_channel(
     shuffle: &ExchangeInfo,
     output_channel_size: usize,
+    spill: Option<&SpillConfig>,
 ) -> Result<(ChanSenderImpl, Vec<ChanReceiverImpl>)> {
     match shuffle.get_mode()? {
-        ShuffleDistributionMode::Single => Ok(new_fifo_channel(output_channel_size)),
-        ShuffleDistributionMode::Hash => Ok(new_hash_shuffle_channel(shuffle, output_channel_size)),
+        ShuffleDistributionMode::Single => Ok(new_fifo_channel(output_channel_size, spill)),
+        ShuffleDistributionMode::Hash => {
+            Ok(new_hash_shuffle_channel(shuffle, output_channel_size, spill))
+        }
         ShuffleDistributionMode::ConsistentHash => {
-            Ok(new_consistent_shuffle_channel(shuffle, output_channel_size))
+            Ok(new_consistent_shuffle_channel(shuffle, output_channel_size, spill))
         }
         ShuffleDistributionMode::Broadcast => {
-            Ok(new_broadcast_channel(shuffle, output_channel_size))
+            Ok(new_broadcast_channel(shuffle, output_channel_size, spill))
         }
         ShuffleDistributionMode::Unspecified => unreachable!(),
     }
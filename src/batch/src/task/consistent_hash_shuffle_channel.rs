@@ -25,16 +25,17 @@ use risingwave_common::error::Result;
 use risingwave_common::util::hash_util::Crc32FastBuilder;
 use risingwave_pb::batch_plan::exchange_info::ConsistentHashInfo;
 use risingwave_pb::batch_plan::*;
-use tokio::sync::mpsc;
 
-use crate::error::BatchError::SenderError;
 use crate::error::Result as BatchResult;
 use crate::task::channel::{ChanReceiver, ChanReceiverImpl, ChanSender, ChanSenderImpl};
 use crate::task::data_chunk_in_channel::DataChunkInChannel;
+use crate::task::spill_channel::{
+    spillable_channel, SpillConfig, SpillableReceiver, SpillableSender,
+};
 
 #[derive(Clone)]
 pub struct ConsistentHashShuffleSender {
-    senders: Vec<mpsc::Sender<Option<DataChunkInChannel>>>,
+    senders: Vec<SpillableSender>,
     consistent_hash_info: ConsistentHashInfo,
     output_count: usize,
 }
@@ -48,7 +49,7 @@ impl Debug for ConsistentHashShuffleSender {
 }
 
 pub struct ConsistentHashShuffleReceiver {
-    receiver: mpsc::Receiver<Option<DataChunkInChannel>>,
+    receiver: SpillableReceiver,
 }
 
 fn generate_hash_values(
@@ -136,8 +137,7 @@ impl ConsistentHashShuffleSender {
             if new_data_chunk.cardinality() > 0 {
                 self.senders[sink_id]
                     .send(Some(DataChunkInChannel::new(new_data_chunk)))
-                    .await
-                    .map_err(|_| SenderError)?
+                    .await?
             }
         }
         Ok(())
@@ -145,7 +145,7 @@ impl ConsistentHashShuffleSender {
 
     async fn send_done(&mut self) -> BatchResult<()> {
         for sender in &self.senders {
-            sender.send(None).await.map_err(|_| SenderError)?
+            sender.send(None).await?
         }
 
         Ok(())
@@ -157,7 +157,7 @@ impl ChanReceiver for ConsistentHashShuffleReceiver {
 
     fn recv(&mut self) -> Self::RecvFuture<'_> {
         async move {
-            match self.receiver.recv().await {
+            match self.receiver.recv().await? {
                 Some(data_chunk) => Ok(data_chunk),
                 // Early close should be treated as error.
                 None => Err(InternalError("broken hash_shuffle_channel".to_string()).into()),
@@ -169,6 +169,7 @@ impl ChanReceiver for ConsistentHashShuffleReceiver {
 pub fn new_consistent_shuffle_channel(
     shuffle: &ExchangeInfo,
     output_channel_size: usize,
+    spill: Option<&SpillConfig>,
 ) -> (ChanSenderImpl, Vec<ChanReceiverImpl>) {
     let consistent_hash_info = match shuffle.distribution {
         Some(exchange_info::Distribution::ConsistentHashInfo(ref v)) => v.clone(),
@@ -185,8 +186,8 @@ pub fn new_consistent_shuffle_channel(
 
     let mut senders = Vec::with_capacity(output_count);
     let mut receivers = Vec::with_capacity(output_count);
-    for _ in 0..output_count {
-        let (s, r) = mpsc::channel(output_channel_size);
+    for output_id in 0..output_count {
+        let (s, r) = spillable_channel(output_channel_size, spill, output_id);
         senders.push(s);
         receivers.push(r);
     }
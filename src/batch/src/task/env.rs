@@ -20,6 +20,7 @@ use risingwave_connector::source::monitor::SourceMetrics;
 use risingwave_rpc_client::ComputeClientPoolRef;
 use risingwave_source::dml_manager::DmlManagerRef;
 use risingwave_storage::StateStoreImpl;
+use risingwave_tracing::RwTracingService;
 
 use crate::executor::BatchTaskMetrics;
 use crate::task::BatchManager;
@@ -56,6 +57,9 @@ pub struct BatchEnvironment {
 
     /// Metrics for source.
     source_metrics: Arc<SourceMetrics>,
+
+    /// Tracing service to report spans of batch tasks.
+    tracing: Arc<RwTracingService>,
 }
 
 impl BatchEnvironment {
@@ -70,6 +74,7 @@ impl BatchEnvironment {
         client_pool: ComputeClientPoolRef,
         dml_manager: DmlManagerRef,
         source_metrics: Arc<SourceMetrics>,
+        tracing: Arc<RwTracingService>,
     ) -> Self {
         BatchEnvironment {
             server_addr,
@@ -81,6 +86,7 @@ impl BatchEnvironment {
             client_pool,
             dml_manager,
             source_metrics,
+            tracing,
         }
     }
 
@@ -108,6 +114,7 @@ impl BatchEnvironment {
             client_pool: Arc::new(ComputeClientPool::default()),
             dml_manager: Arc::new(DmlManager::default()),
             source_metrics: Arc::new(SourceMetrics::default()),
+            tracing: Arc::new(RwTracingService::disabled()),
         }
     }
 
@@ -146,4 +153,8 @@ impl BatchEnvironment {
     pub fn source_metrics(&self) -> Arc<SourceMetrics> {
         self.source_metrics.clone()
     }
+
+    pub fn tracing(&self) -> Arc<RwTracingService> {
+        self.tracing.clone()
+    }
 }
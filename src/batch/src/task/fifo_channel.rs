@@ -18,15 +18,16 @@ use std::future::Future;
 use risingwave_common::array::DataChunk;
 use risingwave_common::error::ErrorCode::InternalError;
 use risingwave_common::error::Result;
-use tokio::sync::mpsc;
 
-use crate::error::BatchError::SenderError;
 use crate::error::Result as BatchResult;
 use crate::task::channel::{ChanReceiver, ChanReceiverImpl, ChanSender, ChanSenderImpl};
 use crate::task::data_chunk_in_channel::DataChunkInChannel;
+use crate::task::spill_channel::{
+    spillable_channel, SpillConfig, SpillableReceiver, SpillableSender,
+};
 #[derive(Clone)]
 pub struct FifoSender {
-    sender: mpsc::Sender<Option<DataChunkInChannel>>,
+    sender: SpillableSender,
 }
 
 impl Debug for FifoSender {
@@ -36,19 +37,14 @@ impl Debug for FifoSender {
 }
 
 pub struct FifoReceiver {
-    receiver: mpsc::Receiver<Option<DataChunkInChannel>>,
+    receiver: SpillableReceiver,
 }
 
 impl ChanSender for FifoSender {
     type SendFuture<'a> = impl Future<Output = BatchResult<()>> + 'a;
 
     fn send(&mut self, chunk: Option<DataChunk>) -> Self::SendFuture<'_> {
-        async {
-            self.sender
-                .send(chunk.map(DataChunkInChannel::new))
-                .await
-                .map_err(|_| SenderError)
-        }
+        async { self.sender.send(chunk.map(DataChunkInChannel::new)).await }
     }
 }
 
@@ -57,7 +53,7 @@ impl ChanReceiver for FifoReceiver {
 
     fn recv(&mut self) -> Self::RecvFuture<'_> {
         async move {
-            match self.receiver.recv().await {
+            match self.receiver.recv().await? {
                 Some(data_chunk) => Ok(data_chunk),
                 // Early close should be treated as error.
                 None => Err(InternalError("broken fifo_channel".to_string()).into()),
@@ -66,8 +62,11 @@ impl ChanReceiver for FifoReceiver {
     }
 }
 
-pub fn new_fifo_channel(output_channel_size: usize) -> (ChanSenderImpl, Vec<ChanReceiverImpl>) {
-    let (s, r) = mpsc::channel(output_channel_size);
+pub fn new_fifo_channel(
+    output_channel_size: usize,
+    spill: Option<&SpillConfig>,
+) -> (ChanSenderImpl, Vec<ChanReceiverImpl>) {
+    let (s, r) = spillable_channel(output_channel_size, spill, 0);
     (
         ChanSenderImpl::Fifo(FifoSender { sender: s }),
         vec![ChanReceiverImpl::Fifo(FifoReceiver { receiver: r })],
@@ -79,7 +78,7 @@ mod tests {
     async fn test_recv_not_fail_on_closed_channel() {
         use crate::task::fifo_channel::new_fifo_channel;
 
-        let (sender, mut receivers) = new_fifo_channel(64);
+        let (sender, mut receivers) = new_fifo_channel(64, None);
         assert_eq!(receivers.len(), 1);
         drop(sender);
 
@@ -24,15 +24,16 @@ use risingwave_common::error::Result;
 use risingwave_common::util::hash_util::Crc32FastBuilder;
 use risingwave_pb::batch_plan::exchange_info::HashInfo;
 use risingwave_pb::batch_plan::*;
-use tokio::sync::mpsc;
 
-use crate::error::BatchError::SenderError;
 use crate::error::Result as BatchResult;
 use crate::task::channel::{ChanReceiver, ChanReceiverImpl, ChanSender, ChanSenderImpl};
 use crate::task::data_chunk_in_channel::DataChunkInChannel;
+use crate::task::spill_channel::{
+    spillable_channel, SpillConfig, SpillableReceiver, SpillableSender,
+};
 #[derive(Clone)]
 pub struct HashShuffleSender {
-    senders: Vec<mpsc::Sender<Option<DataChunkInChannel>>>,
+    senders: Vec<SpillableSender>,
     hash_info: HashInfo,
 }
 
@@ -45,11 +46,18 @@ impl Debug for HashShuffleSender {
 }
 
 pub struct HashShuffleReceiver {
-    receiver: mpsc::Receiver<Option<DataChunkInChannel>>,
+    receiver: SpillableReceiver,
 }
 
-fn generate_hash_values(chunk: &DataChunk, hash_info: &HashInfo) -> BatchResult<Vec<usize>> {
+/// Number of output buffers each copy of the output consists of. With `copy_count` unset (0)
+/// this is simply `output_count`.
+fn outputs_per_copy(hash_info: &HashInfo) -> usize {
     let output_count = hash_info.output_count as usize;
+    output_count / (hash_info.copy_count as usize).max(1)
+}
+
+fn generate_hash_values(chunk: &DataChunk, hash_info: &HashInfo) -> BatchResult<Vec<usize>> {
+    let outputs_per_copy = outputs_per_copy(hash_info);
 
     let hasher_builder = Crc32FastBuilder;
 
@@ -63,7 +71,7 @@ fn generate_hash_values(chunk: &DataChunk, hash_info: &HashInfo) -> BatchResult<
             hasher_builder,
         )
         .iter_mut()
-        .map(|hash_value| hash_value.hash_code() as usize % output_count)
+        .map(|hash_value| hash_value.hash_code() as usize % outputs_per_copy)
         .collect::<Vec<_>>();
     Ok(hash_values)
 }
@@ -75,10 +83,12 @@ fn generate_new_data_chunks(
     hash_values: &[usize],
 ) -> Vec<DataChunk> {
     let output_count = hash_info.output_count as usize;
+    let outputs_per_copy = outputs_per_copy(hash_info);
     let mut vis_maps = vec![vec![]; output_count];
     hash_values.iter().for_each(|hash| {
         for (sink_id, vis_map) in vis_maps.iter_mut().enumerate() {
-            if *hash == sink_id {
+            // A row goes to the same relative sink of every output copy.
+            if *hash == sink_id % outputs_per_copy {
                 vis_map.push(true);
             } else {
                 vis_map.push(false);
@@ -133,8 +143,7 @@ impl HashShuffleSender {
             if new_data_chunk.cardinality() > 0 {
                 self.senders[sink_id]
                     .send(Some(DataChunkInChannel::new(new_data_chunk)))
-                    .await
-                    .map_err(|_| SenderError)?
+                    .await?
             }
         }
         Ok(())
@@ -142,7 +151,7 @@ impl HashShuffleSender {
 
     async fn send_done(&mut self) -> BatchResult<()> {
         for sender in &self.senders {
-            sender.send(None).await.map_err(|_| SenderError)?
+            sender.send(None).await?
         }
 
         Ok(())
@@ -154,7 +163,7 @@ impl ChanReceiver for HashShuffleReceiver {
 
     fn recv(&mut self) -> Self::RecvFuture<'_> {
         async move {
-            match self.receiver.recv().await {
+            match self.receiver.recv().await? {
                 Some(data_chunk) => Ok(data_chunk),
                 // Early close should be treated as error.
                 None => Err(InternalError("broken hash_shuffle_channel".to_string()).into()),
@@ -166,6 +175,7 @@ impl ChanReceiver for HashShuffleReceiver {
 pub fn new_hash_shuffle_channel(
     shuffle: &ExchangeInfo,
     output_channel_size: usize,
+    spill: Option<&SpillConfig>,
 ) -> (ChanSenderImpl, Vec<ChanReceiverImpl>) {
     let hash_info = match shuffle.distribution {
         Some(exchange_info::Distribution::HashInfo(ref v)) => v.clone(),
@@ -175,8 +185,8 @@ pub fn new_hash_shuffle_channel(
     let output_count = hash_info.output_count as usize;
     let mut senders = Vec::with_capacity(output_count);
     let mut receivers = Vec::with_capacity(output_count);
-    for _ in 0..output_count {
-        let (s, r) = mpsc::channel(output_channel_size);
+    for output_id in 0..output_count {
+        let (s, r) = spillable_channel(output_channel_size, spill, output_id);
         senders.push(s);
         receivers.push(r);
     }
@@ -187,3 +197,54 @@ pub fn new_hash_shuffle_channel(
         .collect::<Vec<_>>();
     (channel_sender, channel_receivers)
 }
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::test_prelude::DataChunkTestExt;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_copied_outputs_receive_identical_partitions() {
+        let exchange_info = ExchangeInfo {
+            mode: exchange_info::DistributionMode::Hash as i32,
+            distribution: Some(exchange_info::Distribution::HashInfo(HashInfo {
+                output_count: 4,
+                copy_count: 2,
+                key: vec![0],
+            })),
+        };
+        let (mut sender, mut receivers) = new_hash_shuffle_channel(&exchange_info, 64, None);
+        assert_eq!(receivers.len(), 4);
+
+        let chunk = DataChunk::from_pretty(
+            "i
+             1
+             2
+             3
+             4",
+        );
+        sender.send(Some(chunk)).await.unwrap();
+        sender.send(None).await.unwrap();
+
+        let mut partitions = vec![];
+        for receiver in &mut receivers {
+            let mut chunks = vec![];
+            while let Some(chunk) = receiver.recv().await.unwrap() {
+                chunks.push(chunk.into_data_chunk());
+            }
+            partitions.push(chunks);
+        }
+
+        // Each output of the second copy receives exactly the rows of its counterpart in the
+        // first copy, and one copy alone covers the whole chunk.
+        assert_eq!(partitions[0], partitions[2]);
+        assert_eq!(partitions[1], partitions[3]);
+        let copy_cardinality: usize = partitions[..2]
+            .iter()
+            .flatten()
+            .map(|chunk| chunk.cardinality())
+            .sum();
+        assert_eq!(copy_cardinality, 4);
+    }
+}
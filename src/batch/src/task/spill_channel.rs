@@ -0,0 +1,300 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::SeekFrom;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use prost::Message;
+use risingwave_common::array::DataChunk;
+use risingwave_pb::data::DataChunk as ProstDataChunk;
+use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt};
+use tokio::sync::mpsc;
+use tokio::sync::mpsc::error::TrySendError;
+use tokio::sync::Mutex;
+
+use crate::error::BatchError::SenderError;
+use crate::error::{BatchError, Result as BatchResult};
+use crate::task::data_chunk_in_channel::DataChunkInChannel;
+
+/// Length prefix marking the end-of-channel `None` message in a spill file.
+const END_OF_CHANNEL_MARKER: u32 = u32::MAX;
+
+/// Where and how much one output channel may spill.
+#[derive(Debug, Clone)]
+pub struct SpillConfig {
+    /// Directory holding the spill files. Created lazily on the first spilled chunk.
+    dir: PathBuf,
+    /// File name prefix identifying the task, so that concurrent tasks don't collide.
+    file_prefix: String,
+    /// Maximum number of bytes one spilled output channel may occupy on disk.
+    max_bytes: u64,
+}
+
+impl SpillConfig {
+    pub fn new(dir: PathBuf, file_prefix: String, max_bytes: u64) -> Self {
+        Self {
+            dir,
+            file_prefix,
+            max_bytes,
+        }
+    }
+
+    /// The spill file path of the output channel `output_id`.
+    fn path_of(&self, output_id: usize) -> PathBuf {
+        self.dir
+            .join(format!("{}-{}.spill", self.file_prefix, output_id))
+    }
+}
+
+/// The disk backed part of a spillable channel: a FIFO queue of length-prefixed protobuf encoded
+/// chunks in a file, appended at the tail and popped at the head.
+#[derive(Debug)]
+struct SpillFile {
+    path: PathBuf,
+    max_bytes: u64,
+    file: Option<tokio::fs::File>,
+    read_pos: u64,
+    write_pos: u64,
+    /// Number of messages currently queued in the file.
+    queued: usize,
+}
+
+impl SpillFile {
+    fn new(path: PathBuf, max_bytes: u64) -> Self {
+        Self {
+            path,
+            max_bytes,
+            file: None,
+            read_pos: 0,
+            write_pos: 0,
+            queued: 0,
+        }
+    }
+
+    async fn file(&mut self) -> BatchResult<&mut tokio::fs::File> {
+        if self.file.is_none() {
+            if let Some(dir) = self.path.parent() {
+                tokio::fs::create_dir_all(dir).await?;
+            }
+            let file = tokio::fs::OpenOptions::new()
+                .create(true)
+                .read(true)
+                .write(true)
+                .truncate(true)
+                .open(&self.path)
+                .await?;
+            self.file = Some(file);
+        }
+        Ok(self.file.as_mut().unwrap())
+    }
+
+    async fn append(&mut self, value: Option<DataChunkInChannel>) -> BatchResult<()> {
+        let buffer = match &value {
+            Some(chunk) => {
+                let prost_chunk = chunk.to_protobuf().await;
+                let mut buffer = Vec::with_capacity(4 + prost_chunk.encoded_len());
+                buffer.extend_from_slice(&(prost_chunk.encoded_len() as u32).to_le_bytes());
+                prost_chunk.encode(&mut buffer).expect("vec has capacity");
+                buffer
+            }
+            None => END_OF_CHANNEL_MARKER.to_le_bytes().to_vec(),
+        };
+        if self.write_pos + buffer.len() as u64 > self.max_bytes {
+            return Err(BatchError::ExchangeSpillCapExceeded(self.max_bytes));
+        }
+        let write_pos = self.write_pos;
+        let file = self.file().await?;
+        file.seek(SeekFrom::Start(write_pos)).await?;
+        file.write_all(&buffer).await?;
+        self.write_pos += buffer.len() as u64;
+        self.queued += 1;
+        Ok(())
+    }
+
+    async fn pop(&mut self) -> BatchResult<Option<DataChunkInChannel>> {
+        assert!(self.queued > 0, "pop from an empty spill file");
+        let read_pos = self.read_pos;
+        let file = self.file().await?;
+        file.seek(SeekFrom::Start(read_pos)).await?;
+        let len = file.read_u32_le().await?;
+        let value = if len == END_OF_CHANNEL_MARKER {
+            self.read_pos += 4;
+            None
+        } else {
+            let mut buffer = vec![0; len as usize];
+            file.read_exact(&mut buffer).await?;
+            self.read_pos += 4 + len as u64;
+            let prost_chunk =
+                ProstDataChunk::decode(buffer.as_slice()).map_err(|e| anyhow::anyhow!(e))?;
+            let chunk = DataChunk::from_protobuf(&prost_chunk)?;
+            Some(DataChunkInChannel::new(chunk))
+        };
+        self.queued -= 1;
+        // Reclaim the disk space once the queue has been fully drained.
+        if self.queued == 0 {
+            let file = self.file.as_mut().unwrap();
+            file.set_len(0).await?;
+            self.read_pos = 0;
+            self.write_pos = 0;
+        }
+        Ok(value)
+    }
+}
+
+impl Drop for SpillFile {
+    fn drop(&mut self) {
+        if self.file.is_some() {
+            if let Err(e) = std::fs::remove_file(&self.path) {
+                tracing::warn!("failed to remove spill file {:?}: {}", self.path, e);
+            }
+        }
+    }
+}
+
+/// Sender of a bounded in-memory channel that overflows to local disk instead of blocking the
+/// producer, so that one lagging consumer does not stall the whole stage. Constructed by
+/// [`spillable_channel`].
+#[derive(Debug, Clone)]
+pub(super) struct SpillableSender {
+    sender: mpsc::Sender<Option<DataChunkInChannel>>,
+    spill: Option<Arc<Mutex<SpillFile>>>,
+}
+
+pub(super) struct SpillableReceiver {
+    receiver: mpsc::Receiver<Option<DataChunkInChannel>>,
+    spill: Option<Arc<Mutex<SpillFile>>>,
+}
+
+impl SpillableSender {
+    pub async fn send(&self, value: Option<DataChunkInChannel>) -> BatchResult<()> {
+        let spill = match &self.spill {
+            None => {
+                return self.sender.send(value).await.map_err(|_| SenderError);
+            }
+            Some(spill) => spill,
+        };
+        let mut spill = spill.lock().await;
+        // To preserve the FIFO order, the in-memory channel must not be used as long as older
+        // messages are still queued on disk.
+        if spill.queued == 0 {
+            match self.sender.try_send(value) {
+                Ok(()) => return Ok(()),
+                Err(TrySendError::Closed(_)) => return Err(SenderError),
+                Err(TrySendError::Full(value)) => spill.append(value).await,
+            }
+        } else {
+            spill.append(value).await
+        }
+    }
+}
+
+impl SpillableReceiver {
+    /// Receives the next message in send order. The outer `Option` is `None` iff the channel is
+    /// closed, like [`mpsc::Receiver::recv`].
+    pub async fn recv(&mut self) -> BatchResult<Option<Option<DataChunkInChannel>>> {
+        if let Some(spill) = &self.spill {
+            // Messages in the in-memory channel are always older than the spilled ones, so drain
+            // it first.
+            if let Ok(value) = self.receiver.try_recv() {
+                return Ok(Some(value));
+            }
+            let mut spill = spill.lock().await;
+            if spill.queued > 0 {
+                return Ok(Some(spill.pop().await?));
+            }
+        }
+        Ok(self.receiver.recv().await)
+    }
+}
+
+/// Creates a bounded channel of `capacity` chunks that, if `spill` is given, overflows to a file
+/// under the configured spill directory instead of exerting backpressure. The spill file is
+/// deleted when the channel is dropped, i.e. at the latest when the task is dropped on query end.
+pub(super) fn spillable_channel(
+    capacity: usize,
+    spill: Option<&SpillConfig>,
+    output_id: usize,
+) -> (SpillableSender, SpillableReceiver) {
+    let (sender, receiver) = mpsc::channel(capacity);
+    let spill = spill.map(|config| {
+        Arc::new(Mutex::new(SpillFile::new(
+            config.path_of(output_id),
+            config.max_bytes,
+        )))
+    });
+    (
+        SpillableSender {
+            sender,
+            spill: spill.clone(),
+        },
+        SpillableReceiver { receiver, spill },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::test_prelude::DataChunkTestExt;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_spill_on_full_channel() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = SpillConfig::new(dir.path().to_path_buf(), "test-task".to_string(), 1 << 20);
+        let (sender, mut receiver) = spillable_channel(2, Some(&config), 0);
+
+        let chunk = DataChunk::from_pretty(
+            "i
+             42",
+        );
+        // Overfill the in-memory channel: the excess chunks and the end marker go to disk.
+        for _ in 0..4 {
+            sender
+                .send(Some(DataChunkInChannel::new(chunk.clone())))
+                .await
+                .unwrap();
+        }
+        sender.send(None).await.unwrap();
+        assert!(std::fs::read_dir(dir.path()).unwrap().next().is_some());
+
+        for _ in 0..4 {
+            let received = receiver.recv().await.unwrap().unwrap().unwrap();
+            assert_eq!(received.into_data_chunk(), chunk);
+        }
+        // The end marker is received in order, after the spilled chunks.
+        assert!(receiver.recv().await.unwrap().unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_spill_cap_exceeded() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = SpillConfig::new(dir.path().to_path_buf(), "test-task".to_string(), 16);
+        let (sender, _receiver) = spillable_channel(1, Some(&config), 0);
+
+        let chunk = DataChunk::from_pretty(
+            "i
+             42",
+        );
+        sender
+            .send(Some(DataChunkInChannel::new(chunk.clone())))
+            .await
+            .unwrap();
+        // The channel is full and the chunk is larger than the cap.
+        assert!(sender
+            .send(Some(DataChunkInChannel::new(chunk)))
+            .await
+            .is_err());
+    }
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::fmt::{Debug, Formatter};
 use std::future::Future;
 use std::sync::Arc;
@@ -21,10 +22,12 @@ use futures::StreamExt;
 use minitrace::prelude::*;
 use parking_lot::Mutex;
 use risingwave_common::array::DataChunk;
+use risingwave_common::catalog::TableId;
 use risingwave_common::error::ErrorCode::InternalError;
 use risingwave_common::error::{ErrorCode, Result, RwError};
+use risingwave_pb::batch_plan::plan_node::NodeBody;
 use risingwave_pb::batch_plan::{
-    PlanFragment, TaskId as ProstTaskId, TaskOutputId as ProstOutputId,
+    PlanFragment, PlanNode, TaskId as ProstTaskId, TaskOutputId as ProstOutputId,
 };
 use risingwave_pb::common::BatchQueryEpoch;
 use risingwave_pb::task_service::task_info::TaskStatus;
@@ -37,10 +40,11 @@ use tonic::Status;
 
 use crate::error::BatchError::SenderError;
 use crate::error::{BatchError, Result as BatchResult};
-use crate::executor::{BoxedExecutor, ExecutorBuilder};
+use crate::executor::{BoxedExecutor, ExecutorBuilder, MemUsageRegistry, MEM_USAGE_REGISTRY};
 use crate::rpc::service::exchange::ExchangeWriter;
 use crate::rpc::service::task_service::{GetDataResponseResult, TaskInfoResponseResult};
 use crate::task::channel::{create_output_channel, ChanReceiverImpl, ChanSenderImpl};
+use crate::task::spill_channel::SpillConfig;
 use crate::task::BatchTaskContext;
 
 // Now we will only at most have 2 status for each status channel. Running -> Failed or Finished.
@@ -59,7 +63,7 @@ where
     F: FnMut(usize),
     C: BatchTaskContext,
 {
-    BYTES_ALLOCATED
+    let stat_fut = BYTES_ALLOCATED
         .scope(TaskLocalBytesAllocated::new(), async move {
             // The guard has the same lifetime as the counter so that the counter will keep positive
             // in the whole scope. When the scope exits, the guard is released, so the counter can
@@ -82,7 +86,11 @@ where
                 },
             };
             output
-        })
+        });
+    // The per-operator memory registry shares the lifetime of the allocation counter, so
+    // `MemUsageTrackingExecutor` can attribute the counted bytes to operators.
+    MEM_USAGE_REGISTRY
+        .scope(Arc::new(MemUsageRegistry::default()), stat_fut)
         .await
 }
 
@@ -275,6 +283,32 @@ impl TaskOutput {
     }
 }
 
+/// Collects the ids of all tables the plan reads from the state store, for which the snapshot
+/// has to cover the query epoch before execution starts.
+fn collect_scan_table_ids(plan: &PlanNode, table_ids: &mut HashSet<u32>) {
+    match &plan.node_body {
+        Some(NodeBody::RowSeqScan(scan)) => {
+            if let Some(table_desc) = &scan.table_desc {
+                table_ids.insert(table_desc.table_id);
+            }
+        }
+        Some(NodeBody::DistributedLookupJoin(join)) => {
+            if let Some(table_desc) = &join.inner_side_table_desc {
+                table_ids.insert(table_desc.table_id);
+            }
+        }
+        Some(NodeBody::LocalLookupJoin(join)) => {
+            if let Some(table_desc) = &join.inner_side_table_desc {
+                table_ids.insert(table_desc.table_id);
+            }
+        }
+        _ => {}
+    }
+    for child in &plan.children {
+        collect_scan_table_ids(child, table_ids);
+    }
+}
+
 /// `BatchTaskExecution` represents a single task execution.
 pub struct BatchTaskExecution<C> {
     /// Task id.
@@ -298,8 +332,9 @@ pub struct BatchTaskExecution<C> {
     /// The execution failure.
     failure: Arc<Mutex<Option<RwError>>>,
 
-    /// Shutdown signal sender.
-    shutdown_tx: Mutex<Option<Sender<u64>>>,
+    /// Shutdown signal sender. An empty message aborts the task silently, while a non-empty one
+    /// fails the task with the message as the error reported to the frontend.
+    shutdown_tx: Mutex<Option<Sender<String>>>,
 
     /// State receivers. Will be moved out by `.state_receivers()`. Returned back to client.
     /// This is a hack, cuz there is no easy way to get out the receiver.
@@ -321,9 +356,21 @@ impl<C: BatchTaskContext> BatchTaskExecution<C> {
     ) -> Result<Self> {
         let task_id = TaskId::from(prost_tid);
 
+        let developer = &context.get_config().developer;
+        let spill = developer.batch_output_channel_spill_enabled.then(|| {
+            SpillConfig::new(
+                developer.batch_output_channel_spill_dir.clone().into(),
+                format!(
+                    "{}-{}-{}",
+                    task_id.query_id, task_id.stage_id, task_id.task_id
+                ),
+                developer.batch_output_channel_spill_max_bytes,
+            )
+        });
         let (sender, receivers) = create_output_channel(
             plan.get_exchange_info()?,
-            context.get_config().developer.batch_output_channel_size,
+            developer.batch_output_channel_size,
+            spill.as_ref(),
         )?;
 
         let mut rts = Vec::new();
@@ -354,7 +401,15 @@ impl<C: BatchTaskContext> BatchTaskExecution<C> {
     /// hash partitioned across multiple channels.
     /// To obtain the result, one must pick one of the channels to consume via [`TaskOutputId`]. As
     /// such, parallel consumers are able to consume the result independently.
-    pub async fn async_execute(self: Arc<Self>, state_tx: StateReporter) -> Result<()> {
+    ///
+    /// `trace_span` is the root span the whole task execution is traced under. It is reported
+    /// with the trace id assigned by the frontend, so that all tasks of a distributed query show
+    /// up as a single trace.
+    pub async fn async_execute(
+        self: Arc<Self>,
+        state_tx: StateReporter,
+        trace_span: Span,
+    ) -> Result<()> {
         let mut state_tx = state_tx;
         trace!(
             "Prepare executing plan [{:?}]: {}",
@@ -373,7 +428,7 @@ impl<C: BatchTaskContext> BatchTaskExecution<C> {
 
         // Init shutdown channel and data receivers.
         let sender = self.sender.clone();
-        let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<u64>();
+        let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<String>();
         *self.shutdown_tx.lock() = Some(shutdown_tx);
         let failure = self.failure.clone();
         let task_id = self.task_id.clone();
@@ -400,7 +455,7 @@ impl<C: BatchTaskContext> BatchTaskExecution<C> {
                 if let Err(e) = t_1
                     .try_execute(exec, &mut sender, shutdown_rx, &mut state_tx)
                     .in_span({
-                        let mut span = Span::enter_with_local_parent("batch_execute");
+                        let mut span = trace_span;
                         span.add_property(|| ("task_id", task_id.task_id.to_string()));
                         span.add_property(|| ("stage_id", task_id.stage_id.to_string()));
                         span.add_property(|| ("query_id", task_id.query_id.to_string()));
@@ -468,14 +523,36 @@ impl<C: BatchTaskContext> BatchTaskExecution<C> {
         };
 
         // For every fired Batch Task, we will wrap it with allocation stats to report memory
-        // estimation per task to `BatchManager`.
+        // estimation per task to `BatchManager`. If the task exceeds the configured memory limit,
+        // it is failed with an error naming the operator that holds the most memory.
         let ctx1 = self.context.clone();
         let ctx2 = self.context.clone();
+        let mem_limit_bytes = self.context.get_config().task_memory_limit_mb * 1024 * 1024;
+        let t_3 = self.clone();
+        let mut limit_exceeded = false;
         let alloc_stat_wrap_fut = allocation_stat_for_batch(
             fut,
             Duration::from_millis(1000),
             move |bytes| {
                 ctx1.store_mem_usage(bytes);
+                if mem_limit_bytes != 0 && bytes > mem_limit_bytes && !limit_exceeded {
+                    // Only fire once: the task takes a while to wind down and the usage stays
+                    // above the limit until it does.
+                    limit_exceeded = true;
+                    let top_consumer = MEM_USAGE_REGISTRY
+                        .try_with(|registry| registry.top_consumer())
+                        .ok()
+                        .flatten()
+                        .map(|(identity, op_bytes)| {
+                            format!(", the top memory consumer is {identity} ({op_bytes} bytes)")
+                        })
+                        .unwrap_or_default();
+                    t_3.fail_task(format!(
+                        "task {:?} exceeded the memory limit: {} bytes used, the limit is {} \
+                         bytes{}",
+                        t_3.task_id, bytes, mem_limit_bytes, top_consumer
+                    ));
+                }
             },
             ctx2,
         );
@@ -516,16 +593,38 @@ impl<C: BatchTaskContext> BatchTaskExecution<C> {
         &self,
         root: BoxedExecutor,
         sender: &mut ChanSenderImpl,
-        mut shutdown_rx: Receiver<u64>,
+        mut shutdown_rx: Receiver<String>,
         state_tx: &mut StateReporter,
     ) -> Result<()> {
+        // Before starting to read, make sure every table the plan scans can be served from one
+        // snapshot at the query epoch, waiting for the local version to catch up if necessary.
+        // This keeps the snapshot observed by the frontend consistent across tables even when
+        // they are checkpointed unevenly, e.g. across barrier domains.
+        if let Some(hummock) = self.context.state_store().as_hummock() {
+            let mut table_ids = HashSet::new();
+            collect_scan_table_ids(self.plan.get_root()?, &mut table_ids);
+            if !table_ids.is_empty() {
+                hummock
+                    .try_wait_consistent_snapshot(
+                        table_ids.into_iter().map(TableId::new),
+                        self.epoch.clone().into(),
+                    )
+                    .await?;
+            }
+        }
+
         let mut data_chunk_stream = root.execute();
         let mut state = TaskStatus::Unspecified;
         loop {
             tokio::select! {
             // We prioritize abort signal over normal data chunks.
             biased;
-            _ = &mut shutdown_rx => {
+            msg = &mut shutdown_rx => {
+                if let Ok(err_msg) = msg && !err_msg.is_empty() {
+                    // The task is killed with an error. Return it so that the caller sets the
+                    // failure and reports `TaskStatus::Failed` to the frontend.
+                    return Err(InternalError(err_msg))?;
+                }
                 state = TaskStatus::Aborted;
                 break;
             }
@@ -582,7 +681,7 @@ impl<C: BatchTaskContext> BatchTaskExecution<C> {
         if let Some(sender) = self.shutdown_tx.lock().take() {
             // No need to set state to be Aborted here cuz it will be set by shutdown receiver.
             // Stop task execution.
-            if sender.send(0).is_err() {
+            if sender.send(String::new()).is_err() {
                 warn!("The task has already died before this request, so the abort did no-op")
             } else {
                 info!("Abort task {:?} done", self.task_id);
@@ -590,6 +689,18 @@ impl<C: BatchTaskContext> BatchTaskExecution<C> {
         };
     }
 
+    /// Stops the task execution like [`Self::abort_task`], but reports `err_msg` to the frontend
+    /// as the failure of the task, instead of silently truncating the results.
+    pub fn fail_task(&self, err_msg: String) {
+        if let Some(sender) = self.shutdown_tx.lock().take() {
+            if sender.send(err_msg).is_err() {
+                warn!("The task has already died before this request, so the fail did no-op")
+            } else {
+                info!("Fail task {:?} done", self.task_id);
+            }
+        };
+    }
+
     pub fn get_task_output(&self, output_id: &ProstOutputId) -> Result<TaskOutput> {
         let task_id = TaskId::from(output_id.get_task_id()?);
         let receiver = self.receivers.lock()[output_id.get_output_id() as usize]
@@ -17,10 +17,12 @@ use std::net::SocketAddr;
 use std::sync::Arc;
 
 use hytra::TrAdder;
+use minitrace::prelude::*;
 use parking_lot::Mutex;
 use risingwave_common::config::BatchConfig;
 use risingwave_common::error::ErrorCode::{self, TaskNotFound};
 use risingwave_common::error::Result;
+use risingwave_common::util::resource_util::cpu::total_cpu_available;
 use risingwave_pb::batch_plan::{
     PlanFragment, TaskId as ProstTaskId, TaskOutputId as ProstTaskOutputId,
 };
@@ -62,10 +64,8 @@ impl BatchManager {
     pub fn new(config: BatchConfig, metrics: BatchManagerMetrics) -> Self {
         let runtime = {
             let mut builder = tokio::runtime::Builder::new_multi_thread();
-            if let Some(worker_threads_num) = config.worker_threads_num {
-                builder.worker_threads(worker_threads_num);
-            }
             builder
+                .worker_threads(Self::runtime_worker_threads(&config))
                 .thread_name("risingwave-batch-tasks")
                 .enable_all()
                 .build()
@@ -90,6 +90,7 @@ impl BatchManager {
         epoch: BatchQueryEpoch,
         context: ComputeNodeContext,
         state_reporter: StateReporter,
+        trace_span: Span,
     ) -> Result<()> {
         trace!("Received task id: {:?}, plan: {:?}", tid, plan);
         let task = BatchTaskExecution::new(tid, plan, context, epoch, self.runtime)?;
@@ -109,7 +110,9 @@ impl BatchManager {
             ))
             .into())
         };
-        task.clone().async_execute(state_reporter).await?;
+        task.clone()
+            .async_execute(state_reporter, trace_span)
+            .await?;
         ret
     }
 
@@ -207,13 +210,24 @@ impl BatchManager {
         self.runtime
     }
 
+    /// Decides the thread count of the dedicated batch runtime: the configured number if set,
+    /// otherwise the configured fraction of available CPU cores, at least one. The remaining
+    /// cores are left to the streaming actor runtime, so that heavy ad-hoc queries cannot delay
+    /// barriers.
+    fn runtime_worker_threads(config: &BatchConfig) -> usize {
+        config.worker_threads_num.unwrap_or_else(|| {
+            let cores = total_cpu_available() as f64;
+            ((cores * config.runtime_worker_threads_core_fraction) as usize).max(1)
+        })
+    }
+
     pub fn config(&self) -> &BatchConfig {
         &self.config
     }
 
     /// Kill batch queries with larges memory consumption per task. Required to maintain task level
     /// memory usage in the struct. Will be called by global memory manager.
-    pub fn kill_queries(&self) {
+    pub fn kill_queries(&self, reason: &str) {
         let mut max_mem_task_id = None;
         let mut max_mem = usize::MIN;
         let guard = self.tasks.lock();
@@ -232,9 +246,12 @@ impl BatchManager {
         }
         if let Some(id) = max_mem_task_id {
             let t = guard.get(&id).unwrap();
-            // FIXME: `Abort` will not report error but truncated results to user. We should
-            // consider throw error.
-            t.abort_task();
+            // Fail the task instead of aborting it, so that the frontend reports an error to the
+            // user rather than silently truncating the results.
+            t.fail_task(format!(
+                "task {:?} is killed with {} bytes in use: {}",
+                id, max_mem, reason
+            ));
         }
     }
 
@@ -329,6 +346,7 @@ mod tests {
                 to_committed_batch_query_epoch(0),
                 context.clone(),
                 StateReporter::new_with_test(),
+                Span::new_noop(),
             )
             .await
             .unwrap();
@@ -339,6 +357,7 @@ mod tests {
                 to_committed_batch_query_epoch(0),
                 context,
                 StateReporter::new_with_test(),
+                Span::new_noop(),
             )
             .await
             .unwrap_err();
@@ -386,6 +405,7 @@ mod tests {
                 to_committed_batch_query_epoch(0),
                 context.clone(),
                 StateReporter::new_with_test(),
+                Span::new_noop(),
             )
             .await
             .unwrap();
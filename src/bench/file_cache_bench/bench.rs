@@ -67,6 +67,7 @@ pub async fn run(args: Args, stop: oneshot::Receiver<()>) {
         cache_file_fallocate_unit: args.cache_file_fallocate_unit * 1024 * 1024,
         cache_meta_fallocate_unit: args.cache_meta_fallocate_unit * 1024 * 1024,
         cache_file_max_write_size: args.cache_file_max_write_size * 1024 * 1024,
+        enable_direct_io: !args.disable_direct_io,
         flush_buffer_hooks: vec![hook],
     };
 
@@ -47,6 +47,8 @@ pub struct Args {
     /// (MiB)
     #[clap(long, default_value = "4")]
     cache_file_max_write_size: usize,
+    #[clap(long)]
+    disable_direct_io: bool,
 
     /// (KiB)
     #[clap(long, default_value = "1024")]
@@ -445,6 +445,7 @@ impl From<&ListArray> for arrow_array::ListArray {
             ),
             ArrayImpl::Struct(_) => todo!("list of struct"),
             ArrayImpl::List(_) => todo!("list of list"),
+            ArrayImpl::Jsonb(_) => todo!("list of jsonb"),
             ArrayImpl::Bytea(a) => build(
                 array,
                 a,
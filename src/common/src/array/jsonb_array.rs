@@ -0,0 +1,468 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::{Cursor, Read};
+use std::mem::size_of;
+
+use bytes::{Buf, BufMut};
+use byteorder::{BigEndian, ReadBytesExt};
+use risingwave_pb::common::buffer::CompressionType;
+use risingwave_pb::common::Buffer;
+use risingwave_pb::data::{Array as ProstArray, ArrayType};
+use serde_json::Value;
+
+use super::{Array, ArrayBuilder, ArrayImpl, ArrayMeta, ArrayResult};
+use crate::buffer::{Bitmap, BitmapBuilder};
+use crate::types::to_binary::ToBinary;
+use crate::types::to_text::ToText;
+use crate::types::{DataType, Scalar, ScalarImpl, ScalarRef};
+use crate::util::iter_util::ZipEqDebug;
+
+/// The in-memory representation of a `JSONB` value, backed by [`serde_json::Value`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonbVal(Box<Value>);
+
+/// A reference to a `JSONB` value.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct JsonbRef<'a>(&'a Value);
+
+impl Eq for JsonbVal {}
+impl Eq for JsonbRef<'_> {}
+
+impl PartialOrd for JsonbVal {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for JsonbVal {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.as_scalar_ref().cmp(&other.as_scalar_ref())
+    }
+}
+
+impl PartialOrd for JsonbRef<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for JsonbRef<'_> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        // We do not intend to support ordering `jsonb` values. But `ScalarImpl` requires `Ord`
+        // on all variants, so we order by the text representation here, which at least gives a
+        // stable (although hardly meaningful) order.
+        self.0.to_string().cmp(&other.0.to_string())
+    }
+}
+
+impl Scalar for JsonbVal {
+    type ScalarRefType<'a> = JsonbRef<'a>;
+
+    fn as_scalar_ref(&self) -> Self::ScalarRefType<'_> {
+        JsonbRef(self.0.as_ref())
+    }
+
+    fn to_scalar_value(self) -> ScalarImpl {
+        ScalarImpl::Jsonb(self)
+    }
+}
+
+impl<'a> ScalarRef<'a> for JsonbRef<'a> {
+    type ScalarType = JsonbVal;
+
+    fn to_owned_scalar(&self) -> Self::ScalarType {
+        JsonbVal(self.0.clone().into())
+    }
+
+    fn hash_scalar<H: std::hash::Hasher>(&self, state: &mut H) {
+        use std::hash::Hash as _;
+        // We do not intend to support hashing `jsonb` values either, so the hash implementation
+        // simply follows the unspecified order above.
+        self.0.to_string().hash(state)
+    }
+}
+
+impl ToText for JsonbRef<'_> {
+    fn write<W: std::fmt::Write>(&self, f: &mut W) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+
+    fn write_with_type<W: std::fmt::Write>(&self, ty: &DataType, f: &mut W) -> std::fmt::Result {
+        match ty {
+            DataType::Jsonb => self.write(f),
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl ToBinary for JsonbRef<'_> {
+    fn to_binary_with_type(&self, _ty: &DataType) -> crate::error::Result<Option<bytes::Bytes>> {
+        // Postgres's jsonb binary format: a version byte followed by the text representation.
+        let mut output = bytes::BytesMut::new();
+        output.put_u8(1);
+        output.put_slice(self.0.to_string().as_bytes());
+        Ok(Some(output.freeze()))
+    }
+}
+
+impl std::fmt::Display for JsonbVal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl std::fmt::Display for JsonbRef<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl std::str::FromStr for JsonbVal {
+    type Err = serde_json::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let v: Value = s.parse()?;
+        Ok(Self(v.into()))
+    }
+}
+
+impl From<Value> for JsonbVal {
+    fn from(v: Value) -> Self {
+        Self(v.into())
+    }
+}
+
+impl From<JsonbRef<'_>> for Value {
+    fn from(v: JsonbRef<'_>) -> Self {
+        v.0.clone()
+    }
+}
+
+impl JsonbVal {
+    /// A `null` value in the json sense, which is distinct from a `NULL` datum.
+    pub fn null() -> Self {
+        Self(Value::Null.into())
+    }
+
+    pub fn memcmp_deserialize(
+        deserializer: &mut memcomparable::Deserializer<impl Buf>,
+    ) -> memcomparable::Result<Self> {
+        let v: Value = <String as serde::Deserialize>::deserialize(deserializer)?
+            .parse()
+            .map_err(|_| memcomparable::Error::Message("invalid json".into()))?;
+        Ok(Self(v.into()))
+    }
+}
+
+impl<'a> JsonbRef<'a> {
+    pub fn memcmp_serialize(
+        &self,
+        serializer: &mut memcomparable::Serializer<impl BufMut>,
+    ) -> memcomparable::Result<()> {
+        // As mentioned with `cmp`, this implementation is not intended to be used for sorting,
+        // but to keep the encoding consistent between `JsonbRef` and other variants.
+        serde::Serialize::serialize(&self.0.to_string(), serializer)
+    }
+
+    /// Whether this is a json `null` value, which is distinct from a `NULL` datum.
+    pub fn is_jsonb_null(&self) -> bool {
+        matches!(self.0, Value::Null)
+    }
+
+    /// Returns the field of the json object with the given key, i.e. `jsonb -> 'key'`.
+    pub fn access_object_field(&self, field: &str) -> Option<Self> {
+        self.0.get(field).map(Self)
+    }
+
+    /// Returns the element of the json array at the given index, i.e. `jsonb -> 1`. A negative
+    /// index counts from the end, following PostgreSQL.
+    pub fn access_array_element(&self, idx: i32) -> Option<Self> {
+        let array = self.0.as_array()?;
+        let idx = if idx < 0 {
+            array.len().checked_sub(idx.unsigned_abs() as usize)?
+        } else {
+            idx as usize
+        };
+        array.get(idx).map(Self)
+    }
+
+    /// Returns the text of a json string without quotes, or the text representation of any other
+    /// json value, as required by the `->>` and `#>>` operators.
+    pub fn force_str(&self) -> String {
+        match self.0 {
+            Value::String(s) => s.clone(),
+            v => v.to_string(),
+        }
+    }
+
+    /// Whether `other` is contained in `self`, following the containment rules of Postgres's
+    /// `@>` operator: objects contain objects with a subset of their fields, arrays contain
+    /// values contained by any of their elements, and scalars contain equal scalars.
+    pub fn jsonb_contains(&self, other: &Self) -> bool {
+        fn contains(left: &Value, right: &Value) -> bool {
+            match (left, right) {
+                (Value::Object(left), Value::Object(right)) => right
+                    .iter()
+                    .all(|(key, value)| left.get(key).map_or(false, |v| contains(v, value))),
+                (Value::Array(left), Value::Array(right)) => right
+                    .iter()
+                    .all(|value| left.iter().any(|v| contains(v, value))),
+                // A top-level array may contain a scalar.
+                (Value::Array(left), right) => left.iter().any(|v| v == right),
+                (left, right) => left == right,
+            }
+        }
+        contains(self.0, other.0)
+    }
+}
+
+#[derive(Debug)]
+pub struct JsonbArrayBuilder {
+    bitmap: BitmapBuilder,
+    data: Vec<Value>,
+}
+
+/// `JsonbArray` is a collection of `JSONB` values. Nulls are padded with `Value::Null`, which
+/// is also a valid json value, so always check the null bitmap before accessing the data.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonbArray {
+    bitmap: Bitmap,
+    data: Vec<Value>,
+}
+
+impl Eq for JsonbArray {}
+
+impl ArrayBuilder for JsonbArrayBuilder {
+    type ArrayType = JsonbArray;
+
+    fn with_meta(capacity: usize, _meta: ArrayMeta) -> Self {
+        Self {
+            bitmap: BitmapBuilder::with_capacity(capacity),
+            data: Vec::with_capacity(capacity),
+        }
+    }
+
+    fn append_n(&mut self, n: usize, value: Option<<Self::ArrayType as Array>::RefItem<'_>>) {
+        match value {
+            Some(x) => {
+                self.bitmap.append_n(n, true);
+                self.data
+                    .extend(std::iter::repeat(x).map(|x| x.0.clone()).take(n));
+            }
+            None => {
+                self.bitmap.append_n(n, false);
+                self.data
+                    .extend(std::iter::repeat(Value::Null).take(n));
+            }
+        }
+    }
+
+    fn append_array(&mut self, other: &Self::ArrayType) {
+        for bit in other.bitmap.iter() {
+            self.bitmap.append(bit);
+        }
+        self.data.extend_from_slice(&other.data);
+    }
+
+    fn pop(&mut self) -> Option<()> {
+        self.data.pop().map(|_| self.bitmap.pop().unwrap())
+    }
+
+    fn finish(self) -> Self::ArrayType {
+        Self::ArrayType {
+            bitmap: self.bitmap.finish(),
+            data: self.data,
+        }
+    }
+}
+
+impl Array for JsonbArray {
+    type Builder = JsonbArrayBuilder;
+    type OwnedItem = JsonbVal;
+    type RefItem<'a> = JsonbRef<'a>;
+
+    unsafe fn raw_value_at_unchecked(&self, idx: usize) -> Self::RefItem<'_> {
+        JsonbRef(self.data.get_unchecked(idx))
+    }
+
+    fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    fn to_protobuf(&self) -> ProstArray {
+        // The memory layout contains `serde_json::Value` trees, but in protobuf we transmit this
+        // as variable-length bytes in value encoding. That is, one buffer of length n+1 containing
+        // start and end offsets into the other buffer of all texts concatenated.
+        use std::io::Write;
+
+        let mut offset_buffer =
+            Vec::<u8>::with_capacity((1 + self.data.len()) * size_of::<u64>());
+        let mut data_buffer = Vec::<u8>::with_capacity(self.data.len());
+        for (v, not_null) in self.data.iter().zip_eq_debug(self.null_bitmap().iter()) {
+            if !not_null {
+                continue;
+            }
+            let offset = data_buffer.len() as u64;
+            offset_buffer.extend_from_slice(&offset.to_be_bytes());
+            write!(&mut data_buffer, "{}", v).unwrap();
+        }
+        let offset = data_buffer.len() as u64;
+        offset_buffer.extend_from_slice(&offset.to_be_bytes());
+
+        let values = vec![
+            Buffer {
+                compression: CompressionType::None as i32,
+                body: offset_buffer,
+            },
+            Buffer {
+                compression: CompressionType::None as i32,
+                body: data_buffer,
+            },
+        ];
+
+        let null_bitmap = self.null_bitmap().to_protobuf();
+        ProstArray {
+            null_bitmap: Some(null_bitmap),
+            values,
+            array_type: ArrayType::Jsonb as i32,
+            struct_array_data: None,
+            list_array_data: None,
+        }
+    }
+
+    fn null_bitmap(&self) -> &Bitmap {
+        &self.bitmap
+    }
+
+    fn into_null_bitmap(self) -> Bitmap {
+        self.bitmap
+    }
+
+    fn set_bitmap(&mut self, bitmap: Bitmap) {
+        self.bitmap = bitmap;
+    }
+
+    fn create_builder(&self, capacity: usize) -> super::ArrayBuilderImpl {
+        let array_builder = JsonbArrayBuilder::new(capacity);
+        super::ArrayBuilderImpl::Jsonb(array_builder)
+    }
+}
+
+impl JsonbArray {
+    /// Unlike the other arrays, `JsonbArray` stores parsed values rather than the encoded bytes,
+    /// so it cannot share `read_string_array`, which returns references into the buffer.
+    pub fn from_protobuf(array: &ProstArray, cardinality: usize) -> ArrayResult<ArrayImpl> {
+        ensure!(
+            array.values.len() == 2,
+            "Must have exactly 2 buffers in a jsonb array"
+        );
+
+        let offset_buf = array.get_values()[0].get_body().as_slice();
+        let data_buf = array.get_values()[1].get_body().as_slice();
+
+        let mut builder = JsonbArrayBuilder::new(cardinality);
+        let bitmap: Bitmap = array.get_null_bitmap()?.into();
+        let mut offset_cursor = Cursor::new(offset_buf);
+        let mut data_cursor = Cursor::new(data_buf);
+        let mut prev_offset: i64 = -1;
+
+        let mut buf = Vec::new();
+        for not_null in bitmap.iter() {
+            if not_null {
+                if prev_offset < 0 {
+                    prev_offset = offset_cursor.read_i64::<BigEndian>()?;
+                }
+                let offset = offset_cursor.read_i64::<BigEndian>()?;
+                let length = (offset - prev_offset) as usize;
+                prev_offset = offset;
+                buf.resize(length, Default::default());
+                data_cursor.read_exact(buf.as_mut_slice())?;
+                let v: Value = serde_json::from_slice(buf.as_slice())
+                    .map_err(|e| anyhow::anyhow!("failed to read jsonb from buffer: {}", e))?;
+                builder.append(Some(JsonbRef(&v)));
+            } else {
+                builder.append(None);
+            }
+        }
+        let arr = builder.finish();
+        ensure_eq!(arr.len(), cardinality);
+
+        Ok(arr.into())
+    }
+}
+
+impl<'a> FromIterator<Option<JsonbRef<'a>>> for JsonbArray {
+    fn from_iter<I: IntoIterator<Item = Option<JsonbRef<'a>>>>(iter: I) -> Self {
+        let iter = iter.into_iter();
+        let mut builder = <Self as Array>::Builder::new(iter.size_hint().0);
+        for i in iter {
+            builder.append(i);
+        }
+        builder.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jsonb_access() {
+        let v: JsonbVal = r#"{"a": {"b": [1, "2", null]}}"#.parse().unwrap();
+        let inner = v
+            .as_scalar_ref()
+            .access_object_field("a")
+            .unwrap()
+            .access_object_field("b")
+            .unwrap();
+        assert_eq!(inner.access_array_element(1).unwrap().force_str(), "2");
+        assert!(inner.access_array_element(2).unwrap().is_jsonb_null());
+        assert_eq!(inner.access_array_element(-3).unwrap().to_string(), "1");
+        assert!(inner.access_array_element(3).is_none());
+        assert!(v.as_scalar_ref().access_object_field("b").is_none());
+    }
+
+    #[test]
+    fn test_jsonb_contains() {
+        let v: JsonbVal = r#"{"a": [1, 2], "b": null}"#.parse().unwrap();
+        for (sub, expected) in [
+            (r#"{"a": [2]}"#, true),
+            (r#"{"a": [3]}"#, false),
+            (r#"{"b": null}"#, true),
+            (r#"[1]"#, false),
+        ] {
+            let sub: JsonbVal = sub.parse().unwrap();
+            assert_eq!(
+                v.as_scalar_ref().jsonb_contains(&sub.as_scalar_ref()),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_jsonb_array_protobuf() {
+        let array: JsonbArray = [
+            Some(r#"{"a": 1}"#.parse::<JsonbVal>().unwrap()),
+            None,
+            Some("[null, true]".parse::<JsonbVal>().unwrap()),
+        ]
+        .iter()
+        .map(|v| v.as_ref().map(|v| v.as_scalar_ref()))
+        .collect();
+        let prost_array = array.to_protobuf();
+        let restored = JsonbArray::from_protobuf(&prost_array, array.len()).unwrap();
+        assert_eq!(ArrayImpl::from(array.clone()), restored);
+    }
+}
@@ -20,6 +20,7 @@ pub mod test_utils;
 
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 
 use async_trait::async_trait;
 pub use column::*;
@@ -256,6 +257,29 @@ impl TableOption {
     }
 }
 
+/// Parse the `checkpoint_slo` property into a [`Duration`], if present.
+///
+/// An unparsable value is ignored with a warning, following the lenient handling of
+/// [`TableOption::build_table_option`]. The frontend validates the value at DDL time, so this
+/// only happens for catalogs written by older or external tooling.
+pub fn checkpoint_slo_from_properties(
+    table_properties: &HashMap<String, String>,
+) -> Option<Duration> {
+    let slo_string =
+        table_properties.get(crate::constants::streaming::PROPERTIES_CHECKPOINT_SLO_KEY)?;
+    match humantime::parse_duration(slo_string.trim()) {
+        Ok(slo) => Some(slo),
+        Err(e) => {
+            tracing::warn!(
+                "checkpoint_slo_from_properties parse option slo_string {} fail {}",
+                slo_string,
+                e
+            );
+            None
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, Display, Default, Hash, PartialOrd, PartialEq, Eq)]
 pub struct IndexId {
     pub index_id: u32,
@@ -50,6 +50,11 @@ pub struct TableDesc {
 
     /// the column indices which could receive watermarks.
     pub watermark_columns: FixedBitSet,
+
+    /// Name of the remote cluster this table lives in, if it was resolved through a mounted
+    /// remote catalog. Scans of such a table are scheduled onto the remote cluster's compute
+    /// nodes. `None` for local tables.
+    pub remote_cluster: Option<String>,
 }
 
 impl TableDesc {
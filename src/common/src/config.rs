@@ -17,6 +17,7 @@
 //! [`RwConfig`] corresponds to the whole config file and each other config struct corresponds to a
 //! section in `risingwave.toml`.
 
+use std::collections::HashMap;
 use std::fs;
 
 use clap::ArgEnum;
@@ -124,6 +125,16 @@ pub struct MetaConfig {
     #[serde(default = "default::meta::vacuum_interval_sec")]
     pub vacuum_interval_sec: u64,
 
+    /// Interval of reconciling branched SST metadata left over by interrupted compaction
+    /// group splits. 0 disables the reconciliation.
+    #[serde(default = "default::meta::branched_sst_reconciliation_interval_sec")]
+    pub branched_sst_reconciliation_interval_sec: u64,
+
+    /// Interval of exporting the full hummock checkpoint version to the backup object store,
+    /// for disaster recovery when the meta store is lost. 0 disables the export.
+    #[serde(default = "default::meta::hummock_version_checkpoint_export_interval_sec")]
+    pub hummock_version_checkpoint_export_interval_sec: u64,
+
     /// Maximum allowed heartbeat interval in seconds.
     #[serde(default = "default::meta::max_heartbeat_interval_sec")]
     pub max_heartbeat_interval_secs: u32,
@@ -158,6 +169,17 @@ pub struct MetaConfig {
     /// Schedule space_reclaim compaction for all compaction groups with this interval.
     #[serde(default = "default::meta::periodic_space_reclaim_compaction_interval_sec")]
     pub periodic_space_reclaim_compaction_interval_sec: u64,
+
+    /// Whether to allow the fault injection RPCs for chaos testing. Must never be enabled in
+    /// production.
+    #[serde(default)]
+    pub enable_unsafe_fault_injection: bool,
+
+    /// The maximum number of streaming jobs whose actors are rebuilt concurrently during
+    /// recovery. Limiting this avoids a thundering herd on sources and storage when a cluster
+    /// with many streaming jobs recovers. 0 means no limit.
+    #[serde(default = "default::meta::recovery_build_parallelism")]
+    pub recovery_build_parallelism: usize,
 }
 
 impl Default for MetaConfig {
@@ -186,6 +208,10 @@ pub struct ServerConfig {
     /// 0 = close metrics
     /// >0 = open metrics
     pub metrics_level: u32,
+
+    /// Mutual TLS for node-to-node RPC connections.
+    #[serde(default)]
+    pub tls: TlsConfig,
 }
 
 impl Default for ServerConfig {
@@ -194,15 +220,60 @@ impl Default for ServerConfig {
     }
 }
 
+/// The section `[server.tls]` in `risingwave.toml`, enabling mutual TLS for the RPC connections
+/// between nodes, for deployments where the nodes cannot run on a trusted flat network.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TlsConfig {
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Path to the PEM-encoded certificate presented to peers.
+    #[serde(default)]
+    pub cert_file: String,
+
+    /// Path to the PEM-encoded private key of the certificate.
+    #[serde(default)]
+    pub key_file: String,
+
+    /// Path to the PEM-encoded CA certificate used to verify the certificates of both peer
+    /// servers and peer clients.
+    #[serde(default)]
+    pub ca_cert_file: String,
+
+    /// The interval for re-reading the certificate files, so that rotated certificates are
+    /// picked up without restarting the node.
+    #[serde(default = "default::server::tls_reload_interval_secs")]
+    pub reload_interval_secs: u64,
+}
+
+impl Default for TlsConfig {
+    fn default() -> Self {
+        toml::from_str("").unwrap()
+    }
+}
+
 /// The section `[batch]` in `risingwave.toml`.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct BatchConfig {
-    /// The thread number of the batch task runtime in the compute node. The default value is
-    /// decided by `tokio`.
+    /// The thread number of the batch task runtime in the compute node. If not set, it is
+    /// decided by `runtime_worker_threads_core_fraction`.
     #[serde(default)]
     pub worker_threads_num: Option<usize>,
 
+    /// The fraction of available CPU cores the batch task runtime uses when
+    /// `worker_threads_num` is not set. Batch tasks run on their own runtime so that heavy
+    /// ad-hoc queries cannot steal scheduler time from streaming actors and delay barriers.
+    #[serde(default = "default::batch::runtime_worker_threads_core_fraction")]
+    pub runtime_worker_threads_core_fraction: f64,
+
+    /// The hard limit of memory usage for a single batch task in MB. A task exceeding the limit
+    /// is failed with an error reported to the frontend, instead of risking a node-wide OOM.
+    /// 0 means no limit.
+    #[serde(default = "default::batch::task_memory_limit_mb")]
+    pub task_memory_limit_mb: usize,
+
     #[serde(default)]
     pub developer: DeveloperConfig,
 }
@@ -242,6 +313,12 @@ pub struct StreamingConfig {
     #[serde(default = "default::streaming::async_stack_trace")]
     pub async_stack_trace: AsyncStackTraceOption,
 
+    /// The hard limit of memory usage for a single actor in MB. Actors exceeding the limit are
+    /// suspended at chunk boundaries until their usage drops, backpressuring upstream instead of
+    /// risking a node-wide OOM. 0 means no limit.
+    #[serde(default = "default::streaming::actor_memory_hard_limit_mb")]
+    pub actor_memory_hard_limit_mb: usize,
+
     #[serde(default)]
     pub developer: DeveloperConfig,
 }
@@ -268,6 +345,11 @@ pub struct StorageConfig {
     #[serde(default = "default::storage::bloom_false_positive")]
     pub bloom_false_positive: f64,
 
+    /// Maximum size in KB of the zstd dictionary trained and embedded per SST. 0 disables
+    /// dictionary compression. Only effective for levels compressed with zstd.
+    #[serde(default = "default::storage::sstable_zstd_dict_size_kb")]
+    pub sstable_zstd_dict_size_kb: u32,
+
     /// parallelism while syncing share buffers into L0 SST. Should NOT be 0.
     #[serde(default = "default::storage::share_buffers_sync_parallelism")]
     pub share_buffers_sync_parallelism: u32,
@@ -294,6 +376,12 @@ pub struct StorageConfig {
     #[serde(default = "default::storage::write_conflict_detection_enabled")]
     pub write_conflict_detection_enabled: bool,
 
+    /// Whether to deduplicate writes to the same key within one write batch, keeping only the
+    /// last value. This cuts SST size and compaction work for high-churn keys at the cost of a
+    /// sort pass on ingestion.
+    #[serde(default = "default::storage::write_batch_dedup_enabled")]
+    pub write_batch_dedup_enabled: bool,
+
     /// Capacity of sstable block cache.
     #[serde(default = "default::storage::block_cache_capacity_mb")]
     pub block_cache_capacity_mb: usize,
@@ -302,6 +390,12 @@ pub struct StorageConfig {
     #[serde(default = "default::storage::meta_cache_capacity_mb")]
     pub meta_cache_capacity_mb: usize,
 
+    /// Budget in MB for preloading the meta of newly committed SSTs into the meta cache on each
+    /// version update, so first reads after a checkpoint don't pay cold meta fetch latency.
+    /// 0 disables preloading.
+    #[serde(default = "default::storage::sst_meta_preload_budget_mb")]
+    pub sst_meta_preload_budget_mb: usize,
+
     #[serde(default = "default::storage::disable_remote_compactor")]
     pub disable_remote_compactor: bool,
 
@@ -316,6 +410,11 @@ pub struct StorageConfig {
     #[serde(default = "default::storage::share_buffer_upload_concurrency")]
     pub share_buffer_upload_concurrency: usize,
 
+    /// Maximum bytes per second admitted to SST upload, which spreads the uploads of a synced
+    /// epoch over time instead of bursting them at once. 0 disables shaping.
+    #[serde(default = "default::storage::sst_upload_max_bytes_per_sec")]
+    pub sst_upload_max_bytes_per_sec: u64,
+
     /// Capacity of sstable meta cache.
     #[serde(default = "default::storage::compactor_memory_limit_mb")]
     pub compactor_memory_limit_mb: usize,
@@ -338,9 +437,18 @@ pub struct StorageConfig {
     #[serde(default = "default::storage::max_concurrent_compaction_task_number")]
     pub max_concurrent_compaction_task_number: u64,
 
-    /// Whether to enable state_store_v1 for hummock
-    #[serde(default = "default::storage::enable_state_store_v1")]
-    pub enable_state_store_v1: bool,
+    /// Interval of the background SST scrub in the compactor. 0 disables scrubbing.
+    #[serde(default = "default::storage::sst_scrub_interval_sec")]
+    pub sst_scrub_interval_sec: u64,
+
+    /// State table ids whose storage operations are sampled into the in-memory trace ring
+    /// buffer. Empty disables operation tracing.
+    #[serde(default = "default::storage::traced_table_ids")]
+    pub traced_table_ids: Vec<u32>,
+
+    /// Fraction of get/iter/write operations on traced tables to record, in `[0, 1]`.
+    #[serde(default = "default::storage::trace_sample_ratio")]
+    pub trace_sample_ratio: f64,
 }
 
 impl Default for StorageConfig {
@@ -372,6 +480,11 @@ pub struct FileCacheConfig {
 
     #[serde(default = "default::file_cache::cache_file_max_write_size_mb")]
     pub cache_file_max_write_size_mb: usize,
+
+    /// Whether to open the cache file with `O_DIRECT` to bypass the page cache. Falls back to
+    /// buffered I/O if the file system of the cache directory doesn't support `O_DIRECT`.
+    #[serde(default = "default::file_cache::enable_direct_io")]
+    pub enable_direct_io: bool,
 }
 
 impl Default for FileCacheConfig {
@@ -402,6 +515,20 @@ pub struct DeveloperConfig {
     #[serde(default = "default::developer::batch_chunk_size")]
     pub batch_chunk_size: usize,
 
+    /// Set to true to spill full batch exchange output channels to local disk instead of
+    /// backpressuring the producer, so that one lagging consumer does not stall the whole stage.
+    #[serde(default = "default::developer::batch_output_channel_spill_enabled")]
+    pub batch_output_channel_spill_enabled: bool,
+
+    /// The local directory holding batch exchange spill files. Files are removed when the task
+    /// ends.
+    #[serde(default = "default::developer::batch_output_channel_spill_dir")]
+    pub batch_output_channel_spill_dir: String,
+
+    /// The maximum number of bytes one spilled exchange output channel may occupy on disk.
+    #[serde(default = "default::developer::batch_output_channel_spill_max_bytes")]
+    pub batch_output_channel_spill_max_bytes: u64,
+
     /// Set to true to enable per-executor row count metrics. This will produce a lot of timeseries
     /// and might affect the prometheus performance. If you only need actor input and output
     /// rows data, see `stream_actor_in_record_cnt` and `stream_actor_out_record_cnt` instead.
@@ -421,6 +548,17 @@ pub struct DeveloperConfig {
     #[serde(default = "default::developer::stream_chunk_size")]
     pub stream_chunk_size: usize,
 
+    /// The target size in bytes of the chunk produced by executor at a time. For wide schemas
+    /// the effective chunk cardinality is scaled down from `stream_chunk_size` so that a full
+    /// chunk stays close to this budget.
+    #[serde(default = "default::developer::stream_chunk_target_bytes")]
+    pub stream_chunk_target_bytes: usize,
+
+    /// Per-fragment overrides of the chunk cardinality, keyed by fragment id. Overridden
+    /// fragments skip the byte-based scaling.
+    #[serde(default)]
+    pub stream_chunk_size_per_fragment: HashMap<u32, usize>,
+
     /// The initial permits that a channel holds, i.e., the maximum row count can be buffered in
     /// the channel.
     #[serde(default = "default::developer::stream_exchange_initial_permits")]
@@ -430,6 +568,22 @@ pub struct DeveloperConfig {
     /// in remote exchange.
     #[serde(default = "default::developer::stream_exchange_batched_permits")]
     pub stream_exchange_batched_permits: usize,
+
+    /// Whether to adaptively grow and shrink the permits of local exchange channels based on
+    /// the observed occupancy, instead of sticking to the configured initial permits.
+    #[serde(default)]
+    pub stream_exchange_adaptive_permits: bool,
+
+    /// Whether to spill the states of cold hash agg groups to the local temporary directory when
+    /// the memory manager signals eviction, instead of dropping them from the cache. Spilled
+    /// groups are reloaded transparently on the next access.
+    #[serde(default)]
+    pub stream_hash_agg_spill_enabled: bool,
+
+    /// The maximum number of bytes one hash agg spill file may occupy on disk. Once reached,
+    /// further cold groups are dropped from the cache like a plain eviction.
+    #[serde(default = "default::developer::stream_hash_agg_spill_max_bytes")]
+    pub stream_hash_agg_spill_max_bytes: u64,
 }
 
 impl Default for DeveloperConfig {
@@ -448,6 +602,16 @@ pub struct BackupConfig {
     /// Remote directory for storing snapshots.
     #[serde(default = "default::backup::storage_directory")]
     pub storage_directory: String,
+    /// Interval of automatic meta snapshot. 0 disables automatic snapshots and snapshot
+    /// retention, leaving snapshots fully manual.
+    #[serde(default = "default::backup::meta_snapshot_interval_sec")]
+    pub meta_snapshot_interval_sec: u64,
+    /// Number of the most recent hours to keep a meta snapshot for each.
+    #[serde(default = "default::backup::meta_snapshot_retention_hourly")]
+    pub meta_snapshot_retention_hourly: u64,
+    /// Number of the most recent days to keep a meta snapshot for each.
+    #[serde(default = "default::backup::meta_snapshot_retention_daily")]
+    pub meta_snapshot_retention_daily: u64,
 }
 
 impl Default for BackupConfig {
@@ -476,6 +640,14 @@ mod default {
             30
         }
 
+        pub fn branched_sst_reconciliation_interval_sec() -> u64 {
+            600
+        }
+
+        pub fn hummock_version_checkpoint_export_interval_sec() -> u64 {
+            0
+        }
+
         pub fn max_heartbeat_interval_sec() -> u32 {
             300
         }
@@ -495,6 +667,10 @@ mod default {
         pub fn periodic_space_reclaim_compaction_interval_sec() -> u64 {
             3600 // 60min
         }
+
+        pub fn recovery_build_parallelism() -> usize {
+            10
+        }
     }
 
     pub mod server {
@@ -514,6 +690,10 @@ mod default {
         pub fn metrics_level() -> u32 {
             0
         }
+
+        pub fn tls_reload_interval_secs() -> u64 {
+            600
+        }
     }
 
     pub mod storage {
@@ -526,6 +706,10 @@ mod default {
             64
         }
 
+        pub fn sstable_zstd_dict_size_kb() -> u32 {
+            0
+        }
+
         pub fn bloom_false_positive() -> f64 {
             0.001
         }
@@ -556,6 +740,10 @@ mod default {
             cfg!(debug_assertions)
         }
 
+        pub fn write_batch_dedup_enabled() -> bool {
+            false
+        }
+
         pub fn block_cache_capacity_mb() -> usize {
             512
         }
@@ -564,6 +752,10 @@ mod default {
             128
         }
 
+        pub fn sst_meta_preload_budget_mb() -> usize {
+            0
+        }
+
         pub fn disable_remote_compactor() -> bool {
             false
         }
@@ -580,6 +772,10 @@ mod default {
             8
         }
 
+        pub fn sst_upload_max_bytes_per_sec() -> u64 {
+            0
+        }
+
         pub fn compactor_memory_limit_mb() -> usize {
             512
         }
@@ -601,8 +797,26 @@ mod default {
             16
         }
 
-        pub fn enable_state_store_v1() -> bool {
-            false
+        pub fn sst_scrub_interval_sec() -> u64 {
+            0
+        }
+
+        pub fn traced_table_ids() -> Vec<u32> {
+            vec![]
+        }
+
+        pub fn trace_sample_ratio() -> f64 {
+            0.01
+        }
+    }
+
+    pub mod batch {
+        pub fn runtime_worker_threads_core_fraction() -> f64 {
+            0.5
+        }
+
+        pub fn task_memory_limit_mb() -> usize {
+            0
         }
     }
 
@@ -630,6 +844,10 @@ mod default {
         pub fn async_stack_trace() -> AsyncStackTraceOption {
             AsyncStackTraceOption::On
         }
+
+        pub fn actor_memory_hard_limit_mb() -> usize {
+            0
+        }
     }
 
     pub mod file_cache {
@@ -657,6 +875,10 @@ mod default {
         pub fn cache_file_max_write_size_mb() -> usize {
             4
         }
+
+        pub fn enable_direct_io() -> bool {
+            true
+        }
     }
 
     pub mod developer {
@@ -669,6 +891,18 @@ mod default {
             1024
         }
 
+        pub fn batch_output_channel_spill_enabled() -> bool {
+            false
+        }
+
+        pub fn batch_output_channel_spill_dir() -> String {
+            "/tmp/risingwave_batch_spill".to_string()
+        }
+
+        pub fn batch_output_channel_spill_max_bytes() -> u64 {
+            1 << 30
+        }
+
         pub fn stream_enable_executor_row_count() -> bool {
             false
         }
@@ -685,6 +919,10 @@ mod default {
             1024
         }
 
+        pub fn stream_chunk_target_bytes() -> usize {
+            1024 * 1024
+        }
+
         pub fn stream_exchange_initial_permits() -> usize {
             8192
         }
@@ -692,6 +930,10 @@ mod default {
         pub fn stream_exchange_batched_permits() -> usize {
             1024
         }
+
+        pub fn stream_hash_agg_spill_max_bytes() -> u64 {
+            1 << 30
+        }
     }
 
     pub mod backup {
@@ -702,5 +944,17 @@ mod default {
         pub fn storage_directory() -> String {
             "backup".to_string()
         }
+
+        pub fn meta_snapshot_interval_sec() -> u64 {
+            0
+        }
+
+        pub fn meta_snapshot_retention_hourly() -> u64 {
+            24
+        }
+
+        pub fn meta_snapshot_retention_daily() -> u64 {
+            7
+        }
     }
 }
@@ -33,3 +33,10 @@ pub mod hummock {
     pub const TABLE_OPTION_DUMMY_RETENTION_SECOND: u32 = 0;
     pub const PROPERTIES_RETENTION_SECOND_KEY: &str = "retention_seconds";
 }
+
+pub mod streaming {
+    /// Expected checkpoint latency SLO of a streaming job, e.g. `checkpoint_slo = '5s'`.
+    /// The meta barrier manager reports a violation event when the actual barrier completion
+    /// latency exceeds this value.
+    pub const PROPERTIES_CHECKPOINT_SLO_KEY: &str = "checkpoint_slo";
+}
@@ -12,12 +12,26 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use parking_lot::Mutex;
 use prometheus::HistogramVec;
 use tokio::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
 
+/// Accumulated wait statistics of one lock call site, i.e. one combination of label values.
+#[derive(Clone, Copy, Default)]
+pub struct RwLockWaitStats {
+    pub wait_count: u64,
+    pub total_wait: Duration,
+    pub max_wait: Duration,
+}
+
 pub struct MonitoredRwLock<T> {
     metrics: HistogramVec,
     inner: RwLock<T>,
+    /// Wait statistics per call site, keyed by the label values of the wait-time histogram.
+    stats: Mutex<HashMap<Vec<&'static str>, RwLockWaitStats>>,
 }
 
 impl<T> MonitoredRwLock<T> {
@@ -25,6 +39,7 @@ impl<T> MonitoredRwLock<T> {
         Self {
             metrics,
             inner: RwLock::new(val),
+            stats: Mutex::new(HashMap::new()),
         }
     }
 
@@ -33,7 +48,10 @@ impl<T> MonitoredRwLock<T> {
         label_values: &'b [&'static str],
     ) -> RwLockReadGuard<'a, T> {
         let _timer = self.metrics.with_label_values(label_values).start_timer();
-        self.inner.read().await
+        let start = Instant::now();
+        let guard = self.inner.read().await;
+        self.record_wait(label_values, start.elapsed());
+        guard
     }
 
     pub async fn write<'a, 'b>(
@@ -41,6 +59,26 @@ impl<T> MonitoredRwLock<T> {
         label_values: &'b [&'static str],
     ) -> RwLockWriteGuard<'a, T> {
         let _timer = self.metrics.with_label_values(label_values).start_timer();
-        self.inner.write().await
+        let start = Instant::now();
+        let guard = self.inner.write().await;
+        self.record_wait(label_values, start.elapsed());
+        guard
+    }
+
+    /// Returns the accumulated wait statistics of each call site since process startup.
+    pub fn collect_wait_stats(&self) -> Vec<(Vec<&'static str>, RwLockWaitStats)> {
+        self.stats
+            .lock()
+            .iter()
+            .map(|(label_values, stats)| (label_values.clone(), *stats))
+            .collect()
+    }
+
+    fn record_wait(&self, label_values: &[&'static str], wait: Duration) {
+        let mut stats = self.stats.lock();
+        let entry = stats.entry(label_values.to_vec()).or_default();
+        entry.wait_count += 1;
+        entry.total_wait += wait;
+        entry.max_wait = entry.max_wait.max(wait);
     }
 }
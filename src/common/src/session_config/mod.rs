@@ -18,6 +18,7 @@ mod transaction_isolation_level;
 mod visibility_mode;
 
 use std::ops::Deref;
+use std::time::Duration;
 
 use chrono_tz::Tz;
 use derivative::{self, Derivative};
@@ -32,7 +33,7 @@ use crate::util::epoch::Epoch;
 
 // This is a hack, &'static str is not allowed as a const generics argument.
 // TODO: refine this using the adt_const_params feature.
-const CONFIG_KEYS: [&str; 17] = [
+const CONFIG_KEYS: [&str; 24] = [
     "RW_IMPLICIT_FLUSH",
     "CREATE_COMPACTION_GROUP_FOR_MV",
     "QUERY_MODE",
@@ -50,6 +51,13 @@ const CONFIG_KEYS: [&str; 17] = [
     "STREAMING_PARALLELISM",
     "RW_STREAMING_ENABLE_DELTA_JOIN",
     "RW_ENABLE_TWO_PHASE_AGG",
+    "STATEMENT_TIMEOUT",
+    "MAX_SCAN_BYTES",
+    "MAX_RESULT_ROWS",
+    "RW_FORCE_TWO_PHASE_AGG",
+    "RW_STREAMING_ENABLE_MEMORY_ONLY_STATE",
+    "RW_BATCH_ENABLE_DISTRIBUTED_DML",
+    "RW_STREAMING_ENABLE_SINK_LOG_STORE",
 ];
 
 // MUST HAVE 1v1 relationship to CONFIG_KEYS. e.g. CONFIG_KEYS[IMPLICIT_FLUSH] =
@@ -71,6 +79,13 @@ const TIMEZONE: usize = 13;
 const STREAMING_PARALLELISM: usize = 14;
 const STREAMING_ENABLE_DELTA_JOIN: usize = 15;
 const ENABLE_TWO_PHASE_AGG: usize = 16;
+const STATEMENT_TIMEOUT: usize = 17;
+const MAX_SCAN_BYTES: usize = 18;
+const MAX_RESULT_ROWS: usize = 19;
+const FORCE_TWO_PHASE_AGG: usize = 20;
+const STREAMING_ENABLE_MEMORY_ONLY_STATE: usize = 21;
+const BATCH_ENABLE_DISTRIBUTED_DML: usize = 22;
+const STREAMING_ENABLE_SINK_LOG_STORE: usize = 23;
 
 trait ConfigEntry: Default + for<'a> TryFrom<&'a [&'a str], Error = RwError> {
     fn entry_name() -> &'static str;
@@ -268,6 +283,13 @@ type Timezone = ConfigString<TIMEZONE>;
 type StreamingParallelism = ConfigU64<STREAMING_PARALLELISM, 0>;
 type StreamingEnableDeltaJoin = ConfigBool<STREAMING_ENABLE_DELTA_JOIN, false>;
 type EnableTwoPhaseAgg = ConfigBool<ENABLE_TWO_PHASE_AGG, true>;
+type StatementTimeout = ConfigU64<STATEMENT_TIMEOUT, 0>;
+type MaxScanBytes = ConfigU64<MAX_SCAN_BYTES, 0>;
+type MaxResultRows = ConfigU64<MAX_RESULT_ROWS, 0>;
+type ForceTwoPhaseAgg = ConfigBool<FORCE_TWO_PHASE_AGG, false>;
+type StreamingEnableMemoryOnlyState = ConfigBool<STREAMING_ENABLE_MEMORY_ONLY_STATE, false>;
+type BatchEnableDistributedDml = ConfigBool<BATCH_ENABLE_DISTRIBUTED_DML, false>;
+type StreamingEnableSinkLogStore = ConfigBool<STREAMING_ENABLE_SINK_LOG_STORE, false>;
 
 #[derive(Derivative)]
 #[derivative(Default)]
@@ -329,6 +351,38 @@ pub struct ConfigMap {
 
     /// Enable two phase agg. Defaults to true.
     enable_two_phase_agg: EnableTwoPhaseAgg,
+
+    /// Abort any query that takes more than the specified amount of time in milliseconds. If 0,
+    /// queries never time out.
+    statement_timeout: StatementTimeout,
+
+    /// Abort any query that fetches more than the specified number of bytes from storage. If 0,
+    /// queries may fetch unlimited bytes.
+    max_scan_bytes: MaxScanBytes,
+
+    /// Abort any query that returns more than the specified number of rows. If 0, queries may
+    /// return unlimited rows.
+    max_result_rows: MaxResultRows,
+
+    /// Force the use of two phase agg whenever the aggregation is eligible for it, regardless of
+    /// the input distribution. Defaults to false. Takes precedence over
+    /// `RW_ENABLE_TWO_PHASE_AGG`.
+    force_two_phase_agg: ForceTwoPhaseAgg,
+
+    /// Keep tiny ephemeral operator states (e.g. the `now()` executor) in executor memory and
+    /// snapshot them into the meta store at barriers, instead of writing them to Hummock.
+    /// Defaults to false.
+    streaming_enable_memory_only_state: StreamingEnableMemoryOnlyState,
+
+    /// Run DML writes in parallel on the compute nodes instead of funneling all rows through a
+    /// single task, so that large `INSERT INTO ... SELECT` loads scale with the cluster size.
+    /// Defaults to false.
+    batch_enable_distributed_dml: BatchEnableDistributedDml,
+
+    /// Buffer the change stream of newly created sinks in an internal log store table, so that
+    /// the changes can be replayed to the sink target without re-running the upstream
+    /// computation. Defaults to false.
+    streaming_enable_sink_log_store: StreamingEnableSinkLogStore,
 }
 
 impl ConfigMap {
@@ -372,6 +426,20 @@ impl ConfigMap {
             self.streaming_enable_delta_join = val.as_slice().try_into()?;
         } else if key.eq_ignore_ascii_case(EnableTwoPhaseAgg::entry_name()) {
             self.enable_two_phase_agg = val.as_slice().try_into()?;
+        } else if key.eq_ignore_ascii_case(StatementTimeout::entry_name()) {
+            self.statement_timeout = val.as_slice().try_into()?;
+        } else if key.eq_ignore_ascii_case(MaxScanBytes::entry_name()) {
+            self.max_scan_bytes = val.as_slice().try_into()?;
+        } else if key.eq_ignore_ascii_case(MaxResultRows::entry_name()) {
+            self.max_result_rows = val.as_slice().try_into()?;
+        } else if key.eq_ignore_ascii_case(ForceTwoPhaseAgg::entry_name()) {
+            self.force_two_phase_agg = val.as_slice().try_into()?;
+        } else if key.eq_ignore_ascii_case(StreamingEnableMemoryOnlyState::entry_name()) {
+            self.streaming_enable_memory_only_state = val.as_slice().try_into()?;
+        } else if key.eq_ignore_ascii_case(BatchEnableDistributedDml::entry_name()) {
+            self.batch_enable_distributed_dml = val.as_slice().try_into()?;
+        } else if key.eq_ignore_ascii_case(StreamingEnableSinkLogStore::entry_name()) {
+            self.streaming_enable_sink_log_store = val.as_slice().try_into()?;
         } else {
             return Err(ErrorCode::UnrecognizedConfigurationParameter(key.to_string()).into());
         }
@@ -414,6 +482,20 @@ impl ConfigMap {
             Ok(self.streaming_enable_delta_join.to_string())
         } else if key.eq_ignore_ascii_case(EnableTwoPhaseAgg::entry_name()) {
             Ok(self.enable_two_phase_agg.to_string())
+        } else if key.eq_ignore_ascii_case(StatementTimeout::entry_name()) {
+            Ok(self.statement_timeout.to_string())
+        } else if key.eq_ignore_ascii_case(MaxScanBytes::entry_name()) {
+            Ok(self.max_scan_bytes.to_string())
+        } else if key.eq_ignore_ascii_case(MaxResultRows::entry_name()) {
+            Ok(self.max_result_rows.to_string())
+        } else if key.eq_ignore_ascii_case(ForceTwoPhaseAgg::entry_name()) {
+            Ok(self.force_two_phase_agg.to_string())
+        } else if key.eq_ignore_ascii_case(StreamingEnableMemoryOnlyState::entry_name()) {
+            Ok(self.streaming_enable_memory_only_state.to_string())
+        } else if key.eq_ignore_ascii_case(BatchEnableDistributedDml::entry_name()) {
+            Ok(self.batch_enable_distributed_dml.to_string())
+        } else if key.eq_ignore_ascii_case(StreamingEnableSinkLogStore::entry_name()) {
+            Ok(self.streaming_enable_sink_log_store.to_string())
         } else {
             Err(ErrorCode::UnrecognizedConfigurationParameter(key.to_string()).into())
         }
@@ -501,6 +583,41 @@ impl ConfigMap {
                 setting : self.enable_two_phase_agg.to_string(),
                 description: String::from("Enable two phase aggregation.")
             },
+            VariableInfo{
+                name : StatementTimeout::entry_name().to_lowercase(),
+                setting : self.statement_timeout.to_string(),
+                description: String::from("Aborts any query that takes more than the specified amount of time in milliseconds. If 0, queries never time out.")
+            },
+            VariableInfo{
+                name : MaxScanBytes::entry_name().to_lowercase(),
+                setting : self.max_scan_bytes.to_string(),
+                description: String::from("Aborts any query that fetches more than the specified number of bytes from storage. If 0, queries may fetch unlimited bytes.")
+            },
+            VariableInfo{
+                name : MaxResultRows::entry_name().to_lowercase(),
+                setting : self.max_result_rows.to_string(),
+                description: String::from("Aborts any query that returns more than the specified number of rows. If 0, queries may return unlimited rows.")
+            },
+            VariableInfo{
+                name : ForceTwoPhaseAgg::entry_name().to_lowercase(),
+                setting : self.force_two_phase_agg.to_string(),
+                description: String::from("Force two phase aggregation.")
+            },
+            VariableInfo{
+                name : StreamingEnableMemoryOnlyState::entry_name().to_lowercase(),
+                setting : self.streaming_enable_memory_only_state.to_string(),
+                description: String::from("Keep tiny ephemeral operator states in executor memory, snapshotted into the meta store at barriers instead of written to Hummock.")
+            },
+            VariableInfo{
+                name : BatchEnableDistributedDml::entry_name().to_lowercase(),
+                setting : self.batch_enable_distributed_dml.to_string(),
+                description: String::from("Run DML writes in parallel on the compute nodes instead of funneling all rows through a single task.")
+            },
+            VariableInfo{
+                name : StreamingEnableSinkLogStore::entry_name().to_lowercase(),
+                setting : self.streaming_enable_sink_log_store.to_string(),
+                description: String::from("Buffer the change stream of newly created sinks in an internal log store table.")
+            },
         ]
     }
 
@@ -577,4 +694,41 @@ impl ConfigMap {
     pub fn get_enable_two_phase_agg(&self) -> bool {
         *self.enable_two_phase_agg
     }
+
+    pub fn get_force_two_phase_agg(&self) -> bool {
+        *self.force_two_phase_agg
+    }
+
+    pub fn get_streaming_enable_memory_only_state(&self) -> bool {
+        *self.streaming_enable_memory_only_state
+    }
+
+    pub fn get_batch_enable_distributed_dml(&self) -> bool {
+        *self.batch_enable_distributed_dml
+    }
+
+    pub fn get_streaming_enable_sink_log_store(&self) -> bool {
+        *self.streaming_enable_sink_log_store
+    }
+
+    pub fn get_statement_timeout(&self) -> Option<Duration> {
+        if self.statement_timeout.0 != 0 {
+            return Some(Duration::from_millis(self.statement_timeout.0));
+        }
+        None
+    }
+
+    pub fn get_max_scan_bytes(&self) -> Option<u64> {
+        if self.max_scan_bytes.0 != 0 {
+            return Some(self.max_scan_bytes.0);
+        }
+        None
+    }
+
+    pub fn get_max_result_rows(&self) -> Option<u64> {
+        if self.max_result_rows.0 != 0 {
+            return Some(self.max_result_rows.0);
+        }
+        None
+    }
 }
@@ -66,8 +66,8 @@ use self::struct_type::StructType;
 use self::to_binary::ToBinary;
 use self::to_text::ToText;
 use crate::array::{
-    read_interval_unit, ArrayBuilderImpl, ListRef, ListValue, PrimitiveArrayItemType, StructRef,
-    StructValue,
+    read_interval_unit, ArrayBuilderImpl, JsonbRef, JsonbVal, ListRef, ListValue,
+    PrimitiveArrayItemType, StructRef, StructValue,
 };
 use crate::error::Result as RwResult;
 
@@ -128,6 +128,9 @@ pub enum DataType {
     #[display("bytea")]
     #[from_str(regex = "(?i)^bytea$")]
     Bytea,
+    #[display("jsonb")]
+    #[from_str(regex = "(?i)^jsonb$")]
+    Jsonb,
 }
 
 impl std::str::FromStr for Box<DataType> {
@@ -154,6 +157,7 @@ impl DataTypeName {
             | DataTypeName::Timestamptz
             | DataTypeName::Time
             | DataTypeName::Bytea
+            | DataTypeName::Jsonb
             | DataTypeName::Interval => true,
 
             DataTypeName::Struct | DataTypeName::List => false,
@@ -171,6 +175,7 @@ impl DataTypeName {
             DataTypeName::Float64 => DataType::Float64,
             DataTypeName::Varchar => DataType::Varchar,
             DataTypeName::Bytea => DataType::Bytea,
+            DataTypeName::Jsonb => DataType::Jsonb,
             DataTypeName::Date => DataType::Date,
             DataTypeName::Timestamp => DataType::Timestamp,
             DataTypeName::Timestamptz => DataType::Timestamptz,
@@ -214,6 +219,7 @@ impl From<&ProstDataType> for DataType {
             TypeName::Decimal => DataType::Decimal,
             TypeName::Interval => DataType::Interval,
             TypeName::Bytea => DataType::Bytea,
+            TypeName::Jsonb => DataType::Jsonb,
             TypeName::Struct => {
                 let fields: Vec<DataType> = proto.field_type.iter().map(|f| f.into()).collect_vec();
                 let field_names: Vec<String> = proto.field_names.iter().cloned().collect_vec();
@@ -270,6 +276,26 @@ impl DataType {
             )
             .into(),
             DataType::Bytea => BytesArrayBuilder::new(capacity).into(),
+            DataType::Jsonb => JsonbArrayBuilder::new(capacity).into(),
+        }
+    }
+
+    /// A rough estimate of the width of a value of this type in bytes, used e.g. to scale
+    /// chunk cardinality by row width. Variable-length types use a heuristic.
+    pub fn estimated_width(&self) -> usize {
+        match self {
+            DataType::Boolean => 1,
+            DataType::Int16 => 2,
+            DataType::Int32 | DataType::Float32 | DataType::Date => 4,
+            DataType::Int64
+            | DataType::Float64
+            | DataType::Time
+            | DataType::Timestamp
+            | DataType::Timestamptz => 8,
+            DataType::Decimal | DataType::Interval => 16,
+            DataType::Varchar | DataType::Bytea | DataType::Jsonb => 64,
+            DataType::Struct(t) => t.fields.iter().map(Self::estimated_width).sum(),
+            DataType::List { datatype } => datatype.estimated_width() * 16,
         }
     }
 
@@ -291,6 +317,7 @@ impl DataType {
             DataType::Struct { .. } => TypeName::Struct,
             DataType::List { .. } => TypeName::List,
             DataType::Bytea => TypeName::Bytea,
+            DataType::Jsonb => TypeName::Jsonb,
         }
     }
 
@@ -364,6 +391,7 @@ impl DataType {
             DataType::Boolean => ScalarImpl::Bool(false),
             DataType::Varchar => ScalarImpl::Utf8("".into()),
             DataType::Bytea => ScalarImpl::Bytea("".to_string().into_bytes().into()),
+            DataType::Jsonb => ScalarImpl::Jsonb(JsonbVal::null()),
             DataType::Date => ScalarImpl::NaiveDate(NaiveDateWrapper(NaiveDate::MIN)),
             DataType::Time => ScalarImpl::NaiveTime(NaiveTimeWrapper::from_hms_uncheck(0, 0, 0)),
             DataType::Timestamp => {
@@ -471,7 +499,8 @@ macro_rules! for_all_scalar_variants {
             { NaiveTime, naivetime, NaiveTimeWrapper, NaiveTimeWrapper },
             { Struct, struct, StructValue, StructRef<'scalar> },
             { List, list, ListValue, ListRef<'scalar> },
-            { Bytea, bytea, Box<[u8]>, &'scalar [u8] }
+            { Bytea, bytea, Box<[u8]>, &'scalar [u8] },
+            { Jsonb, jsonb, JsonbVal, JsonbRef<'scalar> }
         }
     };
 }
@@ -856,6 +885,7 @@ impl ScalarRefImpl<'_> {
             }
             Self::Struct(v) => v.memcmp_serialize(ser)?,
             Self::List(v) => v.memcmp_serialize(ser)?,
+            Self::Jsonb(v) => v.memcmp_serialize(ser)?,
         };
         Ok(())
     }
@@ -907,6 +937,7 @@ impl ScalarImpl {
             }),
             Ty::Struct(t) => StructValue::memcmp_deserialize(&t.fields, de)?.to_scalar_value(),
             Ty::List { datatype } => ListValue::memcmp_deserialize(datatype, de)?.to_scalar_value(),
+            Ty::Jsonb => Self::Jsonb(JsonbVal::memcmp_deserialize(de)?),
         })
     }
 
@@ -952,6 +983,7 @@ impl ScalarImpl {
                         .try_fold(0, |a, b| b.map(|b| a + b))?,
                     DataType::Varchar => deserializer.skip_bytes()?,
                     DataType::Bytea => deserializer.skip_bytes()?,
+                    DataType::Jsonb => deserializer.skip_bytes()?,
                 };
 
                 // consume offset of fixed_type
@@ -980,6 +1012,7 @@ pub fn literal_type_match(data_type: &DataType, literal: Option<&ScalarImpl>) ->
                     | (DataType::Float64, ScalarImpl::Float64(_))
                     | (DataType::Varchar, ScalarImpl::Utf8(_))
                     | (DataType::Bytea, ScalarImpl::Bytea(_))
+                    | (DataType::Jsonb, ScalarImpl::Jsonb(_))
                     | (DataType::Date, ScalarImpl::NaiveDate(_))
                     | (DataType::Time, ScalarImpl::NaiveTime(_))
                     | (DataType::Timestamp, ScalarImpl::NaiveDateTime(_))
@@ -1172,6 +1205,10 @@ mod tests {
                     ScalarImpl::Bytea("\\x233".as_bytes().into()),
                     DataType::Bytea,
                 ),
+                DataTypeName::Jsonb => (
+                    ScalarImpl::Jsonb(r#"{"a": 233}"#.parse().unwrap()),
+                    DataType::Jsonb,
+                ),
                 DataTypeName::Time => (
                     ScalarImpl::NaiveTime(NaiveTimeWrapper::from_hms_uncheck(2, 3, 3)),
                     DataType::Time,
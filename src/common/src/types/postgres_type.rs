@@ -30,6 +30,7 @@ impl DataType {
             DataType::Decimal
             | DataType::Varchar
             | DataType::Bytea
+            | DataType::Jsonb
             | DataType::Interval
             | DataType::Struct(_)
             | DataType::List { .. } => -1,
@@ -57,6 +58,7 @@ impl DataType {
             1114 => Ok(DataType::Timestamp),
             1184 => Ok(DataType::Timestamptz),
             1186 => Ok(DataType::Interval),
+            3802 => Ok(DataType::Jsonb),
             1000 => Ok(DataType::List {
                 datatype: Box::new(DataType::Boolean),
             }),
@@ -122,6 +124,7 @@ impl DataType {
             // will be considered as a varchar.
             DataType::Struct(_) => 1043,
             DataType::Bytea => 17,
+            DataType::Jsonb => 3802,
             DataType::List { datatype } => match unnested_list_type(datatype.as_ref().clone()) {
                 DataType::Boolean => 1000,
                 DataType::Int16 => 1005,
@@ -133,6 +136,7 @@ impl DataType {
                 DataType::Date => 1182,
                 DataType::Varchar => 1015,
                 DataType::Bytea => 1001,
+                DataType::Jsonb => 3807,
                 DataType::Time => 1183,
                 DataType::Timestamp => 1115,
                 DataType::Timestamptz => 1185,
@@ -109,6 +109,30 @@ impl OrderedRowSerde {
 
         Ok(len)
     }
+
+    /// Computes the byte offset past the end of each of the first `prefix_len` columns
+    /// serialized in `key`.
+    pub fn deserialize_prefix_column_ends(
+        &self,
+        key: &[u8],
+        prefix_len: usize,
+    ) -> memcomparable::Result<Vec<usize>> {
+        use crate::types::ScalarImpl;
+        let mut column_ends = Vec::with_capacity(prefix_len);
+        let mut len: usize = 0;
+        for index in 0..prefix_len {
+            let data_type = &self.schema[index];
+            let order_type = &self.order_types[index];
+            let data = &key[len..];
+            let mut deserializer = memcomparable::Deserializer::new(data);
+            deserializer.set_reverse(*order_type == OrderType::Descending);
+
+            len += ScalarImpl::encoding_data_size(data_type, &mut deserializer)?;
+            column_ends.push(len);
+        }
+
+        Ok(column_ends)
+    }
 }
 
 #[cfg(test)]
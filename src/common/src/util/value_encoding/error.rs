@@ -28,6 +28,8 @@ pub enum ValueEncodingError {
     InvalidNaiveTimeEncoding(u32, u32),
     #[error("Invalid null tag value encoding: {0}")]
     InvalidTagEncoding(u8),
+    #[error("Invalid jsonb value encoding")]
+    InvalidJsonbEncoding,
     #[error("Invalid struct encoding: {0}")]
     InvalidStructEncoding(crate::array::ArrayError),
     #[error("Invalid list encoding: {0}")]
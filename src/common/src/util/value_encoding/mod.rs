@@ -19,7 +19,7 @@ use bytes::{Buf, BufMut};
 use chrono::{Datelike, Timelike};
 use itertools::Itertools;
 
-use crate::array::{ListRef, ListValue, StructRef, StructValue};
+use crate::array::{JsonbVal, ListRef, ListValue, StructRef, StructValue};
 use crate::types::struct_type::StructType;
 use crate::types::{
     DataType, Datum, Decimal, IntervalUnit, NaiveDateTimeWrapper, NaiveDateWrapper,
@@ -73,6 +73,7 @@ fn serialize_scalar(value: ScalarRefImpl<'_>, buf: &mut impl BufMut) {
         ScalarRefImpl::Float64(v) => buf.put_f64_le(v.into_inner()),
         ScalarRefImpl::Utf8(v) => serialize_str(v.as_bytes(), buf),
         ScalarRefImpl::Bytea(v) => serialize_str(v, buf),
+        ScalarRefImpl::Jsonb(v) => serialize_str(v.to_string().as_bytes(), buf),
         ScalarRefImpl::Bool(v) => buf.put_u8(v as u8),
         ScalarRefImpl::Decimal(v) => serialize_decimal(&v, buf),
         ScalarRefImpl::Interval(v) => serialize_interval(&v, buf),
@@ -156,6 +157,7 @@ fn deserialize_value(ty: &DataType, data: &mut impl Buf) -> Result<ScalarImpl> {
         DataType::Date => ScalarImpl::NaiveDate(deserialize_naivedate(data)?),
         DataType::Struct(struct_def) => deserialize_struct(struct_def, data)?,
         DataType::Bytea => ScalarImpl::Bytea(deserialize_bytea(data).into()),
+        DataType::Jsonb => ScalarImpl::Jsonb(deserialize_jsonb(data)?),
         DataType::List {
             datatype: item_type,
         } => deserialize_list(item_type, data)?,
@@ -197,6 +199,14 @@ fn deserialize_bytea(data: &mut impl Buf) -> Vec<u8> {
     bytes
 }
 
+fn deserialize_jsonb(data: &mut impl Buf) -> Result<JsonbVal> {
+    let bytes = deserialize_bytea(data);
+    std::str::from_utf8(&bytes)
+        .map_err(|_| ValueEncodingError::InvalidJsonbEncoding)?
+        .parse()
+        .map_err(|_| ValueEncodingError::InvalidJsonbEncoding)
+}
+
 fn deserialize_bool(data: &mut impl Buf) -> Result<bool> {
     match data.get_u8() {
         1 => Ok(true),
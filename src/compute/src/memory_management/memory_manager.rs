@@ -91,8 +91,8 @@ impl GlobalMemoryManager {
     #[cfg(target_os = "linux")]
     pub async fn run(
         self: Arc<Self>,
-        _batch_mgr: Arc<BatchManager>,
-        _stream_mgr: Arc<LocalStreamManager>,
+        batch_mgr: Arc<BatchManager>,
+        stream_mgr: Arc<LocalStreamManager>,
     ) {
         use std::time::Duration;
 
@@ -183,9 +183,23 @@ impl GlobalMemoryManager {
             self.metrics
                 .jemalloc_allocated_bytes
                 .set(cur_total_bytes_used as i64);
-            self.metrics
-                .stream_total_mem_usage
-                .set(_stream_mgr.get_total_mem_val().get());
+            let stream_mem_usage = stream_mgr.get_total_mem_val().get();
+            self.metrics.stream_total_mem_usage.set(stream_mem_usage);
+
+            // Batch tasks are only allowed to use the part of the graceful threshold that
+            // streaming is not using, so the batch budget shrinks dynamically when the streaming
+            // memory pressure is high. Kill the most memory-hungry task when the budget is
+            // exceeded, instead of letting the node run towards an OOM.
+            let batch_mem_budget =
+                mem_threshold_graceful.saturating_sub(stream_mem_usage.max(0) as usize);
+            let batch_mem_usage = batch_mgr.total_mem_usage();
+            if batch_mem_usage > batch_mem_budget {
+                batch_mgr.kill_queries(&format!(
+                    "total batch memory usage {} bytes exceeded the memory budget of {} bytes \
+                     under streaming memory pressure",
+                    batch_mem_usage, batch_mem_budget
+                ));
+            }
 
             self.set_watermark_time_ms(watermark_time_ms);
         }
@@ -24,7 +24,9 @@ use risingwave_pb::task_service::exchange_service_server::ExchangeService;
 use risingwave_pb::task_service::{
     GetDataRequest, GetDataResponse, GetStreamRequest, GetStreamResponse,
 };
-use risingwave_stream::executor::exchange::permit::{MessageWithPermits, Permits, Receiver};
+use risingwave_stream::executor::exchange::permit::{
+    MessageWithPermits, PermitAdjuster, Permits, Receiver,
+};
 use risingwave_stream::executor::Message;
 use risingwave_stream::task::LocalStreamManager;
 use tokio_stream::wrappers::ReceiverStream;
@@ -144,6 +146,7 @@ impl ExchangeServiceImpl {
         let down_fragment_id = up_down_fragment_ids.1.to_string();
 
         let permits = receiver.permits();
+        let mut permit_adjuster = receiver.permit_adjuster();
 
         // Select from the permits back from the downstream and the upstream receiver.
         let select_stream = futures::stream::select(
@@ -186,6 +189,9 @@ impl ExchangeServiceImpl {
                     };
                     let bytes = Message::get_encoded_len(&response);
 
+                    // Adapt the channel capacity to the observed message sizes.
+                    permit_adjuster.observe(bytes, permits);
+
                     yield response;
 
                     metrics
@@ -17,8 +17,11 @@ use std::time::Duration;
 
 use risingwave_pb::monitor_service::monitor_service_server::MonitorService;
 use risingwave_pb::monitor_service::{
-    ProfilingRequest, ProfilingResponse, StackTraceRequest, StackTraceResponse,
+    DrainRequest, DrainResponse, ProfilingRequest, ProfilingResponse, StackTraceRequest,
+    StackTraceResponse, StorageTraceRecord, StorageTraceRequest, StorageTraceResponse,
 };
+use risingwave_rpc_client::MetaClient;
+use risingwave_storage::monitor::global_operation_tracer;
 use risingwave_stream::task::LocalStreamManager;
 use tonic::{Request, Response, Status};
 
@@ -26,16 +29,19 @@ use tonic::{Request, Response, Status};
 pub struct MonitorServiceImpl {
     stream_mgr: Arc<LocalStreamManager>,
     grpc_stack_trace_mgr: Option<GrpcStackTraceManagerRef>,
+    meta_client: MetaClient,
 }
 
 impl MonitorServiceImpl {
     pub fn new(
         stream_mgr: Arc<LocalStreamManager>,
         grpc_stack_trace_mgr: Option<GrpcStackTraceManagerRef>,
+        meta_client: MetaClient,
     ) -> Self {
         Self {
             stream_mgr,
             grpc_stack_trace_mgr,
+            meta_client,
         }
     }
 }
@@ -103,6 +109,45 @@ impl MonitorService for MonitorServiceImpl {
             }
         }
     }
+
+    #[cfg_attr(coverage, no_coverage)]
+    async fn storage_trace(
+        &self,
+        request: Request<StorageTraceRequest>,
+    ) -> Result<Response<StorageTraceResponse>, Status> {
+        let _req = request.into_inner();
+
+        let records = global_operation_tracer()
+            .collect()
+            .into_iter()
+            .map(|record| StorageTraceRecord {
+                table_id: record.table_id,
+                op: record.op.as_str().to_string(),
+                duration_us: record.duration_us,
+                key_prefix_hash: record.key_prefix_hash,
+                recorded_at_ms: record.recorded_at_ms,
+            })
+            .collect();
+
+        Ok(Response::new(StorageTraceResponse { records }))
+    }
+
+    #[cfg_attr(coverage, no_coverage)]
+    async fn drain(
+        &self,
+        request: Request<DrainRequest>,
+    ) -> Result<Response<DrainResponse>, Status> {
+        let _req = request.into_inner();
+
+        tracing::info!("draining compute node on request");
+        self.meta_client
+            .drain()
+            .await
+            .map_err(|e| Status::internal(format!("failed to drain compute node: {}", e)))?;
+        tracing::info!("compute node drained, safe to terminate");
+
+        Ok(Response::new(DrainResponse {}))
+    }
 }
 
 pub use grpc_middleware::*;
@@ -141,6 +141,9 @@ impl StreamService for StreamServiceImpl {
         let barrier =
             Barrier::from_protobuf(req.get_barrier().unwrap()).map_err(StreamError::from)?;
 
+        if !req.ephemeral_snapshots.is_empty() {
+            self.mgr.stash_ephemeral_restore(req.ephemeral_snapshots);
+        }
         self.mgr
             .send_barrier(&barrier, req.actor_ids_to_send, req.actor_ids_to_collect)?;
 
@@ -177,6 +180,11 @@ impl StreamService for StreamServiceImpl {
             request_id: req.request_id,
             status: None,
             create_mview_progress: collect_result.create_mview_progress,
+            agg_key_counts: collect_result.agg_key_counts,
+            sink_freshness: collect_result.sink_freshness,
+            backpressure_stats: collect_result.backpressure_stats,
+            collect_traces: collect_result.collect_traces,
+            ephemeral_snapshots: collect_result.ephemeral_snapshots,
             synced_sstables: synced_sstables
                 .into_iter()
                 .map(
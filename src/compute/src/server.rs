@@ -12,8 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::net::SocketAddr;
-use std::sync::Arc;
+use std::collections::HashSet;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::{Arc, RwLock};
 use std::time::Duration;
 
 use async_stack_trace::StackTraceManager;
@@ -43,7 +44,7 @@ use risingwave_source::dml_manager::DmlManager;
 use risingwave_storage::hummock::compactor::{CompactionExecutor, Compactor, CompactorContext};
 use risingwave_storage::hummock::hummock_meta_client::MonitoredHummockMetaClient;
 use risingwave_storage::hummock::{
-    HummockMemoryCollector, MemoryLimiter, TieredCacheMetricsBuilder,
+    HummockMemoryCollector, IoLimiter, MemoryLimiter, TieredCacheMetricsBuilder,
 };
 use risingwave_storage::monitor::{
     monitor_cache, CompactorMetrics, HummockMetrics, HummockStateStoreMetrics,
@@ -55,6 +56,8 @@ use risingwave_stream::executor::monitor::StreamingMetrics;
 use risingwave_stream::task::{LocalStreamManager, StreamEnvironment};
 use tokio::sync::oneshot::Sender;
 use tokio::task::JoinHandle;
+use tonic::service::Interceptor;
+use tonic::{Request, Status};
 
 use crate::memory_management::memory_manager::{
     GlobalMemoryManager, MIN_COMPUTE_MEMORY_MB, SYSTEM_RESERVED_MEMORY_MB,
@@ -69,6 +72,9 @@ use crate::rpc::service::monitor_service::{
 use crate::rpc::service::stream_service::StreamServiceImpl;
 use crate::ComputeNodeOpts;
 
+/// The interval for refreshing the allowlist of peer nodes from the meta service.
+const PEER_ALLOWLIST_REFRESH_INTERVAL: Duration = Duration::from_secs(30);
+
 /// Bootstraps the compute-node.
 pub async fn compute_node_serve(
     listen_addr: SocketAddr,
@@ -95,6 +101,9 @@ pub async fn compute_node_serve(
     );
     info!("> version: {} ({})", RW_VERSION, GIT_SHA);
 
+    // Initialize mutual TLS for node-to-node RPC connections, if enabled.
+    risingwave_rpc_client::tls::init_tls(&config.server.tls).unwrap();
+
     // Initialize all the configs
     let storage_opts = Arc::new(StorageOpts::from(&config));
     let stream_config = Arc::new(config.streaming.clone());
@@ -137,6 +146,17 @@ pub async fn compute_node_serve(
 
     let mut join_handle_vec = vec![];
 
+    let tracing = if config.streaming.enable_jaeger_tracing {
+        Arc::new(
+            risingwave_tracing::RwTracingService::new(risingwave_tracing::TracingConfig::new(
+                "127.0.0.1:6831".to_string(),
+            ))
+            .unwrap(),
+        )
+    } else {
+        Arc::new(risingwave_tracing::RwTracingService::disabled())
+    };
+
     let state_store = StateStoreImpl::new(
         &config.storage.state_store,
         storage_opts.clone(),
@@ -144,16 +164,7 @@ pub async fn compute_node_serve(
         state_store_metrics.clone(),
         object_store_metrics,
         TieredCacheMetricsBuilder::new(registry.clone()),
-        if config.streaming.enable_jaeger_tracing {
-            Arc::new(
-                risingwave_tracing::RwTracingService::new(risingwave_tracing::TracingConfig::new(
-                    "127.0.0.1:6831".to_string(),
-                ))
-                .unwrap(),
-            )
-        } else {
-            Arc::new(risingwave_tracing::RwTracingService::disabled())
-        },
+        tracing.clone(),
         storage_metrics.clone(),
         compactor_metrics.clone(),
     )
@@ -178,11 +189,13 @@ pub async fn compute_node_serve(
                 compaction_executor: Arc::new(CompactionExecutor::new(Some(1))),
                 filter_key_extractor_manager: storage.filter_key_extractor_manager().clone(),
                 read_memory_limiter,
+                io_limiter: IoLimiter::unlimited(),
                 sstable_id_manager: storage.sstable_id_manager().clone(),
                 task_progress_manager: Default::default(),
                 compactor_runtime_config: Arc::new(tokio::sync::Mutex::new(
                     CompactorRuntimeConfig {
                         max_concurrent_task_number: 1,
+                        max_io_bandwidth_bytes_per_sec: 0,
                     },
                 )),
             });
@@ -199,13 +212,6 @@ pub async fn compute_node_serve(
         monitor_cache(memory_collector, &registry).unwrap();
     }
 
-    sub_tasks.push(MetaClient::start_heartbeat_loop(
-        meta_client.clone(),
-        Duration::from_millis(config.server.heartbeat_interval_ms as u64),
-        Duration::from_secs(config.server.max_heartbeat_interval_secs as u64),
-        extra_info_sources,
-    ));
-
     let async_stack_trace_config = match &config.streaming.async_stack_trace {
         AsyncStackTraceOption::Off => None,
         c => Some(async_stack_trace::TraceConfig {
@@ -228,6 +234,15 @@ pub async fn compute_node_serve(
         async_stack_trace_config,
     ));
 
+    // Report per-actor resource usage to the meta service along with the heartbeat.
+    extra_info_sources.push(stream_mgr.clone());
+    sub_tasks.push(MetaClient::start_heartbeat_loop(
+        meta_client.clone(),
+        Duration::from_millis(config.server.heartbeat_interval_ms as u64),
+        Duration::from_secs(config.server.max_heartbeat_interval_secs as u64),
+        extra_info_sources,
+    ));
+
     // Spawn LRU Manager that have access to collect memory from batch mgr and stream mgr.
     let batch_mgr_clone = batch_mgr.clone();
     let stream_mgr_clone = stream_mgr.clone();
@@ -260,6 +275,7 @@ pub async fn compute_node_serve(
         client_pool,
         dml_mgr.clone(),
         source_metrics.clone(),
+        tracing,
     );
 
     let connector_params = risingwave_connector::ConnectorParams {
@@ -285,26 +301,49 @@ pub async fn compute_node_serve(
     #[cfg(any())]
     stream_mgr.clone().spawn_print_trace();
 
+    // When mutual TLS is enabled, exchange RPCs are additionally authenticated per connection
+    // against an allowlist of peer node identities fetched from the meta service. Control RPCs
+    // from the meta service are authenticated by mutual TLS alone, since meta nodes are not
+    // registered as workers.
+    let peer_checker = PeerNodeChecker::new(risingwave_rpc_client::tls::tls_enabled());
+    if peer_checker.enabled {
+        sub_tasks.push(peer_checker.start_refresh_task(meta_client.clone()));
+    }
+
     // Boot the runtime gRPC services.
     let batch_srv = BatchServiceImpl::new(batch_mgr.clone(), batch_env);
     let exchange_srv =
         ExchangeServiceImpl::new(batch_mgr.clone(), stream_mgr.clone(), exchange_srv_metrics);
     let stream_srv = StreamServiceImpl::new(stream_mgr.clone(), stream_env.clone());
-    let monitor_srv = MonitorServiceImpl::new(stream_mgr.clone(), grpc_stack_trace_mgr.clone());
+    let monitor_srv = MonitorServiceImpl::new(
+        stream_mgr.clone(),
+        grpc_stack_trace_mgr.clone(),
+        meta_client.clone(),
+    );
     let config_srv = ConfigServiceImpl::new(batch_mgr, stream_mgr);
     let health_srv = HealthServiceImpl::new();
 
     let (shutdown_send, mut shutdown_recv) = tokio::sync::oneshot::channel::<()>();
     let join_handle = tokio::spawn(async move {
-        tonic::transport::Server::builder()
+        let mut tonic_server = tonic::transport::Server::builder();
+        if let Some(tls) = risingwave_rpc_client::tls::server_tls_config() {
+            tonic_server = tonic_server.tls_config(tls).unwrap();
+        }
+        tonic_server
             .initial_connection_window_size(MAX_CONNECTION_WINDOW_SIZE)
             .initial_stream_window_size(STREAM_WINDOW_SIZE)
             .tcp_nodelay(true)
             .layer(StackTraceMiddlewareLayer::new_optional(
                 grpc_stack_trace_mgr,
             ))
-            .add_service(TaskServiceServer::new(batch_srv))
-            .add_service(ExchangeServiceServer::new(exchange_srv))
+            .add_service(TaskServiceServer::with_interceptor(
+                batch_srv,
+                peer_checker.clone(),
+            ))
+            .add_service(ExchangeServiceServer::with_interceptor(
+                exchange_srv,
+                peer_checker,
+            ))
             .add_service(StreamServiceServer::new(stream_srv))
             .add_service(MonitorServiceServer::new(monitor_srv))
             .add_service(ConfigServiceServer::new(config_srv))
@@ -344,6 +383,76 @@ pub async fn compute_node_serve(
     (join_handle_vec, shutdown_send)
 }
 
+/// Per-connection authentication of exchange RPC peers when mutual TLS is enabled: besides
+/// presenting a certificate signed by the trusted CA, a peer must also be one of the node
+/// identities currently registered to the meta service.
+#[derive(Clone)]
+struct PeerNodeChecker {
+    enabled: bool,
+    allowed_peers: Arc<RwLock<HashSet<IpAddr>>>,
+}
+
+impl PeerNodeChecker {
+    fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            allowed_peers: Arc::new(RwLock::new(HashSet::new())),
+        }
+    }
+
+    /// Starts a worker periodically refreshing the allowlist from the meta service.
+    fn start_refresh_task(&self, meta_client: MetaClient) -> (JoinHandle<()>, Sender<()>) {
+        let allowed_peers = self.allowed_peers.clone();
+        let (shutdown_tx, mut shutdown_rx) = tokio::sync::oneshot::channel();
+        let join_handle = tokio::spawn(async move {
+            let mut min_interval = tokio::time::interval(PEER_ALLOWLIST_REFRESH_INTERVAL);
+            loop {
+                tokio::select! {
+                    _ = min_interval.tick() => {}
+                    _ = &mut shutdown_rx => {
+                        tracing::info!("Peer allowlist refresh loop is stopped");
+                        return;
+                    }
+                }
+                match Self::list_peer_ips(&meta_client).await {
+                    Ok(peers) => *allowed_peers.write().unwrap() = peers,
+                    // Keep the previous allowlist on failure.
+                    Err(e) => tracing::warn!("Failed to refresh the peer allowlist: {}", e),
+                }
+            }
+        });
+        (join_handle, shutdown_tx)
+    }
+
+    /// The addresses of all nodes that may connect to the exchange service.
+    async fn list_peer_ips(meta_client: &MetaClient) -> anyhow::Result<HashSet<IpAddr>> {
+        let mut peers = HashSet::new();
+        for worker_type in [WorkerType::ComputeNode, WorkerType::Frontend] {
+            for worker in meta_client.list_all_nodes(worker_type, true).await? {
+                let addr: HostAddr = worker.get_host().unwrap().into();
+                for socket_addr in tokio::net::lookup_host(addr.to_string()).await? {
+                    peers.insert(socket_addr.ip());
+                }
+            }
+        }
+        Ok(peers)
+    }
+}
+
+impl Interceptor for PeerNodeChecker {
+    fn call(&mut self, request: Request<()>) -> std::result::Result<Request<()>, Status> {
+        if !self.enabled {
+            return Ok(request);
+        }
+        match request.remote_addr() {
+            Some(addr) if self.allowed_peers.read().unwrap().contains(&addr.ip()) => Ok(request),
+            _ => Err(Status::unauthenticated(
+                "peer is not a known node of this cluster",
+            )),
+        }
+    }
+}
+
 /// Check whether the compute node has enough memory to perform computing tasks. Apart from storage,
 /// it must reserve at least `MIN_COMPUTE_MEMORY_MB` for computing and `SYSTEM_RESERVED_MEMORY_MB`
 /// for other system usage. Otherwise, it is not allowed to start.
@@ -71,6 +71,16 @@ where
     }
 }
 
+pub(crate) fn deserialize_usize_from_string<'de, D>(deserializer: D) -> Result<usize, D::Error>
+where
+    D: de::Deserializer<'de>,
+{
+    let s: String = de::Deserialize::deserialize(deserializer)?;
+    s.parse().map_err(|_| {
+        de::Error::invalid_value(de::Unexpected::Str(&s), &"a non-negative integer")
+    })
+}
+
 pub(crate) fn deserialize_duration_from_string<'de, D>(
     deserializer: D,
 ) -> Result<Duration, D::Error>
@@ -14,7 +14,7 @@
 
 use anyhow::{anyhow, Result};
 use num_traits::FromPrimitive;
-use risingwave_common::array::{ListValue, StructValue};
+use risingwave_common::array::{JsonbVal, ListValue, StructValue};
 use risingwave_common::types::{DataType, Datum, Decimal, ScalarImpl};
 use risingwave_common::util::iter_util::ZipEqFast;
 use risingwave_expr::vector_op::cast::{
@@ -50,6 +50,12 @@ fn do_parse_simd_json_value(dtype: &DataType, v: &BorrowedValue<'_>) -> Result<S
             .into(),
         DataType::Varchar => ensure_str!(v, "varchar").to_string().into(),
         DataType::Bytea => ensure_str!(v, "bytea").to_string().into(),
+        // Keep the subtree as a `jsonb` value as-is, via the text representation.
+        DataType::Jsonb => ScalarImpl::Jsonb(
+            v.to_string()
+                .parse::<JsonbVal>()
+                .map_err(|e| anyhow!("expect jsonb: {}", e))?,
+        ),
         DataType::Date => str_to_date(ensure_str!(v, "date"))?.into(),
         DataType::Time => str_to_time(ensure_str!(v, "time"))?.into(),
         DataType::Timestamp => match v {
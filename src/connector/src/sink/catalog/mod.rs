@@ -99,6 +99,11 @@ pub struct SinkCatalog {
 }
 
 impl SinkCatalog {
+    /// Returns the SQL statement that can be used to create this sink.
+    pub fn create_sql(&self) -> String {
+        self.definition.clone()
+    }
+
     pub fn to_proto(&self) -> ProstSink {
         ProstSink {
             id: self.id.into(),
@@ -0,0 +1,406 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use anyhow::anyhow;
+use async_trait::async_trait;
+use reqwest::{Client, RequestBuilder, StatusCode};
+use risingwave_common::array::{Op, RowRef, StreamChunk};
+use risingwave_common::catalog::Schema;
+use risingwave_common::row::Row;
+use risingwave_common::types::to_text::ToText;
+use risingwave_common::types::DataType;
+use risingwave_common::util::iter_util::ZipEqFast;
+use serde_derive::Deserialize;
+use serde_json::{json, Value};
+
+use super::kafka::record_to_json;
+use super::{Result, Sink, SinkError};
+use crate::{deserialize_duration_from_string, deserialize_usize_from_string};
+
+pub const ELASTICSEARCH_SINK: &str = "elasticsearch";
+/// OpenSearch speaks the same bulk protocol, so it is served by the same sink.
+pub const OPENSEARCH_SINK: &str = "opensearch";
+
+const fn _default_bulk_max_entries() -> usize {
+    1024
+}
+
+const fn _default_max_retries() -> usize {
+    3
+}
+
+const fn _default_retry_backoff() -> Duration {
+    Duration::from_millis(100)
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ElasticSearchConfig {
+    /// Base url of the cluster, e.g. `http://localhost:9200`.
+    #[serde(rename = "elasticsearch.url")]
+    pub url: String,
+
+    /// Index to write documents to. If `elasticsearch.index.time_partition` is set, a suffix
+    /// formatted from the current wall clock time in strftime syntax is appended, e.g.
+    /// `%Y.%m.%d` yields `my_index-2023.01.18`.
+    #[serde(rename = "elasticsearch.index")]
+    pub index: String,
+
+    #[serde(rename = "elasticsearch.index.time_partition")]
+    pub time_partition: Option<String>,
+
+    #[serde(rename = "elasticsearch.username")]
+    pub username: Option<String>,
+
+    #[serde(rename = "elasticsearch.password")]
+    pub password: Option<String>,
+
+    /// Maximum number of actions in one bulk request.
+    #[serde(
+        rename = "elasticsearch.bulk.max_entries",
+        default = "_default_bulk_max_entries",
+        deserialize_with = "deserialize_usize_from_string"
+    )]
+    pub bulk_max_entries: usize,
+
+    #[serde(
+        rename = "elasticsearch.retry.max",
+        default = "_default_max_retries",
+        deserialize_with = "deserialize_usize_from_string"
+    )]
+    pub max_retry_num: usize,
+
+    #[serde(
+        rename = "elasticsearch.retry.interval",
+        default = "_default_retry_backoff",
+        deserialize_with = "deserialize_duration_from_string"
+    )]
+    pub retry_interval: Duration,
+}
+
+impl ElasticSearchConfig {
+    pub fn from_hashmap(values: HashMap<String, String>) -> Result<Self> {
+        let config =
+            serde_json::from_value::<ElasticSearchConfig>(serde_json::to_value(values).unwrap())
+                .map_err(|e| SinkError::Config(anyhow!(e)))?;
+        Ok(config)
+    }
+}
+
+pub struct ElasticSearchSink {
+    pub config: ElasticSearchConfig,
+    schema: Schema,
+    pk_indices: Vec<usize>,
+    client: Client,
+    /// Whether the index mapping has been put to the cluster. Mappings are created lazily on the
+    /// first write, once per time partition.
+    created_indexes: Vec<String>,
+}
+
+impl ElasticSearchSink {
+    pub fn new(
+        config: ElasticSearchConfig,
+        schema: Schema,
+        pk_indices: Vec<usize>,
+    ) -> Result<Self> {
+        if pk_indices.is_empty() {
+            return Err(SinkError::Config(anyhow!(
+                "ElasticSearch sink writes documents keyed by primary key, but the sink has none"
+            )));
+        }
+        Ok(Self {
+            config,
+            schema,
+            pk_indices,
+            client: Client::new(),
+            created_indexes: vec![],
+        })
+    }
+
+    /// The index documents are currently written to, i.e. the configured index name plus the
+    /// time partition suffix, if any.
+    fn current_index(&self) -> String {
+        match &self.config.time_partition {
+            Some(format) => format!(
+                "{}-{}",
+                self.config.index,
+                chrono::Utc::now().format(format)
+            ),
+            None => self.config.index.clone(),
+        }
+    }
+
+    /// The `_id` of the document for `row`, i.e. the primary key columns in text encoding joined
+    /// by `_`.
+    fn doc_id(&self, row: RowRef<'_>) -> String {
+        self.pk_indices
+            .iter()
+            .map(|idx| match row.datum_at(*idx) {
+                Some(scalar) => scalar.to_text(),
+                None => "null".to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join("_")
+    }
+
+    fn with_auth(&self, builder: RequestBuilder) -> RequestBuilder {
+        match &self.config.username {
+            Some(username) => builder.basic_auth(username, self.config.password.as_ref()),
+            None => builder,
+        }
+    }
+
+    /// Sends `request`, retrying with backoff as long as the cluster responds with `429 Too Many
+    /// Requests` or a transport error occurs.
+    async fn send_with_retry(&self, request: RequestBuilder) -> Result<reqwest::Response> {
+        let mut backoff = self.config.retry_interval;
+        for _ in 0..self.config.max_retry_num {
+            let request = request
+                .try_clone()
+                .expect("request body is never a stream");
+            match request.send().await {
+                Ok(response) if response.status() != StatusCode::TOO_MANY_REQUESTS => {
+                    return Ok(response)
+                }
+                Ok(_) => {
+                    tracing::warn!(
+                        "ElasticSearch cluster at {} is overloaded, retry in {:?}",
+                        self.config.url,
+                        backoff
+                    );
+                }
+                Err(e) => {
+                    tracing::warn!(
+                        "failed to send request to ElasticSearch cluster at {}: {}, retry in {:?}",
+                        self.config.url,
+                        e,
+                        backoff
+                    );
+                }
+            }
+            tokio::time::sleep(backoff).await;
+            backoff *= 2;
+        }
+        let response = request.send().await.map_err(|e| {
+            SinkError::ElasticSearch(format!("failed to send request: {}", e))
+        })?;
+        Ok(response)
+    }
+
+    /// Creates `index` with a mapping derived from the sink schema, unless it already exists.
+    async fn create_index(&mut self, index: &str) -> Result<()> {
+        if self.created_indexes.iter().any(|i| i == index) {
+            return Ok(());
+        }
+        let url = format!("{}/{}", self.config.url, index);
+        let request = self
+            .with_auth(self.client.put(&url))
+            .json(&schema_to_es_mapping(&self.schema));
+        let response = self.send_with_retry(request).await?;
+        // The index may have been created by a previous run or another parallelism.
+        if !response.status().is_success() {
+            let body: Value = response.json().await.unwrap_or_default();
+            if body["error"]["type"] != json!("resource_already_exists_exception") {
+                return Err(SinkError::ElasticSearch(format!(
+                    "failed to create index {}: {}",
+                    index, body
+                )));
+            }
+        }
+        self.created_indexes.push(index.to_string());
+        Ok(())
+    }
+
+    /// Sends one bulk request and checks the per-action results in the response.
+    async fn send_bulk(&self, lines: Vec<String>) -> Result<()> {
+        let url = format!("{}/_bulk", self.config.url);
+        let mut body = lines.join("\n");
+        body.push('\n');
+        let request = self
+            .with_auth(self.client.post(&url))
+            .header("Content-Type", "application/x-ndjson")
+            .body(body);
+        let response = self.send_with_retry(request).await?;
+        let status = response.status();
+        let body: Value = response
+            .json()
+            .await
+            .map_err(|e| SinkError::ElasticSearch(format!("invalid bulk response: {}", e)))?;
+        if !status.is_success() {
+            return Err(SinkError::ElasticSearch(format!(
+                "bulk request failed with status {}: {}",
+                status, body
+            )));
+        }
+        if body["errors"] == json!(true) {
+            // Report the first failed action. All actions are keyed by primary key, so simply
+            // retrying the chunk from the last checkpoint is safe.
+            let item_error = body["items"]
+                .as_array()
+                .and_then(|items| {
+                    items
+                        .iter()
+                        .filter_map(|item| item["index"]["error"].as_object())
+                        .next()
+                })
+                .map(|error| json!(error).to_string())
+                .unwrap_or_default();
+            return Err(SinkError::ElasticSearch(format!(
+                "bulk request partially failed: {}",
+                item_error
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Sink for ElasticSearchSink {
+    async fn write_batch(&mut self, chunk: StreamChunk) -> Result<()> {
+        let index = self.current_index();
+        self.create_index(&index).await?;
+
+        let mut lines = Vec::with_capacity(chunk.capacity() * 2);
+        for (op, row) in chunk.rows() {
+            let id = self.doc_id(row);
+            match op {
+                Op::Insert | Op::UpdateInsert => {
+                    lines.push(json!({ "index": { "_index": index, "_id": id } }).to_string());
+                    lines.push(
+                        Value::Object(record_to_json(row, self.schema.fields.clone())?)
+                            .to_string(),
+                    );
+                }
+                Op::Delete => {
+                    lines.push(json!({ "delete": { "_index": index, "_id": id } }).to_string());
+                }
+                // The deletion half of an update is subsumed by indexing the new document under
+                // the same id.
+                Op::UpdateDelete => {}
+            }
+            if lines.len() >= self.config.bulk_max_entries {
+                self.send_bulk(std::mem::take(&mut lines)).await?;
+            }
+        }
+        if !lines.is_empty() {
+            self.send_bulk(lines).await?;
+        }
+        Ok(())
+    }
+
+    async fn begin_epoch(&mut self, _epoch: u64) -> Result<()> {
+        Ok(())
+    }
+
+    async fn commit(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Maps a RisingWave data type to the ElasticSearch field type documents are mapped with.
+fn es_type(data_type: &DataType) -> Value {
+    match data_type {
+        DataType::Boolean => json!({ "type": "boolean" }),
+        DataType::Int16 => json!({ "type": "short" }),
+        DataType::Int32 => json!({ "type": "integer" }),
+        DataType::Int64 => json!({ "type": "long" }),
+        DataType::Float32 => json!({ "type": "float" }),
+        DataType::Float64 => json!({ "type": "double" }),
+        // Decimals are serialized in text form to avoid losing precision.
+        DataType::Decimal => json!({ "type": "keyword" }),
+        DataType::Varchar => json!({ "type": "text" }),
+        DataType::Date => json!({ "type": "date", "format": "yyyy-MM-dd" }),
+        DataType::Timestamp => {
+            json!({ "type": "date", "format": "yyyy-MM-dd HH:mm:ss.SSSSSS||yyyy-MM-dd HH:mm:ss" })
+        }
+        DataType::Timestamptz => json!({ "type": "date" }),
+        DataType::Struct(st) => {
+            let mut properties = serde_json::Map::with_capacity(st.fields.len());
+            for (data_type, name) in st.fields.iter().zip_eq_fast(st.field_names.iter()) {
+                properties.insert(name.clone(), es_type(data_type));
+            }
+            json!({ "properties": properties })
+        }
+        // ElasticSearch has no dedicated array type: any field may hold one or more values, so
+        // lists are mapped to the type of their elements.
+        DataType::List { datatype } => es_type(datatype),
+        // Everything else (time, interval, bytea, ...) is serialized in text form.
+        _ => json!({ "type": "keyword" }),
+    }
+}
+
+/// Builds the body of the index creation request, i.e. the field mappings derived from the sink
+/// schema.
+fn schema_to_es_mapping(schema: &Schema) -> Value {
+    let mut properties = serde_json::Map::with_capacity(schema.fields.len());
+    for field in &schema.fields {
+        properties.insert(field.name.clone(), es_type(&field.data_type));
+    }
+    json!({ "mappings": { "properties": properties } })
+}
+
+#[cfg(test)]
+mod test {
+    use maplit::hashmap;
+    use risingwave_common::catalog::Field;
+
+    use super::*;
+
+    #[test]
+    fn parse_elasticsearch_config() {
+        let properties: HashMap<String, String> = hashmap! {
+            "elasticsearch.url".to_string() => "http://localhost:9200".to_string(),
+            "elasticsearch.index".to_string() => "test".to_string(),
+            "elasticsearch.index.time_partition".to_string() => "%Y.%m.%d".to_string(),
+            "elasticsearch.bulk.max_entries".to_string() => "500".to_string(),
+            "elasticsearch.retry.max".to_string() => "5".to_string(),
+            "elasticsearch.retry.interval".to_string() => "1s".to_string(),
+        };
+
+        let config = ElasticSearchConfig::from_hashmap(properties).unwrap();
+        assert_eq!(config.url, "http://localhost:9200");
+        assert_eq!(config.index, "test");
+        assert_eq!(config.time_partition.as_deref(), Some("%Y.%m.%d"));
+        assert_eq!(config.bulk_max_entries, 500);
+        assert_eq!(config.max_retry_num, 5);
+        assert_eq!(config.retry_interval, Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_schema_to_es_mapping() {
+        let schema = Schema::new(vec![
+            Field::with_name(DataType::Int32, "id"),
+            Field::with_name(DataType::Varchar, "name"),
+            Field::with_name(DataType::Timestamp, "ts"),
+            Field::with_name(
+                DataType::List {
+                    datatype: Box::new(DataType::Int64),
+                },
+                "values",
+            ),
+        ]);
+        let mapping = schema_to_es_mapping(&schema);
+        let properties = &mapping["mappings"]["properties"];
+        assert_eq!(properties["id"], json!({ "type": "integer" }));
+        assert_eq!(properties["name"], json!({ "type": "text" }));
+        assert_eq!(properties["ts"]["type"], json!("date"));
+        assert_eq!(properties["values"], json!({ "type": "long" }));
+    }
+}
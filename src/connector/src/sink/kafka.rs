@@ -371,6 +371,9 @@ fn datum_to_json_object(field: &Field, datum: DatumRef<'_>) -> ArrayResult<Value
         ) => {
             json!(scalar.to_text_with_type(&dt))
         }
+        (DataType::Jsonb, ScalarRefImpl::Jsonb(jsonb_ref)) => {
+            json!(Value::from(jsonb_ref))
+        }
         (DataType::List { datatype }, ScalarRefImpl::List(list_ref)) => {
             let mut vec = Vec::with_capacity(list_ref.values_ref().len());
             let inner_field = Field::unnamed(Box::<DataType>::into_inner(datatype));
@@ -403,7 +406,7 @@ fn datum_to_json_object(field: &Field, datum: DatumRef<'_>) -> ArrayResult<Value
     Ok(value)
 }
 
-fn record_to_json(row: RowRef<'_>, schema: Vec<Field>) -> Result<Map<String, Value>> {
+pub fn record_to_json(row: RowRef<'_>, schema: Vec<Field>) -> Result<Map<String, Value>> {
     let mut mappings = Map::with_capacity(schema.len());
     for (field, datum_ref) in schema.iter().zip_eq_fast(row.iter()) {
         let key = field.name.clone();
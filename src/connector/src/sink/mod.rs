@@ -14,6 +14,7 @@
 
 pub mod catalog;
 pub mod console;
+pub mod elasticsearch;
 pub mod kafka;
 pub mod redis;
 pub mod remote;
@@ -32,6 +33,9 @@ use thiserror::Error;
 pub use tracing;
 
 use crate::sink::console::{ConsoleConfig, ConsoleSink, CONSOLE_SINK};
+use crate::sink::elasticsearch::{
+    ElasticSearchConfig, ElasticSearchSink, ELASTICSEARCH_SINK, OPENSEARCH_SINK,
+};
 use crate::sink::kafka::{KafkaConfig, KafkaSink, KAFKA_SINK};
 use crate::sink::redis::{RedisConfig, RedisSink};
 use crate::sink::remote::{RemoteConfig, RemoteSink};
@@ -59,6 +63,7 @@ pub enum SinkConfig {
     Kafka(Box<KafkaConfig>),
     Remote(RemoteConfig),
     Console(ConsoleConfig),
+    ElasticSearch(Box<ElasticSearchConfig>),
     BlackHole,
 }
 
@@ -68,11 +73,16 @@ pub enum SinkState {
     Redis,
     Console,
     Remote,
+    ElasticSearch,
     Blackhole,
 }
 
 pub const BLACKHOLE_SINK: &str = "blackhole";
 
+/// Sink properties prefixed with this define a secondary dead-letter-queue sink. Rows rejected
+/// by the primary sink target are routed there instead of failing the whole sink.
+pub const SINK_DLQ_PREFIX: &str = "dlq.";
+
 impl SinkConfig {
     pub fn from_hashmap(properties: HashMap<String, String>) -> Result<Self> {
         const SINK_TYPE_KEY: &str = "connector";
@@ -86,17 +96,41 @@ impl SinkConfig {
             CONSOLE_SINK => Ok(SinkConfig::Console(ConsoleConfig::from_hashmap(
                 properties,
             )?)),
+            ELASTICSEARCH_SINK | OPENSEARCH_SINK => Ok(SinkConfig::ElasticSearch(Box::new(
+                ElasticSearchConfig::from_hashmap(properties)?,
+            ))),
             BLACKHOLE_SINK => Ok(SinkConfig::BlackHole),
             _ => Ok(SinkConfig::Remote(RemoteConfig::from_hashmap(properties)?)),
         }
     }
 
+    /// Splits the properties prefixed with [`SINK_DLQ_PREFIX`] out of `properties`, returning
+    /// them with the prefix stripped, or `None` if no DLQ is configured.
+    pub fn split_dlq_properties(
+        properties: &mut HashMap<String, String>,
+    ) -> Option<HashMap<String, String>> {
+        let dlq_properties: HashMap<_, _> = properties
+            .iter()
+            .filter_map(|(k, v)| {
+                k.strip_prefix(SINK_DLQ_PREFIX)
+                    .map(|k| (k.to_string(), v.clone()))
+            })
+            .collect();
+        properties.retain(|k, _| !k.starts_with(SINK_DLQ_PREFIX));
+        if dlq_properties.is_empty() {
+            None
+        } else {
+            Some(dlq_properties)
+        }
+    }
+
     pub fn get_connector(&self) -> &'static str {
         match self {
             SinkConfig::Kafka(_) => "kafka",
             SinkConfig::Redis(_) => "redis",
             SinkConfig::Remote(_) => "remote",
             SinkConfig::Console(_) => "console",
+            SinkConfig::ElasticSearch(_) => "elasticsearch",
             SinkConfig::BlackHole => "blackhole",
         }
     }
@@ -108,6 +142,7 @@ pub enum SinkImpl {
     Kafka(Box<KafkaSink>),
     Remote(Box<RemoteSink>),
     Console(Box<ConsoleSink>),
+    ElasticSearch(Box<ElasticSearchSink>),
     Blackhole,
 }
 
@@ -124,6 +159,9 @@ impl SinkImpl {
                 SinkImpl::Kafka(Box::new(KafkaSink::new(*cfg, schema).await?))
             }
             SinkConfig::Console(cfg) => SinkImpl::Console(Box::new(ConsoleSink::new(cfg, schema)?)),
+            SinkConfig::ElasticSearch(cfg) => SinkImpl::ElasticSearch(Box::new(
+                ElasticSearchSink::new(*cfg, schema, pk_indices)?,
+            )),
             SinkConfig::Remote(cfg) => SinkImpl::Remote(Box::new(
                 RemoteSink::new(cfg, schema, pk_indices, connector_params).await?,
             )),
@@ -140,6 +178,7 @@ impl Sink for SinkImpl {
             SinkImpl::Kafka(sink) => sink.write_batch(chunk).await,
             SinkImpl::Remote(sink) => sink.write_batch(chunk).await,
             SinkImpl::Console(sink) => sink.write_batch(chunk).await,
+            SinkImpl::ElasticSearch(sink) => sink.write_batch(chunk).await,
             SinkImpl::Blackhole => Ok(()),
         }
     }
@@ -150,6 +189,7 @@ impl Sink for SinkImpl {
             SinkImpl::Kafka(sink) => sink.begin_epoch(epoch).await,
             SinkImpl::Remote(sink) => sink.begin_epoch(epoch).await,
             SinkImpl::Console(sink) => sink.begin_epoch(epoch).await,
+            SinkImpl::ElasticSearch(sink) => sink.begin_epoch(epoch).await,
             SinkImpl::Blackhole => Ok(()),
         }
     }
@@ -160,6 +200,7 @@ impl Sink for SinkImpl {
             SinkImpl::Kafka(sink) => sink.commit().await,
             SinkImpl::Remote(sink) => sink.commit().await,
             SinkImpl::Console(sink) => sink.commit().await,
+            SinkImpl::ElasticSearch(sink) => sink.commit().await,
             SinkImpl::Blackhole => Ok(()),
         }
     }
@@ -170,6 +211,7 @@ impl Sink for SinkImpl {
             SinkImpl::Kafka(sink) => sink.abort().await,
             SinkImpl::Remote(sink) => sink.abort().await,
             SinkImpl::Console(sink) => sink.abort().await,
+            SinkImpl::ElasticSearch(sink) => sink.abort().await,
             SinkImpl::Blackhole => Ok(()),
         }
     }
@@ -185,6 +227,8 @@ pub enum SinkError {
     Remote(String),
     #[error("Json parse error: {0}")]
     JsonParse(String),
+    #[error("ElasticSearch error: {0}")]
+    ElasticSearch(String),
     #[error("config error: {0}")]
     Config(#[from] anyhow::Error),
 }
@@ -14,12 +14,14 @@
 
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 
 use anyhow::{anyhow, Result};
 use async_trait::async_trait;
 use bytes::Bytes;
 use enum_as_inner::EnumAsInner;
 use futures::stream::BoxStream;
+use futures::StreamExt;
 use itertools::Itertools;
 use prost::Message;
 use risingwave_common::array::StreamChunk;
@@ -55,6 +57,9 @@ use crate::source::kinesis::enumerator::client::KinesisSplitEnumerator;
 use crate::source::kinesis::source::reader::KinesisSplitReader;
 use crate::source::kinesis::split::KinesisSplit;
 use crate::source::kinesis::{KinesisProperties, KINESIS_CONNECTOR};
+use crate::source::nats::enumerator::NatsSplitEnumerator;
+use crate::source::nats::source::NatsSplitReader;
+use crate::source::nats::{NatsProperties, NatsSplit, NATS_CONNECTOR};
 use crate::source::nexmark::source::reader::NexmarkSplitReader;
 use crate::source::nexmark::{
     NexmarkProperties, NexmarkSplit, NexmarkSplitEnumerator, NEXMARK_CONNECTOR,
@@ -147,6 +152,9 @@ pub trait SplitReaderV2: Sized {
 /// The max size of a chunk yielded by source stream.
 pub const MAX_CHUNK_SIZE: usize = 1024;
 
+/// The timeout for fetching the next batch of messages when sampling from a source.
+const SAMPLE_MESSAGE_TIMEOUT: Duration = Duration::from_secs(5);
+
 #[derive(Clone, Debug, Deserialize)]
 pub enum ConnectorProperties {
     Kafka(Box<KafkaProperties>),
@@ -158,6 +166,7 @@ pub enum ConnectorProperties {
     MySqlCdc(Box<CdcProperties>),
     PostgresCdc(Box<CdcProperties>),
     GooglePubsub(Box<PubsubProperties>),
+    Nats(Box<NatsProperties>),
     Dummy(Box<()>),
 }
 
@@ -196,6 +205,50 @@ impl ConnectorProperties {
             _ => {}
         }
     }
+
+    /// Sample at most `max_messages` raw messages from the external source, e.g. for inferring
+    /// the schema at `CREATE SOURCE` time. Currently only supported for the Kafka connector.
+    pub async fn sample_messages(self, max_messages: usize) -> Result<Vec<SourceMessage>> {
+        match self {
+            ConnectorProperties::Kafka(properties) => {
+                let mut properties = *properties;
+                // Let the reader terminate its stream by itself once it has yielded enough
+                // messages, instead of blocking on an idle partition forever.
+                properties.max_num_messages = Some(max_messages.to_string());
+
+                let mut enumerator = KafkaSplitEnumerator::new(properties.clone()).await?;
+                let splits = enumerator.list_splits().await?;
+
+                let mut messages = Vec::with_capacity(max_messages);
+                for split in splits {
+                    if messages.len() >= max_messages {
+                        break;
+                    }
+                    let reader = KafkaSplitReader::new(
+                        properties.clone(),
+                        vec![SplitImpl::Kafka(split)],
+                        ParserConfig::default(),
+                        Arc::new(SourceMetrics::unused()),
+                        SourceInfo::default(),
+                        None,
+                    )
+                    .await?;
+                    let mut stream = reader.into_data_stream();
+                    while let Ok(Some(batch)) =
+                        tokio::time::timeout(SAMPLE_MESSAGE_TIMEOUT, stream.next()).await
+                    {
+                        messages.extend(batch?);
+                        if messages.len() >= max_messages {
+                            break;
+                        }
+                    }
+                }
+                messages.truncate(max_messages);
+                Ok(messages)
+            }
+            _ => Err(anyhow!("sampling messages is not supported for this connector")),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, EnumAsInner, PartialEq, Hash)]
@@ -206,6 +259,7 @@ pub enum SplitImpl {
     Nexmark(NexmarkSplit),
     Datagen(DatagenSplit),
     GooglePubsub(PubsubSplit),
+    Nats(NatsSplit),
     MySqlCdc(CdcSplit),
     PostgresCdc(CdcSplit),
     S3(FsSplit),
@@ -240,6 +294,7 @@ pub enum SplitReaderV2Impl {
     MySqlCdc(Box<CdcSplitReader>),
     PostgresCdc(Box<CdcSplitReader>),
     GooglePubsub(Box<PubsubSplitReader>),
+    Nats(Box<NatsSplitReader>),
 }
 
 pub enum SplitEnumeratorImpl {
@@ -251,6 +306,7 @@ pub enum SplitEnumeratorImpl {
     MySqlCdc(DebeziumSplitEnumerator),
     PostgresCdc(DebeziumSplitEnumerator),
     GooglePubsub(PubsubSplitEnumerator),
+    Nats(NatsSplitEnumerator),
     S3(S3SplitEnumerator),
 }
 
@@ -263,7 +319,8 @@ impl_connector_properties! {
     { S3, S3_CONNECTOR },
     { MySqlCdc, MYSQL_CDC_CONNECTOR },
     { PostgresCdc, POSTGRES_CDC_CONNECTOR },
-    { GooglePubsub, GOOGLE_PUBSUB_CONNECTOR}
+    { GooglePubsub, GOOGLE_PUBSUB_CONNECTOR},
+    { Nats, NATS_CONNECTOR }
 }
 
 impl_split_enumerator! {
@@ -275,6 +332,7 @@ impl_split_enumerator! {
     { MySqlCdc, DebeziumSplitEnumerator },
     { PostgresCdc, DebeziumSplitEnumerator },
     { GooglePubsub, PubsubSplitEnumerator},
+    { Nats, NatsSplitEnumerator },
     { S3, S3SplitEnumerator }
 }
 
@@ -285,6 +343,7 @@ impl_split! {
     { Nexmark, NEXMARK_CONNECTOR, NexmarkSplit },
     { Datagen, DATAGEN_CONNECTOR, DatagenSplit },
     { GooglePubsub, GOOGLE_PUBSUB_CONNECTOR, PubsubSplit },
+    { Nats, NATS_CONNECTOR, NatsSplit },
     { MySqlCdc, MYSQL_CDC_CONNECTOR, CdcSplit },
     { PostgresCdc, POSTGRES_CDC_CONNECTOR, CdcSplit },
     { S3, S3_CONNECTOR, FsSplit }
@@ -300,6 +359,7 @@ impl_split_reader! {
     { MySqlCdc, CdcSplitReader},
     { PostgresCdc, CdcSplitReader},
     { GooglePubsub, PubsubSplitReader },
+    { Nats, NatsSplitReader },
     { Dummy, DummySplitReader }
 }
 
@@ -22,12 +22,14 @@ pub mod google_pubsub;
 pub mod kafka;
 pub mod kinesis;
 pub mod monitor;
+pub mod nats;
 pub mod nexmark;
 pub mod pulsar;
 pub use base::*;
 pub use google_pubsub::GOOGLE_PUBSUB_CONNECTOR;
 pub use kafka::KAFKA_CONNECTOR;
 pub use kinesis::KINESIS_CONNECTOR;
+pub use nats::NATS_CONNECTOR;
 mod manager;
 pub use manager::SourceColumnDesc;
 
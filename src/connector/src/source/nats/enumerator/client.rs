@@ -0,0 +1,65 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use anyhow::{anyhow, bail};
+use async_trait::async_trait;
+
+use crate::source::base::SplitEnumerator;
+use crate::source::nats::split::NatsSplit;
+use crate::source::nats::NatsProperties;
+
+pub struct NatsSplitEnumerator {
+    stream: String,
+    subjects: Vec<String>,
+}
+
+#[async_trait]
+impl SplitEnumerator for NatsSplitEnumerator {
+    type Properties = NatsProperties;
+    type Split = NatsSplit;
+
+    async fn new(properties: Self::Properties) -> anyhow::Result<NatsSplitEnumerator> {
+        let subjects = properties.subjects();
+        if subjects.is_empty() {
+            bail!("`nats.subjects` must contain at least one subject")
+        }
+
+        // Validate config
+        let jetstream = properties.build_jetstream_context().await?;
+        jetstream
+            .get_stream(&properties.stream)
+            .await
+            .map_err(|e| anyhow!("failed to get NATS stream {}: {:?}", properties.stream, e))?;
+
+        Ok(Self {
+            stream: properties.stream,
+            subjects,
+        })
+    }
+
+    async fn list_splits(&mut self) -> anyhow::Result<Vec<NatsSplit>> {
+        tracing::debug!("enumerating nats splits");
+        let splits = self
+            .subjects
+            .iter()
+            .map(|subject| NatsSplit {
+                stream: self.stream.clone(),
+                subject: subject.clone(),
+                start_sequence: None,
+            })
+            .collect();
+
+        Ok(splits)
+    }
+}
@@ -0,0 +1,161 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod enumerator;
+pub mod source;
+pub mod split;
+
+use anyhow::anyhow;
+use async_nats::jetstream::consumer::pull::Config;
+use async_nats::jetstream::consumer::{AckPolicy, Consumer, DeliverPolicy};
+pub use enumerator::*;
+use serde::{Deserialize, Serialize};
+pub use source::*;
+pub use split::*;
+
+pub const NATS_CONNECTOR: &str = "nats";
+
+fn default_durable_consumer_prefix() -> String {
+    "risingwave".to_string()
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Hash)]
+pub struct NatsProperties {
+    /// Address of the NATS server, e.g. `nats://localhost:4222`. Multiple addresses can be
+    /// separated by commas.
+    #[serde(rename = "nats.server_url")]
+    pub server_url: String,
+
+    /// Name of the JetStream stream to consume from.
+    #[serde(rename = "nats.stream")]
+    pub stream: String,
+
+    /// Comma-separated list of subjects to consume. Each subject forms one split, so subjects
+    /// can be consumed by different actors in parallel.
+    #[serde(rename = "nats.subjects")]
+    pub subjects: String,
+
+    /// Prefix of the durable consumer names created on the stream, one per subject. The server
+    /// tracks the ack floor of a durable consumer, so consumption resumes from the last
+    /// acknowledged message even if no offset has been persisted in the source state yet.
+    #[serde(
+        rename = "nats.durable_consumer_prefix",
+        default = "default_durable_consumer_prefix"
+    )]
+    pub durable_consumer_prefix: String,
+
+    #[serde(rename = "nats.user")]
+    pub user: Option<String>,
+
+    #[serde(rename = "nats.password")]
+    pub password: Option<String>,
+}
+
+impl NatsProperties {
+    /// The configured subjects, one per split.
+    pub(crate) fn subjects(&self) -> Vec<String> {
+        self.subjects
+            .split(',')
+            .map(|subject| subject.trim().to_string())
+            .filter(|subject| !subject.is_empty())
+            .collect()
+    }
+
+    /// The name of the durable consumer for `subject`. Subject tokens like `.`, `*` and `>` are
+    /// not allowed in consumer names, so they are replaced with `-`.
+    pub(crate) fn durable_consumer_name(&self, subject: &str) -> String {
+        format!(
+            "{}-{}",
+            self.durable_consumer_prefix,
+            subject.replace(['.', '*', '>'], "-")
+        )
+    }
+
+    pub(crate) async fn build_jetstream_context(
+        &self,
+    ) -> anyhow::Result<async_nats::jetstream::Context> {
+        let mut options = async_nats::ConnectOptions::new();
+        if let (Some(user), Some(password)) = (&self.user, &self.password) {
+            options = options.user_and_password(user.clone(), password.clone());
+        }
+        let client = options
+            .connect(&self.server_url)
+            .await
+            .map_err(|e| anyhow!("failed to connect to NATS server {}: {:?}", self.server_url, e))?;
+        Ok(async_nats::jetstream::new(client))
+    }
+
+    /// Gets or creates the durable consumer for `split`.
+    pub(crate) async fn build_consumer(&self, split: &NatsSplit) -> anyhow::Result<Consumer<Config>> {
+        let jetstream = self.build_jetstream_context().await?;
+        let stream = jetstream
+            .get_stream(&self.stream)
+            .await
+            .map_err(|e| anyhow!("failed to get NATS stream {}: {:?}", self.stream, e))?;
+
+        let deliver_policy = match split.start_sequence {
+            Some(start_sequence) => DeliverPolicy::ByStartSequence { start_sequence },
+            // No offset has been persisted in the source state yet. The durable consumer
+            // resumes from its ack floor, or from the beginning of the stream if it is newly
+            // created.
+            None => DeliverPolicy::All,
+        };
+        let durable_name = self.durable_consumer_name(&split.subject);
+        stream
+            .get_or_create_consumer(
+                &durable_name,
+                Config {
+                    durable_name: Some(durable_name.clone()),
+                    filter_subject: split.subject.clone(),
+                    deliver_policy,
+                    ack_policy: AckPolicy::Explicit,
+                    ..Default::default()
+                },
+            )
+            .await
+            .map_err(|e| anyhow!("failed to get or create NATS consumer {}: {:?}", durable_name, e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_properties() -> NatsProperties {
+        NatsProperties {
+            server_url: "nats://localhost:4222".to_string(),
+            stream: "test-stream".to_string(),
+            subjects: "events.orders, events.users,".to_string(),
+            durable_consumer_prefix: default_durable_consumer_prefix(),
+            user: None,
+            password: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_subjects() {
+        assert_eq!(
+            test_properties().subjects(),
+            vec!["events.orders".to_string(), "events.users".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_durable_consumer_name() {
+        assert_eq!(
+            test_properties().durable_consumer_name("events.orders.*"),
+            "risingwave-events-orders--"
+        );
+    }
+}
@@ -0,0 +1,42 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_nats::jetstream::Message;
+
+use crate::source::{SourceMessage, SourceMeta, SplitId};
+
+/// A message fetched from a NATS JetStream consumer, tagged with the split it belongs to and its
+/// sequence in the stream, which serves as the offset persisted in the source state.
+pub(crate) struct NatsMessage {
+    pub(crate) split_id: SplitId,
+    pub(crate) sequence: u64,
+    pub(crate) message: Message,
+}
+
+impl From<NatsMessage> for SourceMessage {
+    fn from(message: NatsMessage) -> Self {
+        Self {
+            payload: {
+                let payload = &message.message.payload;
+                match payload.len() {
+                    0 => None,
+                    _ => Some(payload.clone()),
+                }
+            },
+            offset: message.sequence.to_string(),
+            split_id: message.split_id,
+            meta: SourceMeta::Empty,
+        }
+    }
+}
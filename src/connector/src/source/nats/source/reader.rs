@@ -0,0 +1,126 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{anyhow, ensure, Context, Result};
+use async_nats::jetstream::consumer::pull::Config;
+use async_nats::jetstream::consumer::Consumer;
+use async_trait::async_trait;
+use futures::{StreamExt, TryStreamExt};
+use futures_async_stream::try_stream;
+
+use super::NatsMessage;
+use crate::impl_common_split_reader_logic;
+use crate::parser::ParserConfig;
+use crate::source::monitor::SourceMetrics;
+use crate::source::nats::NatsProperties;
+use crate::source::{
+    BoxSourceWithStateStream, Column, SourceInfo, SourceMessage, SplitId, SplitImpl, SplitMetaData,
+    SplitReaderV2,
+};
+
+const NATS_MAX_FETCH_MESSAGES: usize = 1024;
+
+impl_common_split_reader_logic!(NatsSplitReader, NatsProperties);
+
+pub struct NatsSplitReader {
+    consumer: Consumer<Config>,
+
+    split_id: SplitId,
+    parser_config: ParserConfig,
+    metrics: Arc<SourceMetrics>,
+    source_info: SourceInfo,
+}
+
+impl NatsSplitReader {
+    #[try_stream(boxed, ok = Vec<SourceMessage>, error = anyhow::Error)]
+    async fn into_data_stream(self) {
+        loop {
+            let mut batch = self
+                .consumer
+                .fetch()
+                .max_messages(NATS_MAX_FETCH_MESSAGES)
+                .messages()
+                .await
+                .map_err(|e| anyhow!("failed to fetch messages from NATS: {:?}", e))?;
+
+            let mut chunk: Vec<SourceMessage> = Vec::new();
+            while let Some(message) = batch.next().await {
+                let message =
+                    message.map_err(|e| anyhow!("failed to fetch message from NATS: {:?}", e))?;
+                let sequence = message
+                    .info()
+                    .map_err(|e| anyhow!("failed to get message info: {:?}", e))?
+                    .stream_sequence;
+                // Ack to advance the ack floor of the durable consumer, so consumption resumes
+                // from here even if no offset has been persisted in the source state yet.
+                message
+                    .ack()
+                    .await
+                    .map_err(|e| anyhow!(e))
+                    .context("failed to ack nats message")?;
+                chunk.push(SourceMessage::from(NatsMessage {
+                    split_id: self.split_id.clone(),
+                    sequence,
+                    message,
+                }));
+            }
+
+            // `fetch` returns an empty batch immediately when no message is pending, so sleep a
+            // while to avoid busy polling the server.
+            if chunk.is_empty() {
+                tokio::time::sleep(Duration::from_millis(100)).await;
+                continue;
+            }
+
+            yield chunk;
+        }
+    }
+}
+
+#[async_trait]
+impl SplitReaderV2 for NatsSplitReader {
+    type Properties = NatsProperties;
+
+    async fn new(
+        properties: NatsProperties,
+        splits: Vec<SplitImpl>,
+        parser_config: ParserConfig,
+        metrics: Arc<SourceMetrics>,
+        source_info: SourceInfo,
+        _columns: Option<Vec<Column>>,
+    ) -> Result<Self> {
+        ensure!(
+            splits.len() == 1,
+            "the nats reader only supports a single split"
+        );
+        let split = splits.into_iter().next().unwrap().into_nats().unwrap();
+
+        let consumer = properties.build_consumer(&split).await?;
+
+        Ok(Self {
+            consumer,
+            split_id: split.id(),
+            parser_config,
+            metrics,
+            source_info,
+        })
+    }
+
+    fn into_stream(self) -> BoxSourceWithStateStream {
+        self.into_chunk_stream()
+    }
+}
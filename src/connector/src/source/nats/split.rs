@@ -0,0 +1,57 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use anyhow::anyhow;
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+
+use crate::source::{SplitId, SplitMetaData};
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Hash)]
+pub struct NatsSplit {
+    pub(crate) stream: String,
+    pub(crate) subject: String,
+
+    /// The stream sequence to start delivering from, which is one past the sequence of the last
+    /// acknowledged message persisted in the source state. When `None`, delivery starts from the
+    /// ack floor of the durable consumer instead.
+    pub(crate) start_sequence: Option<u64>,
+}
+
+impl NatsSplit {
+    pub fn copy_with_offset(&self, start_offset: String) -> Self {
+        // `start_offset` is the stream sequence of the last message yielded by the reader, so
+        // delivery resumes from the next sequence.
+        let start_sequence = start_offset.parse::<u64>().ok().map(|seq| seq + 1);
+        Self {
+            stream: self.stream.clone(),
+            subject: self.subject.clone(),
+            start_sequence,
+        }
+    }
+}
+
+impl SplitMetaData for NatsSplit {
+    fn encode_to_bytes(&self) -> Bytes {
+        Bytes::from(serde_json::to_string(self).unwrap())
+    }
+
+    fn restore_from_bytes(bytes: &[u8]) -> anyhow::Result<Self> {
+        serde_json::from_slice(bytes).map_err(|e| anyhow!(e))
+    }
+
+    fn id(&self) -> SplitId {
+        format!("{}-{}", self.stream, self.subject).into()
+    }
+}
@@ -0,0 +1,47 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::CtlContext;
+
+pub async fn trace(context: &CtlContext, prev_epoch: u64) -> anyhow::Result<()> {
+    let meta_client = context.meta_client().await?;
+
+    let trace = meta_client.get_barrier_trace(prev_epoch).await?;
+    let inject_at_ms = trace.inject_at_ms;
+
+    println!("barrier prev epoch: {}", trace.prev_epoch);
+    println!("injected at: {} (unix ms)", inject_at_ms);
+    if trace.complete_at_ms != 0 {
+        println!(
+            "completed at: {} (+{} ms)",
+            trace.complete_at_ms,
+            trace.complete_at_ms.saturating_sub(inject_at_ms)
+        );
+    } else {
+        println!("completed at: still in-flight");
+    }
+
+    println!("per-fragment collect times, relative to injection:");
+    for fragment in trace.fragments {
+        println!(
+            "  fragment {:>5}: first +{} ms, last +{} ms ({} actors)",
+            fragment.fragment_id,
+            fragment.first_collect_at_ms.saturating_sub(inject_at_ms),
+            fragment.last_collect_at_ms.saturating_sub(inject_at_ms),
+            fragment.actor_count
+        );
+    }
+
+    Ok(())
+}
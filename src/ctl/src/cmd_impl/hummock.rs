@@ -18,14 +18,22 @@ mod list_kv;
 pub use list_kv::*;
 mod sst_dump;
 pub use sst_dump::*;
+mod compact_table;
 mod compaction_group;
+mod diff_versions;
 mod disable_commit_epoch;
 mod list_version_deltas;
+mod reset_compactor_blacklist;
 mod trigger_full_gc;
 mod trigger_manual_compaction;
+mod truncate_above_epoch;
 
+pub use compact_table::*;
 pub use compaction_group::*;
+pub use diff_versions::*;
 pub use disable_commit_epoch::*;
 pub use list_version_deltas::*;
+pub use reset_compactor_blacklist::*;
 pub use trigger_full_gc::*;
 pub use trigger_manual_compaction::*;
+pub use truncate_above_epoch::*;
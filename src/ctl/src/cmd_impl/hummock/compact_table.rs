@@ -0,0 +1,29 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::CtlContext;
+
+pub async fn compact_table(
+    context: &CtlContext,
+    table_id: u32,
+    start_epoch: u64,
+    end_epoch: u64,
+) -> anyhow::Result<()> {
+    let meta_client = context.meta_client().await?;
+    let result = meta_client
+        .compact_table(table_id, start_epoch, end_epoch)
+        .await;
+    println!("{:#?}", result);
+    Ok(())
+}
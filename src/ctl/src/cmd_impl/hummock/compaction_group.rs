@@ -12,8 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use anyhow::Context;
+use comfy_table::Table;
 use risingwave_hummock_sdk::CompactionGroupId;
 use risingwave_pb::hummock::rise_ctl_update_compaction_config_request::mutable_config::MutableConfig;
+use risingwave_pb::hummock::RiseCtlDescribeCompactionGroupResponse;
+use risingwave_rpc_client::HummockMetaClient;
 
 use crate::CtlContext;
 
@@ -24,6 +28,57 @@ pub async fn list_compaction_group(context: &CtlContext) -> anyhow::Result<()> {
     Ok(())
 }
 
+pub async fn describe_compaction_group(
+    context: &CtlContext,
+    group_id: CompactionGroupId,
+) -> anyhow::Result<()> {
+    let meta_client = context.meta_client().await?;
+    let RiseCtlDescribeCompactionGroupResponse {
+        compaction_group,
+        pending_compact_task_num,
+        ..
+    } = meta_client.risectl_describe_compaction_group(group_id).await?;
+    let compaction_group = compaction_group.context("compaction group not found")?;
+    let version = meta_client.get_current_version().await?;
+
+    println!("Compaction group {}", group_id);
+    println!("Member tables: {:?}", compaction_group.member_table_ids);
+    println!("Pending compaction tasks: {}", pending_compact_task_num);
+    println!(
+        "Config: {:#?}",
+        compaction_group.compaction_config.unwrap_or_default()
+    );
+
+    let mut table = Table::new();
+    table.set_header(vec!["Level", "Sub-level", "Type", "SSTs", "Size (KB)"]);
+    if let Some(levels) = version.levels.get(&group_id) {
+        if let Some(l0) = &levels.l0 {
+            println!("L0 sub-level depth: {}", l0.sub_levels.len());
+            // From the newest sub-level to the oldest, as reads do.
+            for sub_level in l0.sub_levels.iter().rev() {
+                table.add_row(vec![
+                    "L0".to_string(),
+                    sub_level.sub_level_id.to_string(),
+                    format!("{:?}", sub_level.level_type()),
+                    sub_level.table_infos.len().to_string(),
+                    (sub_level.total_file_size / 1024).to_string(),
+                ]);
+            }
+        }
+        for level in &levels.levels {
+            table.add_row(vec![
+                format!("L{}", level.level_idx),
+                "".to_string(),
+                format!("{:?}", level.level_type()),
+                level.table_infos.len().to_string(),
+                (level.total_file_size / 1024).to_string(),
+            ]);
+        }
+    }
+    println!("{table}");
+    Ok(())
+}
+
 pub async fn update_compaction_config(
     context: &CtlContext,
     ids: Vec<CompactionGroupId>,
@@ -40,6 +95,38 @@ pub async fn update_compaction_config(
     Ok(())
 }
 
+pub async fn split_compaction_group(
+    context: &CtlContext,
+    group_id: CompactionGroupId,
+    table_ids: Vec<u32>,
+) -> anyhow::Result<()> {
+    let meta_client = context.meta_client().await?;
+    let new_group_id = meta_client
+        .split_compaction_group(group_id, table_ids.as_slice())
+        .await?;
+    println!(
+        "Succeed: split table ids {:?} from compaction group {} into new group {}",
+        table_ids, group_id, new_group_id
+    );
+    Ok(())
+}
+
+pub async fn merge_compaction_group(
+    context: &CtlContext,
+    left_group_id: CompactionGroupId,
+    right_group_id: CompactionGroupId,
+) -> anyhow::Result<()> {
+    let meta_client = context.meta_client().await?;
+    meta_client
+        .merge_compaction_group(left_group_id, right_group_id)
+        .await?;
+    println!(
+        "Succeed: merged compaction group {} into group {}",
+        right_group_id, left_group_id
+    );
+    Ok(())
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn build_compaction_config_vec(
     max_bytes_for_level_base: Option<u64>,
@@ -50,6 +137,8 @@ pub fn build_compaction_config_vec(
     target_file_size_base: Option<u64>,
     compaction_filter_mask: Option<u32>,
     max_sub_compaction: Option<u32>,
+    max_level: Option<u64>,
+    compression_algorithm: Option<String>,
 ) -> Vec<MutableConfig> {
     let mut configs = vec![];
     if let Some(c) = max_bytes_for_level_base {
@@ -76,5 +165,11 @@ pub fn build_compaction_config_vec(
     if let Some(c) = max_sub_compaction {
         configs.push(MutableConfig::MaxSubCompaction(c));
     }
+    if let Some(c) = max_level {
+        configs.push(MutableConfig::MaxLevel(c));
+    }
+    if let Some(c) = compression_algorithm {
+        configs.push(MutableConfig::CompressionAlgorithm(c));
+    }
     configs
 }
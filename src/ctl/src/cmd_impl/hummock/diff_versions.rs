@@ -0,0 +1,40 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::CtlContext;
+
+pub async fn diff_versions(context: &CtlContext, from_id: u64, to_id: u64) -> anyhow::Result<()> {
+    let meta_client = context.meta_client().await?;
+    let diff = meta_client.diff_versions(from_id, to_id).await?;
+    println!(
+        "version {} (epoch {}) -> version {} (epoch {}): total file size delta {} bytes",
+        diff.from_id,
+        diff.from_max_committed_epoch,
+        diff.to_id,
+        diff.to_max_committed_epoch,
+        diff.total_file_size_delta
+    );
+    for level in diff.levels {
+        println!(
+            "compaction group {} level {}: inserted SSTs {:?}, removed SSTs {:?}, file size \
+             delta {} bytes",
+            level.compaction_group_id,
+            level.level_idx,
+            level.inserted_sst_ids,
+            level.removed_sst_ids,
+            level.file_size_delta
+        );
+    }
+    Ok(())
+}
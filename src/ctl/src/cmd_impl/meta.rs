@@ -13,11 +13,15 @@
 // limitations under the License.
 
 mod backup_meta;
+mod catalog;
 mod cluster_info;
 mod pause_resume;
 mod reschedule;
+mod update_config;
 
 pub use backup_meta::*;
+pub use catalog::*;
 pub use cluster_info::*;
 pub use pause_resume::*;
 pub use reschedule::*;
+pub use update_config::*;
@@ -14,6 +14,7 @@
 
 use std::time::Duration;
 
+use risingwave_backup::MetaSnapshotRetentionPolicy;
 use risingwave_pb::backup_service::BackupJobStatus;
 
 use crate::CtlContext;
@@ -50,3 +51,25 @@ pub async fn delete_meta_snapshots(
     tracing::info!("delete meta snapshots succeeded: {:?}", snapshot_ids);
     Ok(())
 }
+
+pub async fn prune_meta_snapshots(
+    context: &CtlContext,
+    keep_hourly: u64,
+    keep_daily: u64,
+) -> anyhow::Result<()> {
+    let meta_client = context.meta_client().await?;
+    let manifest = meta_client.get_meta_snapshot_manifest().await?;
+    let expired = MetaSnapshotRetentionPolicy::new(keep_hourly, keep_daily).expired_snapshots(
+        manifest
+            .snapshot_metadata
+            .iter()
+            .map(|m| (m.id, m.taken_at_sec)),
+    );
+    if expired.is_empty() {
+        tracing::info!("no meta snapshot is expired");
+        return Ok(());
+    }
+    meta_client.delete_meta_snapshot(&expired).await?;
+    tracing::info!("delete expired meta snapshots succeeded: {:?}", expired);
+    Ok(())
+}
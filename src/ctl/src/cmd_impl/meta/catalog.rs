@@ -0,0 +1,93 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use anyhow::{anyhow, Context};
+
+use crate::CtlContext;
+
+/// Export the whole catalog as re-playable SQL statements, either to stdout (`-`) or to a
+/// file. The resulting file can be imported into a fresh cluster (without data) with
+/// `risectl meta import-catalog`.
+pub async fn export_catalog(context: &CtlContext, output: &str) -> anyhow::Result<()> {
+    let meta_client = context.meta_client().await?;
+    let statements = meta_client.export_catalog().await?;
+    let mut content = statements.join("\n");
+    content.push('\n');
+    if output == "-" {
+        print!("{}", content);
+    } else {
+        tokio::fs::write(output, content).await?;
+        tracing::info!(
+            "exported {} catalog statements to {}",
+            statements.len(),
+            output
+        );
+    }
+    Ok(())
+}
+
+/// Import a catalog file exported by `risectl meta export-catalog` by replaying its
+/// statements through a frontend node. Lines starting with `--` record objects that could
+/// not be exported and are skipped.
+pub async fn import_catalog(
+    endpoint: &str,
+    user: &str,
+    database: &str,
+    input: &str,
+) -> anyhow::Result<()> {
+    let content = tokio::fs::read_to_string(input).await?;
+    let (host, port) = endpoint
+        .rsplit_once(':')
+        .ok_or_else(|| anyhow!("expect frontend endpoint in `host:port` format"))?;
+    let (client, connection) = tokio_postgres::Config::new()
+        .host(host)
+        .port(port.parse()?)
+        .user(user)
+        .dbname(database)
+        .connect(tokio_postgres::NoTls)
+        .await
+        .context("failed to connect to the frontend")?;
+    tokio::spawn(async move {
+        if let Err(e) = connection.await {
+            tracing::error!("connection to the frontend failed: {}", e);
+        }
+    });
+
+    let mut statement = String::new();
+    let mut replayed = 0;
+    for line in content.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with("--") {
+            continue;
+        }
+        if !statement.is_empty() {
+            statement.push('\n');
+        }
+        statement.push_str(line);
+        // Statements are terminated by a semicolon at the end of a line.
+        if trimmed.ends_with(';') {
+            client
+                .batch_execute(&statement)
+                .await
+                .with_context(|| format!("failed to replay `{}`", statement))?;
+            replayed += 1;
+            statement.clear();
+        }
+    }
+    if !statement.is_empty() {
+        return Err(anyhow!("unterminated statement `{}`", statement));
+    }
+    tracing::info!("imported {} catalog statements from {}", replayed, input);
+    Ok(())
+}
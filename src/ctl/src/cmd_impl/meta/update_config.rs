@@ -0,0 +1,38 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+
+use crate::CtlContext;
+
+pub async fn update_streaming_config(context: &CtlContext, entries: Vec<String>) -> Result<()> {
+    let entries = entries
+        .iter()
+        .map(|entry| {
+            entry
+                .split_once('=')
+                .map(|(k, v)| (k.to_owned(), v.to_owned()))
+                .ok_or_else(|| anyhow!("invalid config entry `{}`, expect `key=value`", entry))
+        })
+        .collect::<Result<HashMap<_, _>>>()?;
+
+    let meta_client = context.meta_client().await?;
+    meta_client.update_streaming_config(entries).await?;
+
+    println!("Done");
+
+    Ok(())
+}
@@ -56,6 +56,9 @@ enum Commands {
     /// Commands for Benchmarks
     #[clap(subcommand)]
     Bench(BenchCommands),
+    /// Commands for barriers
+    #[clap(subcommand)]
+    Barrier(BarrierCommands),
     /// Commands for tracing the compute nodes
     Trace,
     // TODO(yuhao): profile other nodes
@@ -85,8 +88,22 @@ enum HummockCommands {
         #[clap(short, long = "num-epochs", default_value_t = 100)]
         num_epochs: u32,
     },
+    /// Summarize what changed between two hummock versions
+    DiffVersions {
+        #[clap(long = "from-version-id")]
+        from_id: u64,
+
+        #[clap(long = "to-version-id")]
+        to_id: u64,
+    },
     /// Forbid hummock commit new epochs, which is a prerequisite for compaction deterministic test
     DisableCommitEpoch,
+    /// Truncate all hummock data above the given epoch, for deterministic replay tooling.
+    /// Requires `disable-commit-epoch` to have been issued first
+    TruncateAboveEpoch {
+        #[clap(short, long = "epoch")]
+        epoch: u64,
+    },
     /// list all Hummock key-value pairs
     ListKv {
         #[clap(short, long = "epoch", default_value_t = u64::MAX)]
@@ -107,18 +124,44 @@ enum HummockCommands {
         #[clap(short, long = "level", default_value_t = 1)]
         level: u32,
     },
+    /// trigger a manual compaction for a single state table, with an optional inclusive epoch
+    /// range
+    CompactTable {
+        #[clap(long = "table-id")]
+        table_id: u32,
+
+        /// Inclusive epoch lower bound. 0 means unbounded.
+        #[clap(long = "start-epoch", default_value_t = 0)]
+        start_epoch: u64,
+
+        /// Inclusive epoch upper bound. 0 means unbounded.
+        #[clap(long = "end-epoch", default_value_t = 0)]
+        end_epoch: u64,
+    },
     /// trigger a full GC for SSTs that is not in version and with timestamp <= now -
     /// sst_retention_time_sec.
     TriggerFullGc {
         #[clap(short, long = "sst_retention_time_sec", default_value_t = 259200)]
         sst_retention_time_sec: u64,
     },
+    /// Reset the failure state of a compactor, re-enabling task assignment to it after it has
+    /// been blacklisted for repeated task failures.
+    ResetCompactorBlacklist {
+        #[clap(long)]
+        context_id: u32,
+    },
     /// List pinned versions of each worker.
     ListPinnedVersions {},
     /// List pinned snapshots of each worker.
     ListPinnedSnapshots {},
     /// List all compaction groups.
     ListCompactionGroup,
+    /// Describe a compaction group, including its level layout, pending compaction tasks,
+    /// config and member tables.
+    DescribeGroup {
+        #[clap(long)]
+        id: u64,
+    },
     /// Update compaction config for compaction groups.
     UpdateCompactionConfig {
         #[clap(long)]
@@ -139,6 +182,25 @@ enum HummockCommands {
         compaction_filter_mask: Option<u32>,
         #[clap(long)]
         max_sub_compaction: Option<u32>,
+        #[clap(long)]
+        max_level: Option<u64>,
+        /// Compression algorithm to use for every level, e.g. "None", "Lz4" or "Zstd".
+        #[clap(long)]
+        compression_algorithm: Option<String>,
+    },
+    /// Split the given state tables out of a compaction group into a new group.
+    SplitCompactionGroup {
+        #[clap(long)]
+        compaction_group_id: u64,
+        #[clap(long)]
+        table_ids: Vec<u32>,
+    },
+    /// Merge one compaction group into another and remove the former.
+    MergeCompactionGroup {
+        #[clap(long)]
+        left_group_id: u64,
+        #[clap(long)]
+        right_group_id: u64,
     },
 }
 
@@ -164,6 +226,11 @@ enum MetaCommands {
     Pause,
     /// resume the stream graph
     Resume,
+    /// update streaming executor configs at a barrier boundary
+    ///
+    /// Each entry is a `key=value` pair. All actors will switch to the new configs atomically at
+    /// the same barrier.
+    UpdateStreamingConfig { entries: Vec<String> },
     /// get cluster info
     ClusterInfo,
     /// Reschedule the parallel unit in the stream graph
@@ -193,6 +260,47 @@ enum MetaCommands {
     BackupMeta,
     /// delete meta snapshots
     DeleteMetaSnapshots { snapshot_ids: Vec<u64> },
+    /// delete meta snapshots that are expired per the given retention policy
+    PruneMetaSnapshots {
+        /// number of the most recent hours to keep a meta snapshot for each
+        #[clap(long, default_value_t = 24)]
+        keep_hourly: u64,
+        /// number of the most recent days to keep a meta snapshot for each
+        #[clap(long, default_value_t = 7)]
+        keep_daily: u64,
+    },
+    /// export the whole catalog as re-playable SQL statements
+    ExportCatalog {
+        /// output file path, or `-` for stdout
+        #[clap(long, default_value = "-")]
+        output: String,
+    },
+    /// import a catalog file exported by `export-catalog` into a fresh cluster (without
+    /// data), by replaying its statements through a frontend node
+    ImportCatalog {
+        /// host address of the frontend node to replay the statements through
+        #[clap(long, default_value = "127.0.0.1:4566")]
+        endpoint: String,
+        /// user to connect as
+        #[clap(long, default_value = "root")]
+        user: String,
+        /// database to connect to
+        #[clap(long, default_value = "dev")]
+        database: String,
+        /// input file path
+        input: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum BarrierCommands {
+    /// Show when each fragment collected a barrier, to attribute a slow checkpoint to a
+    /// specific fragment. Only the most recent barriers are traced.
+    Trace {
+        /// `prev_epoch` of the barrier to trace. Defaults to the most recently completed one.
+        #[clap(long, default_value_t = 0)]
+        prev_epoch: u64,
+    },
 }
 
 pub async fn start(opts: CliOpts) -> Result<()> {
@@ -210,6 +318,9 @@ pub async fn start_impl(opts: CliOpts, context: &CtlContext) -> Result<()> {
         Commands::Hummock(HummockCommands::DisableCommitEpoch) => {
             cmd_impl::hummock::disable_commit_epoch(context).await?
         }
+        Commands::Hummock(HummockCommands::TruncateAboveEpoch { epoch }) => {
+            cmd_impl::hummock::truncate_above_epoch(context, epoch).await?
+        }
         Commands::Hummock(HummockCommands::ListVersion) => {
             cmd_impl::hummock::list_version(context).await?;
         }
@@ -219,6 +330,9 @@ pub async fn start_impl(opts: CliOpts, context: &CtlContext) -> Result<()> {
         }) => {
             cmd_impl::hummock::list_version_deltas(context, start_id, num_epochs).await?;
         }
+        Commands::Hummock(HummockCommands::DiffVersions { from_id, to_id }) => {
+            cmd_impl::hummock::diff_versions(context, from_id, to_id).await?;
+        }
         Commands::Hummock(HummockCommands::ListKv { epoch, table_id }) => {
             cmd_impl::hummock::list_kv(context, epoch, table_id).await?;
         }
@@ -238,9 +352,19 @@ pub async fn start_impl(opts: CliOpts, context: &CtlContext) -> Result<()> {
             )
             .await?
         }
+        Commands::Hummock(HummockCommands::CompactTable {
+            table_id,
+            start_epoch,
+            end_epoch,
+        }) => {
+            cmd_impl::hummock::compact_table(context, table_id, start_epoch, end_epoch).await?
+        }
         Commands::Hummock(HummockCommands::TriggerFullGc {
             sst_retention_time_sec,
         }) => cmd_impl::hummock::trigger_full_gc(context, sst_retention_time_sec).await?,
+        Commands::Hummock(HummockCommands::ResetCompactorBlacklist { context_id }) => {
+            cmd_impl::hummock::reset_compactor_blacklist(context, context_id).await?
+        }
         Commands::Hummock(HummockCommands::ListPinnedVersions {}) => {
             list_pinned_versions(context).await?
         }
@@ -250,6 +374,9 @@ pub async fn start_impl(opts: CliOpts, context: &CtlContext) -> Result<()> {
         Commands::Hummock(HummockCommands::ListCompactionGroup) => {
             cmd_impl::hummock::list_compaction_group(context).await?
         }
+        Commands::Hummock(HummockCommands::DescribeGroup { id }) => {
+            cmd_impl::hummock::describe_compaction_group(context, id).await?
+        }
         Commands::Hummock(HummockCommands::UpdateCompactionConfig {
             compaction_group_ids,
             max_bytes_for_level_base,
@@ -260,6 +387,8 @@ pub async fn start_impl(opts: CliOpts, context: &CtlContext) -> Result<()> {
             target_file_size_base,
             compaction_filter_mask,
             max_sub_compaction,
+            max_level,
+            compression_algorithm,
         }) => {
             cmd_impl::hummock::update_compaction_config(
                 context,
@@ -273,10 +402,26 @@ pub async fn start_impl(opts: CliOpts, context: &CtlContext) -> Result<()> {
                     target_file_size_base,
                     compaction_filter_mask,
                     max_sub_compaction,
+                    max_level,
+                    compression_algorithm,
                 ),
             )
             .await?
         }
+        Commands::Hummock(HummockCommands::SplitCompactionGroup {
+            compaction_group_id,
+            table_ids,
+        }) => {
+            cmd_impl::hummock::split_compaction_group(context, compaction_group_id, table_ids)
+                .await?
+        }
+        Commands::Hummock(HummockCommands::MergeCompactionGroup {
+            left_group_id,
+            right_group_id,
+        }) => {
+            cmd_impl::hummock::merge_compaction_group(context, left_group_id, right_group_id)
+                .await?
+        }
         Commands::Table(TableCommands::Scan { mv_name }) => {
             cmd_impl::table::scan(context, mv_name).await?
         }
@@ -287,6 +432,9 @@ pub async fn start_impl(opts: CliOpts, context: &CtlContext) -> Result<()> {
         Commands::Bench(cmd) => cmd_impl::bench::do_bench(context, cmd).await?,
         Commands::Meta(MetaCommands::Pause) => cmd_impl::meta::pause(context).await?,
         Commands::Meta(MetaCommands::Resume) => cmd_impl::meta::resume(context).await?,
+        Commands::Meta(MetaCommands::UpdateStreamingConfig { entries }) => {
+            cmd_impl::meta::update_streaming_config(context, entries).await?
+        }
         Commands::Meta(MetaCommands::ClusterInfo) => cmd_impl::meta::cluster_info(context).await?,
         Commands::Meta(MetaCommands::Reschedule { plan, dry_run }) => {
             cmd_impl::meta::reschedule(context, plan, dry_run).await?
@@ -295,6 +443,22 @@ pub async fn start_impl(opts: CliOpts, context: &CtlContext) -> Result<()> {
         Commands::Meta(MetaCommands::DeleteMetaSnapshots { snapshot_ids }) => {
             cmd_impl::meta::delete_meta_snapshots(context, &snapshot_ids).await?
         }
+        Commands::Meta(MetaCommands::PruneMetaSnapshots {
+            keep_hourly,
+            keep_daily,
+        }) => cmd_impl::meta::prune_meta_snapshots(context, keep_hourly, keep_daily).await?,
+        Commands::Meta(MetaCommands::ExportCatalog { output }) => {
+            cmd_impl::meta::export_catalog(context, &output).await?
+        }
+        Commands::Meta(MetaCommands::ImportCatalog {
+            endpoint,
+            user,
+            database,
+            input,
+        }) => cmd_impl::meta::import_catalog(&endpoint, &user, &database, &input).await?,
+        Commands::Barrier(BarrierCommands::Trace { prev_epoch }) => {
+            cmd_impl::barrier::trace(context, prev_epoch).await?
+        }
         Commands::Trace => cmd_impl::trace::trace(context).await?,
         Commands::Profile { sleep } => cmd_impl::profile::profile(context, sleep).await?,
     }
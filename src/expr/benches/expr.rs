@@ -23,7 +23,7 @@ use criterion::{criterion_group, criterion_main, Criterion};
 use risingwave_common::array::*;
 use risingwave_common::types::{
     DataType, DataTypeName, Decimal, IntervalUnit, NaiveDateTimeWrapper, NaiveDateWrapper,
-    NaiveTimeWrapper, OrderedF32, OrderedF64,
+    NaiveTimeWrapper, OrderedF32, OrderedF64, Scalar,
 };
 use risingwave_expr::expr::expr_unary::new_unary_expr;
 use risingwave_expr::expr::*;
@@ -153,6 +153,14 @@ fn bench_expr(c: &mut Criterion) {
                     .take(CHUNK_SIZE),
             )
             .into(),
+            // 25: jsonb
+            {
+                let jsonb: JsonbVal = "{}".parse().unwrap();
+                (1..=CHUNK_SIZE)
+                    .map(|_| Some(jsonb.as_scalar_ref()))
+                    .collect::<JsonbArray>()
+                    .into()
+            },
         ],
         CHUNK_SIZE,
     );
@@ -171,6 +179,7 @@ fn bench_expr(c: &mut Criterion) {
         InputRefExpression::new(DataType::Interval, 11),
         InputRefExpression::new(DataType::Varchar, 12),
         InputRefExpression::new(DataType::Bytea, 13),
+        InputRefExpression::new(DataType::Jsonb, 25),
     ];
     let inputref_for_type = |ty: DataType| {
         inputrefs
@@ -292,6 +301,7 @@ fn bench_expr(c: &mut Criterion) {
                     Timestamptz => TIMESTAMPTZ_STRING,
                     Interval => INTERVAL_STRING,
                     Bytea => NUMBER_STRING, // any
+                    Jsonb => NUMBER_STRING, // a number is also a valid json
                     _ => {
                         println!("todo: {}", sig.to_string_no_return());
                         continue;
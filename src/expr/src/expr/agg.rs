@@ -32,8 +32,12 @@ pub enum AggKind {
     Avg,
     StringAgg,
     ApproxCountDistinct,
+    ApproxTopK,
     ArrayAgg,
     FirstValue,
+    PercentileCont,
+    PercentileDisc,
+    ApproxPercentile,
 }
 
 impl TryFrom<Type> for AggKind {
@@ -49,8 +53,12 @@ impl TryFrom<Type> for AggKind {
             Type::Count => Ok(AggKind::Count),
             Type::StringAgg => Ok(AggKind::StringAgg),
             Type::ApproxCountDistinct => Ok(AggKind::ApproxCountDistinct),
+            Type::ApproxTopK => Ok(AggKind::ApproxTopK),
             Type::ArrayAgg => Ok(AggKind::ArrayAgg),
             Type::FirstValue => Ok(AggKind::FirstValue),
+            Type::PercentileCont => Ok(AggKind::PercentileCont),
+            Type::PercentileDisc => Ok(AggKind::PercentileDisc),
+            Type::ApproxPercentile => Ok(AggKind::ApproxPercentile),
             Type::Unspecified => bail!("Unrecognized agg."),
         }
     }
@@ -67,8 +75,12 @@ impl AggKind {
             Self::Count => Type::Count,
             Self::StringAgg => Type::StringAgg,
             Self::ApproxCountDistinct => Type::ApproxCountDistinct,
+            Self::ApproxTopK => Type::ApproxTopK,
             Self::ArrayAgg => Type::ArrayAgg,
             Self::FirstValue => Type::FirstValue,
+            Self::PercentileCont => Type::PercentileCont,
+            Self::PercentileDisc => Type::PercentileDisc,
+            Self::ApproxPercentile => Type::ApproxPercentile,
         }
     }
 }
@@ -179,6 +179,18 @@ macro_rules! bytea {
 
 pub(crate) use bytea;
 
+#[macro_export]
+macro_rules! jsonb {
+    ($macro:ident) => {
+        $macro! {
+            risingwave_common::types::DataType::Jsonb,
+            risingwave_common::array::JsonbArray
+        }
+    };
+}
+
+pub(crate) use jsonb;
+
 #[macro_export]
 macro_rules! time {
     ($macro:ident) => {
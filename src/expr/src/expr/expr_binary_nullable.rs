@@ -27,11 +27,16 @@ use crate::expr::template::BinaryNullableExpression;
 use crate::expr::template_fast;
 use crate::vector_op::array_access::array_access;
 use crate::vector_op::cmp::{
-    general_is_distinct_from, general_is_not_distinct_from, general_ne, str_is_distinct_from,
-    str_is_not_distinct_from,
+    general_is_distinct_from, general_is_not_distinct_from, general_ne, list_is_distinct_from,
+    list_is_not_distinct_from, str_is_distinct_from, str_is_not_distinct_from,
+    struct_is_distinct_from, struct_is_not_distinct_from,
 };
 use crate::vector_op::conjunction::{and, or};
 use crate::vector_op::format_type::format_type;
+use crate::vector_op::jsonb::{
+    jsonb_array_element, jsonb_array_element_str, jsonb_contains, jsonb_extract_path,
+    jsonb_object_field, jsonb_object_field_str,
+};
 use crate::{for_all_cmp_variants, ExprError, Result};
 
 macro_rules! gen_is_distinct_from_impl {
@@ -161,6 +166,21 @@ pub fn new_nullable_binary_expr(
 ) -> Result<BoxedExpression> {
     let expr = match expr_type {
         Type::ArrayAccess => build_array_access_expr(ret, l, r),
+        Type::JsonbAccessInner | Type::JsonbAccessStr => {
+            build_jsonb_access_expr(expr_type, ret, l, r)?
+        }
+        Type::JsonbExtractPath => Box::new(BinaryNullableExpression::<
+            JsonbArray,
+            ListArray,
+            JsonbArray,
+            _,
+        >::new(l, r, ret, jsonb_extract_path)),
+        Type::JsonbContains => Box::new(BinaryNullableExpression::<
+            JsonbArray,
+            JsonbArray,
+            BoolArray,
+            _,
+        >::new(l, r, ret, jsonb_contains)),
         Type::And => Box::new(BinaryShortCircuitExpression::new(l, r, expr_type)),
         Type::Or => Box::new(BinaryShortCircuitExpression::new(l, r, expr_type)),
         Type::IsDistinctFrom => new_distinct_from_expr(l, r, ret)?,
@@ -213,9 +233,52 @@ fn build_array_access_expr(
         DataType::Interval => array_access_expression!(IntervalArray),
         DataType::Struct { .. } => array_access_expression!(StructArray),
         DataType::List { .. } => array_access_expression!(ListArray),
+        DataType::Jsonb => array_access_expression!(JsonbArray),
     }
 }
 
+fn build_jsonb_access_expr(
+    expr_type: Type,
+    ret: DataType,
+    l: BoxedExpression,
+    r: BoxedExpression,
+) -> Result<BoxedExpression> {
+    // The right operand selects either an object field (by text) or an array element (by index).
+    let expr: BoxedExpression = match (expr_type, r.return_type()) {
+        (Type::JsonbAccessInner, DataType::Varchar) => Box::new(BinaryNullableExpression::<
+            JsonbArray,
+            Utf8Array,
+            JsonbArray,
+            _,
+        >::new(l, r, ret, jsonb_object_field)),
+        (Type::JsonbAccessInner, DataType::Int32) => Box::new(BinaryNullableExpression::<
+            JsonbArray,
+            I32Array,
+            JsonbArray,
+            _,
+        >::new(l, r, ret, jsonb_array_element)),
+        (Type::JsonbAccessStr, DataType::Varchar) => Box::new(BinaryNullableExpression::<
+            JsonbArray,
+            Utf8Array,
+            Utf8Array,
+            _,
+        >::new(l, r, ret, jsonb_object_field_str)),
+        (Type::JsonbAccessStr, DataType::Int32) => Box::new(BinaryNullableExpression::<
+            JsonbArray,
+            I32Array,
+            Utf8Array,
+            _,
+        >::new(l, r, ret, jsonb_array_element_str)),
+        (tp, rt) => {
+            return Err(ExprError::UnsupportedFunction(format!(
+                "{:?}(jsonb, {:?})",
+                tp, rt
+            )));
+        }
+    };
+    Ok(expr)
+}
+
 pub fn new_distinct_from_expr(
     l: BoxedExpression,
     r: BoxedExpression,
@@ -243,6 +306,22 @@ pub fn new_distinct_from_expr(
         >::new(
             l, r, ret, str_is_distinct_from
         )),
+        (DataType::Struct { .. }, DataType::Struct { .. }) => {
+            Box::new(BinaryNullableExpression::<
+                StructArray,
+                StructArray,
+                BoolArray,
+                _,
+            >::new(l, r, ret, struct_is_distinct_from))
+        }
+        (DataType::List { .. }, DataType::List { .. }) => Box::new(BinaryNullableExpression::<
+            ListArray,
+            ListArray,
+            BoolArray,
+            _,
+        >::new(
+            l, r, ret, list_is_distinct_from
+        )),
         _ => {
             for_all_cmp_variants! { gen_is_distinct_from_impl, l, r, ret, false }
         }
@@ -277,6 +356,22 @@ pub fn new_not_distinct_from_expr(
         >::new(
             l, r, ret, str_is_not_distinct_from
         )),
+        (DataType::Struct { .. }, DataType::Struct { .. }) => {
+            Box::new(BinaryNullableExpression::<
+                StructArray,
+                StructArray,
+                BoolArray,
+                _,
+            >::new(l, r, ret, struct_is_not_distinct_from))
+        }
+        (DataType::List { .. }, DataType::List { .. }) => Box::new(BinaryNullableExpression::<
+            ListArray,
+            ListArray,
+            BoolArray,
+            _,
+        >::new(
+            l, r, ret, list_is_not_distinct_from
+        )),
         _ => {
             for_all_cmp_variants! { gen_is_distinct_from_impl, l, r, ret, true }
         }
@@ -0,0 +1,142 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::convert::TryFrom;
+use std::sync::Arc;
+
+use risingwave_common::array::{ArrayBuilder, ArrayImpl, ArrayRef, DataChunk, Utf8ArrayBuilder};
+use risingwave_common::row::OwnedRow;
+use risingwave_common::types::{DataType, Datum, ScalarImpl};
+use risingwave_pb::expr::expr_node::{RexNode, Type};
+use risingwave_pb::expr::ExprNode;
+
+use crate::expr::{build_from_prost as expr_build_from_prost, BoxedExpression, Expression};
+use crate::vector_op::to_json::datum_to_json;
+use crate::{bail, ensure, ExprError, Result};
+
+/// `ToJsonExpression` converts a value of any type into its JSON text representation. Structs are
+/// encoded as JSON objects and lists as JSON arrays, so it also serves `row_to_json`.
+#[derive(Debug)]
+pub struct ToJsonExpression {
+    child: BoxedExpression,
+}
+
+impl Expression for ToJsonExpression {
+    fn return_type(&self) -> DataType {
+        DataType::Varchar
+    }
+
+    fn eval(&self, input: &DataChunk) -> Result<ArrayRef> {
+        let child_arr = self.child.eval_checked(input)?;
+        let child_type = self.child.return_type();
+        let mut builder = Utf8ArrayBuilder::new(input.capacity());
+        let mut buf = String::new();
+        for datum_ref in child_arr.iter() {
+            if datum_ref.is_some() {
+                buf.clear();
+                datum_to_json(datum_ref, &child_type, &mut buf)?;
+                builder.append(Some(&buf));
+            } else {
+                builder.append(None);
+            }
+        }
+        Ok(Arc::new(ArrayImpl::Utf8(builder.finish())))
+    }
+
+    fn eval_row(&self, input: &OwnedRow) -> Result<Datum> {
+        let child_datum = self.child.eval_row(input)?;
+        child_datum
+            .map(|scalar| {
+                let mut buf = String::new();
+                datum_to_json(
+                    Some(scalar.as_scalar_ref_impl()),
+                    &self.child.return_type(),
+                    &mut buf,
+                )?;
+                Ok(ScalarImpl::Utf8(buf.into()))
+            })
+            .transpose()
+    }
+}
+
+impl ToJsonExpression {
+    pub fn new(child: BoxedExpression) -> Self {
+        ToJsonExpression { child }
+    }
+}
+
+impl<'a> TryFrom<&'a ExprNode> for ToJsonExpression {
+    type Error = ExprError;
+
+    fn try_from(prost: &'a ExprNode) -> Result<Self> {
+        ensure!(prost.get_expr_type().unwrap() == Type::ToJson);
+
+        let RexNode::FuncCall(func_call_node) = prost.get_rex_node().unwrap() else {
+            bail!("Expected RexNode::FuncCall");
+        };
+
+        let children = func_call_node.children.to_vec();
+        ensure!(children.len() == 1);
+        let child = expr_build_from_prost(&children[0])?;
+        Ok(ToJsonExpression::new(child))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array;
+    use risingwave_common::array::{DataChunk, I32Array, StructArray, StructValue, Utf8Array};
+    use risingwave_common::row::OwnedRow;
+    use risingwave_common::types::{DataType, ScalarImpl};
+
+    use crate::expr::expr_to_json::ToJsonExpression;
+    use crate::expr::{Expression, InputRefExpression};
+
+    #[test]
+    fn test_to_json() {
+        let struct_type = DataType::new_struct(
+            vec![DataType::Int32, DataType::Varchar],
+            vec!["id".to_string(), "name".to_string()],
+        );
+        let input_array = StructArray::from_slices_with_field_names(
+            &[true, true, false],
+            vec![
+                array! { I32Array, [Some(1), Some(2), None] }.into(),
+                array! { Utf8Array, [Some("foo"), None, None] }.into(),
+            ],
+            vec![DataType::Int32, DataType::Varchar],
+            vec!["id".to_string(), "name".to_string()],
+        );
+
+        let expr = ToJsonExpression::new(Box::new(InputRefExpression::new(struct_type, 0)));
+        let input_chunk = DataChunk::new(vec![input_array.into()], 3);
+        let result_array = expr.eval(&input_chunk).unwrap();
+        assert_eq!(
+            result_array.value_at(0).unwrap().into_utf8(),
+            r#"{"id":1,"name":"foo"}"#
+        );
+        assert_eq!(
+            result_array.value_at(1).unwrap().into_utf8(),
+            r#"{"id":2,"name":null}"#
+        );
+        assert_eq!(result_array.value_at(2), None);
+
+        let row = OwnedRow::new(vec![Some(ScalarImpl::Struct(StructValue::new(vec![
+            Some(ScalarImpl::Int32(1)),
+            Some(ScalarImpl::Utf8("foo".into())),
+        ])))]);
+        let result = expr.eval_row(&row).unwrap().unwrap();
+        assert_eq!(&*result.into_utf8(), r#"{"id":1,"name":"foo"}"#);
+    }
+}
@@ -33,7 +33,7 @@ pub struct UdfExpression {
     // name: String,
     arg_types: Vec<DataType>,
     return_type: DataType,
-    client: ArrowFlightUdfClient,
+    client: Arc<ArrowFlightUdfClient>,
     function_id: FunctionId,
 }
 
@@ -86,10 +86,10 @@ impl<'a> TryFrom<&'a ExprNode> for UdfExpression {
         let RexNode::Udf(udf) = prost.get_rex_node().unwrap() else {
             bail!("expect UDF");
         };
-        // connect to UDF service and check the function
+        // get a pooled client to the UDF service and check the function
         let (client, function_id) = tokio::task::block_in_place(|| {
             tokio::runtime::Handle::current().block_on(async {
-                let client = ArrowFlightUdfClient::connect(&udf.path).await?;
+                let client = risingwave_udf::get_or_connect(&udf.path).await?;
                 let args = Schema::new(
                     udf.arg_types
                         .iter()
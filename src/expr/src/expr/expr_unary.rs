@@ -35,7 +35,7 @@ use crate::vector_op::ltrim::ltrim;
 use crate::vector_op::md5::md5;
 use crate::vector_op::round::*;
 use crate::vector_op::rtrim::rtrim;
-use crate::vector_op::timestamptz::f64_sec_to_timestamptz;
+use crate::vector_op::timestamptz::{epoch_to_timestamptz, f64_sec_to_timestamptz};
 use crate::vector_op::trim::trim;
 use crate::vector_op::upper::upper;
 use crate::{for_all_cast_variants, ExprError, Result};
@@ -300,6 +300,13 @@ pub fn new_unary_expr(
                 f64_sec_to_timestamptz,
             ))
         }
+        (ProstType::RwEpochToTs, DataType::Timestamptz, DataType::Int64) => {
+            Box::new(UnaryExpression::<I64Array, I64Array, _>::new(
+                child_expr,
+                return_type,
+                epoch_to_timestamptz,
+            ))
+        }
         (expr, ret, child) => {
             return Err(ExprError::UnsupportedFunction(format!(
                 "{:?}({:?}) -> {:?}",
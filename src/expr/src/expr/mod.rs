@@ -33,6 +33,7 @@ pub mod expr_regexp;
 mod expr_some_all;
 mod expr_ternary_bytes;
 mod expr_to_char_const_tmpl;
+mod expr_to_json;
 mod expr_to_timestamp_const_tmpl;
 mod expr_udf;
 pub mod expr_unary;
@@ -61,6 +62,7 @@ use crate::expr::expr_field::FieldExpression;
 use crate::expr::expr_in::InExpression;
 use crate::expr::expr_nested_construct::NestedConstructExpression;
 use crate::expr::expr_regexp::RegexpMatchExpression;
+use crate::expr::expr_to_json::ToJsonExpression;
 use crate::expr::expr_udf::UdfExpression;
 use crate::expr::expr_vnode::VnodeExpression;
 use crate::ExprError;
@@ -108,12 +110,13 @@ pub fn build_from_prost(prost: &ExprNode) -> Result<BoxedExpression> {
         // Fixed number of arguments and based on `Unary/Binary/Ternary/...Expression`
         Cast | Upper | Lower | Md5 | Not | IsTrue | IsNotTrue | IsFalse | IsNotFalse | IsNull
         | IsNotNull | Neg | Ascii | Abs | Ceil | Floor | Round | BitwiseNot | CharLength
-        | BoolOut | OctetLength | BitLength | ToTimestamp => build_unary_expr_prost(prost),
+        | BoolOut | OctetLength | BitLength | ToTimestamp | RwEpochToTs => build_unary_expr_prost(prost),
         Equal | NotEqual | LessThan | LessThanOrEqual | GreaterThan | GreaterThanOrEqual | Add
         | Subtract | Multiply | Divide | Modulus | Extract | RoundDigit | Pow | TumbleStart
         | Position | BitwiseShiftLeft | BitwiseShiftRight | BitwiseAnd | BitwiseOr | BitwiseXor
         | ConcatOp | AtTimeZone | CastWithTimeZone => build_binary_expr_prost(prost),
-        And | Or | IsDistinctFrom | IsNotDistinctFrom | ArrayAccess | FormatType => {
+        And | Or | IsDistinctFrom | IsNotDistinctFrom | ArrayAccess | FormatType
+        | JsonbAccessInner | JsonbAccessStr | JsonbExtractPath | JsonbContains => {
             build_nullable_binary_expr_prost(prost)
         }
         ToChar => build_to_char_expr(prost),
@@ -145,6 +148,7 @@ pub fn build_from_prost(prost: &ExprNode) -> Result<BoxedExpression> {
         Array => NestedConstructExpression::try_from(prost).map(Expression::boxed),
         Row => NestedConstructExpression::try_from(prost).map(Expression::boxed),
         RegexpMatch => RegexpMatchExpression::try_from(prost).map(Expression::boxed),
+        ToJson => ToJsonExpression::try_from(prost).map(Expression::boxed),
         ArrayCat | ArrayAppend | ArrayPrepend => {
             // Now we implement these three functions as a single expression for the
             // sake of simplicity. If performance matters at some time, we can split
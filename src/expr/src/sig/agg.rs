@@ -103,6 +103,47 @@ static AGG_FUNC_SIG_MAP: LazyLock<AggFuncSigMap> = LazyLock::new(|| {
         vec![DataTypeName::Varchar, DataTypeName::Varchar],
         DataTypeName::Varchar,
     );
+    // Handle special case for `approx_top_k`, for it accepts the `k` argument.
+    map.insert(
+        AggKind::ApproxTopK,
+        vec![DataTypeName::Varchar, DataTypeName::Int32],
+        DataTypeName::List,
+    );
+    // Handle special case for the ordered-set aggregates, for they accept the `fraction`
+    // argument followed by the `WITHIN GROUP (ORDER BY ...)` expression.
+    map.insert(
+        AggKind::PercentileCont,
+        vec![DataTypeName::Float64, DataTypeName::Float64],
+        DataTypeName::Float64,
+    );
+    for input in all_types {
+        if let Some(v) = infer_return_type(
+            &AggKind::PercentileDisc,
+            &[DataType::Float64, DataType::from(input)],
+        ) {
+            map.insert(
+                AggKind::PercentileDisc,
+                vec![DataTypeName::Float64, input],
+                DataTypeName::from(v),
+            );
+        }
+    }
+    // Handle special case for `approx_percentile`, for it accepts the constant fraction and an
+    // optional constant compression factor.
+    map.insert(
+        AggKind::ApproxPercentile,
+        vec![DataTypeName::Float64, DataTypeName::Float64],
+        DataTypeName::Float64,
+    );
+    map.insert(
+        AggKind::ApproxPercentile,
+        vec![
+            DataTypeName::Float64,
+            DataTypeName::Float64,
+            DataTypeName::Int32,
+        ],
+        DataTypeName::Float64,
+    );
     map
 });
 
@@ -152,6 +193,18 @@ pub fn infer_return_type(agg_kind: &AggKind, inputs: &[DataType]) -> Option<Data
         (AggKind::ApproxCountDistinct, [_]) => DataType::Int64,
         (AggKind::ApproxCountDistinct, _) => return None,
 
+        // ApproxTopK
+        (AggKind::ApproxTopK, [DataType::Varchar, k]) => match k {
+            DataType::Int16 | DataType::Int32 | DataType::Int64 => DataType::List {
+                datatype: Box::new(DataType::new_struct(
+                    vec![DataType::Varchar, DataType::Int64],
+                    vec!["value".to_string(), "count".to_string()],
+                )),
+            },
+            _ => return None,
+        },
+        (AggKind::ApproxTopK, _) => return None,
+
         // Count
         (AggKind::Count, [] | [_]) => DataType::Int64,
         (AggKind::Count, _) => return None,
@@ -165,6 +218,21 @@ pub fn infer_return_type(agg_kind: &AggKind, inputs: &[DataType]) -> Option<Data
             datatype: Box::new(input.clone()),
         },
         (AggKind::ArrayAgg, _) => return None,
+
+        // PercentileCont, PercentileDisc. The first argument is the constant fraction and the
+        // second one is the `WITHIN GROUP (ORDER BY ...)` expression.
+        (AggKind::PercentileCont, [DataType::Float64, DataType::Float64]) => DataType::Float64,
+        (AggKind::PercentileCont, _) => return None,
+        (AggKind::PercentileDisc, [DataType::Float64, input]) => input.clone(),
+        (AggKind::PercentileDisc, _) => return None,
+
+        // ApproxPercentile. The value and the constant fraction, plus an optional constant
+        // compression factor that trades state size for accuracy.
+        (AggKind::ApproxPercentile, [DataType::Float64, DataType::Float64]) => DataType::Float64,
+        (AggKind::ApproxPercentile, [DataType::Float64, DataType::Float64, DataType::Int32]) => {
+            DataType::Float64
+        }
+        (AggKind::ApproxPercentile, _) => return None,
     };
 
     Some(return_type)
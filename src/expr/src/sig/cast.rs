@@ -12,7 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+//! Cast signatures and the contexts they are allowed in.
+
 use std::collections::BTreeMap;
+use std::ops::Deref;
 use std::sync::LazyLock;
 
 use parse_display::Display;
@@ -43,7 +46,45 @@ impl CastSig {
     }
 }
 
-pub type CastMap = BTreeMap<(DataTypeName, DataTypeName), CastContext>;
+/// The registry of all casts, mapping each castable type pair to the most permissive context
+/// the cast is allowed in.
+#[derive(Default)]
+pub struct CastMap(BTreeMap<(DataTypeName, DataTypeName), CastContext>);
+
+impl Deref for CastMap {
+    type Target = BTreeMap<(DataTypeName, DataTypeName), CastContext>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl CastMap {
+    fn insert(&mut self, from_type: DataTypeName, to_type: DataTypeName, context: CastContext) {
+        self.0.insert((from_type, to_type), context);
+    }
+
+    /// Returns the most permissive context in which casting `from_type` to `to_type` is
+    /// allowed, or `None` if the cast does not exist at all.
+    pub fn cast_context(
+        &self,
+        from_type: DataTypeName,
+        to_type: DataTypeName,
+    ) -> Option<CastContext> {
+        self.0.get(&(from_type, to_type)).copied()
+    }
+
+    /// Checks whether casting `from_type` to `to_type` is allowed in the `allows` context.
+    pub fn castable(
+        &self,
+        from_type: DataTypeName,
+        to_type: DataTypeName,
+        allows: CastContext,
+    ) -> bool {
+        self.cast_context(from_type, to_type)
+            .map_or(false, |context| context <= allows)
+    }
+}
 
 pub fn cast_sigs() -> impl Iterator<Item = CastSig> {
     CAST_MAP
@@ -55,13 +96,15 @@ pub fn cast_sigs() -> impl Iterator<Item = CastSig> {
         })
 }
 
-pub static CAST_MAP: LazyLock<CastMap> = LazyLock::new(|| {
+pub static CAST_MAP: LazyLock<CastMap> = LazyLock::new(build_cast_map);
+
+fn build_cast_map() -> CastMap {
     use DataTypeName as T;
 
     // Implicit cast operations in PG are organized in 3 sequences, with the reverse direction being
     // assign cast operations.
     // https://github.com/postgres/postgres/blob/e0064f0ff6dfada2695330c6bc1945fa7ae813be/src/include/catalog/pg_cast.dat#L18-L20
-    let mut m = BTreeMap::new();
+    let mut m = CastMap::default();
     insert_cast_seq(
         &mut m,
         &[
@@ -91,22 +134,29 @@ pub static CAST_MAP: LazyLock<CastMap> = LazyLock::new(|| {
         T::Time,
         T::Interval,
     ] {
-        m.insert((t, T::Varchar), CastContext::Assign);
-        m.insert((T::Varchar, t), CastContext::Explicit);
+        m.insert(t, T::Varchar, CastContext::Assign);
+        m.insert(T::Varchar, t, CastContext::Explicit);
     }
 
+    // Depart from the PG rule that casts from string are explicit-only: allow assigning a
+    // string to a timestamptz column (e.g. in `INSERT`), which is common when loading data.
+    // Implicit remains disallowed, so joins and function resolution never cast a string
+    // operand silently.
+    m.insert(T::Varchar, T::Timestamptz, CastContext::Assign);
+
+    // Casting between jsonb and string, like `bytea`, is not part of the cast sequences.
+    m.insert(T::Jsonb, T::Varchar, CastContext::Assign);
+    m.insert(T::Varchar, T::Jsonb, CastContext::Explicit);
+
     // Misc casts allowed by PG that are neither in implicit cast sequences nor from/to string.
-    m.insert((T::Timestamp, T::Time), CastContext::Assign);
-    m.insert((T::Timestamptz, T::Time), CastContext::Assign);
-    m.insert((T::Boolean, T::Int32), CastContext::Explicit);
-    m.insert((T::Int32, T::Boolean), CastContext::Explicit);
+    m.insert(T::Timestamp, T::Time, CastContext::Assign);
+    m.insert(T::Timestamptz, T::Time, CastContext::Assign);
+    m.insert(T::Boolean, T::Int32, CastContext::Explicit);
+    m.insert(T::Int32, T::Boolean, CastContext::Explicit);
     m
-});
+}
 
-fn insert_cast_seq(
-    m: &mut BTreeMap<(DataTypeName, DataTypeName), CastContext>,
-    types: &[DataTypeName],
-) {
+fn insert_cast_seq(m: &mut CastMap, types: &[DataTypeName]) {
     for (source_index, source_type) in types.iter().enumerate() {
         for (target_index, target_type) in types.iter().enumerate() {
             let cast_context = match source_index.cmp(&target_index) {
@@ -118,7 +168,7 @@ fn insert_cast_seq(
                 std::cmp::Ordering::Equal => continue,
                 std::cmp::Ordering::Greater => CastContext::Assign,
             };
-            m.insert((*source_type, *target_type), cast_context);
+            m.insert(*source_type, *target_type, cast_context);
         }
     }
 }
@@ -141,6 +141,7 @@ fn build_type_derive_map() -> FuncSigMap {
     ];
     build_binary_cmp_funcs(&mut map, cmp_exprs, &num_types);
     build_binary_cmp_funcs(&mut map, cmp_exprs, &[T::Struct]);
+    build_binary_cmp_funcs(&mut map, cmp_exprs, &[T::List]);
     build_binary_cmp_funcs(
         &mut map,
         cmp_exprs,
@@ -238,6 +239,7 @@ fn build_type_derive_map() -> FuncSigMap {
         T::Timestamptz,
     );
     map.insert(E::ToTimestamp, vec![T::Float64], T::Timestamptz);
+    map.insert(E::RwEpochToTs, vec![T::Int64], T::Timestamptz);
     map.insert(E::ToTimestamp1, vec![T::Varchar, T::Varchar], T::Timestamp);
     map.insert(
         E::AtTimeZone,
@@ -301,6 +303,14 @@ fn build_type_derive_map() -> FuncSigMap {
     // TODO: Support more `to_char` types.
     map.insert(E::ToChar, vec![T::Timestamp, T::Varchar], T::Varchar);
 
+    // jsonb expressions
+    // The right operand of access operators is either an object key or an array index.
+    for t in [T::Varchar, T::Int32] {
+        map.insert(E::JsonbAccessInner, vec![T::Jsonb, t], T::Jsonb);
+        map.insert(E::JsonbAccessStr, vec![T::Jsonb, t], T::Varchar);
+    }
+    map.insert(E::JsonbContains, vec![T::Jsonb, T::Jsonb], T::Boolean);
+
     map
 }
 
@@ -30,6 +30,9 @@ use crate::vector_op::agg::filter::*;
 use crate::vector_op::agg::functions::*;
 use crate::vector_op::agg::general_agg::*;
 use crate::vector_op::agg::general_distinct_agg::*;
+use crate::vector_op::agg::percentile::{
+    create_percentile_cont_state, create_percentile_disc_state,
+};
 use crate::vector_op::agg::string_agg::create_string_agg_state;
 use crate::Result;
 
@@ -101,12 +104,33 @@ impl AggStateFactory {
                 );
                 let agg_col_idx = agg_arg.get_input()?.get_column_idx() as usize;
                 let delim_col_idx = delim_arg.get_input()?.get_column_idx() as usize;
-                create_string_agg_state(agg_col_idx, delim_col_idx, order_pairs)?
+                create_string_agg_state(agg_col_idx, delim_col_idx, distinct, order_pairs)?
             }
             (AggKind::ArrayAgg, [arg]) => {
                 let agg_col_idx = arg.get_input()?.get_column_idx() as usize;
                 create_array_agg_state(return_type.clone(), agg_col_idx, order_pairs)?
             }
+            (AggKind::ApproxTopK, _) => {
+                bail!("approx_top_k is only supported in materialized views")
+            }
+            (AggKind::ApproxPercentile, _) => {
+                bail!("approx_percentile is only supported in materialized views")
+            }
+            (AggKind::PercentileCont, [fraction_arg, value_arg]) => {
+                let fraction_col_idx = fraction_arg.get_input()?.get_column_idx() as usize;
+                let value_col_idx = value_arg.get_input()?.get_column_idx() as usize;
+                create_percentile_cont_state(fraction_col_idx, value_col_idx, order_pairs)?
+            }
+            (AggKind::PercentileDisc, [fraction_arg, value_arg]) => {
+                let fraction_col_idx = fraction_arg.get_input()?.get_column_idx() as usize;
+                let value_col_idx = value_arg.get_input()?.get_column_idx() as usize;
+                create_percentile_disc_state(
+                    fraction_col_idx,
+                    value_col_idx,
+                    return_type.clone(),
+                    order_pairs,
+                )?
+            }
             (agg_kind, [arg]) => {
                 // other unary agg call
                 let input_type = DataType::from(arg.get_type()?);
@@ -0,0 +1,321 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::array::{Array, ArrayBuilderImpl, ArrayImpl, DataChunk, RowRef};
+use risingwave_common::bail;
+use risingwave_common::row::{Row, RowExt};
+use risingwave_common::types::{DataType, Datum};
+use risingwave_common::util::ordered::OrderedRow;
+use risingwave_common::util::sort_util::{OrderPair, OrderType};
+
+use crate::vector_op::agg::aggregator::Aggregator;
+use crate::Result;
+
+/// `percentile_cont` interpolates between the two values adjacent to the requested
+/// fraction of the hypothetical ordering, so it buffers the input as `f64` and sorts
+/// it on output.
+#[derive(Clone)]
+struct PercentileCont {
+    fraction_col_idx: usize,
+    value_col_idx: usize,
+    /// Whether the `WITHIN GROUP (ORDER BY ...)` clause is descending.
+    desc: bool,
+    fraction: Option<f64>,
+    values: Vec<f64>,
+}
+
+impl PercentileCont {
+    fn new(fraction_col_idx: usize, value_col_idx: usize, desc: bool) -> Self {
+        Self {
+            fraction_col_idx,
+            value_col_idx,
+            desc,
+            fraction: None,
+            values: vec![],
+        }
+    }
+
+    fn get_result_and_reset(&mut self) -> Option<f64> {
+        let mut values = std::mem::take(&mut self.values);
+        let fraction = self.fraction.take()?;
+        if values.is_empty() {
+            return None;
+        }
+        values.sort_unstable_by(f64::total_cmp);
+        if self.desc {
+            values.reverse();
+        }
+        let rn = fraction * (values.len() - 1) as f64;
+        let (lo, hi) = (rn.floor() as usize, rn.ceil() as usize);
+        if lo == hi {
+            Some(values[lo])
+        } else {
+            Some(values[lo] * (hi as f64 - rn) + values[hi] * (rn - lo as f64))
+        }
+    }
+}
+
+impl Aggregator for PercentileCont {
+    fn return_type(&self) -> DataType {
+        DataType::Float64
+    }
+
+    fn update_single(&mut self, input: &DataChunk, row_id: usize) -> Result<()> {
+        if let (ArrayImpl::Float64(fraction_col), ArrayImpl::Float64(value_col)) = (
+            input.column_at(self.fraction_col_idx).array_ref(),
+            input.column_at(self.value_col_idx).array_ref(),
+        ) {
+            // rows with null value are ignored, following PostgreSQL
+            if let Some(value) = value_col.value_at(row_id) {
+                self.fraction = fraction_col.value_at(row_id).map(|f| f.0);
+                self.values.push(value.0);
+            }
+            Ok(())
+        } else {
+            bail!("Input fail to match {}.", stringify!(Float64))
+        }
+    }
+
+    fn update_multi(
+        &mut self,
+        input: &DataChunk,
+        start_row_id: usize,
+        end_row_id: usize,
+    ) -> Result<()> {
+        for row_id in start_row_id..end_row_id {
+            self.update_single(input, row_id)?;
+        }
+        Ok(())
+    }
+
+    fn output(&mut self, builder: &mut ArrayBuilderImpl) -> Result<()> {
+        if let ArrayBuilderImpl::Float64(builder) = builder {
+            builder.append(self.get_result_and_reset().map(Into::into));
+            Ok(())
+        } else {
+            bail!("Builder fail to match {}.", stringify!(Float64))
+        }
+    }
+}
+
+/// `percentile_disc` returns the first value whose position in the hypothetical ordering
+/// reaches the requested fraction, so it buffers the input values and sorts them on
+/// output without interpolation.
+#[derive(Clone)]
+struct PercentileDisc {
+    fraction_col_idx: usize,
+    value_col_idx: usize,
+    return_type: DataType,
+    order_col_indices: Vec<usize>,
+    order_types: Vec<OrderType>,
+    fraction: Option<f64>,
+    unordered_values: Vec<(OrderedRow, Datum)>,
+}
+
+impl PercentileDisc {
+    fn new(
+        fraction_col_idx: usize,
+        value_col_idx: usize,
+        return_type: DataType,
+        order_pairs: Vec<OrderPair>,
+    ) -> Self {
+        let (order_col_indices, order_types) = order_pairs
+            .into_iter()
+            .map(|p| (p.column_idx, p.order_type))
+            .unzip();
+        Self {
+            fraction_col_idx,
+            value_col_idx,
+            return_type,
+            order_col_indices,
+            order_types,
+            fraction: None,
+            unordered_values: vec![],
+        }
+    }
+
+    fn push_row(&mut self, value: Datum, row: RowRef<'_>) {
+        let key = OrderedRow::new(
+            row.project(&self.order_col_indices).into_owned_row(),
+            &self.order_types,
+        );
+        self.unordered_values.push((key, value));
+    }
+
+    fn get_result_and_reset(&mut self) -> Datum {
+        let mut values = std::mem::take(&mut self.unordered_values);
+        let fraction = self.fraction.take()?;
+        if values.is_empty() {
+            return None;
+        }
+        values.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+        let rn = fraction * values.len() as f64;
+        let idx = (rn.ceil() as usize).max(1) - 1;
+        values.swap_remove(idx).1
+    }
+}
+
+impl Aggregator for PercentileDisc {
+    fn return_type(&self) -> DataType {
+        self.return_type.clone()
+    }
+
+    fn update_single(&mut self, input: &DataChunk, row_id: usize) -> Result<()> {
+        if let ArrayImpl::Float64(fraction_col) =
+            input.column_at(self.fraction_col_idx).array_ref()
+        {
+            // rows with null value are ignored, following PostgreSQL
+            let value = input
+                .column_at(self.value_col_idx)
+                .array_ref()
+                .datum_at(row_id);
+            if value.is_some() {
+                self.fraction = fraction_col.value_at(row_id).map(|f| f.0);
+                let (row_ref, vis) = input.row_at(row_id);
+                assert!(vis);
+                self.push_row(value, row_ref);
+            }
+            Ok(())
+        } else {
+            bail!("Input fail to match {}.", stringify!(Float64))
+        }
+    }
+
+    fn update_multi(
+        &mut self,
+        input: &DataChunk,
+        start_row_id: usize,
+        end_row_id: usize,
+    ) -> Result<()> {
+        for row_id in start_row_id..end_row_id {
+            self.update_single(input, row_id)?;
+        }
+        Ok(())
+    }
+
+    fn output(&mut self, builder: &mut ArrayBuilderImpl) -> Result<()> {
+        let result = self.get_result_and_reset();
+        builder.append_datum(&result);
+        Ok(())
+    }
+}
+
+pub fn create_percentile_cont_state(
+    fraction_col_idx: usize,
+    value_col_idx: usize,
+    order_pairs: Vec<OrderPair>,
+) -> Result<Box<dyn Aggregator>> {
+    let [order_pair] = order_pairs.as_slice() else {
+        bail!("percentile_cont requires exactly one order by column");
+    };
+    Ok(Box::new(PercentileCont::new(
+        fraction_col_idx,
+        value_col_idx,
+        order_pair.order_type == OrderType::Descending,
+    )))
+}
+
+pub fn create_percentile_disc_state(
+    fraction_col_idx: usize,
+    value_col_idx: usize,
+    return_type: DataType,
+    order_pairs: Vec<OrderPair>,
+) -> Result<Box<dyn Aggregator>> {
+    if order_pairs.len() != 1 {
+        bail!("percentile_disc requires exactly one order by column");
+    }
+    Ok(Box::new(PercentileDisc::new(
+        fraction_col_idx,
+        value_col_idx,
+        return_type,
+        order_pairs,
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::{
+        ArrayBuilder, DataChunkTestExt, F64ArrayBuilder, I32ArrayBuilder,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_percentile_cont() -> Result<()> {
+        let chunk = DataChunk::from_pretty(
+            "F   F
+             0.5 1
+             0.5 2
+             0.5 3
+             0.5 4",
+        );
+        let mut agg = create_percentile_cont_state(
+            0,
+            1,
+            vec![OrderPair::new(1, OrderType::Ascending)],
+        )?;
+        let mut builder = ArrayBuilderImpl::Float64(F64ArrayBuilder::new(0));
+        agg.update_multi(&chunk, 0, chunk.cardinality())?;
+        agg.output(&mut builder)?;
+        let output = builder.finish();
+        let actual = output.as_float64().iter().collect::<Vec<_>>();
+        assert_eq!(actual, vec![Some(2.5.into())]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_percentile_cont_desc() -> Result<()> {
+        let chunk = DataChunk::from_pretty(
+            "F    F
+             0.25 1
+             0.25 2
+             0.25 3",
+        );
+        let mut agg = create_percentile_cont_state(
+            0,
+            1,
+            vec![OrderPair::new(1, OrderType::Descending)],
+        )?;
+        let mut builder = ArrayBuilderImpl::Float64(F64ArrayBuilder::new(0));
+        agg.update_multi(&chunk, 0, chunk.cardinality())?;
+        agg.output(&mut builder)?;
+        let output = builder.finish();
+        let actual = output.as_float64().iter().collect::<Vec<_>>();
+        assert_eq!(actual, vec![Some(2.5.into())]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_percentile_disc() -> Result<()> {
+        let chunk = DataChunk::from_pretty(
+            "F   i
+             0.5 30
+             0.5 10
+             0.5 20",
+        );
+        let mut agg = create_percentile_disc_state(
+            0,
+            1,
+            DataType::Int32,
+            vec![OrderPair::new(1, OrderType::Ascending)],
+        )?;
+        let mut builder = ArrayBuilderImpl::Int32(I32ArrayBuilder::new(0));
+        agg.update_multi(&chunk, 0, chunk.cardinality())?;
+        agg.output(&mut builder)?;
+        let output = builder.finish();
+        let actual = output.as_int32().iter().collect::<Vec<_>>();
+        assert_eq!(actual, vec![Some(20)]);
+        Ok(())
+    }
+}
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
+
 use risingwave_common::array::{
     Array, ArrayBuilder, ArrayBuilderImpl, ArrayImpl, DataChunk, RowRef,
 };
@@ -29,19 +31,27 @@ use crate::Result;
 struct StringAggUnordered {
     agg_col_idx: usize,
     delim_col_idx: usize,
+    distinct: bool,
+    exists: HashSet<String>,
     result: Option<String>,
 }
 
 impl StringAggUnordered {
-    fn new(agg_col_idx: usize, delim_col_idx: usize) -> Self {
+    fn new(agg_col_idx: usize, delim_col_idx: usize, distinct: bool) -> Self {
         Self {
             agg_col_idx,
             delim_col_idx,
+            distinct,
+            exists: HashSet::new(),
             result: None,
         }
     }
 
     fn push(&mut self, value: &str, delim: &str) {
+        if self.distinct && !self.exists.insert(value.to_string()) {
+            // skip duplicate values, the first occurrence wins
+            return;
+        }
         if let Some(result) = &mut self.result {
             result.push_str(delim);
             result.push_str(value);
@@ -51,6 +61,7 @@ impl StringAggUnordered {
     }
 
     fn get_result_and_reset(&mut self) -> Option<String> {
+        self.exists.clear();
         std::mem::take(&mut self.result)
     }
 }
@@ -122,13 +133,19 @@ struct StringAggData {
 struct StringAggOrdered {
     agg_col_idx: usize,
     delim_col_idx: usize,
+    distinct: bool,
     order_col_indices: Vec<usize>,
     order_types: Vec<OrderType>,
     unordered_values: Vec<(OrderedRow, StringAggData)>,
 }
 
 impl StringAggOrdered {
-    fn new(agg_col_idx: usize, delim_col_idx: usize, order_pairs: Vec<OrderPair>) -> Self {
+    fn new(
+        agg_col_idx: usize,
+        delim_col_idx: usize,
+        distinct: bool,
+        order_pairs: Vec<OrderPair>,
+    ) -> Self {
         let (order_col_indices, order_types) = order_pairs
             .into_iter()
             .map(|p| (p.column_idx, p.order_type))
@@ -136,6 +153,7 @@ impl StringAggOrdered {
         Self {
             agg_col_idx,
             delim_col_idx,
+            distinct,
             order_col_indices,
             order_types,
             unordered_values: vec![],
@@ -162,13 +180,21 @@ impl StringAggOrdered {
             return None;
         }
         rows.sort_unstable_by(|a, b| a.0.cmp(&b.0));
-        let mut rows_iter = rows.into_iter();
-        let mut result = rows_iter.next().unwrap().1.value;
-        for (_, data) in rows_iter {
-            result.push_str(&data.delim);
-            result.push_str(&data.value);
+        let mut exists = HashSet::new();
+        let mut result: Option<String> = None;
+        for (_, data) in rows {
+            if self.distinct && !exists.insert(data.value.clone()) {
+                // skip duplicate values, the first one in sort order wins
+                continue;
+            }
+            if let Some(result) = &mut result {
+                result.push_str(&data.delim);
+                result.push_str(&data.value);
+            } else {
+                result = Some(data.value);
+            }
         }
-        Some(result)
+        result
     }
 }
 
@@ -237,17 +263,20 @@ impl Aggregator for StringAggOrdered {
 pub fn create_string_agg_state(
     agg_col_idx: usize,
     delim_col_idx: usize,
+    distinct: bool,
     order_pairs: Vec<OrderPair>,
 ) -> Result<Box<dyn Aggregator>> {
     if order_pairs.is_empty() {
         Ok(Box::new(StringAggUnordered::new(
             agg_col_idx,
             delim_col_idx,
+            distinct,
         )))
     } else {
         Ok(Box::new(StringAggOrdered::new(
             agg_col_idx,
             delim_col_idx,
+            distinct,
             order_pairs,
         )))
     }
@@ -269,7 +298,7 @@ mod tests {
              ccc ,
              ddd ,",
         );
-        let mut agg = create_string_agg_state(0, 1, vec![])?;
+        let mut agg = create_string_agg_state(0, 1, false, vec![])?;
         let mut builder = ArrayBuilderImpl::Utf8(Utf8ArrayBuilder::new(0));
         agg.update_multi(&chunk, 0, chunk.cardinality())?;
         agg.output(&mut builder)?;
@@ -290,7 +319,7 @@ mod tests {
              ccc _
              ddd .",
         );
-        let mut agg = create_string_agg_state(0, 1, vec![])?;
+        let mut agg = create_string_agg_state(0, 1, false, vec![])?;
         let mut builder = ArrayBuilderImpl::Utf8(Utf8ArrayBuilder::new(0));
         agg.update_multi(&chunk, 0, chunk.cardinality())?;
         agg.output(&mut builder)?;
@@ -302,6 +331,27 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_string_agg_distinct() -> Result<()> {
+        let chunk = DataChunk::from_pretty(
+            "T   T
+             aaa ,
+             bbb ,
+             aaa ,
+             ccc ,",
+        );
+        let mut agg = create_string_agg_state(0, 1, true, vec![])?;
+        let mut builder = ArrayBuilderImpl::Utf8(Utf8ArrayBuilder::new(0));
+        agg.update_multi(&chunk, 0, chunk.cardinality())?;
+        agg.output(&mut builder)?;
+        let output = builder.finish();
+        let actual = output.as_utf8();
+        let actual = actual.iter().collect::<Vec<_>>();
+        let expected = "aaa,bbb,ccc";
+        assert_eq!(actual, &[Some(expected)]);
+        Ok(())
+    }
+
     #[test]
     fn test_string_agg_with_order() -> Result<()> {
         let chunk = DataChunk::from_pretty(
@@ -314,6 +364,7 @@ mod tests {
         let mut agg = create_string_agg_state(
             1,
             0,
+            false,
             vec![
                 OrderPair::new(2, OrderType::Ascending),
                 OrderPair::new(3, OrderType::Descending),
@@ -330,4 +381,26 @@ mod tests {
         assert_eq!(actual, &[Some(expected)]);
         Ok(())
     }
+
+    #[test]
+    fn test_string_agg_distinct_with_order() -> Result<()> {
+        let chunk = DataChunk::from_pretty(
+            "T T   i
+             _ aaa 3
+             _ bbb 1
+             _ aaa 2
+             _ ccc 4",
+        );
+        let mut agg =
+            create_string_agg_state(1, 0, true, vec![OrderPair::new(2, OrderType::Ascending)])?;
+        let mut builder = ArrayBuilderImpl::Utf8(Utf8ArrayBuilder::new(0));
+        agg.update_multi(&chunk, 0, chunk.cardinality())?;
+        agg.output(&mut builder)?;
+        let output = builder.finish();
+        let actual = output.as_utf8();
+        let actual = actual.iter().collect::<Vec<_>>();
+        let expected = "bbb_aaa_ccc";
+        assert_eq!(actual, &[Some(expected)]);
+        Ok(())
+    }
 }
@@ -19,7 +19,7 @@ use std::str::FromStr;
 use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
 use itertools::Itertools;
 use num_traits::ToPrimitive;
-use risingwave_common::array::{Array, ListRef, ListValue, StructRef, StructValue};
+use risingwave_common::array::{Array, JsonbVal, ListRef, ListValue, StructRef, StructValue};
 use risingwave_common::types::struct_type::StructType;
 use risingwave_common::types::to_text::ToText;
 use risingwave_common::types::{
@@ -48,6 +48,7 @@ const PARSE_ERROR_STR_TO_TIME: &str =
     "Can't cast string to time (expected format is HH:MM:SS[.D+{up to 6 digits}] or HH:MM)";
 const PARSE_ERROR_STR_TO_DATE: &str = "Can't cast string to date (expected format is YYYY-MM-DD)";
 const PARSE_ERROR_STR_TO_BYTEA: &str = "Invalid Bytea syntax";
+const PARSE_ERROR_STR_TO_JSONB: &str = "Invalid Jsonb syntax";
 
 #[inline(always)]
 pub fn str_to_date(elem: &str) -> Result<NaiveDateWrapper> {
@@ -196,6 +197,12 @@ pub fn str_to_bytea(elem: &str) -> Result<Box<[u8]>> {
     }
 }
 
+#[inline(always)]
+pub fn str_to_jsonb(elem: &str) -> Result<JsonbVal> {
+    elem.parse()
+        .map_err(|_| ExprError::Parse(PARSE_ERROR_STR_TO_JSONB.into()))
+}
+
 // Refer to Materialize: https://github.com/MaterializeInc/materialize/blob/1766ab3978bc90abf75eb9b1fbadfcc95eca1993/src/repr/src/strconv.rs#L623
 pub fn parse_bytes_hex(s: &str) -> Result<Vec<u8>> {
     // Can't use `hex::decode` here, as it doesn't tolerate whitespace
@@ -428,6 +435,7 @@ pub fn literal_parsing(
         DataType::List { .. } => return Err(None),
         DataType::Struct(_) => return Err(None),
         DataType::Bytea => str_to_bytea(s)?.into(),
+        DataType::Jsonb => str_to_jsonb(s)?.into(),
     };
     Ok(scalar)
 }
@@ -455,6 +463,7 @@ macro_rules! for_all_cast_variants {
             { varchar, decimal, str_parse, false },
             { varchar, boolean, str_to_bool, false },
             { varchar, bytea, str_to_bytea, false },
+            { varchar, jsonb, str_to_jsonb, false },
             // `str_to_list` requires `target_elem_type` and is handled elsewhere
 
             { boolean, varchar, bool_to_varchar, false },
@@ -469,6 +478,7 @@ macro_rules! for_all_cast_variants {
             { date, varchar, general_to_text, false },
             { timestamp, varchar, general_to_text, false },
             { list, varchar, |x, w| general_to_text(x, w), false },
+            { jsonb, varchar, |x, w| general_to_text(x, w), false },
 
             { boolean, int32, try_cast, false },
             { int32, boolean, int32_to_bool, false },
@@ -173,6 +173,50 @@ pub fn str_is_not_distinct_from(l: Option<&str>, r: Option<&str>) -> Result<Opti
     }
 }
 
+pub fn struct_is_distinct_from(
+    l: Option<StructRef<'_>>,
+    r: Option<StructRef<'_>>,
+) -> Result<Option<bool>> {
+    match (l, r) {
+        (Some(lv), Some(rv)) => Ok(Some(lv != rv)),
+        (None, None) => Ok(Some(false)),
+        _ => Ok(Some(true)),
+    }
+}
+
+pub fn struct_is_not_distinct_from(
+    l: Option<StructRef<'_>>,
+    r: Option<StructRef<'_>>,
+) -> Result<Option<bool>> {
+    match (l, r) {
+        (Some(lv), Some(rv)) => Ok(Some(lv == rv)),
+        (None, None) => Ok(Some(true)),
+        _ => Ok(Some(false)),
+    }
+}
+
+pub fn list_is_distinct_from(
+    l: Option<ListRef<'_>>,
+    r: Option<ListRef<'_>>,
+) -> Result<Option<bool>> {
+    match (l, r) {
+        (Some(lv), Some(rv)) => Ok(Some(lv != rv)),
+        (None, None) => Ok(Some(false)),
+        _ => Ok(Some(true)),
+    }
+}
+
+pub fn list_is_not_distinct_from(
+    l: Option<ListRef<'_>>,
+    r: Option<ListRef<'_>>,
+) -> Result<Option<bool>> {
+    match (l, r) {
+        (Some(lv), Some(rv)) => Ok(Some(lv == rv)),
+        (None, None) => Ok(Some(true)),
+        _ => Ok(Some(false)),
+    }
+}
+
 #[inline(always)]
 pub fn is_true(v: Option<bool>) -> Option<bool> {
     Some(v == Some(true))
@@ -207,7 +251,8 @@ pub fn is_not_unknown(v: Option<bool>) -> Option<bool> {
 mod tests {
     use std::str::FromStr;
 
-    use risingwave_common::types::Decimal;
+    use risingwave_common::array::{ListValue, StructValue};
+    use risingwave_common::types::{Decimal, ScalarImpl};
 
     use super::*;
 
@@ -218,4 +263,31 @@ mod tests {
             1.1f32
         ))
     }
+
+    #[test]
+    fn test_nested_is_distinct_from() {
+        let lv = StructValue::new(vec![Some(ScalarImpl::Int32(1))]);
+        let rv = StructValue::new(vec![Some(ScalarImpl::Int32(2))]);
+        let l = StructRef::ValueRef { val: &lv };
+        let r = StructRef::ValueRef { val: &rv };
+        assert_eq!(struct_is_distinct_from(Some(l), Some(r)).unwrap(), Some(true));
+        assert_eq!(struct_is_distinct_from(Some(l), None).unwrap(), Some(true));
+        assert_eq!(struct_is_distinct_from(None, None).unwrap(), Some(false));
+        assert_eq!(
+            struct_is_not_distinct_from(Some(l), Some(l)).unwrap(),
+            Some(true)
+        );
+
+        let lv = ListValue::new(vec![Some(ScalarImpl::Int32(1))]);
+        let rv = ListValue::new(vec![None]);
+        let l = ListRef::ValueRef { val: &lv };
+        let r = ListRef::ValueRef { val: &rv };
+        assert_eq!(list_is_distinct_from(Some(l), Some(r)).unwrap(), Some(true));
+        assert_eq!(list_is_distinct_from(None, Some(r)).unwrap(), Some(true));
+        assert_eq!(list_is_not_distinct_from(None, None).unwrap(), Some(true));
+        assert_eq!(
+            list_is_not_distinct_from(Some(l), Some(l)).unwrap(),
+            Some(true)
+        );
+    }
 }
@@ -0,0 +1,193 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::array::{JsonbRef, JsonbVal, ListRef};
+use risingwave_common::types::{ScalarRef, ScalarRefImpl};
+
+use crate::Result;
+
+/// `jsonb -> 'key'`: returns the field of the json object with the given key.
+#[inline(always)]
+pub fn jsonb_object_field(l: Option<JsonbRef<'_>>, r: Option<&str>) -> Result<Option<JsonbVal>> {
+    Ok(match (l, r) {
+        (Some(v), Some(f)) => v.access_object_field(f).map(|v| v.to_owned_scalar()),
+        _ => None,
+    })
+}
+
+/// `jsonb -> 1`: returns the element of the json array at the given index.
+#[inline(always)]
+pub fn jsonb_array_element(l: Option<JsonbRef<'_>>, r: Option<i32>) -> Result<Option<JsonbVal>> {
+    Ok(match (l, r) {
+        (Some(v), Some(i)) => v.access_array_element(i).map(|v| v.to_owned_scalar()),
+        _ => None,
+    })
+}
+
+/// `jsonb ->> 'key'`: like `->`, but returns the result as text.
+#[inline(always)]
+pub fn jsonb_object_field_str(l: Option<JsonbRef<'_>>, r: Option<&str>) -> Result<Option<Box<str>>> {
+    Ok(match (l, r) {
+        (Some(v), Some(f)) => v.access_object_field(f).and_then(jsonb_to_text),
+        _ => None,
+    })
+}
+
+/// `jsonb ->> 1`: like `->`, but returns the result as text.
+#[inline(always)]
+pub fn jsonb_array_element_str(
+    l: Option<JsonbRef<'_>>,
+    r: Option<i32>,
+) -> Result<Option<Box<str>>> {
+    Ok(match (l, r) {
+        (Some(v), Some(i)) => v.access_array_element(i).and_then(jsonb_to_text),
+        _ => None,
+    })
+}
+
+/// `jsonb #> '{a, 1}'` and `jsonb_extract_path`: extracts the json value at the given path, where
+/// each path element is either an object key or the text form of an array index.
+pub fn jsonb_extract_path(
+    l: Option<JsonbRef<'_>>,
+    r: Option<ListRef<'_>>,
+) -> Result<Option<JsonbVal>> {
+    let (Some(v), Some(path)) = (l, r) else {
+        return Ok(None);
+    };
+    let mut cur = v;
+    for elem in path.values_ref() {
+        let Some(ScalarRefImpl::Utf8(key)) = elem else {
+            // A null path element never matches anything, following PostgreSQL.
+            return Ok(None);
+        };
+        let next = cur.access_object_field(key).or_else(|| {
+            key.parse::<i32>()
+                .ok()
+                .and_then(|i| cur.access_array_element(i))
+        });
+        match next {
+            Some(next) => cur = next,
+            None => return Ok(None),
+        }
+    }
+    Ok(Some(cur.to_owned_scalar()))
+}
+
+/// `jsonb @> jsonb`: whether the left json value contains the right one.
+#[inline(always)]
+pub fn jsonb_contains(l: Option<JsonbRef<'_>>, r: Option<JsonbRef<'_>>) -> Result<Option<bool>> {
+    Ok(match (l, r) {
+        (Some(l), Some(r)) => Some(l.jsonb_contains(&r)),
+        _ => None,
+    })
+}
+
+/// A json `null` maps to a `NULL` datum when extracting text, while all other json values map
+/// to their (unquoted) text representation.
+fn jsonb_to_text(v: JsonbRef<'_>) -> Option<Box<str>> {
+    if v.is_jsonb_null() {
+        None
+    } else {
+        Some(v.force_str().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::types::Scalar;
+
+    use super::*;
+
+    #[test]
+    fn test_jsonb_access() {
+        let v: JsonbVal = r#"{"a": {"b": ["apple", 1]}}"#.parse().unwrap();
+        let a = jsonb_object_field(Some(v.as_scalar_ref()), Some("a"))
+            .unwrap()
+            .unwrap();
+        let b = jsonb_object_field(Some(a.as_scalar_ref()), Some("b"))
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            jsonb_array_element(Some(b.as_scalar_ref()), Some(0)).unwrap(),
+            Some(r#""apple""#.parse().unwrap())
+        );
+        assert_eq!(
+            jsonb_array_element_str(Some(b.as_scalar_ref()), Some(0)).unwrap(),
+            Some("apple".into())
+        );
+        assert_eq!(
+            jsonb_object_field(Some(v.as_scalar_ref()), Some("b")).unwrap(),
+            None
+        );
+        assert_eq!(jsonb_object_field(None, Some("a")).unwrap(), None);
+    }
+
+    #[test]
+    fn test_jsonb_access_str_of_null() {
+        let v: JsonbVal = r#"{"a": null}"#.parse().unwrap();
+        assert_eq!(
+            jsonb_object_field(Some(v.as_scalar_ref()), Some("a")).unwrap(),
+            Some(JsonbVal::null())
+        );
+        assert_eq!(
+            jsonb_object_field_str(Some(v.as_scalar_ref()), Some("a")).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_jsonb_extract_path() {
+        use risingwave_common::array::ListValue;
+        use risingwave_common::types::ScalarImpl;
+
+        let v: JsonbVal = r#"{"a": {"b": [42]}}"#.parse().unwrap();
+        let path = ListValue::new(vec![
+            Some(ScalarImpl::Utf8("a".into())),
+            Some(ScalarImpl::Utf8("b".into())),
+            Some(ScalarImpl::Utf8("0".into())),
+        ]);
+        assert_eq!(
+            jsonb_extract_path(
+                Some(v.as_scalar_ref()),
+                Some(ListRef::ValueRef { val: &path })
+            )
+            .unwrap(),
+            Some("42".parse().unwrap())
+        );
+
+        let missing = ListValue::new(vec![Some(ScalarImpl::Utf8("c".into()))]);
+        assert_eq!(
+            jsonb_extract_path(
+                Some(v.as_scalar_ref()),
+                Some(ListRef::ValueRef { val: &missing })
+            )
+            .unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_jsonb_contains() {
+        let l: JsonbVal = r#"{"a": 1, "b": 2}"#.parse().unwrap();
+        let r: JsonbVal = r#"{"b": 2}"#.parse().unwrap();
+        assert_eq!(
+            jsonb_contains(Some(l.as_scalar_ref()), Some(r.as_scalar_ref())).unwrap(),
+            Some(true)
+        );
+        assert_eq!(
+            jsonb_contains(Some(r.as_scalar_ref()), Some(l.as_scalar_ref())).unwrap(),
+            Some(false)
+        );
+    }
+}
@@ -24,6 +24,7 @@ pub mod conjunction;
 pub mod date_trunc;
 pub mod extract;
 pub mod format_type;
+pub mod jsonb;
 pub mod length;
 pub mod like;
 pub mod lower;
@@ -39,6 +40,7 @@ pub mod split_part;
 pub mod substr;
 pub mod timestamptz;
 pub mod to_char;
+pub mod to_json;
 pub mod to_timestamp;
 pub mod translate;
 pub mod trim;
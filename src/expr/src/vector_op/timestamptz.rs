@@ -18,6 +18,7 @@ use chrono::{TimeZone, Utc};
 use chrono_tz::Tz;
 use num_traits::ToPrimitive;
 use risingwave_common::types::{NaiveDateTimeWrapper, OrderedF64};
+use risingwave_common::util::epoch::Epoch;
 
 use crate::vector_op::cast::{str_to_timestamp, str_with_time_zone_to_timestamptz};
 use crate::{ExprError, Result};
@@ -40,6 +41,12 @@ pub fn f64_sec_to_timestamptz(elem: OrderedF64) -> Result<i64> {
         .ok_or(ExprError::NumericOutOfRange)
 }
 
+/// Maps a hummock epoch to the wall-clock time it represents, in usecs since the UNIX epoch.
+#[inline(always)]
+pub fn epoch_to_timestamptz(elem: i64) -> Result<i64> {
+    Ok(Epoch(elem as u64).as_unix_millis() as i64 * 1000)
+}
+
 #[inline(always)]
 pub fn timestamp_at_time_zone(input: NaiveDateTimeWrapper, time_zone: &str) -> Result<i64> {
     let time_zone = lookup_time_zone(time_zone)?;
@@ -0,0 +1,213 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Write;
+
+use anyhow::anyhow;
+use risingwave_common::array::{ListRef, StructRef};
+use risingwave_common::types::struct_type::StructType;
+use risingwave_common::types::to_text::ToText;
+use risingwave_common::types::{DataType, DatumRef, Decimal, ScalarRefImpl};
+use risingwave_common::util::iter_util::ZipEqFast;
+
+use crate::Result;
+
+/// Append the JSON text representation of `datum` to `output`, driven by `data_type`.
+///
+/// Scalar types follow the PostgreSQL `to_json` conventions: booleans and finite numbers map to
+/// the corresponding JSON values, and everything else is rendered as a JSON string of its text
+/// representation. Structs become JSON objects and lists become JSON arrays, recursively.
+pub fn datum_to_json(datum: DatumRef<'_>, data_type: &DataType, output: &mut String) -> Result<()> {
+    let Some(value) = datum else {
+        output.push_str("null");
+        return Ok(());
+    };
+    match (data_type, value) {
+        (DataType::Boolean, ScalarRefImpl::Bool(v)) => {
+            output.push_str(if v { "true" } else { "false" })
+        }
+        (DataType::Int16, v @ ScalarRefImpl::Int16(_))
+        | (DataType::Int32, v @ ScalarRefImpl::Int32(_))
+        | (DataType::Int64, v @ ScalarRefImpl::Int64(_)) => output.push_str(&v.to_text()),
+        (DataType::Float32, ScalarRefImpl::Float32(v)) => {
+            write_json_number(v.is_finite(), &v.to_text(), output)
+        }
+        (DataType::Float64, ScalarRefImpl::Float64(v)) => {
+            write_json_number(v.is_finite(), &v.to_text(), output)
+        }
+        (DataType::Decimal, ScalarRefImpl::Decimal(v)) => {
+            write_json_number(matches!(v, Decimal::Normalized(_)), &v.to_text(), output)
+        }
+        (DataType::Varchar, ScalarRefImpl::Utf8(v)) => write_json_string(v, output),
+        // Already a json value, emit its text representation as-is.
+        (DataType::Jsonb, ScalarRefImpl::Jsonb(v)) => write!(output, "{}", v).unwrap(),
+        (DataType::Struct(ty), ScalarRefImpl::Struct(v)) => struct_to_json(v, ty, output)?,
+        (DataType::List { datatype }, ScalarRefImpl::List(v)) => {
+            list_to_json(v, datatype, output)?
+        }
+        // All remaining types (dates, timestamps, intervals, bytea, ...) are rendered as JSON
+        // strings of their text representation, like in PostgreSQL.
+        (
+            ty @ (DataType::Date
+            | DataType::Time
+            | DataType::Timestamp
+            | DataType::Timestamptz
+            | DataType::Interval
+            | DataType::Bytea),
+            v,
+        ) => write_json_string(&v.to_text_with_type(ty), output),
+        (ty, v) => return Err(anyhow!("cannot convert {:?} of type {:?} to json", v, ty).into()),
+    }
+    Ok(())
+}
+
+/// Convert a struct into a JSON object, keyed by the field names. Unnamed fields follow the
+/// PostgreSQL convention of `f1`, `f2`, ...
+fn struct_to_json(input: StructRef<'_>, ty: &StructType, output: &mut String) -> Result<()> {
+    output.push('{');
+    for (i, (datum_ref, field_type)) in input
+        .fields_ref()
+        .into_iter()
+        .zip_eq_fast(ty.fields.iter())
+        .enumerate()
+    {
+        if i > 0 {
+            output.push(',');
+        }
+        match ty.field_names.get(i) {
+            Some(name) if !name.is_empty() => write_json_string(name, output),
+            _ => write_json_string(&format!("f{}", i + 1), output),
+        }
+        output.push(':');
+        datum_to_json(datum_ref, field_type, output)?;
+    }
+    output.push('}');
+    Ok(())
+}
+
+/// Convert a list into a JSON array by converting each element.
+fn list_to_json(input: ListRef<'_>, elem_type: &DataType, output: &mut String) -> Result<()> {
+    output.push('[');
+    for (i, datum_ref) in input.values_ref().into_iter().enumerate() {
+        if i > 0 {
+            output.push(',');
+        }
+        datum_to_json(datum_ref, elem_type, output)?;
+    }
+    output.push(']');
+    Ok(())
+}
+
+/// Append `text` as a JSON number if it is a valid one, otherwise as a JSON string. `NaN` and
+/// `Infinity` are not valid JSON numbers, so they are quoted like in PostgreSQL.
+fn write_json_number(valid: bool, text: &str, output: &mut String) {
+    if valid {
+        output.push_str(text);
+    } else {
+        write_json_string(text, output);
+    }
+}
+
+/// Append `s` as a JSON string literal, escaping characters as required by RFC 8259.
+fn write_json_string(s: &str, output: &mut String) {
+    output.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\x08' => output.push_str("\\b"),
+            '\x0c' => output.push_str("\\f"),
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            '\t' => output.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(output, "\\u{:04x}", c as u32).unwrap(),
+            c => output.push(c),
+        }
+    }
+    output.push('"');
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use risingwave_common::array::{ListValue, StructValue};
+    use risingwave_common::types::{DataType, Datum, ScalarImpl};
+
+    use super::*;
+
+    fn to_json(datum: Datum, data_type: &DataType) -> String {
+        let mut output = String::new();
+        datum_to_json(
+            datum.as_ref().map(|d| d.as_scalar_ref_impl()),
+            data_type,
+            &mut output,
+        )
+        .unwrap();
+        output
+    }
+
+    #[test]
+    fn test_scalar_to_json() {
+        assert_eq!(to_json(None, &DataType::Int32), "null");
+        assert_eq!(to_json(Some(ScalarImpl::Bool(true)), &DataType::Boolean), "true");
+        assert_eq!(to_json(Some(ScalarImpl::Int32(-42)), &DataType::Int32), "-42");
+        assert_eq!(
+            to_json(Some(ScalarImpl::Float64(2.5.into())), &DataType::Float64),
+            "2.5"
+        );
+        assert_eq!(
+            to_json(Some(ScalarImpl::Float64(f64::NAN.into())), &DataType::Float64),
+            "\"NaN\""
+        );
+        assert_eq!(
+            to_json(Some(ScalarImpl::Utf8("a\"b\nc".into())), &DataType::Varchar),
+            "\"a\\\"b\\nc\""
+        );
+        assert_eq!(
+            to_json(Some(ScalarImpl::Int64(0)), &DataType::Timestamptz),
+            "\"1970-01-01 00:00:00+00:00\""
+        );
+    }
+
+    #[test]
+    fn test_composite_to_json() {
+        let struct_type = StructType::new(vec![
+            (DataType::Int32, "id".to_string()),
+            (DataType::Varchar, "name".to_string()),
+        ]);
+        let struct_value = StructValue::new(vec![
+            Some(ScalarImpl::Int32(1)),
+            Some(ScalarImpl::Utf8("foo".into())),
+        ]);
+        assert_eq!(
+            to_json(
+                Some(ScalarImpl::Struct(struct_value)),
+                &DataType::Struct(Arc::new(struct_type))
+            ),
+            r#"{"id":1,"name":"foo"}"#
+        );
+
+        let list_value = ListValue::new(vec![Some(ScalarImpl::Int32(1)), None]);
+        assert_eq!(
+            to_json(
+                Some(ScalarImpl::List(list_value)),
+                &DataType::List {
+                    datatype: Box::new(DataType::Int32)
+                }
+            ),
+            "[1,null]"
+        );
+    }
+}
@@ -109,6 +109,10 @@ impl Binder {
                 func_types.push(ExprType::IsNull);
                 ExprType::RegexpMatch
             }
+            BinaryOperator::Arrow => ExprType::JsonbAccessInner,
+            BinaryOperator::LongArrow => ExprType::JsonbAccessStr,
+            BinaryOperator::HashArrow => ExprType::JsonbExtractPath,
+            BinaryOperator::Contains => ExprType::JsonbContains,
             _ => {
                 return Err(
                     ErrorCode::NotImplemented(format!("binary op: {:?}", op), 112.into()).into(),
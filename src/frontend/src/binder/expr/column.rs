@@ -72,6 +72,22 @@ impl Binder {
             }
         }
 
+        // Try to find the column in a visible lateral context, i.e. the columns of the preceding
+        // `FROM` items when binding the arguments of a lateral table function. Since lateral
+        // contexts are merged cumulatively, the column index is already valid in the joined
+        // schema, so a plain `InputRef` suffices.
+        for lateral_context in self.lateral_contexts.iter().rev() {
+            if lateral_context.is_visible {
+                if let Ok(index) = lateral_context
+                    .context
+                    .get_column_binding_index(&table_name, &column_name)
+                {
+                    let column = &lateral_context.context.columns[index];
+                    return Ok(InputRef::new(column.index, column.field.data_type.clone()).into());
+                }
+            }
+        }
+
         // Try to find a correlated column in `upper_contexts`, starting from the innermost context.
         let mut err = ErrorCode::ItemNotFound(format!("Invalid column: {}", column_name));
         for (i, (context, _)) in self.upper_subquery_contexts.iter().rev().enumerate() {
@@ -23,7 +23,7 @@ use risingwave_common::array::ListValue;
 use risingwave_common::catalog::PG_CATALOG_SCHEMA_NAME;
 use risingwave_common::error::{ErrorCode, Result};
 use risingwave_common::session_config::USER_NAME_WILD_CARD;
-use risingwave_common::types::{DataType, ScalarImpl};
+use risingwave_common::types::{DataType, Decimal, ScalarImpl};
 use risingwave_common::{GIT_SHA, RW_VERSION};
 use risingwave_expr::expr::AggKind;
 use risingwave_sqlparser::ast::{Function, FunctionArg, FunctionArgExpr, WindowSpec};
@@ -128,6 +128,20 @@ impl Binder {
             .map(|arg| self.bind_function_arg(arg))
             .flatten_ok()
             .try_collect()?;
+        if kind == AggKind::ApproxTopK {
+            // The `k` argument decides the size of the sketch, so it must be a positive constant.
+            let valid_k = inputs.get(1).and_then(|k| k.as_literal()).map_or(
+                false,
+                |k| matches!(k.get_data(), Some(ScalarImpl::Int32(v)) if *v > 0),
+            );
+            if !valid_k {
+                return Err(ErrorCode::InvalidInputSyntax(
+                    "the second argument of approx_top_k must be a positive integer constant"
+                        .to_string(),
+                )
+                .into());
+            }
+        }
         if f.distinct {
             match &kind {
                 AggKind::Count if inputs.is_empty() => {
@@ -190,7 +204,9 @@ impl Binder {
             None => Condition::true_cond(),
         };
 
-        if f.distinct && !f.order_by.is_empty() {
+        // `string_agg` deduplicates on its materialized input, so it supports DISTINCT and
+        // ORDER BY at the same time, while other aggregates don't.
+        if f.distinct && !f.order_by.is_empty() && kind != AggKind::StringAgg {
             // <https://www.postgresql.org/docs/current/sql-expressions.html#SYNTAX-AGGREGATES:~:text=the%20DISTINCT%20list.-,Note,-The%20ability%20to>
             return Err(ErrorCode::InvalidInputSyntax(
                 "DISTINCT and ORDER BY are not supported to appear at the same time now"
@@ -204,6 +220,98 @@ impl Binder {
                 .map(|e| self.bind_order_by_expr(e))
                 .try_collect()?,
         );
+
+        // The value argument of `approx_percentile` is interpolated from a t-digest sketch, so
+        // it must be castable to `double precision`; the fraction must be a constant, and the
+        // optional compression factor decides the sketch size.
+        let inputs = if kind == AggKind::ApproxPercentile {
+            if inputs.len() != 2 && inputs.len() != 3 {
+                return Err(ErrorCode::InvalidInputSyntax(
+                    "approx_percentile takes a value, a constant fraction and an optional \
+                     constant compression factor"
+                        .to_string(),
+                )
+                .into());
+            }
+            let fraction_valid = inputs[1].as_literal().map_or(false, |literal| {
+                match literal.get_data() {
+                    Some(ScalarImpl::Decimal(v)) => {
+                        (Decimal::from(0)..=Decimal::from(1)).contains(v)
+                    }
+                    Some(ScalarImpl::Float64(v)) => (0.0..=1.0).contains(&v.0),
+                    Some(ScalarImpl::Int32(v)) => (0..=1).contains(v),
+                    _ => false,
+                }
+            });
+            if !fraction_valid {
+                return Err(ErrorCode::InvalidInputSyntax(
+                    "the second argument of approx_percentile must be a constant fraction \
+                     between 0 and 1"
+                        .to_string(),
+                )
+                .into());
+            }
+            let compression_valid = inputs.get(2).map_or(true, |compression| {
+                compression.as_literal().map_or(false, |literal| {
+                    matches!(literal.get_data(), Some(ScalarImpl::Int32(v)) if *v > 0)
+                })
+            });
+            if !compression_valid {
+                return Err(ErrorCode::InvalidInputSyntax(
+                    "the third argument of approx_percentile must be a positive integer constant"
+                        .to_string(),
+                )
+                .into());
+            }
+            let mut inputs = inputs.into_iter();
+            let value = inputs.next().unwrap().cast_implicit(DataType::Float64)?;
+            let fraction = inputs.next().unwrap().cast_implicit(DataType::Float64)?;
+            [value, fraction].into_iter().chain(inputs).collect()
+        } else {
+            inputs
+        };
+
+        // For ordered-set aggregates, the only argument is the constant fraction, and the value
+        // to aggregate is the single `WITHIN GROUP (ORDER BY ...)` expression, which is appended
+        // as the second argument here.
+        let inputs = if matches!(kind, AggKind::PercentileCont | AggKind::PercentileDisc) {
+            let fraction_valid = inputs.len() == 1
+                && inputs[0].as_literal().map_or(false, |literal| {
+                    match literal.get_data() {
+                        Some(ScalarImpl::Decimal(v)) => {
+                            (Decimal::from(0)..=Decimal::from(1)).contains(v)
+                        }
+                        Some(ScalarImpl::Float64(v)) => (0.0..=1.0).contains(&v.0),
+                        Some(ScalarImpl::Int32(v)) => (0..=1).contains(v),
+                        _ => false,
+                    }
+                });
+            if !fraction_valid {
+                return Err(ErrorCode::InvalidInputSyntax(format!(
+                    "the argument of {} must be a constant fraction between 0 and 1",
+                    kind
+                ))
+                .into());
+            }
+            let [order_by_expr] = order_by.sort_exprs.as_slice() else {
+                return Err(ErrorCode::InvalidInputSyntax(format!(
+                    "{} requires a WITHIN GROUP (ORDER BY ...) clause with exactly one expression",
+                    kind
+                ))
+                .into());
+            };
+            let fraction = inputs.into_iter().next().unwrap();
+            let mut value = order_by_expr.expr.clone();
+            if kind == AggKind::PercentileCont {
+                // `percentile_cont` interpolates between adjacent values, so it only works on
+                // types castable to `double precision`.
+                value = value.cast_implicit(DataType::Float64)?;
+            }
+            vec![fraction.cast_implicit(DataType::Float64)?, value]
+        } else {
+            inputs
+        };
+
         Ok(ExprImpl::AggCall(Box::new(AggCall::new(
             kind, inputs, f.distinct, order_by, filter,
         )?)))
@@ -370,6 +478,10 @@ impl Binder {
                 ("array_cat", raw_call(ExprType::ArrayCat)),
                 ("array_append", raw_call(ExprType::ArrayAppend)),
                 ("array_prepend", raw_call(ExprType::ArrayPrepend)),
+                // json
+                ("to_json", raw_call(ExprType::ToJson)),
+                ("row_to_json", raw_call(ExprType::ToJson)),
+                ("jsonb_extract_path", raw_call(ExprType::JsonbExtractPath)),
                 // System information operations.
                 (
                     "pg_typeof",
@@ -500,6 +612,13 @@ impl Binder {
                 }))),
                 // internal
                 ("rw_vnode", raw_call(ExprType::Vnode)),
+                ("rw_epoch_to_ts", raw_call(ExprType::RwEpochToTs)),
+                ("rw_current_epoch", guard_by_len(0, raw(|binder, _inputs| {
+                    Ok(ExprImpl::from(Literal::new(
+                        Some(ScalarImpl::Int64(binder.bind_epoch as i64)),
+                        DataType::Int64,
+                    )))
+                }))),
                 // TODO: choose which pg version we should return.
                 ("version", raw_literal(ExprImpl::literal_varchar(format!(
                     "PostgreSQL 13.9-RisingWave-{} ({})",
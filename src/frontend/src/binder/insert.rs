@@ -48,6 +48,11 @@ pub struct BoundInsert {
     /// Empty if user does not define insert columns
     pub column_indices: Vec<usize>,
 
+    /// Positions of the distribution key columns of the target table in the insert source, used
+    /// by the optimizer to shard parallel DML writes by vnode ownership. Empty when the table is
+    /// distributed by the hidden row id.
+    pub dist_key_indices: Vec<usize>,
+
     pub source: BoundQuery,
 
     /// Used as part of an extra `Project` when the column types of the query does not match
@@ -84,6 +89,7 @@ impl Binder {
             .filter(|c| !c.is_hidden())
             .collect_vec();
         let row_id_index = table_catalog.row_id_index;
+        let distribution_key = table_catalog.distribution_key.clone();
 
         let expected_types: Vec<DataType> = columns_to_insert
             .iter()
@@ -218,12 +224,31 @@ impl Binder {
             )));
         }
 
+        // Tables without a user-defined primary key are distributed by the hidden row id, which
+        // is only generated downstream; their rows may be written from any shard.
+        let dist_key_indices = if row_id_index.is_some() {
+            vec![]
+        } else {
+            distribution_key
+                .iter()
+                .map(|&key| {
+                    if target_table_col_indices.is_empty() {
+                        Some(key)
+                    } else {
+                        target_table_col_indices.iter().position(|&col| col == key)
+                    }
+                })
+                .collect::<Option<Vec<_>>>()
+                .unwrap_or_default()
+        };
+
         let insert = BoundInsert {
             table_id,
             table_name,
             owner,
             row_id_index,
             column_indices: target_table_col_indices,
+            dist_key_indices,
             source,
             cast_exprs,
             returning_list,
@@ -51,6 +51,7 @@ pub use update::BoundUpdate;
 pub use values::BoundValues;
 
 use crate::catalog::catalog_service::CatalogReadGuard;
+use crate::catalog::federation::RemoteClusterCatalog;
 use crate::catalog::ViewId;
 use crate::session::{AuthContext, SessionImpl};
 
@@ -65,6 +66,9 @@ pub struct Binder {
     context: BindContext,
     auth_context: Arc<AuthContext>,
     bind_timestamp_ms: u64,
+    /// The hummock epoch of the latest snapshot at bind time, i.e. the snapshot the query is
+    /// expected to read from.
+    bind_epoch: u64,
     /// A stack holding contexts of outer queries when binding a subquery.
     /// It also holds all of the lateral contexts for each respective
     /// subquery.
@@ -90,15 +94,19 @@ pub struct Binder {
 
     /// `ShareId`s identifying shared views.
     shared_views: HashMap<ViewId, ShareId>,
+
+    /// Catalogs of remote clusters mounted read-only, keyed by cluster name. Used to resolve
+    /// `cluster.schema.object` references.
+    remote_catalogs: Arc<HashMap<String, Arc<RemoteClusterCatalog>>>,
 }
 
 impl Binder {
     fn new_inner(session: &SessionImpl, in_create_mv: bool) -> Binder {
-        let now_ms = session
+        let bind_epoch = session
             .env()
             .hummock_snapshot_manager()
-            .latest_snapshot_current_epoch()
-            .as_unix_millis();
+            .latest_snapshot_current_epoch();
+        let now_ms = bind_epoch.as_unix_millis();
         Binder {
             catalog: session.env().catalog_reader().read_guard(),
             db_name: session.database().to_string(),
@@ -106,6 +114,7 @@ impl Binder {
             context: BindContext::new(),
             auth_context: session.auth_context(),
             bind_timestamp_ms: now_ms,
+            bind_epoch: bind_epoch.0,
             upper_subquery_contexts: vec![],
             lateral_contexts: vec![],
             next_subquery_id: 0,
@@ -114,6 +123,7 @@ impl Binder {
             search_path: session.config().get_search_path(),
             in_create_mv,
             shared_views: HashMap::new(),
+            remote_catalogs: session.env().remote_catalogs().clone(),
         }
     }
 
@@ -83,6 +83,27 @@ impl Relation {
         }
     }
 
+    /// Whether the relation references a table of a mounted remote cluster. Such queries must
+    /// run in distributed mode so that the scan stages can be scheduled onto the remote
+    /// compute nodes.
+    pub fn contains_remote_table(&self) -> bool {
+        match self {
+            Relation::BaseTable(t) => t.remote_cluster.is_some(),
+            Relation::Subquery(s) => {
+                if let BoundSetExpr::Select(select) = &s.query.body
+                    && let Some(relation) = &select.from {
+                    relation.contains_remote_table()
+                } else {
+                    false
+                }
+            }
+            Relation::Join(j) => {
+                j.left.contains_remote_table() || j.right.contains_remote_table()
+            }
+            _ => false,
+        }
+    }
+
     pub fn is_correlated(&self, depth: Depth) -> bool {
         match self {
             Relation::Subquery(subquery) => subquery.query.is_correlated(depth),
@@ -259,6 +280,17 @@ impl Binder {
         name: ObjectName,
         alias: Option<TableAlias>,
     ) -> Result<Relation> {
+        // A three-part name whose first part is a mounted remote cluster refers to an object of
+        // that cluster, unless the cluster shares its name with the current database.
+        if let [cluster, schema, table] = name.0.as_slice() {
+            let cluster_name = cluster.real_value();
+            if cluster_name != self.db_name && self.remote_catalogs.contains_key(&cluster_name) {
+                let schema_name = schema.real_value();
+                let table_name = table.real_value();
+                return self.bind_remote_table(&cluster_name, &schema_name, &table_name, alias);
+            }
+        }
+
         let (schema_name, table_name) = Self::resolve_schema_qualified_name(&self.db_name, name)?;
         if schema_name.is_none() && let Some(item) = self.context.cte_to_relation.get(&table_name) {
             // Handles CTE
@@ -380,11 +412,15 @@ impl Binder {
                         alias,
                     )
                 } else if let Ok(table_function_type) = TableFunctionType::from_str(func_name) {
+                    // Table functions are implicitly lateral in PostgreSQL: their arguments may
+                    // reference columns of the preceding `FROM` items.
+                    self.try_mark_lateral_as_visible();
                     let args: Vec<ExprImpl> = args
                         .into_iter()
                         .map(|arg| self.bind_function_arg(arg))
                         .flatten_ok()
                         .try_collect()?;
+                    self.try_mark_lateral_as_invisible();
                     let tf = TableFunction::new(table_function_type, args)?;
                     let columns = [(
                         false,
@@ -16,7 +16,7 @@ use std::ops::Deref;
 use std::sync::Arc;
 
 use itertools::Itertools;
-use risingwave_common::catalog::{Field, SYSTEM_SCHEMAS};
+use risingwave_common::catalog::{Field, DEFAULT_DATABASE_NAME, SYSTEM_SCHEMAS};
 use risingwave_common::error::{ErrorCode, Result, RwError};
 use risingwave_common::session_config::USER_NAME_WILD_CARD;
 use risingwave_sqlparser::ast::{Statement, TableAlias};
@@ -37,6 +37,9 @@ pub struct BoundBaseTable {
     pub table_id: TableId,
     pub table_catalog: TableCatalog,
     pub table_indexes: Vec<Arc<IndexCatalog>>,
+    /// Name of the mounted remote cluster the table was resolved from, or `None` for a local
+    /// table. See [`crate::catalog::federation`].
+    pub remote_cluster: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -208,11 +211,60 @@ impl Binder {
             table_id,
             table_catalog,
             table_indexes,
+            remote_cluster: None,
         };
 
         Ok::<_, RwError>((Relation::BaseTable(Box::new(table)), columns))
     }
 
+    /// Binds a table or materialized view of a mounted remote cluster, referenced as
+    /// `cluster.schema.object`. The object is resolved in the remote cluster's default
+    /// database. Indexes of remote tables are not used for planning, and other remote objects
+    /// (sources, views) cannot be referenced.
+    pub(crate) fn bind_remote_table(
+        &mut self,
+        cluster_name: &str,
+        schema_name: &str,
+        table_name: &str,
+        alias: Option<TableAlias>,
+    ) -> Result<Relation> {
+        if self.in_create_mv {
+            return Err(ErrorCode::BindError(
+                "streaming jobs on tables of a remote cluster are not supported".to_string(),
+            )
+            .into());
+        }
+
+        let remote = self.remote_catalogs.get(cluster_name).unwrap();
+        let (table_catalog, _) = remote
+            .catalog()
+            .get_table_by_name(DEFAULT_DATABASE_NAME, SchemaPath::Name(schema_name), table_name)
+            .map_err(|_| {
+                CatalogError::NotFound(
+                    "table or materialized view in remote cluster",
+                    format!("{}.{}.{}", cluster_name, schema_name, table_name),
+                )
+            })?;
+        let table_catalog = table_catalog.deref().clone();
+
+        let table_id = table_catalog.id();
+        let columns = table_catalog
+            .columns
+            .iter()
+            .map(|c| (c.is_hidden, Field::from(&c.column_desc)))
+            .collect_vec();
+
+        let table = BoundBaseTable {
+            table_id,
+            table_catalog,
+            table_indexes: vec![],
+            remote_cluster: Some(cluster_name.to_string()),
+        };
+
+        self.bind_table_to_context(columns, table_name.to_string(), alias)?;
+        Ok(Relation::BaseTable(Box::new(table)))
+    }
+
     fn resolve_view_relation(
         &mut self,
         view_catalog: &ViewCatalog,
@@ -291,6 +343,7 @@ impl Binder {
             table_id,
             table_catalog,
             table_indexes,
+            remote_cluster: None,
         })
     }
 
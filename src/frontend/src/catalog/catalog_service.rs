@@ -22,7 +22,7 @@ use risingwave_common::error::{Result, RwError};
 use risingwave_pb::catalog::{
     Database as ProstDatabase, Function as ProstFunction, Index as ProstIndex,
     Schema as ProstSchema, Sink as ProstSink, Source as ProstSource, Table as ProstTable,
-    View as ProstView,
+    TableStats as ProstTableStats, View as ProstView,
 };
 use risingwave_pb::stream_plan::StreamFragmentGraph;
 use risingwave_rpc_client::MetaClient;
@@ -108,6 +108,8 @@ pub trait CatalogWriter: Send + Sync {
     async fn drop_index(&self, index_id: IndexId) -> Result<()>;
 
     async fn drop_function(&self, function_id: FunctionId) -> Result<()>;
+
+    async fn update_table_stats(&self, table_id: TableId, stats: ProstTableStats) -> Result<()>;
 }
 
 #[derive(Clone)]
@@ -245,6 +247,14 @@ impl CatalogWriter for CatalogWriterImpl {
         let version = self.meta_client.drop_database(database_id).await?;
         self.wait_version(version).await
     }
+
+    async fn update_table_stats(&self, table_id: TableId, stats: ProstTableStats) -> Result<()> {
+        let version = self
+            .meta_client
+            .update_table_stats(table_id.table_id, stats)
+            .await?;
+        self.wait_version(version).await
+    }
 }
 
 impl CatalogWriterImpl {
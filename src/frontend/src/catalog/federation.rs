@@ -0,0 +1,265 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Read-only federation of remote RisingWave clusters' catalogs.
+//!
+//! A frontend can mount the catalog of another cluster via its meta endpoint with the
+//! `--mount-remote-catalog` option. The remote catalog is fetched once at mount time and kept
+//! read-only: no DDL or DML against the remote cluster is possible through the mount. This is
+//! useful for splitting ingestion and serving clusters, where the serving cluster's frontends
+//! reference MVs maintained by the ingestion cluster.
+//!
+//! Remote tables and materialized views are referenced as `cluster.schema.object` and can only
+//! be queried in distributed mode: the scan stages of such a query are scheduled onto the
+//! remote cluster's compute nodes according to the remote vnode mapping, and their output is
+//! fetched over the regular exchange. Each query reads the remote cluster's committed epoch as
+//! of scheduling time without pinning it, so a long-running query may fail if the remote
+//! cluster reclaims the epoch meanwhile. Indexes of remote tables are not used for planning.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use risingwave_common::bail;
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_common::hash::{ParallelUnitId, ParallelUnitMapping};
+use risingwave_common::util::addr::HostAddr;
+use risingwave_common::util::worker_util::get_pu_to_worker_mapping;
+use risingwave_pb::common::{WorkerNode, WorkerType};
+use risingwave_pb::meta::subscribe_response::Info;
+use risingwave_pb::meta::SubscribeType;
+use risingwave_rpc_client::{HummockMetaClient, MetaClient};
+use tokio::sync::oneshot::Sender;
+use tokio::task::JoinHandle;
+
+use crate::catalog::root_catalog::Catalog;
+use crate::catalog::FragmentId;
+
+/// A read-only snapshot of a remote cluster's catalog, fetched from its meta node at mount
+/// time.
+///
+/// The remote compute nodes are recorded alongside so that batch queries referencing remote
+/// MVs can fetch their data over exchange from the remote cluster.
+pub struct RemoteClusterCatalog {
+    cluster_name: String,
+    meta_addr: String,
+    catalog: Catalog,
+    worker_nodes: Vec<WorkerNode>,
+    /// Vnode mappings of the remote fragments as of mount time, used to schedule scans of
+    /// remote tables onto the remote compute nodes owning the data.
+    fragment_mappings: HashMap<FragmentId, ParallelUnitMapping>,
+    /// Client to the remote meta node, used to resolve the remote committed epoch per query.
+    meta_client: MetaClient,
+
+    /// Keeps the registration with the remote meta node alive.
+    _heartbeat_join_handle: JoinHandle<()>,
+    _heartbeat_shutdown_sender: Sender<()>,
+}
+
+impl RemoteClusterCatalog {
+    pub fn cluster_name(&self) -> &str {
+        &self.cluster_name
+    }
+
+    pub fn meta_addr(&self) -> &str {
+        &self.meta_addr
+    }
+
+    /// The remote catalog as of mount time. Read-only: there is no writer for it.
+    pub fn catalog(&self) -> &Catalog {
+        &self.catalog
+    }
+
+    /// All worker nodes of the remote cluster as of mount time.
+    pub fn worker_nodes(&self) -> &[WorkerNode] {
+        &self.worker_nodes
+    }
+
+    /// The compute nodes of the remote cluster, i.e. the nodes remote exchange will read from.
+    pub fn compute_nodes(&self) -> impl Iterator<Item = &WorkerNode> {
+        self.worker_nodes
+            .iter()
+            .filter(|node| node.r#type() == WorkerType::ComputeNode)
+    }
+
+    /// The vnode mapping of the given remote fragment as of mount time.
+    pub fn get_fragment_mapping(&self, fragment_id: &FragmentId) -> Option<ParallelUnitMapping> {
+        self.fragment_mappings.get(fragment_id).cloned()
+    }
+
+    /// Resolves remote parallel unit ids to the remote worker nodes hosting them, like
+    /// [`WorkerNodeManager::get_workers_by_parallel_unit_ids`] does for the local cluster.
+    ///
+    /// [`WorkerNodeManager::get_workers_by_parallel_unit_ids`]:
+    /// crate::scheduler::worker_node_manager::WorkerNodeManager::get_workers_by_parallel_unit_ids
+    pub fn get_workers_by_parallel_unit_ids(
+        &self,
+        parallel_unit_ids: &[ParallelUnitId],
+    ) -> Result<Vec<WorkerNode>> {
+        let pu_to_worker = get_pu_to_worker_mapping(&self.worker_nodes);
+        let mut workers = Vec::with_capacity(parallel_unit_ids.len());
+        for parallel_unit_id in parallel_unit_ids {
+            match pu_to_worker.get(parallel_unit_id) {
+                Some(worker) => workers.push(worker.clone()),
+                None => bail!(
+                    "no worker node found in remote cluster {} for parallel unit id: {}",
+                    self.cluster_name,
+                    parallel_unit_id
+                ),
+            }
+        }
+        Ok(workers)
+    }
+
+    /// The committed epoch of the remote cluster, fetched from its meta node. Queries read
+    /// remote tables at this epoch without pinning it.
+    pub async fn committed_epoch(&self) -> Result<u64> {
+        Ok(self.meta_client.get_epoch().await?.committed_epoch)
+    }
+}
+
+/// Parses the `--mount-remote-catalog` option: comma-separated `name=meta_addr` pairs.
+pub fn parse_remote_catalog_mounts(opt: &str) -> Result<Vec<(String, String)>> {
+    opt.split(',')
+        .map(|mount| {
+            mount
+                .split_once('=')
+                .map(|(name, addr)| (name.trim().to_string(), addr.trim().to_string()))
+                .filter(|(name, addr)| !name.is_empty() && !addr.is_empty())
+                .ok_or_else(|| {
+                    ErrorCode::InvalidConfigValue {
+                        config_entry: "mount_remote_catalog".to_string(),
+                        config_value: mount.to_string(),
+                    }
+                    .into()
+                })
+        })
+        .collect()
+}
+
+/// Mounts the catalog of the remote cluster behind `meta_addr` read-only.
+///
+/// Registers this frontend as a worker in the remote cluster and fetches the initialization
+/// snapshot of its catalog, the same way the observer does against the local meta node. The
+/// subscription is dropped afterwards: the mount stays at the snapshot until the frontend is
+/// restarted.
+pub async fn mount_remote_catalog(
+    cluster_name: String,
+    meta_addr: String,
+    frontend_address: &HostAddr,
+    heartbeat_interval: Duration,
+    max_heartbeat_interval: Duration,
+) -> Result<RemoteClusterCatalog> {
+    let meta_client = MetaClient::register_new(
+        meta_addr.as_str(),
+        WorkerType::Frontend,
+        frontend_address,
+        0,
+    )
+    .await?;
+
+    let mut stream = meta_client.subscribe(SubscribeType::Frontend).await?;
+    let resp = stream
+        .message()
+        .await
+        .map_err(|e| ErrorCode::InternalError(e.to_string()))?
+        .ok_or_else(|| {
+            ErrorCode::InternalError(format!(
+                "remote meta node {} closed the subscription before sending a snapshot",
+                meta_addr
+            ))
+        })?;
+    let Some(Info::Snapshot(snapshot)) = resp.info else {
+        return Err(ErrorCode::InternalError(format!(
+            "expect a catalog snapshot from remote meta node {}, got {:?}",
+            meta_addr, resp.info
+        ))
+        .into());
+    };
+
+    let mut catalog = Catalog::default();
+    for db in snapshot.databases {
+        catalog.create_database(&db)
+    }
+    for schema in snapshot.schemas {
+        catalog.create_schema(&schema)
+    }
+    for table in snapshot.tables {
+        catalog.create_table(&table)
+    }
+    for source in snapshot.sources {
+        catalog.create_source(&source)
+    }
+    for index in snapshot.indexes {
+        catalog.create_index(&index)
+    }
+    for sink in snapshot.sinks {
+        catalog.create_sink(&sink)
+    }
+    for view in snapshot.views {
+        catalog.create_view(&view)
+    }
+    catalog.set_version(snapshot.version.unwrap().catalog_version);
+
+    let fragment_mappings = snapshot
+        .parallel_unit_mappings
+        .iter()
+        .map(|mapping| {
+            (
+                mapping.fragment_id,
+                ParallelUnitMapping::from_protobuf(mapping.mapping.as_ref().unwrap()),
+            )
+        })
+        .collect();
+
+    let (heartbeat_join_handle, heartbeat_shutdown_sender) = MetaClient::start_heartbeat_loop(
+        meta_client.clone(),
+        heartbeat_interval,
+        max_heartbeat_interval,
+        vec![],
+    );
+    meta_client.activate(frontend_address).await?;
+
+    Ok(RemoteClusterCatalog {
+        cluster_name,
+        meta_addr,
+        catalog,
+        worker_nodes: snapshot.nodes,
+        fragment_mappings,
+        meta_client,
+        _heartbeat_join_handle: heartbeat_join_handle,
+        _heartbeat_shutdown_sender: heartbeat_shutdown_sender,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_remote_catalog_mounts;
+
+    #[test]
+    fn test_parse_remote_catalog_mounts() {
+        assert_eq!(
+            parse_remote_catalog_mounts("ingest=http://127.0.0.1:5690").unwrap(),
+            vec![("ingest".to_string(), "http://127.0.0.1:5690".to_string())]
+        );
+        assert_eq!(
+            parse_remote_catalog_mounts("a=http://h1:5690, b=http://h2:5690").unwrap(),
+            vec![
+                ("a".to_string(), "http://h1:5690".to_string()),
+                ("b".to_string(), "http://h2:5690".to_string()),
+            ]
+        );
+        assert!(parse_remote_catalog_mounts("no-equals-sign").is_err());
+        assert!(parse_remote_catalog_mounts("=http://h1:5690").is_err());
+    }
+}
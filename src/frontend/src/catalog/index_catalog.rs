@@ -125,6 +125,11 @@ impl IndexCatalog {
         self.index_table.columns.len() == self.primary_table.columns.len()
     }
 
+    /// Returns the SQL statement that can be used to create this index.
+    pub fn create_sql(&self) -> String {
+        self.index_table.create_sql()
+    }
+
     /// a mapping maps column index of secondary index to column index of primary table
     pub fn secondary_to_primary_mapping(&self) -> &HashMap<usize, usize> {
         &self.secondary_to_primary_mapping
@@ -25,6 +25,7 @@ use thiserror::Error;
 pub(crate) mod catalog_service;
 
 pub(crate) mod database_catalog;
+pub(crate) mod federation;
 pub(crate) mod function_catalog;
 pub(crate) mod index_catalog;
 pub(crate) mod root_catalog;
@@ -12,15 +12,29 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
+use itertools::Itertools;
 use risingwave_common::catalog::ColumnCatalog;
 use risingwave_pb::catalog::{Source as ProstSource, StreamSourceInfo, WatermarkDesc};
+use risingwave_pb::plan_common::RowFormatType;
+use risingwave_sqlparser::ast::{
+    AstString, AvroSchema, CsvInfo, Ident, ProtobufSchema, SourceSchema,
+};
 
 use super::{ColumnId, RelationCatalog, SourceId};
 use crate::user::UserId;
 use crate::WithOptions;
 
+/// Whether a `WITH` option holds a credential and thus should not be printed in the output of
+/// `SHOW CREATE SOURCE`, e.g. the password of the schema registry.
+fn is_secret_option(key: &str) -> bool {
+    let key = key.to_lowercase();
+    ["secret", "password", "token", "credentials", "private.key"]
+        .iter()
+        .any(|s| key.contains(s))
+}
+
 /// This struct `SourceCatalog` is used in frontend.
 /// Compared with `ProstSource`, it only maintains information used during optimization.
 #[derive(Clone, Debug)]
@@ -37,6 +51,80 @@ pub struct SourceCatalog {
     pub watermark_descs: Vec<WatermarkDesc>,
 }
 
+impl SourceCatalog {
+    /// Returns the SQL statement that can be used to create this source.
+    ///
+    /// Unlike tables, the original definition of a source is not persisted, so the statement is
+    /// assembled from the catalog here. Secret options in the `WITH` clause are redacted.
+    pub fn create_sql(&self) -> String {
+        let mut items = self
+            .columns
+            .iter()
+            .filter(|c| !c.is_hidden)
+            .map(|c| format!("{} {}", Ident::with_quote('"', c.name()), c.data_type()))
+            .collect_vec();
+        if self.row_id_index.is_none() && !self.pk_col_ids.is_empty() {
+            let pk_names = self
+                .pk_col_ids
+                .iter()
+                .map(|col_id| {
+                    let col = self
+                        .columns
+                        .iter()
+                        .find(|c| c.column_id() == *col_id)
+                        .expect("primary key column not found");
+                    format!("{}", Ident::with_quote('"', col.name()))
+                })
+                .join(", ");
+            items.push(format!("PRIMARY KEY ({})", pk_names));
+        }
+
+        // Sort the options to make the output deterministic.
+        let with_properties = self
+            .properties
+            .iter()
+            .collect::<BTreeMap<_, _>>()
+            .into_iter()
+            .map(|(k, v)| {
+                let v = if is_secret_option(k) { "[REDACTED]" } else { v };
+                format!("{} = '{}'", k, v)
+            })
+            .join(", ");
+
+        let source_schema = match self.info.row_format() {
+            RowFormatType::Json => SourceSchema::Json,
+            RowFormatType::Protobuf => SourceSchema::Protobuf(ProtobufSchema {
+                message_name: AstString(self.info.proto_message_name.clone()),
+                row_schema_location: AstString(self.info.row_schema_location.clone()),
+                use_schema_registry: self.info.use_schema_registry,
+            }),
+            RowFormatType::DebeziumJson => SourceSchema::DebeziumJson,
+            RowFormatType::Avro => SourceSchema::Avro(AvroSchema {
+                message_name: AstString(self.info.proto_message_name.clone()),
+                row_schema_location: AstString(self.info.row_schema_location.clone()),
+                use_schema_registry: self.info.use_schema_registry,
+            }),
+            RowFormatType::Maxwell => SourceSchema::Maxwell,
+            RowFormatType::CanalJson => SourceSchema::CanalJson,
+            RowFormatType::Csv => SourceSchema::Csv(CsvInfo {
+                delimiter: self.info.csv_delimiter as u8,
+                has_header: self.info.csv_has_header,
+            }),
+            RowFormatType::RowUnspecified => {
+                unreachable!("row format of source {} is unspecified", self.name)
+            }
+        };
+
+        format!(
+            "CREATE SOURCE {} ({}) WITH ({}) ROW FORMAT {}",
+            Ident::with_quote('"', self.name.clone()),
+            items.join(", "),
+            with_properties,
+            source_schema
+        )
+    }
+}
+
 impl From<&ProstSource> for SourceCatalog {
     fn from(prost: &ProstSource) -> Self {
         let id = prost.id;
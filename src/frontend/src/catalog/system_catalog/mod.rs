@@ -30,6 +30,7 @@ use risingwave_common::row::OwnedRow;
 use risingwave_common::types::DataType;
 
 use crate::catalog::catalog_service::CatalogReader;
+use crate::catalog::federation::RemoteClusterCatalog;
 use crate::catalog::system_catalog::information_schema::*;
 use crate::catalog::system_catalog::pg_catalog::*;
 use crate::catalog::system_catalog::rw_catalog::*;
@@ -92,6 +93,8 @@ pub struct SysCatalogReaderImpl {
     // Read from meta.
     meta_client: Arc<dyn FrontendMetaClient>,
     auth_context: Arc<AuthContext>,
+    // Catalogs of remote clusters mounted read-only, keyed by cluster name.
+    remote_catalogs: Arc<HashMap<String, Arc<RemoteClusterCatalog>>>,
 }
 
 impl SysCatalogReaderImpl {
@@ -101,6 +104,7 @@ impl SysCatalogReaderImpl {
         worker_node_manager: WorkerNodeManagerRef,
         meta_client: Arc<dyn FrontendMetaClient>,
         auth_context: Arc<AuthContext>,
+        remote_catalogs: Arc<HashMap<String, Arc<RemoteClusterCatalog>>>,
     ) -> Self {
         Self {
             catalog_reader,
@@ -108,6 +112,7 @@ impl SysCatalogReaderImpl {
             worker_node_manager,
             meta_client,
             auth_context,
+            remote_catalogs,
         }
     }
 }
@@ -205,4 +210,10 @@ prepare_sys_catalog! {
     { INFORMATION_SCHEMA, COLUMNS, vec![], read_columns_info },
     { INFORMATION_SCHEMA, TABLES, vec![], read_tables_info },
     { RW_CATALOG, RW_META_SNAPSHOT, vec![], read_meta_snapshot await },
+    { RW_CATALOG, RW_MV_STATUS, vec![0], read_mv_status await },
+    { RW_CATALOG, RW_SINK_FRESHNESS, vec![0], read_sink_freshness await },
+    { RW_CATALOG, RW_HUMMOCK_EPOCHS, vec![0], read_hummock_epochs await },
+    { RW_CATALOG, RW_HUMMOCK_COMPACTION_TASKS, vec![0], read_hummock_compaction_tasks await },
+    { RW_CATALOG, RW_HUMMOCK_SSTABLES, vec![], read_hummock_sstables await },
+    { RW_CATALOG, RW_REMOTE_CLUSTERS, vec![0], read_remote_clusters },
 }
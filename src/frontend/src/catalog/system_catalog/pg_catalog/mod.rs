@@ -225,6 +225,123 @@ impl SysCatalogReaderImpl {
         Ok(meta_snapshots)
     }
 
+    pub(super) async fn read_mv_status(&self) -> Result<Vec<OwnedRow>> {
+        let mv_status = self.meta_client.list_mv_status().await?;
+        Ok(mv_status
+            .into_iter()
+            .map(|s| {
+                let backfilling = s.backfilling;
+                OwnedRow::new(vec![
+                    Some(ScalarImpl::Int32(s.table_id as i32)),
+                    Some(ScalarImpl::Utf8(s.name.into())),
+                    Some(ScalarImpl::Int64(s.last_committed_epoch as i64)),
+                    Some(ScalarImpl::Int64(s.barrier_lag_ms as i64)),
+                    backfilling.then(|| ScalarImpl::Float64(s.backfill_progress.into())),
+                    (backfilling && s.slowest_actor_id != 0)
+                        .then(|| ScalarImpl::Int32(s.slowest_actor_id as i32)),
+                    Some(ScalarImpl::Int64(s.state_size_bytes as i64)),
+                ])
+            })
+            .collect_vec())
+    }
+
+    pub(super) async fn read_sink_freshness(&self) -> Result<Vec<OwnedRow>> {
+        let try_get_date_time = |time_millis: u64| {
+            if time_millis == 0 {
+                return None;
+            }
+            NaiveDateTimeWrapper::with_secs_nsecs(
+                (time_millis / 1000) as i64,
+                (time_millis % 1000 * 1_000_000) as u32,
+            )
+            .map(ScalarImpl::NaiveDateTime)
+            .ok()
+        };
+        let sink_freshness = self.meta_client.list_sink_freshness().await?;
+        Ok(sink_freshness
+            .into_iter()
+            .map(|s| {
+                let committed = s.last_commit_at_ms != 0;
+                OwnedRow::new(vec![
+                    Some(ScalarImpl::Int32(s.sink_id as i32)),
+                    Some(ScalarImpl::Utf8(s.name.into())),
+                    try_get_date_time(s.sampled_event_ts_ms),
+                    try_get_date_time(s.last_commit_at_ms),
+                    committed.then(|| ScalarImpl::Int64(s.freshness_ms as i64)),
+                ])
+            })
+            .collect_vec())
+    }
+
+    pub(super) async fn read_hummock_epochs(&self) -> Result<Vec<OwnedRow>> {
+        let commit_times = self.meta_client.list_epoch_commit_times().await?;
+        Ok(commit_times
+            .into_iter()
+            .map(|t| {
+                OwnedRow::new(vec![
+                    Some(ScalarImpl::Int64(t.epoch as i64)),
+                    NaiveDateTimeWrapper::with_secs_nsecs(
+                        (t.commit_ts_ms / 1000) as i64,
+                        (t.commit_ts_ms % 1000 * 1_000_000) as u32,
+                    )
+                    .map(ScalarImpl::NaiveDateTime)
+                    .ok(),
+                ])
+            })
+            .collect_vec())
+    }
+
+    pub(super) async fn read_hummock_compaction_tasks(&self) -> Result<Vec<OwnedRow>> {
+        let task_summaries = self.meta_client.list_compact_task_summaries().await?;
+        Ok(task_summaries
+            .into_iter()
+            .map(|t| {
+                OwnedRow::new(vec![
+                    Some(ScalarImpl::Int64(t.task_id as i64)),
+                    Some(ScalarImpl::Int64(t.compaction_group_id as i64)),
+                    Some(ScalarImpl::Int32(t.target_level as i32)),
+                    Some(ScalarImpl::Int32(t.input_file_count as i32)),
+                    Some(ScalarImpl::Int64(t.input_file_size as i64)),
+                    Some(ScalarImpl::Utf8(t.status().as_str_name().into())),
+                    Some(ScalarImpl::Int32(t.assignee_context_id as i32)),
+                    Some(ScalarImpl::Utf8(t.task_type().as_str_name().into())),
+                ])
+            })
+            .collect_vec())
+    }
+
+    pub(super) async fn read_hummock_sstables(&self) -> Result<Vec<OwnedRow>> {
+        let level_summaries = self.meta_client.list_level_summaries().await?;
+        Ok(level_summaries
+            .into_iter()
+            .map(|l| {
+                OwnedRow::new(vec![
+                    Some(ScalarImpl::Int64(l.compaction_group_id as i64)),
+                    Some(ScalarImpl::Int32(l.level_idx as i32)),
+                    Some(ScalarImpl::Int32(l.sst_count as i32)),
+                    Some(ScalarImpl::Int64(l.total_file_size as i64)),
+                    Some(ScalarImpl::Int32(l.sub_level_count as i32)),
+                ])
+            })
+            .collect_vec())
+    }
+
+    pub(super) fn read_remote_clusters(&self) -> Result<Vec<OwnedRow>> {
+        Ok(self
+            .remote_catalogs
+            .values()
+            .sorted_by_key(|remote| remote.cluster_name().to_string())
+            .map(|remote| {
+                OwnedRow::new(vec![
+                    Some(ScalarImpl::Utf8(remote.cluster_name().into())),
+                    Some(ScalarImpl::Utf8(remote.meta_addr().into())),
+                    Some(ScalarImpl::Int64(remote.catalog().version() as i64)),
+                    Some(ScalarImpl::Int32(remote.compute_nodes().count() as i32)),
+                ])
+            })
+            .collect_vec())
+    }
+
     // FIXME(noel): Tracked by <https://github.com/risingwavelabs/risingwave/issues/3431#issuecomment-1164160988>
     pub(super) fn read_opclass_info(&self) -> Result<Vec<OwnedRow>> {
         Ok(vec![])
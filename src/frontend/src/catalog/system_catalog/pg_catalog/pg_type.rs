@@ -65,6 +65,7 @@ pub const PG_TYPE_DATA: &[(i32, &str)] = &[
     (1184, "timestamptz"),
     (1186, "interval"),
     (1700, "numeric"),
+    (3802, "jsonb"),
 ];
 
 pub static PG_TYPE_DATA_ROWS: LazyLock<Vec<OwnedRow>> = LazyLock::new(|| {
@@ -12,5 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod rw_hummock_compaction_tasks;
+mod rw_hummock_epochs;
+mod rw_hummock_sstables;
 mod rw_meta_snapshot;
+mod rw_mv_status;
+mod rw_remote_clusters;
+mod rw_sink_freshness;
+
+pub use rw_hummock_compaction_tasks::*;
+pub use rw_hummock_epochs::*;
+pub use rw_hummock_sstables::*;
 pub use rw_meta_snapshot::*;
+pub use rw_mv_status::*;
+pub use rw_remote_clusters::*;
+pub use rw_sink_freshness::*;
@@ -0,0 +1,28 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::types::DataType;
+
+use crate::catalog::system_catalog::SystemCatalogColumnsDef;
+
+pub const RW_HUMMOCK_SSTABLES_TABLE_NAME: &str = "rw_hummock_sstables";
+
+pub const RW_HUMMOCK_SSTABLES_COLUMNS: &[SystemCatalogColumnsDef<'_>] = &[
+    (DataType::Int64, "compaction_group_id"),
+    (DataType::Int32, "level_idx"),
+    (DataType::Int32, "sst_count"),
+    (DataType::Int64, "total_file_size"),
+    // number of overlapping sub levels, only meaningful for L0
+    (DataType::Int32, "sub_level_count"),
+];
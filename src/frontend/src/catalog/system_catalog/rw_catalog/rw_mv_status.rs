@@ -0,0 +1,35 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::types::DataType;
+
+use crate::catalog::system_catalog::SystemCatalogColumnsDef;
+
+pub const RW_MV_STATUS_TABLE_NAME: &str = "rw_mv_status";
+
+/// Maintenance status of each materialized view, for self-diagnosis of lagging views.
+pub const RW_MV_STATUS_COLUMNS: &[SystemCatalogColumnsDef<'_>] = &[
+    (DataType::Int32, "mv_id"),
+    (DataType::Varchar, "name"),
+    // epoch of the last committed checkpoint, which the queryable state reflects
+    (DataType::Int64, "last_committed_epoch"),
+    // milliseconds elapsed since the last committed checkpoint was issued
+    (DataType::Int64, "barrier_lag_ms"),
+    // fraction of backfill actors that have caught up, NULL when not backfilling
+    (DataType::Float64, "backfill_progress"),
+    // the backfill actor furthest behind, NULL when not backfilling or all actors are done
+    (DataType::Int32, "slowest_actor_id"),
+    // total key and value size of the materialized state in bytes
+    (DataType::Int64, "state_size_bytes"),
+];
@@ -0,0 +1,33 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::types::DataType;
+
+use crate::catalog::system_catalog::SystemCatalogColumnsDef;
+
+pub const RW_SINK_FRESHNESS_TABLE_NAME: &str = "rw_sink_freshness";
+
+/// End-to-end freshness of each sink: how far the data committed to the sink target lags behind
+/// the source event timestamps, taken from the slowest parallel actor of the sink. The time
+/// columns are NULL for sinks that have not committed since the meta service started.
+pub const RW_SINK_FRESHNESS_COLUMNS: &[SystemCatalogColumnsDef<'_>] = &[
+    (DataType::Int32, "sink_id"),
+    (DataType::Varchar, "name"),
+    // the newest sampled event timestamp committed to the sink target
+    (DataType::Timestamp, "last_event_time"),
+    // when the last sink commit completed
+    (DataType::Timestamp, "last_commit_time"),
+    // milliseconds between the two, the freshness SLA number of the pipeline
+    (DataType::Int64, "freshness_ms"),
+];
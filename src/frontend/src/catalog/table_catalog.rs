@@ -24,7 +24,9 @@ use risingwave_connector::sink::catalog::SinkId;
 use risingwave_pb::catalog::table::{
     OptionalAssociatedSourceId, TableType as ProstTableType, TableVersion as ProstTableVersion,
 };
-use risingwave_pb::catalog::{ColumnIndex as ProstColumnIndex, Table as ProstTable};
+use risingwave_pb::catalog::{
+    ColumnIndex as ProstColumnIndex, Table as ProstTable, TableStats as ProstTableStats,
+};
 
 use super::{ColumnId, DatabaseId, FragmentId, RelationCatalog, SchemaId};
 use crate::optimizer::property::FieldOrder;
@@ -127,6 +129,14 @@ pub struct TableCatalog {
 
     /// the column indices which could receive watermarks.
     pub watermark_columns: FixedBitSet,
+
+    /// Statistics collected by `ANALYZE`, consumed by the optimizer. `None` if the table has
+    /// never been analyzed.
+    pub stats: Option<ProstTableStats>,
+
+    /// If true, the (tiny) state of this internal table is kept in executor memory and
+    /// snapshotted into the meta store at barriers, instead of being written to Hummock.
+    pub memory_only: bool,
 }
 
 #[derive(Copy, Clone, Debug, PartialEq)]
@@ -298,6 +308,7 @@ impl TableCatalog {
             value_indices: self.value_indices.clone(),
             read_prefix_len_hint: self.read_prefix_len_hint,
             watermark_columns: self.watermark_columns.clone(),
+            remote_cluster: None,
         }
     }
 
@@ -363,6 +374,8 @@ impl TableCatalog {
             read_prefix_len_hint: self.read_prefix_len_hint as u32,
             version: self.version.as_ref().map(TableVersion::to_prost),
             watermark_indices: self.watermark_columns.ones().map(|x| x as _).collect_vec(),
+            stats: self.stats.clone(),
+            memory_only: self.memory_only,
         }
     }
 
@@ -433,6 +446,8 @@ impl From<ProstTable> for TableCatalog {
             read_prefix_len_hint: tb.read_prefix_len_hint as usize,
             version: tb.version.map(TableVersion::from_prost),
             watermark_columns,
+            stats: tb.stats,
+            memory_only: tb.memory_only,
         }
     }
 }
@@ -531,6 +546,8 @@ mod tests {
                 next_column_id: 2,
             }),
             watermark_indices: vec![],
+            stats: None,
+            memory_only: false,
         }
         .into();
 
@@ -593,6 +610,8 @@ mod tests {
                 read_prefix_len_hint: 0,
                 version: Some(TableVersion::new_initial_for_test(ColumnId::new(1))),
                 watermark_columns: FixedBitSet::with_capacity(2),
+                stats: None,
+                memory_only: false,
             }
         );
         assert_eq!(table, TableCatalog::from(table.to_prost(0, 0)));
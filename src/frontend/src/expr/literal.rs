@@ -51,6 +51,7 @@ impl std::fmt::Debug for Literal {
                     | DataType::Timestamptz
                     | DataType::Time
                     | DataType::Interval
+                    | DataType::Jsonb
                     | DataType::Struct(_) => write!(
                         f,
                         "'{}'",
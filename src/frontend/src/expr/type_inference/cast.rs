@@ -138,7 +138,7 @@ pub fn cast_ok(source: &DataType, target: &DataType, allows: CastContext) -> boo
 }
 
 pub fn cast_ok_base(source: DataTypeName, target: DataTypeName, allows: CastContext) -> bool {
-    matches!(CAST_MAP.get(&(source, target)), Some(context) if *context <= allows)
+    CAST_MAP.castable(source, target, allows)
 }
 
 fn cast_ok_struct(source: &DataType, target: &DataType, allows: CastContext) -> bool {
@@ -264,7 +264,7 @@ mod tests {
                 " TTT TTT     ",
                 " TTTT TT     ",
                 " TTTTT T     ",
-                "             ", // varchar
+                "          T  ", // varchar
                 "       T TT  ",
                 "       TT TT ",
                 "       TTT T ",
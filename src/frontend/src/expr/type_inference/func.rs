@@ -325,6 +325,37 @@ fn infer_type_for_special(
                 .try_collect()?;
             Ok(Some(DataType::Varchar))
         }
+        ExprType::ToJson => {
+            ensure_arity!("to_json", | inputs | == 1);
+            Ok(Some(DataType::Varchar))
+        }
+        ExprType::JsonbExtractPath => {
+            // Handles both `jsonb #> text[]` and the variadic `jsonb_extract_path(jsonb,
+            // VARIADIC text[])`, normalizing the path elements into a single `text[]` input.
+            ensure_arity!("jsonb_extract_path", 2 <= | inputs |);
+            if inputs[0].return_type() != DataType::Jsonb {
+                return Err(ErrorCode::BindError(format!(
+                    "function jsonb_extract_path({}, ...) does not exist",
+                    inputs[0].return_type()
+                ))
+                .into());
+            }
+            let list_varchar = DataType::List {
+                datatype: Box::new(DataType::Varchar),
+            };
+            if inputs.len() != 2 || inputs[1].return_type() != list_varchar {
+                let inputs_owned = std::mem::take(inputs);
+                let mut iter = inputs_owned.into_iter();
+                let jsonb = iter.next().unwrap();
+                let path = iter
+                    .map(|e| e.cast_explicit(DataType::Varchar))
+                    .try_collect()?;
+                let path =
+                    FunctionCall::new_unchecked(ExprType::Array, path, list_varchar).into();
+                *inputs = vec![jsonb, path];
+            }
+            Ok(Some(DataType::Jsonb))
+        }
         ExprType::IsNotNull => {
             ensure_arity!("is_not_null", | inputs | == 1);
             match inputs[0].return_type() {
@@ -0,0 +1,254 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use futures::StreamExt;
+use itertools::Itertools;
+use pgwire::pg_response::{PgResponse, StatementType};
+use risingwave_common::array::DataChunk;
+use risingwave_common::catalog::TableId;
+use risingwave_common::error::{ErrorCode, Result, RwError};
+use risingwave_common::row::Row as _;
+use risingwave_common::session_config::QueryMode;
+use risingwave_common::types::to_text::ToText;
+use risingwave_common::types::{ScalarImpl, ScalarRefImpl};
+use risingwave_common::util::iter_util::ZipEqFast;
+use risingwave_pb::catalog::{ColumnStats as ProstColumnStats, TableStats as ProstTableStats};
+use risingwave_sqlparser::ast::ObjectName;
+use risingwave_sqlparser::parser::Parser;
+
+use super::query::{distribute_execute, gen_batch_query_plan, local_execute};
+use super::{HandlerArgs, RwPgResponse};
+use crate::binder::{Binder, Relation};
+use crate::catalog::table_catalog::TableType;
+use crate::optimizer::OptimizerContext;
+use crate::scheduler::{BatchPlanFragmenter, PinnedHummockSnapshot};
+use crate::session::SessionImpl;
+
+/// Maximum number of rows sampled for building histograms.
+const HISTOGRAM_SAMPLE_SIZE: usize = 10000;
+/// Number of equi-depth histogram buckets per column.
+const HISTOGRAM_BUCKET_NUM: usize = 16;
+
+/// Handle `ANALYZE <table>`.
+///
+/// This runs two ordinary batch queries against the table: one aggregation computing the row
+/// count and the approximate NDV of each column, and one sampling scan used to build per-column
+/// equi-depth histograms. The collected [`ProstTableStats`] are then persisted on the table's
+/// catalog entry in the meta service, so that they survive restarts and are propagated to all
+/// frontends through the usual catalog notifications. The optimizer consumes them for
+/// cardinality estimation.
+///
+/// Privileges are not checked here explicitly: the internal queries go through the normal
+/// planning path, which requires `SELECT` on the table.
+pub async fn handle_analyze(
+    handler_args: HandlerArgs,
+    table_name: ObjectName,
+) -> Result<RwPgResponse> {
+    let session = handler_args.session.clone();
+
+    // (index of the column in the table, column name)
+    let (table_id, columns): (TableId, Vec<(usize, String)>) = {
+        let mut binder = Binder::new(&session);
+        let relation = binder.bind_relation_by_name(table_name.clone(), None)?;
+        match relation {
+            Relation::BaseTable(table)
+                if matches!(
+                    table.table_catalog.table_type(),
+                    TableType::Table | TableType::MaterializedView
+                ) =>
+            {
+                let columns = table
+                    .table_catalog
+                    .columns
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, c)| !c.is_hidden)
+                    .map(|(idx, c)| (idx, c.name().to_string()))
+                    .collect_vec();
+                (table.table_id, columns)
+            }
+            _ => {
+                return Err(ErrorCode::InvalidInputSyntax(
+                    "ANALYZE is only supported on tables and materialized views".to_string(),
+                )
+                .into())
+            }
+        }
+    };
+
+    // `COUNT(*)` and the approximate NDV of every column, in a single aggregation.
+    let count_sql = format!(
+        "SELECT COUNT(*){} FROM {}",
+        columns
+            .iter()
+            .map(|(_, name)| format!(", APPROX_COUNT_DISTINCT({})", quote_ident(name)))
+            .join(""),
+        table_name
+    );
+    let count_chunks = execute_internal_query(session.clone(), count_sql).await?;
+    let counts: Vec<i64> = {
+        let row = count_chunks
+            .iter()
+            .flat_map(|chunk| chunk.rows())
+            .next()
+            .ok_or_else(|| {
+                RwError::from(ErrorCode::InternalError(
+                    "no rows returned by the ANALYZE count query".to_string(),
+                ))
+            })?;
+        (0..=columns.len())
+            .map(|i| match row.datum_at(i) {
+                Some(ScalarRefImpl::Int64(count)) => Ok(count),
+                datum => Err(RwError::from(ErrorCode::InternalError(format!(
+                    "unexpected datum {:?} in the ANALYZE count query output",
+                    datum
+                )))),
+            })
+            .try_collect()?
+    };
+
+    // Sample rows for histograms. `LIMIT` gives an arbitrary rather than a uniform sample, which
+    // is acceptable for the best-effort estimates the histograms are used for.
+    let histogram_bounds: Vec<Vec<String>> = if columns.is_empty() {
+        vec![]
+    } else {
+        let sample_sql = format!(
+            "SELECT {} FROM {} LIMIT {}",
+            columns
+                .iter()
+                .map(|(_, name)| quote_ident(name))
+                .join(", "),
+            table_name,
+            HISTOGRAM_SAMPLE_SIZE
+        );
+        let sample_chunks = execute_internal_query(session.clone(), sample_sql).await?;
+        (0..columns.len())
+            .map(|i| {
+                let values = sample_chunks
+                    .iter()
+                    .flat_map(|chunk| chunk.rows())
+                    .filter_map(|row| row.datum_at(i).map(ScalarRefImpl::into_scalar_impl))
+                    .collect_vec();
+                equi_depth_bounds(values)
+            })
+            .collect_vec()
+    };
+
+    let stats = ProstTableStats {
+        row_count: counts[0].max(0) as u64,
+        column_stats: columns
+            .iter()
+            .zip_eq_fast(histogram_bounds)
+            .enumerate()
+            .map(|(i, (&(idx, _), bounds))| {
+                (
+                    idx as u32,
+                    ProstColumnStats {
+                        ndv: counts[i + 1].max(0) as u64,
+                        histogram_bounds: bounds,
+                    },
+                )
+            })
+            .collect(),
+    };
+
+    let catalog_writer = session.env().catalog_writer();
+    catalog_writer.update_table_stats(table_id, stats).await?;
+
+    Ok(PgResponse::empty_result(StatementType::ANALYZE))
+}
+
+/// Quote an identifier so that it can be embedded in a generated query.
+fn quote_ident(ident: &str) -> String {
+    format!("\"{}\"", ident.replace('"', "\"\""))
+}
+
+/// Compute the upper bounds of equi-depth histogram buckets over the sampled `values`, in the
+/// text encoding of the column's type. Duplicated bounds are collapsed, so columns with few
+/// distinct values get fewer buckets.
+fn equi_depth_bounds(mut values: Vec<ScalarImpl>) -> Vec<String> {
+    if values.is_empty() {
+        return vec![];
+    }
+    // All values are of the column's type, so comparison never panics.
+    values.sort_unstable();
+    let mut bounds = (1..=HISTOGRAM_BUCKET_NUM)
+        .map(|bucket| {
+            let last_idx = (values.len() * bucket / HISTOGRAM_BUCKET_NUM).saturating_sub(1);
+            values[last_idx].as_scalar_ref_impl().to_text()
+        })
+        .collect_vec();
+    bounds.dedup();
+    bounds
+}
+
+/// Plan and run a generated query through the normal batch execution path, collecting all
+/// result chunks.
+async fn execute_internal_query(
+    session: Arc<SessionImpl>,
+    sql: String,
+) -> Result<Vec<DataChunk>> {
+    let stmt = Parser::parse_sql(&sql)
+        .map_err(|e| RwError::from(ErrorCode::InternalError(e.to_string())))?
+        .into_iter()
+        .exactly_one()
+        .expect("a single generated statement");
+    let handler_args = HandlerArgs::new(session.clone(), &stmt, &sql)?;
+    let only_checkpoint_visible = session.config().only_checkpoint_visible();
+
+    // Subblock to make sure PlanRef (an Rc) is dropped before `await` below.
+    let (plan_fragmenter, query_mode) = {
+        let context = OptimizerContext::from_handler_args(handler_args);
+        let (plan, query_mode, _schema) = gen_batch_query_plan(&session, context.into(), stmt)?;
+        let plan_fragmenter = BatchPlanFragmenter::new(
+            session.env().worker_node_manager_ref(),
+            session.env().catalog_reader().clone(),
+            session.env().remote_catalogs().clone(),
+            plan,
+        )?;
+        (plan_fragmenter, query_mode)
+    };
+    let query = plan_fragmenter.generate_complete_query().await?;
+
+    let query_snapshot = {
+        let hummock_snapshot_manager = session.env().hummock_snapshot_manager();
+        let query_id = query.query_id().clone();
+        let pinned_snapshot = hummock_snapshot_manager.acquire(&query_id).await?;
+        PinnedHummockSnapshot::FrontendPinned(pinned_snapshot, only_checkpoint_visible)
+    };
+
+    let mut chunks = vec![];
+    match query_mode {
+        QueryMode::Local => {
+            let mut stream = local_execute(session.clone(), query, query_snapshot).await?;
+            while let Some(chunk) = stream.next().await {
+                chunks.push(chunk.map_err(|err| {
+                    RwError::from(ErrorCode::InternalError(format!("{}", err)))
+                })?);
+            }
+        }
+        QueryMode::Distributed => {
+            let mut stream = distribute_execute(session.clone(), query, query_snapshot).await?;
+            while let Some(chunk) = stream.next().await {
+                chunks.push(chunk.map_err(|err| {
+                    RwError::from(ErrorCode::InternalError(format!("{}", err)))
+                })?);
+            }
+        }
+    }
+    Ok(chunks)
+}
@@ -55,9 +55,11 @@ pub async fn handle_create_function(
             )
         }
     };
-    if language != "arrow_flight" {
+    // All external languages are served over the same Arrow Flight protocol; the language name
+    // only describes what runs on the other side of the endpoint.
+    if language != "python" && language != "arrow_flight" {
         return Err(ErrorCode::InvalidParameterValue(
-            "LANGUAGE should be one of: arrow_flight".to_string(),
+            "LANGUAGE should be one of: python, arrow_flight".to_string(),
         )
         .into());
     }
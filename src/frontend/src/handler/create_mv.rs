@@ -83,6 +83,9 @@ pub fn gen_create_mv_plan(
 
     let definition = context.normalized_sql().to_owned();
 
+    // Validate the checkpoint SLO option early, so that an invalid duration fails the DDL.
+    context.with_options().checkpoint_slo()?;
+
     let bound = {
         let mut binder = Binder::new_for_stream(session);
         binder.bind_query(query)?
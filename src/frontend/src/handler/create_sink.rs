@@ -12,48 +12,96 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::rc::Rc;
+
+use fixedbitset::FixedBitSet;
 use pgwire::pg_response::{PgResponse, StatementType};
 use risingwave_common::catalog::{DatabaseId, SchemaId, UserId};
-use risingwave_common::error::Result;
+use risingwave_common::error::{ErrorCode, Result};
 use risingwave_connector::sink::catalog::SinkCatalog;
 use risingwave_pb::stream_plan::stream_fragment_graph::Parallelism;
-use risingwave_sqlparser::ast::{
-    CreateSink, CreateSinkStatement, ObjectName, Query, Select, SelectItem, SetExpr, TableFactor,
-    TableWithJoins,
-};
+use risingwave_pb::user::grant_privilege::{Action, Object};
+use risingwave_sqlparser::ast::{CreateSink, CreateSinkStatement, ObjectName};
 
 use super::create_mv::get_column_names;
 use super::RwPgResponse;
 use crate::binder::Binder;
+use crate::catalog::root_catalog::SchemaPath;
+use crate::catalog::table_catalog::TableType;
+use crate::handler::privilege::ObjectCheckItem;
 use crate::handler::HandlerArgs;
-use crate::optimizer::{OptimizerContext, OptimizerContextRef, PlanRef};
+use crate::optimizer::plan_node::LogicalScan;
+use crate::optimizer::property::{Order, RequiredDist};
+use crate::optimizer::{OptimizerContext, OptimizerContextRef, PlanRef, PlanRoot};
 use crate::session::SessionImpl;
 use crate::stream_fragmenter::build_graph;
 use crate::Planner;
 
-pub fn gen_sink_query_from_name(from_name: ObjectName) -> Result<Query> {
-    let table_factor = TableFactor::Table {
-        name: from_name,
-        alias: None,
-    };
-    let from = vec![TableWithJoins {
-        relation: table_factor,
-        joins: vec![],
-    }];
-    let select = Select {
-        from,
-        projection: vec![SelectItem::Wildcard],
-        ..Default::default()
+/// Generates a plan that scans the existing materialized view or table directly, so that
+/// `CREATE SINK ... FROM name` attaches a new fragment to the changelog of the relation instead
+/// of re-planning (and re-computing) its defining query.
+fn gen_sink_plan_from_relation(
+    session: &SessionImpl,
+    context: OptimizerContextRef,
+    from_name: ObjectName,
+) -> Result<PlanRoot> {
+    let db_name = session.database();
+    let (schema_name, table_name) = Binder::resolve_schema_qualified_name(db_name, from_name)?;
+    let search_path = session.config().get_search_path();
+    let user_name = &session.auth_context().user_name;
+    let schema_path = SchemaPath::new(schema_name.as_deref(), &search_path, user_name);
+
+    let table = {
+        let read_guard = session.env().catalog_reader().read_guard();
+        let (table, _schema_name) =
+            read_guard.get_table_by_name(db_name, schema_path, &table_name)?;
+        table.clone()
     };
-    let body = SetExpr::Select(Box::new(select));
-    Ok(Query {
-        with: None,
-        body,
-        order_by: vec![],
-        limit: None,
-        offset: None,
-        fetch: None,
-    })
+
+    match table.table_type() {
+        TableType::Table | TableType::MaterializedView => {}
+        TableType::Index | TableType::Internal => {
+            return Err(ErrorCode::InvalidInputSyntax(format!(
+                "cannot create sink from \"{}\": only tables and materialized views are supported",
+                table_name
+            ))
+            .into());
+        }
+    }
+
+    session.check_privileges(&[ObjectCheckItem::new(
+        table.owner,
+        Action::Select,
+        Object::TableId(table.id.table_id),
+    )])?;
+
+    let scan: PlanRef = LogicalScan::create(
+        table_name,
+        false,
+        Rc::new(table.table_desc()),
+        // The sink consumes all changes of the relation, so indexes are irrelevant.
+        vec![],
+        context,
+    )
+    .into();
+
+    // Only sink the user-visible columns of the relation.
+    let mut out_fields = FixedBitSet::with_capacity(scan.schema().len());
+    let mut out_names = Vec::new();
+    for (i, column) in table.columns().iter().enumerate() {
+        if !column.is_hidden() {
+            out_fields.set(i, true);
+            out_names.push(column.name().to_string());
+        }
+    }
+
+    Ok(PlanRoot::new(
+        scan,
+        RequiredDist::Any,
+        Order::any(),
+        out_fields,
+        out_names,
+    ))
 }
 
 pub fn gen_sink_plan(
@@ -65,31 +113,42 @@ pub fn gen_sink_plan(
     let (sink_schema_name, sink_table_name) =
         Binder::resolve_schema_qualified_name(db_name, stmt.sink_name.clone())?;
 
-    let query = match stmt.sink_from {
-        CreateSink::From(from_name) => Box::new(gen_sink_query_from_name(from_name)?),
-        CreateSink::AsQuery(query) => query,
-    };
-
     let (sink_database_id, sink_schema_id) =
         session.get_database_and_schema_id_for_create(sink_schema_name)?;
 
     let definition = context.normalized_sql().to_owned();
 
-    let bound = {
-        let mut binder = Binder::new(session);
-        binder.bind_query(*query)?
+    let mut plan_root = match stmt.sink_from {
+        CreateSink::From(from_name) => {
+            // Attach directly to the changelog of the relation, without re-planning its
+            // defining query.
+            let mut plan_root =
+                gen_sink_plan_from_relation(session, context.clone(), from_name)?;
+            if !stmt.columns.is_empty() {
+                plan_root
+                    .set_out_names(stmt.columns.iter().map(|c| c.real_value()).collect())?;
+            }
+            plan_root
+        }
+        CreateSink::AsQuery(query) => {
+            let bound = {
+                let mut binder = Binder::new(session);
+                binder.bind_query(*query)?
+            };
+
+            // If colume names not specified, use the name in materialized view.
+            let col_names = get_column_names(&bound, session, stmt.columns)?;
+
+            let mut plan_root = Planner::new(context.clone()).plan_query(bound)?;
+            if let Some(col_names) = col_names {
+                plan_root.set_out_names(col_names)?;
+            };
+            plan_root
+        }
     };
 
-    // If colume names not specified, use the name in materialized view.
-    let col_names = get_column_names(&bound, session, stmt.columns)?;
-
     let properties = context.with_options().clone();
 
-    let mut plan_root = Planner::new(context).plan_query(bound)?;
-    if let Some(col_names) = col_names {
-        plan_root.set_out_names(col_names)?;
-    };
-
     let sink_plan = plan_root.gen_sink_plan(sink_table_name, definition, properties)?;
 
     let sink_desc = sink_plan.sink_desc().clone();
@@ -12,22 +12,26 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use itertools::Itertools;
+use pgwire::pg_field_descriptor::PgFieldDescriptor;
 use pgwire::pg_response::{PgResponse, StatementType};
+use pgwire::types::Row;
 use risingwave_common::catalog::{ColumnCatalog, ColumnDesc, ROW_ID_COLUMN_ID};
 use risingwave_common::error::ErrorCode::{self, ProtocolError};
 use risingwave_common::error::{Result, RwError};
 use risingwave_common::types::DataType;
+use risingwave_common::util::iter_util::ZipEqFast;
 use risingwave_connector::parser::{AvroParserConfig, ProtobufParserConfig};
-use risingwave_connector::source::KAFKA_CONNECTOR;
+use risingwave_connector::source::{ConnectorProperties, SourceMessage, KAFKA_CONNECTOR};
 use risingwave_pb::catalog::{
     ColumnIndex as ProstColumnIndex, Source as ProstSource, StreamSourceInfo, WatermarkDesc,
 };
 use risingwave_pb::plan_common::RowFormatType;
 use risingwave_sqlparser::ast::{
-    AvroSchema, CreateSourceStatement, ProtobufSchema, SourceSchema, SourceWatermark,
+    AvroSchema, ColumnDef, CreateSourceStatement, DataType as AstDataType, Ident, ProtobufSchema,
+    SourceSchema, SourceWatermark, StructField,
 };
 
 use super::create_table::bind_sql_table_constraints;
@@ -42,6 +46,9 @@ use crate::session::SessionImpl;
 
 pub(crate) const UPSTREAM_SOURCE_KEY: &str = "connector";
 
+/// The number of messages sampled from the source for `INFER SCHEMA`.
+const SCHEMA_INFER_SAMPLE_COUNT: usize = 16;
+
 /// Map an Avro schema to a relational schema.
 async fn extract_avro_table_schema(
     schema: &AvroSchema,
@@ -284,6 +291,198 @@ fn bind_source_watermark(
     Ok(watermark_descs)
 }
 
+/// Infer the type of a single json value. Returns `None` for `null`, whose type can only be
+/// determined by other samples.
+fn infer_json_type(value: &serde_json::Value) -> Option<DataType> {
+    match value {
+        serde_json::Value::Null => None,
+        serde_json::Value::Bool(_) => Some(DataType::Boolean),
+        serde_json::Value::Number(number) => Some(if number.is_f64() {
+            DataType::Float64
+        } else {
+            DataType::Int64
+        }),
+        serde_json::Value::String(_) => Some(DataType::Varchar),
+        serde_json::Value::Array(elements) => {
+            let element_type = elements
+                .iter()
+                .flat_map(infer_json_type)
+                .reduce(merge_json_types)
+                .unwrap_or(DataType::Varchar);
+            Some(DataType::List {
+                datatype: Box::new(element_type),
+            })
+        }
+        serde_json::Value::Object(object) => {
+            let (fields, field_names) = object
+                .iter()
+                .map(|(name, value)| {
+                    (
+                        infer_json_type(value).unwrap_or(DataType::Varchar),
+                        name.clone(),
+                    )
+                })
+                .unzip();
+            Some(DataType::new_struct(fields, field_names))
+        }
+    }
+}
+
+/// Merge the types inferred for the same field from different samples.
+fn merge_json_types(lhs: DataType, rhs: DataType) -> DataType {
+    match (lhs, rhs) {
+        (lhs, rhs) if lhs == rhs => lhs,
+        (DataType::Int64, DataType::Float64) | (DataType::Float64, DataType::Int64) => {
+            DataType::Float64
+        }
+        (DataType::List { datatype: lhs }, DataType::List { datatype: rhs }) => DataType::List {
+            datatype: Box::new(merge_json_types(*lhs, *rhs)),
+        },
+        (DataType::Struct(lhs), DataType::Struct(rhs)) => {
+            let mut fields: BTreeMap<String, DataType> = lhs
+                .field_names
+                .iter()
+                .cloned()
+                .zip_eq_fast(lhs.fields.iter().cloned())
+                .collect();
+            for (name, data_type) in rhs
+                .field_names
+                .iter()
+                .cloned()
+                .zip_eq_fast(rhs.fields.iter().cloned())
+            {
+                let merged = match fields.remove(&name) {
+                    Some(existing) => merge_json_types(existing, data_type),
+                    None => data_type,
+                };
+                fields.insert(name, merged);
+            }
+            let (field_names, fields) = fields.into_iter().unzip();
+            DataType::new_struct(fields, field_names)
+        }
+        // Incompatible types across samples fall back to varchar, keeping the raw text.
+        _ => DataType::Varchar,
+    }
+}
+
+/// Infer the columns from sampled messages, as the union of the fields of all samples.
+fn infer_json_columns(messages: &[SourceMessage]) -> Result<Vec<(String, DataType)>> {
+    let mut columns: BTreeMap<String, Option<DataType>> = BTreeMap::new();
+    for message in messages {
+        let payload = message.payload.as_ref().ok_or_else(|| {
+            RwError::from(ProtocolError("sampled message has no payload".to_string()))
+        })?;
+        let value: serde_json::Value = serde_json::from_slice(payload).map_err(|e| {
+            RwError::from(ProtocolError(format!(
+                "failed to parse sampled message as json: {}",
+                e
+            )))
+        })?;
+        let object = match value {
+            serde_json::Value::Object(object) => object,
+            _ => {
+                return Err(RwError::from(ProtocolError(
+                    "sampled message is not a json object".to_string(),
+                )))
+            }
+        };
+        for (name, value) in object {
+            let inferred = infer_json_type(&value);
+            let column = columns.entry(name).or_insert(None);
+            *column = match (column.take(), inferred) {
+                (Some(lhs), Some(rhs)) => Some(merge_json_types(lhs, rhs)),
+                (lhs, rhs) => lhs.or(rhs),
+            };
+        }
+    }
+    Ok(columns
+        .into_iter()
+        // Fields that are `null` in all samples default to varchar.
+        .map(|(name, data_type)| (name, data_type.unwrap_or(DataType::Varchar)))
+        .collect())
+}
+
+fn data_type_to_ast_data_type(data_type: &DataType) -> AstDataType {
+    match data_type {
+        DataType::Boolean => AstDataType::Boolean,
+        DataType::Int64 => AstDataType::BigInt,
+        DataType::Float64 => AstDataType::Double,
+        DataType::Varchar => AstDataType::Varchar,
+        DataType::List { datatype } => {
+            AstDataType::Array(Box::new(data_type_to_ast_data_type(datatype)))
+        }
+        DataType::Struct(struct_type) => AstDataType::Struct(
+            struct_type
+                .field_names
+                .iter()
+                .zip_eq_fast(struct_type.fields.iter())
+                .map(|(name, data_type)| StructField {
+                    name: Ident::with_quote('"', name.clone()),
+                    data_type: data_type_to_ast_data_type(data_type),
+                })
+                .collect(),
+        ),
+        _ => unreachable!("data type {:?} is never inferred from json", data_type),
+    }
+}
+
+/// Sample some messages from the source, infer the columns from them, and render the completed
+/// `CREATE SOURCE` statement for the user to confirm.
+async fn infer_schema_for_source(
+    handler_args: HandlerArgs,
+    mut stmt: CreateSourceStatement,
+) -> Result<RwPgResponse> {
+    if !matches!(stmt.source_schema, SourceSchema::Json) {
+        return Err(ErrorCode::InvalidInputSyntax(
+            "INFER SCHEMA is only supported with ROW FORMAT JSON".to_owned(),
+        )
+        .into());
+    }
+    if !stmt.columns.is_empty() {
+        return Err(ErrorCode::InvalidInputSyntax(
+            "INFER SCHEMA cannot be used together with a user-defined schema".to_owned(),
+        )
+        .into());
+    }
+
+    let with_properties = handler_args.with_options.inner().clone();
+    let properties = ConnectorProperties::extract(with_properties)
+        .map_err(|e| RwError::from(ProtocolError(e.to_string())))?;
+    let messages = properties
+        .sample_messages(SCHEMA_INFER_SAMPLE_COUNT)
+        .await
+        .map_err(|e| RwError::from(ProtocolError(e.to_string())))?;
+    if messages.is_empty() {
+        return Err(RwError::from(ProtocolError(
+            "failed to infer the schema: no message sampled from the source".to_string(),
+        )));
+    }
+
+    stmt.columns = infer_json_columns(&messages)?
+        .into_iter()
+        .map(|(name, data_type)| {
+            ColumnDef::new(
+                Ident::with_quote('"', name),
+                data_type_to_ast_data_type(&data_type),
+                None,
+                vec![],
+            )
+        })
+        .collect();
+    stmt.infer_schema = false;
+
+    Ok(PgResponse::new_for_stream(
+        StatementType::SHOW_COMMAND,
+        None,
+        vec![Row::new(vec![Some(format!("{}", stmt).into())])].into(),
+        vec![PgFieldDescriptor::new(
+            "Create Sql".to_owned(),
+            DataType::VARCHAR.to_oid(),
+            DataType::VARCHAR.type_len(),
+        )],
+    ))
+}
+
 pub async fn handle_create_source(
     handler_args: HandlerArgs,
     stmt: CreateSourceStatement,
@@ -292,6 +491,10 @@ pub async fn handle_create_source(
 
     session.check_relation_name_duplicated(stmt.source_name.clone())?;
 
+    if stmt.infer_schema {
+        return infer_schema_for_source(handler_args, stmt).await;
+    }
+
     let db_name = session.database();
     let (schema_name, name) = Binder::resolve_schema_qualified_name(db_name, stmt.source_name)?;
     let (database_id, schema_id) = session.get_database_and_schema_id_for_create(schema_name)?;
@@ -12,12 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+
+use itertools::Itertools;
 use pgwire::pg_field_descriptor::PgFieldDescriptor;
 use pgwire::pg_response::{PgResponse, StatementType};
 use pgwire::types::Row;
 use risingwave_common::error::{ErrorCode, Result};
 use risingwave_common::types::DataType;
-use risingwave_sqlparser::ast::{ExplainOptions, ExplainType, Statement};
+use risingwave_sqlparser::ast::{ExplainOptions, ExplainType, ObjectName, Statement};
 
 use super::create_index::gen_create_index_plan;
 use super::create_mv::gen_create_mv_plan;
@@ -27,10 +30,12 @@ use super::create_table::{
 };
 use super::query::gen_batch_query_plan;
 use super::RwPgResponse;
+use crate::binder::{Binder, Relation};
 use crate::handler::HandlerArgs;
 use crate::optimizer::plan_node::Convention;
 use crate::optimizer::OptimizerContext;
 use crate::scheduler::BatchPlanFragmenter;
+use crate::session::SessionImpl;
 use crate::stream_fragmenter::build_graph;
 use crate::utils::explain_stream_graph;
 
@@ -48,6 +53,12 @@ pub async fn handle_explain(
 
     let session = context.session_ctx().clone();
 
+    // `EXPLAIN (DISTSQL) DESCRIBE <relation>` shows the actual actor placement of an existing
+    // streaming job, as reported by the meta service.
+    if let Statement::Describe { name } = &stmt && options.explain_type == ExplainType::DistSql {
+        return explain_actor_placement(&session, name.clone()).await;
+    }
+
     let mut plan_fragmenter = None;
     let mut rows = {
         let plan = match stmt {
@@ -133,6 +144,7 @@ pub async fn handle_explain(
                     plan_fragmenter = Some(BatchPlanFragmenter::new(
                         session.env().worker_node_manager_ref(),
                         session.env().catalog_reader().clone(),
+                        session.env().remote_catalogs().clone(),
                         plan,
                     )?);
                 }
@@ -195,3 +207,92 @@ pub async fn handle_explain(
         )],
     ))
 }
+
+/// Shows the fragments of an existing streaming job and the worker node each of its actors is
+/// placed on, as reported by the meta service.
+async fn explain_actor_placement(
+    session: &SessionImpl,
+    table_name: ObjectName,
+) -> Result<RwPgResponse> {
+    let table_id = {
+        let mut binder = Binder::new(session);
+        let relation = binder.bind_relation_by_name(table_name.clone(), None)?;
+        match relation {
+            Relation::BaseTable(table) => table.table_id,
+            _ => {
+                return Err(ErrorCode::NotSupported(
+                    format!("EXPLAIN (DISTSQL) DESCRIBE {}", table_name),
+                    "only tables, materialized views and indexes have actor placement".to_string(),
+                )
+                .into())
+            }
+        }
+    };
+
+    let mut table_fragments = session
+        .env()
+        .meta_client()
+        .list_table_fragments(&[table_id.table_id])
+        .await?;
+    let info = table_fragments.remove(&table_id.table_id).ok_or_else(|| {
+        ErrorCode::InternalError(format!(
+            "fragments of table {} not found in the meta service",
+            table_id
+        ))
+    })?;
+
+    // Map worker ids to their addresses for readability.
+    let worker_addrs: HashMap<u32, String> = session
+        .env()
+        .worker_node_manager()
+        .list_worker_nodes()
+        .into_iter()
+        .filter_map(|worker| {
+            worker
+                .host
+                .map(|host| (worker.id, format!("{}:{}", host.host, host.port)))
+        })
+        .collect();
+
+    let mut rows = vec![];
+    for fragment in info.fragments.iter().sorted_by_key(|fragment| fragment.id) {
+        rows.push(Row::new(vec![Some(
+            format!(
+                "Fragment {} (parallelism: {})",
+                fragment.id,
+                fragment.actors.len()
+            )
+            .into(),
+        )]));
+        let actors_by_worker = fragment
+            .actors
+            .iter()
+            .map(|actor| (actor.worker_id, actor.id))
+            .into_group_map();
+        for (worker_id, mut actor_ids) in actors_by_worker.into_iter().sorted() {
+            actor_ids.sort_unstable();
+            let addr = worker_addrs
+                .get(&worker_id)
+                .map(|addr| addr.as_str())
+                .unwrap_or("unknown");
+            rows.push(Row::new(vec![Some(
+                format!(
+                    "  Worker {} ({}): actors {:?}",
+                    worker_id, addr, actor_ids
+                )
+                .into(),
+            )]));
+        }
+    }
+
+    Ok(PgResponse::new_for_stream(
+        StatementType::EXPLAIN,
+        None,
+        rows.into(),
+        vec![PgFieldDescriptor::new(
+            "QUERY PLAN".to_owned(),
+            DataType::VARCHAR.to_oid(),
+            DataType::VARCHAR.type_len(),
+        )],
+    ))
+}
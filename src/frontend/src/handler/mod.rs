@@ -32,6 +32,7 @@ use crate::utils::WithOptions;
 
 mod alter_table;
 pub mod alter_user;
+mod analyze;
 mod create_database;
 pub mod create_function;
 pub mod create_index;
@@ -60,6 +61,7 @@ pub mod handle_privilege;
 pub mod privilege;
 pub mod query;
 mod show;
+mod transaction;
 pub mod util;
 pub mod variable;
 
@@ -248,6 +250,9 @@ pub async fn handle(
             handle_privilege::handle_revoke_privilege(handler_args, stmt).await
         }
         Statement::Describe { name } => describe::handle_describe(handler_args, name),
+        Statement::Analyze { table_name } => {
+            analyze::handle_analyze(handler_args, table_name).await
+        }
         Statement::ShowObjects(show_object) => show::handle_show_object(handler_args, show_object),
         Statement::ShowCreateObject { create_type, name } => {
             show::handle_show_create_object(handler_args, create_type, name)
@@ -400,6 +405,9 @@ pub async fn handle(
             ROLLBACK,
             "Ignored temporarily. See detail in issue#2541".to_string(),
         )),
+        Statement::SetTransaction {
+            modes, snapshot, ..
+        } => transaction::handle_set_transaction(handler_args, modes, snapshot).await,
         _ => Err(
             ErrorCode::NotImplemented(format!("Unhandled statement: {}", stmt), None.into()).into(),
         ),
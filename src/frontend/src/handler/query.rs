@@ -60,14 +60,20 @@ pub fn gen_batch_query_plan(
     let mut planner = Planner::new(context);
 
     let mut must_local = false;
+    let mut must_dist = stmt_type.is_dml();
     if let BoundStatement::Query(query) = &bound {
         if let BoundSetExpr::Select(select) = &query.body
-            && let Some(relation) = &select.from
-            && relation.contains_sys_table() {
+            && let Some(relation) = &select.from {
+            if relation.contains_sys_table() {
                 must_local = true;
+            }
+            // Scans of remote tables are scheduled onto the remote cluster's compute nodes,
+            // which only the distributed scheduler supports.
+            if relation.contains_remote_table() {
+                must_dist = true;
+            }
         }
     }
-    let must_dist = stmt_type.is_dml();
 
     let query_mode = match (must_dist, must_local) {
         (true, true) => {
@@ -116,6 +122,7 @@ pub async fn handle_query(
         let plan_fragmenter = BatchPlanFragmenter::new(
             session.env().worker_node_manager_ref(),
             session.env().catalog_reader().clone(),
+            session.env().remote_catalogs().clone(),
             plan,
         )?;
         context.append_notice(&mut notice);
@@ -176,35 +183,38 @@ pub async fn handle_query(
         | StatementType::UPDATE_RETURNING => None,
 
         StatementType::INSERT | StatementType::DELETE | StatementType::UPDATE => {
-            let first_row_set = row_stream.next().await;
-            let first_row_set = match first_row_set {
-                None => {
-                    return Err(RwError::from(ErrorCode::InternalError(
-                        "no affected rows in output".to_string(),
-                    )))
-                }
-                Some(row) => {
-                    row.map_err(|err| RwError::from(ErrorCode::InternalError(format!("{}", err))))?
+            // With distributed DML enabled, each parallel task returns its own affected row
+            // count, so sum the counts over all returned rows.
+            let mut total_affected_rows: i64 = 0;
+            let mut seen_rows = false;
+            while let Some(row_set) = row_stream.next().await {
+                let row_set = row_set
+                    .map_err(|err| RwError::from(ErrorCode::InternalError(format!("{}", err))))?;
+                for row in row_set {
+                    let affected_rows_str = row.values()[0]
+                        .as_ref()
+                        .expect("compute node should return affected rows in output");
+                    total_affected_rows += if let Format::Binary = first_field_format {
+                        i64::from_sql(&postgres_types::Type::INT8, affected_rows_str).unwrap()
+                    } else {
+                        String::from_utf8(affected_rows_str.to_vec())
+                            .unwrap()
+                            .parse()
+                            .unwrap_or_default()
+                    };
+                    seen_rows = true;
                 }
-            };
-            let affected_rows_str = first_row_set[0].values()[0]
-                .as_ref()
-                .expect("compute node should return affected rows in output");
-            if let Format::Binary = first_field_format {
-                Some(
-                    i64::from_sql(&postgres_types::Type::INT8, affected_rows_str)
-                        .unwrap()
-                        .try_into()
-                        .expect("affected rows count large than i32"),
-                )
-            } else {
-                Some(
-                    String::from_utf8(affected_rows_str.to_vec())
-                        .unwrap()
-                        .parse()
-                        .unwrap_or_default(),
-                )
             }
+            if !seen_rows {
+                return Err(RwError::from(ErrorCode::InternalError(
+                    "no affected rows in output".to_string(),
+                )));
+            }
+            Some(
+                total_affected_rows
+                    .try_into()
+                    .expect("affected rows count large than i32"),
+            )
         }
         _ => unreachable!(),
     };
@@ -167,7 +167,25 @@ pub fn handle_show_create_object(
                 .ok_or_else(|| CatalogError::NotFound("table", name.to_string()))?;
             table.create_sql()
         }
-        _ => {
+        ShowCreateType::Index => {
+            let index = schema
+                .get_index_by_name(&object_name)
+                .ok_or_else(|| CatalogError::NotFound("index", name.to_string()))?;
+            index.create_sql()
+        }
+        ShowCreateType::Source => {
+            let source = schema
+                .get_source_by_name(&object_name)
+                .ok_or_else(|| CatalogError::NotFound("source", name.to_string()))?;
+            source.create_sql()
+        }
+        ShowCreateType::Sink => {
+            let sink = schema
+                .get_sink_by_name(&object_name)
+                .ok_or_else(|| CatalogError::NotFound("sink", name.to_string()))?;
+            sink.create_sql()
+        }
+        ShowCreateType::Function => {
             return Err(ErrorCode::NotImplemented(
                 format!("show create on: {}", show_create_type),
                 None.into(),
@@ -264,4 +282,42 @@ mod tests {
 
         assert_eq!(columns, expected_columns);
     }
+
+    #[tokio::test]
+    async fn test_show_create_source() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+
+        let sql = r#"CREATE SOURCE t1 (v1 int, v2 varchar)
+        WITH (kafka.topic = 'abc', kafka.servers = 'localhost:1001')
+        ROW FORMAT JSON"#;
+        frontend.run_sql(sql).await.unwrap();
+
+        let mut pg_response = frontend.run_sql("SHOW CREATE SOURCE t1").await.unwrap();
+
+        let mut rows = vec![];
+        #[for_await]
+        for row_set in pg_response.values_stream() {
+            let row_set = row_set.unwrap();
+            for row in row_set {
+                rows.push((
+                    std::str::from_utf8(row.index(0).as_ref().unwrap())
+                        .unwrap()
+                        .to_string(),
+                    std::str::from_utf8(row.index(1).as_ref().unwrap())
+                        .unwrap()
+                        .to_string(),
+                ));
+            }
+        }
+
+        assert_eq!(
+            rows,
+            vec![(
+                "public.t1".to_string(),
+                "CREATE SOURCE \"t1\" (\"v1\" integer, \"v2\" varchar) \
+                 WITH (kafka.servers = 'localhost:1001', kafka.topic = 'abc') ROW FORMAT JSON"
+                    .to_string()
+            )]
+        );
+    }
 }
@@ -0,0 +1,104 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use pgwire::pg_response::{PgResponse, StatementType};
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_sqlparser::ast::{TransactionAccessMode, TransactionMode, Value};
+
+use super::{HandlerArgs, RwPgResponse};
+
+/// TTL of the lease acquired for an exported snapshot. The lease holds back the compaction
+/// watermark so that the snapshot stays readable, and expires on its own if the exporting
+/// session is abandoned, so that GC is never blocked forever.
+const SNAPSHOT_LEASE_TTL_SEC: u64 = 3600;
+
+/// Handles `SET TRANSACTION`, backed by the `QUERY_EPOCH` session variable.
+///
+/// - `SET TRANSACTION READ ONLY` pins the latest committed epoch as the session's snapshot,
+///   registers an iterator lease for it in meta and reports the lease id as a token in a notice.
+/// - `SET TRANSACTION SNAPSHOT '<token>'` resolves such a token against the lease in meta, so
+///   that multiple sessions (e.g. parallel export workers) read the exact same consistent
+///   snapshot. An expired or unknown token is rejected.
+/// - `SET TRANSACTION READ WRITE` releases the snapshot and reverts to always reading the latest
+///   epoch.
+///
+/// Isolation level modes are accepted but ignored, in the same way as `BEGIN` and
+/// `START TRANSACTION`.
+pub async fn handle_set_transaction(
+    handler_args: HandlerArgs,
+    modes: Vec<TransactionMode>,
+    snapshot: Option<Value>,
+) -> Result<RwPgResponse> {
+    let session = handler_args.session;
+
+    if let Some(snapshot) = snapshot {
+        let token = match snapshot {
+            Value::SingleQuotedString(s) => s,
+            Value::Number(s) => s,
+            v => {
+                return Err(ErrorCode::InvalidInputSyntax(format!(
+                    "invalid snapshot token: {}",
+                    v
+                ))
+                .into())
+            }
+        };
+        let lease_id = token.parse::<u64>().map_err(|_| {
+            ErrorCode::InvalidInputSyntax(format!("invalid snapshot token: {}", token))
+        })?;
+        let Some(epoch) = session.env().meta_client().get_iterator_lease(lease_id).await? else {
+            return Err(ErrorCode::InternalError(format!(
+                "snapshot token {} has expired or does not exist",
+                token
+            ))
+            .into());
+        };
+        session.set_config("query_epoch", vec![epoch.to_string()])?;
+        return Ok(PgResponse::empty_result(StatementType::SET_OPTION));
+    }
+
+    let mut notice = None;
+    for mode in modes {
+        match mode {
+            TransactionMode::AccessMode(TransactionAccessMode::ReadOnly) => {
+                let epoch = session
+                    .env()
+                    .hummock_snapshot_manager()
+                    .latest_snapshot_current_epoch();
+                let lease_id = session
+                    .env()
+                    .meta_client()
+                    .acquire_iterator_lease(epoch.0, SNAPSHOT_LEASE_TTL_SEC)
+                    .await?;
+                session.set_config("query_epoch", vec![epoch.0.to_string()])?;
+                notice = Some(format!(
+                    "Exported snapshot token: {}. Use `SET TRANSACTION SNAPSHOT '{}'` in other \
+                     sessions to read the same snapshot. The snapshot expires in {} seconds.",
+                    lease_id, lease_id, SNAPSHOT_LEASE_TTL_SEC
+                ));
+            }
+            TransactionMode::AccessMode(TransactionAccessMode::ReadWrite) => {
+                session.set_config("query_epoch", vec!["0".to_string()])?;
+            }
+            TransactionMode::IsolationLevel(_) => {
+                // Ignored: batch queries always read a consistent snapshot.
+            }
+        }
+    }
+
+    Ok(match notice {
+        Some(notice) => PgResponse::empty_result_with_notice(StatementType::SET_OPTION, notice),
+        None => PgResponse::empty_result(StatementType::SET_OPTION),
+    })
+}
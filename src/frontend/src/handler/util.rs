@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::future::Future;
 use std::pin::Pin;
 use std::sync::Arc;
 use std::task::{Context, Poll};
@@ -19,6 +20,8 @@ use std::task::{Context, Poll};
 use bytes::Bytes;
 use futures::Stream;
 use itertools::Itertools;
+use pgwire::error::SqlStateError;
+use pgwire::error_or_notice::SqlState;
 use pgwire::pg_field_descriptor::PgFieldDescriptor;
 use pgwire::pg_response::RowSetResult;
 use pgwire::pg_server::BoxedError;
@@ -31,6 +34,7 @@ use risingwave_common::row::Row as _;
 use risingwave_common::types::{DataType, ScalarRefImpl};
 use risingwave_common::util::iter_util::ZipEqFast;
 use risingwave_expr::vector_op::timestamptz::timestamptz_to_string;
+use tokio::time::Sleep;
 
 use crate::session::SessionImpl;
 
@@ -41,6 +45,10 @@ pin_project! {
     /// This is essentially `StreamExt::map(self, move |res| res.map(|chunk| to_pg_rows(chunk,
     /// format)))` but we need a nameable type as part of [`super::PgResponseStream`], but we cannot
     /// name the type of a closure.
+    ///
+    /// It also enforces the per-query resource limits of the session (`statement_timeout`,
+    /// `max_scan_bytes` and `max_result_rows`): when one is exceeded, the query is cancelled
+    /// through the abort path and the client gets an error with a proper SQLSTATE code.
     pub struct DataChunkToRowSetAdapter<VS>
     where
         VS: Stream<Item = Result<DataChunk, BoxedError>>,
@@ -50,6 +58,13 @@ pin_project! {
         column_types: Vec<DataType>,
         formats: Vec<Format>,
         session_data: StaticSessionData,
+        session: Arc<SessionImpl>,
+        #[pin]
+        statement_timeout: Option<Sleep>,
+        max_scan_bytes: Option<u64>,
+        max_result_rows: Option<u64>,
+        scanned_bytes: u64,
+        result_rows: u64,
     }
 }
 
@@ -71,11 +86,23 @@ where
         let session_data = StaticSessionData {
             timezone: session.config().get_timezone().into(),
         };
+        let statement_timeout = session
+            .config()
+            .get_statement_timeout()
+            .map(tokio::time::sleep);
+        let max_scan_bytes = session.config().get_max_scan_bytes();
+        let max_result_rows = session.config().get_max_result_rows();
         Self {
             chunk_stream,
             column_types,
             formats,
             session_data,
+            session,
+            statement_timeout,
+            max_scan_bytes,
+            max_result_rows,
+            scanned_bytes: 0,
+            result_rows: 0,
         }
     }
 }
@@ -88,14 +115,65 @@ where
 
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
         let mut this = self.project();
+        if let Some(statement_timeout) = this.statement_timeout.as_pin_mut()
+            && statement_timeout.poll(cx).is_ready()
+        {
+            this.session.cancel_current_query();
+            return Poll::Ready(Some(Err(Box::new(SqlStateError::new(
+                SqlState::QUERY_CANCELED,
+                "canceling statement due to statement timeout".to_string(),
+            )))));
+        }
         match this.chunk_stream.as_mut().poll_next(cx) {
             Poll::Pending => Poll::Pending,
             Poll::Ready(chunk) => match chunk {
                 Some(chunk_result) => match chunk_result {
-                    Ok(chunk) => Poll::Ready(Some(
-                        to_pg_rows(this.column_types, chunk, this.formats, this.session_data)
-                            .map_err(|err| err.into()),
-                    )),
+                    Ok(chunk) => {
+                        *this.result_rows += chunk.cardinality() as u64;
+                        if let Some(max_result_rows) = *this.max_result_rows
+                            && *this.result_rows > max_result_rows
+                        {
+                            this.session.cancel_current_query();
+                            return Poll::Ready(Some(Err(Box::new(SqlStateError::new(
+                                SqlState::PROGRAM_LIMIT_EXCEEDED,
+                                format!(
+                                    "query returns more than max_result_rows ({}) rows",
+                                    max_result_rows
+                                ),
+                            )))));
+                        }
+                        match to_pg_rows(this.column_types, chunk, this.formats, this.session_data)
+                        {
+                            Ok(rows) => {
+                                // The bytes fetched from the stage executions are approximated by
+                                // the bytes of the materialized rows.
+                                *this.scanned_bytes += rows
+                                    .iter()
+                                    .map(|row| {
+                                        row.values()
+                                            .iter()
+                                            .flatten()
+                                            .map(|value| value.len() as u64)
+                                            .sum::<u64>()
+                                    })
+                                    .sum::<u64>();
+                                if let Some(max_scan_bytes) = *this.max_scan_bytes
+                                    && *this.scanned_bytes > max_scan_bytes
+                                {
+                                    this.session.cancel_current_query();
+                                    return Poll::Ready(Some(Err(Box::new(SqlStateError::new(
+                                        SqlState::PROGRAM_LIMIT_EXCEEDED,
+                                        format!(
+                                            "query scans more than max_scan_bytes ({}) bytes",
+                                            max_scan_bytes
+                                        ),
+                                    )))));
+                                }
+                                Poll::Ready(Some(Ok(rows)))
+                            }
+                            Err(err) => Poll::Ready(Some(Err(err.into()))),
+                        }
+                    }
                     Err(err) => Poll::Ready(Some(Err(err))),
                 },
                 None => Poll::Ready(None),
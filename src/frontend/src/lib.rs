@@ -109,6 +109,11 @@ pub struct FrontendOpts {
     )]
     pub health_check_listener_addr: String,
 
+    /// Mount the catalogs of remote RisingWave clusters read-only, as comma-separated
+    /// `name=meta_addr` pairs. See [`catalog::federation`] for details.
+    #[clap(long, env = "RW_MOUNT_REMOTE_CATALOG")]
+    pub mount_remote_catalog: Option<String>,
+
     /// The path of `risingwave.toml` configuration file.
     ///
     /// If empty, default configuration values will be used.
@@ -15,8 +15,9 @@
 use std::collections::HashMap;
 
 use risingwave_pb::backup_service::MetaSnapshotMetadata;
-use risingwave_pb::hummock::HummockSnapshot;
+use risingwave_pb::hummock::{CompactTaskSummary, EpochCommitTime, HummockSnapshot, LevelSummary};
 use risingwave_pb::meta::list_table_fragments_response::TableFragmentInfo;
+use risingwave_pb::meta::{MvStatus, SinkFreshness};
 use risingwave_rpc_client::error::Result;
 use risingwave_rpc_client::{HummockMetaClient, MetaClient};
 
@@ -42,7 +43,21 @@ pub trait FrontendMetaClient: Send + Sync {
 
     async fn unpin_snapshot_before(&self, epoch: u64) -> Result<()>;
 
+    async fn acquire_iterator_lease(&self, epoch: u64, ttl_sec: u64) -> Result<u64>;
+
+    async fn get_iterator_lease(&self, lease_id: u64) -> Result<Option<u64>>;
+
     async fn list_meta_snapshots(&self) -> Result<Vec<MetaSnapshotMetadata>>;
+
+    async fn list_mv_status(&self) -> Result<Vec<MvStatus>>;
+
+    async fn list_sink_freshness(&self) -> Result<Vec<SinkFreshness>>;
+
+    async fn list_epoch_commit_times(&self) -> Result<Vec<EpochCommitTime>>;
+
+    async fn list_compact_task_summaries(&self) -> Result<Vec<CompactTaskSummary>>;
+
+    async fn list_level_summaries(&self) -> Result<Vec<LevelSummary>>;
 }
 
 pub struct FrontendMetaClientImpl(pub MetaClient);
@@ -76,8 +91,36 @@ impl FrontendMetaClient for FrontendMetaClientImpl {
         self.0.unpin_snapshot_before(epoch).await
     }
 
+    async fn acquire_iterator_lease(&self, epoch: u64, ttl_sec: u64) -> Result<u64> {
+        self.0.acquire_iterator_lease(epoch, ttl_sec).await
+    }
+
+    async fn get_iterator_lease(&self, lease_id: u64) -> Result<Option<u64>> {
+        self.0.get_iterator_lease(lease_id).await
+    }
+
     async fn list_meta_snapshots(&self) -> Result<Vec<MetaSnapshotMetadata>> {
         let manifest = self.0.get_meta_snapshot_manifest().await?;
         Ok(manifest.snapshot_metadata)
     }
+
+    async fn list_mv_status(&self) -> Result<Vec<MvStatus>> {
+        self.0.list_mv_status().await
+    }
+
+    async fn list_sink_freshness(&self) -> Result<Vec<SinkFreshness>> {
+        self.0.list_sink_freshness().await
+    }
+
+    async fn list_epoch_commit_times(&self) -> Result<Vec<EpochCommitTime>> {
+        self.0.list_epoch_commit_times().await
+    }
+
+    async fn list_compact_task_summaries(&self) -> Result<Vec<CompactTaskSummary>> {
+        self.0.list_compact_task_summaries().await
+    }
+
+    async fn list_level_summaries(&self) -> Result<Vec<LevelSummary>> {
+        self.0.list_level_summaries().await
+    }
 }
@@ -529,10 +529,13 @@ impl PlanRoot {
             ctx.trace("To Batch Distributed Plan:");
             ctx.trace(plan.explain_to_string().unwrap());
         }
-        if has_batch_insert(plan.clone())
-            || has_batch_delete(plan.clone())
-            || has_batch_update(plan.clone())
-            || Self::require_additional_exchange_on_root(plan.clone())
+        // If the root is already an exchange, the required single distribution has been enforced
+        // there and no additional one is needed. This happens for parallelized DML writes.
+        if plan.node_type() != PlanNodeType::BatchExchange
+            && (has_batch_insert(plan.clone())
+                || has_batch_delete(plan.clone())
+                || has_batch_update(plan.clone())
+                || Self::require_additional_exchange_on_root(plan.clone()))
         {
             plan =
                 BatchExchange::new(plan, self.required_order.clone(), Distribution::Single).into();
@@ -695,7 +698,11 @@ impl PlanRoot {
     }
 
     /// Optimize and generate a create index plan.
-    pub fn gen_index_plan(&mut self, index_name: String) -> Result<StreamMaterialize> {
+    pub fn gen_index_plan(
+        &mut self,
+        index_name: String,
+        definition: String,
+    ) -> Result<StreamMaterialize> {
         let stream_plan = self.gen_stream_plan()?;
 
         StreamMaterialize::create(
@@ -705,7 +712,7 @@ impl PlanRoot {
             self.required_order.clone(),
             self.out_fields.clone(),
             self.out_names.clone(),
-            "".into(), // TODO: fill definition here for `SHOW CREATE`
+            definition,
             TableType::Index,
         )
     }
@@ -35,13 +35,11 @@ pub struct BatchInsert {
 impl BatchInsert {
     pub fn new(logical: LogicalInsert) -> Self {
         let ctx = logical.base.ctx.clone();
-        // TODO: derive from input
-        let base = PlanBase::new_batch(
-            ctx,
-            logical.schema().clone(),
-            Distribution::Single,
-            Order::any(),
-        );
+        let dist = match logical.input().distribution() {
+            Distribution::Single => Distribution::Single,
+            _ => Distribution::SomeShard,
+        };
+        let base = PlanBase::new_batch(ctx, logical.schema().clone(), dist, Order::any());
         BatchInsert { base, logical }
     }
 }
@@ -66,8 +64,32 @@ impl_plan_tree_node_for_unary! { BatchInsert }
 
 impl ToDistributedBatch for BatchInsert {
     fn to_distributed(&self) -> Result<PlanRef> {
-        let new_input = RequiredDist::single()
-            .enforce_if_not_satisfies(self.input().to_distributed()?, &Order::any())?;
+        let input = self.input().to_distributed()?;
+        let new_input = if self
+            .base
+            .ctx
+            .session_ctx()
+            .config()
+            .get_batch_enable_distributed_dml()
+        {
+            // Write in parallel on the compute nodes instead of funneling all rows through a
+            // single DML channel. Each parallel task writes into the DML channel local to the
+            // compute node it is scheduled on, and the table source executors stamp the rows
+            // with an epoch in barrier order per channel, so no cross-node epoch coordination
+            // is needed. When the distribution key is fully provided by the insert source,
+            // shard the input by it so that each row is written on its vnode-owning node;
+            // tables distributed by the hidden row id accept rows from any shard. Each
+            // parallel task returns its own affected row count and the handler sums them up.
+            let dist_key_indices = self.logical.dist_key_indices();
+            if dist_key_indices.is_empty() {
+                input
+            } else {
+                RequiredDist::shard_by_key(input.schema().len(), &dist_key_indices)
+                    .enforce_if_not_satisfies(input, &Order::any())?
+            }
+        } else {
+            RequiredDist::single().enforce_if_not_satisfies(input, &Order::any())?
+        };
         Ok(self.clone_with_input(new_input).into())
     }
 }
@@ -0,0 +1,110 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use risingwave_common::error::Result;
+use risingwave_pb::batch_plan::plan_node::NodeBody;
+use risingwave_pb::batch_plan::LateralTableFunctionNode;
+
+use super::ExprRewritable;
+use crate::expr::ExprRewriter;
+use crate::optimizer::plan_node::{
+    LogicalLateralTableFunction, PlanBase, PlanTreeNodeUnary, ToBatchProst, ToDistributedBatch,
+    ToLocalBatch,
+};
+use crate::optimizer::property::{Distribution, Order};
+use crate::optimizer::PlanRef;
+
+#[derive(Debug, Clone)]
+pub struct BatchLateralTableFunction {
+    pub base: PlanBase,
+    logical: LogicalLateralTableFunction,
+}
+
+impl BatchLateralTableFunction {
+    pub fn new(logical: LogicalLateralTableFunction) -> Self {
+        let ctx = logical.base.ctx.clone();
+        let dist = match logical.input().distribution() {
+            Distribution::Single => Distribution::Single,
+            Distribution::SomeShard
+            | Distribution::HashShard(_)
+            | Distribution::UpstreamHashShard(_, _) => Distribution::SomeShard,
+            Distribution::Broadcast => unreachable!(),
+        };
+        let base = PlanBase::new_batch(ctx, logical.schema().clone(), dist, Order::any());
+        BatchLateralTableFunction { base, logical }
+    }
+}
+
+impl fmt::Display for BatchLateralTableFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "BatchLateralTableFunction {{ {:?} }}",
+            self.logical.table_function
+        )
+    }
+}
+
+impl PlanTreeNodeUnary for BatchLateralTableFunction {
+    fn input(&self) -> PlanRef {
+        self.logical.input()
+    }
+
+    fn clone_with_input(&self, input: PlanRef) -> Self {
+        Self::new(self.logical.clone_with_input(input))
+    }
+}
+
+impl_plan_tree_node_for_unary! { BatchLateralTableFunction }
+
+impl ToDistributedBatch for BatchLateralTableFunction {
+    fn to_distributed(&self) -> Result<PlanRef> {
+        let new_input = self.input().to_distributed()?;
+        Ok(self.clone_with_input(new_input).into())
+    }
+}
+
+impl ToBatchProst for BatchLateralTableFunction {
+    fn to_batch_prost_body(&self) -> NodeBody {
+        NodeBody::LateralTableFunction(LateralTableFunctionNode {
+            table_function: Some(self.logical.table_function.to_protobuf()),
+        })
+    }
+}
+
+impl ToLocalBatch for BatchLateralTableFunction {
+    fn to_local(&self) -> Result<PlanRef> {
+        let new_input = self.input().to_local()?;
+        Ok(self.clone_with_input(new_input).into())
+    }
+}
+
+impl ExprRewritable for BatchLateralTableFunction {
+    fn has_rewritable_expr(&self) -> bool {
+        true
+    }
+
+    fn rewrite_exprs(&self, r: &mut dyn ExprRewriter) -> PlanRef {
+        Self::new(
+            self.logical
+                .rewrite_exprs(r)
+                .as_logical_lateral_table_function()
+                .unwrap()
+                .clone(),
+        )
+        .into()
+    }
+}
@@ -238,6 +238,38 @@ impl<PlanRef: stream::StreamPlanRef> Agg<PlanRef> {
                         type_name: String::default(),
                     });
                 }
+                AggKind::ApproxTopK => {
+                    // Add the column for the space-saving sketch entries.
+                    internal_table_catalog_builder.add_column(&Field {
+                        data_type: DataType::List {
+                            datatype: Box::new(DataType::new_struct(
+                                vec![DataType::Varchar, DataType::Int64, DataType::Int64],
+                                vec![
+                                    String::from("value"),
+                                    String::from("count"),
+                                    String::from("error"),
+                                ],
+                            )),
+                        },
+                        name: String::from("entries"),
+                        sub_fields: vec![],
+                        type_name: String::default(),
+                    });
+                }
+                AggKind::ApproxPercentile => {
+                    // Add the column for the t-digest centroids.
+                    internal_table_catalog_builder.add_column(&Field {
+                        data_type: DataType::List {
+                            datatype: Box::new(DataType::new_struct(
+                                vec![DataType::Float64, DataType::Int64],
+                                vec![String::from("mean"), String::from("weight")],
+                            )),
+                        },
+                        name: String::from("centroids"),
+                        sub_fields: vec![],
+                        type_name: String::default(),
+                    });
+                }
                 _ => {
                     panic!(
                         "state of agg kind `{}` is not supposed to be `TableState`",
@@ -264,7 +296,9 @@ impl<PlanRef: stream::StreamPlanRef> Agg<PlanRef> {
                 | AggKind::Max
                 | AggKind::StringAgg
                 | AggKind::ArrayAgg
-                | AggKind::FirstValue => {
+                | AggKind::FirstValue
+                | AggKind::PercentileCont
+                | AggKind::PercentileDisc => {
                     if !in_append_only {
                         // columns with order requirement in state table
                         let sort_keys = {
@@ -275,7 +309,10 @@ impl<PlanRef: stream::StreamPlanRef> Agg<PlanRef> {
                                 AggKind::Max => {
                                     vec![(OrderType::Descending, agg_call.inputs[0].index)]
                                 }
-                                AggKind::StringAgg | AggKind::ArrayAgg => agg_call
+                                AggKind::StringAgg
+                                | AggKind::ArrayAgg
+                                | AggKind::PercentileCont
+                                | AggKind::PercentileDisc => agg_call
                                     .order_by_fields
                                     .iter()
                                     .map(|o| (o.direction.to_order(), o.input.index))
@@ -285,7 +322,10 @@ impl<PlanRef: stream::StreamPlanRef> Agg<PlanRef> {
                         };
                         // other columns that should be contained in state table
                         let include_keys = match agg_call.agg_kind {
-                            AggKind::StringAgg | AggKind::ArrayAgg => {
+                            AggKind::StringAgg
+                            | AggKind::ArrayAgg
+                            | AggKind::PercentileCont
+                            | AggKind::PercentileDisc => {
                                 agg_call.inputs.iter().map(|i| i.index).collect()
                             }
                             _ => vec![],
@@ -309,6 +349,18 @@ impl<PlanRef: stream::StreamPlanRef> Agg<PlanRef> {
                         AggCallState::Table(Box::new(state))
                     }
                 }
+                AggKind::ApproxTopK => {
+                    // The space-saving sketch cannot handle deletions, so the append-only
+                    // requirement has been checked when converting to the stream plan.
+                    let state = gen_table_state(agg_call.agg_kind);
+                    AggCallState::Table(Box::new(state))
+                }
+                AggKind::ApproxPercentile => {
+                    // The t-digest sketch folds deletions into the nearest centroid, so it works
+                    // on both append-only and retractable streams.
+                    let state = gen_table_state(agg_call.agg_kind);
+                    AggCallState::Table(Box::new(state))
+                }
             })
             .collect()
     }
@@ -580,6 +632,15 @@ impl PlanAggCall {
             AggKind::ArrayAgg => {
                 panic!("2-phase ArrayAgg is not supported yet")
             }
+            AggKind::ApproxTopK => {
+                panic!("2-phase ApproxTopK is not supported yet")
+            }
+            AggKind::PercentileCont | AggKind::PercentileDisc => {
+                panic!("2-phase percentile aggregation is not supported yet")
+            }
+            AggKind::ApproxPercentile => {
+                panic!("2-phase approx_percentile is not supported yet")
+            }
         };
         PlanAggCall {
             agg_kind: total_agg_kind,
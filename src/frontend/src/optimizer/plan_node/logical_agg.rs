@@ -172,10 +172,20 @@ impl LogicalAgg {
     }
 
     fn gen_dist_stream_agg_plan(&self, stream_input: PlanRef) -> Result<PlanRef> {
-        // having group key, is not simple agg. we will just use shuffle agg
-        // TODO(stonepage): in some situation the 2-phase agg is better. maybe some switch or
-        // hints for it.
+        let input_dist = stream_input.distribution().clone();
+        let input_append_only = stream_input.append_only();
+
+        // having group key, is not simple agg. we will just use shuffle agg, unless the session
+        // forces 2-phase agg and the input is hash-distributed, in which case the vnode-based
+        // 2-phase agg can pre-aggregate skewed group keys before the shuffle.
         if !self.group_key().is_empty() {
+            if self.must_try_two_phase_agg() {
+                if let Distribution::HashShard(dists) | Distribution::UpstreamHashShard(dists, _) =
+                    input_dist
+                {
+                    return self.gen_vnode_two_phase_streaming_agg_plan(stream_input, &dists);
+                }
+            }
             return Ok(StreamHashAgg::new(
                 self.clone_with_input(
                     RequiredDist::shard_by_key(stream_input.schema().len(), self.group_key())
@@ -187,9 +197,6 @@ impl LogicalAgg {
         }
 
         // now only simple agg
-        let input_dist = stream_input.distribution().clone();
-        let input_append_only = stream_input.append_only();
-
         let gen_single_plan = |stream_input: PlanRef| -> Result<PlanRef> {
             Ok(StreamGlobalSimpleAgg::new(self.clone_with_input(
                 RequiredDist::single().enforce_if_not_satisfies(stream_input, &Order::any())?,
@@ -227,9 +234,15 @@ impl LogicalAgg {
 
     /// Check if the aggregation result will be affected by order by clause, if any.
     pub(crate) fn is_agg_result_affected_by_order(&self) -> bool {
-        self.agg_calls()
-            .iter()
-            .any(|call| matches!(call.agg_kind, AggKind::StringAgg | AggKind::ArrayAgg))
+        self.agg_calls().iter().any(|call| {
+            matches!(
+                call.agg_kind,
+                AggKind::StringAgg
+                    | AggKind::ArrayAgg
+                    | AggKind::PercentileCont
+                    | AggKind::PercentileDisc
+            )
+        })
     }
 
     pub(crate) fn can_two_phase_agg(&self) -> bool {
@@ -241,12 +254,30 @@ impl LogicalAgg {
             // QUESTION: why do we need `&& call.order_by_fields.is_empty()` ?
             //    && call.order_by_fields.is_empty()
         }) && !self.is_agg_result_affected_by_order()
-            && self
+            && (self
                 .base
                 .ctx()
                 .session_ctx()
                 .config()
                 .get_enable_two_phase_agg()
+                || self
+                    .base
+                    .ctx()
+                    .session_ctx()
+                    .config()
+                    .get_force_two_phase_agg())
+    }
+
+    /// Whether the session forces generating a 2-phase agg plan whenever the agg calls are
+    /// eligible for it, regardless of the input distribution.
+    pub(crate) fn must_try_two_phase_agg(&self) -> bool {
+        self.can_two_phase_agg()
+            && self
+                .base
+                .ctx()
+                .session_ctx()
+                .config()
+                .get_force_two_phase_agg()
     }
 
     // Check if the output of the aggregation needs to be sorted and return ordering req by group
@@ -396,7 +427,14 @@ impl LogicalAggBuilder {
         let mut has_non_distinct_array_agg = false;
         self.agg_calls.iter().for_each(|agg_call| {
             if agg_call.distinct {
-                has_distinct = true;
+                // Distinct `string_agg` with order by clause is deduplicated by the agg
+                // executors directly based on its materialized input, so it doesn't require
+                // the 2-phase rewriting and doesn't count as a distinct aggregate here.
+                if !(agg_call.agg_kind == AggKind::StringAgg
+                    && !agg_call.order_by_fields.is_empty())
+                {
+                    has_distinct = true;
+                }
             }
             if !agg_call.order_by_fields.is_empty() {
                 has_order_by = true;
@@ -981,8 +1019,24 @@ impl ToStream for LogicalAgg {
             .chain(self.agg_calls().iter().cloned())
             .collect_vec();
 
+        let stream_input = self.input().to_stream(ctx)?;
+
+        // The space-saving sketch of `approx_top_k` cannot handle deletions.
+        if !stream_input.append_only()
+            && self
+                .agg_calls()
+                .iter()
+                .any(|call| call.agg_kind == AggKind::ApproxTopK)
+        {
+            return Err(ErrorCode::NotImplemented(
+                "approx_top_k on a non-append-only stream".to_string(),
+                None.into(),
+            )
+            .into());
+        }
+
         let logical_agg = LogicalAgg::new(agg_calls, self.group_key().to_vec(), self.input());
-        let stream_agg = logical_agg.gen_dist_stream_agg_plan(self.input().to_stream(ctx)?)?;
+        let stream_agg = logical_agg.gen_dist_stream_agg_plan(stream_input)?;
 
         let stream_project = StreamProject::new(LogicalProject::with_out_col_idx(
             stream_agg,
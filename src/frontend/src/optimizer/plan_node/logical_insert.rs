@@ -40,6 +40,7 @@ pub struct LogicalInsert {
     table_id: TableId,
     input: PlanRef,
     column_indices: Vec<usize>, // columns in which to insert
+    dist_key_indices: Vec<usize>, // distribution key columns of the table in the input
     row_id_index: Option<usize>,
     returning: bool,
 }
@@ -51,6 +52,7 @@ impl LogicalInsert {
         table_name: String,
         table_id: TableId,
         column_indices: Vec<usize>,
+        dist_key_indices: Vec<usize>,
         row_id_index: Option<usize>,
         returning: bool,
     ) -> Self {
@@ -68,6 +70,7 @@ impl LogicalInsert {
             table_id,
             input,
             column_indices,
+            dist_key_indices,
             row_id_index,
             returning,
         }
@@ -79,6 +82,7 @@ impl LogicalInsert {
         table_name: String,
         table_id: TableId,
         column_indices: Vec<usize>,
+        dist_key_indices: Vec<usize>,
         row_id_index: Option<usize>,
         returning: bool,
     ) -> Result<Self> {
@@ -87,6 +91,7 @@ impl LogicalInsert {
             table_name,
             table_id,
             column_indices,
+            dist_key_indices,
             row_id_index,
             returning,
         ))
@@ -112,6 +117,12 @@ impl LogicalInsert {
         self.column_indices.clone()
     }
 
+    // Get the indexes of the distribution key columns of the table in the input
+    #[must_use]
+    pub fn dist_key_indices(&self) -> Vec<usize> {
+        self.dist_key_indices.clone()
+    }
+
     #[must_use]
     pub fn table_id(&self) -> TableId {
         self.table_id
@@ -138,6 +149,7 @@ impl PlanTreeNodeUnary for LogicalInsert {
             self.table_name.clone(),
             self.table_id,
             self.column_indices.clone(),
+            self.dist_key_indices.clone(),
             self.row_id_index,
             self.returning,
         )
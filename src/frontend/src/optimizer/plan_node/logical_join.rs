@@ -20,6 +20,7 @@ use fixedbitset::FixedBitSet;
 use itertools::Itertools;
 use risingwave_common::catalog::Schema;
 use risingwave_common::error::{ErrorCode, Result, RwError};
+use risingwave_common::types::{DataType, ScalarImpl};
 use risingwave_pb::plan_common::JoinType;
 
 use super::generic::GenericPlanNode;
@@ -34,10 +35,11 @@ use crate::optimizer::plan_node::utils::IndicesDisplay;
 use crate::optimizer::plan_node::{
     BatchFilter, BatchHashJoin, BatchLookupJoin, BatchNestedLoopJoin, ColumnPruningContext,
     EqJoinPredicate, LogicalFilter, LogicalScan, PredicatePushdownContext, RewriteStreamContext,
-    StreamDynamicFilter, StreamFilter, ToStreamContext,
+    StreamDynamicFilter, StreamFilter, StreamIntervalJoin, ToStreamContext,
 };
-use crate::optimizer::plan_visitor::{MaxOneRowVisitor, PlanVisitor};
+use crate::optimizer::plan_visitor::{estimate_cardinality, MaxOneRowVisitor, PlanVisitor};
 use crate::optimizer::property::{Distribution, FunctionalDependencySet, Order, RequiredDist};
+use crate::optimizer::rule::JoinCommuteRule;
 use crate::utils::{ColIndexMapping, Condition, ConditionDisplay};
 
 /// `LogicalJoin` combines two relations according to some condition.
@@ -1183,6 +1185,164 @@ impl LogicalJoin {
         }
     }
 
+    /// Interprets `expr` as an event time column optionally shifted by a constant interval,
+    /// returning the column and the shift in milliseconds. Intervals involving months are
+    /// rejected, as they have no fixed width in milliseconds.
+    fn as_time_offset(expr: &ExprImpl) -> Option<(&InputRef, i64)> {
+        match expr {
+            ExprImpl::InputRef(input_ref) => Some((input_ref, 0)),
+            ExprImpl::FunctionCall(function_call) => {
+                let sign = match function_call.get_expr_type() {
+                    ExprType::Add => 1,
+                    ExprType::Subtract => -1,
+                    _ => return None,
+                };
+                let (input_ref, literal) = match function_call.inputs() {
+                    [ExprImpl::InputRef(input_ref), ExprImpl::Literal(literal)] => {
+                        (input_ref, literal)
+                    }
+                    _ => return None,
+                };
+                let interval = match literal.get_data() {
+                    Some(ScalarImpl::Interval(interval)) => interval,
+                    _ => return None,
+                };
+                if interval.get_months() != 0 {
+                    return None;
+                }
+                let offset_ms =
+                    interval.get_days() as i64 * 24 * 60 * 60 * 1000 + interval.get_ms();
+                Some((input_ref, sign * offset_ms))
+            }
+            _ => None,
+        }
+    }
+
+    /// Normalizes `expr` to `left.ts - right.ts >= bound_ms` (`is_lower`) or `<= bound_ms`,
+    /// returning `(left_ts_idx, right_ts_idx, is_lower, bound_ms)`. Both columns must be
+    /// timestamps from different inputs.
+    fn as_interval_cond(expr: &ExprImpl, left_len: usize) -> Option<(usize, usize, bool, i64)> {
+        let function_call = expr.as_function_call()?;
+        let is_greater = match function_call.get_expr_type() {
+            ExprType::GreaterThanOrEqual => true,
+            ExprType::LessThanOrEqual => false,
+            // Strict comparisons are not supported for now.
+            _ => return None,
+        };
+        let (a_expr, b_expr) = match function_call.inputs() {
+            [a_expr, b_expr] => (a_expr, b_expr),
+            _ => return None,
+        };
+        let (a, a_off_ms) = Self::as_time_offset(a_expr)?;
+        let (b, b_off_ms) = Self::as_time_offset(b_expr)?;
+        if a.return_type() != DataType::Timestamp || b.return_type() != DataType::Timestamp {
+            return None;
+        }
+        // `a + a_off CMP b + b_off`, i.e. `a - b CMP b_off - a_off`.
+        if a.index < left_len && b.index >= left_len {
+            Some((a.index, b.index - left_len, is_greater, b_off_ms - a_off_ms))
+        } else if b.index < left_len && a.index >= left_len {
+            Some((b.index, a.index - left_len, !is_greater, a_off_ms - b_off_ms))
+        } else {
+            None
+        }
+    }
+
+    /// If the join condition consists of exactly two comparisons that together bound
+    /// `left.ts - right.ts` to a fixed interval, e.g. as produced by
+    /// `a.ts BETWEEN b.ts - INTERVAL '1' MINUTE AND b.ts + INTERVAL '1' MINUTE`, the inner
+    /// join can be converted to a [`StreamIntervalJoin`]. Both event time columns must carry
+    /// watermarks, which the executor relies on to clean up state.
+    fn to_stream_interval_join(
+        &self,
+        predicate: Condition,
+        ctx: &mut ToStreamContext,
+    ) -> Result<Option<PlanRef>> {
+        /// The minimum width of a state time bucket. The bucket is as coarse as the bound range
+        /// so that a probe scans at most two buckets, but not finer than this, to avoid an
+        /// excessive number of buckets for narrow ranges.
+        const MIN_BUCKET_SIZE_MS: i64 = 60 * 1000;
+
+        if self.join_type() != JoinType::Inner {
+            return Ok(None);
+        }
+        if predicate.conjunctions.len() != 2 {
+            return Ok(None);
+        }
+
+        let left_len = self.left().schema().len();
+        let mut ts_pair = None;
+        let mut lower_bound_ms = None;
+        let mut upper_bound_ms = None;
+        for conjunction in &predicate.conjunctions {
+            let (left_ts_idx, right_ts_idx, is_lower, bound_ms) =
+                match Self::as_interval_cond(conjunction, left_len) {
+                    Some(cond) => cond,
+                    None => return Ok(None),
+                };
+            if *ts_pair.get_or_insert((left_ts_idx, right_ts_idx)) != (left_ts_idx, right_ts_idx)
+            {
+                return Ok(None);
+            }
+            let bound = if is_lower {
+                &mut lower_bound_ms
+            } else {
+                &mut upper_bound_ms
+            };
+            if bound.replace(bound_ms).is_some() {
+                return Ok(None);
+            }
+        }
+        let (left_ts_idx, right_ts_idx) = ts_pair.unwrap();
+        let (lower_bound_ms, upper_bound_ms) = match (lower_bound_ms, upper_bound_ms) {
+            (Some(lower_bound_ms), Some(upper_bound_ms)) if lower_bound_ms <= upper_bound_ms => {
+                (lower_bound_ms, upper_bound_ms)
+            }
+            _ => return Ok(None),
+        };
+
+        // The executor scans the full state of the other side per input row, so both sides must
+        // be colocated on a single actor.
+        let left = RequiredDist::single()
+            .enforce_if_not_satisfies(self.left().to_stream(ctx)?, &Order::any())?;
+        let right = RequiredDist::single()
+            .enforce_if_not_satisfies(self.right().to_stream(ctx)?, &Order::any())?;
+
+        if !left.watermark_columns()[left_ts_idx] || !right.watermark_columns()[right_ts_idx] {
+            return Ok(None);
+        }
+
+        let bucket_size_ms = (upper_bound_ms - lower_bound_ms).max(MIN_BUCKET_SIZE_MS);
+
+        let default_indices = (0..self.internal_column_num()).collect::<Vec<_>>();
+        let logical_join = self
+            .clone_with_left_right(left, right)
+            .clone_with_output_indices(default_indices.clone());
+        let plan: PlanRef = StreamIntervalJoin::new(
+            logical_join,
+            left_ts_idx,
+            right_ts_idx,
+            lower_bound_ms,
+            upper_bound_ms,
+            bucket_size_ms,
+        )
+        .into();
+
+        // `IntervalJoinExecutor` always outputs all columns from both sides.
+        if self.output_indices() != &default_indices {
+            let logical_project = LogicalProject::with_mapping(
+                plan,
+                ColIndexMapping::with_remaining_columns(
+                    self.output_indices(),
+                    self.internal_column_num(),
+                ),
+            );
+            Ok(Some(StreamProject::new(logical_project).into()))
+        } else {
+            Ok(Some(plan))
+        }
+    }
+
     fn to_batch_hash_join(
         &self,
         predicate: EqJoinPredicate,
@@ -1254,6 +1414,22 @@ impl ToBatch for LogicalJoin {
             self.on().clone(),
         );
 
+        // Prefer building the hash table on the smaller input: if both inputs have row counts
+        // collected by `ANALYZE` and the left one is estimated to be smaller, commute the join
+        // so that it becomes the right (build) side. Without statistics the join is left
+        // untouched.
+        if self.join_type() == JoinType::Inner && predicate.has_eq() {
+            let left_rows = estimate_cardinality(self.left());
+            let right_rows = estimate_cardinality(self.right());
+            if let (Some(left_rows), Some(right_rows)) = (left_rows, right_rows) {
+                if left_rows < right_rows {
+                    // The commuted join no longer satisfies the condition above, so this
+                    // recurses at most once.
+                    return JoinCommuteRule::commute(self).to_batch();
+                }
+            }
+        }
+
         let left = self.left().to_batch()?;
         let right = self.right().to_batch()?;
         let logical_join = self.clone_with_left_right(left, right);
@@ -1304,6 +1480,8 @@ impl ToStream for LogicalJoin {
             self.to_stream_dynamic_filter(self.on().clone(), ctx)?
         {
             Ok(dynamic_filter)
+        } else if let Some(interval_join) = self.to_stream_interval_join(self.on().clone(), ctx)? {
+            Ok(interval_join)
         } else {
             Err(RwError::from(ErrorCode::NotSupported(
                 "streaming nested-loop join".to_string(),
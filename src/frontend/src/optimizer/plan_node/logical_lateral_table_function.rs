@@ -0,0 +1,167 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use risingwave_common::catalog::{Field, Schema};
+use risingwave_common::error::Result;
+
+use super::{
+    ColPrunable, ExprRewritable, LogicalProject, PlanBase, PlanRef, PlanTreeNodeUnary,
+    PredicatePushdown, ToBatch, ToStream,
+};
+use crate::expr::{Expr, ExprImpl, ExprRewriter, InputRef, TableFunction};
+use crate::optimizer::plan_node::{
+    gen_filter_and_pushdown, BatchLateralTableFunction, ColumnPruningContext, LogicalProjectSet,
+    PredicatePushdownContext, RewriteStreamContext, ToStreamContext,
+};
+use crate::optimizer::property::FunctionalDependencySet;
+use crate::utils::{ColIndexMapping, Condition};
+
+/// [`LogicalLateralTableFunction`] evaluates a table function once per row of its input, whose
+/// columns the function arguments may reference (e.g. `SELECT * FROM t, unnest(t.arr)`). The
+/// output schema is the input columns followed by the table function's return column, and each
+/// input row is repeated once per element the function produced for it.
+#[derive(Debug, Clone)]
+pub struct LogicalLateralTableFunction {
+    pub base: PlanBase,
+    input: PlanRef,
+    pub table_function: TableFunction,
+}
+
+impl LogicalLateralTableFunction {
+    pub fn new(input: PlanRef, table_function: TableFunction) -> Self {
+        let ctx = input.ctx();
+        let mut fields = input.schema().fields.clone();
+        fields.push(Field::with_name(
+            table_function.return_type(),
+            table_function.function_type.name(),
+        ));
+        let schema = Schema { fields };
+        let functional_dependency = FunctionalDependencySet::new(schema.len());
+        let base = PlanBase::new_logical(ctx, schema, vec![], functional_dependency);
+        Self {
+            base,
+            input,
+            table_function,
+        }
+    }
+}
+
+impl LogicalLateralTableFunction {
+    /// Rewrites to an equivalent [`LogicalProjectSet`] evaluating the table function once per
+    /// input row. Used for streaming, where `ProjectSet` additionally yields a leading hidden
+    /// `projected_row_id` column so that the output has a pk.
+    fn to_project_set(&self) -> LogicalProjectSet {
+        let input = self.input();
+        let mut select_list: Vec<ExprImpl> = input
+            .schema()
+            .data_types()
+            .into_iter()
+            .enumerate()
+            .map(|(i, data_type)| InputRef::new(i, data_type).into())
+            .collect();
+        select_list.push(self.table_function.clone().into());
+        LogicalProjectSet::new(input, select_list)
+    }
+}
+
+impl PlanTreeNodeUnary for LogicalLateralTableFunction {
+    fn input(&self) -> PlanRef {
+        self.input.clone()
+    }
+
+    fn clone_with_input(&self, input: PlanRef) -> Self {
+        Self::new(input, self.table_function.clone())
+    }
+}
+
+impl_plan_tree_node_for_unary! { LogicalLateralTableFunction }
+
+impl fmt::Display for LogicalLateralTableFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "LogicalLateralTableFunction {{ {:?} }}",
+            self.table_function
+        )
+    }
+}
+
+impl ColPrunable for LogicalLateralTableFunction {
+    fn prune_col(&self, required_cols: &[usize], _ctx: &mut ColumnPruningContext) -> PlanRef {
+        // The function arguments may reference arbitrary input columns, so keep the node intact
+        // and only prune with a project on top.
+        LogicalProject::with_out_col_idx(self.clone().into(), required_cols.iter().cloned()).into()
+    }
+}
+
+impl ExprRewritable for LogicalLateralTableFunction {
+    fn has_rewritable_expr(&self) -> bool {
+        true
+    }
+
+    fn rewrite_exprs(&self, r: &mut dyn ExprRewriter) -> PlanRef {
+        let mut new = self.clone();
+        new.table_function.args = new
+            .table_function
+            .args
+            .into_iter()
+            .map(|e| r.rewrite_expr(e))
+            .collect();
+        new.base = self.base.clone_with_new_plan_id();
+        new.into()
+    }
+}
+
+impl PredicatePushdown for LogicalLateralTableFunction {
+    fn predicate_pushdown(
+        &self,
+        predicate: Condition,
+        ctx: &mut PredicatePushdownContext,
+    ) -> PlanRef {
+        // The predicate may reference the function's output column, so do not push it down.
+        gen_filter_and_pushdown(self, predicate, Condition::true_cond(), ctx)
+    }
+}
+
+impl ToBatch for LogicalLateralTableFunction {
+    fn to_batch(&self) -> Result<PlanRef> {
+        let new_input = self.input().to_batch()?;
+        let new_logical = self.clone_with_input(new_input);
+        Ok(BatchLateralTableFunction::new(new_logical).into())
+    }
+}
+
+impl ToStream for LogicalLateralTableFunction {
+    fn to_stream(&self, ctx: &mut ToStreamContext) -> Result<PlanRef> {
+        // This plan node is replaced with a `ProjectSet` in `logical_rewrite_for_stream`, which
+        // is always called first when generating a streaming plan. Still, keep the output schema
+        // consistent here by projecting the hidden `projected_row_id` column away.
+        let project_set: PlanRef = self.to_project_set().into();
+        let project = LogicalProject::with_out_col_idx(project_set, 1..self.schema().len() + 1);
+        project.to_stream(ctx)
+    }
+
+    fn logical_rewrite_for_stream(
+        &self,
+        ctx: &mut RewriteStreamContext,
+    ) -> Result<(PlanRef, ColIndexMapping)> {
+        let (project_set, out_col_change) = self.to_project_set().logical_rewrite_for_stream(ctx)?;
+        // `ProjectSet` prepends the hidden `projected_row_id` column, shifting our columns by 1.
+        let mapping = ColIndexMapping::with_shift_offset(self.schema().len(), 1)
+            .composite(&out_col_change);
+        Ok((project_set, mapping))
+    }
+}
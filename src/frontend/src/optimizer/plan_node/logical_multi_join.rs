@@ -29,6 +29,7 @@ use crate::optimizer::plan_node::{
     ColumnPruningContext, PlanTreeNode, PredicatePushdownContext, RewriteStreamContext,
     ToStreamContext,
 };
+use crate::optimizer::plan_visitor::estimate_cardinality;
 use crate::optimizer::property::FunctionalDependencySet;
 use crate::utils::{ColIndexMapping, Condition, ConditionDisplay, ConnectedComponentLabeller};
 
@@ -421,11 +422,28 @@ impl LogicalMultiJoin {
 
         let mut join_ordering = vec![];
 
+        // Estimated row count of each input, based on the statistics collected by `ANALYZE`.
+        // `None` for inputs over tables that have never been analyzed.
+        let input_estimates: Vec<Option<u64>> = self
+            .inputs
+            .iter()
+            .map(|input| estimate_cardinality(input.clone()))
+            .collect();
+
         for component in edge_sets {
             let mut eq_cond_edges: Vec<(usize, usize)> = component.into_iter().collect();
 
-            // TODO(jon-chuang): add sorting of eq_cond_edges based on selectivity here
+            // Sort the edges by the estimated size of their cross product, so that the smallest
+            // joins are placed at the bottom of the left-deep tree. Edges involving inputs
+            // without statistics are estimated as `u64::MAX`. The preceding sort acts as a
+            // tie-breaker, which keeps the ordering stable when no statistics are available.
             eq_cond_edges.sort();
+            eq_cond_edges.sort_by_key(|&(a, b)| {
+                input_estimates[a]
+                    .zip(input_estimates[b])
+                    .and_then(|(a, b)| a.checked_mul(b))
+                    .unwrap_or(u64::MAX)
+            });
 
             if eq_cond_edges.is_empty() {
                 // There is nothing to join in this connected component
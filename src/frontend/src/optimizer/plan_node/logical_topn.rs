@@ -27,7 +27,7 @@ use super::{
 use crate::expr::{ExprType, FunctionCall, InputRef};
 use crate::optimizer::plan_node::{
     BatchTopN, ColumnPruningContext, LogicalProject, PredicatePushdownContext,
-    RewriteStreamContext, StreamTopN, ToStreamContext,
+    RewriteStreamContext, StreamDedup, StreamTopN, ToStreamContext,
 };
 use crate::optimizer::property::{Distribution, FieldOrder, Order, OrderDisplay, RequiredDist};
 use crate::planner::LIMIT_ALL_COUNT;
@@ -154,6 +154,23 @@ impl LogicalTopN {
             .infer_internal_table_catalog(&self.base, vnode_col_idx)
     }
 
+    /// Returns whether this group TopN can be implemented by [`StreamDedup`], which stores only
+    /// one row per key instead of maintaining a TopN state. This is the case for `DISTINCT ON`
+    /// queries (group TopN with limit 1) on an append-only input, when all the order columns are
+    /// part of the group key so that any row of a key is an acceptable choice.
+    fn can_use_dedup(&self) -> bool {
+        self.limit() == 1
+            && self.offset() == 0
+            && !self.with_ties()
+            && !self.group_key().is_empty()
+            && self.input().append_only()
+            && self
+                .topn_order()
+                .field_order
+                .iter()
+                .all(|fo| self.group_key().contains(&fo.index))
+    }
+
     fn gen_dist_stream_top_n_plan(&self, stream_input: PlanRef) -> Result<PlanRef> {
         let input_dist = stream_input.distribution().clone();
 
@@ -396,7 +413,11 @@ impl ToStream for LogicalTopN {
             let input = RequiredDist::hash_shard(self.group_key())
                 .enforce_if_not_satisfies(input, &Order::any())?;
             let logical = self.clone_with_input(input);
-            StreamGroupTopN::new(logical, None).into()
+            if logical.can_use_dedup() {
+                StreamDedup::new(logical).into()
+            } else {
+                StreamGroupTopN::new(logical, None).into()
+            }
         } else {
             self.gen_dist_stream_top_n_plan(self.input().to_stream(ctx)?)?
         })
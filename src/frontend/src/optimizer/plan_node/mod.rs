@@ -455,6 +455,7 @@ mod batch_hash_agg;
 mod batch_hash_join;
 mod batch_hop_window;
 mod batch_insert;
+mod batch_lateral_table_function;
 mod batch_limit;
 mod batch_lookup_join;
 mod batch_nested_loop_join;
@@ -478,6 +479,7 @@ mod logical_filter;
 mod logical_hop_window;
 mod logical_insert;
 mod logical_join;
+mod logical_lateral_table_function;
 mod logical_limit;
 mod logical_multi_join;
 mod logical_now;
@@ -492,6 +494,7 @@ mod logical_topn;
 mod logical_union;
 mod logical_update;
 mod logical_values;
+mod stream_dedup;
 mod stream_delta_join;
 mod stream_dml;
 mod stream_dynamic_filter;
@@ -504,6 +507,7 @@ mod stream_hash_agg;
 mod stream_hash_join;
 mod stream_hop_window;
 mod stream_index_scan;
+mod stream_interval_join;
 mod stream_local_simple_agg;
 mod stream_materialize;
 mod stream_now;
@@ -529,6 +533,7 @@ pub use batch_hash_agg::BatchHashAgg;
 pub use batch_hash_join::BatchHashJoin;
 pub use batch_hop_window::BatchHopWindow;
 pub use batch_insert::BatchInsert;
+pub use batch_lateral_table_function::BatchLateralTableFunction;
 pub use batch_limit::BatchLimit;
 pub use batch_lookup_join::BatchLookupJoin;
 pub use batch_nested_loop_join::BatchNestedLoopJoin;
@@ -552,6 +557,7 @@ pub use logical_filter::LogicalFilter;
 pub use logical_hop_window::LogicalHopWindow;
 pub use logical_insert::LogicalInsert;
 pub use logical_join::LogicalJoin;
+pub use logical_lateral_table_function::LogicalLateralTableFunction;
 pub use logical_limit::LogicalLimit;
 pub use logical_multi_join::{LogicalMultiJoin, LogicalMultiJoinBuilder};
 pub use logical_now::LogicalNow;
@@ -566,6 +572,7 @@ pub use logical_topn::LogicalTopN;
 pub use logical_union::LogicalUnion;
 pub use logical_update::LogicalUpdate;
 pub use logical_values::LogicalValues;
+pub use stream_dedup::StreamDedup;
 pub use stream_delta_join::StreamDeltaJoin;
 pub use stream_dml::StreamDml;
 pub use stream_dynamic_filter::StreamDynamicFilter;
@@ -578,6 +585,7 @@ pub use stream_hash_agg::StreamHashAgg;
 pub use stream_hash_join::StreamHashJoin;
 pub use stream_hop_window::StreamHopWindow;
 pub use stream_index_scan::StreamIndexScan;
+pub use stream_interval_join::StreamIntervalJoin;
 pub use stream_local_simple_agg::StreamLocalSimpleAgg;
 pub use stream_materialize::StreamMaterialize;
 pub use stream_now::StreamNow;
@@ -628,6 +636,7 @@ macro_rules! for_all_plan_nodes {
             , { Logical, TopN }
             , { Logical, HopWindow }
             , { Logical, TableFunction }
+            , { Logical, LateralTableFunction }
             , { Logical, MultiJoin }
             , { Logical, Expand }
             , { Logical, ProjectSet }
@@ -654,6 +663,7 @@ macro_rules! for_all_plan_nodes {
             , { Batch, TopN }
             , { Batch, HopWindow }
             , { Batch, TableFunction }
+            , { Batch, LateralTableFunction }
             , { Batch, Expand }
             , { Batch, LookupJoin }
             , { Batch, ProjectSet }
@@ -677,8 +687,10 @@ macro_rules! for_all_plan_nodes {
             , { Stream, IndexScan }
             , { Stream, Expand }
             , { Stream, DynamicFilter }
+            , { Stream, IntervalJoin }
             , { Stream, ProjectSet }
             , { Stream, GroupTopN }
+            , { Stream, Dedup }
             , { Stream, Union }
             , { Stream, RowIdGen }
             , { Stream, Dml }
@@ -709,6 +721,7 @@ macro_rules! for_logical_plan_nodes {
             , { Logical, TopN }
             , { Logical, HopWindow }
             , { Logical, TableFunction }
+            , { Logical, LateralTableFunction }
             , { Logical, MultiJoin }
             , { Logical, Expand }
             , { Logical, ProjectSet }
@@ -745,6 +758,7 @@ macro_rules! for_batch_plan_nodes {
             , { Batch, Update }
             , { Batch, HopWindow }
             , { Batch, TableFunction }
+            , { Batch, LateralTableFunction }
             , { Batch, Expand }
             , { Batch, LookupJoin }
             , { Batch, ProjectSet }
@@ -777,8 +791,10 @@ macro_rules! for_stream_plan_nodes {
             , { Stream, IndexScan }
             , { Stream, Expand }
             , { Stream, DynamicFilter }
+            , { Stream, IntervalJoin }
             , { Stream, ProjectSet }
             , { Stream, GroupTopN }
+            , { Stream, Dedup }
             , { Stream, Union }
             , { Stream, RowIdGen }
             , { Stream, Dml }
@@ -733,6 +733,18 @@ pub fn to_stream_prost_body(
         }),
         Node::Sink(me) => ProstNode::Sink(SinkNode {
             sink_desc: Some(me.sink_desc.to_proto()),
+            log_store_table: me
+                .input
+                .0
+                .ctx
+                .session_ctx()
+                .config()
+                .get_streaming_enable_sink_log_store()
+                .then(|| {
+                    super::stream_sink::infer_log_store_table_catalog(&me.input.0.schema)
+                        .with_id(state.gen_table_id_wrapped())
+                        .to_internal_table_prost()
+                }),
         }),
         Node::Source(me) => {
             let me = &me.core.catalog;
@@ -0,0 +1,115 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use risingwave_common::util::sort_util::OrderType;
+use risingwave_pb::stream_plan::stream_node::NodeBody as ProstStreamNode;
+
+use super::utils::TableCatalogBuilder;
+use super::{ExprRewritable, LogicalTopN, PlanBase, PlanTreeNodeUnary, StreamNode};
+use crate::stream_fragmenter::BuildFragmentGraphState;
+use crate::{PlanRef, TableCatalog};
+
+/// `StreamDedup` deduplicates an append-only input on the columns specified by the group key,
+/// keeping only the first row of each distinct key. It is selected for `DISTINCT ON` queries
+/// (which are planned as group TopN with limit 1) when any row of a key is acceptable, and stores
+/// only one row per key instead of maintaining a TopN state.
+#[derive(Debug, Clone)]
+pub struct StreamDedup {
+    pub base: PlanBase,
+    logical: LogicalTopN,
+}
+
+impl StreamDedup {
+    pub fn new(logical: LogicalTopN) -> Self {
+        assert!(!logical.group_key().is_empty());
+        assert_eq!(logical.limit(), 1);
+        assert_eq!(logical.offset(), 0);
+        assert!(!logical.with_ties());
+        let input = logical.input();
+        assert!(input.append_only());
+        let schema = input.schema().clone();
+
+        // Each key emits exactly one row, so the dedup columns form the stream key, and the
+        // output is append-only as well.
+        let base = PlanBase::new_stream(
+            input.ctx(),
+            schema,
+            logical.group_key().to_vec(),
+            input.functional_dependency().clone(),
+            input.distribution().clone(),
+            true,
+            input.watermark_columns().clone(),
+        );
+        StreamDedup { base, logical }
+    }
+
+    pub fn dedup_cols(&self) -> &[usize] {
+        self.logical.group_key()
+    }
+
+    /// Infers the state table catalog, which stores one row per distinct key with the dedup
+    /// columns as its primary key.
+    pub fn infer_internal_table_catalog(&self) -> TableCatalog {
+        let input = self.logical.input();
+        let mut internal_table_catalog_builder =
+            TableCatalogBuilder::new(input.ctx().with_options().internal_table_subset());
+
+        input.schema().fields().iter().for_each(|field| {
+            internal_table_catalog_builder.add_column(field);
+        });
+        self.dedup_cols().iter().for_each(|&idx| {
+            internal_table_catalog_builder.add_order_column(idx, OrderType::Ascending);
+        });
+
+        internal_table_catalog_builder.set_read_prefix_len_hint(self.dedup_cols().len());
+        internal_table_catalog_builder.build(input.distribution().dist_column_indices().to_vec())
+    }
+}
+
+impl StreamNode for StreamDedup {
+    fn to_stream_prost_body(&self, state: &mut BuildFragmentGraphState) -> ProstStreamNode {
+        use risingwave_pb::stream_plan::*;
+        let table = self
+            .infer_internal_table_catalog()
+            .with_id(state.gen_table_id_wrapped());
+        ProstStreamNode::AppendOnlyDedup(DedupNode {
+            state_table: Some(table.to_internal_table_prost()),
+            dedup_column_indices: self.dedup_cols().iter().map(|idx| *idx as u32).collect(),
+        })
+    }
+}
+
+impl fmt::Display for StreamDedup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StreamAppendOnlyDedup")
+            .field("dedup_cols", &self.dedup_cols())
+            .finish()
+    }
+}
+
+impl_plan_tree_node_for_unary! { StreamDedup }
+
+impl PlanTreeNodeUnary for StreamDedup {
+    fn input(&self) -> PlanRef {
+        self.logical.input()
+    }
+
+    fn clone_with_input(&self, input: PlanRef) -> Self {
+        Self::new(self.logical.clone_with_input(input))
+    }
+}
+
+impl ExprRewritable for StreamDedup {}
@@ -0,0 +1,210 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use fixedbitset::FixedBitSet;
+use risingwave_common::catalog::Field;
+use risingwave_common::types::DataType;
+use risingwave_common::util::sort_util::OrderType;
+use risingwave_pb::plan_common::JoinType;
+use risingwave_pb::stream_plan::stream_node::NodeBody;
+use risingwave_pb::stream_plan::IntervalJoinNode;
+
+use super::utils::{IndicesDisplay, TableCatalogBuilder};
+use super::{ExprRewritable, LogicalJoin, PlanBase, PlanRef, PlanTreeNodeBinary, StreamNode};
+use crate::optimizer::plan_node::generic::GenericPlanRef;
+use crate::optimizer::plan_node::stream;
+use crate::optimizer::property::Distribution;
+use crate::stream_fragmenter::BuildFragmentGraphState;
+use crate::TableCatalog;
+
+/// [`StreamIntervalJoin`] implements an inner [`super::LogicalJoin`] whose condition only bounds
+/// the difference of the two event time columns: a left row and a right row match iff
+/// `left.ts - right.ts` is within `[lower_bound_ms, upper_bound_ms]`.
+///
+/// The state of each side is keyed by a coarse time bucket of the event time first, so that state
+/// cleanup under watermarks is a range delete per bucket instead of per-row tombstones.
+#[derive(Debug, Clone)]
+pub struct StreamIntervalJoin {
+    pub base: PlanBase,
+    /// The output indices of `logical` are identity, and its condition has been encoded into the
+    /// bounds below.
+    logical: LogicalJoin,
+    left_ts_idx: usize,
+    right_ts_idx: usize,
+    /// Inclusive bounds of `left.ts - right.ts`, in milliseconds.
+    lower_bound_ms: i64,
+    upper_bound_ms: i64,
+    /// Width of a state time bucket, in milliseconds. It is no less than the width of the bound
+    /// range, so that a probe scans at most two buckets.
+    bucket_size_ms: i64,
+}
+
+impl StreamIntervalJoin {
+    pub fn new(
+        logical: LogicalJoin,
+        left_ts_idx: usize,
+        right_ts_idx: usize,
+        lower_bound_ms: i64,
+        upper_bound_ms: i64,
+        bucket_size_ms: i64,
+    ) -> Self {
+        assert_eq!(logical.join_type(), JoinType::Inner);
+        assert!(lower_bound_ms <= upper_bound_ms);
+        assert!(bucket_size_ms >= (upper_bound_ms - lower_bound_ms).max(1));
+        // The executor scans the full state of the other side per input row, so both sides must
+        // be colocated on a single actor.
+        assert_eq!(*logical.left().distribution(), Distribution::Single);
+        assert_eq!(*logical.right().distribution(), Distribution::Single);
+
+        let ctx = logical.base.ctx.clone();
+        let left_len = logical.left().schema().len();
+        // Inner join won't change the append-only behavior of the stream.
+        let append_only = logical.left().append_only() && logical.right().append_only();
+
+        // The executor emits watermarks for both output event time columns, each bounded by both
+        // input watermarks.
+        let mut watermark_columns = FixedBitSet::with_capacity(logical.schema().len());
+        watermark_columns.set(left_ts_idx, true);
+        watermark_columns.set(left_len + right_ts_idx, true);
+
+        let base = PlanBase::new_stream(
+            ctx,
+            logical.schema().clone(),
+            logical.base.logical_pk.to_vec(),
+            logical.functional_dependency().clone(),
+            Distribution::Single,
+            append_only,
+            watermark_columns,
+        );
+        Self {
+            base,
+            logical,
+            left_ts_idx,
+            right_ts_idx,
+            lower_bound_ms,
+            upper_bound_ms,
+            bucket_size_ms,
+        }
+    }
+}
+
+impl fmt::Display for StreamIntervalJoin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let verbose = self.base.ctx.is_explain_verbose();
+        let mut builder = f.debug_struct("StreamIntervalJoin");
+
+        let fields = &self.logical.schema().fields;
+        builder.field(
+            "predicate",
+            &format_args!(
+                "({} - {}) IN [{} ms, {} ms]",
+                fields[self.left_ts_idx].name,
+                fields[self.logical.left().schema().len() + self.right_ts_idx].name,
+                self.lower_bound_ms,
+                self.upper_bound_ms,
+            ),
+        );
+
+        if verbose {
+            builder.field(
+                "output",
+                &IndicesDisplay {
+                    indices: self.logical.output_indices(),
+                    input_schema: self.logical.schema(),
+                },
+            );
+        }
+
+        builder.finish()
+    }
+}
+
+impl PlanTreeNodeBinary for StreamIntervalJoin {
+    fn left(&self) -> PlanRef {
+        self.logical.left()
+    }
+
+    fn right(&self) -> PlanRef {
+        self.logical.right()
+    }
+
+    fn clone_with_left_right(&self, left: PlanRef, right: PlanRef) -> Self {
+        Self::new(
+            self.logical.clone_with_left_right(left, right),
+            self.left_ts_idx,
+            self.right_ts_idx,
+            self.lower_bound_ms,
+            self.upper_bound_ms,
+            self.bucket_size_ms,
+        )
+    }
+}
+
+impl_plan_tree_node_for_binary! { StreamIntervalJoin }
+
+impl StreamNode for StreamIntervalJoin {
+    fn to_stream_prost_body(&self, state: &mut BuildFragmentGraphState) -> NodeBody {
+        let left_table = infer_internal_table_catalog(self.left().plan_base(), self.left_ts_idx)
+            .with_id(state.gen_table_id_wrapped());
+        let right_table = infer_internal_table_catalog(self.right().plan_base(), self.right_ts_idx)
+            .with_id(state.gen_table_id_wrapped());
+        NodeBody::IntervalJoin(IntervalJoinNode {
+            left_time_column: self.left_ts_idx as u32,
+            right_time_column: self.right_ts_idx as u32,
+            lower_bound_ms: self.lower_bound_ms,
+            upper_bound_ms: self.upper_bound_ms,
+            bucket_size_ms: self.bucket_size_ms,
+            left_table: Some(left_table.to_internal_table_prost()),
+            right_table: Some(right_table.to_internal_table_prost()),
+        })
+    }
+}
+
+impl ExprRewritable for StreamIntervalJoin {}
+
+/// The state of an interval join side is the input rows keyed by `(bucket, ts, input pk)`.
+fn infer_internal_table_catalog(
+    input: &impl stream::StreamPlanRef,
+    ts_idx: usize,
+) -> TableCatalog {
+    let schema = input.schema();
+
+    // We require that the inputs have distribution `Single`.
+    assert_eq!(
+        input.distribution().dist_column_indices().to_vec(),
+        Vec::<usize>::new()
+    );
+
+    let mut internal_table_catalog_builder =
+        TableCatalogBuilder::new(input.ctx().with_options().internal_table_subset());
+
+    internal_table_catalog_builder.add_column(&Field::with_name(DataType::Int64, "bucket"));
+    schema.fields().iter().for_each(|field| {
+        internal_table_catalog_builder.add_column(field);
+    });
+
+    let mut pk_indices = vec![0, 1 + ts_idx];
+    for idx in input.logical_pk() {
+        if !pk_indices.contains(&(1 + idx)) {
+            pk_indices.push(1 + idx);
+        }
+    }
+    pk_indices.iter().for_each(|idx| {
+        internal_table_catalog_builder.add_order_column(*idx, OrderType::Ascending)
+    });
+
+    internal_table_catalog_builder.build(vec![])
+}
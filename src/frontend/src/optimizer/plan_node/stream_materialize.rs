@@ -241,7 +241,7 @@ impl StreamMaterialize {
         }
 
         let distribution_key = distribution.dist_column_indices().to_vec();
-        let properties = input.ctx().with_options().internal_table_subset(); // TODO: remove this
+        let properties = input.ctx().with_options().streaming_job_subset(); // TODO: remove this
         let read_prefix_len_hint = pk_indices.len();
 
         Ok(TableCatalog {
@@ -266,6 +266,8 @@ impl StreamMaterialize {
             read_prefix_len_hint,
             version,
             watermark_columns,
+            stats: None,
+            memory_only: false,
         })
     }
 
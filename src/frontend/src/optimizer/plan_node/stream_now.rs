@@ -89,9 +89,17 @@ impl StreamNode for StreamNow {
             internal_table_catalog_builder.add_column(field);
         });
 
-        let table_catalog = internal_table_catalog_builder
+        let mut table_catalog = internal_table_catalog_builder
             .build(dist_keys)
             .with_id(state.gen_table_id_wrapped());
+        // The state is a single timestamp, so it can be kept in executor memory and snapshotted
+        // into the meta store at barriers instead of being written to Hummock.
+        table_catalog.memory_only = self
+            .base
+            .ctx()
+            .session_ctx()
+            .config()
+            .get_streaming_enable_memory_only_state();
         NodeBody::Now(NowNode {
             state_table: Some(table_catalog.to_internal_table_prost()),
         })
@@ -12,14 +12,20 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::fmt;
 
+use risingwave_common::catalog::{Field, Schema};
+use risingwave_common::types::DataType;
+use risingwave_common::util::sort_util::OrderType;
 use risingwave_connector::sink::catalog::desc::SinkDesc;
 use risingwave_pb::stream_plan::stream_node::NodeBody as ProstStreamNode;
 
+use super::utils::TableCatalogBuilder;
 use super::{ExprRewritable, PlanBase, PlanRef, StreamNode};
 use crate::optimizer::plan_node::PlanTreeNodeUnary;
 use crate::stream_fragmenter::BuildFragmentGraphState;
+use crate::{TableCatalog, WithOptions};
 
 /// [`StreamSink`] represents a table/connector sink at the very end of the graph.
 #[derive(Debug, Clone)]
@@ -45,6 +51,51 @@ impl StreamSink {
     }
 }
 
+/// Infer the internal table buffering the change stream of the sink. Its schema is
+/// `vnode | epoch | seq_id | op` followed by the payload columns of the sink, ordered by
+/// `(vnode, epoch, seq_id)` so that the changes of each parallelism can be replayed in their
+/// original order. The leading vnode column partitions the table among the parallel sink
+/// actors, like the watermark filter state table.
+pub fn infer_log_store_table_catalog(input_schema: &Schema) -> TableCatalog {
+    let mut builder = TableCatalogBuilder::new(WithOptions::new(HashMap::default()));
+
+    let vnode_col_idx = builder.add_column(&Field {
+        data_type: DataType::Int16,
+        name: "vnode".to_string(),
+        sub_fields: vec![],
+        type_name: "".to_string(),
+    });
+    let epoch_col_idx = builder.add_column(&Field {
+        data_type: DataType::Int64,
+        name: "epoch".to_string(),
+        sub_fields: vec![],
+        type_name: "".to_string(),
+    });
+    let seq_id_col_idx = builder.add_column(&Field {
+        data_type: DataType::Int64,
+        name: "seq_id".to_string(),
+        sub_fields: vec![],
+        type_name: "".to_string(),
+    });
+    builder.add_column(&Field {
+        data_type: DataType::Int16,
+        name: "op".to_string(),
+        sub_fields: vec![],
+        type_name: "".to_string(),
+    });
+    for field in input_schema.fields() {
+        builder.add_column(field);
+    }
+
+    builder.add_order_column(vnode_col_idx, OrderType::Ascending);
+    builder.add_order_column(epoch_col_idx, OrderType::Ascending);
+    builder.add_order_column(seq_id_col_idx, OrderType::Ascending);
+
+    builder.set_vnode_col_idx(vnode_col_idx);
+
+    builder.build(vec![vnode_col_idx])
+}
+
 impl PlanTreeNodeUnary for StreamSink {
     fn input(&self) -> PlanRef {
         self.input.clone()
@@ -66,11 +117,26 @@ impl fmt::Display for StreamSink {
 }
 
 impl StreamNode for StreamSink {
-    fn to_stream_prost_body(&self, _state: &mut BuildFragmentGraphState) -> ProstStreamNode {
+    fn to_stream_prost_body(&self, state: &mut BuildFragmentGraphState) -> ProstStreamNode {
         use risingwave_pb::stream_plan::*;
 
+        // The log store is opt-in: without it the sink delivers directly and nothing is
+        // buffered.
+        let log_store_table = self
+            .base
+            .ctx
+            .session_ctx()
+            .config()
+            .get_streaming_enable_sink_log_store()
+            .then(|| {
+                infer_log_store_table_catalog(self.input.schema())
+                    .with_id(state.gen_table_id_wrapped())
+                    .to_internal_table_prost()
+            });
+
         ProstStreamNode::Sink(SinkNode {
             sink_desc: Some(self.sink_desc.to_proto()),
+            log_store_table,
         })
     }
 }
@@ -149,6 +149,8 @@ impl TableCatalogBuilder {
             read_prefix_len_hint: self.read_prefix_len_hint,
             version: None, // the internal table is not versioned and can't be schema changed
             watermark_columns,
+            stats: None,
+            memory_only: false,
         }
     }
 
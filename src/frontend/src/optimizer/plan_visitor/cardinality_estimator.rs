@@ -0,0 +1,52 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::optimizer::plan_node::{LogicalScan, LogicalValues};
+use crate::optimizer::plan_visitor::PlanVisitor;
+use crate::PlanRef;
+
+/// Estimate the cardinality of the given logical plan, based on the row counts collected by
+/// `ANALYZE` and stored in the catalog.
+///
+/// The estimate is a coarse upper bound: scans report the analyzed row count, joins multiply
+/// the estimates of their inputs, and filtering is ignored. Returns `None` if any involved
+/// table has never been analyzed, so the caller can fall back to a stats-free heuristic.
+/// Correctness of a plan must never depend on the returned value.
+pub fn estimate_cardinality(plan: PlanRef) -> Option<u64> {
+    CardinalityEstimator.visit(plan)
+}
+
+struct CardinalityEstimator;
+
+impl PlanVisitor<Option<u64>> for CardinalityEstimator {
+    fn merge(a: Option<u64>, b: Option<u64>) -> Option<u64> {
+        a.zip(b).and_then(|(a, b)| a.checked_mul(b))
+    }
+
+    fn visit_logical_scan(&mut self, plan: &LogicalScan) -> Option<u64> {
+        if plan.is_sys_table() {
+            return None;
+        }
+        let catalog_reader = plan.base.ctx.session_ctx().env().catalog_reader().clone();
+        let table = catalog_reader
+            .read_guard()
+            .get_table_by_id(&plan.table_desc().table_id)
+            .ok()?;
+        table.stats.as_ref().map(|stats| stats.row_count)
+    }
+
+    fn visit_logical_values(&mut self, plan: &LogicalValues) -> Option<u64> {
+        Some(plan.rows().len() as u64)
+    }
+}
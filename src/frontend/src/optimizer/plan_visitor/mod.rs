@@ -13,6 +13,8 @@
 // limitations under the License.
 
 use paste::paste;
+mod cardinality_estimator;
+pub use cardinality_estimator::*;
 mod max_one_row_visitor;
 pub use max_one_row_visitor::*;
 mod plan_correlated_id_finder;
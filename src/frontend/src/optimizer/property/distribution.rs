@@ -133,6 +133,7 @@ impl Distribution {
                     );
                     Some(DistributionProst::HashInfo(HashInfo {
                         output_count,
+                        copy_count: 0,
                         key: key.iter().map(|num| *num as u32).collect(),
                     }))
                 }
@@ -75,7 +75,14 @@ impl DistinctAggRule {
         // mapping from `subset` to `flag_value`, which is used to deduplicate `column_subsets`.
         let mut hash_map = HashMap::new();
         let (distinct_aggs, non_distinct_aggs): (Vec<_>, Vec<_>) =
-            agg_calls.iter().partition(|agg_call| agg_call.distinct);
+            agg_calls.iter().partition(|agg_call| {
+                // Distinct `string_agg` with order by clause is deduplicated by the agg
+                // executors directly, so it should not be rewritten here. `syntax_check`
+                // guarantees that it doesn't coexist with other distinct aggregates.
+                agg_call.distinct
+                    && !(agg_call.agg_kind == AggKind::StringAgg
+                        && !agg_call.order_by_fields.is_empty())
+            });
         if distinct_aggs.is_empty() {
             return None;
         }
@@ -272,7 +279,11 @@ impl DistinctAggRule {
                     | AggKind::Avg
                     | AggKind::StringAgg
                     | AggKind::ArrayAgg
-                    | AggKind::FirstValue => (),
+                    | AggKind::ApproxTopK
+                    | AggKind::FirstValue
+                    | AggKind::PercentileCont
+                    | AggKind::PercentileDisc
+                    | AggKind::ApproxPercentile => (),
                     AggKind::Count => {
                         agg_call.agg_kind = AggKind::Sum0;
                     }
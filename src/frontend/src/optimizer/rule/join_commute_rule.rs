@@ -31,41 +31,9 @@ pub struct JoinCommuteRule {}
 impl Rule for JoinCommuteRule {
     fn apply(&self, plan: PlanRef) -> Option<PlanRef> {
         let join: &LogicalJoin = plan.as_logical_join()?;
-        let join_type = join.join_type();
-        match join_type {
+        match join.join_type() {
             JoinType::RightOuter | JoinType::RightSemi | JoinType::RightAnti => {
-                let (left, right, on, join_type, output_indices) = join.clone().decompose();
-
-                let left_len = left.schema().len();
-                let right_len = right.schema().len();
-
-                let new_output_indices = output_indices
-                    .into_iter()
-                    .map(|i| {
-                        if i < left_len {
-                            i + right_len
-                        } else {
-                            i - left_len
-                        }
-                    })
-                    .collect_vec();
-
-                let mut condition_rewriter = Rewriter {
-                    join_left_len: left_len,
-                    join_left_offset: right_len as isize,
-                    join_right_offset: -(left_len as isize),
-                };
-                let new_on = on.rewrite_expr(&mut condition_rewriter);
-
-                let new_join = LogicalJoin::with_output_indices(
-                    right,
-                    left,
-                    Self::inverse_join_type(join_type),
-                    new_on,
-                    new_output_indices,
-                );
-
-                Some(new_join.into())
+                Some(Self::commute(join).into())
             }
             JoinType::Inner
             | JoinType::LeftOuter
@@ -105,6 +73,41 @@ impl JoinCommuteRule {
         Box::new(JoinCommuteRule {})
     }
 
+    /// Swap the left and right inputs of `join`, preserving its semantics by inverting the join
+    /// type and rewriting the condition and output indices accordingly.
+    pub(crate) fn commute(join: &LogicalJoin) -> LogicalJoin {
+        let (left, right, on, join_type, output_indices) = join.clone().decompose();
+
+        let left_len = left.schema().len();
+        let right_len = right.schema().len();
+
+        let new_output_indices = output_indices
+            .into_iter()
+            .map(|i| {
+                if i < left_len {
+                    i + right_len
+                } else {
+                    i - left_len
+                }
+            })
+            .collect_vec();
+
+        let mut condition_rewriter = Rewriter {
+            join_left_len: left_len,
+            join_left_offset: right_len as isize,
+            join_right_offset: -(left_len as isize),
+        };
+        let new_on = on.rewrite_expr(&mut condition_rewriter);
+
+        LogicalJoin::with_output_indices(
+            right,
+            left,
+            Self::inverse_join_type(join_type),
+            new_on,
+            new_output_indices,
+        )
+    }
+
     fn inverse_join_type(join_type: JoinType) -> JoinType {
         match join_type {
             JoinType::Unspecified => JoinType::Unspecified,
@@ -33,6 +33,7 @@ impl Planner {
             insert.table_name.clone(),
             insert.table_id,
             insert.column_indices,
+            insert.dist_key_indices,
             insert.row_id_index,
             returning,
         )?
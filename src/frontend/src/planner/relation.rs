@@ -17,6 +17,7 @@ use std::rc::Rc;
 use itertools::Itertools;
 use risingwave_common::error::{ErrorCode, Result};
 use risingwave_common::types::{DataType, ScalarImpl};
+use risingwave_pb::plan_common::JoinType;
 
 use crate::binder::{
     BoundBaseTable, BoundJoin, BoundShare, BoundSource, BoundSystemTable, BoundWatermark,
@@ -24,10 +25,11 @@ use crate::binder::{
 };
 use crate::expr::{ExprImpl, ExprType, FunctionCall, InputRef, TableFunction};
 use crate::optimizer::plan_node::{
-    LogicalHopWindow, LogicalJoin, LogicalProject, LogicalScan, LogicalShare, LogicalSource,
-    LogicalTableFunction, PlanRef,
+    LogicalFilter, LogicalHopWindow, LogicalJoin, LogicalLateralTableFunction, LogicalProject,
+    LogicalScan, LogicalShare, LogicalSource, LogicalTableFunction, PlanRef,
 };
 use crate::planner::Planner;
+use crate::utils::Condition;
 
 const ERROR_WINDOW_SIZE_ARG: &str =
     "The size arg of window table function should be an interval literal.";
@@ -60,10 +62,12 @@ impl Planner {
     }
 
     pub(super) fn plan_base_table(&mut self, base_table: BoundBaseTable) -> Result<PlanRef> {
+        let mut table_desc = base_table.table_catalog.table_desc();
+        table_desc.remote_cluster = base_table.remote_cluster.clone();
         Ok(LogicalScan::create(
             base_table.table_catalog.name().to_string(),
             false,
-            Rc::new(base_table.table_catalog.table_desc()),
+            Rc::new(table_desc),
             base_table
                 .table_indexes
                 .iter()
@@ -96,6 +100,27 @@ impl Planner {
     }
 
     pub(super) fn plan_join(&mut self, join: BoundJoin) -> Result<PlanRef> {
+        // A table function whose arguments reference columns of the preceding `FROM` items is
+        // lateral: it must be evaluated once per row of the left side instead of being joined as
+        // an independent relation.
+        if let Relation::TableFunction(tf) = &join.right {
+            if tf.args.iter().any(|arg| arg.has_input_ref()) {
+                if join.join_type != JoinType::Inner {
+                    return Err(ErrorCode::NotImplemented(
+                        "lateral table function in non-inner join".into(),
+                        None.into(),
+                    )
+                    .into());
+                }
+                let Relation::TableFunction(tf) = join.right else {
+                    unreachable!()
+                };
+                let left = self.plan_relation(join.left)?;
+                let node = LogicalLateralTableFunction::new(left, *tf).into();
+                return Ok(LogicalFilter::create(node, Condition::with_expr(join.cond)));
+            }
+        }
+
         let left = self.plan_relation(join.left)?;
         let right = self.plan_relation(join.right)?;
         let join_type = join.join_type;
@@ -78,7 +78,7 @@ impl Planner {
                     }
                     None => {
                         return Err(ErrorCode::InvalidInputSyntax(
-                            "the SELECT DISTINCT ON expressions must match the leftmost SELECT DISTINCT ON expressions"
+                            "SELECT DISTINCT ON expressions must match initial ORDER BY expressions"
                                 .into(),
                         )
                         .into());
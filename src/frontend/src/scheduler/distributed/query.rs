@@ -20,6 +20,7 @@ use std::sync::Arc;
 
 use anyhow::anyhow;
 use futures::executor::block_on;
+use itertools::Itertools;
 use petgraph::dot::{Config, Dot};
 use petgraph::Graph;
 use pgwire::pg_server::SessionId;
@@ -37,7 +38,9 @@ use crate::scheduler::distributed::query::QueryMessage::Stage;
 use crate::scheduler::distributed::stage::StageEvent::ScheduledRoot;
 use crate::scheduler::distributed::StageEvent::Scheduled;
 use crate::scheduler::distributed::StageExecution;
-use crate::scheduler::plan_fragmenter::{Query, StageId, ROOT_TASK_ID, ROOT_TASK_OUTPUT_ID};
+use crate::scheduler::plan_fragmenter::{
+    Query, SharedStageGroup, StageId, ROOT_TASK_ID, ROOT_TASK_OUTPUT_ID,
+};
 use crate::scheduler::worker_node_manager::WorkerNodeManagerRef;
 use crate::scheduler::{
     ExecutionContextRef, PinnedHummockSnapshot, SchedulerError, SchedulerResult,
@@ -80,6 +83,9 @@ pub struct QueryExecution {
 struct QueryRunner {
     query: Arc<Query>,
     stage_executions: HashMap<StageId, Arc<StageExecution>>,
+    /// Groups of identical stages sharing one execution, keyed by their canonical stage. See
+    /// [`crate::scheduler::plan_fragmenter::StageGraph::shared_execution_groups`].
+    shared_stage_groups: HashMap<StageId, SharedStageGroup>,
     scheduled_stages_count: usize,
     /// Query messages receiver. For example, stage state change events, query commands.
     msg_receiver: Receiver<QueryMessage>,
@@ -129,7 +135,9 @@ impl QueryExecution {
         // Because the snapshot may be released before all stages are scheduled, we only pass a
         // reference of `pinned_snapshot`. Its ownership will be moved into `QueryRunner` so that it
         // can control when to release the snapshot.
+        let shared_stage_groups = self.query.stage_graph.shared_execution_groups();
         let stage_executions = self.gen_stage_executions(
+            &shared_stage_groups,
             &pinned_snapshot,
             context,
             worker_node_manager,
@@ -148,6 +156,7 @@ impl QueryExecution {
                 let runner = QueryRunner {
                     query: self.query.clone(),
                     stage_executions,
+                    shared_stage_groups,
                     msg_receiver,
                     root_stage_sender: Some(root_stage_sender),
                     scheduled_stages_count: 0,
@@ -190,8 +199,10 @@ impl QueryExecution {
         };
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn gen_stage_executions(
         &self,
+        shared_stage_groups: &HashMap<StageId, SharedStageGroup>,
         pinned_snapshot: &PinnedHummockSnapshot,
         context: ExecutionContextRef,
         worker_node_manager: WorkerNodeManagerRef,
@@ -201,21 +212,67 @@ impl QueryExecution {
         let mut stage_executions: HashMap<StageId, Arc<StageExecution>> =
             HashMap::with_capacity(self.query.stage_graph.stages.len());
 
+        // Stages sharing the execution of another stage, mapped to their canonical stage.
+        let canonical_stages: HashMap<StageId, StageId> = shared_stage_groups
+            .iter()
+            .flat_map(|(canonical, group)| {
+                group
+                    .member_offsets
+                    .keys()
+                    .filter(|member| *member != canonical)
+                    .map(|member| (*member, *canonical))
+            })
+            .collect();
+
         for stage_id in self.query.stage_graph.stage_ids_by_topo_order() {
+            if let Some(canonical) = canonical_stages.get(&stage_id) {
+                // The canonical stage comes earlier in topological order and is executed once
+                // on behalf of all members of its group.
+                stage_executions.insert(stage_id, stage_executions[canonical].clone());
+                continue;
+            }
+
             let children_stages = self
                 .query
                 .stage_graph
                 .get_child_stages_unchecked(&stage_id)
                 .iter()
-                .map(|s| stage_executions[s].clone())
+                .map(|child| canonical_stages.get(child).copied().unwrap_or(*child))
+                .unique()
+                .map(|child| stage_executions[&child].clone())
                 .collect::<Vec<Arc<StageExecution>>>();
 
+            // Exchanges consuming a member of a shared group read the member's own copy of the
+            // canonical stage's output.
+            let exchange_output_offsets = self
+                .query
+                .stage_graph
+                .get_child_stages_unchecked(&stage_id)
+                .iter()
+                .filter_map(|child| {
+                    let canonical = canonical_stages.get(child).copied().unwrap_or(*child);
+                    shared_stage_groups
+                        .get(&canonical)
+                        .map(|group| (*child, (canonical, group.member_offsets[child])))
+                })
+                .collect();
+
+            // The canonical stage of a shared group produces one copy of its output per member.
+            let stage = match shared_stage_groups.get(&stage_id) {
+                Some(group) => Arc::new(
+                    self.query.stage_graph.stages[&stage_id]
+                        .clone_with_exchange_info(Some(group.exchange_info.clone())),
+                ),
+                None => self.query.stage_graph.stages[&stage_id].clone(),
+            };
+
             let stage_exec = Arc::new(StageExecution::new(
                 pinned_snapshot.get_batch_query_epoch(),
-                self.query.stage_graph.stages[&stage_id].clone(),
+                stage,
                 worker_node_manager.clone(),
                 self.shutdown_tx.clone(),
                 children_stages,
+                exchange_output_offsets,
                 compute_client_pool.clone(),
                 catalog_reader.clone(),
                 context.clone(),
@@ -258,7 +315,11 @@ impl QueryRunner {
         // Start leaf stages.
         let leaf_stages = self.query.leaf_stages();
         for stage_id in &leaf_stages {
-            self.stage_executions[stage_id].start().await;
+            // Members of a shared group resolve to a single execution, which may have been
+            // started for another member already.
+            if self.stage_executions[stage_id].is_pending().await {
+                self.stage_executions[stage_id].start().await;
+            }
             tracing::trace!(
                 "Query stage {:?}-{:?} started.",
                 self.query.query_id,
@@ -270,6 +331,15 @@ impl QueryRunner {
         // To convince the compiler that `pinned_snapshot` will only be dropped once.
         let mut pinned_snapshot_to_drop = Some(pinned_snapshot);
 
+        // Stages sharing an execution schedule and complete together, reporting one event for
+        // the whole group.
+        let distinct_execution_count = self.stage_executions.len()
+            - self
+                .shared_stage_groups
+                .values()
+                .map(|group| group.member_offsets.len() - 1)
+                .sum::<usize>();
+
         let mut finished_stage_cnt = 0usize;
         while let Some(msg_inner) = self.msg_receiver.recv().await {
             match msg_inner {
@@ -280,7 +350,14 @@ impl QueryRunner {
                         stage_id
                     );
                     self.scheduled_stages_count += 1;
-                    stages_with_table_scan.remove(&stage_id);
+                    // A shared stage is scheduled once on behalf of every member of its group.
+                    let members = match self.shared_stage_groups.get(&stage_id) {
+                        Some(group) => group.member_offsets.keys().copied().collect_vec(),
+                        None => vec![stage_id],
+                    };
+                    for member in &members {
+                        stages_with_table_scan.remove(member);
+                    }
                     // If query contains lookup join we need to delay epoch unpin util the end of
                     // the query.
                     if !has_lookup_join_stage && stages_with_table_scan.is_empty() {
@@ -293,12 +370,14 @@ impl QueryRunner {
 
                     // For root stage, we execute in frontend local. We will pass the root fragment
                     // to QueryResultFetcher and execute to get a Chunk stream.
-                    for parent in self.query.get_parents(&stage_id) {
-                        if self.all_children_scheduled(parent).await
+                    for member in &members {
+                        for parent in self.query.get_parents(member) {
+                            if self.all_children_scheduled(parent).await
                                 // Do not schedule same stage twice.
                                 && self.stage_executions[parent].is_pending().await
-                        {
-                            self.stage_executions[parent].start().await;
+                            {
+                                self.stage_executions[parent].start().await;
+                            }
                         }
                     }
                 }
@@ -319,8 +398,8 @@ impl QueryRunner {
                 }
                 Stage(StageEvent::Completed(_)) => {
                     finished_stage_cnt += 1;
-                    assert!(finished_stage_cnt <= self.stage_executions.len());
-                    if finished_stage_cnt == self.stage_executions.len() {
+                    assert!(finished_stage_cnt <= distinct_execution_count);
+                    if finished_stage_cnt == distinct_execution_count {
                         // Now all stages completed, we should remove all
                         self.clean_all_stages(None).await;
                         break;
@@ -426,6 +505,7 @@ pub(crate) mod tests {
     use risingwave_common::constants::hummock::TABLE_OPTION_DUMMY_RETENTION_SECOND;
     use risingwave_common::hash::ParallelUnitMapping;
     use risingwave_common::types::DataType;
+    use risingwave_pb::batch_plan::exchange_info::Distribution as DistributionProst;
     use risingwave_pb::common::{HostAddress, ParallelUnit, WorkerNode, WorkerType};
     use risingwave_pb::plan_common::JoinType;
     use risingwave_rpc_client::ComputeClientPool;
@@ -479,7 +559,47 @@ pub(crate) mod tests {
             .is_err());
     }
 
+    #[tokio::test]
+    async fn test_shared_stage_deduplication() {
+        let query = create_query_inner(true).await;
+        let graph = &query.stage_graph;
+
+        // The two identical scan stages form one shared group.
+        assert_eq!(graph.shared_stage_mapping.len(), 1);
+        let (&duplicate, &canonical) = graph.shared_stage_mapping.iter().next().unwrap();
+        let groups = graph.shared_execution_groups();
+        let group = &groups[&canonical];
+        assert_eq!(group.member_offsets.len(), 2);
+        assert_eq!(group.member_offsets[&canonical], 0);
+
+        // The canonical stage produces one copy of its hash-shuffled output per member, and the
+        // duplicate's exchange reads the second copy.
+        let original = graph.stages[&canonical].exchange_info.as_ref().unwrap();
+        let Some(DistributionProst::HashInfo(original_hash)) = &original.distribution else {
+            panic!("expected hash distribution, got {:?}", original);
+        };
+        let widened = &group.exchange_info;
+        let Some(DistributionProst::HashInfo(widened_hash)) = &widened.distribution else {
+            panic!("expected hash distribution, got {:?}", widened);
+        };
+        assert_eq!(widened_hash.output_count, original_hash.output_count * 2);
+        assert_eq!(widened_hash.copy_count, 2);
+        assert_eq!(widened_hash.key, original_hash.key);
+        assert_eq!(group.member_offsets[&duplicate], original_hash.output_count);
+
+        // The join sides of the default query differ, so nothing is shared there.
+        let query = create_query().await;
+        assert!(query.stage_graph.shared_stage_mapping.is_empty());
+    }
+
     pub async fn create_query() -> Query {
+        create_query_inner(false).await
+    }
+
+    /// With `identical_join_sides`, both join sides are plain scans of the same table, so the
+    /// two scan stages have the same deterministic fingerprint and share one execution.
+    /// Otherwise a filter is added to one side to keep the stages distinct.
+    async fn create_query_inner(identical_join_sides: bool) -> Query {
         // Construct a Hash Join with Exchange node.
         // Logical plan:
         //
@@ -525,6 +645,7 @@ pub(crate) mod tests {
                 value_indices: vec![0, 1, 2],
                 read_prefix_len_hint: 0,
                 watermark_columns: FixedBitSet::with_capacity(3),
+                remote_cluster: None,
             }),
             vec![],
             ctx,
@@ -533,13 +654,17 @@ pub(crate) mod tests {
         .unwrap()
         .to_distributed()
         .unwrap();
-        let batch_filter = BatchFilter::new(LogicalFilter::new(
-            batch_plan_node.clone(),
-            Condition {
-                conjunctions: vec![],
-            },
-        ))
-        .into();
+        let right_input: PlanRef = if identical_join_sides {
+            batch_plan_node.clone()
+        } else {
+            BatchFilter::new(LogicalFilter::new(
+                batch_plan_node.clone(),
+                Condition {
+                    conjunctions: vec![],
+                },
+            ))
+            .into()
+        };
         let batch_exchange_node1: PlanRef = BatchExchange::new(
             batch_plan_node.clone(),
             Order::default(),
@@ -547,7 +672,7 @@ pub(crate) mod tests {
         )
         .into();
         let batch_exchange_node2: PlanRef = BatchExchange::new(
-            batch_filter,
+            right_input,
             Order::default(),
             Distribution::HashShard(vec![0, 1]),
         )
@@ -636,6 +761,7 @@ pub(crate) mod tests {
         let fragmenter = BatchPlanFragmenter::new(
             worker_node_manager,
             catalog_reader,
+            Arc::new(HashMap::new()),
             batch_exchange_node.clone(),
         )
         .unwrap();
@@ -13,7 +13,9 @@
 // limitations under the License.
 
 use std::cell::RefCell;
+use std::collections::hash_map::DefaultHasher;
 use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
 use std::mem;
 use std::rc::Rc;
 use std::sync::Arc;
@@ -39,7 +41,7 @@ use risingwave_pb::batch_plan::{
     DistributedLookupJoinNode, ExchangeNode, ExchangeSource, MergeSortExchangeNode, PlanFragment,
     PlanNode as PlanNodeProst, PlanNode, TaskId as TaskIdProst, TaskOutputId,
 };
-use risingwave_pb::common::{BatchQueryEpoch, HostAddress, WorkerNode};
+use risingwave_pb::common::{batch_query_epoch, BatchQueryEpoch, HostAddress, WorkerNode};
 use risingwave_pb::task_service::{AbortTaskRequest, TaskInfoResponse};
 use risingwave_rpc_client::ComputeClientPoolRef;
 use tokio::spawn;
@@ -120,6 +122,10 @@ pub struct StageExecution {
     ///
     /// We use `Vec` here since children's size is usually small.
     children: Vec<Arc<StageExecution>>,
+    /// Redirections for exchanges consuming a member of a shared stage group, mapping the
+    /// source stage id to the canonical stage actually executed and the output id the member's
+    /// copy of its output starts at. Exchanges not listed here read the source stage directly.
+    exchange_output_offsets: HashMap<StageId, (StageId, u32)>,
     compute_client_pool: ComputeClientPoolRef,
     catalog_reader: CatalogReader,
 
@@ -136,6 +142,8 @@ struct StageRunner {
     // Send message to `QueryRunner` to notify stage state change.
     msg_sender: Sender<QueryMessage>,
     children: Vec<Arc<StageExecution>>,
+    /// See [`StageExecution::exchange_output_offsets`].
+    exchange_output_offsets: HashMap<StageId, (StageId, u32)>,
     compute_client_pool: ComputeClientPoolRef,
     catalog_reader: CatalogReader,
 
@@ -167,6 +175,7 @@ impl StageExecution {
         worker_node_manager: WorkerNodeManagerRef,
         msg_sender: Sender<QueryMessage>,
         children: Vec<Arc<StageExecution>>,
+        exchange_output_offsets: HashMap<StageId, (StageId, u32)>,
         compute_client_pool: ComputeClientPoolRef,
         catalog_reader: CatalogReader,
         ctx: ExecutionContextRef,
@@ -182,6 +191,7 @@ impl StageExecution {
             state: Arc::new(RwLock::new(Pending { msg_sender })),
             shutdown_tx: RwLock::new(None),
             children,
+            exchange_output_offsets,
             compute_client_pool,
             catalog_reader,
             ctx,
@@ -201,6 +211,7 @@ impl StageExecution {
                     tasks: self.tasks.clone(),
                     msg_sender,
                     children: self.children.clone(),
+                    exchange_output_offsets: self.exchange_output_offsets.clone(),
                     state: self.state.clone(),
                     compute_client_pool: self.compute_client_pool.clone(),
                     catalog_reader: self.catalog_reader.clone(),
@@ -326,7 +337,28 @@ impl StageRunner {
             // the task.
             // We schedule the task to the worker node that owns the data partition.
             let parallel_unit_ids = vnode_bitmaps.keys().cloned().collect_vec();
-            let workers = self.worker_node_manager.get_workers_by_parallel_unit_ids(&parallel_unit_ids)?;
+            // A scan of a table of a mounted remote cluster runs on the remote compute nodes
+            // owning the data and reads the remote committed epoch as of now, which is not
+            // pinned. See [`crate::catalog::federation`].
+            let (workers, epoch) = match table_scan_info.remote_cluster() {
+                Some(cluster_name) => {
+                    let env = self.ctx.session().env();
+                    let remote = env.remote_catalogs().get(cluster_name).ok_or_else(|| {
+                        anyhow!("remote cluster {} is not mounted", cluster_name)
+                    })?;
+                    let epoch = BatchQueryEpoch {
+                        epoch: Some(batch_query_epoch::Epoch::Committed(
+                            remote.committed_epoch().await?,
+                        )),
+                    };
+                    let workers = remote.get_workers_by_parallel_unit_ids(&parallel_unit_ids)?;
+                    (workers, epoch)
+                }
+                None => (
+                    self.worker_node_manager.get_workers_by_parallel_unit_ids(&parallel_unit_ids)?,
+                    self.epoch.clone(),
+                ),
+            };
 
             for (i, (parallel_unit_id, worker)) in parallel_unit_ids
                 .into_iter()
@@ -340,7 +372,12 @@ impl StageRunner {
                 };
                 let vnode_ranges = vnode_bitmaps[&parallel_unit_id].clone();
                 let plan_fragment = self.create_plan_fragment(i as u32, Some(PartitionInfo::Table(vnode_ranges)));
-                futures.push(self.schedule_task(task_id, plan_fragment, Some(worker)));
+                futures.push(self.schedule_task(
+                    task_id,
+                    plan_fragment,
+                    Some(worker),
+                    epoch.clone(),
+                ));
             }
         } else if let Some(source_info) = self.stage.source_info.as_ref() {
             for (id, split) in source_info.split_info().unwrap().iter().enumerate() {
@@ -351,7 +388,12 @@ impl StageRunner {
                 };
                 let plan_fragment = self.create_plan_fragment(id as u32, Some(PartitionInfo::Source(split.clone())));
                 let worker = self.choose_worker(&plan_fragment, id as u32)?;
-                futures.push(self.schedule_task(task_id, plan_fragment, worker));
+                futures.push(self.schedule_task(
+                    task_id,
+                    plan_fragment,
+                    worker,
+                    self.epoch.clone(),
+                ));
             }
         }
         else {
@@ -363,7 +405,12 @@ impl StageRunner {
                 };
                 let plan_fragment = self.create_plan_fragment(id, None);
                 let worker = self.choose_worker(&plan_fragment, id)?;
-                futures.push(self.schedule_task(task_id, plan_fragment, worker));
+                futures.push(self.schedule_task(
+                    task_id,
+                    plan_fragment,
+                    worker,
+                    self.epoch.clone(),
+                ));
             }
         }
 
@@ -727,6 +774,7 @@ impl StageRunner {
         task_id: TaskIdProst,
         plan_fragment: PlanFragment,
         worker: Option<WorkerNode>,
+        epoch: BatchQueryEpoch,
     ) -> SchedulerResult<Fuse<Streaming<TaskInfoResponse>>> {
         let worker_node_addr = worker
             .unwrap_or(self.worker_node_manager.next_random()?)
@@ -740,8 +788,17 @@ impl StageRunner {
             .map_err(|e| anyhow!(e))?;
 
         let t_id = task_id.task_id;
+
+        // Derive the trace id from the query id, so that the spans reported by all stages and
+        // compute nodes of this query are merged into a single distributed trace.
+        let trace_id = {
+            let mut hasher = DefaultHasher::new();
+            self.stage.query_id.id.hash(&mut hasher);
+            hasher.finish()
+        };
+
         let stream_status = compute_client
-            .create_task(task_id, plan_fragment, self.epoch.clone())
+            .create_task(task_id, plan_fragment, epoch, trace_id)
             .await
             .map_err(|e| anyhow!(e))?
             .fuse();
@@ -789,15 +846,22 @@ impl StageRunner {
 
         match execution_plan_node.plan_node_type {
             PlanNodeType::BatchExchange => {
-                // Find the stage this exchange node should fetch from and get all exchange sources.
+                // Find the stage this exchange node should fetch from and get all exchange
+                // sources. An exchange consuming a member of a shared stage group is redirected
+                // to the canonical stage and reads the member's own copy of its output.
+                let source_stage_id = execution_plan_node.source_stage_id.unwrap();
+                let (child_stage_id, output_offset) = self
+                    .exchange_output_offsets
+                    .get(&source_stage_id)
+                    .copied()
+                    .unwrap_or((source_stage_id, 0));
                 let child_stage = self
                     .children
                     .iter()
-                    .find(|child_stage| {
-                        child_stage.stage.id == execution_plan_node.source_stage_id.unwrap()
-                    })
+                    .find(|child_stage| child_stage.stage.id == child_stage_id)
                     .unwrap();
-                let exchange_sources = child_stage.all_exchange_sources_for(task_id);
+                let exchange_sources =
+                    child_stage.all_exchange_sources_for(output_offset + task_id);
 
                 match &execution_plan_node.node {
                     NodeBody::Exchange(_exchange_node) => PlanNodeProst {
@@ -12,8 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::hash_map::{DefaultHasher, Entry};
 use std::collections::{HashMap, HashSet};
 use std::fmt::{Debug, Formatter};
+use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
 use anyhow::anyhow;
@@ -26,6 +28,9 @@ use risingwave_common::error::RwError;
 use risingwave_common::hash::{ParallelUnitId, ParallelUnitMapping, VirtualNode};
 use risingwave_common::util::scan_range::ScanRange;
 use risingwave_connector::source::{ConnectorProperties, SplitEnumeratorImpl, SplitImpl};
+use risingwave_pb::batch_plan::exchange_info::{
+    BroadcastInfo, Distribution as DistributionProst, DistributionMode, HashInfo,
+};
 use risingwave_pb::batch_plan::plan_node::NodeBody;
 use risingwave_pb::batch_plan::{ExchangeInfo, ScanRange as ScanRangeProto};
 use risingwave_pb::common::Buffer;
@@ -36,6 +41,7 @@ use uuid::Uuid;
 
 use super::SchedulerError;
 use crate::catalog::catalog_service::CatalogReader;
+use crate::catalog::federation::RemoteClusterCatalog;
 use crate::optimizer::plan_node::generic::GenericPlanRef;
 use crate::optimizer::plan_node::{PlanNodeId, PlanNodeType};
 use crate::optimizer::property::Distribution;
@@ -111,6 +117,28 @@ impl ExecutionPlanNode {
     pub fn node_type(&self) -> PlanNodeType {
         self.plan_node_type
     }
+
+    /// Hash the plan subtree rooted at this node for detecting identical subplans. The
+    /// `plan_node_id` is excluded since it is unique per node, and the `source_stage_id` of
+    /// exchange nodes is substituted with the fingerprint of the child stage, so that two
+    /// exchanges reading from different but identical stages hash equally.
+    ///
+    /// Returns `None` if any referenced child stage is not deterministic.
+    fn hash_subtree(
+        &self,
+        child_fingerprints: &HashMap<StageId, Option<u64>>,
+        hasher: &mut impl Hasher,
+    ) -> Option<()> {
+        self.plan_node_type.hash(hasher);
+        format!("{:?}", self.node).hash(hasher);
+        if let Some(source_stage_id) = self.source_stage_id {
+            (*child_fingerprints.get(&source_stage_id)?)?.hash(hasher);
+        }
+        for child in &self.children {
+            child.hash_subtree(child_fingerprints, hasher)?;
+        }
+        Some(())
+    }
 }
 
 /// `BatchPlanFragmenter` splits a query plan into fragments.
@@ -119,6 +147,9 @@ pub struct BatchPlanFragmenter {
     next_stage_id: StageId,
     worker_node_manager: WorkerNodeManagerRef,
     catalog_reader: CatalogReader,
+    /// Catalogs of remote clusters mounted read-only, used to resolve the vnode mappings of
+    /// remote tables.
+    remote_catalogs: Arc<HashMap<String, Arc<RemoteClusterCatalog>>>,
 
     stage_graph_builder: Option<StageGraphBuilder>,
     stage_graph: Option<StageGraph>,
@@ -136,6 +167,7 @@ impl BatchPlanFragmenter {
     pub fn new(
         worker_node_manager: WorkerNodeManagerRef,
         catalog_reader: CatalogReader,
+        remote_catalogs: Arc<HashMap<String, Arc<RemoteClusterCatalog>>>,
         batch_node: PlanRef,
     ) -> SchedulerResult<Self> {
         let mut plan_fragmenter = Self {
@@ -144,6 +176,7 @@ impl BatchPlanFragmenter {
             next_stage_id: 0,
             worker_node_manager,
             catalog_reader,
+            remote_catalogs,
             stage_graph: None,
         };
         plan_fragmenter.split_into_stage(batch_node)?;
@@ -291,6 +324,11 @@ pub struct TableScanInfo {
     ///
     /// `None` iff the table is a system table.
     partitions: Option<HashMap<ParallelUnitId, TablePartitionInfo>>,
+
+    /// Name of the mounted remote cluster the table lives in, or `None` for a local table. If
+    /// set, the partitions refer to parallel units of the remote cluster, and the scan tasks
+    /// are scheduled onto its compute nodes.
+    remote_cluster: Option<String>,
 }
 
 impl TableScanInfo {
@@ -299,6 +337,21 @@ impl TableScanInfo {
         Self {
             name,
             partitions: Some(partitions),
+            remote_cluster: None,
+        }
+    }
+
+    /// For a table of a mounted remote cluster, with partitions referring to remote parallel
+    /// units.
+    pub fn new_remote(
+        name: String,
+        partitions: HashMap<ParallelUnitId, TablePartitionInfo>,
+        remote_cluster: String,
+    ) -> Self {
+        Self {
+            name,
+            partitions: Some(partitions),
+            remote_cluster: Some(remote_cluster),
         }
     }
 
@@ -307,6 +360,7 @@ impl TableScanInfo {
         Self {
             name,
             partitions: None,
+            remote_cluster: None,
         }
     }
 
@@ -317,6 +371,10 @@ impl TableScanInfo {
     pub fn partitions(&self) -> Option<&HashMap<u32, TablePartitionInfo>> {
         self.partitions.as_ref()
     }
+
+    pub fn remote_cluster(&self) -> Option<&str> {
+        self.remote_cluster.as_deref()
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -362,6 +420,26 @@ impl QueryStage {
         self.has_lookup_join
     }
 
+    /// Fingerprint of the plan subtree rooted at this stage, used to detect identical
+    /// deterministic subplans within one query. `child_fingerprints` maps the id of each child
+    /// stage to its fingerprint.
+    ///
+    /// Returns `None` if this stage is not deterministic: source scans fetch external data that
+    /// may change between executions, and lookup joins couple execution with inner-side reads,
+    /// so neither can be shared.
+    fn deterministic_fingerprint(
+        &self,
+        child_fingerprints: &HashMap<StageId, Option<u64>>,
+    ) -> Option<u64> {
+        if self.source_info.is_some() || self.has_lookup_join {
+            return None;
+        }
+        let mut hasher = DefaultHasher::new();
+        self.parallelism.hash(&mut hasher);
+        self.root.hash_subtree(child_fingerprints, &mut hasher)?;
+        Some(hasher.finish())
+    }
+
     pub fn clone_with_exchange_info(&self, exchange_info: Option<ExchangeInfo>) -> Self {
         if let Some(exchange_info) = exchange_info {
             return Self {
@@ -507,6 +585,57 @@ pub struct StageGraph {
     child_edges: HashMap<StageId, HashSet<StageId>>,
     /// Traverse from down to top. Used in schedule each stage.
     parent_edges: HashMap<StageId, HashSet<StageId>>,
+    /// Identical deterministic stage subtrees within this query, mapping each duplicate stage
+    /// to the canonical stage with the same fingerprint. See [`Self::detect_shared_stages`].
+    pub shared_stage_mapping: HashMap<StageId, StageId>,
+}
+
+/// A group of identical deterministic stages that shares a single execution. The canonical
+/// stage runs once with a widened exchange info, producing one copy of its output per member;
+/// the exchanges consuming each member read the member's own copy.
+#[derive(Debug)]
+pub struct SharedStageGroup {
+    /// Exchange info of the canonical stage, widened to produce one output copy per member.
+    pub exchange_info: ExchangeInfo,
+    /// Output id the consuming exchange of each member starts at on the canonical stage's
+    /// tasks. Contains all members of the group, including the canonical stage at offset 0.
+    pub member_offsets: HashMap<StageId, u32>,
+}
+
+/// Widens the exchange info of a shared stage to produce `copies` identical copies of its
+/// output, one per member of the group. Returns the number of outputs each copy consists of
+/// along with the widened info, or `None` for layouts that cannot be widened: broadcast
+/// exchanges do not carry their output count and consistent-hash exchanges route by vnode.
+fn widen_exchange_info(exchange_info: &ExchangeInfo, copies: u32) -> Option<(u32, ExchangeInfo)> {
+    match exchange_info.mode() {
+        // A single-output exchange becomes a broadcast with one output per member.
+        DistributionMode::Single => Some((
+            1,
+            ExchangeInfo {
+                mode: DistributionMode::Broadcast as i32,
+                distribution: Some(DistributionProst::BroadcastInfo(BroadcastInfo {
+                    count: copies,
+                })),
+            },
+        )),
+        DistributionMode::Hash => {
+            let Some(DistributionProst::HashInfo(hash_info)) = &exchange_info.distribution else {
+                return None;
+            };
+            Some((
+                hash_info.output_count,
+                ExchangeInfo {
+                    mode: DistributionMode::Hash as i32,
+                    distribution: Some(DistributionProst::HashInfo(HashInfo {
+                        output_count: hash_info.output_count * copies,
+                        copy_count: copies,
+                        key: hash_info.key.clone(),
+                    })),
+                },
+            ))
+        }
+        _ => None,
+    }
 }
 
 impl StageGraph {
@@ -555,9 +684,116 @@ impl StageGraph {
             stages: complete_stages,
             child_edges: self.child_edges,
             parent_edges: self.parent_edges,
+            shared_stage_mapping: self.shared_stage_mapping,
         })
     }
 
+    /// Detect identical deterministic stage subtrees, which are common with self-joins of the
+    /// same MV snapshot. Returns a mapping from each duplicate stage to the first stage with
+    /// the same fingerprint.
+    ///
+    /// The scheduler executes each group of identical stages only once, according to
+    /// [`Self::shared_execution_groups`].
+    fn detect_shared_stages(&self) -> HashMap<StageId, StageId> {
+        let mut fingerprints: HashMap<StageId, Option<u64>> = HashMap::new();
+        let mut canonical_by_fingerprint: HashMap<u64, StageId> = HashMap::new();
+        let mut mapping = HashMap::new();
+        for stage_id in self.stage_ids_by_topo_order() {
+            let fingerprint = self.stages[&stage_id].deterministic_fingerprint(&fingerprints);
+            if let Some(fingerprint_value) = fingerprint {
+                match canonical_by_fingerprint.entry(fingerprint_value) {
+                    Entry::Occupied(canonical) => {
+                        mapping.insert(stage_id, *canonical.get());
+                    }
+                    Entry::Vacant(slot) => {
+                        slot.insert(stage_id);
+                    }
+                }
+            }
+            fingerprints.insert(stage_id, fingerprint);
+        }
+        mapping
+    }
+
+    /// Groups the stages of [`Self::shared_stage_mapping`] into shared executions, keyed by the
+    /// canonical stage of each group. Only members whose consuming exchange is identical to the
+    /// canonical one can share its execution: the canonical stage then produces one copy of its
+    /// output per member (its reference count), and each member's exchange reads its own copy,
+    /// so that no task output is ever taken twice. Stages whose exchange layout cannot be
+    /// widened keep executing on their own.
+    pub fn shared_execution_groups(&self) -> HashMap<StageId, SharedStageGroup> {
+        // A duplicate can share its canonical stage's execution only if both are consumed by a
+        // single exchange each, those exchanges are identical, and the exchange layout can be
+        // widened to carry one output copy per member.
+        let mut eligible: HashMap<StageId, StageId> = self
+            .shared_stage_mapping
+            .iter()
+            .filter(|(duplicate, canonical)| {
+                let Some(exchange_info) = &self.stages[*canonical].exchange_info else {
+                    return false;
+                };
+                widen_exchange_info(exchange_info, 2).is_some()
+                    && self.stages[*duplicate].exchange_info.as_ref() == Some(exchange_info)
+                    && self.parent_edges[*duplicate].len() == 1
+                    && self.parent_edges[*canonical].len() == 1
+            })
+            .map(|(duplicate, canonical)| (*duplicate, *canonical))
+            .collect();
+
+        // A stage can only be deduplicated away if its whole subtree is: otherwise its children
+        // would still execute, with nothing consuming their output. Drop violating duplicates
+        // until the remaining set is consistent.
+        loop {
+            let inconsistent = eligible
+                .keys()
+                .filter(|duplicate| {
+                    self.child_edges[duplicate]
+                        .iter()
+                        .any(|child| !eligible.contains_key(child))
+                })
+                .copied()
+                .collect_vec();
+            if inconsistent.is_empty() {
+                break;
+            }
+            for duplicate in inconsistent {
+                eligible.remove(&duplicate);
+            }
+        }
+
+        let mut members_by_canonical: HashMap<StageId, Vec<StageId>> = HashMap::new();
+        for (duplicate, canonical) in eligible {
+            members_by_canonical
+                .entry(canonical)
+                .or_default()
+                .push(duplicate);
+        }
+
+        let mut groups = HashMap::new();
+        for (canonical, mut duplicates) in members_by_canonical {
+            let exchange_info = self.stages[&canonical].exchange_info.as_ref().unwrap();
+            let copies = (duplicates.len() + 1) as u32;
+            let (outputs_per_member, exchange_info) = widen_exchange_info(exchange_info, copies)
+                .expect("eligibility is checked above");
+            // Offsets are assigned in stage id order, with the canonical stage first, to keep
+            // them deterministic.
+            duplicates.sort_unstable();
+            let member_offsets = std::iter::once(canonical)
+                .chain(duplicates)
+                .enumerate()
+                .map(|(i, member)| (member, i as u32 * outputs_per_member))
+                .collect();
+            groups.insert(
+                canonical,
+                SharedStageGroup {
+                    exchange_info,
+                    member_offsets,
+                },
+            );
+        }
+        groups
+    }
+
     #[async_recursion]
     async fn complete_stage(
         &self,
@@ -642,12 +878,21 @@ impl StageGraphBuilder {
     }
 
     pub fn build(self, root_stage_id: StageId) -> StageGraph {
-        StageGraph {
+        let mut graph = StageGraph {
             root_stage_id,
             stages: self.stages,
             child_edges: self.child_edges,
             parent_edges: self.parent_edges,
+            shared_stage_mapping: HashMap::new(),
+        };
+        graph.shared_stage_mapping = graph.detect_shared_stages();
+        if !graph.shared_stage_mapping.is_empty() {
+            tracing::debug!(
+                "detected identical deterministic stages: {:?}",
+                graph.shared_stage_mapping
+            );
         }
+        graph
     }
 
     /// Link parent stage and child stage. Maintain the mappings of parent -> child and child ->
@@ -880,6 +1125,29 @@ impl BatchPlanFragmenter {
             let name = scan_node.logical().table_name().to_owned();
             let info = if scan_node.logical().is_sys_table() {
                 TableScanInfo::system_table(name)
+            } else if let Some(cluster_name) = &scan_node.logical().table_desc().remote_cluster {
+                // The table lives in a mounted remote cluster: derive the partitions from the
+                // remote vnode mapping instead of the local one.
+                let table_desc = scan_node.logical().table_desc();
+                let remote = self.remote_catalogs.get(cluster_name).ok_or_else(|| {
+                    anyhow!("remote cluster {} is not mounted", cluster_name)
+                })?;
+                let table_catalog = remote
+                    .catalog()
+                    .get_table_by_id(&table_desc.table_id)
+                    .map_err(RwError::from)?;
+                let vnode_mapping = remote
+                    .get_fragment_mapping(&table_catalog.fragment_id)
+                    .ok_or_else(|| {
+                        anyhow!(
+                            "failed to get the vnode mapping for the `Materialize` of {}.{}",
+                            cluster_name,
+                            table_catalog.name()
+                        )
+                    })?;
+                let partitions =
+                    derive_partitions(scan_node.scan_ranges(), table_desc, &vnode_mapping);
+                TableScanInfo::new_remote(name, partitions, cluster_name.clone())
             } else {
                 let table_desc = scan_node.logical().table_desc();
                 let table_catalog = self
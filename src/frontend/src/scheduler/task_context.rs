@@ -51,6 +51,7 @@ impl BatchTaskContext for FrontendBatchTaskContext {
             self.env.worker_node_manager_ref(),
             self.env.meta_client_ref(),
             self.auth_context.clone(),
+            self.env.remote_catalogs().clone(),
         ))
     }
 
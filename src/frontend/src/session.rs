@@ -57,6 +57,9 @@ use tracing::info;
 use crate::binder::Binder;
 use crate::catalog::catalog_service::{CatalogReader, CatalogWriter, CatalogWriterImpl};
 use crate::catalog::root_catalog::Catalog;
+use crate::catalog::federation::{
+    mount_remote_catalog, parse_remote_catalog_mounts, RemoteClusterCatalog,
+};
 use crate::catalog::{check_schema_writable, DatabaseId, SchemaId};
 use crate::handler::privilege::ObjectCheckItem;
 use crate::handler::util::to_pg_field;
@@ -102,6 +105,10 @@ pub struct FrontendEnv {
     source_metrics: Arc<SourceMetrics>,
 
     batch_config: BatchConfig,
+
+    /// Catalogs of remote clusters mounted read-only via `--mount-remote-catalog`, keyed by
+    /// cluster name.
+    remote_catalogs: Arc<HashMap<String, Arc<RemoteClusterCatalog>>>,
 }
 
 type SessionMapRef = Arc<Mutex<HashMap<(i32, i32), Arc<SessionImpl>>>>;
@@ -143,6 +150,7 @@ impl FrontendEnv {
             frontend_metrics: Arc::new(FrontendMetrics::for_test()),
             batch_config: BatchConfig::default(),
             source_metrics: Arc::new(SourceMetrics::default()),
+            remote_catalogs: Arc::new(HashMap::new()),
         }
     }
 
@@ -158,6 +166,9 @@ impl FrontendEnv {
         );
         info!("> version: {} ({})", RW_VERSION, GIT_SHA);
 
+        // Initialize mutual TLS for node-to-node RPC connections, if enabled.
+        risingwave_rpc_client::tls::init_tls(&config.server.tls)?;
+
         let batch_config = config.batch;
 
         let frontend_address: HostAddr = opts
@@ -232,6 +243,27 @@ impl FrontendEnv {
 
         meta_client.activate(&frontend_address).await?;
 
+        // Mount the catalogs of remote clusters, if any.
+        let mut remote_catalogs = HashMap::new();
+        if let Some(mounts) = &opts.mount_remote_catalog {
+            for (cluster_name, remote_meta_addr) in parse_remote_catalog_mounts(mounts)? {
+                info!(
+                    "mounting remote catalog {} from {}",
+                    cluster_name, remote_meta_addr
+                );
+                let remote_catalog = mount_remote_catalog(
+                    cluster_name.clone(),
+                    remote_meta_addr,
+                    &frontend_address,
+                    Duration::from_millis(config.server.heartbeat_interval_ms as u64),
+                    Duration::from_secs(config.server.max_heartbeat_interval_secs as u64),
+                )
+                .await?;
+                remote_catalogs.insert(cluster_name, Arc::new(remote_catalog));
+            }
+        }
+        let remote_catalogs = Arc::new(remote_catalogs);
+
         let client_pool = Arc::new(ComputeClientPool::new(config.server.connection_pool_size));
 
         let registry = prometheus::Registry::new();
@@ -273,6 +305,7 @@ impl FrontendEnv {
                 sessions_map: Arc::new(Mutex::new(HashMap::new())),
                 batch_config,
                 source_metrics,
+                remote_catalogs,
             },
             observer_join_handle,
             heartbeat_join_handle,
@@ -300,6 +333,11 @@ impl FrontendEnv {
         &self.user_info_reader
     }
 
+    /// Catalogs of remote clusters mounted read-only, keyed by cluster name.
+    pub fn remote_catalogs(&self) -> &Arc<HashMap<String, Arc<RemoteClusterCatalog>>> {
+        &self.remote_catalogs
+    }
+
     pub fn worker_node_manager(&self) -> &WorkerNodeManager {
         &self.worker_node_manager
     }
@@ -626,6 +664,9 @@ impl SessionManager<PgResponseStream> for SessionManagerImpl {
     }
 
     fn end_session(&self, session: &Self::Session) {
+        // The client has disconnected. Cancel any query that is still running in this session,
+        // so that abandoned connections don't keep queries burning CPU on the compute nodes.
+        session.cancel_current_query();
         self.delete_session(&session.session_id());
     }
 }
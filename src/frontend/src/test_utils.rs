@@ -32,10 +32,11 @@ use risingwave_pb::catalog::table::OptionalAssociatedSourceId;
 use risingwave_pb::catalog::{
     Database as ProstDatabase, Function as ProstFunction, Index as ProstIndex,
     Schema as ProstSchema, Sink as ProstSink, Source as ProstSource, Table as ProstTable,
-    View as ProstView,
+    TableStats as ProstTableStats, View as ProstView,
 };
-use risingwave_pb::hummock::HummockSnapshot;
+use risingwave_pb::hummock::{CompactTaskSummary, EpochCommitTime, HummockSnapshot, LevelSummary};
 use risingwave_pb::meta::list_table_fragments_response::TableFragmentInfo;
+use risingwave_pb::meta::{MvStatus, SinkFreshness};
 use risingwave_pb::stream_plan::StreamFragmentGraph;
 use risingwave_pb::user::update_user_request::UpdateField;
 use risingwave_pb::user::{GrantPrivilege, UserInfo};
@@ -380,6 +381,24 @@ impl CatalogWriter for MockCatalogWriter {
         self.catalog.write().drop_schema(database_id, schema_id);
         Ok(())
     }
+
+    async fn update_table_stats(&self, table_id: TableId, stats: ProstTableStats) -> Result<()> {
+        let &schema_id = self
+            .table_id_to_schema_id
+            .read()
+            .get(&table_id.table_id)
+            .unwrap();
+        let database_id = self.get_database_id_by_schema(schema_id);
+        let mut table = self
+            .catalog
+            .read()
+            .get_table_by_id(&table_id)
+            .unwrap()
+            .to_prost(schema_id, database_id);
+        table.stats = Some(stats);
+        self.catalog.write().update_table(&table);
+        Ok(())
+    }
 }
 
 impl MockCatalogWriter {
@@ -672,9 +691,37 @@ impl FrontendMetaClient for MockFrontendMetaClient {
         Ok(())
     }
 
+    async fn acquire_iterator_lease(&self, _epoch: u64, _ttl_sec: u64) -> RpcResult<u64> {
+        Ok(1)
+    }
+
+    async fn get_iterator_lease(&self, _lease_id: u64) -> RpcResult<Option<u64>> {
+        Ok(Some(0))
+    }
+
     async fn list_meta_snapshots(&self) -> RpcResult<Vec<MetaSnapshotMetadata>> {
         Ok(vec![])
     }
+
+    async fn list_epoch_commit_times(&self) -> RpcResult<Vec<EpochCommitTime>> {
+        Ok(vec![])
+    }
+
+    async fn list_mv_status(&self) -> RpcResult<Vec<MvStatus>> {
+        Ok(vec![])
+    }
+
+    async fn list_sink_freshness(&self) -> RpcResult<Vec<SinkFreshness>> {
+        Ok(vec![])
+    }
+
+    async fn list_compact_task_summaries(&self) -> RpcResult<Vec<CompactTaskSummary>> {
+        Ok(vec![])
+    }
+
+    async fn list_level_summaries(&self) -> RpcResult<Vec<LevelSummary>> {
+        Ok(vec![])
+    }
 }
 
 #[cfg(test)]
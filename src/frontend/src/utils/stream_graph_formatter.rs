@@ -40,6 +40,9 @@ struct StreamGraphFormatter {
     edges: HashMap<u64, StreamFragmentEdge>,
     verbose: bool,
     tables: BTreeMap<u32, Table>,
+    /// The parallelism specified for the job, if any. Used to show the planned parallelism of
+    /// each fragment in verbose mode.
+    specified_parallelism: Option<u64>,
 }
 
 impl StreamGraphFormatter {
@@ -48,6 +51,7 @@ impl StreamGraphFormatter {
             edges: HashMap::default(),
             tables: BTreeMap::default(),
             verbose,
+            specified_parallelism: None,
         }
     }
 
@@ -66,6 +70,7 @@ impl StreamGraphFormatter {
         for edge in &graph.edges {
             self.edges.insert(edge.link_id, edge.clone());
         }
+        self.specified_parallelism = graph.parallelism.as_ref().map(|p| p.parallelism);
 
         for (_, fragment) in graph.fragments.iter().sorted_by_key(|(id, _)| **id) {
             self.explain_fragment(fragment, f)?;
@@ -109,7 +114,17 @@ impl StreamGraphFormatter {
         fragment: &StreamFragment,
         f: &mut impl std::fmt::Write,
     ) -> std::fmt::Result {
-        writeln!(f, "Fragment {}", fragment.get_fragment_id())?;
+        let parallelism = if !self.verbose {
+            "".to_string()
+        } else if fragment.is_singleton {
+            " (parallelism: single)".to_string()
+        } else {
+            match self.specified_parallelism {
+                Some(parallelism) => format!(" (parallelism: {})", parallelism),
+                None => " (parallelism: default)".to_string(),
+            }
+        };
+        writeln!(f, "Fragment {}{}", fragment.get_fragment_id(), parallelism)?;
         self.explain_node(1, fragment.node.as_ref().unwrap(), f)?;
         writeln!(f)
     }
@@ -218,6 +233,10 @@ impl StreamGraphFormatter {
                     "state table: {}",
                     self.add_table(node.get_table().unwrap())
                 )),
+                stream_node::NodeBody::AppendOnlyDedup(node) => Some(format!(
+                    "state table: {}",
+                    self.add_table(node.get_state_table().unwrap())
+                )),
                 stream_node::NodeBody::Now(node) => Some(format!(
                     "state table: {}",
                     self.add_table(node.get_state_table().unwrap())
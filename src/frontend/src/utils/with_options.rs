@@ -15,6 +15,7 @@
 use std::collections::HashMap;
 use std::convert::TryFrom;
 use std::num::NonZeroU32;
+use std::time::Duration;
 
 use itertools::Itertools;
 use risingwave_common::error::{ErrorCode, RwError};
@@ -24,9 +25,11 @@ use risingwave_sqlparser::ast::{
 
 mod options {
     use risingwave_common::catalog::hummock::PROPERTIES_RETENTION_SECOND_KEY;
+    use risingwave_common::constants::streaming::PROPERTIES_CHECKPOINT_SLO_KEY;
 
     pub const APPEND_ONLY: &str = "appendonly";
     pub const RETENTION_SECONDS: &str = PROPERTIES_RETENTION_SECOND_KEY;
+    pub const CHECKPOINT_SLO: &str = PROPERTIES_CHECKPOINT_SLO_KEY;
 }
 
 /// Options or properties extracted from the `WITH` clause of DDLs.
@@ -66,6 +69,27 @@ impl WithOptions {
             .and_then(|s| s.parse().ok())
     }
 
+    /// Parse the checkpoint latency SLO from the options.
+    ///
+    /// Returns an error if the value is present but not a valid duration, so that an invalid
+    /// SLO fails the DDL instead of being silently ignored by the barrier manager.
+    pub fn checkpoint_slo(&self) -> Result<Option<Duration>, RwError> {
+        self.inner
+            .get(options::CHECKPOINT_SLO)
+            .map(|s| {
+                humantime::parse_duration(s.trim()).map_err(|e| {
+                    ErrorCode::InvalidParameterValue(format!(
+                        "invalid duration \"{}\" for {}: {}",
+                        s,
+                        options::CHECKPOINT_SLO,
+                        e
+                    ))
+                    .into()
+                })
+            })
+            .transpose()
+    }
+
     /// Parse the append only property from the options.
     pub fn append_only(&self) -> bool {
         if let Some(val) = self.inner.get(options::APPEND_ONLY) {
@@ -96,6 +120,14 @@ impl WithOptions {
     pub fn internal_table_subset(&self) -> Self {
         self.subset([options::RETENTION_SECONDS])
     }
+
+    /// Get the subset of the options for the table catalog of a streaming job itself.
+    ///
+    /// Compared to [`Self::internal_table_subset`], this additionally includes options that
+    /// only make sense on the job, like `checkpoint_slo`.
+    pub fn streaming_job_subset(&self) -> Self {
+        self.subset([options::RETENTION_SECONDS, options::CHECKPOINT_SLO])
+    }
 }
 
 impl TryFrom<&[SqlOption]> for WithOptions {
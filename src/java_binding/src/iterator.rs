@@ -19,6 +19,7 @@ use bytes::Bytes;
 use futures::TryStreamExt;
 use risingwave_common::row::{OwnedRow, RowDeserializer};
 use risingwave_common::types::ScalarImpl;
+use risingwave_hummock_sdk::filter_key_extractor::FilterKeyExtractorManager;
 use risingwave_hummock_sdk::key::{TableKey, TableKeyRange};
 use risingwave_object_store::object::object_metrics::ObjectStoreMetrics;
 use risingwave_object_store::object::parse_remote_object_store;
@@ -119,8 +120,11 @@ impl Iterator {
             1 << 10,
             TieredCache::none(),
         ));
-        let reader =
-            HummockVersionReader::new(sstable_store, Arc::new(HummockStateStoreMetrics::unused()));
+        let reader = HummockVersionReader::new(
+            sstable_store,
+            Arc::new(FilterKeyExtractorManager::default()),
+            Arc::new(HummockStateStoreMetrics::unused()),
+        );
 
         let stream = {
             let stream = reader
@@ -12,17 +12,25 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::sync::Arc;
 use std::time::Instant;
 
+use anyhow::anyhow;
 use itertools::Itertools;
 use prometheus::Registry;
 use risingwave_backup::error::BackupError;
+use risingwave_backup::mv_snapshot::MvSnapshot;
 use risingwave_backup::storage::MetaSnapshotStorageRef;
-use risingwave_backup::{MetaBackupJobId, MetaSnapshotId, MetaSnapshotManifest};
+use risingwave_backup::{
+    MetaBackupJobId, MetaSnapshotId, MetaSnapshotManifest, MetaSnapshotRetentionPolicy,
+};
 use risingwave_common::bail;
+use risingwave_hummock_sdk::compaction_group::hummock_version_ext::HummockVersionExt;
 use risingwave_hummock_sdk::HummockSstableId;
-use risingwave_pb::backup_service::{BackupJobStatus, MetaBackupManifestId};
+use risingwave_pb::backup_service::{BackupJobStatus, MetaBackupManifestId, MvSnapshotPin};
+use risingwave_pb::catalog::table::TableType;
+use risingwave_pb::catalog::Table;
 use risingwave_pb::meta::subscribe_response::{Info, Operation};
 use tokio::task::JoinHandle;
 
@@ -30,6 +38,7 @@ use crate::backup_restore::meta_snapshot_builder::MetaSnapshotBuilder;
 use crate::backup_restore::metrics::BackupManagerMetrics;
 use crate::hummock::{HummockManagerRef, HummockVersionSafePoint};
 use crate::manager::{IdCategory, MetaSrvEnv};
+use crate::model::MetadataModel;
 use crate::storage::MetaStore;
 use crate::MetaResult;
 
@@ -192,6 +201,33 @@ impl<S: MetaStore> BackupManager<S> {
         Ok(())
     }
 
+    /// Deletes backups that are expired per the given retention policy.
+    /// Returns ids of deleted backups.
+    pub async fn apply_retention_policy(
+        &self,
+        policy: &MetaSnapshotRetentionPolicy,
+    ) -> MetaResult<Vec<MetaSnapshotId>> {
+        let expired = policy.expired_snapshots(
+            self.backup_store
+                .manifest()
+                .snapshot_metadata
+                .iter()
+                .map(|m| (m.id, m.taken_at_sec)),
+        );
+        if !expired.is_empty() {
+            self.delete_backups(&expired).await?;
+            self.env
+                .notification_manager()
+                .notify_hummock_without_version(
+                    Operation::Update,
+                    Info::MetaBackupManifestId(MetaBackupManifestId {
+                        id: self.backup_store.manifest().manifest_id,
+                    }),
+                );
+        }
+        Ok(expired)
+    }
+
     /// List all `SSTables` required by backups.
     pub fn list_pinned_ssts(&self) -> Vec<HummockSstableId> {
         self.backup_store
@@ -203,9 +239,139 @@ impl<S: MetaStore> BackupManager<S> {
             .collect_vec()
     }
 
+    /// List all `SSTables` pinned by exported materialized view snapshots. The pins are persisted
+    /// in meta store, so they survive meta node restarts and hold until the snapshot is imported
+    /// or released.
+    pub async fn list_pinned_mv_snapshot_ssts(&self) -> MetaResult<Vec<HummockSstableId>> {
+        Ok(MvSnapshotPin::list(&*self.env.meta_store_ref())
+            .await?
+            .into_iter()
+            .flat_map(|pin| pin.ssts)
+            .dedup()
+            .collect_vec())
+    }
+
     pub fn manifest(&self) -> Arc<MetaSnapshotManifest> {
         self.backup_store.manifest()
     }
+
+    /// Exports a logical backup of the materialized view `mv_table_id`: its definition plus
+    /// references to the SSTs holding its state. A hummock version safe point is registered for
+    /// the duration of the export, and before it is released an [`MvSnapshotPin`] is persisted in
+    /// meta store, excluding the referenced SSTs from vacuum until the snapshot is imported or
+    /// released via [`Self::release_mv_snapshot`]. Returns the snapshot id and an encoded
+    /// [`MvSnapshot`].
+    pub async fn export_mv_snapshot(
+        &self,
+        mv_table_id: u32,
+    ) -> MetaResult<(MetaSnapshotId, Vec<u8>)> {
+        let _safe_point = self.hummock_manager.register_safe_point().await;
+        let meta_store_snapshot = self.env.meta_store_ref().snapshot().await;
+        let tables = Table::list_at_snapshot::<S>(&meta_store_snapshot).await?;
+        let mv = tables
+            .iter()
+            .find(|t| t.id == mv_table_id)
+            .cloned()
+            .ok_or_else(|| anyhow!("table {} not found", mv_table_id))?;
+        if mv.table_type() != TableType::MaterializedView {
+            bail!(format!("table {} is not a materialized view", mv_table_id));
+        }
+        let table_fragments =
+            crate::model::TableFragments::list_at_snapshot::<S>(&meta_store_snapshot)
+                .await?
+                .into_iter()
+                .find(|tf| tf.table_id().table_id == mv_table_id)
+                .ok_or_else(|| anyhow!("fragments of table {} not found", mv_table_id))?;
+        let internal_table_ids: HashSet<u32> =
+            table_fragments.internal_table_ids().into_iter().collect();
+        let internal_tables = tables
+            .into_iter()
+            .filter(|t| internal_table_ids.contains(&t.id))
+            .collect_vec();
+        let state_table_ids: HashSet<u32> = std::iter::once(mv_table_id)
+            .chain(internal_table_ids.iter().cloned())
+            .collect();
+        let ssts = self
+            .hummock_manager
+            .get_current_version()
+            .await
+            .get_combined_levels()
+            .into_iter()
+            .flat_map(|level| &level.table_infos)
+            .filter(|sst| sst.table_ids.iter().any(|id| state_table_ids.contains(id)))
+            .unique_by(|sst| sst.id)
+            .cloned()
+            .collect_vec();
+        let id = self
+            .env
+            .id_gen_manager()
+            .generate::<{ IdCategory::Backup }>()
+            .await?;
+        let snapshot = MvSnapshot {
+            id,
+            mv,
+            internal_tables,
+            table_fragments: table_fragments.to_protobuf(),
+            ssts,
+        };
+        // Persist the pin while the safe point is still held, so that the SSTs are protected
+        // from vacuum without interruption once the export returns.
+        MvSnapshotPin {
+            id,
+            ssts: snapshot.sst_ids(),
+        }
+        .insert(&*self.env.meta_store_ref())
+        .await?;
+        Ok((id, snapshot.encode()))
+    }
+
+    /// Releases the SST pin of an exported materialized view snapshot, making the SSTs eligible
+    /// for vacuum again once they are no longer referenced otherwise. The snapshot is not
+    /// restorable afterwards.
+    pub async fn release_mv_snapshot(&self, snapshot_id: MetaSnapshotId) -> MetaResult<()> {
+        MvSnapshotPin::delete(&*self.env.meta_store_ref(), &snapshot_id).await?;
+        Ok(())
+    }
+
+    /// Imports a materialized view from an encoded [`MvSnapshot`], e.g. to recover it after an
+    /// accidental `DROP`. The catalog and fragments are written to meta store directly, so like a
+    /// full meta restore the import only takes effect after the next recovery. The state tables
+    /// are re-registered to compaction groups and the referenced SSTs are re-added to the hummock
+    /// version, so the state of the materialized view is readable again even if the SSTs have
+    /// been compacted out of the version since the export. The SSTs themselves must still exist
+    /// in object store, which the exporting cluster guarantees by pinning them until the
+    /// snapshot is released.
+    pub async fn import_mv_snapshot(&self, snapshot: &[u8]) -> MetaResult<()> {
+        let snapshot = MvSnapshot::decode(snapshot)?;
+        let meta_store = self.env.meta_store_ref();
+        for table in std::iter::once(&snapshot.mv).chain(snapshot.internal_tables.iter()) {
+            if Table::select(&*meta_store, &table.id).await?.is_some() {
+                bail!(format!("table {} already exists", table.id));
+            }
+        }
+        if crate::model::TableFragments::select(&*meta_store, &snapshot.mv.id)
+            .await?
+            .is_some()
+        {
+            bail!(format!("fragments of table {} already exist", snapshot.mv.id));
+        }
+        for table in std::iter::once(&snapshot.mv).chain(snapshot.internal_tables.iter()) {
+            table.insert(&*meta_store).await?;
+        }
+        let table_fragments = crate::model::TableFragments::from_protobuf(snapshot.table_fragments);
+        table_fragments.insert(&*meta_store).await?;
+        // Make the state readable again: register the state tables to compaction groups and
+        // re-add the SSTs to the current version. Re-added SSTs may also contain stale versions
+        // of other tables' keys, which is harmless as they are shadowed by newer epochs.
+        self.hummock_manager
+            .register_table_fragments(&table_fragments, &snapshot.mv.properties)
+            .await?;
+        self.hummock_manager.import_ssts(snapshot.ssts).await?;
+        // The snapshot is restored, so its pin (if this cluster is the exporting one) is no
+        // longer needed: the SSTs are referenced by the current version again.
+        MvSnapshotPin::delete(&*meta_store, &snapshot.id).await?;
+        Ok(())
+    }
 }
 
 /// `BackupWorker` creates a database snapshot.
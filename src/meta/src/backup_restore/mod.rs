@@ -18,6 +18,8 @@ mod error;
 mod meta_snapshot_builder;
 mod metrics;
 mod restore;
+mod scheduler;
+pub use scheduler::*;
 mod utils;
 
 pub use restore::*;
@@ -0,0 +1,76 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use risingwave_backup::MetaSnapshotRetentionPolicy;
+use tokio::sync::oneshot::Sender;
+use tokio::task::JoinHandle;
+
+use crate::backup_restore::BackupManagerRef;
+use crate::hummock::VacuumManagerRef;
+use crate::storage::MetaStore;
+
+/// Starts a task to periodically take meta snapshots and apply the snapshot retention policy.
+///
+/// Each tick it
+/// 1. deletes snapshots that are expired per `retention_policy`,
+/// 2. starts a new backup job, which becomes visible in the manifest once it succeeds,
+/// 3. vacuums metadata, because deleting snapshots may unpin SSTs and thus unblock version
+/// checkpoint and delta pruning.
+pub fn start_meta_snapshot_scheduler<S>(
+    backup_manager: BackupManagerRef<S>,
+    vacuum_manager: VacuumManagerRef<S>,
+    interval: Duration,
+    retention_policy: MetaSnapshotRetentionPolicy,
+) -> (JoinHandle<()>, Sender<()>)
+where
+    S: MetaStore,
+{
+    let (shutdown_tx, mut shutdown_rx) = tokio::sync::oneshot::channel();
+    let join_handle = tokio::spawn(async move {
+        let mut min_trigger_interval = tokio::time::interval(interval);
+        min_trigger_interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            tokio::select! {
+                // Wait for interval
+                _ = min_trigger_interval.tick() => {},
+                // Shutdown scheduler
+                _ = &mut shutdown_rx => {
+                    tracing::info!("Meta snapshot scheduler is stopped");
+                    return;
+                }
+            }
+            match backup_manager.apply_retention_policy(&retention_policy).await {
+                Ok(expired) => {
+                    if !expired.is_empty() {
+                        tracing::info!("Deleted expired meta snapshots {:?}", expired);
+                    }
+                }
+                Err(err) => {
+                    tracing::warn!("Apply meta snapshot retention policy error {:#?}", err);
+                }
+            }
+            // It's possible the previous backup job is still running, e.g. with a tiny interval.
+            // Then this tick is skipped, because concurrent backup job is not supported.
+            if let Err(err) = backup_manager.start_backup_job().await {
+                tracing::warn!("Automatic meta snapshot error {:#?}", err);
+            }
+            if let Err(err) = vacuum_manager.vacuum_metadata().await {
+                tracing::warn!("Vacuum metadata error {:#?}", err);
+            }
+        }
+    });
+    (join_handle, shutdown_tx)
+}
@@ -26,8 +26,8 @@ use risingwave_pb::stream_plan::add_mutation::Dispatchers;
 use risingwave_pb::stream_plan::barrier::Mutation;
 use risingwave_pb::stream_plan::update_mutation::*;
 use risingwave_pb::stream_plan::{
-    AddMutation, Dispatcher, PauseMutation, ResumeMutation, SourceChangeSplitMutation,
-    StopMutation, UpdateMutation,
+    AddMutation, ConfigChangeMutation, Dispatcher, PauseMutation, ResumeMutation,
+    SourceChangeSplitMutation, StopMutation, UpdateMutation,
 };
 use risingwave_pb::stream_service::{DropActorsRequest, WaitEpochCommitRequest};
 use risingwave_rpc_client::StreamClientPoolRef;
@@ -35,7 +35,7 @@ use uuid::Uuid;
 
 use super::info::BarrierActorInfo;
 use super::snapshot::SnapshotManagerRef;
-use crate::barrier::CommandChanges;
+use crate::barrier::{BarrierDomainId, CommandChanges};
 use crate::manager::{FragmentManagerRef, WorkerId};
 use crate::model::{ActorId, DispatcherId, FragmentId, TableFragments};
 use crate::storage::MetaStore;
@@ -127,6 +127,14 @@ impl Command {
         Self::Plain(Some(Mutation::Resume(ResumeMutation {})))
     }
 
+    /// Broadcast the given config entries to all streaming actors, to be applied atomically at
+    /// this barrier.
+    pub fn config_change(entries: HashMap<String, String>) -> Self {
+        Self::Plain(Some(Mutation::ConfigChange(ConfigChangeMutation {
+            entries,
+        })))
+    }
+
     /// Changes to the actors to be sent or collected after this command is committed.
     pub fn changes(&self) -> CommandChanges {
         match self {
@@ -150,6 +158,15 @@ impl Command {
         }
     }
 
+    /// Whether this command (re)creates actors, which then need the persisted snapshots of
+    /// `memory_only` executor states to be delivered along with the barrier.
+    pub fn creates_actors(&self) -> bool {
+        matches!(
+            self,
+            Command::CreateStreamingJob { .. } | Command::RescheduleFragment(_)
+        )
+    }
+
     /// If we need to send a barrier to modify actor configuration, we will pause the barrier
     /// injection. return true.
     pub fn should_pause_inject_barrier(&self) -> bool {
@@ -186,6 +203,17 @@ pub struct CommandContext<S: MetaStore> {
 
     pub checkpoint: bool,
 
+    /// The barrier domain this barrier targets, or `None` for a domain-spanning barrier. The
+    /// barrier is only sent to and collected from the actors of this domain, and its checkpoint
+    /// only commits this domain's epoch.
+    pub barrier_domain: Option<BarrierDomainId>,
+
+    /// Persisted snapshots of `memory_only` executor states to be sent along with the barrier.
+    /// Set on any barrier whose command (re)creates actors — the first barrier injected by
+    /// recovery, as well as creating a streaming job and rescheduling — so that the executors
+    /// can restore their state; empty otherwise.
+    pub ephemeral_snapshots: Vec<EphemeralStateSnapshot>,
+
     source_manager: SourceManagerRef<S>,
 }
 
@@ -200,6 +228,7 @@ impl<S: MetaStore> CommandContext<S> {
         curr_epoch: Epoch,
         command: Command,
         checkpoint: bool,
+        barrier_domain: Option<BarrierDomainId>,
         source_manager: SourceManagerRef<S>,
     ) -> Self {
         Self {
@@ -211,6 +240,8 @@ impl<S: MetaStore> CommandContext<S> {
             curr_epoch,
             command,
             checkpoint,
+            barrier_domain,
+            ephemeral_snapshots: vec![],
             source_manager,
         }
     }
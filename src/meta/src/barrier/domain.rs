@@ -0,0 +1,53 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Barrier domains.
+//!
+//! A barrier domain is a set of streaming jobs that share barriers. The domain of a streaming job
+//! is decided when the job is created, is the id of the database the job belongs to, and is
+//! persisted in its [`TableFragments`](crate::model::TableFragments).
+//!
+//! Except for domain-spanning barriers (see below), each barrier targets a single domain: it is
+//! injected into and collected from the actors of that domain only, and its checkpoint commits
+//! only that domain's epoch. Concretely:
+//!
+//! - Periodic barriers rotate through the active domains, so each domain is checkpointed on its
+//!   own cadence, and a backpressured domain does not delay barrier collection in the others.
+//! - [`CheckpointControl`](super::CheckpointControl) commits barriers of the same domain strictly
+//!   in epoch order, but lets barriers of different domains commit out of order, so a domain with
+//!   a slow checkpoint does not block the commits, and thus the data freshness, of the others.
+//! - The Hummock manager partitions its epoch bookkeeping by domain: the version tracks a
+//!   committed epoch per domain, commits are sanity-checked against the epoch of their own domain
+//!   only, and `max_committed_epoch` becomes the maximum over all domains. A commit of a lagging
+//!   domain inserts its L0 sub-level at the position of its epoch rather than on top.
+//!
+//! Epochs are still allocated from the single global sequence, because compute nodes collect
+//! barriers, and seal epochs in the state store, in one globally ordered stream. For the same
+//! reason, syncing a checkpoint on a compute node uploads all data sealed on it, so the SSTs of
+//! one domain's checkpoint may carry data other domains sealed at earlier epochs. That data is
+//! barrier-aligned and thus consistent, but it couples domains on shared compute nodes; fully
+//! decoupling them requires partitioning sealing and syncing by domain on the compute nodes.
+//!
+//! A few barriers must span all domains and are ordered against every domain's barriers:
+//! the first barrier injected by recovery, barriers that carry a cross-job command such as
+//! rescheduling, and barriers with externally attached notifiers (e.g. `FLUSH`), whose
+//! subscribers may await data of any database. Recovery itself also remains global: a failed
+//! barrier of any domain recovers the whole cluster.
+
+/// The id of a barrier domain. Currently this is always the id of the database the streaming job
+/// belongs to.
+pub type BarrierDomainId = u32;
+
+/// The domain that jobs with no specific domain are assigned to, e.g. in tests.
+pub const GLOBAL_BARRIER_DOMAIN: BarrierDomainId = 0;
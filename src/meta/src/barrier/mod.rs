@@ -12,11 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
 use std::mem::take;
 use std::ops::Deref;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use fail::fail_point;
 use futures::future::try_join_all;
@@ -24,15 +25,20 @@ use itertools::Itertools;
 use prometheus::HistogramTimer;
 use risingwave_common::bail;
 use risingwave_common::catalog::TableId;
-use risingwave_common::util::epoch::INVALID_EPOCH;
+use risingwave_common::util::epoch::{Epoch, INVALID_EPOCH};
 use risingwave_hummock_sdk::{ExtendedSstableInfo, HummockSstableId};
+use risingwave_pb::catalog::table::TableType;
 use risingwave_pb::common::worker_node::State::Running;
 use risingwave_pb::common::WorkerType;
 use risingwave_pb::meta::subscribe_response::{Info, Operation};
 use risingwave_pb::meta::table_fragments::actor_status::ActorState;
+use risingwave_pb::meta::barrier_trace::FragmentTrace;
+use risingwave_pb::meta::{
+    BackpressureEdge, BarrierTrace, MvStatus, RecoveryProgress, SinkFreshness,
+};
 use risingwave_pb::stream_plan::Barrier;
 use risingwave_pb::stream_service::{
-    BarrierCompleteRequest, BarrierCompleteResponse, InjectBarrierRequest,
+    BarrierCompleteRequest, BarrierCompleteResponse, EphemeralStateSnapshot, InjectBarrierRequest,
 };
 use risingwave_rpc_client::StreamClientPoolRef;
 use tokio::sync::mpsc::UnboundedSender;
@@ -44,8 +50,10 @@ use uuid::Uuid;
 use self::command::CommandContext;
 use self::info::BarrierActorInfo;
 use self::notifier::Notifier;
-use self::progress::TrackingCommand;
+use self::progress::{CreateMviewProgressSummary, TrackingCommand};
+use self::slo::SloMonitor;
 use self::snapshot::SnapshotManagerRef;
+use self::trace::BarrierTraceStore;
 use crate::barrier::progress::CreateMviewProgressTracker;
 use crate::barrier::snapshot::SnapshotManager;
 use crate::barrier::BarrierEpochState::{Completed, InFlight};
@@ -53,21 +61,25 @@ use crate::hummock::HummockManagerRef;
 use crate::manager::{
     CatalogManagerRef, ClusterManagerRef, FragmentManagerRef, MetaSrvEnv, WorkerId,
 };
-use crate::model::{ActorId, BarrierManagerState};
+use crate::model::{ActorId, BarrierManagerState, MetadataModel};
 use crate::rpc::metrics::MetaMetrics;
 use crate::storage::meta_store::MetaStore;
 use crate::stream::SourceManagerRef;
 use crate::{MetaError, MetaResult};
 
 mod command;
+mod domain;
 mod info;
 mod notifier;
 mod progress;
 mod recovery;
 mod schedule;
+mod slo;
 mod snapshot;
+mod trace;
 
 pub use self::command::{Command, Reschedule};
+pub use self::domain::{BarrierDomainId, GLOBAL_BARRIER_DOMAIN};
 pub use self::schedule::BarrierScheduler;
 
 /// Status of barrier manager.
@@ -147,6 +159,33 @@ pub struct GlobalBarrierManager<S: MetaStore> {
 
     metrics: Arc<MetaMetrics>,
 
+    /// Delay applied before injecting each barrier, in milliseconds. Only set through the fault
+    /// injection service for chaos testing.
+    inject_barrier_delay_ms: AtomicU64,
+
+    /// Latest backfill progress of creating streaming jobs, keyed by the id of the table being
+    /// created. Refreshed by the barrier loop and read by [`Self::list_mv_status`].
+    creating_job_progress: Mutex<HashMap<TableId, CreateMviewProgressSummary>>,
+
+    /// Progress of the ongoing recovery, or `None` if no recovery is running. Updated by the
+    /// recovery procedure and read by [`Self::get_recovery_progress`].
+    recovery_progress: Mutex<Option<RecoveryProgress>>,
+
+    /// Latest freshness reported by each sink actor, keyed by sink id and then actor id, as
+    /// `(sampled_event_ts_ms, commit_at_ms)`. Refreshed by the barrier loop and read by
+    /// [`Self::list_sink_freshness`].
+    sink_freshness: Mutex<HashMap<u32, HashMap<u32, (u64, u64)>>>,
+
+    /// Total blocked output time and elapsed wall time over the last barrier interval, as
+    /// `(blocked_ns, elapsed_ns)` summed over the actors of each exchange edge and keyed by
+    /// `(fragment_id, downstream_fragment_id)`. Refreshed by the barrier loop and read by
+    /// [`Self::list_backpressure`].
+    backpressure: Mutex<HashMap<(u32, u32), (u64, u64)>>,
+
+    /// Inject/collect timestamps of the most recent barriers, read by
+    /// [`Self::get_barrier_trace`].
+    barrier_traces: BarrierTraceStore,
+
     pub(crate) env: MetaSrvEnv<S>,
 }
 
@@ -310,6 +349,7 @@ where
     fn enqueue_command(&mut self, command_ctx: Arc<CommandContext<S>>, notifiers: Vec<Notifier>) {
         let timer = self.metrics.barrier_latency.start_timer();
         self.command_ctx_queue.push_back(EpochNode {
+            enqueue_time: Instant::now(),
             timer: Some(timer),
             wait_commit_timer: None,
             state: InFlight,
@@ -318,8 +358,12 @@ where
         });
     }
 
-    /// Change the state of this `prev_epoch` to `Completed`. Return continuous nodes
-    /// with `Completed` starting from first node [`Completed`..`InFlight`) and remove them.
+    /// Change the state of this `prev_epoch` to `Completed`. Return the nodes that are ready to
+    /// be committed and remove them: a `Completed` node is ready once every node before it
+    /// targets a disjoint barrier domain. Nodes of the same domain, and domain-spanning nodes
+    /// against all the others, are thus committed strictly in epoch order, while nodes of
+    /// different domains may be committed out of order, so that a domain with a slow checkpoint
+    /// does not block the commits of the others.
     fn barrier_completed(
         &mut self,
         prev_epoch: u64,
@@ -336,13 +380,32 @@ where
             node.wait_commit_timer = Some(wait_commit_timer);
             node.state = Completed(result);
         };
-        // Find all continuous nodes with 'Complete' starting from first node
-        let index = self
-            .command_ctx_queue
-            .iter()
-            .position(|x| !matches!(x.state, Completed(_)))
-            .unwrap_or(self.command_ctx_queue.len());
-        let complete_nodes = self.command_ctx_queue.drain(..index).collect_vec();
+        // Scan the queue front to back, removing every completed node that is not ordered after
+        // a yet uncommitted node of an overlapping domain.
+        let mut complete_nodes = vec![];
+        let mut blocked_domains = HashSet::new();
+        let mut blocked_all = false;
+        let mut index = 0;
+        while index < self.command_ctx_queue.len() {
+            let node = &self.command_ctx_queue[index];
+            let barrier_domain = node.command_ctx.barrier_domain;
+            let blocked = blocked_all
+                || match barrier_domain {
+                    Some(domain) => blocked_domains.contains(&domain),
+                    None => !blocked_domains.is_empty(),
+                };
+            if !blocked && matches!(node.state, Completed(_)) {
+                complete_nodes.push(self.command_ctx_queue.remove(index).unwrap());
+            } else {
+                match barrier_domain {
+                    Some(domain) => {
+                        blocked_domains.insert(domain);
+                    }
+                    None => blocked_all = true,
+                }
+                index += 1;
+            }
+        }
         complete_nodes
             .iter()
             .for_each(|node| self.remove_changes(node.command_ctx.command.changes()));
@@ -436,6 +499,8 @@ where
 
 /// The state and message of this barrier, a node for concurrent checkpoint.
 pub struct EpochNode<S: MetaStore> {
+    /// Time when this barrier was enqueued, for checking against per-MV checkpoint SLOs.
+    enqueue_time: Instant,
     /// Timer for recording barrier latency, taken after `complete_barriers`.
     timer: Option<HistogramTimer>,
     /// The timer of `barrier_wait_commit_latency`
@@ -498,10 +563,23 @@ where
             snapshot_manager,
             source_manager,
             metrics,
+            inject_barrier_delay_ms: AtomicU64::new(0),
+            creating_job_progress: Mutex::new(HashMap::new()),
+            recovery_progress: Mutex::new(None),
+            sink_freshness: Mutex::new(HashMap::new()),
+            backpressure: Mutex::new(HashMap::new()),
+            barrier_traces: BarrierTraceStore::new(),
             env,
         }
     }
 
+    /// Set the delay applied before injecting each barrier, for chaos testing. A zero duration
+    /// clears a previously set delay.
+    pub fn set_inject_barrier_delay(&self, delay: Duration) {
+        self.inject_barrier_delay_ms
+            .store(delay.as_millis() as u64, Ordering::Relaxed);
+    }
+
     pub async fn start(barrier_manager: BarrierManagerRef<S>) -> (JoinHandle<()>, Sender<()>) {
         let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel();
         let join_handle = tokio::spawn(async move {
@@ -526,6 +604,7 @@ where
     /// Start an infinite loop to take scheduled barriers and send them.
     async fn run(&self, mut shutdown_rx: Receiver<()>) {
         let mut tracker = CreateMviewProgressTracker::new();
+        let mut slo_monitor = SloMonitor::new(self.catalog_manager.clone(), self.metrics.clone());
         let mut state = BarrierManagerState::create(self.env.meta_store()).await;
         if self.enable_recovery {
             // handle init, here we simply trigger a recovery process to achieve the consistency. We
@@ -553,6 +632,7 @@ where
         let mut barrier_timer: Option<HistogramTimer> = None;
         let (barrier_complete_tx, mut barrier_complete_rx) = tokio::sync::mpsc::unbounded_channel();
         let mut checkpoint_control = CheckpointControl::new(self.metrics.clone());
+        let mut last_barrier_domain = None;
         loop {
             tokio::select! {
                 biased;
@@ -577,6 +657,7 @@ where
                         result,
                         &mut state,
                         &mut tracker,
+                        &mut slo_monitor,
                         &mut checkpoint_control,
                     )
                     .await;
@@ -597,8 +678,11 @@ where
                 notifiers,
                 checkpoint,
             } = self.scheduled_barriers.pop_or_default().await;
+            let barrier_domain = self
+                .resolve_barrier_domain(&command, &notifiers, &mut last_barrier_domain)
+                .await;
             let info = self
-                .resolve_actor_info(&mut checkpoint_control, &command)
+                .resolve_actor_info(&mut checkpoint_control, &command, barrier_domain)
                 .await;
 
             let prev_epoch = state.in_flight_prev_epoch;
@@ -615,7 +699,7 @@ where
                 .await
                 .unwrap();
 
-            let command_ctx = Arc::new(CommandContext::new(
+            let mut command_ctx = CommandContext::new(
                 self.fragment_manager.clone(),
                 self.snapshot_manager.clone(),
                 self.env.stream_client_pool_ref(),
@@ -624,11 +708,28 @@ where
                 new_epoch,
                 command,
                 checkpoint,
+                barrier_domain,
                 self.source_manager.clone(),
-            ));
+            );
+            if command_ctx.command.creates_actors() {
+                // Deliver the persisted snapshots of `memory_only` executor states along with
+                // any barrier that (re)creates actors, so that actors built by scaling or
+                // migration can restore their state just like those built by recovery.
+                command_ctx.ephemeral_snapshots =
+                    EphemeralStateSnapshot::list(self.env.meta_store())
+                        .await
+                        .unwrap();
+            }
+            let command_ctx = Arc::new(command_ctx);
             let mut notifiers = notifiers;
             notifiers.iter_mut().for_each(Notifier::notify_to_send);
 
+            let inject_delay_ms = self.inject_barrier_delay_ms.load(Ordering::Relaxed);
+            if inject_delay_ms > 0 {
+                // Fault injection for chaos testing.
+                tokio::time::sleep(Duration::from_millis(inject_delay_ms)).await;
+            }
+
             checkpoint_control.enqueue_command(command_ctx.clone(), notifiers);
             self.inject_barrier(command_ctx, barrier_complete_tx.clone())
                 .await;
@@ -642,6 +743,7 @@ where
         barrier_complete_tx: UnboundedSender<(u64, MetaResult<Vec<BarrierCompleteResponse>>)>,
     ) {
         let prev_epoch = command_context.prev_epoch.0;
+        self.barrier_traces.record_inject(prev_epoch);
         let result = self.inject_barrier_inner(command_context.clone()).await;
         match result {
             Ok(node_need_collect) => {
@@ -677,6 +779,7 @@ where
             } else {
                 node_need_collect.insert(*node_id, true);
                 let mutation = mutation.clone();
+                let ephemeral_snapshots = command_context.ephemeral_snapshots.clone();
                 let request_id = Uuid::new_v4().to_string();
                 let barrier = Barrier {
                     epoch: Some(risingwave_pb::data::Epoch {
@@ -697,6 +800,7 @@ where
                         barrier: Some(barrier),
                         actor_ids_to_send,
                         actor_ids_to_collect,
+                        ephemeral_snapshots,
                     };
                     tracing::trace!(
                         target: "events::meta::barrier::inject_barrier",
@@ -761,6 +865,7 @@ where
         result: MetaResult<Vec<BarrierCompleteResponse>>,
         state: &mut BarrierManagerState,
         tracker: &mut CreateMviewProgressTracker<S>,
+        slo_monitor: &mut SloMonitor<S>,
         checkpoint_control: &mut CheckpointControl<S>,
     ) {
         if let Err(err) = result {
@@ -773,14 +878,16 @@ where
                 .await;
             return;
         }
+        let resps = result.unwrap();
+        self.barrier_traces.record_collect(prev_epoch, &resps);
         // change the state to Complete
-        let mut complete_nodes = checkpoint_control.barrier_completed(prev_epoch, result.unwrap());
+        let mut complete_nodes = checkpoint_control.barrier_completed(prev_epoch, resps);
         // try commit complete nodes
         let (mut index, mut err_msg) = (0, None);
         for (i, node) in complete_nodes.iter_mut().enumerate() {
             assert!(matches!(node.state, Completed(_)));
             if let Err(err) = self
-                .complete_barrier(node, tracker, checkpoint_control)
+                .complete_barrier(node, tracker, slo_monitor, checkpoint_control)
                 .await
             {
                 index = i;
@@ -822,6 +929,9 @@ where
             // If failed, enter recovery mode.
             self.set_status(BarrierManagerStatus::Recovering).await;
             *tracker = CreateMviewProgressTracker::new();
+            self.creating_job_progress.lock().await.clear();
+            self.sink_freshness.lock().await.clear();
+            self.backpressure.lock().await.clear();
             self.snapshot_manager
                 .unpin_all()
                 .await
@@ -843,14 +953,16 @@ where
         &self,
         node: &mut EpochNode<S>,
         tracker: &mut CreateMviewProgressTracker<S>,
+        slo_monitor: &mut SloMonitor<S>,
         checkpoint_control: &mut CheckpointControl<S>,
     ) -> MetaResult<()> {
         let prev_epoch = node.command_ctx.prev_epoch.0;
         match &mut node.state {
             Completed(resps) => {
-                // We must ensure all epochs are committed in ascending order,
-                // because the storage engine will query from new to old in the order in which
-                // the L0 layer files are generated.
+                // We must ensure the epochs of each barrier domain are committed in ascending
+                // order, because the storage engine will query from new to old in the order in
+                // which the L0 layer files are generated. Commits of different domains may
+                // interleave; the storage engine keeps the L0 sub-levels ordered by epoch.
                 // See https://github.com/risingwave-labs/risingwave/issues/1251
                 let checkpoint = node.command_ctx.checkpoint;
                 let (sst_to_worker, synced_ssts) = collect_synced_ssts(resps);
@@ -864,7 +976,12 @@ where
                 } else if checkpoint {
                     new_snapshot = self
                         .hummock_manager
-                        .commit_epoch(node.command_ctx.prev_epoch.0, synced_ssts, sst_to_worker)
+                        .commit_epoch(
+                            node.command_ctx.prev_epoch.0,
+                            synced_ssts,
+                            sst_to_worker,
+                            node.command_ctx.barrier_domain,
+                        )
                         .await?;
                 } else {
                     new_snapshot = Some(self.hummock_manager.update_current_epoch(prev_epoch));
@@ -913,6 +1030,17 @@ where
                     checkpoint_control.stash_command_to_finish(command);
                 }
 
+                // Refresh the shared view of backfill progress for diagnosis.
+                *self.creating_job_progress.lock().await = tracker.summaries();
+
+                self.update_agg_key_stats(resps);
+                self.update_sink_freshness(resps).await;
+                self.update_backpressure(resps).await;
+
+                if checkpoint {
+                    self.persist_ephemeral_snapshots(resps).await?;
+                }
+
                 let remaining = checkpoint_control.finish_commands(checkpoint).await?;
                 // If there are remaining commands (that requires checkpoint to finish), we force
                 // the next barrier to be a checkpoint.
@@ -924,12 +1052,148 @@ where
                 node.timer.take().unwrap().observe_duration();
                 node.wait_commit_timer.take().unwrap().observe_duration();
 
+                if checkpoint {
+                    slo_monitor
+                        .on_checkpoint_completed(node.enqueue_time.elapsed())
+                        .await;
+                }
+
                 Ok(())
             }
             InFlight => unreachable!(),
         }
     }
 
+    /// Persist the snapshots of `memory_only` executor states reported with this checkpoint
+    /// barrier, tagged with its epoch, so that they can be sent back to the executors whenever
+    /// their actors are (re)created.
+    async fn persist_ephemeral_snapshots(
+        &self,
+        resps: &[BarrierCompleteResponse],
+    ) -> MetaResult<()> {
+        for snapshot in resps.iter().flat_map(|r| &r.ephemeral_snapshots) {
+            snapshot.insert(self.env.meta_store()).await?;
+        }
+        Ok(())
+    }
+
+    /// Update the distinct key count metrics reported by hash agg actors, and warn about
+    /// aggregations whose group keys are skewed among their parallel actors. Such aggregations
+    /// may benefit from being re-created with `SET rw_force_two_phase_agg = true`.
+    fn update_agg_key_stats(&self, resps: &[BarrierCompleteResponse]) {
+        /// Do not report skew for aggregations whose largest actor holds fewer keys than this.
+        const SKEW_MIN_KEY_COUNT: u64 = 1000;
+        /// An aggregation is considered skewed when the largest actor holds this many times more
+        /// keys than the average actor.
+        const SKEW_RATIO: u64 = 4;
+
+        let mut counts_by_table: HashMap<u32, Vec<(u32, u64)>> = HashMap::new();
+        for stat in resps.iter().flat_map(|r| &r.agg_key_counts) {
+            self.metrics
+                .agg_distinct_key_count
+                .with_label_values(&[&stat.table_id.to_string(), &stat.actor_id.to_string()])
+                .set(stat.distinct_key_count as i64);
+            counts_by_table
+                .entry(stat.table_id)
+                .or_default()
+                .push((stat.actor_id, stat.distinct_key_count));
+        }
+
+        for (table_id, counts) in counts_by_table {
+            if counts.len() <= 1 {
+                continue;
+            }
+            let sum: u64 = counts.iter().map(|(_, count)| count).sum();
+            let avg = sum / counts.len() as u64;
+            let (max_actor, max_count) = counts
+                .iter()
+                .copied()
+                .max_by_key(|(_, count)| *count)
+                .unwrap();
+            if max_count >= SKEW_MIN_KEY_COUNT && max_count > avg.max(1) * SKEW_RATIO {
+                tracing::warn!(
+                    target: "events::meta::agg_skew",
+                    "group keys of agg (result table {}) are skewed: actor {} holds {} distinct \
+                     keys while the average over {} actors is {}. Consider re-creating the \
+                     streaming job with `SET rw_force_two_phase_agg = true`",
+                    table_id,
+                    max_actor,
+                    max_count,
+                    counts.len(),
+                    avg,
+                );
+            }
+        }
+    }
+
+    /// Update the sink freshness reported by sink actors with this barrier, and set the per-actor
+    /// freshness metrics.
+    async fn update_sink_freshness(&self, resps: &[BarrierCompleteResponse]) {
+        let mut sink_freshness = self.sink_freshness.lock().await;
+        for stat in resps.iter().flat_map(|r| &r.sink_freshness) {
+            self.metrics
+                .sink_freshness_ms
+                .with_label_values(&[&stat.sink_id.to_string(), &stat.actor_id.to_string()])
+                .set(stat.commit_at_ms.saturating_sub(stat.sampled_event_ts_ms) as i64);
+            sink_freshness
+                .entry(stat.sink_id)
+                .or_default()
+                .insert(stat.actor_id, (stat.sampled_event_ts_ms, stat.commit_at_ms));
+        }
+    }
+
+    /// Update the per-edge backpressure stats reported with this barrier, summing the blocked
+    /// and elapsed time over the parallel actors of each fragment edge. The map is rebuilt on
+    /// every barrier, so edges of dropped or rescheduled actors phase out automatically.
+    async fn update_backpressure(&self, resps: &[BarrierCompleteResponse]) {
+        let mut backpressure: HashMap<(u32, u32), (u64, u64)> = HashMap::new();
+        for stat in resps.iter().flat_map(|r| &r.backpressure_stats) {
+            let (blocked_ns, elapsed_ns) = backpressure
+                .entry((stat.fragment_id, stat.downstream_fragment_id))
+                .or_default();
+            *blocked_ns += stat.blocked_duration_ns;
+            *elapsed_ns += stat.elapsed_duration_ns;
+        }
+        *self.backpressure.lock().await = backpressure;
+    }
+
+    /// Decide the barrier domain the next barrier targets, or `None` for a barrier that must
+    /// span all domains. Barriers that carry the command or notifiers of a specific streaming
+    /// job target the domain of that job; pure periodic barriers rotate through the active
+    /// domains, so that each domain is checkpointed independently. See the `domain` module.
+    async fn resolve_barrier_domain(
+        &self,
+        command: &Command,
+        notifiers: &[Notifier],
+        last_barrier_domain: &mut Option<BarrierDomainId>,
+    ) -> Option<BarrierDomainId> {
+        match command {
+            // Attached notifiers (e.g. of a `FLUSH`) may await the data of any database, which
+            // we cannot tell here, so such barriers span all domains.
+            Command::Plain(None) if notifiers.is_empty() => {
+                let domains = self.fragment_manager.all_barrier_domain_ids().await;
+                let next = domains
+                    .iter()
+                    .copied()
+                    .find(|domain| Some(*domain) > *last_barrier_domain)
+                    .or_else(|| domains.iter().next().copied());
+                *last_barrier_domain = next;
+                next
+            }
+            Command::CreateStreamingJob {
+                table_fragments, ..
+            } => Some(table_fragments.barrier_domain_id()),
+            Command::DropStreamingJobs(table_ids) => {
+                // The catalog guarantees all jobs of a drop belong to the same database.
+                let table_id = table_ids.iter().next().expect("should not be empty");
+                self.fragment_manager.get_barrier_domain_id(table_id).await
+            }
+            // Other commands either carry mutations for all actors or may touch the jobs of
+            // several databases, so their barriers span all domains.
+            _ => None,
+        }
+    }
+
     /// Resolve actor information from cluster, fragment manager and `ChangedTableId`.
     /// We use `changed_table_id` to modify the actors to be sent or collected. Because these actor
     /// will create or drop before this barrier flow through them.
@@ -937,6 +1201,7 @@ where
         &self,
         checkpoint_control: &mut CheckpointControl<S>,
         command: &Command,
+        barrier_domain: Option<BarrierDomainId>,
     ) -> BarrierActorInfo {
         checkpoint_control.pre_resolve(command);
 
@@ -947,7 +1212,10 @@ where
             .cluster_manager
             .list_worker_node(WorkerType::ComputeNode, Some(Running))
             .await;
-        let all_actor_infos = self.fragment_manager.load_all_actors(check_state).await;
+        let all_actor_infos = self
+            .fragment_manager
+            .load_all_actors(barrier_domain, check_state)
+            .await;
 
         let info = BarrierActorInfo::resolve(all_nodes, all_actor_infos);
 
@@ -955,6 +1223,178 @@ where
 
         info
     }
+
+    /// Assemble the maintenance status of all materialized views from the barrier progress and
+    /// hummock stats, for the `rw_mv_status` system table.
+    pub async fn list_mv_status(&self) -> MetaResult<Vec<MvStatus>> {
+        let current_version = self.hummock_manager.get_current_version().await;
+        let max_committed_epoch = current_version.max_committed_epoch;
+        let domain_committed_epochs = current_version.domain_committed_epochs;
+        let version_stats = self.hummock_manager.get_version_stats().await;
+        // Each barrier domain checkpoints independently, so the committed epoch, and thus the
+        // barrier lag, is tracked per domain. See the `domain` module.
+        let barrier_domains: HashMap<TableId, BarrierDomainId> = self
+            .fragment_manager
+            .list_table_fragments()
+            .await?
+            .into_iter()
+            .map(|tf| (tf.table_id(), tf.barrier_domain_id()))
+            .collect();
+        let creating_job_progress = self.creating_job_progress.lock().await.clone();
+
+        let mv_status = self
+            .catalog_manager
+            .list_tables()
+            .await
+            .into_iter()
+            .filter(|table| table.table_type() == TableType::MaterializedView)
+            .map(|table| {
+                let state_size_bytes = version_stats
+                    .table_stats
+                    .get(&table.id)
+                    .map(|stats| (stats.total_key_size + stats.total_value_size).max(0) as u64)
+                    .unwrap_or(0);
+                let last_committed_epoch = barrier_domains
+                    .get(&TableId::new(table.id))
+                    .and_then(|domain| domain_committed_epochs.get(domain))
+                    .copied()
+                    .unwrap_or(max_committed_epoch);
+                let barrier_lag_ms = Epoch::physical_now()
+                    .saturating_sub(Epoch(last_committed_epoch).physical_time());
+                let progress = creating_job_progress.get(&TableId::new(table.id));
+                MvStatus {
+                    table_id: table.id,
+                    name: table.name,
+                    last_committed_epoch,
+                    barrier_lag_ms,
+                    backfilling: progress.is_some(),
+                    backfill_progress: progress
+                        .map(|p| p.done_actors as f64 / p.actor_count as f64)
+                        .unwrap_or(0.0),
+                    slowest_actor_id: progress.and_then(|p| p.slowest_actor).unwrap_or(0),
+                    state_size_bytes,
+                }
+            })
+            .collect();
+        Ok(mv_status)
+    }
+
+    /// Assemble the end-to-end freshness of all sinks from the per-actor reports, for the
+    /// `rw_sink_freshness` system table. The freshness of a sink is taken from its slowest
+    /// parallel actor. Sinks that have not committed since the meta service started are reported
+    /// with zero timestamps.
+    pub async fn list_sink_freshness(&self) -> MetaResult<Vec<SinkFreshness>> {
+        let sink_freshness = self.sink_freshness.lock().await.clone();
+        let freshness = self
+            .catalog_manager
+            .list_sinks()
+            .await
+            .into_iter()
+            .map(|sink| {
+                let slowest = sink_freshness.get(&sink.id).and_then(|actors| {
+                    actors
+                        .values()
+                        .copied()
+                        .max_by_key(|(event_ts_ms, commit_at_ms)| {
+                            commit_at_ms.saturating_sub(*event_ts_ms)
+                        })
+                });
+                let (sampled_event_ts_ms, last_commit_at_ms) = slowest.unwrap_or((0, 0));
+                SinkFreshness {
+                    sink_id: sink.id,
+                    name: sink.name,
+                    sampled_event_ts_ms,
+                    last_commit_at_ms,
+                    freshness_ms: last_commit_at_ms.saturating_sub(sampled_event_ts_ms),
+                }
+            })
+            .collect();
+        Ok(freshness)
+    }
+
+    /// List the exchange edges ordered by how backpressured they are, most backpressured first.
+    /// The ratio of an edge is the fraction of wall time its upstream actors spent blocked on
+    /// dispatching to the downstream fragment over the last barrier interval. A `limit` of 0
+    /// means no limit.
+    pub async fn list_backpressure(&self, limit: usize) -> MetaResult<Vec<BackpressureEdge>> {
+        let backpressure = self.backpressure.lock().await.clone();
+        let mut edges: Vec<_> = backpressure
+            .into_iter()
+            .map(
+                |((fragment_id, downstream_fragment_id), (blocked_ns, elapsed_ns))| {
+                    BackpressureEdge {
+                        fragment_id,
+                        downstream_fragment_id,
+                        backpressure_ratio: if elapsed_ns == 0 {
+                            0.0
+                        } else {
+                            blocked_ns as f64 / elapsed_ns as f64
+                        },
+                    }
+                },
+            )
+            .collect();
+        edges.sort_by(|a, b| b.backpressure_ratio.total_cmp(&a.backpressure_ratio));
+        if limit != 0 {
+            edges.truncate(limit);
+        }
+        Ok(edges)
+    }
+
+    /// Get the progress of the ongoing recovery. Returns a default progress with
+    /// `under_recovery = false` when no recovery is running.
+    pub async fn get_recovery_progress(&self) -> RecoveryProgress {
+        self.recovery_progress
+            .lock()
+            .await
+            .clone()
+            .unwrap_or_default()
+    }
+
+    /// Assemble the trace of the barrier with the given `prev_epoch` (0 for the most recently
+    /// completed one), aggregating the per-actor collect timestamps by fragment.
+    pub async fn get_barrier_trace(&self, prev_epoch: u64) -> MetaResult<BarrierTrace> {
+        let Some((prev_epoch, entry)) = self.barrier_traces.get(prev_epoch) else {
+            bail!("no trace recorded for barrier with prev epoch {}", prev_epoch);
+        };
+
+        // Resolve actors to fragments at query time, to keep the per-barrier hot path free of
+        // fragment listing. Actors of streaming jobs dropped in the meantime cannot be resolved
+        // anymore and are skipped.
+        let mut fragment_by_actor = HashMap::new();
+        for table_fragments in self.fragment_manager.list_table_fragments().await? {
+            for fragment in table_fragments.fragments() {
+                for actor in &fragment.actors {
+                    fragment_by_actor.insert(actor.actor_id, fragment.fragment_id);
+                }
+            }
+        }
+
+        let mut fragments: BTreeMap<u32, FragmentTrace> = BTreeMap::new();
+        for (actor_id, collect_at_ms) in entry.actor_collects {
+            let Some(&fragment_id) = fragment_by_actor.get(&actor_id) else {
+                continue;
+            };
+            let trace = fragments
+                .entry(fragment_id)
+                .or_insert_with(|| FragmentTrace {
+                    fragment_id,
+                    first_collect_at_ms: u64::MAX,
+                    last_collect_at_ms: 0,
+                    actor_count: 0,
+                });
+            trace.first_collect_at_ms = trace.first_collect_at_ms.min(collect_at_ms);
+            trace.last_collect_at_ms = trace.last_collect_at_ms.max(collect_at_ms);
+            trace.actor_count += 1;
+        }
+
+        Ok(BarrierTrace {
+            prev_epoch,
+            inject_at_ms: entry.inject_at_ms,
+            complete_at_ms: entry.complete_at_ms,
+            fragments: fragments.into_values().collect(),
+        })
+    }
 }
 
 pub type BarrierManagerRef<S> = Arc<GlobalBarrierManager<S>>;
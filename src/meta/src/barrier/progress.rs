@@ -16,11 +16,13 @@ use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use risingwave_common::catalog::TableId;
 use risingwave_common::util::epoch::Epoch;
 use risingwave_pb::stream_service::barrier_complete_response::CreateMviewProgress;
 
 use super::command::CommandContext;
 use super::notifier::Notifier;
+use super::CommandChanges;
 use crate::model::ActorId;
 use crate::storage::MetaStore;
 
@@ -78,6 +80,40 @@ impl Progress {
     fn actors(&self) -> impl Iterator<Item = ActorId> + '_ {
         self.states.keys().cloned()
     }
+
+    /// Returns a point-in-time summary of this progress.
+    fn summary(&self) -> CreateMviewProgressSummary {
+        let slowest_actor = self
+            .states
+            .iter()
+            .filter_map(|(actor, state)| match state {
+                // Actors still consuming the snapshot have not reported an epoch yet, so they
+                // are considered the furthest behind.
+                ChainState::ConsumingSnapshot => Some((*actor, Epoch(0))),
+                ChainState::ConsumingUpstream(epoch) => Some((*actor, *epoch)),
+                ChainState::Done => None,
+            })
+            .min_by_key(|(_, epoch)| *epoch)
+            .map(|(actor, _)| actor);
+        CreateMviewProgressSummary {
+            done_actors: self.done_count,
+            actor_count: self.states.len(),
+            slowest_actor,
+        }
+    }
+}
+
+/// A point-in-time view of the backfill progress of one creating mview, for diagnosis purposes
+/// like the `rw_mv_status` system table.
+#[derive(Clone, Debug)]
+pub struct CreateMviewProgressSummary {
+    /// Number of chain actors that have caught up with the upstream.
+    pub done_actors: usize,
+    /// Total number of chain actors of the creating mview.
+    pub actor_count: usize,
+    /// The chain actor with the smallest consumed epoch, i.e. the one furthest behind. `None`
+    /// if all actors are done.
+    pub slowest_actor: Option<ActorId>,
 }
 
 /// The command tracking by the [`CreateMviewProgressTracker`].
@@ -128,6 +164,18 @@ impl<S: MetaStore> CreateMviewProgressTracker<S> {
         None
     }
 
+    /// Returns a summary of the backfill progress of each creating mview, keyed by the id of the
+    /// table being created.
+    pub fn summaries(&self) -> HashMap<TableId, CreateMviewProgressSummary> {
+        self.progress_map
+            .values()
+            .filter_map(|(progress, command)| match command.context.command.changes() {
+                CommandChanges::CreateTable(table_id) => Some((table_id, progress.summary())),
+                _ => None,
+            })
+            .collect()
+    }
+
     /// Update the progress of `actor` according to the Prost struct.
     ///
     /// If all actors in this MV have finished, returns the command.
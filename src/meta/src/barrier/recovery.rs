@@ -12,19 +12,22 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 use futures::future::try_join_all;
 use itertools::Itertools;
+use risingwave_common::catalog::checkpoint_slo_from_properties;
 use risingwave_common::util::epoch::Epoch;
 use risingwave_pb::common::worker_node::State;
 use risingwave_pb::common::{ActorInfo, WorkerNode, WorkerType};
+use risingwave_pb::meta::RecoveryProgress;
 use risingwave_pb::stream_plan::barrier::Mutation;
 use risingwave_pb::stream_plan::AddMutation;
 use risingwave_pb::stream_service::{
-    BroadcastActorInfoTableRequest, BuildActorsRequest, ForceStopActorsRequest, UpdateActorsRequest,
+    BroadcastActorInfoTableRequest, BuildActorsRequest, EphemeralStateSnapshot,
+    ForceStopActorsRequest, UpdateActorsRequest,
 };
 use tokio_retry::strategy::{jitter, ExponentialBackoff};
 use tracing::{debug, error, warn};
@@ -34,7 +37,7 @@ use crate::barrier::command::CommandContext;
 use crate::barrier::info::BarrierActorInfo;
 use crate::barrier::{CheckpointControl, Command, GlobalBarrierManager};
 use crate::manager::WorkerId;
-use crate::model::ActorId;
+use crate::model::{ActorId, MetadataModel, TableFragments};
 use crate::storage::MetaStore;
 use crate::stream::build_actor_connector_splits;
 use crate::MetaResult;
@@ -57,9 +60,11 @@ where
     }
 
     async fn resolve_actor_info_for_recovery(&self) -> BarrierActorInfo {
+        // Recovery rebuilds the actors of all barrier domains, so resolve across all of them.
         self.resolve_actor_info(
             &mut CheckpointControl::new(self.metrics.clone()),
             &Command::barrier(),
+            None,
         )
         .await
     }
@@ -118,6 +123,10 @@ where
         self.scheduled_barriers.abort().await;
 
         tracing::info!("recovery start!");
+        *self.recovery_progress.lock().await = Some(RecoveryProgress {
+            under_recovery: true,
+            ..Default::default()
+        });
         self.clean_dirty_fragments()
             .await
             .expect("clean dirty fragments");
@@ -156,8 +165,9 @@ where
 
             let prev_epoch = new_epoch;
             new_epoch = prev_epoch.next();
-            // checkpoint, used as init barrier to initialize all executors.
-            let command_ctx = Arc::new(CommandContext::new(
+            // checkpoint, used as init barrier to initialize all executors. It spans all barrier
+            // domains, since recovery rebuilds the whole stream graph.
+            let mut command_ctx = CommandContext::new(
                 self.fragment_manager.clone(),
                 self.snapshot_manager.clone(),
                 self.env.stream_client_pool_ref(),
@@ -166,8 +176,18 @@ where
                 new_epoch,
                 command,
                 true,
+                None,
                 self.source_manager.clone(),
-            ));
+            );
+            // Send the persisted snapshots of `memory_only` executor states along with the init
+            // barrier, so that the executors can restore their state.
+            command_ctx.ephemeral_snapshots =
+                EphemeralStateSnapshot::list(self.env.meta_store())
+                    .await
+                    .inspect_err(|err| {
+                        error!(err = ?err, "list ephemeral state snapshots failed");
+                    })?;
+            let command_ctx = Arc::new(command_ctx);
 
             let (barrier_complete_tx, mut barrier_complete_rx) =
                 tokio::sync::mpsc::unbounded_channel();
@@ -189,6 +209,7 @@ where
         })
         .await
         .expect("Retry until recovery success.");
+        *self.recovery_progress.lock().await = None;
         tracing::info!("recovery success");
 
         new_epoch
@@ -312,20 +333,99 @@ where
         Ok(())
     }
 
-    /// Build all actors in compute nodes.
+    /// List all streaming jobs to rebuild, serving-critical jobs first.
+    ///
+    /// Materialized views declaring a `checkpoint_slo` are considered serving-critical and are
+    /// ordered by their SLO ascending, so that the most latency-sensitive views are rebuilt
+    /// first. The remaining jobs follow in table id order.
+    async fn recovery_queue(&self) -> MetaResult<Vec<TableFragments>> {
+        let slo_by_table: HashMap<u32, Duration> = self
+            .catalog_manager
+            .list_tables()
+            .await
+            .into_iter()
+            .filter_map(|t| checkpoint_slo_from_properties(&t.properties).map(|slo| (t.id, slo)))
+            .collect();
+
+        let mut queue = self.fragment_manager.list_table_fragments().await?;
+        queue.sort_by_key(|table_fragments| {
+            let table_id = table_fragments.table_id().table_id;
+            let slo = slo_by_table
+                .get(&table_id)
+                .copied()
+                .unwrap_or(Duration::MAX);
+            (slo, table_id)
+        });
+        Ok(queue)
+    }
+
+    /// Build all actors in compute nodes, one batch of streaming jobs at a time.
+    ///
+    /// Jobs are rebuilt in the priority order given by [`Self::recovery_queue`], with at most
+    /// `recovery_build_parallelism` jobs per batch, so that a cluster with many streaming jobs
+    /// does not overwhelm the compute nodes with all actor builds at once. The progress is
+    /// published for [`GlobalBarrierManager::get_recovery_progress`].
     async fn build_actors(&self, info: &BarrierActorInfo) -> MetaResult<()> {
-        for (node_id, actors) in &info.actor_map {
-            let node = info.node_map.get(node_id).unwrap();
-            let client = self.env.stream_client_pool().get(node).await?;
+        let queue = self.recovery_queue().await?;
+        let total_jobs = queue.len();
+        let batch_size = match self.env.opts.recovery_build_parallelism {
+            0 => total_jobs.max(1),
+            n => n,
+        };
+
+        // Only the actors resolved in `info` are to be built; the others have been cleaned up
+        // as dirty fragments or are still unassigned.
+        let to_build: HashMap<WorkerId, HashSet<ActorId>> = info
+            .actor_map
+            .iter()
+            .map(|(&node_id, actors)| (node_id, actors.iter().copied().collect()))
+            .collect();
 
-            let request_id = Uuid::new_v4().to_string();
-            tracing::debug!(request_id = request_id.as_str(), actors = ?actors, "build actors");
-            client
-                .build_actors(BuildActorsRequest {
-                    request_id,
-                    actor_id: actors.to_owned(),
-                })
-                .await?;
+        let mut rebuilt_jobs = 0;
+        *self.recovery_progress.lock().await = Some(RecoveryProgress {
+            under_recovery: true,
+            rebuilt_jobs,
+            total_jobs: total_jobs as u32,
+        });
+
+        for batch in queue.chunks(batch_size) {
+            // node_id => actors of this batch of jobs.
+            let mut node_actors: HashMap<WorkerId, Vec<ActorId>> = HashMap::new();
+            for table_fragments in batch {
+                for (node_id, actor_ids) in table_fragments.worker_actor_ids() {
+                    if let Some(to_build) = to_build.get(&node_id) {
+                        node_actors
+                            .entry(node_id)
+                            .or_default()
+                            .extend(actor_ids.into_iter().filter(|a| to_build.contains(a)));
+                    }
+                }
+            }
+
+            for (node_id, actors) in &node_actors {
+                if actors.is_empty() {
+                    continue;
+                }
+                let node = info.node_map.get(node_id).unwrap();
+                let client = self.env.stream_client_pool().get(node).await?;
+
+                let request_id = Uuid::new_v4().to_string();
+                tracing::debug!(request_id = request_id.as_str(), actors = ?actors, "build actors");
+                client
+                    .build_actors(BuildActorsRequest {
+                        request_id,
+                        actor_id: actors.to_owned(),
+                    })
+                    .await?;
+            }
+
+            rebuilt_jobs += batch.len() as u32;
+            debug!("rebuilt actors of {}/{} streaming jobs", rebuilt_jobs, total_jobs);
+            *self.recovery_progress.lock().await = Some(RecoveryProgress {
+                under_recovery: true,
+                rebuilt_jobs,
+                total_jobs: total_jobs as u32,
+            });
         }
 
         Ok(())
@@ -0,0 +1,100 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use risingwave_common::catalog::checkpoint_slo_from_properties;
+use risingwave_pb::catalog::table::TableType;
+
+use crate::manager::CatalogManagerRef;
+use crate::rpc::metrics::MetaMetrics;
+use crate::storage::MetaStore;
+
+/// Tracks the per-MV checkpoint latency SLOs declared with `checkpoint_slo = '...'` and reports
+/// violations when a checkpoint barrier takes longer to complete than the declared SLO.
+///
+/// All materialized views share the global checkpoint barrier, so the completion latency is the
+/// same for all of them. The per-MV part is which views declared an SLO: a violation event and
+/// metric is emitted for each of those views, so users can tell which MV's expectation is not
+/// being met.
+pub(super) struct SloMonitor<S: MetaStore> {
+    catalog_manager: CatalogManagerRef<S>,
+    metrics: Arc<MetaMetrics>,
+    /// Cached `table id -> (name, slo)` of materialized views declaring an SLO.
+    slo_by_table: HashMap<u32, (String, Duration)>,
+    last_refresh: Option<Instant>,
+}
+
+impl<S> SloMonitor<S>
+where
+    S: MetaStore,
+{
+    /// How often the SLO cache is refreshed from the catalog. Violations are only checked
+    /// against the cached view, so a newly declared SLO takes effect within this interval.
+    const REFRESH_INTERVAL: Duration = Duration::from_secs(60);
+
+    pub fn new(catalog_manager: CatalogManagerRef<S>, metrics: Arc<MetaMetrics>) -> Self {
+        Self {
+            catalog_manager,
+            metrics,
+            slo_by_table: HashMap::new(),
+            last_refresh: None,
+        }
+    }
+
+    /// Called when a checkpoint barrier is committed, with the duration from barrier injection
+    /// to commit.
+    pub async fn on_checkpoint_completed(&mut self, latency: Duration) {
+        self.refresh_if_stale().await;
+
+        for (table_id, (name, slo)) in &self.slo_by_table {
+            if latency > *slo {
+                self.metrics
+                    .barrier_slo_violation_count
+                    .with_label_values(&[&table_id.to_string()])
+                    .inc();
+                tracing::warn!(
+                    target: "events::meta::barrier::slo_violation",
+                    "checkpoint latency {:?} violated SLO {:?} of materialized view {} (id {})",
+                    latency,
+                    slo,
+                    name,
+                    table_id
+                );
+            }
+        }
+    }
+
+    async fn refresh_if_stale(&mut self) {
+        if let Some(last_refresh) = self.last_refresh {
+            if last_refresh.elapsed() < Self::REFRESH_INTERVAL {
+                return;
+            }
+        }
+
+        self.slo_by_table = self
+            .catalog_manager
+            .list_tables()
+            .await
+            .into_iter()
+            .filter(|t| t.table_type() == TableType::MaterializedView)
+            .filter_map(|t| {
+                checkpoint_slo_from_properties(&t.properties).map(|slo| (t.id, (t.name, slo)))
+            })
+            .collect();
+        self.last_refresh = Some(Instant::now());
+    }
+}
@@ -0,0 +1,116 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use parking_lot::Mutex;
+use risingwave_pb::stream_service::BarrierCompleteResponse;
+
+use crate::model::ActorId;
+
+/// Trace of one barrier: when the meta service injected it, when each actor collected it, and
+/// when all compute nodes reported it as collected.
+///
+/// The inject and complete timestamps are taken on the meta node, while the per-actor collect
+/// timestamps are taken on the compute nodes, so comparing them across nodes is subject to
+/// clock skew. Comparing the collect timestamps of different fragments, which is what the trace
+/// is for, is not affected by the skew between meta and compute nodes.
+#[derive(Clone, Debug, Default)]
+pub(super) struct BarrierTraceEntry {
+    /// Unix timestamp in milliseconds when the barrier was injected.
+    pub inject_at_ms: u64,
+    /// Unix timestamp in milliseconds when all compute nodes reported the barrier as collected,
+    /// or 0 if the barrier is still in-flight.
+    pub complete_at_ms: u64,
+    /// Unix timestamp in milliseconds when each actor collected the barrier.
+    pub actor_collects: Vec<(ActorId, u64)>,
+}
+
+/// In-memory store of the traces of the most recent barriers, queried through the
+/// `GetBarrierTrace` rpc to attribute a slow checkpoint to a specific fragment.
+///
+/// Actors are not resolved to fragments here: that requires listing all table fragments, which
+/// would be wasteful to do for every barrier, so it's deferred to query time.
+pub(super) struct BarrierTraceStore {
+    /// Traces of the most recent barriers, keyed by `prev_epoch`.
+    entries: Mutex<BTreeMap<u64, BarrierTraceEntry>>,
+}
+
+impl BarrierTraceStore {
+    /// The number of barriers to keep traces for. The oldest traces are dropped beyond this.
+    const MAX_TRACED_BARRIERS: usize = 256;
+
+    pub fn new() -> Self {
+        Self {
+            entries: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    fn now_ms() -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Clock may have gone backwards")
+            .as_millis() as u64
+    }
+
+    /// Record that the barrier with `prev_epoch` is being injected to the compute nodes.
+    pub fn record_inject(&self, prev_epoch: u64) {
+        let mut entries = self.entries.lock();
+        entries.insert(
+            prev_epoch,
+            BarrierTraceEntry {
+                inject_at_ms: Self::now_ms(),
+                ..Default::default()
+            },
+        );
+        while entries.len() > Self::MAX_TRACED_BARRIERS {
+            entries.pop_first();
+        }
+    }
+
+    /// Record that all compute nodes have collected the barrier with `prev_epoch`, folding the
+    /// per-actor collect timestamps carried by their responses into the trace.
+    pub fn record_collect(&self, prev_epoch: u64, resps: &[BarrierCompleteResponse]) {
+        let mut entries = self.entries.lock();
+        // The entry may have been evicted in the meantime.
+        let Some(entry) = entries.get_mut(&prev_epoch) else {
+            return;
+        };
+        entry.complete_at_ms = Self::now_ms();
+        entry.actor_collects.extend(
+            resps
+                .iter()
+                .flat_map(|resp| &resp.collect_traces)
+                .map(|trace| (trace.actor_id, trace.collect_at_ms)),
+        );
+    }
+
+    /// Return the trace of the barrier with `prev_epoch`, or of the most recently completed
+    /// barrier if `prev_epoch` is 0.
+    pub fn get(&self, prev_epoch: u64) -> Option<(u64, BarrierTraceEntry)> {
+        let entries = self.entries.lock();
+        if prev_epoch == 0 {
+            entries
+                .iter()
+                .rev()
+                .find(|(_, entry)| entry.complete_at_ms != 0)
+                .map(|(epoch, entry)| (*epoch, entry.clone()))
+        } else {
+            entries
+                .get(&prev_epoch)
+                .map(|entry| (prev_epoch, entry.clone()))
+        }
+    }
+}
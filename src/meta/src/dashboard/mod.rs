@@ -56,6 +56,7 @@ pub(super) mod handlers {
     use risingwave_pb::catalog::table::TableType;
     use risingwave_pb::catalog::{Sink, Source, Table};
     use risingwave_pb::common::WorkerNode;
+    use risingwave_pb::meta::actor_usage_info::ActorUsage;
     use risingwave_pb::meta::{ActorLocation, TableFragments as ProstTableFragments};
     use risingwave_pb::stream_plan::StreamActor;
     use serde_json::json;
@@ -180,6 +181,17 @@ pub(super) mod handlers {
         Ok(Json(actors))
     }
 
+    /// Lists the per-actor resource usage reported by compute nodes, ordered by poll time
+    /// descending so that the dashboard can directly render a "top actors" view.
+    pub async fn list_actor_usages<S: MetaStore>(
+        Extension(srv): Extension<Service<S>>,
+    ) -> Result<Json<Vec<ActorUsage>>> {
+        let mut usages = srv.cluster_manager.list_actor_usages().await;
+        usages.sort_by_key(|usage| std::cmp::Reverse(usage.poll_duration_ns));
+
+        Ok(Json(usages))
+    }
+
     pub async fn list_table_fragments<S: MetaStore>(
         Extension(srv): Extension<Service<S>>,
     ) -> Result<Json<Vec<TableActors>>> {
@@ -225,6 +237,7 @@ where
         let api_router = Router::new()
             .route("/clusters/:ty", get(list_clusters::<S>))
             .route("/actors", get(list_actors::<S>))
+            .route("/actor_usages", get(list_actor_usages::<S>))
             .route("/fragments", get(list_table_fragments::<S>))
             .route("/fragments2", get(list_fragments::<S>))
             .route("/materialized_views", get(list_materialized_views::<S>))
@@ -16,11 +16,12 @@
 // This source code is licensed under both the GPLv2 (found in the
 // COPYING file in the root directory) and Apache 2.0 License
 // (found in the LICENSE.Apache file in the root directory).
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use risingwave_hummock_sdk::HummockCompactionTaskId;
 use risingwave_pb::hummock::hummock_version::Levels;
-use risingwave_pb::hummock::{compact_task, CompactionConfig};
+use risingwave_pb::hummock::{compact_task, CompactionConfig, LevelScore};
 
 use super::picker::{SpaceReclaimCompactionPicker, TtlReclaimCompactionPicker};
 use super::{
@@ -330,6 +331,39 @@ impl DynamicLevelSelectorCore {
         ctx.score_levels.sort_by(|a, b| b.0.cmp(&a.0));
         ctx
     }
+
+    /// Exposes the internal scoring of [`Self::get_priority_levels`] without picking any task:
+    /// one entry per (select, target) level pair considered, sorted by score, plus how many
+    /// bytes the select level holds beyond its target capacity.
+    pub fn get_level_scores(&self, levels: &Levels, handlers: &[LevelHandler]) -> Vec<LevelScore> {
+        let ctx = self.get_priority_levels(levels, handlers);
+        ctx.score_levels
+            .iter()
+            .map(|(score, select_level, target_level)| {
+                let debt_bytes = if *select_level == 0 {
+                    // All data in L0 still has to be merged down the LSM tree.
+                    levels.l0.as_ref().map(|l0| l0.total_file_size).unwrap_or(0)
+                } else {
+                    levels
+                        .levels
+                        .iter()
+                        .find(|level| level.level_idx as usize == *select_level)
+                        .map(|level| {
+                            level
+                                .total_file_size
+                                .saturating_sub(ctx.level_max_bytes[*select_level])
+                        })
+                        .unwrap_or(0)
+                };
+                LevelScore {
+                    select_level: *select_level as u32,
+                    target_level: *target_level as u32,
+                    score: *score,
+                    debt_bytes,
+                }
+            })
+            .collect()
+    }
 }
 
 impl LevelSelector for DynamicLevelSelector {
@@ -617,6 +651,54 @@ impl LevelSelector for TtlCompactionSelector {
     }
 }
 
+/// Creates a [`LevelSelector`] of one task type from its [`SelectorOption`]. Factories are kept
+/// in a registry on `HummockManager`, so custom selection policies can be registered at manager
+/// creation time without patching the built-in dispatch.
+pub type LevelSelectorFactory = Box<dyn Fn(SelectorOption) -> Box<dyn LevelSelector> + Send + Sync>;
+
+/// Returns the factories of the built-in selectors, keyed by the task type they serve.
+pub fn default_selector_factories() -> HashMap<compact_task::TaskType, LevelSelectorFactory> {
+    let mut factories: HashMap<compact_task::TaskType, LevelSelectorFactory> = HashMap::new();
+    factories.insert(
+        compact_task::TaskType::Dynamic,
+        Box::new(|selector_option| {
+            let selector_option = selector_option.as_dynamic().expect("tried to as_dynamic");
+            Box::new(DynamicLevelSelector::new(
+                selector_option.compaction_config.clone(),
+                create_overlap_strategy(selector_option.compaction_config.compaction_mode()),
+            ))
+        }),
+    );
+    factories.insert(
+        compact_task::TaskType::Manual,
+        Box::new(|selector_option| {
+            let selector_option = selector_option.as_manual().expect("tried to as_manual");
+            Box::new(ManualCompactionSelector::new(
+                selector_option.compaction_config.clone(),
+                create_overlap_strategy(selector_option.compaction_config.compaction_mode()),
+                selector_option.option,
+            ))
+        }),
+    );
+    factories.insert(
+        compact_task::TaskType::SpaceReclaim,
+        Box::new(|selector_option| {
+            let selector_option = selector_option
+                .as_space_reclaim()
+                .expect("tried to as_space_reclaim");
+            Box::new(SpaceReclaimCompactionSelector::new(selector_option))
+        }),
+    );
+    factories.insert(
+        compact_task::TaskType::Ttl,
+        Box::new(|selector_option| {
+            let selector_option = selector_option.as_ttl().expect("tried to as_ttl");
+            Box::new(TtlCompactionSelector::new(selector_option.compaction_config))
+        }),
+    );
+    factories
+}
+
 #[cfg(test)]
 pub mod tests {
     use std::ops::Range;
@@ -33,7 +33,8 @@ use risingwave_pb::hummock::hummock_version::Levels;
 use risingwave_pb::hummock::{CompactTask, CompactionConfig, InputLevel, KeyRange, LevelType};
 
 pub use crate::hummock::compaction::level_selector::{
-    selector_option, DynamicLevelSelector, LevelSelector, ManualCompactionSelector, SelectorOption,
+    default_selector_factories, selector_option, DynamicLevelSelector, DynamicLevelSelectorCore,
+    LevelSelector, LevelSelectorFactory, ManualCompactionSelector, SelectorOption,
     SpaceReclaimCompactionSelector, TtlCompactionSelector,
 };
 use crate::hummock::compaction::overlap_strategy::{OverlapStrategy, RangeOverlapStrategy};
@@ -154,6 +155,7 @@ impl CompactStatus {
             current_epoch_time: 0,
             target_sub_level_id: ret.input.target_sub_level_id,
             task_type: ret.compaction_task_type as i32,
+            max_sub_compaction: 0,
         };
         Some(compact_task)
     }
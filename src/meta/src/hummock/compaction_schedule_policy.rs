@@ -12,11 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::sync::Arc;
 
 use risingwave_hummock_sdk::HummockContextId;
-use risingwave_pb::hummock::compact_task::TaskStatus;
+use risingwave_pb::hummock::compact_task::{TaskStatus, TaskType};
 use risingwave_pb::hummock::{CompactTask, CompactTaskAssignment, SubscribeCompactTasksResponse};
 use tokio::sync::mpsc::Receiver;
 
@@ -26,16 +26,73 @@ use crate::MetaResult;
 
 const STREAM_BUFFER_SIZE: usize = 4;
 
+/// A dynamic compaction task whose input contains at least this many L0 sstables is considered an
+/// emergency: leaving it queued behind reclaim tasks would eventually block flushes.
+const EMERGENCY_L0_SST_COUNT: usize = 64;
+
+/// The priority class of a compaction task. Variants are declared from the most to the least
+/// urgent, so the derived `Ord` compares two classes directly.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TaskPriority {
+    /// Compaction triggered manually by the user.
+    Manual,
+    /// Dynamic compaction whose input contains an excessive number of L0 sstables.
+    EmergencyL0,
+    /// Ordinary dynamic compaction.
+    Dynamic,
+    /// TTL and space reclaim compaction.
+    Reclaim,
+}
+
+impl TaskPriority {
+    /// Classifies a picked compaction task.
+    pub fn of(compact_task: &CompactTask) -> Self {
+        match compact_task.task_type() {
+            TaskType::Manual => TaskPriority::Manual,
+            TaskType::Dynamic | TaskType::SharedBuffer => {
+                let l0_sst_count = compact_task
+                    .input_ssts
+                    .iter()
+                    .filter(|level| level.level_idx == 0)
+                    .map(|level| level.table_infos.len())
+                    .sum::<usize>();
+                if l0_sst_count >= EMERGENCY_L0_SST_COUNT {
+                    TaskPriority::EmergencyL0
+                } else {
+                    TaskPriority::Dynamic
+                }
+            }
+            TaskType::SpaceReclaim | TaskType::Ttl | TaskType::TypeUnspecified => {
+                TaskPriority::Reclaim
+            }
+        }
+    }
+
+    /// Classifies a compaction request before its task is picked. A dynamic request is assumed
+    /// to be non-emergency because its input is not known yet.
+    pub fn from_task_type(task_type: TaskType) -> Self {
+        match task_type {
+            TaskType::Manual => TaskPriority::Manual,
+            TaskType::Dynamic | TaskType::SharedBuffer => TaskPriority::Dynamic,
+            TaskType::SpaceReclaim | TaskType::Ttl | TaskType::TypeUnspecified => {
+                TaskPriority::Reclaim
+            }
+        }
+    }
+}
+
 /// The implementation of compaction task scheduling policy.
 pub trait CompactionSchedulePolicy: Send + Sync {
-    /// Get next idle compactor to assign task.
+    /// Get next idle compactor to assign task. Compactors in `excluded` (e.g. blacklisted for
+    /// repeated task failures) are skipped.
     fn next_idle_compactor(
         &self,
         compactor_assigned_task_num: &HashMap<HummockContextId, u64>,
+        excluded: &HashSet<HummockContextId>,
     ) -> Option<Arc<Compactor>>;
 
-    /// Get next compactor to assign task.
-    fn next_compactor(&self) -> Option<Arc<Compactor>>;
+    /// Get next compactor to assign task. Compactors in `excluded` are skipped.
+    fn next_compactor(&self, excluded: &HashSet<HummockContextId>) -> Option<Arc<Compactor>>;
 
     fn add_compactor(
         &mut self,
@@ -77,6 +134,12 @@ pub trait CompactionSchedulePolicy: Send + Sync {
     fn compactor_num(&self) -> usize;
 
     fn max_concurrent_task_num(&self) -> usize;
+
+    /// Returns a busy compactor whose least urgent queued assignment belongs to a strictly lower
+    /// priority class than `priority`, together with the id of that assignment, so that the
+    /// caller can preempt it. Returns `None` if nothing can be preempted.
+    fn next_preemptible_compactor(&self, priority: TaskPriority)
+        -> Option<(Arc<Compactor>, u64)>;
 }
 
 // This strategy is retained just for reference, it is not used.
@@ -107,6 +170,7 @@ impl CompactionSchedulePolicy for RoundRobinPolicy {
     fn next_idle_compactor(
         &self,
         compactor_assigned_task_num: &HashMap<HummockContextId, u64>,
+        excluded: &HashSet<HummockContextId>,
     ) -> Option<Arc<Compactor>> {
         if self.compactors.is_empty() {
             return None;
@@ -116,6 +180,9 @@ impl CompactionSchedulePolicy for RoundRobinPolicy {
             .iter()
             .chain(&self.compactors[..compactor_index])
         {
+            if excluded.contains(context_id) {
+                continue;
+            }
             let compactor = self.compactor_map.get(context_id).unwrap();
             if *compactor_assigned_task_num
                 .get(&compactor.context_id())
@@ -128,13 +195,16 @@ impl CompactionSchedulePolicy for RoundRobinPolicy {
         None
     }
 
-    fn next_compactor(&self) -> Option<Arc<Compactor>> {
+    fn next_compactor(&self, excluded: &HashSet<HummockContextId>) -> Option<Arc<Compactor>> {
         if self.compactors.is_empty() {
             return None;
         }
         let compactor_index = self.next_compactor % self.compactors.len();
-        let compactor = self.compactors[compactor_index];
-        Some(self.compactor_map.get(&compactor).unwrap().clone())
+        self.compactors[compactor_index..]
+            .iter()
+            .chain(&self.compactors[..compactor_index])
+            .find(|context_id| !excluded.contains(context_id))
+            .map(|context_id| self.compactor_map.get(context_id).unwrap().clone())
     }
 
     fn add_compactor(
@@ -189,6 +259,14 @@ impl CompactionSchedulePolicy for RoundRobinPolicy {
             .map(|c| c.max_concurrent_task_number() as usize)
             .sum()
     }
+
+    fn next_preemptible_compactor(
+        &self,
+        _priority: TaskPriority,
+    ) -> Option<(Arc<Compactor>, u64)> {
+        // This policy does not track per-task state, so it never preempts.
+        None
+    }
 }
 
 /// The score must be linear to the input for easy update.
@@ -210,23 +288,35 @@ pub struct ScoredPolicy {
     // That is to say `score_to_compactor` should be a subset of `context_id_to_score`.
     score_to_compactor: BTreeMap<(Score, HummockContextId), Arc<Compactor>>,
     context_id_to_score: HashMap<HummockContextId, Score>,
+
+    /// The priority classes of the queued assignments of each compactor, used to decide which
+    /// assignment to preempt. This is bookkeeping on top of the score, so the fairness counters
+    /// in `context_id_to_score` are not affected by preemption.
+    pending_task_priority: HashMap<HummockContextId, BTreeSet<(TaskPriority, u64)>>,
 }
 
 impl ScoredPolicy {
     /// Initialize policy with already assigned tasks.
     pub fn with_task_assignment(task_assignment: &[CompactTaskAssignment]) -> Self {
         let mut compactor_to_score = HashMap::new();
+        let mut pending_task_priority: HashMap<HummockContextId, BTreeSet<(TaskPriority, u64)>> =
+            HashMap::new();
         task_assignment.iter().for_each(|assignment| {
-            let score_delta =
-                Self::calculate_score_delta(assignment.compact_task.as_ref().unwrap());
+            let compact_task = assignment.compact_task.as_ref().unwrap();
+            let score_delta = Self::calculate_score_delta(compact_task);
             compactor_to_score
                 .entry(assignment.context_id)
                 .and_modify(|old_score| *old_score += score_delta)
                 .or_insert(score_delta);
+            pending_task_priority
+                .entry(assignment.context_id)
+                .or_default()
+                .insert((TaskPriority::of(compact_task), compact_task.task_id));
         });
         Self {
             score_to_compactor: BTreeMap::new(),
             context_id_to_score: compactor_to_score,
+            pending_task_priority,
         }
     }
 
@@ -273,8 +363,12 @@ impl CompactionSchedulePolicy for ScoredPolicy {
     fn next_idle_compactor(
         &self,
         compactor_assigned_task_num: &HashMap<HummockContextId, u64>,
+        excluded: &HashSet<HummockContextId>,
     ) -> Option<Arc<Compactor>> {
         for compactor in self.score_to_compactor.values() {
+            if excluded.contains(&compactor.context_id()) {
+                continue;
+            }
             if *compactor_assigned_task_num
                 .get(&compactor.context_id())
                 .unwrap_or(&0)
@@ -286,12 +380,11 @@ impl CompactionSchedulePolicy for ScoredPolicy {
         None
     }
 
-    fn next_compactor(&self) -> Option<Arc<Compactor>> {
-        if let Some((_, compactor)) = self.score_to_compactor.first_key_value() {
-            Some(compactor.clone())
-        } else {
-            None
-        }
+    fn next_compactor(&self, excluded: &HashSet<HummockContextId>) -> Option<Arc<Compactor>> {
+        self.score_to_compactor
+            .iter()
+            .find(|((_, context_id), _)| !excluded.contains(context_id))
+            .map(|(_, compactor)| compactor.clone())
     }
 
     fn add_compactor(
@@ -320,6 +413,7 @@ impl CompactionSchedulePolicy for ScoredPolicy {
         if let Some(pending_bytes) = self.context_id_to_score.remove(&context_id) {
             self.score_to_compactor.remove(&(pending_bytes, context_id));
         }
+        self.pending_task_priority.remove(&context_id);
     }
 
     fn get_compactor(&self, context_id: HummockContextId) -> Option<Arc<Compactor>> {
@@ -337,6 +431,10 @@ impl CompactionSchedulePolicy for ScoredPolicy {
     ) -> Result<()> {
         if let Some(score) = self.context_id_to_score.get(&context_id) {
             self.update_compactor_score(context_id, *score, compact_task);
+            self.pending_task_priority
+                .entry(context_id)
+                .or_default()
+                .insert((TaskPriority::of(compact_task), compact_task.task_id));
             Ok(())
         } else {
             Err(Error::InvalidContext(context_id))
@@ -348,6 +446,12 @@ impl CompactionSchedulePolicy for ScoredPolicy {
         if let Some(score) = self.context_id_to_score.get(&context_id) {
             self.update_compactor_score(context_id, *score, task);
         }
+        if let Some(pending) = self.pending_task_priority.get_mut(&context_id) {
+            pending.remove(&(TaskPriority::of(task), task.task_id));
+            if pending.is_empty() {
+                self.pending_task_priority.remove(&context_id);
+            }
+        }
     }
 
     fn compactor_num(&self) -> usize {
@@ -360,11 +464,28 @@ impl CompactionSchedulePolicy for ScoredPolicy {
             .map(|c| c.max_concurrent_task_number() as usize)
             .sum()
     }
+
+    fn next_preemptible_compactor(
+        &self,
+        priority: TaskPriority,
+    ) -> Option<(Arc<Compactor>, u64)> {
+        // Prefer the compactor with the largest score, as preempting it restores fairness the
+        // fastest.
+        for ((_, context_id), compactor) in self.score_to_compactor.iter().rev() {
+            if let Some(pending) = self.pending_task_priority.get(context_id)
+                && let Some((lowest_priority, task_id)) = pending.iter().next_back()
+                && *lowest_priority > priority
+            {
+                return Some((compactor.clone(), *task_id));
+            }
+        }
+        None
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::collections::HashMap;
+    use std::collections::{HashMap, HashSet};
 
     use risingwave_common::try_match_expand;
     use risingwave_hummock_sdk::compaction_group::StaticCompactionGroupId;
@@ -376,7 +497,7 @@ mod tests {
 
     use crate::hummock::compaction::compaction_config::CompactionConfigBuilder;
     use crate::hummock::compaction_schedule_policy::{
-        CompactionSchedulePolicy, RoundRobinPolicy, ScoredPolicy,
+        CompactionSchedulePolicy, RoundRobinPolicy, ScoredPolicy, TaskPriority,
     };
     use crate::hummock::test_utils::{
         commit_from_meta_node, generate_test_tables, get_sst_ids,
@@ -439,6 +560,7 @@ mod tests {
             current_epoch_time: 0,
             target_sub_level_id: 0,
             task_type: compact_task::TaskType::Dynamic as i32,
+            max_sub_compaction: 0,
         }
     }
 
@@ -546,7 +668,7 @@ mod tests {
         assert_eq!(policy.compactors.len(), 5);
         let task = dummy_compact_task(0, 1);
         for i in 0..receivers.len() * 3 {
-            let compactor = policy.next_compactor().unwrap();
+            let compactor = policy.next_compactor(&HashSet::new()).unwrap();
             policy
                 .assign_compact_task(compactor.context_id(), &task)
                 .unwrap();
@@ -574,8 +696,8 @@ mod tests {
         assert_eq!(policy.context_id_to_score.len(), existing_tasks.len());
 
         // No compactor available.
-        assert!(policy.next_idle_compactor(&HashMap::new()).is_none());
-        assert!(policy.next_compactor().is_none());
+        assert!(policy.next_idle_compactor(&HashMap::new(), &HashSet::new()).is_none());
+        assert!(policy.next_compactor(&HashSet::new()).is_none());
 
         // Adding existing compactor does not change score.
         policy.add_compactor(0, u64::MAX);
@@ -657,7 +779,7 @@ mod tests {
         let mut policy = ScoredPolicy::for_test();
 
         // No compactor available.
-        assert!(policy.next_compactor().is_none());
+        assert!(policy.next_compactor(&HashSet::new()).is_none());
 
         // Add 3 compactors.
         for context_id in 0..3 {
@@ -670,7 +792,7 @@ mod tests {
         let task4 = dummy_compact_task(3, 1);
 
         // Now the compactors should be (0, 0), (0, 1), (0, 2).
-        let compactor = policy.next_compactor().unwrap();
+        let compactor = policy.next_compactor(&HashSet::new()).unwrap();
         policy
             .assign_compact_task(compactor.context_id(), &task1)
             .unwrap();
@@ -679,7 +801,7 @@ mod tests {
         assert!(policy.score_to_compactor.contains_key(&(5, 0)));
 
         // (0, 1), (0, 2), (5, 0).
-        let compactor = policy.next_compactor().unwrap();
+        let compactor = policy.next_compactor(&HashSet::new()).unwrap();
         policy
             .assign_compact_task(compactor.context_id(), &task2)
             .unwrap();
@@ -688,7 +810,7 @@ mod tests {
         assert!(policy.score_to_compactor.contains_key(&(10, 1)));
 
         // (0, 2), (5, 0), (10, 1).
-        let compactor = policy.next_compactor().unwrap();
+        let compactor = policy.next_compactor(&HashSet::new()).unwrap();
         policy
             .assign_compact_task(compactor.context_id(), &task3)
             .unwrap();
@@ -703,7 +825,7 @@ mod tests {
         assert!(policy.score_to_compactor.contains_key(&(0, 1)));
 
         // (0, 1), (5, 0), (7, 2).
-        let compactor = policy.next_compactor().unwrap();
+        let compactor = policy.next_compactor(&HashSet::new()).unwrap();
         policy
             .assign_compact_task(compactor.context_id(), &task4)
             .unwrap();
@@ -726,17 +848,17 @@ mod tests {
         let task3 = dummy_compact_task(2, 5);
 
         // Fill compactor 0 with small tasks.
-        let compactor = policy.next_compactor().unwrap();
+        let compactor = policy.next_compactor(&HashSet::new()).unwrap();
         policy
             .assign_compact_task(compactor.context_id(), &task1)
             .unwrap();
         assert_eq!(compactor.context_id(), 0);
-        let compactor = policy.next_compactor().unwrap();
+        let compactor = policy.next_compactor(&HashSet::new()).unwrap();
         policy
             .assign_compact_task(compactor.context_id(), &task3)
             .unwrap();
         assert_eq!(compactor.context_id(), 1);
-        let compactor = policy.next_compactor().unwrap();
+        let compactor = policy.next_compactor(&HashSet::new()).unwrap();
         policy
             .assign_compact_task(compactor.context_id(), &task2)
             .unwrap();
@@ -747,8 +869,55 @@ mod tests {
         compactor_assigned_task_num.insert(0, 2);
         compactor_assigned_task_num.insert(1, 1);
         let compactor = policy
-            .next_idle_compactor(&compactor_assigned_task_num)
+            .next_idle_compactor(&compactor_assigned_task_num, &HashSet::new())
             .unwrap();
         assert_eq!(compactor.context_id(), 1);
     }
+
+    #[test]
+    fn test_scored_next_preemptible_compactor() {
+        let mut policy = ScoredPolicy::for_test();
+
+        // Add 2 compactors.
+        for context_id in 0..2 {
+            policy.add_compactor(context_id, u64::MAX);
+        }
+
+        // Nothing is queued yet.
+        assert!(policy
+            .next_preemptible_compactor(TaskPriority::Manual)
+            .is_none());
+
+        let dynamic_task = dummy_compact_task(0, 5);
+        let mut ttl_task = dummy_compact_task(1, 7);
+        ttl_task.task_type = compact_task::TaskType::Ttl as i32;
+        policy.assign_compact_task(0, &dynamic_task).unwrap();
+        policy.assign_compact_task(1, &ttl_task).unwrap();
+
+        // A reclaim task cannot preempt anything.
+        assert!(policy
+            .next_preemptible_compactor(TaskPriority::Reclaim)
+            .is_none());
+
+        // A dynamic task can only preempt the queued ttl task.
+        let (compactor, task_id) = policy
+            .next_preemptible_compactor(TaskPriority::Dynamic)
+            .unwrap();
+        assert_eq!(compactor.context_id(), 1);
+        assert_eq!(task_id, 1);
+
+        // Once the ttl task is reported, only the dynamic task is left, which only manual
+        // compaction can preempt.
+        let mut reported_ttl_task = ttl_task.clone();
+        reported_ttl_task.set_task_status(TaskStatus::Success);
+        policy.report_compact_task(1, &reported_ttl_task);
+        assert!(policy
+            .next_preemptible_compactor(TaskPriority::Dynamic)
+            .is_none());
+        let (compactor, task_id) = policy
+            .next_preemptible_compactor(TaskPriority::Manual)
+            .unwrap();
+        assert_eq!(compactor.context_id(), 0);
+        assert_eq!(task_id, 0);
+    }
 }
@@ -14,21 +14,24 @@
 
 use std::collections::HashSet;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use parking_lot::Mutex;
 use risingwave_hummock_sdk::compact::compact_task_to_string;
+use risingwave_hummock_sdk::compaction_group::hummock_version_ext::HummockVersionExt;
 use risingwave_hummock_sdk::CompactionGroupId;
 use risingwave_pb::hummock::compact_task::{self, TaskStatus};
 use risingwave_pb::hummock::subscribe_compact_tasks_response::Task;
-use risingwave_pb::hummock::CompactTask;
+use risingwave_pb::hummock::{CompactTask, HummockVersion};
 use tokio::sync::mpsc::error::SendError;
 use tokio::sync::mpsc::UnboundedSender;
 use tokio::sync::oneshot::Receiver;
 use tokio::sync::Notify;
 
+use super::compaction_schedule_policy::TaskPriority;
 use super::{CompactionPickParma, Compactor};
 use crate::hummock::error::Error;
+use crate::hummock::metrics_utils::pending_compaction_bytes;
 use crate::hummock::{CompactorManagerRef, HummockManagerRef};
 use crate::manager::{LocalNotification, MetaSrvEnv};
 use crate::storage::MetaStore;
@@ -82,6 +85,87 @@ impl CompactionRequestChannel {
     }
 }
 
+/// How long a pending request may wait before it is scheduled unconditionally, regardless of its
+/// priority score.
+const SCHEDULE_STARVATION_THRESHOLD: Duration = Duration::from_secs(300);
+
+/// A compaction request waiting to be scheduled.
+struct PendingRequest {
+    compaction_group: CompactionGroupId,
+    task_type: compact_task::TaskType,
+    enqueued_at: Instant,
+}
+
+/// Backlog of compaction requests, scheduled by a priority score instead of arrival order.
+///
+/// The score combines the L0 file count and pending bytes of the group with the time the request
+/// has been waiting. The age term grows without bound and requests older than
+/// [`SCHEDULE_STARVATION_THRESHOLD`] are scheduled first unconditionally, so every request
+/// eventually runs even if busier groups keep arriving.
+#[derive(Default)]
+struct PendingRequestQueue {
+    requests: Vec<PendingRequest>,
+}
+
+impl PendingRequestQueue {
+    fn push(&mut self, compaction_group: CompactionGroupId, task_type: compact_task::TaskType) {
+        // `CompactionRequestChannel` already deduplicates requests by compaction group.
+        self.requests.push(PendingRequest {
+            compaction_group,
+            task_type,
+            enqueued_at: Instant::now(),
+        });
+    }
+
+    fn is_empty(&self) -> bool {
+        self.requests.is_empty()
+    }
+
+    /// Pops the request to schedule next, scored against `version`.
+    fn pop_highest(
+        &mut self,
+        version: &HummockVersion,
+    ) -> Option<(CompactionGroupId, compact_task::TaskType)> {
+        if self.requests.is_empty() {
+            return None;
+        }
+        // The oldest starved request wins over any score.
+        let starved = self
+            .requests
+            .iter()
+            .enumerate()
+            .filter(|(_, request)| request.enqueued_at.elapsed() >= SCHEDULE_STARVATION_THRESHOLD)
+            .min_by_key(|(_, request)| request.enqueued_at)
+            .map(|(index, _)| index);
+        let index = starved.unwrap_or_else(|| {
+            self.requests
+                .iter()
+                .enumerate()
+                .max_by_key(|(_, request)| Self::score(request, version))
+                .map(|(index, _)| index)
+                .unwrap()
+        });
+        let request = self.requests.swap_remove(index);
+        Some((request.compaction_group, request.task_type))
+    }
+
+    fn score(request: &PendingRequest, version: &HummockVersion) -> u64 {
+        let mut l0_file_count = 0;
+        version.level_iter(request.compaction_group, |level| {
+            if level.level_idx == 0 {
+                l0_file_count += level.table_infos.len() as u64;
+            }
+            true
+        });
+        let pending_bytes = pending_compaction_bytes(version, request.compaction_group);
+        // One L0 file weighs as much as 32 MB of pending bytes; one second of queueing as much
+        // as one L0 file.
+        l0_file_count * 32
+            + pending_bytes / (1024 * 1024)
+            + request.enqueued_at.elapsed().as_secs() * 32
+    }
+}
+
 /// Schedules compaction task picking and assignment.
 ///
 /// When no idle compactor is available, the scheduling will be paused until
@@ -137,51 +221,68 @@ where
         min_space_reclaim_trigger_interval
             .set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
 
+        let mut pending_queue = PendingRequestQueue::default();
         loop {
-            let (compaction_group, task_type) = tokio::select! {
-                recv = sched_rx.recv() => {
-                    match recv {
-                        Some((compaction_group, task_type)) => (compaction_group, task_type),
-                        None => {
-                            tracing::warn!("Compactor Scheduler: The Hummock manager has dropped the connection,
-                                it means it has either died or started a new session. Exiting.");
-                            return;
+            // Pull in every request that has already arrived, so scheduling considers the whole
+            // backlog rather than arrival order.
+            while let Ok((compaction_group, task_type)) = sched_rx.try_recv() {
+                pending_queue.push(compaction_group, task_type);
+            }
+            if pending_queue.is_empty() {
+                tokio::select! {
+                    recv = sched_rx.recv() => {
+                        match recv {
+                            Some((compaction_group, task_type)) => {
+                                pending_queue.push(compaction_group, task_type);
+                            }
+                            None => {
+                                tracing::warn!("Compactor Scheduler: The Hummock manager has dropped the connection,
+                                    it means it has either died or started a new session. Exiting.");
+                                return;
+                            }
                         }
-                    }
-                },
+                    },
 
-                _ = min_trigger_interval.tick() => {
-                    // Disable periodic trigger for compaction_deterministic_test.
-                    if self.env.opts.compaction_deterministic_test {
-                        continue;
-                    }
-                    // Periodically trigger compaction for all compaction groups.
-                    for cg_id in self.hummock_manager.compaction_group_ids().await {
-                        if let Err(e) = sched_channel.try_sched_compaction(cg_id, compact_task::TaskType::Dynamic) {
-                            tracing::warn!("Failed to schedule base compaction for compaction group {}. {}", cg_id, e);
+                    _ = min_trigger_interval.tick() => {
+                        // Disable periodic trigger for compaction_deterministic_test.
+                        if self.env.opts.compaction_deterministic_test {
+                            continue;
                         }
-                    }
-                    continue;
-                },
+                        // Periodically trigger compaction for all compaction groups.
+                        for cg_id in self.hummock_manager.compaction_group_ids().await {
+                            if let Err(e) = sched_channel.try_sched_compaction(cg_id, compact_task::TaskType::Dynamic) {
+                                tracing::warn!("Failed to schedule base compaction for compaction group {}. {}", cg_id, e);
+                            }
+                        }
+                        continue;
+                    },
 
-                _ = min_space_reclaim_trigger_interval.tick() => {
-                      // Disable periodic trigger for compaction_deterministic_test.
-                      if self.env.opts.compaction_deterministic_test {
+                    _ = min_space_reclaim_trigger_interval.tick() => {
+                          // Disable periodic trigger for compaction_deterministic_test.
+                          if self.env.opts.compaction_deterministic_test {
+                            continue;
+                        }
+                        // Periodically trigger space_reclaim compaction for all compaction groups.
+                        for cg_id in self.hummock_manager.compaction_group_ids().await {
+                            if let Err(e) = sched_channel.try_sched_compaction(cg_id, compact_task::TaskType::SpaceReclaim) {
+                                tracing::warn!("Failed to schedule base compaction for compaction group {}. {}", cg_id, e);
+                            }
+                        }
                         continue;
                     }
-                    // Periodically trigger space_reclaim compaction for all compaction groups.
-                    for cg_id in self.hummock_manager.compaction_group_ids().await {
-                        if let Err(e) = sched_channel.try_sched_compaction(cg_id, compact_task::TaskType::SpaceReclaim) {
-                            tracing::warn!("Failed to schedule base compaction for compaction group {}. {}", cg_id, e);
-                        }
+
+                    // Shutdown compactor scheduler
+                    _ = &mut shutdown_rx => {
+                        break;
                     }
-                    continue;
                 }
+            }
 
-                // Shutdown compactor scheduler
-                _ = &mut shutdown_rx => {
-                    break;
-                }
+            // Schedule the highest-priority pending group first.
+            let current_version = self.hummock_manager.get_current_version().await;
+            let (compaction_group, task_type) = match pending_queue.pop_highest(&current_version) {
+                Some(request) => request,
+                None => continue,
             };
 
             sync_point::sync_point!("BEFORE_SCHEDULE_COMPACTION_TASK");
@@ -191,13 +292,21 @@ where
             let compactor = loop {
                 if let Some(compactor) = self.hummock_manager.get_idle_compactor().await {
                     break compactor;
-                } else {
-                    tracing::debug!("No available compactor, pausing compaction.");
-                    tokio::select! {
-                        _ = self.compaction_resume_notifier.notified() => {},
-                        _ = &mut shutdown_rx => {
-                            return;
-                        }
+                }
+                // A request in a higher priority class may preempt a queued lower-priority
+                // assignment instead of waiting.
+                if let Some(compactor) = self
+                    .hummock_manager
+                    .preempt_assignment(TaskPriority::from_task_type(task_type))
+                    .await
+                {
+                    break compactor;
+                }
+                tracing::debug!("No available compactor, pausing compaction.");
+                tokio::select! {
+                    _ = self.compaction_resume_notifier.notified() => {},
+                    _ = &mut shutdown_rx => {
+                        return;
                     }
                 }
             };
@@ -371,13 +480,19 @@ where
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
     use std::sync::Arc;
+    use std::time::Instant;
 
     use assert_matches::assert_matches;
     use risingwave_hummock_sdk::compaction_group::StaticCompactionGroupId;
+    use risingwave_pb::hummock::compact_task::TaskType;
+    use risingwave_pb::hummock::hummock_version::Levels;
+    use risingwave_pb::hummock::{HummockVersion, Level, LevelType, OverlappingLevel, SstableInfo};
 
     use crate::hummock::compaction_scheduler::{
-        CompactionRequestChannel, CompactionRequestChannelItem, ScheduleStatus,
+        CompactionRequestChannel, CompactionRequestChannelItem, PendingRequestQueue,
+        ScheduleStatus, SCHEDULE_STARVATION_THRESHOLD,
     };
     use crate::hummock::test_utils::{add_ssts, setup_compute_env};
     use crate::hummock::{CompactionPickParma, CompactionScheduler};
@@ -609,4 +724,63 @@ mod tests {
         );
         assert_eq!(hummock_manager.list_all_tasks_ids().await.len(), 1);
     }
+
+    fn version_with_l0(groups: Vec<(u64, u64)>) -> HummockVersion {
+        let levels = groups
+            .into_iter()
+            .map(|(group_id, l0_file_count)| {
+                let sub_levels = (0..l0_file_count)
+                    .map(|sub_level_id| Level {
+                        level_idx: 0,
+                        level_type: LevelType::Nonoverlapping as i32,
+                        table_infos: vec![SstableInfo {
+                            id: sub_level_id,
+                            file_size: 100,
+                            ..Default::default()
+                        }],
+                        total_file_size: 100,
+                        sub_level_id,
+                    })
+                    .collect();
+                (
+                    group_id,
+                    Levels {
+                        l0: Some(OverlappingLevel {
+                            sub_levels,
+                            total_file_size: l0_file_count * 100,
+                        }),
+                        levels: vec![],
+                    },
+                )
+            })
+            .collect::<HashMap<_, _>>();
+        HummockVersion {
+            levels,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_pending_queue_priority() {
+        let version = version_with_l0(vec![(1, 1), (2, 8)]);
+        let mut queue = PendingRequestQueue::default();
+        queue.push(1, TaskType::Dynamic);
+        queue.push(2, TaskType::Dynamic);
+        // The group with more pending L0 work is scheduled first.
+        assert_eq!(queue.pop_highest(&version), Some((2, TaskType::Dynamic)));
+        assert_eq!(queue.pop_highest(&version), Some((1, TaskType::Dynamic)));
+        assert_eq!(queue.pop_highest(&version), None);
+    }
+
+    #[test]
+    fn test_pending_queue_starvation() {
+        let version = version_with_l0(vec![(1, 1), (2, 8)]);
+        let mut queue = PendingRequestQueue::default();
+        queue.push(1, TaskType::Dynamic);
+        queue.push(2, TaskType::Dynamic);
+        // An aged request preempts a higher-scored one.
+        queue.requests[0].enqueued_at = Instant::now() - SCHEDULE_STARVATION_THRESHOLD;
+        assert_eq!(queue.pop_highest(&version), Some((1, TaskType::Dynamic)));
+        assert_eq!(queue.pop_highest(&version), Some((2, TaskType::Dynamic)));
+    }
 }
@@ -12,17 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::ops::Deref;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
-use std::time::SystemTime;
+use std::time::{Instant, SystemTime};
 
 use fail::fail_point;
 use itertools::Itertools;
 use parking_lot::RwLock;
 use risingwave_hummock_sdk::compact::CompactorRuntimeConfig;
 use risingwave_hummock_sdk::{HummockCompactionTaskId, HummockContextId};
+use risingwave_pb::hummock::compact_task::TaskStatus;
 use risingwave_pb::hummock::subscribe_compact_tasks_response::Task;
 use risingwave_pb::hummock::{
     CancelCompactTask, CompactTask, CompactTaskAssignment, CompactTaskProgress,
@@ -30,7 +31,9 @@ use risingwave_pb::hummock::{
 };
 use tokio::sync::mpsc::{Receiver, Sender};
 
-use super::compaction_schedule_policy::{CompactionSchedulePolicy, RoundRobinPolicy, ScoredPolicy};
+use super::compaction_schedule_policy::{
+    CompactionSchedulePolicy, RoundRobinPolicy, ScoredPolicy, TaskPriority,
+};
 use crate::hummock::error::Result;
 use crate::manager::MetaSrvEnv;
 use crate::model::MetadataModel;
@@ -48,6 +51,14 @@ pub struct Compactor {
     max_concurrent_task_number: AtomicU64,
 }
 
+/// Hardware resources reported by a compactor on subscription, used by the meta node to size
+/// compaction tasks so that they fit the smallest members of a heterogeneous fleet.
+#[derive(Clone, Copy, Debug)]
+pub struct CompactorCapability {
+    pub cpu_core_num: u32,
+    pub total_memory_bytes: u64,
+}
+
 struct TaskHeartbeat {
     task: CompactTask,
     num_ssts_sealed: u32,
@@ -107,6 +118,39 @@ impl Compactor {
     }
 }
 
+/// A compactor whose decayed failure score reaches this threshold is blacklisted: no more tasks
+/// are assigned to it until the score decays below the threshold again, or the score is reset
+/// manually via `risectl hummock reset-compactor-blacklist`.
+const COMPACTOR_BLACKLIST_THRESHOLD: f64 = 5.0;
+
+/// Interval over which a compactor's failure score is halved.
+const COMPACTOR_FAILURE_DECAY_INTERVAL_SEC: u64 = 300;
+
+/// Exponentially decayed count of failed compaction tasks of one compactor.
+struct CompactorFailureState {
+    score: f64,
+    last_decay: Instant,
+}
+
+impl CompactorFailureState {
+    fn new() -> Self {
+        Self {
+            score: 0.0,
+            last_decay: Instant::now(),
+        }
+    }
+
+    fn decay(&mut self) {
+        let elapsed = self.last_decay.elapsed().as_secs_f64();
+        self.score *= 0.5f64.powf(elapsed / COMPACTOR_FAILURE_DECAY_INTERVAL_SEC as f64);
+        self.last_decay = Instant::now();
+    }
+
+    fn is_blacklisted(&self) -> bool {
+        self.score >= COMPACTOR_BLACKLIST_THRESHOLD
+    }
+}
+
 /// `CompactorManager` maintains compactors which can process compact task.
 /// A compact task is tracked in `HummockManager::Compaction` via both `CompactStatus` and
 /// `CompactTaskAssignment`.
@@ -131,6 +175,13 @@ pub struct CompactorManager {
     // A map: { context_id -> { task_id -> heartbeat } }
     task_heartbeats:
         RwLock<HashMap<HummockContextId, HashMap<HummockCompactionTaskId, TaskHeartbeat>>>,
+    // Capabilities reported by subscribed compactors. Compactors that don't report (e.g. older
+    // versions) are absent and don't constrain task sizing.
+    capabilities: RwLock<HashMap<HummockContextId, CompactorCapability>>,
+
+    // Decayed failure scores of compactors, by context id. The state intentionally survives
+    // re-subscription, so that a broken node does not escape the blacklist by reconnecting.
+    failure_states: RwLock<HashMap<HummockContextId, CompactorFailureState>>,
 }
 
 impl CompactorManager {
@@ -146,6 +197,8 @@ impl CompactorManager {
             ))),
             task_expiry_seconds,
             task_heartbeats: Default::default(),
+            capabilities: Default::default(),
+            failure_states: Default::default(),
         };
         // Initialize heartbeat for existing tasks.
         task_assignment.into_iter().for_each(|assignment| {
@@ -161,6 +214,8 @@ impl CompactorManager {
             policy: RwLock::new(Box::new(RoundRobinPolicy::new())),
             task_expiry_seconds: 1,
             task_heartbeats: Default::default(),
+            capabilities: Default::default(),
+            failure_states: Default::default(),
         }
     }
 
@@ -170,22 +225,36 @@ impl CompactorManager {
             policy: RwLock::new(policy),
             task_expiry_seconds: 1,
             task_heartbeats: Default::default(),
+            capabilities: Default::default(),
+            failure_states: Default::default(),
         }
     }
 
-    /// Gets next idle compactor to assign task.
+    /// Gets next idle compactor to assign task, skipping blacklisted compactors.
     pub fn next_idle_compactor(
         &self,
         compactor_assigned_task_num: &HashMap<HummockContextId, u64>,
     ) -> Option<Arc<Compactor>> {
+        let blacklisted = self.blacklisted_compactors();
         let policy = self.policy.read();
-        policy.next_idle_compactor(compactor_assigned_task_num)
+        policy.next_idle_compactor(compactor_assigned_task_num, &blacklisted)
     }
 
-    /// Gets next compactor to assign task.
+    /// Gets next compactor to assign task, skipping blacklisted compactors.
     pub fn next_compactor(&self) -> Option<Arc<Compactor>> {
+        let blacklisted = self.blacklisted_compactors();
+        let policy = self.policy.read();
+        policy.next_compactor(&blacklisted)
+    }
+
+    /// Gets a busy compactor whose least urgent queued assignment can be preempted by a task of
+    /// `priority`, together with the id of that assignment.
+    pub fn next_preemptible_compactor(
+        &self,
+        priority: TaskPriority,
+    ) -> Option<(Arc<Compactor>, u64)> {
         let policy = self.policy.read();
-        policy.next_compactor()
+        policy.next_preemptible_compactor(priority)
     }
 
     /// Retrieve a receiver of tasks for the compactor identified by `context_id`. The sender should
@@ -215,12 +284,33 @@ impl CompactorManager {
     pub fn remove_compactor(&self, context_id: HummockContextId) {
         let mut policy = self.policy.write();
         policy.remove_compactor(context_id);
+        self.capabilities.write().remove(&context_id);
 
         // To remove the heartbeats, they need to be forcefully purged,
         // which is only safe when the context has been completely removed from meta.
         tracing::info!("Removed compactor session {}", context_id);
     }
 
+    pub fn set_compactor_capability(
+        &self,
+        context_id: HummockContextId,
+        capability: CompactorCapability,
+    ) {
+        self.capabilities.write().insert(context_id, capability);
+    }
+
+    /// Returns the smallest reported core count and memory among subscribed compactors, or `None`
+    /// if no compactor has reported its capability.
+    pub fn min_fleet_capability(&self) -> Option<CompactorCapability> {
+        let capabilities = self.capabilities.read();
+        let cpu_core_num = capabilities.values().map(|c| c.cpu_core_num).min()?;
+        let total_memory_bytes = capabilities.values().map(|c| c.total_memory_bytes).min()?;
+        Some(CompactorCapability {
+            cpu_core_num,
+            total_memory_bytes,
+        })
+    }
+
     pub fn set_compactor_config(
         &self,
         context_id: HummockContextId,
@@ -247,11 +337,73 @@ impl CompactorManager {
 
     // Report the completion of a compaction task to adjust the compaction schedule policy.
     pub fn report_compact_task(&self, context_id: HummockContextId, compact_task: &CompactTask) {
+        match compact_task.task_status() {
+            TaskStatus::Success => self.record_task_success(context_id),
+            // These states indicate that the compactor node itself is broken: it failed to
+            // execute the task or stopped responding while doing so. Cancellations initiated by
+            // meta are not the compactor's fault and do not count.
+            TaskStatus::ExecuteFailed
+            | TaskStatus::JoinHandleFailed
+            | TaskStatus::TrackSstIdFailed
+            | TaskStatus::SendFailCanceled
+            | TaskStatus::HeartbeatCanceled => self.record_task_failure(context_id),
+            _ => {}
+        }
         self.policy
             .write()
             .report_compact_task(context_id, compact_task)
     }
 
+    fn record_task_failure(&self, context_id: HummockContextId) {
+        let mut guard = self.failure_states.write();
+        let state = guard
+            .entry(context_id)
+            .or_insert_with(CompactorFailureState::new);
+        state.decay();
+        let was_blacklisted = state.is_blacklisted();
+        state.score += 1.0;
+        if !was_blacklisted && state.is_blacklisted() {
+            tracing::warn!(
+                "Compactor {} is blacklisted after repeated task failures. It will be retried \
+                 after the failure score decays, or reset it manually via risectl.",
+                context_id
+            );
+        }
+    }
+
+    fn record_task_success(&self, context_id: HummockContextId) {
+        if let Some(state) = self.failure_states.write().get_mut(&context_id) {
+            state.decay();
+            state.score /= 2.0;
+        }
+    }
+
+    /// Returns the compactors that are currently blacklisted from task assignment, dropping the
+    /// states that have fully decayed along the way.
+    fn blacklisted_compactors(&self) -> HashSet<HummockContextId> {
+        let mut guard = self.failure_states.write();
+        guard.retain(|_, state| {
+            state.decay();
+            state.score >= 0.5
+        });
+        guard
+            .iter()
+            .filter(|(_, state)| state.is_blacklisted())
+            .map(|(context_id, _)| *context_id)
+            .collect()
+    }
+
+    pub fn is_blacklisted(&self, context_id: HummockContextId) -> bool {
+        self.blacklisted_compactors().contains(&context_id)
+    }
+
+    /// Forgets the failure history of a compactor, re-enabling task assignment to it immediately.
+    pub fn reset_compactor_blacklist(&self, context_id: HummockContextId) {
+        if self.failure_states.write().remove(&context_id).is_some() {
+            tracing::info!("Reset failure state of compactor {}", context_id);
+        }
+    }
+
     /// Forcefully purging the heartbeats for a task is only safe when the
     /// context has been completely removed from meta.
     /// Returns true if there were remaining heartbeats for the task.
@@ -394,10 +546,11 @@ mod tests {
     use std::time::Duration;
 
     use risingwave_hummock_sdk::compaction_group::StaticCompactionGroupId;
-    use risingwave_pb::hummock::CompactTaskProgress;
+    use risingwave_pb::hummock::compact_task::TaskStatus;
+    use risingwave_pb::hummock::{CompactTask, CompactTaskProgress};
 
     use crate::hummock::test_utils::{add_ssts, setup_compute_env};
-    use crate::hummock::{CompactionPickParma, CompactorManager};
+    use crate::hummock::{CompactionPickParma, CompactorCapability, CompactorManager};
 
     #[tokio::test]
     async fn test_compactor_manager() {
@@ -504,4 +657,70 @@ mod tests {
         assert_eq!(compactor_manager.compactor_num(), 0);
         assert!(compactor_manager.get_compactor(context_id).is_none());
     }
+
+    #[tokio::test]
+    async fn test_compactor_blacklist() {
+        let compactor_manager = CompactorManager::for_test();
+        compactor_manager.add_compactor(1, 1);
+        compactor_manager.add_compactor(2, 1);
+
+        let failed_task = CompactTask {
+            task_status: TaskStatus::ExecuteFailed as i32,
+            ..Default::default()
+        };
+        for _ in 0..5 {
+            compactor_manager.report_compact_task(1, &failed_task);
+        }
+        assert!(compactor_manager.is_blacklisted(1));
+        assert!(!compactor_manager.is_blacklisted(2));
+        // Tasks are no longer assigned to the blacklisted compactor.
+        for _ in 0..10 {
+            assert_ne!(compactor_manager.next_compactor().unwrap().context_id(), 1);
+        }
+
+        // A successful task halves the failure score.
+        let success_task = CompactTask {
+            task_status: TaskStatus::Success as i32,
+            ..Default::default()
+        };
+        compactor_manager.report_compact_task(1, &success_task);
+        assert!(!compactor_manager.is_blacklisted(1));
+
+        // Manual reset forgets the failure history completely.
+        for _ in 0..5 {
+            compactor_manager.report_compact_task(1, &failed_task);
+        }
+        assert!(compactor_manager.is_blacklisted(1));
+        compactor_manager.reset_compactor_blacklist(1);
+        assert!(!compactor_manager.is_blacklisted(1));
+    }
+
+    #[tokio::test]
+    async fn test_min_fleet_capability() {
+        let compactor_manager = CompactorManager::for_test();
+        assert!(compactor_manager.min_fleet_capability().is_none());
+        compactor_manager.add_compactor(1, 1);
+        compactor_manager.set_compactor_capability(
+            1,
+            CompactorCapability {
+                cpu_core_num: 8,
+                total_memory_bytes: 16 << 30,
+            },
+        );
+        compactor_manager.add_compactor(2, 1);
+        compactor_manager.set_compactor_capability(
+            2,
+            CompactorCapability {
+                cpu_core_num: 2,
+                total_memory_bytes: 32 << 30,
+            },
+        );
+        let min = compactor_manager.min_fleet_capability().unwrap();
+        assert_eq!(min.cpu_core_num, 2);
+        assert_eq!(min.total_memory_bytes, 16 << 30);
+        compactor_manager.remove_compactor(2);
+        let min = compactor_manager.min_fleet_capability().unwrap();
+        assert_eq!(min.cpu_core_num, 8);
+        assert_eq!(min.total_memory_bytes, 16 << 30);
+    }
 }
@@ -13,13 +13,17 @@
 // limitations under the License.
 
 use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
 
 use function_name::named;
 use itertools::Itertools;
 use risingwave_hummock_sdk::{CompactionGroupId, HummockCompactionTaskId, HummockContextId};
-use risingwave_pb::hummock::{compact_task, CompactTaskAssignment, CompactionConfig};
+use risingwave_pb::hummock::{
+    compact_task, CompactTaskAssignment, CompactTaskSummary, CompactionConfig,
+    CompactionGroupScore,
+};
 
-use crate::hummock::compaction::{CompactStatus, LevelSelector};
+use crate::hummock::compaction::{CompactStatus, DynamicLevelSelectorCore, LevelSelector};
 use crate::hummock::manager::read_lock;
 use crate::hummock::HummockManager;
 use crate::model::BTreeMapTransaction;
@@ -102,6 +106,63 @@ where
             .len() as u64
     }
 
+    /// Counts the compaction tasks of the given group that are assigned to compactors but not
+    /// yet finished.
+    #[named]
+    pub async fn get_assigned_compact_task_num_by_group(
+        &self,
+        compaction_group_id: CompactionGroupId,
+    ) -> u64 {
+        read_lock!(self, compaction)
+            .await
+            .compact_task_assignment
+            .values()
+            .filter(|s| {
+                s.compact_task
+                    .as_ref()
+                    .expect("compact_task shouldn't be None")
+                    .compaction_group_id
+                    == compaction_group_id
+            })
+            .count() as u64
+    }
+
+    /// Lists summaries of all compaction tasks that are assigned to compactors but not yet
+    /// finished, for the `rw_hummock_compaction_tasks` system table.
+    #[named]
+    pub async fn list_compact_task_summaries(&self) -> Vec<CompactTaskSummary> {
+        read_lock!(self, compaction)
+            .await
+            .compact_task_assignment
+            .values()
+            .map(|assignment| {
+                let task = assignment
+                    .compact_task
+                    .as_ref()
+                    .expect("compact_task shouldn't be None");
+                CompactTaskSummary {
+                    task_id: task.task_id,
+                    compaction_group_id: task.compaction_group_id,
+                    target_level: task.target_level,
+                    input_file_count: task
+                        .input_ssts
+                        .iter()
+                        .map(|level| level.table_infos.len())
+                        .sum::<usize>() as u32,
+                    input_file_size: task
+                        .input_ssts
+                        .iter()
+                        .flat_map(|level| &level.table_infos)
+                        .map(|sst| sst.file_size)
+                        .sum(),
+                    status: task.task_status,
+                    assignee_context_id: assignment.context_id,
+                    task_type: task.task_type,
+                }
+            })
+            .collect_vec()
+    }
+
     #[named]
     pub async fn get_assigned_tasks_number(&self, context_id: HummockContextId) -> u64 {
         read_lock!(self, compaction)
@@ -122,6 +183,34 @@ where
             .compaction_config()
     }
 
+    /// Returns the dynamic level selector's per-level compaction scores and size debt of each
+    /// compaction group, so operators and autoscalers can see how far behind compaction is.
+    #[named]
+    pub async fn get_compaction_scores(&self) -> Vec<CompactionGroupScore> {
+        let compaction = read_lock!(self, compaction).await;
+        let current_version = read_lock!(self, versioning).await.current_version.clone();
+        let mut group_scores = vec![];
+        for (compaction_group_id, levels) in &current_version.levels {
+            let compact_status = match compaction.compaction_statuses.get(compaction_group_id) {
+                Some(compact_status) => compact_status,
+                None => continue,
+            };
+            let group = match self.compaction_group(*compaction_group_id).await {
+                Some(group) => group,
+                // The compaction group has been deleted.
+                None => continue,
+            };
+            let dynamic_level_core =
+                DynamicLevelSelectorCore::new(Arc::new(group.compaction_config()));
+            group_scores.push(CompactionGroupScore {
+                compaction_group_id: *compaction_group_id,
+                level_scores: dynamic_level_core
+                    .get_level_scores(levels, &compact_status.level_handlers),
+            });
+        }
+        group_scores
+    }
+
     #[named]
     pub async fn list_all_tasks_ids(&self) -> Vec<HummockCompactionTaskId> {
         let compaction = read_lock!(self, compaction).await;
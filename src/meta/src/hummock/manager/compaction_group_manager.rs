@@ -24,8 +24,9 @@ use risingwave_pb::hummock::rise_ctl_update_compaction_config_request::mutable_c
 use risingwave_pb::hummock::CompactionConfig;
 use tokio::sync::{OnceCell, RwLock};
 
+use super::compaction::Compaction;
 use super::versioning::Versioning;
-use super::write_lock;
+use super::{read_lock, write_lock};
 use crate::hummock::compaction::compaction_config::CompactionConfigBuilder;
 use crate::hummock::compaction_group::CompactionGroup;
 use crate::hummock::error::{Error, Result};
@@ -268,6 +269,56 @@ impl<S: MetaStore> HummockManager<S> {
         compaction_group_manager.all_table_ids()
     }
 
+    /// Splits `table_ids` out of `group_id` into a new compaction group. SSTs containing data
+    /// of the moved tables are branched into the new group. Returns the new group id.
+    #[named]
+    pub async fn split_compaction_group(
+        &self,
+        group_id: CompactionGroupId,
+        table_ids: &[StateTableId],
+    ) -> Result<CompactionGroupId> {
+        let compaction_guard = read_lock!(self, compaction).await;
+        let mut versioning_guard = write_lock!(self, versioning).await;
+        let versioning = versioning_guard.deref_mut();
+        self.compaction_group_manager
+            .write()
+            .await
+            .split_group(
+                self,
+                Some(&*compaction_guard),
+                versioning,
+                group_id,
+                table_ids,
+                self.env.meta_store(),
+            )
+            .await
+    }
+
+    /// Merges all member tables and SSTs of `right_group_id` into `left_group_id` and removes
+    /// the right group. The inverse of [`HummockManager::split_compaction_group`].
+    #[named]
+    pub async fn merge_compaction_group(
+        &self,
+        left_group_id: CompactionGroupId,
+        right_group_id: CompactionGroupId,
+    ) -> Result<()> {
+        let mut compaction_guard = write_lock!(self, compaction).await;
+        let mut versioning_guard = write_lock!(self, versioning).await;
+        let versioning = versioning_guard.deref_mut();
+        self.compaction_group_manager
+            .write()
+            .await
+            .merge_group(
+                self,
+                compaction_guard.deref_mut(),
+                versioning,
+                left_group_id,
+                right_group_id,
+                self.env.meta_store(),
+            )
+            .await
+    }
+
     pub async fn update_compaction_config(
         &self,
         compaction_group_ids: &[CompactionGroupId],
@@ -281,7 +332,10 @@ impl<S: MetaStore> HummockManager<S> {
                 config_to_update,
                 self.env.meta_store(),
             )
-            .await
+            .await?;
+        // Propagate the new configs to in-memory compaction state, so they take effect without
+        // recreating the groups or restarting meta.
+        self.sync_group_configs(compaction_group_ids).await
     }
 }
 
@@ -600,6 +654,140 @@ impl<S: MetaStore> CompactionGroupManagerInner<S> {
         Ok(())
     }
 
+    async fn split_group(
+        &mut self,
+        hummock_manager: &HummockManager<S>,
+        compaction: Option<&Compaction>,
+        versioning: &mut Versioning,
+        group_id: CompactionGroupId,
+        table_ids: &[StateTableId],
+        meta_store: &S,
+    ) -> Result<CompactionGroupId> {
+        let mut compaction_group_id_set = self.compaction_groups.keys().cloned().collect_vec();
+        let parent_group = self
+            .compaction_groups
+            .get(&group_id)
+            .cloned()
+            .ok_or(Error::InvalidCompactionGroup(group_id))?;
+        for table_id in table_ids {
+            if !parent_group.member_table_ids.contains(table_id) {
+                return Err(Error::InvalidCompactionGroupMember(*table_id));
+            }
+        }
+        let new_group_id = self
+            .id_generator_ref
+            .generate::<{ IdCategory::CompactionGroup }>()
+            .await?;
+        compaction_group_id_set.push(new_group_id);
+        let mut compaction_groups = BTreeMapTransaction::new(&mut self.compaction_groups);
+        let mut new_group =
+            CompactionGroup::new(new_group_id, parent_group.compaction_config.clone());
+        new_group.parent_group_id = group_id;
+        {
+            let mut parent = compaction_groups.get_mut(group_id).unwrap();
+            for table_id in table_ids {
+                parent.member_table_ids.remove(table_id);
+                new_group.member_table_ids.insert(*table_id);
+                if let Some(table_option) = parent.table_id_to_options.remove(table_id) {
+                    new_group.table_id_to_options.insert(*table_id, table_option);
+                }
+            }
+        }
+        compaction_groups.insert(new_group_id, new_group);
+        let mut trx = Transaction::default();
+        compaction_groups.apply_to_txn(&mut trx)?;
+        let mut trx_wrapper = Some(trx);
+        hummock_manager
+            .sync_group(
+                compaction,
+                versioning,
+                &Self::gen_compaction_group_snapshot(&compaction_groups, compaction_group_id_set),
+                &mut trx_wrapper,
+            )
+            .await?;
+        if let Some(trx) = trx_wrapper.take() {
+            meta_store.txn(trx).await?;
+        }
+        compaction_groups.commit();
+
+        // Update in-memory index
+        for table_id in table_ids {
+            self.index.insert(*table_id, new_group_id);
+        }
+        tracing::info!(
+            "Split table ids {:?} from compaction group {} into new group {}",
+            table_ids,
+            group_id,
+            new_group_id
+        );
+        Ok(new_group_id)
+    }
+
+    async fn merge_group(
+        &mut self,
+        hummock_manager: &HummockManager<S>,
+        compaction: &mut Compaction,
+        versioning: &mut Versioning,
+        left_group_id: CompactionGroupId,
+        right_group_id: CompactionGroupId,
+        meta_store: &S,
+    ) -> Result<()> {
+        // Static groups may be merged into but never merged away.
+        if left_group_id == right_group_id
+            || right_group_id <= StaticCompactionGroupId::End as CompactionGroupId
+        {
+            return Err(Error::InvalidCompactionGroup(right_group_id));
+        }
+        let right_group = self
+            .compaction_groups
+            .get(&right_group_id)
+            .cloned()
+            .ok_or(Error::InvalidCompactionGroup(right_group_id))?;
+        if !self.compaction_groups.contains_key(&left_group_id) {
+            return Err(Error::InvalidCompactionGroup(left_group_id));
+        }
+        let mut compaction_groups = BTreeMapTransaction::new(&mut self.compaction_groups);
+        {
+            let mut left_group = compaction_groups.get_mut(left_group_id).unwrap();
+            for table_id in &right_group.member_table_ids {
+                left_group.member_table_ids.insert(*table_id);
+            }
+            for (table_id, table_option) in &right_group.table_id_to_options {
+                left_group
+                    .table_id_to_options
+                    .insert(*table_id, *table_option);
+            }
+        }
+        compaction_groups.remove(right_group_id);
+        let mut trx = Transaction::default();
+        compaction_groups.apply_to_txn(&mut trx)?;
+        let mut trx_wrapper = Some(trx);
+        hummock_manager
+            .merge_group_in_version(
+                compaction,
+                versioning,
+                left_group_id,
+                right_group_id,
+                &mut trx_wrapper,
+            )
+            .await?;
+        if let Some(trx) = trx_wrapper.take() {
+            meta_store.txn(trx).await?;
+        }
+        compaction_groups.commit();
+
+        // Update in-memory index
+        for table_id in right_group.member_table_ids() {
+            self.index.insert(*table_id, left_group_id);
+        }
+        tracing::info!(
+            "Merged compaction group {} into group {}",
+            right_group_id,
+            left_group_id
+        );
+        Ok(())
+    }
+
     async fn purge_stale_groups(
         &mut self,
         hummock_manager: &HummockManager<S>,
@@ -721,6 +909,22 @@ fn update_compaction_config(target: &mut CompactionConfig, items: &[MutableConfi
             MutableConfig::MaxSubCompaction(c) => {
                 target.max_sub_compaction = *c;
             }
+            MutableConfig::MaxLevel(c) => {
+                target.max_level = *c;
+                // Keep one compression algorithm entry per level.
+                let algorithm = target
+                    .compression_algorithm
+                    .last()
+                    .cloned()
+                    .unwrap_or_else(|| "None".to_string());
+                target
+                    .compression_algorithm
+                    .resize(*c as usize + 1, algorithm);
+            }
+            MutableConfig::CompressionAlgorithm(c) => {
+                let levels = target.compression_algorithm.len();
+                target.compression_algorithm = vec![c.clone(); levels];
+            }
         }
     }
 }
@@ -18,18 +18,20 @@ use std::ops::DerefMut;
 use fail::fail_point;
 use function_name::named;
 use itertools::Itertools;
+use risingwave_common::util::epoch::INVALID_EPOCH;
 use risingwave_hummock_sdk::{
     ExtendedSstableInfo, HummockContextId, HummockEpoch, HummockSstableId,
 };
 use risingwave_pb::hummock::subscribe_compact_tasks_response::Task;
 use risingwave_pb::hummock::{HummockVersion, ValidationTask};
 
+use crate::barrier::BarrierDomainId;
 use crate::hummock::error::{Error, Result};
 use crate::hummock::manager::{
     commit_multi_var, read_lock, start_measure_real_process_timer, write_lock,
 };
 use crate::hummock::HummockManager;
-use crate::manager::META_NODE_ID;
+use crate::manager::{IdCategory, META_NODE_ID};
 use crate::model::{BTreeMapTransaction, ValTransaction};
 use crate::storage::{MetaStore, Transaction};
 
@@ -88,12 +90,45 @@ where
         Ok(())
     }
 
-    /// Checks whether `context_id` is valid.
+    /// Checks whether `context_id` is valid, i.e. is either a live worker node or an open
+    /// external compaction session.
     pub async fn check_context(&self, context_id: HummockContextId) -> bool {
         self.cluster_manager
             .get_worker_by_id(context_id)
             .await
             .is_some()
+            || self.is_external_compaction_context(context_id)
+    }
+
+    /// Leases a context id for an external compaction session. External compactors are not
+    /// worker nodes; the id is drawn from the same sequence as worker ids so that it can
+    /// never collide with one.
+    pub async fn register_external_compaction_context(&self) -> Result<HummockContextId> {
+        let context_id = self
+            .env
+            .id_gen_manager()
+            .generate::<{ IdCategory::Worker }>()
+            .await? as HummockContextId;
+        self.external_compaction_contexts.write().insert(context_id);
+        tracing::info!("Opened external compaction session {}", context_id);
+        Ok(context_id)
+    }
+
+    pub fn is_external_compaction_context(&self, context_id: HummockContextId) -> bool {
+        self.external_compaction_contexts.read().contains(&context_id)
+    }
+
+    /// Closes an external compaction session and releases everything its context id pins,
+    /// including unfinished compaction tasks.
+    pub async fn unregister_external_compaction_context(
+        &self,
+        context_id: HummockContextId,
+    ) -> Result<()> {
+        if self.external_compaction_contexts.write().remove(&context_id) {
+            self.release_contexts([context_id]).await?;
+            tracing::info!("Closed external compaction session {}", context_id);
+        }
+        Ok(())
     }
 
     /// Release invalid contexts, aka worker node ids which are no longer valid in `ClusterManager`.
@@ -133,6 +168,7 @@ where
         sstables: &Vec<ExtendedSstableInfo>,
         sst_to_context: &HashMap<HummockSstableId, HummockContextId>,
         current_version: &HummockVersion,
+        barrier_domain: Option<BarrierDomainId>,
     ) -> Result<()> {
         for (sst_id, context_id) in sst_to_context {
             #[cfg(test)]
@@ -146,11 +182,23 @@ where
             }
         }
 
-        if epoch <= current_version.max_committed_epoch {
+        // Epochs are committed in ascending order within a barrier domain, while commits of
+        // different domains may interleave. A domain-spanning commit is ordered against the
+        // commits of every domain, i.e. against `max_committed_epoch`.
+        let committed_epoch = match barrier_domain {
+            Some(barrier_domain) => current_version
+                .domain_committed_epochs
+                .get(&barrier_domain)
+                .copied()
+                .unwrap_or(INVALID_EPOCH),
+            None => current_version.max_committed_epoch,
+        };
+        if epoch <= committed_epoch {
             return Err(anyhow::anyhow!(
-                "Epoch {} <= max_committed_epoch {}",
+                "Epoch {} <= committed epoch {} of barrier domain {:?}",
                 epoch,
-                current_version.max_committed_epoch
+                committed_epoch,
+                barrier_domain
             )
             .into());
         }
@@ -19,48 +19,57 @@ use std::collections::{BTreeMap, HashMap, HashSet};
 use std::ops::Bound::{Excluded, Included};
 use std::ops::DerefMut;
 use std::sync::{Arc, LazyLock};
-use std::time::Instant;
+use std::time::{Duration, Instant, SystemTime};
 
 use arc_swap::ArcSwap;
 use fail::fail_point;
 use function_name::named;
 use itertools::Itertools;
+use prost::Message;
 use risingwave_common::monitor::rwlock::MonitoredRwLock;
 use risingwave_common::util::epoch::{Epoch, INVALID_EPOCH};
 use risingwave_hummock_sdk::compact::compact_task_to_string;
 use risingwave_hummock_sdk::compaction_group::hummock_version_ext::{
     add_new_sub_level, HummockLevelsExt, HummockVersionExt, HummockVersionUpdateExt,
 };
+use risingwave_hummock_sdk::key::{key_with_epoch, next_key};
 use risingwave_hummock_sdk::{
     CompactionGroupId, ExtendedSstableInfo, HummockCompactionTaskId, HummockContextId,
-    HummockEpoch, HummockSstableId, HummockVersionId, SstIdRange, FIRST_VERSION_ID,
-    INVALID_VERSION_ID,
+    HummockEpoch, HummockIteratorLeaseId, HummockSstableId, HummockVersionId, SstIdRange,
+    FIRST_VERSION_ID, INVALID_VERSION_ID,
 };
+use risingwave_object_store::object::ObjectStoreRef;
 use risingwave_pb::hummock::compact_task::{self, TaskStatus};
 use risingwave_pb::hummock::group_delta::DeltaType;
 use risingwave_pb::hummock::hummock_version::Levels;
 use risingwave_pb::hummock::subscribe_compact_tasks_response::Task;
 use risingwave_pb::hummock::{
-    version_update_payload, CompactTask, CompactTaskAssignment, CompactionConfig, GroupConstruct,
-    GroupDelta, GroupDestroy, HummockPinnedSnapshot, HummockPinnedVersion, HummockSnapshot,
+    version_update_payload, CompactTask, CompactTaskAssignment, CompactionConfig,
+    CompactionGroupScalingStats, GroupConstruct, GroupDelta, GroupDestroy, GroupMerge,
+    EpochCommitTime, HummockPinnedSnapshot, HummockPinnedVersion, HummockSnapshot,
     HummockVersion, HummockVersionDelta, HummockVersionDeltas, HummockVersionStats,
-    IntraLevelDelta, LevelType,
+    IntraLevelDelta, KeyRange, LevelSummary, LevelType, LockContention, SstableInfo, VersionDiff,
 };
 use risingwave_pb::meta::subscribe_response::{Info, Operation};
 use tokio::sync::oneshot::Sender;
 use tokio::sync::{Notify, RwLockReadGuard, RwLockWriteGuard};
 use tokio::task::JoinHandle;
 
+use crate::barrier::BarrierDomainId;
 use crate::hummock::compaction::{
-    create_overlap_strategy, selector_option, CompactStatus, DynamicLevelSelector, LevelSelector,
-    LocalSelectorStatistic, ManualCompactionOption, SelectorOption,
+    default_selector_factories, selector_option, CompactStatus, DynamicLevelSelectorCore,
+    LevelSelector, LevelSelectorFactory, LocalSelectorStatistic, ManualCompactionOption,
+    SelectorOption,
 };
 use crate::hummock::compaction_group::CompactionGroup;
+use crate::hummock::compaction_schedule_policy::TaskPriority;
 use crate::hummock::compaction_scheduler::CompactionRequestChannelRef;
 use crate::hummock::error::{Error, Result};
+use crate::hummock::level_handler::LevelHandler;
 use crate::hummock::metrics_utils::{
-    remove_compaction_group_in_sst_stat, trigger_pin_unpin_snapshot_state,
-    trigger_pin_unpin_version_state, trigger_sst_stat, trigger_version_stat,
+    pending_compaction_bytes, remove_compaction_group_in_sst_stat, trigger_level_score_stat,
+    trigger_pin_unpin_snapshot_state, trigger_pin_unpin_version_state, trigger_sst_stat,
+    trigger_version_stat,
 };
 use crate::hummock::CompactorManagerRef;
 use crate::manager::{ClusterManagerRef, IdCategory, LocalNotification, MetaSrvEnv, META_NODE_ID};
@@ -68,7 +77,7 @@ use crate::model::{
     BTreeMapEntryTransaction, BTreeMapTransaction, MetadataModel, ValTransaction, VarTransaction,
 };
 use crate::rpc::metrics::MetaMetrics;
-use crate::storage::{MetaStore, Transaction};
+use crate::storage::{MetaStore, MetaStoreError, Transaction, DEFAULT_COLUMN_FAMILY};
 
 mod compaction_group_manager;
 mod context;
@@ -112,12 +121,86 @@ pub struct HummockManager<S: MetaStore> {
     compaction_resume_notifier: parking_lot::RwLock<Option<Arc<Notify>>>,
     compaction_tasks_to_cancel: parking_lot::Mutex<Vec<HummockCompactionTaskId>>,
 
+    // Cumulative per-group ingest bytes, plus the snapshot taken at the previous scaling-hint
+    // poll. Used to derive per-group ingest rates for external compactor autoscalers.
+    group_ingest_stats: parking_lot::Mutex<GroupIngestStats>,
+
+    // Epoch-pinned leases held by long-running batch scans, preventing the safe epoch from
+    // advancing past data they are still reading. Leases are kept in memory only: they are
+    // lost on meta failover, so the protection is best-effort and bounded by the lease TTL.
+    iterator_leases: parking_lot::Mutex<IteratorLeases>,
+
+    // Fencing token of this meta leader term, claimed in the meta store on construction and
+    // stamped on every version delta commit. Tokens increase monotonically across meta
+    // failovers: every meta store commit checks the claimed token (see `commit_trx`) so that
+    // in-flight commits of a deposed leader are aborted, and clients additionally reject
+    // deltas carrying a smaller token than one already applied.
+    fence_token: u64,
+
+    // Factories that create a `LevelSelector` for each compaction task type. Built-in
+    // selectors are registered on construction; custom policies can be registered via
+    // `register_selector_factory` before serving.
+    selector_factories: parking_lot::RwLock<HashMap<compact_task::TaskType, LevelSelectorFactory>>,
+
+    // Best-effort periodic export of the full checkpoint version to the object store, as a
+    // disaster recovery anchor in case the meta store is lost. `None` disables the export.
+    version_checkpoint_export: parking_lot::Mutex<Option<VersionCheckpointExport>>,
+
+    // Context ids leased to external compaction sessions. External compactors are not
+    // worker nodes, so `check_context` consults this set in addition to the cluster
+    // manager. Sessions are kept in memory only: they are lost on meta failover and the
+    // external service has to reopen them.
+    external_compaction_contexts: parking_lot::RwLock<HashSet<HummockContextId>>,
+
     compactor_manager: CompactorManagerRef,
     event_sender: HummockManagerEventSender,
 }
 
+/// Well-known object path under which [`HummockManager::proceed_version_checkpoint`] exports the
+/// full checkpoint version. A single object is overwritten in place so that no garbage is left
+/// behind.
+pub const HUMMOCK_VERSION_CHECKPOINT_PATH: &str = "hummock_version_checkpoint/latest";
+
+struct VersionCheckpointExport {
+    object_store: ObjectStoreRef,
+    interval: Duration,
+    last_export: Option<Instant>,
+}
+
 pub type HummockManagerRef<S> = Arc<HummockManager<S>>;
 
+#[derive(Default)]
+struct GroupIngestStats {
+    /// Cumulative bytes committed to each compaction group via `commit_epoch`.
+    accumulated_bytes: HashMap<CompactionGroupId, u64>,
+    /// `(time, accumulated_bytes)` snapshot taken at the previous scaling-hint poll.
+    last_poll: Option<(Instant, HashMap<CompactionGroupId, u64>)>,
+}
+
+#[derive(Default)]
+struct IteratorLeases {
+    next_lease_id: HummockIteratorLeaseId,
+    leases: BTreeMap<HummockIteratorLeaseId, IteratorLease>,
+}
+
+struct IteratorLease {
+    epoch: HummockEpoch,
+    expires_at: Instant,
+}
+
+/// Assumed backlog of pending compaction bytes that a single compactor node can keep up with.
+/// Used to derive the recommended capacity in [`HummockManager::get_compaction_scaling_hint`].
+const PENDING_BYTES_PER_COMPACTOR: u64 = 4 * 1024 * 1024 * 1024;
+
+/// Upper bound on the TTL an iterator lease may request, to bound how long a leaked lease can
+/// hold back the safe epoch.
+const MAX_ITERATOR_LEASE_TTL: Duration = Duration::from_secs(3600);
+
+/// Key under [`DEFAULT_COLUMN_FAMILY`] holding the fence token of the current meta leader term.
+/// Every hummock meta store transaction carries an equality precondition on it, so that in-flight
+/// commits of a deposed leader are aborted by the meta store instead of being persisted.
+const HUMMOCK_FENCE_TOKEN_KEY: &[u8] = b"hummock_fence_token";
+
 /// Commit multiple `ValTransaction`s to state store and upon success update the local in-mem state
 /// by the way
 /// After called, the `ValTransaction` will be dropped.
@@ -195,9 +278,6 @@ macro_rules! start_measure_real_process_timer {
 pub(crate) use start_measure_real_process_timer;
 
 use self::compaction_group_manager::CompactionGroupManagerInner;
-use super::compaction::{
-    ManualCompactionSelector, SpaceReclaimCompactionSelector, TtlCompactionSelector,
-};
 use super::Compactor;
 use crate::hummock::manager::worker::HummockManagerEventSender;
 
@@ -258,6 +338,13 @@ impl CompactionPickParma {
     }
 }
 
+/// A finished or cancelled compaction task to be reported, together with the stats change
+/// introduced by it.
+pub struct ReportTask {
+    pub compact_task: CompactTask,
+    pub table_stats_change: Option<ProstTableStatsMap>,
+}
+
 impl<S> HummockManager<S>
 where
     S: MetaStore,
@@ -307,6 +394,46 @@ where
         compaction_group_manager: tokio::sync::RwLock<CompactionGroupManagerInner<S>>,
     ) -> Result<HummockManagerRef<S>> {
         let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        // Acquire a fencing token for this leader term and claim it in the meta store with a
+        // compare-and-swap on the previous term's token. Every subsequent commit carries an
+        // equality precondition on the claimed token (see `commit_trx`), so in-flight commits
+        // of a deposed leader are aborted by the meta store instead of being persisted.
+        let fence_token = env
+            .id_gen_manager()
+            .generate::<{ IdCategory::HummockFenceToken }>()
+            .await?;
+        let prev_token = match env
+            .meta_store()
+            .get_cf(DEFAULT_COLUMN_FAMILY, HUMMOCK_FENCE_TOKEN_KEY)
+            .await
+        {
+            Ok(value) => Some(value),
+            Err(MetaStoreError::ItemNotFound(_)) => None,
+            Err(e) => return Err(e.into()),
+        };
+        if let Some(prev_token) = &prev_token {
+            let prev_token = memcomparable::from_slice::<u64>(prev_token).unwrap();
+            if prev_token >= fence_token {
+                return Err(Error::MetaStore(anyhow::anyhow!(
+                    "fence token {} has been claimed by a newer meta leader term",
+                    prev_token
+                )));
+            }
+        }
+        let mut claim_trx = Transaction::default();
+        if let Some(prev_token) = prev_token {
+            claim_trx.check_equal(
+                DEFAULT_COLUMN_FAMILY.to_string(),
+                HUMMOCK_FENCE_TOKEN_KEY.to_vec(),
+                prev_token,
+            );
+        }
+        claim_trx.put(
+            DEFAULT_COLUMN_FAMILY.to_string(),
+            HUMMOCK_FENCE_TOKEN_KEY.to_vec(),
+            memcomparable::to_vec(&fence_token).unwrap(),
+        );
+        env.meta_store().txn(claim_trx).await?;
         let instance = HummockManager {
             env,
             versioning: MonitoredRwLock::new(
@@ -323,6 +450,12 @@ where
             compaction_request_channel: parking_lot::RwLock::new(None),
             compaction_resume_notifier: parking_lot::RwLock::new(None),
             compaction_tasks_to_cancel: parking_lot::Mutex::new(vec![]),
+            group_ingest_stats: parking_lot::Mutex::new(Default::default()),
+            iterator_leases: parking_lot::Mutex::new(Default::default()),
+            fence_token,
+            selector_factories: parking_lot::RwLock::new(default_selector_factories()),
+            version_checkpoint_export: parking_lot::Mutex::new(None),
+            external_compaction_contexts: parking_lot::RwLock::new(Default::default()),
             compactor_manager,
             latest_snapshot: ArcSwap::from_pointee(HummockSnapshot {
                 committed_epoch: INVALID_EPOCH,
@@ -449,6 +582,7 @@ where
                 levels: Default::default(),
                 max_committed_epoch: INVALID_EPOCH,
                 safe_epoch: INVALID_EPOCH,
+                domain_committed_epochs: Default::default(),
             };
             // Initialize independent levels via corresponding compaction groups' config.
             for compaction_group in self.compaction_groups().await {
@@ -532,7 +666,7 @@ where
     async fn commit_trx(
         &self,
         meta_store: &S,
-        trx: Transaction,
+        mut trx: Transaction,
         context_id: Option<HummockContextId>,
     ) -> Result<()> {
         if let Some(context_id) = context_id {
@@ -544,6 +678,15 @@ where
             }
         }
 
+        // Fence the commit on the leader term: if another meta node has claimed a newer fence
+        // token since this manager started, the transaction is aborted by the meta store
+        // instead of persisting the writes of a deposed leader.
+        trx.check_equal(
+            DEFAULT_COLUMN_FAMILY.to_string(),
+            HUMMOCK_FENCE_TOKEN_KEY.to_vec(),
+            memcomparable::to_vec(&self.fence_token).unwrap(),
+        );
+
         meta_store.txn(trx).await.map_err(Into::into)
     }
 
@@ -804,6 +947,26 @@ where
     }
 
     #[named]
+    /// Clamps `compaction_config` so that picked tasks fit the smallest member of the current
+    /// compactor fleet: sub-compaction parallelism is capped by the fewest reported cores and
+    /// task input size by half the smallest reported memory. Without any reported capability
+    /// the config is returned unchanged.
+    fn fleet_aware_compaction_config(
+        &self,
+        compaction_config: &CompactionConfig,
+    ) -> CompactionConfig {
+        let mut config = compaction_config.clone();
+        if let Some(capability) = self.compactor_manager.min_fleet_capability() {
+            config.max_sub_compaction = config
+                .max_sub_compaction
+                .min(capability.cpu_core_num.max(1));
+            config.max_compaction_bytes = config
+                .max_compaction_bytes
+                .min((capability.total_memory_bytes / 2).max(config.target_file_size_base));
+        }
+        config
+    }
+
     pub async fn get_compact_task_impl(
         &self,
         compaction_group_id: CompactionGroupId,
@@ -847,6 +1010,7 @@ where
                 .pinned_snapshots
                 .values()
                 .map(|v| v.minimal_pinned_snapshot)
+                .chain(self.min_leased_epoch())
                 .fold(max_committed_epoch, std::cmp::min);
             (versioning_guard.current_version.clone(), watermark)
         };
@@ -859,17 +1023,23 @@ where
         // avoid data loss, the selector_option must be constructed after the current_version is
         // obtained
         let task_type = compaction_pick_parma.task_type;
+        let compaction_config = self.fleet_aware_compaction_config(&group_config.compaction_config);
         let selector_option = self
-            .build_selector_option(&group_config.compaction_config, compaction_pick_parma)
+            .build_selector_option(&compaction_config, compaction_pick_parma)
             .await;
 
         // get selector
+        let selector_factories = self.selector_factories.read();
         let selector = Self::fetch_selector(
+            &selector_factories,
             compaction_selectors,
             compaction_group_id,
             task_type,
             selector_option,
         );
+        // `selector` borrows from `compaction_selectors`, not from the factories, so the lock can
+        // be released before the awaits below.
+        drop(selector_factories);
 
         let can_trivial_move = matches!(selector.task_type(), compact_task::TaskType::Dynamic);
 
@@ -933,6 +1103,7 @@ where
                 .map(|id_to_option| (*id_to_option.0, id_to_option.1.into()))
                 .collect();
             compact_task.current_epoch_time = Epoch::now().0;
+            compact_task.max_sub_compaction = compaction_config.max_sub_compaction;
 
             compact_task.compaction_filter_mask =
                 group_config.compaction_config.compaction_filter_mask;
@@ -947,6 +1118,18 @@ where
                 &current_version,
                 compaction_group_id,
             );
+            if let (Some(levels), Some(compact_status)) = (
+                current_version.levels.get(&compaction_group_id),
+                compaction.compaction_statuses.get(&compaction_group_id),
+            ) {
+                let dynamic_level_core =
+                    DynamicLevelSelectorCore::new(Arc::new(compaction_config.clone()));
+                trigger_level_score_stat(
+                    &self.metrics,
+                    compaction_group_id,
+                    &dynamic_level_core.get_level_scores(levels, &compact_status.level_handlers),
+                );
+            }
 
             tracing::trace!(
                 "For compaction group {}: pick up {} tables in level {} to compact.  cost time: {:?}",
@@ -1003,15 +1186,77 @@ where
         Ok(())
     }
 
-    fn fetch_selector(
-        compaction_selectors: &mut HashMap<
+    /// Applies an updated compaction config of the given groups to in-memory compaction state:
+    /// resizes the per-group [`CompactStatus`] to the new `max_level` and drops cached selectors
+    /// so they are rebuilt from the new config on next use. Compactors pick up new parameters
+    /// with the next dispatched task, as tasks embed all relevant config.
+    #[named]
+    pub(super) async fn sync_group_configs(
+        &self,
+        compaction_group_ids: &[CompactionGroupId],
+    ) -> Result<()> {
+        let mut compaction_guard = write_lock!(self, compaction).await;
+        let compaction = compaction_guard.deref_mut();
+        for &group_id in compaction_group_ids {
+            let group_config = match self.compaction_group(group_id).await {
+                Some(group_config) => group_config,
+                None => continue,
+            };
+            let handler_count = group_config.compaction_config.max_level as usize + 1;
+            if let Some(compact_status) = compaction.compaction_statuses.get_mut(&group_id) {
+                if compact_status.level_handlers.len() < handler_count {
+                    let mut compact_status = VarTransaction::new(compact_status);
+                    while compact_status.level_handlers.len() < handler_count {
+                        let level = compact_status.level_handlers.len() as u32;
+                        compact_status.level_handlers.push(LevelHandler::new(level));
+                    }
+                    commit_multi_var!(self, None, Transaction::default(), compact_status)?;
+                } else if compact_status.level_handlers.len() > handler_count {
+                    if compact_status.level_handlers[handler_count..]
+                        .iter()
+                        .all(|handler| handler.pending_tasks_ids().is_empty())
+                    {
+                        let mut compact_status = VarTransaction::new(compact_status);
+                        compact_status.level_handlers.truncate(handler_count);
+                        commit_multi_var!(self, None, Transaction::default(), compact_status)?;
+                    } else {
+                        tracing::warn!(
+                            "defer shrinking compact status of group {} to {} levels: pending \
+                             tasks in removed levels",
+                            group_id,
+                            handler_count
+                        );
+                    }
+                }
+            }
+            // Cached selectors are rebuilt from the new config on next use.
+            compaction.compaction_selectors.remove(&group_id);
+        }
+        Ok(())
+    }
+
+    /// Registers a [`LevelSelectorFactory`] for `task_type`, replacing any previous one. This is
+    /// meant to be called right after [`HummockManager::new`], so custom selection policies can be
+    /// plugged in without patching the built-in dispatch. Selectors already instantiated from the
+    /// previous factory keep serving until their group entry is dropped.
+    pub fn register_selector_factory(
+        &self,
+        task_type: compact_task::TaskType,
+        factory: LevelSelectorFactory,
+    ) {
+        self.selector_factories.write().insert(task_type, factory);
+    }
+
+    fn fetch_selector<'a>(
+        selector_factories: &HashMap<compact_task::TaskType, LevelSelectorFactory>,
+        compaction_selectors: &'a mut HashMap<
             CompactionGroupId,
             HashMap<compact_task::TaskType, Box<dyn LevelSelector>>,
         >,
         compaction_group_id: CompactionGroupId,
         task_type: compact_task::TaskType,
         selector_option: SelectorOption,
-    ) -> &mut Box<dyn LevelSelector> {
+    ) -> &'a mut Box<dyn LevelSelector> {
         match compaction_selectors
             .entry(compaction_group_id)
             .or_default()
@@ -1020,50 +1265,10 @@ where
             Occupied(mut selector) => selector.get_mut().try_update(selector_option),
 
             Vacant(entry) => {
-                let new_selector: Box<dyn LevelSelector> = match task_type {
-                    compact_task::TaskType::Dynamic => {
-                        let selector_option =
-                            selector_option.as_dynamic().expect("tried to as_dynamic");
-                        Box::new(DynamicLevelSelector::new(
-                            selector_option.compaction_config.clone(),
-                            create_overlap_strategy(
-                                selector_option.compaction_config.compaction_mode(),
-                            ),
-                        ))
-                    }
-
-                    compact_task::TaskType::Manual => {
-                        let selector_option =
-                            selector_option.as_manual().expect("tried to as_dynamic");
-                        Box::new(ManualCompactionSelector::new(
-                            selector_option.compaction_config.clone(),
-                            create_overlap_strategy(
-                                selector_option.compaction_config.compaction_mode(),
-                            ),
-                            selector_option.option,
-                        ))
-                    }
-
-                    compact_task::TaskType::SpaceReclaim => {
-                        let selector_option = selector_option
-                            .as_space_reclaim()
-                            .expect("tried to as_space_reclaim");
-                        Box::new(SpaceReclaimCompactionSelector::new(selector_option))
-                    }
-
-                    compact_task::TaskType::Ttl => {
-                        let selector_option = selector_option.as_ttl().expect("tried to as_ttl");
-                        Box::new(TtlCompactionSelector::new(
-                            selector_option.compaction_config,
-                        ))
-                    }
-
-                    _ => {
-                        panic!()
-                    }
-                };
-
-                entry.insert(new_selector);
+                let factory = selector_factories.get(&task_type).unwrap_or_else(|| {
+                    panic!("no selector factory registered for task type {:?}", task_type)
+                });
+                entry.insert(factory(selector_option));
             }
         }
 
@@ -1108,6 +1313,41 @@ where
         .await
     }
 
+    /// Picks and assigns a compaction task for the external compaction session `context_id`,
+    /// scanning all compaction groups. Returns `None` if no group has compaction pending.
+    pub async fn pull_external_compact_task(
+        &self,
+        context_id: HummockContextId,
+    ) -> Result<Option<CompactTask>> {
+        if !self.is_external_compaction_context(context_id) {
+            return Err(Error::InvalidContext(context_id));
+        }
+        for compaction_group_id in self.compaction_group_ids().await {
+            let mut compact_task = match self
+                .get_compact_task(compaction_group_id, CompactionPickParma::new_base_parma())
+                .await?
+            {
+                Some(compact_task) => compact_task,
+                None => {
+                    continue;
+                }
+            };
+            if let Err(err) = self.assign_compaction_task(&compact_task, context_id).await {
+                tracing::warn!(
+                    "Failed to assign compaction task {} to external session {}: {:#?}",
+                    compact_task.task_id,
+                    context_id,
+                    err
+                );
+                self.cancel_compact_task(&mut compact_task, TaskStatus::AssignFailCanceled)
+                    .await?;
+                continue;
+            }
+            return Ok(Some(compact_task));
+        }
+        Ok(None)
+    }
+
     #[named]
     pub async fn get_idle_compactor(&self) -> Option<Arc<Compactor>> {
         let compaction_guard = read_lock!(self, compaction).await;
@@ -1127,6 +1367,39 @@ where
             .next_idle_compactor(&compactor_assigned_task_num)
     }
 
+    /// Tries to free a busy compactor for a task of `priority` by cancelling its least urgent
+    /// queued assignment, if that assignment belongs to a strictly lower priority class. The
+    /// preempted task is canceled and will be rescheduled by the periodic compaction trigger.
+    #[named]
+    pub async fn preempt_assignment(&self, priority: TaskPriority) -> Option<Arc<Compactor>> {
+        let (compactor, task_id) = self.compactor_manager.next_preemptible_compactor(priority)?;
+        let mut compact_task = {
+            let compaction_guard = read_lock!(self, compaction).await;
+            compaction_guard
+                .compact_task_assignment
+                .get(&task_id)?
+                .compact_task
+                .clone()?
+        };
+        match self
+            .cancel_compact_task(&mut compact_task, TaskStatus::ManualCanceled)
+            .await
+        {
+            Ok(_) => {
+                tracing::info!(
+                    "Preempted queued compaction task {} on compactor {}",
+                    task_id,
+                    compactor.context_id()
+                );
+                Some(compactor)
+            }
+            Err(err) => {
+                tracing::warn!("Failed to preempt compaction task {}: {:#?}", task_id, err);
+                None
+            }
+        }
+    }
+
     /// Assign a compaction task to the compactor identified by `assignee_context_id`.
     #[named]
     pub async fn assign_compaction_task(
@@ -1164,9 +1437,13 @@ where
             Transaction::default(),
             compact_task_assignment
         )?;
-        // Update compaction schedule policy.
-        self.compactor_manager
-            .assign_compact_task(assignee_context_id, compact_task)?;
+        // Update compaction schedule policy. External compaction sessions pull tasks
+        // instead of having them pushed over a subscription channel, so the policy does
+        // not track them.
+        if !self.is_external_compaction_context(assignee_context_id) {
+            self.compactor_manager
+                .assign_compact_task(assignee_context_id, compact_task)?;
+        }
 
         // Initiate heartbeat for the task to track its progress.
         self.compactor_manager
@@ -1217,6 +1494,19 @@ where
         Ok(ret)
     }
 
+    /// Finishes or cancels multiple compaction tasks in a single meta transaction, grouping the
+    /// version deltas of all successful tasks into one commit and one notification. This cuts the
+    /// per-task lock and meta store write overhead when compactors report many small tasks at
+    /// once.
+    pub async fn report_compact_tasks(
+        &self,
+        context_id: HummockContextId,
+        report_tasks: &mut [ReportTask],
+    ) -> Result<Vec<bool>> {
+        self.report_compact_tasks_impl(Some(context_id), report_tasks, None)
+            .await
+    }
+
     /// Finishes or cancels a compaction task, according to `task_status`.
     ///
     /// If `context_id` is not None, its validity will be checked when writing meta store.
@@ -1224,7 +1514,6 @@ where
     ///
     /// Return Ok(false) indicates either the task is not found,
     /// or the task is not owned by `context_id` when `context_id` is not None.
-    #[named]
     pub async fn report_compact_task_impl(
         &self,
         context_id: Option<HummockContextId>,
@@ -1232,6 +1521,30 @@ where
         compaction_guard: Option<RwLockWriteGuard<'_, Compaction>>,
         table_stats_change: Option<ProstTableStatsMap>,
     ) -> Result<bool> {
+        let mut report_tasks = [ReportTask {
+            compact_task: std::mem::take(compact_task),
+            table_stats_change,
+        }];
+        let ret = self
+            .report_compact_tasks_impl(context_id, &mut report_tasks, compaction_guard)
+            .await?;
+        let [report_task] = report_tasks;
+        *compact_task = report_task.compact_task;
+        Ok(ret[0])
+    }
+
+    /// Batched version of [`HummockManager::report_compact_task_impl`].
+    ///
+    /// Each task is validated independently: a task that fails the assignment check is skipped
+    /// and reported as `false` in the returned vector, without affecting the rest of the batch.
+    /// If all tasks fail the check, nothing is written to the meta store at all.
+    #[named]
+    pub async fn report_compact_tasks_impl(
+        &self,
+        context_id: Option<HummockContextId>,
+        report_tasks: &mut [ReportTask],
+        compaction_guard: Option<RwLockWriteGuard<'_, Compaction>>,
+    ) -> Result<Vec<bool>> {
         let mut compaction_guard = match compaction_guard {
             None => write_lock!(self, compaction).await,
             Some(compaction_guard) => compaction_guard,
@@ -1252,80 +1565,125 @@ where
         let assigned_task_num = compaction.compact_task_assignment.len();
         let mut compact_task_assignment =
             BTreeMapTransaction::new(&mut compaction.compact_task_assignment);
-        let assignee_context_id = compact_task_assignment
-            .remove(compact_task.task_id)
-            .map(|assignment| assignment.context_id);
 
-        // For context_id is None, there is no need to check the task assignment.
-        if let Some(context_id) = context_id {
-            match assignee_context_id {
-                Some(id) => {
-                    // Assignee id mismatch.
-                    if id != context_id {
-                        tracing::warn!(
-                            "Wrong reporter {}. Compaction task {} is assigned to {}",
-                            context_id,
-                            compact_task.task_id,
-                            *assignee_context_id.as_ref().unwrap(),
-                        );
-                        return Ok(false);
+        let mut accepted = Vec::with_capacity(report_tasks.len());
+        let mut assignee_context_ids = Vec::with_capacity(report_tasks.len());
+        for report_task in report_tasks.iter_mut() {
+            let compact_task = &mut report_task.compact_task;
+            let assignee_context_id = compact_task_assignment
+                .get(&compact_task.task_id)
+                .map(|assignment| assignment.context_id);
+
+            // For context_id is None, there is no need to check the task assignment.
+            if let Some(context_id) = context_id {
+                match assignee_context_id {
+                    Some(id) => {
+                        // Assignee id mismatch.
+                        if id != context_id {
+                            tracing::warn!(
+                                "Wrong reporter {}. Compaction task {} is assigned to {}",
+                                context_id,
+                                compact_task.task_id,
+                                id,
+                            );
+                            accepted.push(false);
+                            assignee_context_ids.push(assignee_context_id);
+                            continue;
+                        }
                     }
+                    None => {
+                        // The task is not found.
+                        tracing::warn!("Compaction task {} not found", compact_task.task_id);
+                        accepted.push(false);
+                        assignee_context_ids.push(None);
+                        continue;
+                    }
+                }
+            }
+            compact_task_assignment.remove(compact_task.task_id);
+
+            match compact_statuses.get_mut(compact_task.compaction_group_id) {
+                Some(mut compact_status) => {
+                    compact_status.report_compact_task(compact_task);
                 }
                 None => {
-                    // The task is not found.
-                    tracing::warn!("Compaction task {} not found", compact_task.task_id);
-                    return Ok(false);
+                    compact_task.set_task_status(TaskStatus::InvalidGroupCanceled);
                 }
             }
-        }
 
-        match compact_statuses.get_mut(compact_task.compaction_group_id) {
-            Some(mut compact_status) => {
-                compact_status.report_compact_task(compact_task);
-            }
-            None => {
-                compact_task.set_task_status(TaskStatus::InvalidGroupCanceled);
-            }
+            debug_assert!(
+                compact_task.task_status() != TaskStatus::Pending,
+                "report pending compaction task"
+            );
+            accepted.push(true);
+            assignee_context_ids.push(assignee_context_id);
+        }
+        if accepted.iter().all(|accepted| !accepted) {
+            // All tasks are rejected, so the staged changes are simply dropped.
+            return Ok(accepted);
         }
 
-        debug_assert!(
-            compact_task.task_status() != TaskStatus::Pending,
-            "report pending compaction task"
-        );
         {
-            // The compaction task is finished.
+            // The compaction tasks are finished.
             let mut versioning_guard = write_lock!(self, versioning).await;
             let versioning = versioning_guard.deref_mut();
-            let current_version = &mut versioning.current_version;
-            let is_success = if let TaskStatus::Success = compact_task.task_status() {
-                let is_expired = !current_version
-                    .get_levels()
-                    .contains_key(&compact_task.compaction_group_id)
-                    || Self::is_compact_task_expired(compact_task, &versioning.branched_ssts);
-                if is_expired {
-                    compact_task.set_task_status(TaskStatus::InvalidGroupCanceled);
-                    false
-                } else {
-                    true
-                }
-            } else {
-                false
-            };
-            if is_success {
+
+            // Decide which tasks are successfully finished before generating any version delta,
+            // because the expiration check borrows `versioning` immutably.
+            let mut is_success = Vec::with_capacity(report_tasks.len());
+            for (report_task, accepted) in report_tasks.iter_mut().zip(accepted.iter()) {
+                let compact_task = &mut report_task.compact_task;
+                let success = *accepted
+                    && if let TaskStatus::Success = compact_task.task_status() {
+                        let is_expired = !versioning
+                            .current_version
+                            .get_levels()
+                            .contains_key(&compact_task.compaction_group_id)
+                            || Self::is_compact_task_expired(
+                                compact_task,
+                                &versioning.branched_ssts,
+                            );
+                        if is_expired {
+                            compact_task.set_task_status(TaskStatus::InvalidGroupCanceled);
+                            false
+                        } else {
+                            true
+                        }
+                    } else {
+                        false
+                    };
+                is_success.push(success);
+            }
+
+            if is_success.iter().any(|success| *success) {
+                let mut new_version = versioning.current_version.clone();
                 let mut hummock_version_deltas =
                     BTreeMapTransaction::new(&mut versioning.hummock_version_deltas);
                 let mut branched_ssts = BTreeMapTransaction::new(&mut versioning.branched_ssts);
-                let version_delta = gen_version_delta(
-                    &mut hummock_version_deltas,
-                    &mut branched_ssts,
-                    current_version,
-                    compact_task,
-                    CompactStatus::is_trivial_move_task(compact_task),
-                    deterministic_mode,
-                );
                 let mut version_stats = VarTransaction::new(&mut versioning.version_stats);
-                if let Some(table_stats_change) = table_stats_change {
-                    add_prost_table_stats_map(&mut version_stats.table_stats, &table_stats_change);
+                let mut version_deltas = Vec::with_capacity(report_tasks.len());
+                for (report_task, success) in report_tasks.iter().zip(is_success.iter()) {
+                    if !success {
+                        continue;
+                    }
+                    let compact_task = &report_task.compact_task;
+                    let version_delta = gen_version_delta(
+                        &mut hummock_version_deltas,
+                        &mut branched_ssts,
+                        &new_version,
+                        compact_task,
+                        CompactStatus::is_trivial_move_task(compact_task),
+                        deterministic_mode,
+                        self.fence_token,
+                    );
+                    new_version.apply_version_delta(&version_delta);
+                    version_deltas.push(version_delta);
+                    if let Some(table_stats_change) = &report_task.table_stats_change {
+                        add_prost_table_stats_map(
+                            &mut version_stats.table_stats,
+                            table_stats_change,
+                        );
+                    }
                 }
 
                 commit_multi_var!(
@@ -1338,9 +1696,13 @@ where
                     version_stats
                 )?;
                 branched_ssts.commit_memory();
-                current_version.apply_version_delta(&version_delta);
+                versioning.current_version = new_version;
 
-                trigger_version_stat(&self.metrics, current_version, &versioning.version_stats);
+                trigger_version_stat(
+                    &self.metrics,
+                    &versioning.current_version,
+                    &versioning.version_stats,
+                );
 
                 if !deterministic_mode {
                     self.env
@@ -1348,19 +1710,12 @@ where
                         .notify_hummock_without_version(
                             Operation::Add,
                             Info::HummockVersionDeltas(
-                                risingwave_pb::hummock::HummockVersionDeltas {
-                                    version_deltas: vec![versioning
-                                        .hummock_version_deltas
-                                        .last_key_value()
-                                        .unwrap()
-                                        .1
-                                        .clone()],
-                                },
+                                risingwave_pb::hummock::HummockVersionDeltas { version_deltas },
                             ),
                         );
                 }
             } else {
-                // The compaction task is cancelled or failed.
+                // All compaction tasks are cancelled or failed.
                 commit_multi_var!(
                     self,
                     context_id,
@@ -1371,89 +1726,105 @@ where
             }
         }
 
-        let task_status = compact_task.task_status();
-        let task_status_label = task_status.as_str_name();
-        let task_type_label = compact_task.task_type().as_str_name();
-        if let Some(context_id) = assignee_context_id {
-            // A task heartbeat is removed IFF we report the task status of a task and it still has
-            // a valid assignment, OR we remove the node context from our list of nodes,
-            // in which case the associated heartbeats are forcefully purged.
-            self.compactor_manager
-                .remove_task_heartbeat(context_id, compact_task.task_id);
-            // Also, if the task is already assigned, we need to update the compaction schedule
-            // policy.
-            self.compactor_manager
-                .report_compact_task(context_id, compact_task);
-            // Tell compaction scheduler to resume compaction if there's any compactor becoming
-            // available.
-            if assigned_task_num == self.compactor_manager.max_concurrent_task_number() {
-                self.try_resume_compaction(CompactionResumeTrigger::TaskReport {
-                    original_task_num: assigned_task_num,
-                });
+        for ((report_task, accepted), assignee_context_id) in report_tasks
+            .iter()
+            .zip(accepted.iter())
+            .zip(assignee_context_ids.iter())
+        {
+            if !*accepted {
+                continue;
             }
+            let compact_task = &report_task.compact_task;
+            let task_status = compact_task.task_status();
+            let task_status_label = task_status.as_str_name();
+            let task_type_label = compact_task.task_type().as_str_name();
+            if let Some(context_id) = *assignee_context_id {
+                // A task heartbeat is removed IFF we report the task status of a task and it still
+                // has a valid assignment, OR we remove the node context from our list of nodes,
+                // in which case the associated heartbeats are forcefully purged.
+                self.compactor_manager
+                    .remove_task_heartbeat(context_id, compact_task.task_id);
+                // Also, if the task is already assigned, we need to update the compaction schedule
+                // policy.
+                self.compactor_manager
+                    .report_compact_task(context_id, compact_task);
+                // Tell compaction scheduler to resume compaction if there's any compactor becoming
+                // available.
+                if assigned_task_num == self.compactor_manager.max_concurrent_task_number() {
+                    self.try_resume_compaction(CompactionResumeTrigger::TaskReport {
+                        original_task_num: assigned_task_num,
+                    });
+                }
+
+                // Update compaction task count.
+                //
+                // A corner case is that the compactor is deleted
+                // immediately after it reports the task and before the meta node handles
+                // it. In that case, its host address will not be obtainable.
+                if let Some(worker) = self.cluster_manager.get_worker_by_id(context_id).await {
+                    let host = worker.worker_node.host.unwrap();
+                    self.metrics
+                        .compact_frequency
+                        .with_label_values(&[
+                            &format!("{}:{}", host.host, host.port),
+                            &compact_task.compaction_group_id.to_string(),
+                            task_type_label,
+                            task_status_label,
+                        ])
+                        .inc();
+                }
+            } else {
+                // There are two cases where assignee_context_id is not available
+                // 1. compactor does not exist
+                // 2. trivial_move
+
+                let label = if CompactStatus::is_trivial_move_task(compact_task) {
+                    // TODO: only support can_trivial_move in DynamicLevelCompcation, will check
+                    // task_type next PR
+                    "trivial-move"
+                } else {
+                    "unassigned"
+                };
 
-            // Update compaction task count.
-            //
-            // A corner case is that the compactor is deleted
-            // immediately after it reports the task and before the meta node handles
-            // it. In that case, its host address will not be obtainable.
-            if let Some(worker) = self.cluster_manager.get_worker_by_id(context_id).await {
-                let host = worker.worker_node.host.unwrap();
                 self.metrics
                     .compact_frequency
                     .with_label_values(&[
-                        &format!("{}:{}", host.host, host.port),
+                        label,
                         &compact_task.compaction_group_id.to_string(),
                         task_type_label,
                         task_status_label,
                     ])
                     .inc();
             }
-        } else {
-            // There are two cases where assignee_context_id is not available
-            // 1. compactor does not exist
-            // 2. trivial_move
-
-            let label = if CompactStatus::is_trivial_move_task(compact_task) {
-                // TODO: only support can_trivial_move in DynamicLevelCompcation, will check
-                // task_type next PR
-                "trivial-move"
-            } else {
-                "unassigned"
-            };
-
-            self.metrics
-                .compact_frequency
-                .with_label_values(&[
-                    label,
-                    &compact_task.compaction_group_id.to_string(),
-                    task_type_label,
-                    task_status_label,
-                ])
-                .inc();
-        }
 
-        tracing::trace!(
-            "Reported compaction task. {}. cost time: {:?}",
-            compact_task_to_string(compact_task),
-            start_time.elapsed(),
-        );
+            tracing::trace!(
+                "Reported compaction task. {}. cost time: {:?}",
+                compact_task_to_string(compact_task),
+                start_time.elapsed(),
+            );
 
-        trigger_sst_stat(
-            &self.metrics,
-            compaction
-                .compaction_statuses
-                .get(&compact_task.compaction_group_id),
-            read_lock!(self, versioning).await.current_version.borrow(),
-            compact_task.compaction_group_id,
-        );
+            if !deterministic_mode
+                && matches!(compact_task.task_type(), compact_task::TaskType::Dynamic)
+            {
+                self.try_send_compaction_request(
+                    compact_task.compaction_group_id,
+                    compact_task.task_type(),
+                );
+            }
+        }
 
-        if !deterministic_mode
-            && matches!(compact_task.task_type(), compact_task::TaskType::Dynamic)
+        for compaction_group_id in report_tasks
+            .iter()
+            .zip(accepted.iter())
+            .filter(|(_, accepted)| **accepted)
+            .map(|(report_task, _)| report_task.compact_task.compaction_group_id)
+            .unique()
         {
-            self.try_send_compaction_request(
-                compact_task.compaction_group_id,
-                compact_task.task_type(),
+            trigger_sst_stat(
+                &self.metrics,
+                compaction.compaction_statuses.get(&compaction_group_id),
+                read_lock!(self, versioning).await.current_version.borrow(),
+                compaction_group_id,
             );
         }
 
@@ -1463,7 +1834,7 @@ where
             self.check_state_consistency().await;
         }
 
-        Ok(true)
+        Ok(accepted)
     }
 
     async fn sync_group<'a>(
@@ -1490,6 +1861,7 @@ where
                 prev_id: old_version.id,
                 safe_epoch: old_version.safe_epoch,
                 trivial_move: false,
+                fence_token: self.fence_token,
                 ..Default::default()
             },
         );
@@ -1679,13 +2051,137 @@ where
         Ok(None)
     }
 
-    /// Caller should ensure `epoch` > `max_committed_epoch`
+    /// Moves all SSTs of `right_group_id` into `left_group_id` in the version and removes the
+    /// right group, as part of the meta transaction in `trx_extern_part`. Compaction tasks
+    /// targeting either group are cancelled, because the key space they were picked against
+    /// has changed.
+    pub(super) async fn merge_group_in_version(
+        &self,
+        compaction: &mut Compaction,
+        versioning: &mut Versioning,
+        left_group_id: CompactionGroupId,
+        right_group_id: CompactionGroupId,
+        trx_extern_part: &mut Option<Transaction>,
+    ) -> Result<()> {
+        let old_version = versioning.current_version.clone();
+        if !old_version.levels.contains_key(&left_group_id) {
+            return Err(Error::InvalidCompactionGroup(left_group_id));
+        }
+        if !old_version.levels.contains_key(&right_group_id) {
+            // The right group has never been synced into the version, so there is no data to
+            // move. The caller commits the group metadata change on its own.
+            return Ok(());
+        }
+        let mut right_sst_ids = vec![];
+        old_version.level_iter(right_group_id, |level| {
+            right_sst_ids.extend(level.table_infos.iter().map(|info| info.id));
+            true
+        });
+        // An SST branched into both groups would appear twice in the left group after the
+        // merge. Such branches are rewritten by compaction eventually, so let the caller retry
+        // later.
+        for sst_id in &right_sst_ids {
+            if versioning
+                .branched_ssts
+                .get(sst_id)
+                .map_or(false, |branches| branches.contains_key(&left_group_id))
+            {
+                return Err(Error::InvalidSst(*sst_id));
+            }
+        }
+
+        // Cancel tasks of both groups before modifying the version.
+        let mut tasks_to_cancel = compaction
+            .compact_task_assignment
+            .values()
+            .filter_map(|assignment| {
+                let task = assignment.compact_task.as_ref().unwrap();
+                (task.compaction_group_id == left_group_id
+                    || task.compaction_group_id == right_group_id)
+                    .then_some(task.task_id)
+            })
+            .collect_vec();
+        let mut compact_statuses = BTreeMapTransaction::new(&mut compaction.compaction_statuses);
+        compact_statuses.remove(right_group_id);
+
+        let new_version_id = old_version.id + 1;
+        let mut new_version_delta = BTreeMapEntryTransaction::new_insert(
+            &mut versioning.hummock_version_deltas,
+            new_version_id,
+            HummockVersionDelta {
+                prev_id: old_version.id,
+                safe_epoch: old_version.safe_epoch,
+                max_committed_epoch: old_version.max_committed_epoch,
+                trivial_move: false,
+                fence_token: self.fence_token,
+                ..Default::default()
+            },
+        );
+        new_version_delta.id = new_version_id;
+        new_version_delta
+            .group_deltas
+            .entry(left_group_id)
+            .or_default()
+            .group_deltas
+            .push(GroupDelta {
+                delta_type: Some(DeltaType::GroupMerge(GroupMerge { right_group_id })),
+            });
+        let mut new_hummock_version = old_version;
+        new_hummock_version.id = new_version_id;
+        new_hummock_version.merge_levels(right_group_id, left_group_id);
+
+        // Re-key branches of moved SSTs from the right group to the left group.
+        let mut branched_ssts = BTreeMapTransaction::new(&mut versioning.branched_ssts);
+        for sst_id in right_sst_ids {
+            if let Some(mut entry) = branched_ssts.get_mut(sst_id) {
+                if let Some(divide_ver) = entry.remove(&right_group_id) {
+                    entry.insert(left_group_id, divide_ver);
+                }
+            }
+        }
+
+        commit_multi_var!(
+            self,
+            None,
+            trx_extern_part.take().unwrap_or_default(),
+            new_version_delta,
+            compact_statuses
+        )?;
+        branched_ssts.commit_memory();
+        versioning.current_version = new_hummock_version;
+
+        self.env
+            .notification_manager()
+            .notify_hummock_without_version(
+                Operation::Add,
+                Info::HummockVersionDeltas(risingwave_pb::hummock::HummockVersionDeltas {
+                    version_deltas: vec![versioning
+                        .hummock_version_deltas
+                        .last_key_value()
+                        .unwrap()
+                        .1
+                        .clone()],
+                }),
+            );
+
+        remove_compaction_group_in_sst_stat(&self.metrics, right_group_id);
+        self.compaction_tasks_to_cancel
+            .lock()
+            .append(&mut tasks_to_cancel);
+        Ok(())
+    }
+
+    /// Commits `epoch` for the given barrier domain, or for all domains if `barrier_domain` is
+    /// `None`. Caller should ensure `epoch` is greater than the committed epoch of the domain;
+    /// commits of different domains may interleave, so `epoch` is allowed to be smaller than
+    /// `max_committed_epoch`.
     #[named]
     pub async fn commit_epoch(
         &self,
         epoch: HummockEpoch,
         sstables: Vec<impl Into<ExtendedSstableInfo>>,
         sst_to_context: HashMap<HummockSstableId, HummockContextId>,
+        barrier_domain: Option<BarrierDomainId>,
     ) -> Result<Option<HummockSnapshot>> {
         let mut sstables = sstables.into_iter().map(|s| s.into()).collect_vec();
         let mut versioning_guard = write_lock!(self, versioning).await;
@@ -1707,6 +2203,7 @@ where
             &sstables,
             &sst_to_context,
             &versioning.current_version,
+            barrier_domain,
         )
         .await?;
 
@@ -1725,6 +2222,7 @@ where
                 prev_id: old_version.id,
                 safe_epoch: old_version.safe_epoch,
                 trivial_move: false,
+                fence_token: self.fence_token,
                 ..Default::default()
             },
         );
@@ -1789,6 +2287,7 @@ where
         sstables.append(&mut branch_sstables);
 
         let mut modified_compaction_groups = vec![];
+        let mut ingest_bytes_by_group: HashMap<CompactionGroupId, u64> = HashMap::new();
         // Append SSTs to a new version.
         for (compaction_group_id, sstables) in &sstables
             .into_iter()
@@ -1812,6 +2311,10 @@ where
                 .into_iter()
                 .map(|ExtendedSstableInfo { sst_info, .. }| sst_info)
                 .collect_vec();
+            ingest_bytes_by_group.insert(
+                compaction_group_id,
+                group_sstables.iter().map(|sst| sst.file_size).sum(),
+            );
             let group_deltas = &mut new_version_delta
                 .group_deltas
                 .entry(compaction_group_id)
@@ -1841,9 +2344,28 @@ where
             );
         }
 
-        // Create a new_version, possibly merely to bump up the version id and max_committed_epoch.
-        new_version_delta.max_committed_epoch = epoch;
-        new_hummock_version.max_committed_epoch = epoch;
+        // Create a new_version, possibly merely to bump up the version id and the committed
+        // epochs. The committed epoch is partitioned by barrier domain: this commit advances the
+        // epoch of its own domain (or of all domains, for a domain-spanning barrier), while
+        // `max_committed_epoch` is the maximum over all domains and thus never decreases, even
+        // when a lagging domain commits.
+        let max_committed_epoch = new_hummock_version.max_committed_epoch.max(epoch);
+        new_version_delta.max_committed_epoch = max_committed_epoch;
+        new_hummock_version.max_committed_epoch = max_committed_epoch;
+        let committed_domains = match barrier_domain {
+            Some(barrier_domain) => vec![barrier_domain],
+            None => new_hummock_version
+                .domain_committed_epochs
+                .keys()
+                .copied()
+                .collect_vec(),
+        };
+        for domain in committed_domains {
+            new_version_delta.domain_committed_epochs.insert(domain, epoch);
+            new_hummock_version
+                .domain_committed_epochs
+                .insert(domain, epoch);
+        }
 
         // Apply stats changes.
         let mut version_stats = VarTransaction::new(&mut versioning.version_stats);
@@ -1860,13 +2382,38 @@ where
         branched_ssts.commit_memory();
         versioning.current_version = new_hummock_version;
 
+        // Account per-group ingest bytes for compactor autoscaling.
+        {
+            let mut ingest_stats = self.group_ingest_stats.lock();
+            for (group_id, bytes) in &ingest_bytes_by_group {
+                *ingest_stats.accumulated_bytes.entry(*group_id).or_default() += bytes;
+                self.metrics
+                    .compaction_group_ingest_bytes
+                    .with_label_values(&[&group_id.to_string()])
+                    .inc_by(*bytes);
+            }
+        }
+
+        // The snapshot exposed to frontends tracks the maximum over all barrier domains, so a
+        // commit of a lagging domain does not move it backwards.
+        let prev_snapshot = self.latest_snapshot.rcu(|snapshot| HummockSnapshot {
+            committed_epoch: snapshot.committed_epoch.max(epoch),
+            current_epoch: snapshot.current_epoch.max(epoch),
+        });
         let snapshot = HummockSnapshot {
-            committed_epoch: epoch,
-            current_epoch: epoch,
+            committed_epoch: prev_snapshot.committed_epoch.max(epoch),
+            current_epoch: prev_snapshot.current_epoch.max(epoch),
         };
-        let prev_snapshot = self.latest_snapshot.swap(snapshot.clone().into());
-        assert!(prev_snapshot.committed_epoch < epoch);
-        assert!(prev_snapshot.current_epoch < epoch);
+
+        // Record the commit wall-clock time of this epoch for `rw_hummock_epochs`.
+        let commit_ts_ms = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .expect("system clock set earlier than UNIX epoch")
+            .as_millis() as u64;
+        versioning.epoch_commit_times.push_back((epoch, commit_ts_ms));
+        if versioning.epoch_commit_times.len() > MAX_EPOCH_COMMIT_TIME_RECORDS {
+            versioning.epoch_commit_times.pop_front();
+        }
 
         trigger_version_stat(
             &self.metrics,
@@ -1914,19 +2461,249 @@ where
     }
 
     /// We don't commit an epoch without checkpoint. We will only update the `max_current_epoch`.
+    /// Barriers of different domains may complete out of order, so the epoch is only advanced,
+    /// never moved backwards.
     pub fn update_current_epoch(&self, max_current_epoch: HummockEpoch) -> HummockSnapshot {
         // We only update `max_current_epoch`!
         let prev_snapshot = self.latest_snapshot.rcu(|snapshot| HummockSnapshot {
             committed_epoch: snapshot.committed_epoch,
-            current_epoch: max_current_epoch,
+            current_epoch: snapshot.current_epoch.max(max_current_epoch),
         });
-        assert!(prev_snapshot.current_epoch < max_current_epoch);
 
         tracing::trace!("new current epoch {}", max_current_epoch);
         HummockSnapshot {
             committed_epoch: prev_snapshot.committed_epoch,
-            current_epoch: max_current_epoch,
+            current_epoch: prev_snapshot.current_epoch.max(max_current_epoch),
+        }
+    }
+
+    /// Re-adds SSTs to the current version, making their data readable again. Used when
+    /// importing an exported materialized view snapshot, whose SSTs may have been compacted out
+    /// of the version since the export. The tables of the SSTs must have been registered to
+    /// compaction groups beforehand; SSTs whose tables are not registered are skipped with a
+    /// warning.
+    ///
+    /// Like `commit_epoch`, the SSTs are inserted as a new overlapping sub level of L0 and
+    /// `max_committed_epoch` is bumped by one, so that the delta replays on compute nodes the
+    /// same way a commit does.
+    #[named]
+    pub async fn import_ssts(&self, ssts: Vec<SstableInfo>) -> Result<()> {
+        let mut versioning_guard = write_lock!(self, versioning).await;
+        let (_, compaction_group_index) = self.compaction_groups_and_index().await;
+        let versioning = versioning_guard.deref_mut();
+
+        let mut group_ssts: BTreeMap<CompactionGroupId, Vec<SstableInfo>> = BTreeMap::new();
+        for sst in ssts {
+            match sst
+                .table_ids
+                .iter()
+                .find_map(|id| compaction_group_index.get(id))
+            {
+                Some(compaction_group_id) => {
+                    group_ssts
+                        .entry(*compaction_group_id)
+                        .or_default()
+                        .push(sst);
+                }
+                None => {
+                    tracing::warn!(
+                        "skip importing SST {}: none of its tables belongs to a compaction group",
+                        sst.get_id(),
+                    );
+                }
+            }
+        }
+        if group_ssts.is_empty() {
+            return Ok(());
         }
+
+        let old_version = versioning.current_version.clone();
+        let new_version_id = old_version.id + 1;
+        let epoch = old_version.max_committed_epoch + 1;
+        let mut new_version_delta = BTreeMapEntryTransaction::new_insert(
+            &mut versioning.hummock_version_deltas,
+            new_version_id,
+            HummockVersionDelta {
+                prev_id: old_version.id,
+                safe_epoch: old_version.safe_epoch,
+                trivial_move: false,
+                fence_token: self.fence_token,
+                ..Default::default()
+            },
+        );
+        let mut new_hummock_version = old_version;
+        new_version_delta.id = new_version_id;
+        new_hummock_version.id = new_version_id;
+
+        let mut modified_compaction_groups = vec![];
+        for (compaction_group_id, group_sstables) in group_ssts {
+            modified_compaction_groups.push(compaction_group_id);
+            let group_deltas = &mut new_version_delta
+                .group_deltas
+                .entry(compaction_group_id)
+                .or_default()
+                .group_deltas;
+            let version_l0 = new_hummock_version
+                .get_compaction_group_levels_mut(compaction_group_id)
+                .l0
+                .as_mut()
+                .expect("Expect level 0 is not empty");
+            let l0_sub_level_id = epoch;
+            let group_delta = GroupDelta {
+                delta_type: Some(DeltaType::IntraLevel(IntraLevelDelta {
+                    level_idx: 0,
+                    inserted_table_infos: group_sstables.clone(),
+                    l0_sub_level_id,
+                    ..Default::default()
+                })),
+            };
+            group_deltas.push(group_delta);
+
+            add_new_sub_level(
+                version_l0,
+                l0_sub_level_id,
+                LevelType::Overlapping,
+                group_sstables,
+            );
+        }
+
+        new_version_delta.max_committed_epoch = epoch;
+        new_hummock_version.max_committed_epoch = epoch;
+
+        commit_multi_var!(self, None, Transaction::default(), new_version_delta)?;
+        versioning.current_version = new_hummock_version;
+
+        self.latest_snapshot.rcu(|snapshot| HummockSnapshot {
+            committed_epoch: snapshot.committed_epoch.max(epoch),
+            current_epoch: snapshot.current_epoch.max(epoch),
+        });
+
+        self.env
+            .notification_manager()
+            .notify_hummock_without_version(
+                Operation::Add,
+                Info::HummockVersionDeltas(risingwave_pb::hummock::HummockVersionDeltas {
+                    version_deltas: vec![versioning
+                        .hummock_version_deltas
+                        .last_key_value()
+                        .unwrap()
+                        .1
+                        .clone()],
+                }),
+            );
+
+        drop(versioning_guard);
+        for id in modified_compaction_groups {
+            self.try_send_compaction_request(id, compact_task::TaskType::Dynamic);
+        }
+        Ok(())
+    }
+
+    /// Collects per-compaction-group statistics for an external compactor autoscaler, along
+    /// with a recommended number of compactor nodes.
+    ///
+    /// The ingest rate is averaged over the interval since the previous call, so callers are
+    /// expected to poll this periodically. The first poll reports a rate of 0.
+    #[named]
+    pub async fn get_compaction_scaling_hint(&self) -> (Vec<CompactionGroupScalingStats>, u32) {
+        let versioning_guard = read_lock!(self, versioning).await;
+        let current_version = &versioning_guard.current_version;
+        let now = Instant::now();
+        let mut ingest_stats = self.group_ingest_stats.lock();
+        let mut ingest_rates: HashMap<CompactionGroupId, u64> = HashMap::new();
+        if let Some((last_time, last_bytes)) = &ingest_stats.last_poll {
+            let elapsed = now.duration_since(*last_time).as_secs_f64();
+            if elapsed > 0.0 {
+                for (group_id, bytes) in &ingest_stats.accumulated_bytes {
+                    let delta = bytes - last_bytes.get(group_id).copied().unwrap_or(0);
+                    ingest_rates.insert(*group_id, (delta as f64 / elapsed) as u64);
+                }
+            }
+        }
+        ingest_stats.last_poll = Some((now, ingest_stats.accumulated_bytes.clone()));
+        drop(ingest_stats);
+
+        let group_stats = current_version
+            .levels
+            .keys()
+            .map(|group_id| CompactionGroupScalingStats {
+                group_id: *group_id,
+                pending_compaction_bytes: pending_compaction_bytes(current_version, *group_id),
+                ingest_bytes_per_sec: ingest_rates.get(group_id).copied().unwrap_or(0),
+            })
+            .collect_vec();
+        let total_pending_bytes: u64 = group_stats
+            .iter()
+            .map(|s| s.pending_compaction_bytes)
+            .sum();
+        // Always recommend at least one compactor so that new ingestion doesn't stall.
+        let recommended_capacity = ((total_pending_bytes + PENDING_BYTES_PER_COMPACTOR - 1)
+            / PENDING_BYTES_PER_COMPACTOR)
+            .clamp(1, u32::MAX as u64) as u32;
+        (group_stats, recommended_capacity)
+    }
+
+    /// Acquires a lease that prevents the safe epoch from advancing past `epoch` until the
+    /// lease expires or is released. Used by long-running batch scans that may outlive the
+    /// minimal pinned snapshot.
+    pub fn acquire_iterator_lease(
+        &self,
+        epoch: HummockEpoch,
+        ttl: Duration,
+    ) -> HummockIteratorLeaseId {
+        let ttl = ttl.min(MAX_ITERATOR_LEASE_TTL);
+        let mut guard = self.iterator_leases.lock();
+        guard.next_lease_id += 1;
+        let lease_id = guard.next_lease_id;
+        guard.leases.insert(
+            lease_id,
+            IteratorLease {
+                epoch,
+                expires_at: Instant::now() + ttl,
+            },
+        );
+        lease_id
+    }
+
+    /// Extends the expiry of an existing lease. Returns false if the lease has already expired
+    /// or is unknown, in which case the caller must recreate its iterator.
+    pub fn renew_iterator_lease(&self, lease_id: HummockIteratorLeaseId, ttl: Duration) -> bool {
+        let ttl = ttl.min(MAX_ITERATOR_LEASE_TTL);
+        let mut guard = self.iterator_leases.lock();
+        match guard.leases.get_mut(&lease_id) {
+            Some(lease) if lease.expires_at > Instant::now() => {
+                lease.expires_at = Instant::now() + ttl;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn release_iterator_lease(&self, lease_id: HummockIteratorLeaseId) {
+        self.iterator_leases.lock().leases.remove(&lease_id);
+    }
+
+    /// Returns the epoch pinned by an unexpired lease, e.g. to resolve an exported snapshot
+    /// token back to the epoch it protects. Returns `None` if the lease has expired or is
+    /// unknown, in which case the underlying data may already have been reclaimed.
+    pub fn get_iterator_lease_epoch(
+        &self,
+        lease_id: HummockIteratorLeaseId,
+    ) -> Option<HummockEpoch> {
+        let guard = self.iterator_leases.lock();
+        match guard.leases.get(&lease_id) {
+            Some(lease) if lease.expires_at > Instant::now() => Some(lease.epoch),
+            _ => None,
+        }
+    }
+
+    /// Returns the minimum epoch pinned by unexpired iterator leases, pruning expired leases
+    /// along the way.
+    fn min_leased_epoch(&self) -> Option<HummockEpoch> {
+        let mut guard = self.iterator_leases.lock();
+        let now = Instant::now();
+        guard.leases.retain(|_, lease| lease.expires_at > now);
+        guard.leases.values().map(|lease| lease.epoch).min()
     }
 
     pub async fn get_new_sst_ids(&self, number: u32) -> Result<SstIdRange> {
@@ -1967,17 +2744,64 @@ where
             Excluded(old_checkpoint_id),
             Included(new_checkpoint_id),
         ));
+        let checkpoint_version_copy = versioning.checkpoint_version.clone();
         #[cfg(test)]
         {
             drop(versioning_guard);
             self.check_state_consistency().await;
         }
+        #[cfg(not(test))]
+        drop(versioning_guard);
+        self.maybe_export_version_checkpoint(&checkpoint_version_copy)
+            .await;
         self.metrics
             .checkpoint_version_id
             .set(new_checkpoint_id as i64);
         Ok(new_checkpoint_id - old_checkpoint_id)
     }
 
+    /// Enables periodic export of the full checkpoint version to `object_store` under
+    /// [`HUMMOCK_VERSION_CHECKPOINT_PATH`], at most once per `interval`. The export is
+    /// best-effort: the meta store checkpoint remains the source of truth and export failures
+    /// only log a warning.
+    pub fn init_version_checkpoint_export(&self, object_store: ObjectStoreRef, interval: Duration) {
+        *self.version_checkpoint_export.lock() = Some(VersionCheckpointExport {
+            object_store,
+            interval,
+            last_export: None,
+        });
+    }
+
+    async fn maybe_export_version_checkpoint(&self, checkpoint_version: &HummockVersion) {
+        let object_store = {
+            let mut guard = self.version_checkpoint_export.lock();
+            match guard.as_mut() {
+                Some(export)
+                    if export
+                        .last_export
+                        .map_or(true, |last| last.elapsed() >= export.interval) =>
+                {
+                    export.last_export = Some(Instant::now());
+                    export.object_store.clone()
+                }
+                _ => return,
+            }
+        };
+        if let Err(err) = object_store
+            .upload(
+                HUMMOCK_VERSION_CHECKPOINT_PATH,
+                checkpoint_version.encode_to_vec().into(),
+            )
+            .await
+        {
+            tracing::warn!(
+                "Failed to export version checkpoint {}: {:#?}",
+                checkpoint_version.id,
+                err
+            );
+        }
+    }
+
     #[named]
     pub async fn get_min_pinned_version_id(&self) -> HummockVersionId {
         read_lock!(self, versioning).await.min_pinned_version_id()
@@ -2030,6 +2854,128 @@ where
         read_lock!(self, versioning).await.current_version.clone()
     }
 
+    /// Lists wall-clock commit times of recent epochs, oldest first.
+    #[named]
+    pub async fn list_epoch_commit_times(&self) -> Vec<EpochCommitTime> {
+        read_lock!(self, versioning)
+            .await
+            .epoch_commit_times
+            .iter()
+            .map(|(epoch, commit_ts_ms)| EpochCommitTime {
+                epoch: *epoch,
+                commit_ts_ms: *commit_ts_ms,
+            })
+            .collect_vec()
+    }
+
+    /// Lists per-level SST counts and sizes of the current version, for the
+    /// `rw_hummock_sstables` system table.
+    #[named]
+    pub async fn list_level_summaries(&self) -> Vec<LevelSummary> {
+        let current_version = read_lock!(self, versioning).await.current_version.clone();
+        let mut summaries = vec![];
+        for (compaction_group_id, levels) in &current_version.levels {
+            if let Some(l0) = &levels.l0 {
+                summaries.push(LevelSummary {
+                    compaction_group_id: *compaction_group_id,
+                    level_idx: 0,
+                    sst_count: l0
+                        .sub_levels
+                        .iter()
+                        .map(|sub_level| sub_level.table_infos.len())
+                        .sum::<usize>() as u32,
+                    total_file_size: l0.total_file_size,
+                    sub_level_count: l0.sub_levels.len() as u32,
+                });
+            }
+            for level in &levels.levels {
+                summaries.push(LevelSummary {
+                    compaction_group_id: *compaction_group_id,
+                    level_idx: level.level_idx,
+                    sst_count: level.table_infos.len() as u32,
+                    total_file_size: level.total_file_size,
+                    sub_level_count: 0,
+                });
+            }
+        }
+        summaries
+    }
+
+    /// Records SSTs reported corrupted by the compactor scrub service, so that reads can avoid
+    /// them and operators can quarantine the objects.
+    #[named]
+    pub async fn report_corrupted_ssts(&self, sst_ids: Vec<HummockSstableId>) {
+        if sst_ids.is_empty() {
+            return;
+        }
+        tracing::error!("Corrupted SSTs reported by scrub: {:?}", sst_ids);
+        let mut versioning_guard = write_lock!(self, versioning).await;
+        versioning_guard.corrupted_sst_ids.extend(sst_ids);
+    }
+
+    /// Lists SSTs that have been reported corrupted by the compactor scrub service.
+    #[named]
+    pub async fn list_corrupted_ssts(&self) -> Vec<HummockSstableId> {
+        read_lock!(self, versioning)
+            .await
+            .corrupted_sst_ids
+            .iter()
+            .copied()
+            .collect_vec()
+    }
+
+    /// Summarizes what changed between two hummock versions: SSTs inserted and removed per
+    /// level per compaction group, epoch advancement and total file size delta. Both versions
+    /// are rebuilt by folding the in-memory version deltas, so `from_id` must not precede the
+    /// checkpoint version and `to_id` must not exceed the current version.
+    #[named]
+    pub async fn diff_versions(
+        &self,
+        from_id: HummockVersionId,
+        to_id: HummockVersionId,
+    ) -> Result<VersionDiff> {
+        if from_id > to_id {
+            return Err(Error::Internal(anyhow::anyhow!(
+                "from version {} is newer than to version {}",
+                from_id,
+                to_id
+            )));
+        }
+        let versioning = read_lock!(self, versioning).await;
+        let rebuild = |id: HummockVersionId| -> Result<HummockVersion> {
+            if id == versioning.current_version.id {
+                return Ok(versioning.current_version.clone());
+            }
+            if id < versioning.checkpoint_version.id {
+                return Err(Error::Internal(anyhow::anyhow!(
+                    "version {} precedes checkpoint version {} and cannot be rebuilt",
+                    id,
+                    versioning.checkpoint_version.id
+                )));
+            }
+            let mut redo_state = versioning.checkpoint_version.clone();
+            for version_delta in versioning.hummock_version_deltas.values() {
+                if redo_state.id == id {
+                    break;
+                }
+                if version_delta.prev_id == redo_state.id {
+                    redo_state.apply_version_delta(version_delta);
+                }
+            }
+            if redo_state.id != id {
+                return Err(Error::Internal(anyhow::anyhow!(
+                    "version {} is not reconstructible from version deltas",
+                    id
+                )));
+            }
+            Ok(redo_state)
+        };
+        let from_version = rebuild(from_id)?;
+        let to_version = rebuild(to_id)?;
+        drop(versioning);
+        Ok(diff_hummock_versions(&from_version, &to_version))
+    }
+
     /// Get version deltas from meta store
     #[cfg_attr(coverage, no_coverage)]
     pub async fn list_version_deltas(
@@ -2130,6 +3076,80 @@ where
         versioning_guard.current_version.clone()
     }
 
+    /// Truncates all data above `epoch`: invalidates every version delta committed after `epoch`
+    /// and rebuilds the current version from the checkpoint, effectively rewinding
+    /// `max_committed_epoch`. Used by deterministic replay tooling and disaster drills.
+    ///
+    /// As a guard, commit epochs must have been disabled beforehand (see
+    /// [`HummockManager::disable_commit_epoch`]), so the rewind cannot race with ongoing
+    /// commits. Streaming jobs must be stopped and the cluster recovered afterwards, as compute
+    /// nodes may still refer to the invalidated versions. Fails if the checkpoint version has
+    /// already advanced past `epoch`.
+    #[named]
+    pub async fn truncate_above_epoch(&self, epoch: HummockEpoch) -> Result<HummockVersion> {
+        let mut versioning_guard = write_lock!(self, versioning).await;
+        let versioning = versioning_guard.deref_mut();
+        if !versioning.disable_commit_epochs {
+            return Err(Error::Internal(anyhow::anyhow!(
+                "refuse to truncate above epoch {}: commit epochs must be disabled first",
+                epoch
+            )));
+        }
+        if epoch >= versioning.current_version.max_committed_epoch {
+            return Ok(versioning.current_version.clone());
+        }
+        if versioning.checkpoint_version.max_committed_epoch > epoch {
+            return Err(Error::Internal(anyhow::anyhow!(
+                "cannot truncate above epoch {}: checkpoint version {} has already committed \
+                 epoch {}",
+                epoch,
+                versioning.checkpoint_version.id,
+                versioning.checkpoint_version.max_committed_epoch
+            )));
+        }
+
+        // Deltas are ordered by version id and their committed epochs are non-decreasing, so
+        // every delta from the first one exceeding `epoch` on is invalidated. This includes
+        // subsequent compaction deltas, as they may reference SSTs containing newer data.
+        let invalid_ids = versioning
+            .hummock_version_deltas
+            .iter()
+            .skip_while(|(_, delta)| delta.max_committed_epoch <= epoch)
+            .map(|(id, _)| *id)
+            .collect_vec();
+        let mut hummock_version_deltas =
+            BTreeMapTransaction::new(&mut versioning.hummock_version_deltas);
+        for id in invalid_ids {
+            hummock_version_deltas.remove(id);
+        }
+        commit_multi_var!(self, None, Transaction::default(), hummock_version_deltas)?;
+
+        // Rebuild the current version by replaying the surviving deltas on the checkpoint.
+        let mut redo_state = versioning.checkpoint_version.clone();
+        for version_delta in versioning.hummock_version_deltas.values() {
+            if version_delta.prev_id == redo_state.id {
+                redo_state.apply_version_delta(version_delta);
+            }
+        }
+        versioning.current_version = redo_state;
+        versioning.branched_ssts = versioning.current_version.build_branched_sst_info();
+        self.latest_snapshot.store(
+            HummockSnapshot {
+                committed_epoch: versioning.current_version.max_committed_epoch,
+                current_epoch: versioning.current_version.max_committed_epoch,
+            }
+            .into(),
+        );
+
+        tracing::info!(
+            "truncated hummock versions above epoch {}, current version {} at epoch {}",
+            epoch,
+            versioning.current_version.id,
+            versioning.current_version.max_committed_epoch
+        );
+        Ok(versioning.current_version.clone())
+    }
+
     /// Triggers compacitons to specified compaction groups.
     /// Don't wait for compaction finish
     pub async fn trigger_compaction_deterministic(
@@ -2236,17 +3256,21 @@ where
     ) -> Result<()> {
         let start_time = Instant::now();
 
-        // 1. Get idle compactor.
+        // 1. Get idle compactor. Since manual compaction is in the highest priority class, it
+        // may preempt a queued lower-priority assignment when all compactors are busy.
         let compactor = match self.get_idle_compactor().await {
             Some(compactor) => compactor,
-            None => {
-                tracing::warn!("trigger_manual_compaction No compactor is available.");
-                return Err(anyhow::anyhow!(
-                    "trigger_manual_compaction No compactor is available. compaction_group {}",
-                    compaction_group
-                )
-                .into());
-            }
+            None => match self.preempt_assignment(TaskPriority::Manual).await {
+                Some(compactor) => compactor,
+                None => {
+                    tracing::warn!("trigger_manual_compaction No compactor is available.");
+                    return Err(anyhow::anyhow!(
+                        "trigger_manual_compaction No compactor is available. compaction_group {}",
+                        compaction_group
+                    )
+                    .into());
+                }
+            },
         };
 
         // 2. Get manual compaction task.
@@ -2317,6 +3341,75 @@ where
         Ok(())
     }
 
+    /// `compact_range`-style entry point for manual compaction, keyed by state table id.
+    ///
+    /// Resolves the compaction group, the input level and the key range of `table_id` internally
+    /// and then delegates to [`HummockManager::trigger_manual_compaction`], so that callers don't
+    /// need to know SST ids or raw key ranges. The optional epoch bounds are encoded into the
+    /// full-key bounds of the key range; since SSTs only record their boundary keys, they narrow
+    /// the selection best-effort instead of filtering exactly by epoch.
+    pub async fn trigger_compaction_for_table(
+        &self,
+        table_id: StateTableId,
+        start_epoch: Option<HummockEpoch>,
+        end_epoch: Option<HummockEpoch>,
+    ) -> Result<()> {
+        let (_, index) = self.compaction_groups_and_index().await;
+        let compaction_group = match index.get(&table_id) {
+            Some(compaction_group) => *compaction_group,
+            None => {
+                return Err(Error::Internal(anyhow::anyhow!(
+                    "table {} is not registered to any compaction group",
+                    table_id
+                )));
+            }
+        };
+
+        // Pick the lowest level that contains SSTs of the table as the input level.
+        let version = self.get_current_version().await;
+        let levels = version.get_compaction_group_levels(compaction_group);
+        let contains_table = |sst: &SstableInfo| sst.table_ids.contains(&table_id);
+        let level = if levels.l0.as_ref().map_or(false, |l0| {
+            l0.sub_levels
+                .iter()
+                .any(|sub_level| sub_level.table_infos.iter().any(contains_table))
+        }) {
+            0
+        } else {
+            match levels
+                .levels
+                .iter()
+                .find(|level| level.table_infos.iter().any(contains_table))
+            {
+                Some(level) => level.level_idx as usize,
+                None => {
+                    return Err(Error::Internal(anyhow::anyhow!(
+                        "no SST of table {} to compact",
+                        table_id
+                    )));
+                }
+            }
+        };
+
+        // Full keys are encoded as `table_id | table_key | epoch`, so the table spans the range
+        // between its table id prefix and the next one.
+        let prefix = table_id.to_be_bytes().to_vec();
+        let key_range = KeyRange {
+            left: key_with_epoch(prefix.clone(), end_epoch.unwrap_or(HummockEpoch::MAX)),
+            right: key_with_epoch(next_key(&prefix), start_epoch.unwrap_or(0)),
+            right_exclusive: true,
+        };
+
+        let option = ManualCompactionOption {
+            sst_ids: vec![],
+            key_range,
+            internal_table_id: HashSet::from_iter([table_id]),
+            level,
+        };
+        self.trigger_manual_compaction(compaction_group, option)
+            .await
+    }
+
     pub fn compactor_manager_ref_for_test(&self) -> CompactorManagerRef {
         self.compactor_manager.clone()
     }
@@ -2334,6 +3427,36 @@ where
     pub fn cluster_manager(&self) -> &ClusterManagerRef<S> {
         &self.cluster_manager
     }
+
+    /// Returns the call sites that waited longest on the `compaction` and `versioning` locks,
+    /// ordered by total wait time descending. `top_n == 0` returns all of them.
+    pub fn get_lock_contention(&self, top_n: usize) -> Vec<LockContention> {
+        let mut contentions = self
+            .compaction
+            .collect_wait_stats()
+            .into_iter()
+            .chain(self.versioning.collect_wait_stats())
+            .filter_map(|(label_values, stats)| {
+                let (call_site, lock_name, lock_mode) = match label_values.as_slice() {
+                    [call_site, lock_name, lock_mode] => (*call_site, *lock_name, *lock_mode),
+                    _ => return None,
+                };
+                Some(LockContention {
+                    call_site: call_site.to_string(),
+                    lock_name: lock_name.to_string(),
+                    lock_mode: lock_mode.to_string(),
+                    wait_count: stats.wait_count,
+                    total_wait_time_ms: stats.total_wait.as_millis() as u64,
+                    max_wait_time_ms: stats.max_wait.as_millis() as u64,
+                })
+            })
+            .collect_vec();
+        contentions.sort_by(|a, b| b.total_wait_time_ms.cmp(&a.total_wait_time_ms));
+        if top_n > 0 {
+            contentions.truncate(top_n);
+        }
+        contentions
+    }
 }
 
 fn drop_sst(
@@ -2362,11 +3485,13 @@ fn gen_version_delta<'a>(
     compact_task: &CompactTask,
     trivial_move: bool,
     deterministic_mode: bool,
+    fence_token: u64,
 ) -> HummockVersionDelta {
     let mut version_delta = HummockVersionDelta {
         prev_id: old_version.id,
         max_committed_epoch: old_version.max_committed_epoch,
         trivial_move,
+        fence_token,
         ..Default::default()
     };
     let group_deltas = &mut version_delta
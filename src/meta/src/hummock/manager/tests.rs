@@ -15,8 +15,10 @@
 use std::borrow::Borrow;
 use std::cmp::Ordering;
 use std::sync::Arc;
+use std::time::Duration;
 
 use itertools::Itertools;
+use prost::Message;
 use risingwave_common::util::epoch::INVALID_EPOCH;
 use risingwave_hummock_sdk::compact::compact_task_to_string;
 use risingwave_hummock_sdk::compaction_group::hummock_version_ext::HummockVersionExt;
@@ -26,11 +28,14 @@ use risingwave_hummock_sdk::table_stats::{to_prost_table_stats_map, TableStats,
 use risingwave_hummock_sdk::{
     HummockContextId, HummockEpoch, HummockVersionId, LocalSstableInfo, FIRST_VERSION_ID,
 };
+use risingwave_object_store::object::object_metrics::ObjectStoreMetrics;
+use risingwave_object_store::object::{InMemObjectStore, ObjectStore, ObjectStoreImpl};
 use risingwave_pb::common::{HostAddress, WorkerType};
 use risingwave_pb::hummock::compact_task::TaskStatus;
 use risingwave_pb::hummock::version_update_payload::Payload;
 use risingwave_pb::hummock::{
-    HummockPinnedSnapshot, HummockPinnedVersion, HummockSnapshot, KeyRange, SstableInfo,
+    HummockPinnedSnapshot, HummockPinnedVersion, HummockSnapshot, HummockVersion, KeyRange,
+    SstableInfo,
 };
 
 use crate::hummock::compaction::ManualCompactionOption;
@@ -38,6 +43,7 @@ use crate::hummock::error::Error;
 use crate::hummock::test_utils::*;
 use crate::hummock::{
     start_compaction_scheduler, CompactionPickParma, CompactionScheduler, HummockManagerRef,
+    ReportTask, HUMMOCK_VERSION_CHECKPOINT_PATH,
 };
 use crate::manager::WorkerId;
 use crate::model::MetadataModel;
@@ -209,6 +215,75 @@ async fn test_hummock_compaction_task() {
         .unwrap());
 }
 
+#[tokio::test]
+async fn test_hummock_report_compact_tasks_batch() {
+    let (_, hummock_manager, _, worker_node) = setup_compute_env(80).await;
+
+    // Add some sstables and commit.
+    let epoch: u64 = 1;
+    let original_tables = generate_test_tables(epoch, get_sst_ids(&hummock_manager, 2).await);
+    register_sstable_infos_to_compaction_group(
+        &hummock_manager,
+        &original_tables,
+        StaticCompactionGroupId::StateDefault.into(),
+    )
+    .await;
+    commit_from_meta_node(
+        hummock_manager.borrow(),
+        epoch,
+        to_local_sstable_info(&original_tables),
+    )
+    .await
+    .unwrap();
+
+    // Get a compaction task and assign it.
+    let compactor_manager = hummock_manager.compactor_manager_ref_for_test();
+    compactor_manager.add_compactor(worker_node.id, u64::MAX);
+    let compactor = hummock_manager.get_idle_compactor().await.unwrap();
+    let mut compact_task = hummock_manager
+        .get_compact_task(
+            StaticCompactionGroupId::StateDefault.into(),
+            CompactionPickParma::new_base_parma(),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+    hummock_manager
+        .assign_compaction_task(&compact_task, compactor.context_id())
+        .await
+        .unwrap();
+    compact_task.set_task_status(TaskStatus::Success);
+
+    // A task unknown to the manager fails the assignment check without affecting the rest of
+    // the batch.
+    let mut unknown_task = compact_task.clone();
+    unknown_task.task_id += 100;
+
+    let version_id = hummock_manager.get_current_version().await.id;
+    let mut report_tasks = vec![
+        ReportTask {
+            compact_task,
+            table_stats_change: None,
+        },
+        ReportTask {
+            compact_task: unknown_task,
+            table_stats_change: None,
+        },
+    ];
+    assert_eq!(
+        hummock_manager
+            .report_compact_tasks(compactor.context_id(), &mut report_tasks)
+            .await
+            .unwrap(),
+        vec![true, false]
+    );
+    // Only the successful task generates a version delta.
+    assert_eq!(
+        hummock_manager.get_current_version().await.id,
+        version_id + 1
+    );
+}
+
 #[tokio::test]
 async fn test_hummock_table() {
     let (_env, hummock_manager, _cluster_manager, _worker_node) = setup_compute_env(80).await;
@@ -751,7 +826,7 @@ async fn test_invalid_sst_id() {
         .map(|LocalSstableInfo { sst_info, .. }| (sst_info.id, WorkerId::MAX))
         .collect();
     let error = hummock_manager
-        .commit_epoch(epoch, ssts.clone(), sst_to_worker)
+        .commit_epoch(epoch, ssts.clone(), sst_to_worker, None)
         .await
         .unwrap_err();
     assert!(matches!(error, Error::InvalidSst(1)));
@@ -761,7 +836,7 @@ async fn test_invalid_sst_id() {
         .map(|LocalSstableInfo { sst_info, .. }| (sst_info.id, context_id))
         .collect();
     hummock_manager
-        .commit_epoch(epoch, ssts, sst_to_worker)
+        .commit_epoch(epoch, ssts, sst_to_worker, None)
         .await
         .unwrap();
 }
@@ -1202,7 +1277,7 @@ async fn test_version_stats() {
         .map(|LocalSstableInfo { sst_info, .. }| (sst_info.id, worker_node.id))
         .collect();
     hummock_manager
-        .commit_epoch(epoch, ssts, sst_to_worker)
+        .commit_epoch(epoch, ssts, sst_to_worker, None)
         .await
         .unwrap();
 
@@ -1277,3 +1352,63 @@ async fn test_version_stats() {
     assert_eq!(compact_table3_stats.total_value_size, 0);
     assert_eq!(compact_table3_stats.total_key_size, 0);
 }
+
+#[tokio::test]
+async fn test_external_compaction_session() {
+    let (_env, hummock_manager, _cluster_manager, _worker_node) = setup_compute_env(80).await;
+    let context_id = hummock_manager
+        .register_external_compaction_context()
+        .await
+        .unwrap();
+    assert!(hummock_manager.is_external_compaction_context(context_id));
+    assert!(hummock_manager.check_context(context_id).await);
+    // No compaction pending.
+    assert!(hummock_manager
+        .pull_external_compact_task(context_id)
+        .await
+        .unwrap()
+        .is_none());
+    hummock_manager
+        .unregister_external_compaction_context(context_id)
+        .await
+        .unwrap();
+    assert!(!hummock_manager.is_external_compaction_context(context_id));
+    assert!(!hummock_manager.check_context(context_id).await);
+    // Pulling with a closed session is rejected.
+    assert!(matches!(
+        hummock_manager
+            .pull_external_compact_task(context_id)
+            .await
+            .unwrap_err(),
+        Error::InvalidContext(_)
+    ));
+}
+
+#[tokio::test]
+async fn test_version_checkpoint_export() {
+    let (_env, hummock_manager, _cluster_manager, worker_node) = setup_compute_env(80).await;
+    let context_id = worker_node.id;
+    let object_store = Arc::new(ObjectStoreImpl::InMem(
+        InMemObjectStore::new().monitored(Arc::new(ObjectStoreMetrics::unused())),
+    ));
+
+    // Export is disabled until initialized.
+    add_test_tables(hummock_manager.as_ref(), context_id).await;
+    assert!(hummock_manager.proceed_version_checkpoint().await.unwrap() > 0);
+    assert!(object_store
+        .read(HUMMOCK_VERSION_CHECKPOINT_PATH, None)
+        .await
+        .is_err());
+
+    hummock_manager.init_version_checkpoint_export(object_store.clone(), Duration::ZERO);
+    add_ssts(3, hummock_manager.as_ref(), context_id).await;
+    assert!(hummock_manager.proceed_version_checkpoint().await.unwrap() > 0);
+    let exported = HummockVersion::decode(
+        object_store
+            .read(HUMMOCK_VERSION_CHECKPOINT_PATH, None)
+            .await
+            .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(exported.id, hummock_manager.get_current_version().await.id);
+}
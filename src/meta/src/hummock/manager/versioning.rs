@@ -13,24 +13,26 @@
 // limitations under the License.
 
 use std::cmp;
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
 use std::ops::RangeBounds;
 
 use function_name::named;
 use itertools::Itertools;
+use risingwave_hummock_sdk::compaction_group::hummock_version_ext::HummockVersionUpdateExt;
 use risingwave_hummock_sdk::{
-    CompactionGroupId, HummockContextId, HummockSstableId, HummockVersionId,
+    CompactionGroupId, HummockContextId, HummockEpoch, HummockSstableId, HummockVersionId,
 };
 use risingwave_pb::common::WorkerNode;
 use risingwave_pb::hummock::{
     HummockPinnedSnapshot, HummockPinnedVersion, HummockVersion, HummockVersionDelta,
-    HummockVersionStats,
+    HummockVersionStats, VersionDiff, VersionDiffLevel,
 };
 
 use crate::hummock::manager::worker::{HummockManagerEvent, HummockManagerEventSender};
 use crate::hummock::manager::{read_lock, write_lock};
 use crate::hummock::metrics_utils::trigger_safepoint_stat;
 use crate::hummock::HummockManager;
+use crate::model::BTreeMapTransaction;
 use crate::storage::MetaStore;
 
 /// `HummockVersionSafePoint` prevents hummock versions GE than it from being GC.
@@ -56,6 +58,9 @@ impl Drop for HummockVersionSafePoint {
 }
 
 #[derive(Default)]
+/// Max number of epoch commit time records kept in `Versioning::epoch_commit_times`.
+pub(super) const MAX_EPOCH_COMMIT_TIME_RECORDS: usize = 1024;
+
 pub struct Versioning {
     // Volatile states below
     /// Avoide commit epoch epochs
@@ -79,6 +84,11 @@ pub struct Versioning {
     /// `version_safe_points` is similar to `pinned_versions` expect for being a transient state.
     /// Hummock versions GE than min(safe_point) should not be GCed.
     pub version_safe_points: Vec<HummockVersionId>,
+    /// Wall-clock commit time of recent epochs, oldest first. Bounded by
+    /// [`MAX_EPOCH_COMMIT_TIME_RECORDS`].
+    pub epoch_commit_times: VecDeque<(HummockEpoch, u64)>,
+    /// SSTs reported corrupted by the compactor scrub service, to be quarantined.
+    pub corrupted_sst_ids: BTreeSet<HummockSstableId>,
 
     // Persistent states below
     /// Mapping from id of each hummock version which succeeds checkpoint to its
@@ -91,6 +101,24 @@ pub struct Versioning {
     pub version_stats: HummockVersionStats,
 }
 
+/// Summary of a branched SST reconciliation run. See
+/// [`Versioning::reconcile_branched_ssts`].
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct BranchedSstReconcileReport {
+    /// Branches that referenced a compaction group absent from the current version.
+    pub orphan_branches_removed: usize,
+    /// Branches whose divide version disagreed with the one in the current version.
+    pub stale_divide_versions_repaired: usize,
+    /// Entries that no longer described a branched SST and were dropped entirely.
+    pub entries_removed: usize,
+}
+
+impl BranchedSstReconcileReport {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
 impl Versioning {
     pub fn min_pinned_version_id(&self) -> HummockVersionId {
         let mut min_pinned_version_id = HummockVersionId::MAX;
@@ -129,6 +157,46 @@ impl Versioning {
             // deleted.
         }
     }
+
+    /// Reconciles `branched_ssts` with the current version in a single transaction: branches
+    /// referencing compaction groups absent from the current version are dropped, stale divide
+    /// versions are re-synchronized, and entries that no longer describe a branched SST are
+    /// removed. Such leftovers come from interrupted `sync_group` runs and previously required
+    /// manual meta intervention.
+    pub fn reconcile_branched_ssts(&mut self) -> BranchedSstReconcileReport {
+        let expected = self.current_version.build_branched_sst_info();
+        let mut report = BranchedSstReconcileReport::default();
+        let sst_ids = self.branched_ssts.keys().cloned().collect_vec();
+        let mut branched_ssts = BTreeMapTransaction::new(&mut self.branched_ssts);
+        for sst_id in sst_ids {
+            let truth = expected.get(&sst_id);
+            let mut entry = branched_ssts.get_mut(sst_id).unwrap();
+            entry.retain(
+                |group_id, divide_version| match truth.and_then(|t| t.get(group_id)) {
+                    Some(expected_divide_version) => {
+                        if divide_version != expected_divide_version {
+                            *divide_version = *expected_divide_version;
+                            report.stale_divide_versions_repaired += 1;
+                        }
+                        true
+                    }
+                    None => {
+                        report.orphan_branches_removed += 1;
+                        false
+                    }
+                },
+            );
+            // An entry with at most one branch at divide version 0 is not a branch at all.
+            let is_branched = entry.len() > 1 || entry.values().any(|v| *v != 0);
+            drop(entry);
+            if !is_branched {
+                branched_ssts.remove(sst_id);
+                report.entries_removed += 1;
+            }
+        }
+        branched_ssts.commit_memory();
+        report
+    }
 }
 
 impl<S> HummockManager<S>
@@ -194,14 +262,113 @@ where
         }
         trigger_safepoint_stat(&self.metrics, &wl.version_safe_points);
     }
+
+    /// Reconciles `branched_ssts` with the current version and reports what was repaired.
+    /// It is run periodically to clean up after interrupted group splits, and can also be
+    /// triggered on demand.
+    #[named]
+    pub async fn reconcile_branched_ssts(&self) -> BranchedSstReconcileReport {
+        let report = write_lock!(self, versioning).await.reconcile_branched_ssts();
+        if !report.is_empty() {
+            tracing::info!(
+                "reconciled branched SSTs: removed {} orphan branches, repaired {} stale divide \
+                 versions, removed {} degenerated entries",
+                report.orphan_branches_removed,
+                report.stale_divide_versions_repaired,
+                report.entries_removed
+            );
+        }
+        report
+    }
+}
+
+/// Computes which SSTs were inserted and removed per level per compaction group between two
+/// versions, along with the file size change. L0 sub levels are aggregated into level 0.
+pub(super) fn diff_hummock_versions(from: &HummockVersion, to: &HummockVersion) -> VersionDiff {
+    // (compaction group, level idx) -> sst id -> file size
+    let collect = |version: &HummockVersion| {
+        let mut ssts: BTreeMap<(u64, u32), HashMap<u64, u64>> = BTreeMap::new();
+        for (group_id, levels) in &version.levels {
+            if let Some(l0) = &levels.l0 {
+                let entry = ssts.entry((*group_id, 0)).or_default();
+                for sub_level in &l0.sub_levels {
+                    for sst in &sub_level.table_infos {
+                        entry.insert(sst.id, sst.file_size);
+                    }
+                }
+            }
+            for level in &levels.levels {
+                let entry = ssts.entry((*group_id, level.level_idx)).or_default();
+                for sst in &level.table_infos {
+                    entry.insert(sst.id, sst.file_size);
+                }
+            }
+        }
+        ssts
+    };
+    let from_ssts = collect(from);
+    let to_ssts = collect(to);
+
+    let empty = HashMap::new();
+    let mut diff_levels = vec![];
+    let mut total_file_size_delta = 0;
+    for key in from_ssts.keys().chain(to_ssts.keys()).cloned().sorted().dedup() {
+        let from_level = from_ssts.get(&key).unwrap_or(&empty);
+        let to_level = to_ssts.get(&key).unwrap_or(&empty);
+        let inserted_sst_ids = to_level
+            .keys()
+            .filter(|sst_id| !from_level.contains_key(sst_id))
+            .copied()
+            .sorted()
+            .collect_vec();
+        let removed_sst_ids = from_level
+            .keys()
+            .filter(|sst_id| !to_level.contains_key(sst_id))
+            .copied()
+            .sorted()
+            .collect_vec();
+        if inserted_sst_ids.is_empty() && removed_sst_ids.is_empty() {
+            continue;
+        }
+        let file_size_delta = inserted_sst_ids
+            .iter()
+            .map(|sst_id| to_level[sst_id] as i64)
+            .sum::<i64>()
+            - removed_sst_ids
+                .iter()
+                .map(|sst_id| from_level[sst_id] as i64)
+                .sum::<i64>();
+        total_file_size_delta += file_size_delta;
+        diff_levels.push(VersionDiffLevel {
+            compaction_group_id: key.0,
+            level_idx: key.1,
+            inserted_sst_ids,
+            removed_sst_ids,
+            file_size_delta,
+        });
+    }
+
+    VersionDiff {
+        from_id: from.id,
+        to_id: to.id,
+        from_max_committed_epoch: from.max_committed_epoch,
+        to_max_committed_epoch: to.max_committed_epoch,
+        total_file_size_delta,
+        levels: diff_levels,
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::collections::{BTreeMap, HashMap};
+
     use risingwave_hummock_sdk::HummockVersionId;
-    use risingwave_pb::hummock::{HummockPinnedVersion, HummockVersionDelta};
+    use risingwave_pb::hummock::hummock_version::Levels;
+    use risingwave_pb::hummock::{
+        HummockPinnedVersion, HummockVersion, HummockVersionDelta, Level, SstableInfo,
+    };
 
-    use crate::hummock::manager::versioning::Versioning;
+    use crate::hummock::manager::versioning::{diff_hummock_versions, Versioning};
 
     #[tokio::test]
     async fn test_extend_ssts_to_delete_from_deltas_trivial_move() {
@@ -240,4 +407,96 @@ mod tests {
         versioning.pinned_versions.clear();
         assert_eq!(versioning.min_pinned_version_id(), HummockVersionId::MAX);
     }
+
+    #[test]
+    fn test_reconcile_branched_ssts() {
+        let mut versioning = Versioning::default();
+        // Branch with a stale divide version plus a branch into a nonexistent group 2.
+        versioning
+            .branched_ssts
+            .insert(10, HashMap::from_iter([(1, 0), (2, 0)]));
+        // All branches reference nonexistent groups, so the whole entry is dropped.
+        versioning
+            .branched_ssts
+            .insert(11, HashMap::from_iter([(2, 1)]));
+        versioning.current_version = HummockVersion {
+            id: 1,
+            levels: HashMap::from_iter([(
+                1,
+                Levels {
+                    l0: Some(Default::default()),
+                    levels: vec![Level {
+                        level_idx: 1,
+                        table_infos: vec![
+                            SstableInfo {
+                                id: 10,
+                                divide_version: 1,
+                                ..Default::default()
+                            },
+                            SstableInfo {
+                                id: 12,
+                                ..Default::default()
+                            },
+                        ],
+                        ..Default::default()
+                    }],
+                },
+            )]),
+            ..Default::default()
+        };
+
+        let report = versioning.reconcile_branched_ssts();
+        assert_eq!(report.orphan_branches_removed, 2);
+        assert_eq!(report.stale_divide_versions_repaired, 1);
+        assert_eq!(report.entries_removed, 1);
+        assert_eq!(
+            versioning.branched_ssts,
+            BTreeMap::from_iter([(10, HashMap::from_iter([(1, 1)]))])
+        );
+
+        // A second run is a no-op.
+        assert!(versioning.reconcile_branched_ssts().is_empty());
+        assert_eq!(versioning.branched_ssts.len(), 1);
+    }
+
+    #[test]
+    fn test_diff_hummock_versions() {
+        let version_with_ssts = |id, max_committed_epoch, sst_ids: &[u64]| HummockVersion {
+            id,
+            max_committed_epoch,
+            levels: HashMap::from_iter([(
+                1,
+                Levels {
+                    l0: None,
+                    levels: vec![Level {
+                        level_idx: 1,
+                        table_infos: sst_ids
+                            .iter()
+                            .map(|sst_id| SstableInfo {
+                                id: *sst_id,
+                                file_size: 100,
+                                ..Default::default()
+                            })
+                            .collect(),
+                        ..Default::default()
+                    }],
+                },
+            )]),
+            ..Default::default()
+        };
+        let from = version_with_ssts(1, 10, &[1, 2, 3]);
+        let to = version_with_ssts(2, 20, &[3, 4]);
+        let diff = diff_hummock_versions(&from, &to);
+        assert_eq!(diff.from_id, 1);
+        assert_eq!(diff.to_id, 2);
+        assert_eq!(diff.from_max_committed_epoch, 10);
+        assert_eq!(diff.to_max_committed_epoch, 20);
+        assert_eq!(diff.total_file_size_delta, -100);
+        assert_eq!(diff.levels.len(), 1);
+        assert_eq!(diff.levels[0].compaction_group_id, 1);
+        assert_eq!(diff.levels[0].level_idx, 1);
+        assert_eq!(diff.levels[0].inserted_sst_ids, vec![4]);
+        assert_eq!(diff.levels[0].removed_sst_ids, vec![1, 2]);
+        assert_eq!(diff.levels[0].file_size_delta, -100);
+    }
 }
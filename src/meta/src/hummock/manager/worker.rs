@@ -14,7 +14,8 @@
 
 use std::time::Duration;
 
-use risingwave_hummock_sdk::HummockVersionId;
+use risingwave_hummock_sdk::{CompactionGroupId, HummockVersionId};
+use risingwave_pb::hummock::CompactTask;
 use sync_point::sync_point;
 use tokio::task::JoinHandle;
 use tokio_retry::strategy::{jitter, ExponentialBackoff};
@@ -27,12 +28,31 @@ use crate::storage::MetaStore;
 pub type HummockManagerEventSender = tokio::sync::mpsc::UnboundedSender<HummockManagerEvent>;
 pub type HummockManagerEventReceiver = tokio::sync::mpsc::UnboundedReceiver<HummockManagerEvent>;
 
+/// The number of shard workers processing [`HummockManagerEvent`]s. Events of the same compaction
+/// group are always routed to the same shard, so they are processed in the order they are sent,
+/// while events of different groups can be processed concurrently.
+const EVENT_SHARD_COUNT: usize = 4;
+
 pub enum HummockManagerEvent {
+    CancelCompactionTask(CompactTask),
     DropSafePoint(HummockVersionId),
     #[allow(dead_code)]
     Shutdown,
 }
 
+impl HummockManagerEvent {
+    /// The compaction group the event is scoped to, which determines the shard worker the event
+    /// is routed to. Events not scoped to any group are routed to shard 0.
+    fn compaction_group_id(&self) -> Option<CompactionGroupId> {
+        match self {
+            HummockManagerEvent::CancelCompactionTask(compact_task) => {
+                Some(compact_task.compaction_group_id)
+            }
+            HummockManagerEvent::DropSafePoint(_) | HummockManagerEvent::Shutdown => None,
+        }
+    }
+}
+
 impl<S> HummockManager<S>
 where
     S: MetaStore,
@@ -47,12 +67,42 @@ where
             .notification_manager()
             .insert_local_sender(local_notification_tx)
             .await;
+
+        // Spawn the shard workers. They exit after the dispatcher below returns and drops the
+        // shard senders.
+        let mut shard_senders = Vec::with_capacity(EVENT_SHARD_COUNT);
+        for shard_id in 0..EVENT_SHARD_COUNT {
+            let (shard_tx, mut shard_rx) =
+                tokio::sync::mpsc::unbounded_channel::<HummockManagerEvent>();
+            let hummock_manager = self.clone();
+            let queue_depth = hummock_manager
+                .metrics
+                .hummock_manager_event_queue_depth
+                .with_label_values(&[&shard_id.to_string()]);
+            tokio::spawn(async move {
+                while let Some(event) = shard_rx.recv().await {
+                    queue_depth.dec();
+                    hummock_manager.handle_hummock_manager_event(event).await;
+                }
+            });
+            shard_senders.push(shard_tx);
+        }
+
         let hummock_manager = self.clone();
         tokio::spawn(async move {
             loop {
                 tokio::select! {
                     notification = local_notification_rx.recv() => {
                         match notification {
+                            // Cancellation is scoped to a compaction group, so hand it over to
+                            // the shard workers instead of blocking the dispatcher on its retry
+                            // loop.
+                            Some(LocalNotification::CompactionTaskNeedCancel(compact_task)) => {
+                                hummock_manager.dispatch_event(
+                                    &shard_senders,
+                                    HummockManagerEvent::CancelCompactionTask(compact_task),
+                                );
+                            }
                             Some(notification) => {
                                 hummock_manager
                                     .handle_local_notification(notification)
@@ -65,12 +115,13 @@ where
                     }
                     hummock_manager_event = receiver.recv() => {
                         match hummock_manager_event {
+                            Some(HummockManagerEvent::Shutdown) => {
+                                tracing::info!("Hummock manager worker is stopped");
+                                return;
+                            }
                             Some(hummock_manager_event) => {
-                                if !hummock_manager
-                                    .handle_hummock_manager_event(hummock_manager_event)
-                                    .await {
-                                    return;
-                                }
+                                hummock_manager
+                                    .dispatch_event(&shard_senders, hummock_manager_event);
                             }
                             None => {
                                 return;
@@ -82,19 +133,66 @@ where
         })
     }
 
-    /// Returns false indicates to shutdown worker
-    async fn handle_hummock_manager_event(&self, event: HummockManagerEvent) -> bool {
+    /// Routes `event` to a shard worker by the hash of its compaction group id, so that events of
+    /// the same group are processed by a single worker in the order they are dispatched.
+    fn dispatch_event(
+        &self,
+        shard_senders: &[HummockManagerEventSender],
+        event: HummockManagerEvent,
+    ) {
+        let shard_id = event
+            .compaction_group_id()
+            .map_or(0, |group_id| group_id as usize % shard_senders.len());
+        if shard_senders[shard_id].send(event).is_ok() {
+            self.metrics
+                .hummock_manager_event_queue_depth
+                .with_label_values(&[&shard_id.to_string()])
+                .inc();
+        } else {
+            tracing::warn!("Hummock manager event shard worker {} is gone", shard_id);
+        }
+    }
+
+    async fn handle_hummock_manager_event(&self, event: HummockManagerEvent) {
         match event {
+            HummockManagerEvent::CancelCompactionTask(compact_task) => {
+                self.cancel_compaction_task_with_retry(compact_task).await;
+            }
             HummockManagerEvent::DropSafePoint(id) => {
                 self.unregister_safe_point(id).await;
                 sync_point!("UNREGISTER_HUMMOCK_VERSION_SAFE_POINT");
             }
             HummockManagerEvent::Shutdown => {
-                tracing::info!("Hummock manager worker is stopped");
-                return false;
+                unreachable!("`Shutdown` is handled by the dispatcher")
             }
         }
-        true
+    }
+
+    async fn cancel_compaction_task_with_retry(&self, compact_task: CompactTask) {
+        let retry_strategy = ExponentialBackoff::from_millis(10)
+            .max_delay(Duration::from_secs(60))
+            .map(jitter);
+        let task_id = compact_task.task_id;
+        tokio_retry::RetryIf::spawn(
+            retry_strategy,
+            || async {
+                let mut compact_task_mut = compact_task.clone();
+                if let Err(err) = self.cancel_compact_task_impl(&mut compact_task_mut).await {
+                    tracing::warn!(
+                        "Failed to cancel compaction task {}. {}. Will retry.",
+                        compact_task.task_id,
+                        err
+                    );
+                    return Err(err);
+                }
+                Ok(())
+            },
+            RetryableError::default(),
+        )
+        .await
+        .expect("retry until success");
+        tracing::info!("Cancelled compaction task {}", task_id);
+        sync_point!("AFTER_CANCEL_COMPACTION_TASK_ASYNC");
     }
 
     async fn handle_local_notification(&self, notification: LocalNotification) {
@@ -105,7 +203,7 @@ where
             LocalNotification::WorkerNodeIsDeleted(worker_node) => {
                 self.compactor_manager.remove_compactor(worker_node.id);
                 tokio_retry::RetryIf::spawn(
-                    retry_strategy.clone(),
+                    retry_strategy,
                     || async {
                         if let Err(err) = self.release_contexts(vec![worker_node.id]).await {
                             tracing::warn!(
@@ -124,31 +222,10 @@ where
                 tracing::info!("Released hummock context {}", worker_node.id);
                 sync_point!("AFTER_RELEASE_HUMMOCK_CONTEXTS_ASYNC");
             }
-            // TODO move `CompactionTaskNeedCancel` to `handle_hummock_manager_event`
-            // TODO extract retry boilerplate code
+            // `CompactionTaskNeedCancel` is intercepted by the dispatcher in `start_worker` and
+            // routed to the shard workers as [`HummockManagerEvent::CancelCompactionTask`].
             LocalNotification::CompactionTaskNeedCancel(compact_task) => {
-                let task_id = compact_task.task_id;
-                tokio_retry::RetryIf::spawn(
-                    retry_strategy.clone(),
-                    || async {
-                        let mut compact_task_mut = compact_task.clone();
-                        if let Err(err) = self.cancel_compact_task_impl(&mut compact_task_mut).await
-                        {
-                            tracing::warn!(
-                                "Failed to cancel compaction task {}. {}. Will retry.",
-                                compact_task.task_id,
-                                err
-                            );
-                            return Err(err);
-                        }
-                        Ok(())
-                    },
-                    RetryableError::default(),
-                )
-                .await
-                .expect("retry until success");
-                tracing::info!("Cancelled compaction task {}", task_id);
-                sync_point!("AFTER_CANCEL_COMPACTION_TASK_ASYNC");
+                self.cancel_compaction_task_with_retry(compact_task).await;
             }
         }
     }
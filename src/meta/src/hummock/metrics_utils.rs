@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashSet};
 use std::sync::atomic::Ordering;
 use std::time::{SystemTime, UNIX_EPOCH};
 
@@ -21,7 +21,7 @@ use prost::Message;
 use risingwave_hummock_sdk::compaction_group::hummock_version_ext::HummockVersionExt;
 use risingwave_hummock_sdk::{CompactionGroupId, HummockContextId, HummockEpoch, HummockVersionId};
 use risingwave_pb::hummock::{
-    HummockPinnedSnapshot, HummockPinnedVersion, HummockVersion, HummockVersionStats,
+    HummockPinnedSnapshot, HummockPinnedVersion, HummockVersion, HummockVersionStats, LevelScore,
 };
 
 use crate::hummock::compaction::CompactStatus;
@@ -115,6 +115,11 @@ pub fn trigger_sst_stat(
         .with_label_values(&[&level_label])
         .set(sst_num as i64);
 
+    metrics
+        .compaction_group_pending_bytes
+        .with_label_values(&[&compaction_group_id.to_string()])
+        .set(pending_compaction_bytes(current_version, compaction_group_id) as i64);
+
     let previous_time = metrics.time_after_last_observation.load(Ordering::Relaxed);
     let current_time = SystemTime::now()
         .duration_since(UNIX_EPOCH)
@@ -148,6 +153,48 @@ pub fn trigger_sst_stat(
     }
 }
 
+/// Reports the dynamic level selector's per-level compaction scores and size debt of the given
+/// group. Each select level keeps only its largest score.
+pub fn trigger_level_score_stat(
+    metrics: &MetaMetrics,
+    compaction_group_id: CompactionGroupId,
+    level_scores: &[LevelScore],
+) {
+    let mut reported_levels = HashSet::new();
+    for level_score in level_scores {
+        // `level_scores` is sorted by score, so the first entry of a select level wins.
+        if !reported_levels.insert(level_score.select_level) {
+            continue;
+        }
+        let level_label = format!("{}_{}", level_score.select_level, compaction_group_id);
+        metrics
+            .level_compaction_score
+            .with_label_values(&[&level_label])
+            .set(level_score.score as i64);
+        metrics
+            .level_debt_bytes
+            .with_label_values(&[&level_label])
+            .set(level_score.debt_bytes as i64);
+    }
+}
+
+/// Bytes awaiting compaction in the given group, approximated by the total file size of L0.
+/// All SSTs in L0 still have to be merged down the LSM tree, so L0 size is a good proxy for
+/// how much work external compactors are lagging behind.
+pub fn pending_compaction_bytes(
+    current_version: &HummockVersion,
+    compaction_group_id: CompactionGroupId,
+) -> u64 {
+    let mut pending_bytes = 0;
+    current_version.level_iter(compaction_group_id, |level| {
+        if level.level_idx == 0 {
+            pending_bytes += level.total_file_size;
+        }
+        true
+    });
+    pending_bytes
+}
+
 pub fn remove_compaction_group_in_sst_stat(
     metrics: &MetaMetrics,
     compaction_group_id: CompactionGroupId,
@@ -167,6 +214,14 @@ pub fn remove_compaction_group_in_sst_stat(
             .level_compact_cnt
             .remove_label_values(&[&level_label])
             .ok();
+        metrics
+            .level_compaction_score
+            .remove_label_values(&[&level_label])
+            .ok();
+        metrics
+            .level_debt_bytes
+            .remove_label_values(&[&level_label])
+            .ok();
         if !should_continue {
             break;
         }
@@ -178,6 +233,16 @@ pub fn remove_compaction_group_in_sst_stat(
         .level_sst_num
         .remove_label_values(&[&level_label])
         .ok();
+
+    let group_label = compaction_group_id.to_string();
+    metrics
+        .compaction_group_pending_bytes
+        .remove_label_values(&[&group_label])
+        .ok();
+    metrics
+        .compaction_group_ingest_bytes
+        .remove_label_values(&[&group_label])
+        .ok();
 }
 
 pub fn trigger_pin_unpin_version_state(
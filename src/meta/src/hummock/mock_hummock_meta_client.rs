@@ -156,7 +156,7 @@ impl HummockMetaClient for MockHummockMetaClient {
             .map(|LocalSstableInfo { sst_info, .. }| (sst_info.id, self.context_id))
             .collect();
         self.hummock_manager
-            .commit_epoch(epoch, sstables, sst_to_worker)
+            .commit_epoch(epoch, sstables, sst_to_worker, None)
             .await
             .map_err(mock_err)?;
         Ok(())
@@ -256,6 +256,10 @@ impl HummockMetaClient for MockHummockMetaClient {
         todo!()
     }
 
+    async fn report_corrupted_ssts(&self, _sst_ids: Vec<HummockSstableId>) -> Result<()> {
+        unimplemented!()
+    }
+
     async fn report_full_scan_task(&self, _sst_ids: Vec<HummockSstableId>) -> Result<()> {
         unimplemented!()
     }
@@ -263,6 +267,14 @@ impl HummockMetaClient for MockHummockMetaClient {
     async fn trigger_full_gc(&self, _sst_retention_time_sec: u64) -> Result<()> {
         unimplemented!()
     }
+
+    async fn get_dropped_table_ids(&self, table_ids: Vec<u32>) -> Result<Vec<u32>> {
+        let registered_table_ids = self.hummock_manager.all_table_ids().await;
+        Ok(table_ids
+            .into_iter()
+            .filter(|table_id| !registered_table_ids.contains(table_id))
+            .collect())
+    }
 }
 
 impl MockHummockMetaClient {
@@ -50,6 +50,7 @@ use crate::MetaOpts;
 
 /// Start hummock's asynchronous tasks.
 pub fn start_hummock_workers<S>(
+    hummock_manager: HummockManagerRef<S>,
     vacuum_manager: VacuumManagerRef<S>,
     compaction_scheduler: CompactionSchedulerRef<S>,
     meta_opts: &MetaOpts,
@@ -64,6 +65,12 @@ where
             vacuum_manager,
             Duration::from_secs(meta_opts.vacuum_interval_sec),
         ));
+        if meta_opts.branched_sst_reconciliation_interval_sec > 0 {
+            workers.push(start_branched_sst_reconciler(
+                hummock_manager,
+                Duration::from_secs(meta_opts.branched_sst_reconciliation_interval_sec),
+            ));
+        }
     }
     workers
 }
@@ -119,3 +126,32 @@ where
     });
     (join_handle, shutdown_tx)
 }
+
+/// Starts a task to periodically reconcile branched SST metadata left over by interrupted
+/// compaction group splits.
+pub fn start_branched_sst_reconciler<S>(
+    hummock_manager: HummockManagerRef<S>,
+    interval: Duration,
+) -> (JoinHandle<()>, Sender<()>)
+where
+    S: MetaStore,
+{
+    let (shutdown_tx, mut shutdown_rx) = tokio::sync::oneshot::channel();
+    let join_handle = tokio::spawn(async move {
+        let mut min_trigger_interval = tokio::time::interval(interval);
+        min_trigger_interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            tokio::select! {
+                // Wait for interval
+                _ = min_trigger_interval.tick() => {},
+                // Shutdown reconciler
+                _ = &mut shutdown_rx => {
+                    tracing::info!("Branched SST reconciler is stopped");
+                    return;
+                }
+            }
+            hummock_manager.reconcile_branched_ssts().await;
+        }
+    });
+    (join_handle, shutdown_tx)
+}
@@ -73,7 +73,7 @@ where
         .map(|LocalSstableInfo { sst_info, .. }| (sst_info.id, context_id))
         .collect();
     hummock_manager
-        .commit_epoch(epoch, ssts, sst_to_worker)
+        .commit_epoch(epoch, ssts, sst_to_worker, None)
         .await
         .unwrap();
     // Current state: {v0: [], v1: [test_tables]}
@@ -142,7 +142,7 @@ where
         .map(|LocalSstableInfo { sst_info, .. }| (sst_info.id, context_id))
         .collect();
     hummock_manager
-        .commit_epoch(epoch, ssts, sst_to_worker)
+        .commit_epoch(epoch, ssts, sst_to_worker, None)
         .await
         .unwrap();
     // Current state: {v0: [], v1: [test_tables], v2: [test_tables_2, to_delete:test_tables], v3:
@@ -361,7 +361,7 @@ where
         .map(|LocalSstableInfo { sst_info, .. }| (sst_info.id, META_NODE_ID))
         .collect();
     hummock_manager_ref
-        .commit_epoch(epoch, ssts, sst_to_worker)
+        .commit_epoch(epoch, ssts, sst_to_worker, None)
         .await
 }
 
@@ -387,7 +387,7 @@ where
         .map(|LocalSstableInfo { sst_info, .. }| (sst_info.id, context_id))
         .collect();
     hummock_manager
-        .commit_epoch(epoch, ssts, sst_to_worker)
+        .commit_epoch(epoch, ssts, sst_to_worker, None)
         .await
         .unwrap();
     test_tables
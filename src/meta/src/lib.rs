@@ -144,6 +144,8 @@ pub fn start(opts: MetaNodeOpts) -> Pin<Box<dyn Future<Output = ()> + Send>> {
         let config = load_config(&opts.config_path, Some(opts.override_opts));
         info!("> config: {:?}", config);
         info!("> version: {} ({})", RW_VERSION, GIT_SHA);
+        // Initialize mutual TLS for node-to-node RPC connections, if enabled.
+        risingwave_rpc_client::tls::init_tls(&config.server.tls).unwrap();
         let listen_addr: SocketAddr = opts.listen_addr.parse().unwrap();
         let meta_addr = opts.host.unwrap_or_else(|| listen_addr.ip().to_string());
         let dashboard_addr = opts.dashboard_host.map(|x| x.parse().unwrap());
@@ -194,6 +196,12 @@ pub fn start(opts: MetaNodeOpts) -> Pin<Box<dyn Future<Output = ()> + Send>> {
                 checkpoint_frequency,
                 compaction_deterministic_test: config.meta.enable_compaction_deterministic,
                 vacuum_interval_sec: config.meta.vacuum_interval_sec,
+                branched_sst_reconciliation_interval_sec: config
+                    .meta
+                    .branched_sst_reconciliation_interval_sec,
+                hummock_version_checkpoint_export_interval_sec: config
+                    .meta
+                    .hummock_version_checkpoint_export_interval_sec,
                 min_sst_retention_time_sec: config.meta.min_sst_retention_time_sec,
                 collect_gc_watermark_spin_interval_sec: config
                     .meta
@@ -205,9 +213,14 @@ pub fn start(opts: MetaNodeOpts) -> Pin<Box<dyn Future<Output = ()> + Send>> {
                 connector_rpc_endpoint: opts.connector_rpc_endpoint,
                 backup_storage_url: config.backup.storage_url,
                 backup_storage_directory: config.backup.storage_directory,
+                meta_snapshot_interval_sec: config.backup.meta_snapshot_interval_sec,
+                meta_snapshot_retention_hourly: config.backup.meta_snapshot_retention_hourly,
+                meta_snapshot_retention_daily: config.backup.meta_snapshot_retention_daily,
                 periodic_space_reclaim_compaction_interval_sec: config
                     .meta
                     .periodic_space_reclaim_compaction_interval_sec,
+                enable_unsafe_fault_injection: config.meta.enable_unsafe_fault_injection,
+                recovery_build_parallelism: config.meta.recovery_build_parallelism,
             },
         )
         .await
@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use std::collections::hash_map::Entry;
-use std::collections::{BTreeMap, HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::sync::Arc;
 
 use anyhow::{anyhow, Context};
@@ -31,9 +31,10 @@ use risingwave_pb::stream_plan::stream_node::NodeBody;
 use risingwave_pb::stream_plan::{
     Dispatcher, DispatcherType, FragmentTypeFlag, StreamActor, StreamNode,
 };
+use risingwave_pb::stream_service::EphemeralStateSnapshot;
 use tokio::sync::{RwLock, RwLockReadGuard};
 
-use crate::barrier::Reschedule;
+use crate::barrier::{BarrierDomainId, Reschedule};
 use crate::manager::cluster::WorkerId;
 use crate::manager::{commit_meta, MetaSrvEnv};
 use crate::model::{
@@ -120,6 +121,19 @@ where
         !self.core.read().await.table_fragments.is_empty()
     }
 
+    /// Returns the barrier domains of all streaming jobs, i.e. the ones barriers should rotate
+    /// through.
+    pub async fn all_barrier_domain_ids(&self) -> BTreeSet<BarrierDomainId> {
+        let map = &self.core.read().await.table_fragments;
+        map.values().map(|tf| tf.barrier_domain_id()).collect()
+    }
+
+    /// Returns the barrier domain of the streaming job on the given table, if the job exists.
+    pub async fn get_barrier_domain_id(&self, table_id: &TableId) -> Option<BarrierDomainId> {
+        let map = &self.core.read().await.table_fragments;
+        map.get(table_id).map(|tf| tf.barrier_domain_id())
+    }
+
     pub async fn batch_update_table_fragments(
         &self,
         table_fragments: &[TableFragments],
@@ -322,6 +336,12 @@ where
                 self.notify_fragment_mapping(&table_fragments, Operation::Delete)
                     .await;
             }
+
+            // Clean up the persisted snapshots of `memory_only` states of the dropped internal
+            // tables, if any.
+            for internal_table_id in table_fragments.internal_table_ids() {
+                EphemeralStateSnapshot::delete(self.env.meta_store(), &internal_table_id).await?;
+            }
         }
 
         Ok(())
@@ -331,6 +351,7 @@ where
     /// collected
     pub async fn load_all_actors(
         &self,
+        barrier_domain: Option<BarrierDomainId>,
         check_state: impl Fn(ActorState, TableId, ActorId) -> bool,
     ) -> ActorInfos {
         let mut actor_maps = HashMap::new();
@@ -338,6 +359,13 @@ where
 
         let map = &self.core.read().await.table_fragments;
         for fragments in map.values() {
+            // A barrier targeting a specific domain is only sent to and collected from the
+            // actors of that domain's streaming jobs.
+            if let Some(barrier_domain) = barrier_domain
+                && fragments.barrier_domain_id() != barrier_domain
+            {
+                continue;
+            }
             for (worker_id, actor_states) in fragments.worker_actor_states() {
                 for (actor_id, actor_state) in actor_states {
                     if check_state(actor_state, fragments.table_id(), actor_id) {
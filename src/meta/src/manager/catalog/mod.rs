@@ -31,10 +31,12 @@ use risingwave_common::catalog::{
     DEFAULT_SUPER_USER_ID, SYSTEM_SCHEMAS,
 };
 use risingwave_common::{bail, ensure};
-use risingwave_pb::catalog::table::OptionalAssociatedSourceId;
-use risingwave_pb::catalog::{Database, Function, Index, Schema, Sink, Source, Table, View};
+use risingwave_pb::catalog::table::{OptionalAssociatedSourceId, TableType};
+use risingwave_pb::catalog::{
+    Database, Function, Index, Schema, Sink, Source, Table, TableStats, View,
+};
 use risingwave_pb::meta::subscribe_response::{Info, Operation};
-use risingwave_pb::user::grant_privilege::{ActionWithGrantOption, Object};
+use risingwave_pb::user::grant_privilege::{Action, ActionWithGrantOption, Object};
 use risingwave_pb::user::update_user_request::UpdateField;
 use risingwave_pb::user::{GrantPrivilege, UserInfo};
 use tokio::sync::{Mutex, MutexGuard};
@@ -774,6 +776,30 @@ where
         }
     }
 
+    /// Replaces the statistics of a table with those collected by `ANALYZE`.
+    pub async fn update_table_stats(
+        &self,
+        table_id: TableId,
+        stats: TableStats,
+    ) -> MetaResult<NotificationVersion> {
+        let core = &mut *self.core.lock().await;
+        let database_core = &mut core.database;
+        let mut tables = BTreeMapTransaction::new(&mut database_core.tables);
+
+        let mut table = tables
+            .get_mut(table_id)
+            .ok_or_else(|| MetaError::catalog_id_not_found("table", table_id))?;
+        table.stats = Some(stats);
+        let table = table.clone();
+        commit_meta!(self, tables)?;
+
+        let version = self
+            .notify_frontend(Operation::Update, Info::Table(table))
+            .await;
+
+        Ok(version)
+    }
+
     pub async fn get_index_table(&self, index_id: IndexId) -> MetaResult<TableId> {
         let index = Index::select(self.env.meta_store(), &index_id).await?;
         if let Some(index) = index {
@@ -1462,6 +1488,10 @@ where
         self.core.lock().await.database.list_table_ids(schema_id)
     }
 
+    pub async fn list_sinks(&self) -> Vec<Sink> {
+        self.core.lock().await.database.list_sinks()
+    }
+
     pub async fn list_sources(&self) -> Vec<Source> {
         self.core.lock().await.database.list_sources()
     }
@@ -1949,3 +1979,216 @@ where
         users_need_update
     }
 }
+
+// Catalog export related methods.
+impl<S> CatalogManager<S>
+where
+    S: MetaStore,
+{
+    /// Exports the whole catalog as re-playable SQL statements, used by `risectl meta
+    /// export-catalog` for promoting the definitions of one environment to a fresh cluster
+    /// (without data).
+    ///
+    /// Statements are ordered so that each one only depends on those before it: users,
+    /// databases, schemas, then relations in creation (id) order, then privilege grants.
+    /// Statements are not database-qualified, so clusters with multiple databases should be
+    /// imported per database. User passwords are not exported, since only encrypted auth info
+    /// is stored.
+    pub async fn export_catalog(&self) -> Vec<String> {
+        let core = &*self.core.lock().await;
+        let database_core = &core.database;
+        let user_core = &core.user;
+
+        let mut statements = Vec::new();
+
+        // Users.
+        for user_info in user_core.user_info.values() {
+            if user_info.id == DEFAULT_SUPER_USER_ID || user_info.id == DEFAULT_SUPER_USER_FOR_PG_ID
+            {
+                continue;
+            }
+            let mut stmt = format!("CREATE USER \"{}\" WITH", user_info.name);
+            stmt.push_str(if user_info.is_super {
+                " SUPERUSER"
+            } else {
+                " NOSUPERUSER"
+            });
+            if user_info.can_create_db {
+                stmt.push_str(" CREATEDB");
+            }
+            if user_info.can_create_user {
+                stmt.push_str(" CREATEUSER");
+            }
+            stmt.push_str(if user_info.can_login {
+                " LOGIN"
+            } else {
+                " NOLOGIN"
+            });
+            stmt.push(';');
+            statements.push(stmt);
+        }
+
+        // Databases and schemas.
+        for db in database_core.databases.values() {
+            if db.name == DEFAULT_DATABASE_NAME {
+                continue;
+            }
+            statements.push(format!("CREATE DATABASE \"{}\";", db.name));
+        }
+        for schema in database_core.schemas.values() {
+            if schema.name == DEFAULT_SCHEMA_NAME || SYSTEM_SCHEMAS.contains(&schema.name.as_str())
+            {
+                continue;
+            }
+            statements.push(format!("CREATE SCHEMA \"{}\";", schema.name));
+        }
+
+        // Relations, in creation (id) order so that dependencies are created first.
+        let associated_source_ids: HashSet<u32> = database_core
+            .tables
+            .values()
+            .filter_map(|table| table.optional_associated_source_id.as_ref())
+            .map(|OptionalAssociatedSourceId::AssociatedSourceId(id)| *id)
+            .collect();
+        let mut relations: Vec<(u32, String)> = Vec::new();
+        for source in database_core.sources.values() {
+            if associated_source_ids.contains(&source.id) {
+                // Will be recreated by the associated table's `CREATE TABLE`.
+                continue;
+            }
+            relations.push((
+                source.id,
+                Self::replayable_definition(&source.definition, "source", &source.name),
+            ));
+        }
+        for table in database_core.tables.values() {
+            match table.table_type() {
+                TableType::Table | TableType::MaterializedView => {
+                    relations.push((
+                        table.id,
+                        Self::replayable_definition(&table.definition, "table", &table.name),
+                    ));
+                }
+                // Internal tables are recreated along with their streaming job. Index tables
+                // are handled below via the `Index` catalog.
+                TableType::Internal | TableType::Index | TableType::Unspecified => {}
+            }
+        }
+        for index in database_core.indexes.values() {
+            // Index tables don't record their definition yet, so they cannot be replayed.
+            relations.push((
+                index.id,
+                format!("-- skipped index \"{}\": no definition recorded", index.name),
+            ));
+        }
+        for sink in database_core.sinks.values() {
+            relations.push((
+                sink.id,
+                Self::replayable_definition(&sink.definition, "sink", &sink.name),
+            ));
+        }
+        for view in database_core.views.values() {
+            let columns = if view.columns.is_empty() {
+                String::new()
+            } else {
+                format!(
+                    " ({})",
+                    view.columns
+                        .iter()
+                        .map(|field| format!("\"{}\"", field.name))
+                        .join(", ")
+                )
+            };
+            relations.push((
+                view.id,
+                format!("CREATE VIEW \"{}\"{} AS {};", view.name, columns, view.sql),
+            ));
+        }
+        relations.sort_by_key(|(id, _)| *id);
+        statements.extend(relations.into_iter().map(|(_, stmt)| stmt));
+
+        // Privileges.
+        for user_info in user_core.user_info.values() {
+            if user_info.id == DEFAULT_SUPER_USER_ID || user_info.id == DEFAULT_SUPER_USER_FOR_PG_ID
+            {
+                continue;
+            }
+            for privilege in &user_info.grant_privileges {
+                let target = match privilege.object.as_ref() {
+                    Some(Object::DatabaseId(id)) => database_core
+                        .databases
+                        .get(id)
+                        .map(|db| format!("DATABASE \"{}\"", db.name)),
+                    Some(Object::SchemaId(id)) => database_core
+                        .schemas
+                        .get(id)
+                        .map(|schema| format!("SCHEMA \"{}\"", schema.name)),
+                    Some(Object::TableId(id)) => database_core
+                        .tables
+                        .get(id)
+                        .map(|table| format!("MATERIALIZED VIEW \"{}\"", table.name)),
+                    Some(Object::SourceId(id)) => database_core
+                        .sources
+                        .get(id)
+                        .map(|source| format!("SOURCE \"{}\"", source.name)),
+                    Some(Object::AllTablesSchemaId(id)) => database_core
+                        .schemas
+                        .get(id)
+                        .map(|schema| {
+                            format!("ALL MATERIALIZED VIEWS IN SCHEMA \"{}\"", schema.name)
+                        }),
+                    Some(Object::AllSourcesSchemaId(id)) => database_core
+                        .schemas
+                        .get(id)
+                        .map(|schema| format!("ALL SOURCES IN SCHEMA \"{}\"", schema.name)),
+                    _ => None,
+                };
+                if let Some(target) = target {
+                    let actions = privilege
+                        .action_with_opts
+                        .iter()
+                        .map(|a| match a.action() {
+                            Action::Select => "SELECT",
+                            Action::Insert => "INSERT",
+                            Action::Update => "UPDATE",
+                            Action::Delete => "DELETE",
+                            Action::Create => "CREATE",
+                            Action::Connect => "CONNECT",
+                            Action::Unspecified => "UNSPECIFIED",
+                        })
+                        .join(", ");
+                    let with_grant_option = privilege
+                        .action_with_opts
+                        .iter()
+                        .all(|a| a.with_grant_option);
+                    statements.push(format!(
+                        "GRANT {} ON {} TO \"{}\"{};",
+                        actions,
+                        target,
+                        user_info.name,
+                        if with_grant_option {
+                            " WITH GRANT OPTION"
+                        } else {
+                            ""
+                        }
+                    ));
+                }
+            }
+        }
+
+        statements
+    }
+
+    /// The stored definition of a relation, normalized into a single replayable statement, or
+    /// a comment if the catalog predates definition recording.
+    fn replayable_definition(definition: &str, relation_kind: &str, name: &str) -> String {
+        if definition.is_empty() {
+            format!(
+                "-- skipped {} \"{}\": no definition recorded",
+                relation_kind, name
+            )
+        } else {
+            format!("{};", definition.trim_end_matches(';'))
+        }
+    }
+}
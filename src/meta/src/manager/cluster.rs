@@ -18,9 +18,11 @@ use std::sync::Arc;
 use std::time::{Duration, SystemTime};
 
 use itertools::Itertools;
+use risingwave_common::bail;
 use risingwave_common::hash::ParallelUnitId;
 use risingwave_pb::common::worker_node::State;
 use risingwave_pb::common::{HostAddress, ParallelUnit, WorkerNode, WorkerType};
+use risingwave_pb::meta::actor_usage_info::ActorUsage;
 use risingwave_pb::meta::heartbeat_request;
 use risingwave_pb::meta::subscribe_response::{Info, Operation};
 use tokio::sync::oneshot::Sender;
@@ -193,11 +195,33 @@ where
         Ok(())
     }
 
+    /// Exclude a compute node from the scheduling of new streaming jobs, so that its actors can
+    /// be migrated away before it is unregistered. The marker is kept in memory only: a draining
+    /// worker that is still registered after a meta node restart is scheduled normally again.
+    pub async fn mark_worker_node_draining(&self, host_address: HostAddress) -> MetaResult<Worker> {
+        let mut core = self.core.write().await;
+        let worker = core.get_worker_by_host_checked(host_address)?;
+        if worker.worker_type() != WorkerType::ComputeNode {
+            bail!("only compute nodes can be drained");
+        }
+        core.draining_workers.insert(worker.worker_id());
+        Ok(worker)
+    }
+
+    /// Return a draining compute node to normal scheduling, e.g. when a drain fails halfway.
+    pub async fn cancel_worker_node_draining(&self, host_address: HostAddress) {
+        let mut core = self.core.write().await;
+        if let Some(worker) = core.get_worker_by_host(host_address) {
+            core.draining_workers.remove(&worker.worker_id());
+        }
+    }
+
     pub async fn delete_worker_node(&self, host_address: HostAddress) -> MetaResult<WorkerType> {
         let mut core = self.core.write().await;
         let worker = core.get_worker_by_host_checked(host_address.clone())?;
         let worker_type = worker.worker_type();
         let worker_node = worker.to_protobuf();
+        core.draining_workers.remove(&worker.worker_id());
 
         // Persist deletion.
         Worker::delete(self.env.meta_store(), &host_address).await?;
@@ -240,6 +264,15 @@ where
         Err(MetaError::invalid_worker(worker_id))
     }
 
+    /// Returns the per-actor resource usage samples reported by all workers via heartbeat.
+    pub async fn list_actor_usages(&self) -> Vec<ActorUsage> {
+        let core = self.core.read().await;
+        core.workers
+            .values()
+            .flat_map(|worker| worker.actor_usages().iter().cloned())
+            .collect_vec()
+    }
+
     pub async fn start_heartbeat_checker(
         cluster_manager: ClusterManagerRef<S>,
         check_interval: Duration,
@@ -387,6 +420,9 @@ pub struct ClusterManagerCore {
 
     /// Record for parallel units.
     parallel_units: Vec<ParallelUnit>,
+
+    /// Compute nodes being drained, excluded from the scheduling of new streaming jobs.
+    draining_workers: HashSet<WorkerId>,
 }
 
 impl ClusterManagerCore {
@@ -406,6 +442,7 @@ impl ClusterManagerCore {
         Ok(Self {
             workers: worker_map,
             parallel_units,
+            draining_workers: HashSet::new(),
         })
     }
 
@@ -470,6 +507,7 @@ impl ClusterManagerCore {
             .list_worker_node(WorkerType::ComputeNode, Some(State::Running))
             .into_iter()
             .map(|w| w.id)
+            .filter(|id| !self.draining_workers.contains(id))
             .collect();
 
         self.parallel_units
@@ -483,6 +521,7 @@ impl ClusterManagerCore {
         let active_workers: HashMap<_, _> = self
             .list_worker_node(WorkerType::ComputeNode, Some(State::Running))
             .into_iter()
+            .filter(|w| !self.draining_workers.contains(&w.id))
             .map(|w| (w.id, w))
             .collect();
 
@@ -588,6 +627,53 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_draining_worker() -> MetaResult<()> {
+        let env = MetaSrvEnv::for_test().await;
+
+        let cluster_manager = Arc::new(
+            ClusterManager::new(env.clone(), Duration::new(0, 0))
+                .await
+                .unwrap(),
+        );
+
+        let fake_host_address = HostAddress {
+            host: "localhost".to_string(),
+            port: 6000,
+        };
+        let fake_parallelism = 4;
+        cluster_manager
+            .add_worker_node(
+                WorkerType::ComputeNode,
+                fake_host_address.clone(),
+                fake_parallelism,
+            )
+            .await?;
+        cluster_manager
+            .activate_worker_node(fake_host_address.clone())
+            .await?;
+        assert_cluster_manager(&cluster_manager, fake_parallelism).await;
+
+        // A draining worker is excluded from the scheduling of new streaming jobs.
+        cluster_manager
+            .mark_worker_node_draining(fake_host_address.clone())
+            .await?;
+        assert_cluster_manager(&cluster_manager, 0).await;
+        assert!(cluster_manager
+            .get_streaming_cluster_info()
+            .await
+            .worker_nodes
+            .is_empty());
+
+        // Cancelling the drain returns the worker to scheduling.
+        cluster_manager
+            .cancel_worker_node_draining(fake_host_address.clone())
+            .await;
+        assert_cluster_manager(&cluster_manager, fake_parallelism).await;
+
+        Ok(())
+    }
+
     async fn assert_cluster_manager(
         cluster_manager: &ClusterManager<MemStore>,
         parallel_count: usize,
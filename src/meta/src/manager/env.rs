@@ -72,6 +72,12 @@ pub struct MetaOpts {
 
     /// Interval of GC metadata in meta store and stale SSTs in object store.
     pub vacuum_interval_sec: u64,
+    /// Interval of reconciling branched SST metadata left over by interrupted compaction
+    /// group splits. 0 disables the reconciliation.
+    pub branched_sst_reconciliation_interval_sec: u64,
+    /// Interval of exporting the full hummock checkpoint version to the backup object store.
+    /// 0 disables the export.
+    pub hummock_version_checkpoint_export_interval_sec: u64,
     /// Threshold used by worker node to filter out new SSTs when scanning object store.
     pub min_sst_retention_time_sec: u64,
     /// The spin interval when collecting global GC watermark in hummock
@@ -94,9 +100,23 @@ pub struct MetaOpts {
     pub backup_storage_url: String,
     /// The storage directory for storing backups.
     pub backup_storage_directory: String,
+    /// Interval of automatic meta snapshot. 0 disables automatic snapshots and snapshot
+    /// retention.
+    pub meta_snapshot_interval_sec: u64,
+    /// Number of the most recent hours to keep a meta snapshot for each.
+    pub meta_snapshot_retention_hourly: u64,
+    /// Number of the most recent days to keep a meta snapshot for each.
+    pub meta_snapshot_retention_daily: u64,
 
     /// Schedule space_reclaim_compaction for all compaction groups with this interval.
     pub periodic_space_reclaim_compaction_interval_sec: u64,
+
+    /// Whether to allow the fault injection RPCs for chaos testing.
+    pub enable_unsafe_fault_injection: bool,
+
+    /// The maximum number of streaming jobs whose actors are rebuilt concurrently during
+    /// recovery. 0 means no limit.
+    pub recovery_build_parallelism: usize,
 }
 
 impl MetaOpts {
@@ -110,6 +130,8 @@ impl MetaOpts {
             checkpoint_frequency: 10,
             compaction_deterministic_test: false,
             vacuum_interval_sec: 30,
+            branched_sst_reconciliation_interval_sec: 600,
+            hummock_version_checkpoint_export_interval_sec: 0,
             min_sst_retention_time_sec: 3600 * 24 * 7,
             collect_gc_watermark_spin_interval_sec: 5,
             enable_committed_sst_sanity_check: false,
@@ -119,7 +141,12 @@ impl MetaOpts {
             connector_rpc_endpoint: None,
             backup_storage_url: "memory".to_string(),
             backup_storage_directory: "backup".to_string(),
+            meta_snapshot_interval_sec: 0,
+            meta_snapshot_retention_hourly: 24,
+            meta_snapshot_retention_daily: 7,
             periodic_space_reclaim_compaction_interval_sec: 60,
+            enable_unsafe_fault_injection: false,
+            recovery_build_parallelism: 10,
         }
     }
 }
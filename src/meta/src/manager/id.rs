@@ -141,6 +141,7 @@ pub mod IdCategory {
     pub const _Index: IdCategoryType = 14;
     pub const CompactionGroup: IdCategoryType = 15;
     pub const Function: IdCategoryType = 16;
+    pub const HummockFenceToken: IdCategoryType = 17;
 }
 
 pub type IdGeneratorManagerRef<S> = Arc<IdGeneratorManager<S>>;
@@ -163,6 +164,7 @@ pub struct IdGeneratorManager<S> {
     hummock_compaction_task: Arc<StoredIdGenerator<S>>,
     parallel_unit: Arc<StoredIdGenerator<S>>,
     compaction_group: Arc<StoredIdGenerator<S>>,
+    hummock_fence_token: Arc<StoredIdGenerator<S>>,
 }
 
 impl<S> IdGeneratorManager<S>
@@ -219,6 +221,9 @@ where
                 )
                 .await,
             ),
+            hummock_fence_token: Arc::new(
+                StoredIdGenerator::new(meta_store.clone(), "hummock_fence_token", Some(1)).await,
+            ),
         }
     }
 
@@ -239,6 +244,7 @@ where
             IdCategory::ParallelUnit => &self.parallel_unit,
             IdCategory::HummockCompactionTask => &self.hummock_compaction_task,
             IdCategory::CompactionGroup => &self.compaction_group,
+            IdCategory::HummockFenceToken => &self.hummock_fence_token,
             _ => unreachable!(),
         }
     }
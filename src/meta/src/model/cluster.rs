@@ -18,6 +18,7 @@ use std::time::{Duration, SystemTime};
 
 use risingwave_hummock_sdk::HummockSstableId;
 use risingwave_pb::common::{HostAddress, WorkerNode, WorkerType};
+use risingwave_pb::meta::actor_usage_info::ActorUsage;
 use risingwave_pb::meta::heartbeat_request::extra_info::Info;
 
 use crate::model::{MetadataModel, MetadataModelResult};
@@ -42,6 +43,8 @@ pub struct Worker {
     info_version_id: u64,
     // GC watermark.
     hummock_gc_watermark: Option<HummockSstableId>,
+    // Latest per-actor resource usage samples, only reported by compute nodes.
+    actor_usages: Vec<ActorUsage>,
 }
 
 impl MetadataModel for Worker {
@@ -62,6 +65,7 @@ impl MetadataModel for Worker {
             expire_at: INVALID_EXPIRE_AT,
             info_version_id: 0,
             hummock_gc_watermark: Default::default(),
+            actor_usages: Default::default(),
         }
     }
 
@@ -102,6 +106,9 @@ impl Worker {
                 Info::HummockGcWatermark(info) => {
                     self.hummock_gc_watermark = Some(info);
                 }
+                Info::ActorUsage(info) => {
+                    self.actor_usages = info.usages;
+                }
             }
         }
     }
@@ -110,6 +117,10 @@ impl Worker {
         self.hummock_gc_watermark
     }
 
+    pub fn actor_usages(&self) -> &[ActorUsage] {
+        &self.actor_usages
+    }
+
     pub fn info_version_id(&self) -> u64 {
         self.info_version_id
     }
@@ -0,0 +1,43 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_pb::stream_service::EphemeralStateSnapshot;
+
+use crate::model::{MetadataModel, MetadataModelResult};
+
+/// Column family name for snapshots of `memory_only` executor states.
+const EPHEMERAL_STATE_CF_NAME: &str = "cf/ephemeral_state";
+
+/// Snapshots of `memory_only` executor states reported by the compute nodes, persisted at
+/// checkpoints and sent back along with the first barrier after actor (re)creation.
+impl MetadataModel for EphemeralStateSnapshot {
+    type KeyType = u32;
+    type ProstType = Self;
+
+    fn cf_name() -> String {
+        EPHEMERAL_STATE_CF_NAME.to_string()
+    }
+
+    fn to_protobuf(&self) -> Self::ProstType {
+        self.clone()
+    }
+
+    fn from_protobuf(prost: Self::ProstType) -> Self {
+        prost
+    }
+
+    fn key(&self) -> MetadataModelResult<Self::KeyType> {
+        Ok(self.table_id)
+    }
+}
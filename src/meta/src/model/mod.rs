@@ -15,7 +15,9 @@
 mod barrier;
 mod catalog;
 mod cluster;
+mod ephemeral_state;
 mod error;
+mod mv_snapshot_pin;
 mod notification;
 mod stream;
 mod user;
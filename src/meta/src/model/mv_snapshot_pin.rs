@@ -0,0 +1,44 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_pb::backup_service::MvSnapshotPin;
+
+use crate::model::{MetadataModel, MetadataModelResult};
+
+/// Column family name for SST pins of exported materialized view snapshots.
+const MV_SNAPSHOT_PIN_CF_NAME: &str = "cf/mv_snapshot_pin";
+
+/// Pins the SSTs referenced by an exported `MvSnapshot`, so that they are excluded from vacuum
+/// until the snapshot is imported or released. Persisted in meta store to survive meta node
+/// restarts.
+impl MetadataModel for MvSnapshotPin {
+    type KeyType = u64;
+    type ProstType = Self;
+
+    fn cf_name() -> String {
+        MV_SNAPSHOT_PIN_CF_NAME.to_string()
+    }
+
+    fn to_protobuf(&self) -> Self::ProstType {
+        self.clone()
+    }
+
+    fn from_protobuf(prost: Self::ProstType) -> Self {
+        prost
+    }
+
+    fn key(&self) -> MetadataModelResult<Self::KeyType> {
+        Ok(self.id)
+    }
+}
@@ -29,6 +29,7 @@ use risingwave_pb::stream_plan::{
 };
 
 use super::{ActorId, FragmentId};
+use crate::barrier::{BarrierDomainId, GLOBAL_BARRIER_DOMAIN};
 use crate::manager::{SourceId, WorkerId};
 use crate::model::{MetadataModel, MetadataModelResult};
 use crate::stream::{build_actor_connector_splits, build_actor_split_impls, SplitAssignment};
@@ -59,6 +60,10 @@ pub struct TableFragments {
 
     /// The environment associated with this stream plan and its fragments
     pub(crate) env: StreamEnvironment,
+
+    /// The barrier domain the streaming job belongs to, which is the id of its database. Jobs in
+    /// the same domain are checkpointed and recovered together.
+    pub(crate) barrier_domain_id: BarrierDomainId,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -101,6 +106,7 @@ impl MetadataModel for TableFragments {
             actor_status: self.actor_status.clone().into_iter().collect(),
             actor_splits: build_actor_connector_splits(&self.actor_splits),
             env: Some(self.env.to_protobuf()),
+            barrier_domain_id: self.barrier_domain_id,
         }
     }
 
@@ -113,6 +119,7 @@ impl MetadataModel for TableFragments {
             actor_status: prost.actor_status.into_iter().collect(),
             actor_splits: build_actor_split_impls(&prost.actor_splits),
             env,
+            barrier_domain_id: prost.barrier_domain_id,
         }
     }
 
@@ -129,6 +136,7 @@ impl TableFragments {
             fragments,
             &BTreeMap::new(),
             StreamEnvironment::default(),
+            GLOBAL_BARRIER_DOMAIN,
         )
     }
 
@@ -139,6 +147,7 @@ impl TableFragments {
         fragments: BTreeMap<FragmentId, Fragment>,
         actor_locations: &BTreeMap<ActorId, ParallelUnit>,
         env: StreamEnvironment,
+        barrier_domain_id: BarrierDomainId,
     ) -> Self {
         let actor_status = actor_locations
             .iter()
@@ -160,6 +169,7 @@ impl TableFragments {
             actor_status,
             actor_splits: HashMap::default(),
             env,
+            barrier_domain_id,
         }
     }
 
@@ -181,6 +191,11 @@ impl TableFragments {
         self.state
     }
 
+    /// Returns the barrier domain the streaming job belongs to.
+    pub fn barrier_domain_id(&self) -> BarrierDomainId {
+        self.barrier_domain_id
+    }
+
     /// Returns the timezone of the table
     pub fn timezone(&self) -> Option<String> {
         self.env.timezone.clone()
@@ -34,11 +34,20 @@ pub struct MetaMetrics {
 
     /// Latency between each barrier send
     pub barrier_send_latency: Histogram,
+    /// The number of checkpoints whose completion latency exceeded the `checkpoint_slo`
+    /// declared on a materialized view, labeled by the table id of that view.
+    pub barrier_slo_violation_count: IntCounterVec,
     /// The number of all barriers. It is the sum of barreriers that are in-flight or completed but
     /// waiting for other barriers
     pub all_barrier_nums: IntGauge,
     /// The number of in-flight barriers
     pub in_flight_barrier_nums: IntGauge,
+    /// The number of distinct group keys held by each hash agg actor, labeled by the id of the
+    /// agg result table and the actor id. Used to observe skewed group key distributions.
+    pub agg_distinct_key_count: IntGaugeVec,
+    /// Milliseconds between the sampled event timestamp and the completion of the last sink
+    /// commit, labeled by the sink id and the actor id. The end-to-end freshness of a pipeline.
+    pub sink_freshness_ms: IntGaugeVec,
 
     /// Max committed epoch
     pub max_committed_epoch: IntGauge,
@@ -50,6 +59,10 @@ pub struct MetaMetrics {
     pub level_sst_num: IntGaugeVec,
     /// The number of SSTs to be merged to next level in each level
     pub level_compact_cnt: IntGaugeVec,
+    /// The compaction score of each level, scaled by 100.
+    pub level_compaction_score: IntGaugeVec,
+    /// Bytes each level holds beyond its target capacity.
+    pub level_debt_bytes: IntGaugeVec,
     /// The number of compact tasks
     pub compact_frequency: IntCounterVec,
 
@@ -66,6 +79,10 @@ pub struct MetaMetrics {
     pub min_safepoint_version_id: IntGauge,
     /// Hummock version stats
     pub version_stats: IntGaugeVec,
+    /// Bytes awaiting compaction in each compaction group.
+    pub compaction_group_pending_bytes: IntGaugeVec,
+    /// Cumulative bytes ingested into each compaction group via `commit_epoch`.
+    pub compaction_group_ingest_bytes: IntCounterVec,
 
     /// Latency for hummock manager to acquire lock
     pub hummock_manager_lock_time: HistogramVec,
@@ -73,6 +90,9 @@ pub struct MetaMetrics {
     /// Latency for hummock manager to really process a request after acquire the lock
     pub hummock_manager_real_process_time: HistogramVec,
 
+    /// The number of pending events in each hummock manager event worker shard.
+    pub hummock_manager_event_queue_depth: IntGaugeVec,
+
     pub time_after_last_observation: AtomicU64,
 
     /// The number of workers in the cluster.
@@ -113,6 +133,14 @@ impl MetaMetrics {
         );
         let barrier_send_latency = register_histogram_with_registry!(opts, registry).unwrap();
 
+        let barrier_slo_violation_count = register_int_counter_vec_with_registry!(
+            "meta_barrier_slo_violation_count",
+            "num of checkpoints that violated the declared checkpoint_slo of a materialized view",
+            &["table_id"],
+            registry
+        )
+        .unwrap();
+
         let all_barrier_nums = register_int_gauge_with_registry!(
             "all_barrier_nums",
             "num of of all_barrier",
@@ -126,6 +154,22 @@ impl MetaMetrics {
         )
         .unwrap();
 
+        let agg_distinct_key_count = register_int_gauge_vec_with_registry!(
+            "meta_agg_distinct_key_count",
+            "num of distinct group keys held by each hash agg actor",
+            &["table_id", "actor_id"],
+            registry
+        )
+        .unwrap();
+
+        let sink_freshness_ms = register_int_gauge_vec_with_registry!(
+            "meta_sink_freshness_ms",
+            "ms between sampled event time and sink commit completion of each sink actor",
+            &["sink_id", "actor_id"],
+            registry
+        )
+        .unwrap();
+
         let max_committed_epoch = register_int_gauge_with_registry!(
             "storage_max_committed_epoch",
             "max committed epoch",
@@ -160,6 +204,22 @@ impl MetaMetrics {
         )
         .unwrap();
 
+        let level_compaction_score = register_int_gauge_vec_with_registry!(
+            "storage_level_compaction_score",
+            "compaction score of each level, scaled by 100",
+            &["level_index"],
+            registry
+        )
+        .unwrap();
+
+        let level_debt_bytes = register_int_gauge_vec_with_registry!(
+            "storage_level_debt_bytes",
+            "bytes each level holds beyond its target capacity",
+            &["level_index"],
+            registry
+        )
+        .unwrap();
+
         let compact_frequency = register_int_counter_vec_with_registry!(
             "storage_level_compact_frequency",
             "num of compactions from each level to next level",
@@ -223,6 +283,22 @@ impl MetaMetrics {
         )
         .unwrap();
 
+        let compaction_group_pending_bytes = register_int_gauge_vec_with_registry!(
+            "storage_compaction_group_pending_compaction_bytes",
+            "bytes awaiting compaction in each compaction group",
+            &["group"],
+            registry
+        )
+        .unwrap();
+
+        let compaction_group_ingest_bytes = register_int_counter_vec_with_registry!(
+            "storage_compaction_group_ingest_bytes",
+            "cumulative bytes committed to each compaction group",
+            &["group"],
+            registry
+        )
+        .unwrap();
+
         let hummock_manager_lock_time = register_histogram_vec_with_registry!(
             "hummock_manager_lock_time",
             "latency for hummock manager to acquire the rwlock",
@@ -239,6 +315,14 @@ impl MetaMetrics {
         )
         .unwrap();
 
+        let hummock_manager_event_queue_depth = register_int_gauge_vec_with_registry!(
+            "meta_hummock_manager_event_queue_depth",
+            "number of pending events in each hummock manager event worker shard",
+            &["shard"],
+            registry
+        )
+        .unwrap();
+
         let worker_num = register_int_gauge_vec_with_registry!(
             "worker_num",
             "number of nodes in the cluster",
@@ -254,25 +338,33 @@ impl MetaMetrics {
             barrier_latency,
             barrier_wait_commit_latency,
             barrier_send_latency,
+            barrier_slo_violation_count,
             all_barrier_nums,
             in_flight_barrier_nums,
+            agg_distinct_key_count,
+            sink_freshness_ms,
 
             max_committed_epoch,
             safe_epoch,
             min_pinned_epoch,
             level_sst_num,
             level_compact_cnt,
+            level_compaction_score,
+            level_debt_bytes,
             compact_frequency,
             compact_skip_frequency,
             level_file_size,
             version_size,
             version_stats,
+            compaction_group_pending_bytes,
+            compaction_group_ingest_bytes,
             current_version_id,
             checkpoint_version_id,
             min_pinned_version_id,
             min_safepoint_version_id,
             hummock_manager_lock_time,
             hummock_manager_real_process_time,
+            hummock_manager_event_queue_depth,
             time_after_last_observation: AtomicU64::new(0),
 
             worker_num,
@@ -19,6 +19,7 @@ use std::time::Duration;
 use either::Either;
 use etcd_client::ConnectOptions;
 use risingwave_backup::storage::ObjectStoreMetaSnapshotStorage;
+use risingwave_backup::MetaSnapshotRetentionPolicy;
 use risingwave_common::monitor::process_linux::monitor_process;
 use risingwave_common_service::metrics_manager::MetricsManager;
 use risingwave_object_store::object::object_metrics::ObjectStoreMetrics;
@@ -26,8 +27,10 @@ use risingwave_object_store::object::parse_remote_object_store;
 use risingwave_pb::backup_service::backup_service_server::BackupServiceServer;
 use risingwave_pb::ddl_service::ddl_service_server::DdlServiceServer;
 use risingwave_pb::health::health_server::HealthServer;
+use risingwave_pb::hummock::external_compaction_service_server::ExternalCompactionServiceServer;
 use risingwave_pb::hummock::hummock_manager_service_server::HummockManagerServiceServer;
 use risingwave_pb::meta::cluster_service_server::ClusterServiceServer;
+use risingwave_pb::meta::fault_injection_service_server::FaultInjectionServiceServer;
 use risingwave_pb::meta::heartbeat_service_server::HeartbeatServiceServer;
 use risingwave_pb::meta::meta_member_service_server::MetaMemberServiceServer;
 use risingwave_pb::meta::notification_service_server::NotificationServiceServer;
@@ -44,7 +47,7 @@ use super::service::health_service::HealthServiceImpl;
 use super::service::notification_service::NotificationServiceImpl;
 use super::service::scale_service::ScaleServiceImpl;
 use super::DdlServiceImpl;
-use crate::backup_restore::BackupManager;
+use crate::backup_restore::{start_meta_snapshot_scheduler, BackupManager};
 use crate::barrier::{BarrierScheduler, GlobalBarrierManager};
 use crate::hummock::{CompactionScheduler, HummockManager};
 use crate::manager::{
@@ -54,6 +57,8 @@ use crate::rpc::election_client::{ElectionClient, EtcdElectionClient};
 use crate::rpc::metrics::MetaMetrics;
 use crate::rpc::service::backup_service::BackupServiceImpl;
 use crate::rpc::service::cluster_service::ClusterServiceImpl;
+use crate::rpc::service::external_compaction_service::ExternalCompactionServiceImpl;
+use crate::rpc::service::fault_injection_service::FaultInjectionServiceImpl;
 use crate::rpc::service::heartbeat_service::HeartbeatServiceImpl;
 use crate::rpc::service::hummock_service::HummockServiceImpl;
 use crate::rpc::service::meta_member_service::MetaMemberServiceImpl;
@@ -422,10 +427,16 @@ pub async fn start_service_as_election_leader<S: MetaStore>(
     let backup_storage = Arc::new(
         ObjectStoreMetaSnapshotStorage::new(
             &env.opts.backup_storage_directory,
-            backup_object_store,
+            backup_object_store.clone(),
         )
         .await?,
     );
+    if env.opts.hummock_version_checkpoint_export_interval_sec > 0 {
+        hummock_manager.init_version_checkpoint_export(
+            backup_object_store,
+            Duration::from_secs(env.opts.hummock_version_checkpoint_export_interval_sec),
+        );
+    }
     let backup_manager = Arc::new(BackupManager::new(
         env.clone(),
         hummock_manager.clone(),
@@ -460,10 +471,12 @@ pub async fn start_service_as_election_leader<S: MetaStore>(
         stream_manager.clone(),
     );
 
-    let cluster_srv = ClusterServiceImpl::<S>::new(cluster_manager.clone());
+    let cluster_srv =
+        ClusterServiceImpl::<S>::new(cluster_manager.clone(), stream_manager.clone());
     let stream_srv = StreamServiceImpl::<S>::new(
         env.clone(),
         barrier_scheduler.clone(),
+        barrier_manager.clone(),
         fragment_manager.clone(),
     );
     let hummock_srv = HummockServiceImpl::new(
@@ -472,6 +485,8 @@ pub async fn start_service_as_election_leader<S: MetaStore>(
         vacuum_manager.clone(),
         fragment_manager.clone(),
     );
+    let external_compaction_srv =
+        ExternalCompactionServiceImpl::new(hummock_manager.clone(), compactor_manager.clone());
     let notification_srv = NotificationServiceImpl::new(
         env.clone(),
         catalog_manager,
@@ -481,7 +496,14 @@ pub async fn start_service_as_election_leader<S: MetaStore>(
         backup_manager.clone(),
     );
     let health_srv = HealthServiceImpl::new();
-    let backup_srv = BackupServiceImpl::new(backup_manager);
+    let backup_srv = BackupServiceImpl::new(backup_manager.clone());
+    let fault_injection_srv = FaultInjectionServiceImpl::<S>::new(
+        env.clone(),
+        fragment_manager.clone(),
+        cluster_manager.clone(),
+        barrier_manager.clone(),
+        compactor_manager.clone(),
+    );
 
     if let Some(prometheus_addr) = address_info.prometheus_addr {
         MetricsManager::boot_metrics_service(
@@ -498,7 +520,23 @@ pub async fn start_service_as_election_leader<S: MetaStore>(
 
     // sub_tasks executed concurrently. Can be shutdown via shutdown_all
     let mut sub_tasks =
-        hummock::start_hummock_workers(vacuum_manager, compaction_scheduler, &env.opts);
+        hummock::start_hummock_workers(
+            hummock_manager.clone(),
+            vacuum_manager.clone(),
+            compaction_scheduler,
+            &env.opts,
+        );
+    if env.opts.meta_snapshot_interval_sec > 0 {
+        sub_tasks.push(start_meta_snapshot_scheduler(
+            backup_manager,
+            vacuum_manager,
+            Duration::from_secs(env.opts.meta_snapshot_interval_sec),
+            MetaSnapshotRetentionPolicy::new(
+                env.opts.meta_snapshot_retention_hourly,
+                env.opts.meta_snapshot_retention_daily,
+            ),
+        ));
+    }
     sub_tasks.push(
         ClusterManager::start_worker_num_monitor(
             cluster_manager.clone(),
@@ -554,6 +592,7 @@ pub async fn start_service_as_election_leader<S: MetaStore>(
         .add_service(ClusterServiceServer::new(cluster_srv))
         .add_service(StreamManagerServiceServer::new(stream_srv))
         .add_service(HummockManagerServiceServer::new(hummock_srv))
+        .add_service(ExternalCompactionServiceServer::new(external_compaction_srv))
         .add_service(NotificationServiceServer::new(notification_srv))
         .add_service(MetaMemberServiceServer::new(meta_member_srv))
         .add_service(DdlServiceServer::new(ddl_srv))
@@ -561,6 +600,7 @@ pub async fn start_service_as_election_leader<S: MetaStore>(
         .add_service(ScaleServiceServer::new(scale_srv))
         .add_service(HealthServer::new(health_srv))
         .add_service(BackupServiceServer::new(backup_srv))
+        .add_service(FaultInjectionServiceServer::new(fault_injection_srv))
         .serve_with_shutdown(address_info.listen_addr, async move {
             tokio::select! {
                 res = svc_shutdown_rx.changed() => {
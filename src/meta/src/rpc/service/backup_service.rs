@@ -17,8 +17,10 @@ use std::ops::Deref;
 use risingwave_pb::backup_service::backup_service_server::BackupService;
 use risingwave_pb::backup_service::{
     BackupMetaRequest, BackupMetaResponse, DeleteMetaSnapshotRequest, DeleteMetaSnapshotResponse,
-    GetBackupJobStatusRequest, GetBackupJobStatusResponse, GetMetaSnapshotManifestRequest,
-    GetMetaSnapshotManifestResponse,
+    ExportMvSnapshotRequest, ExportMvSnapshotResponse, GetBackupJobStatusRequest,
+    GetBackupJobStatusResponse, GetMetaSnapshotManifestRequest, GetMetaSnapshotManifestResponse,
+    ImportMvSnapshotRequest, ImportMvSnapshotResponse, ReleaseMvSnapshotRequest,
+    ReleaseMvSnapshotResponse,
 };
 use tonic::{Request, Response, Status};
 
@@ -80,4 +82,35 @@ where
             manifest: Some(self.backup_manager.manifest().deref().into()),
         }))
     }
+
+    async fn export_mv_snapshot(
+        &self,
+        request: Request<ExportMvSnapshotRequest>,
+    ) -> Result<Response<ExportMvSnapshotResponse>, Status> {
+        let mv_table_id = request.into_inner().mv_table_id;
+        let (snapshot_id, snapshot) =
+            self.backup_manager.export_mv_snapshot(mv_table_id).await?;
+        Ok(Response::new(ExportMvSnapshotResponse {
+            snapshot_id,
+            snapshot,
+        }))
+    }
+
+    async fn import_mv_snapshot(
+        &self,
+        request: Request<ImportMvSnapshotRequest>,
+    ) -> Result<Response<ImportMvSnapshotResponse>, Status> {
+        let snapshot = request.into_inner().snapshot;
+        self.backup_manager.import_mv_snapshot(&snapshot).await?;
+        Ok(Response::new(ImportMvSnapshotResponse {}))
+    }
+
+    async fn release_mv_snapshot(
+        &self,
+        request: Request<ReleaseMvSnapshotRequest>,
+    ) -> Result<Response<ReleaseMvSnapshotResponse>, Status> {
+        let snapshot_id = request.into_inner().snapshot_id;
+        self.backup_manager.release_mv_snapshot(snapshot_id).await?;
+        Ok(Response::new(ReleaseMvSnapshotResponse {}))
+    }
 }
@@ -15,25 +15,33 @@
 use risingwave_pb::meta::cluster_service_server::ClusterService;
 use risingwave_pb::meta::{
     ActivateWorkerNodeRequest, ActivateWorkerNodeResponse, AddWorkerNodeRequest,
-    AddWorkerNodeResponse, DeleteWorkerNodeRequest, DeleteWorkerNodeResponse, ListAllNodesRequest,
-    ListAllNodesResponse,
+    AddWorkerNodeResponse, DeleteWorkerNodeRequest, DeleteWorkerNodeResponse,
+    DrainWorkerNodeRequest, DrainWorkerNodeResponse, ListAllNodesRequest, ListAllNodesResponse,
 };
 use tonic::{Request, Response, Status};
 
 use crate::manager::ClusterManagerRef;
 use crate::storage::MetaStore;
+use crate::stream::GlobalStreamManagerRef;
 
 #[derive(Clone)]
 pub struct ClusterServiceImpl<S: MetaStore> {
     cluster_manager: ClusterManagerRef<S>,
+    stream_manager: GlobalStreamManagerRef<S>,
 }
 
 impl<S> ClusterServiceImpl<S>
 where
     S: MetaStore,
 {
-    pub fn new(cluster_manager: ClusterManagerRef<S>) -> Self {
-        ClusterServiceImpl { cluster_manager }
+    pub fn new(
+        cluster_manager: ClusterManagerRef<S>,
+        stream_manager: GlobalStreamManagerRef<S>,
+    ) -> Self {
+        ClusterServiceImpl {
+            cluster_manager,
+            stream_manager,
+        }
     }
 }
 
@@ -80,6 +88,16 @@ where
         Ok(Response::new(DeleteWorkerNodeResponse { status: None }))
     }
 
+    async fn drain_worker_node(
+        &self,
+        request: Request<DrainWorkerNodeRequest>,
+    ) -> Result<Response<DrainWorkerNodeResponse>, Status> {
+        let req = request.into_inner();
+        let host = req.get_host()?.clone();
+        self.stream_manager.drain_worker_node(host).await?;
+        Ok(Response::new(DrainWorkerNodeResponse { status: None }))
+    }
+
     async fn list_all_nodes(
         &self,
         request: Request<ListAllNodesRequest>,
@@ -501,6 +501,22 @@ where
         ))
     }
 
+    async fn update_table_stats(
+        &self,
+        request: Request<UpdateTableStatsRequest>,
+    ) -> Result<Response<UpdateTableStatsResponse>, Status> {
+        let req = request.into_inner();
+        let stats = req.get_stats()?.clone();
+        let version = self
+            .catalog_manager
+            .update_table_stats(req.table_id, stats)
+            .await?;
+        Ok(Response::new(UpdateTableStatsResponse {
+            status: None,
+            version,
+        }))
+    }
+
     async fn get_table(
         &self,
         request: Request<GetTableRequest>,
@@ -524,6 +540,14 @@ where
             Ok(Response::new(GetTableResponse { table: None }))
         }
     }
+
+    async fn export_catalog(
+        &self,
+        _request: Request<ExportCatalogRequest>,
+    ) -> Result<Response<ExportCatalogResponse>, Status> {
+        let statements = self.catalog_manager.export_catalog().await;
+        Ok(Response::new(ExportCatalogResponse { statements }))
+    }
 }
 
 impl<S> DdlServiceImpl<S>
@@ -643,8 +667,15 @@ where
         // 8. Build the table fragments structure that will be persisted in the stream manager, and
         // the context that contains all information needed for building the actors on the compute
         // nodes.
-        let table_fragments =
-            TableFragments::new(id.into(), graph, &building_locations.actor_locations, env);
+        // The job joins the barrier domain of its database, so that it is checkpointed together
+        // with the other jobs of the database. See the `domain` module of the barrier manager.
+        let table_fragments = TableFragments::new(
+            id.into(),
+            graph,
+            &building_locations.actor_locations,
+            env,
+            stream_job.database_id(),
+        );
 
         let ctx = CreateStreamingJobContext {
             dispatchers,
@@ -0,0 +1,154 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_pb::hummock::external_compaction_service_server::ExternalCompactionService;
+use risingwave_pb::hummock::*;
+use tonic::{Request, Response, Status};
+
+use crate::hummock::{CompactorManagerRef, HummockManagerRef};
+use crate::storage::MetaStore;
+
+pub struct ExternalCompactionServiceImpl<S>
+where
+    S: MetaStore,
+{
+    hummock_manager: HummockManagerRef<S>,
+    compactor_manager: CompactorManagerRef,
+}
+
+impl<S> ExternalCompactionServiceImpl<S>
+where
+    S: MetaStore,
+{
+    pub fn new(
+        hummock_manager: HummockManagerRef<S>,
+        compactor_manager: CompactorManagerRef,
+    ) -> Self {
+        ExternalCompactionServiceImpl {
+            hummock_manager,
+            compactor_manager,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<S> ExternalCompactionService for ExternalCompactionServiceImpl<S>
+where
+    S: MetaStore,
+{
+    async fn open_session(
+        &self,
+        _request: Request<OpenExternalCompactionSessionRequest>,
+    ) -> Result<Response<OpenExternalCompactionSessionResponse>, Status> {
+        let context_id = self
+            .hummock_manager
+            .register_external_compaction_context()
+            .await?;
+        Ok(Response::new(OpenExternalCompactionSessionResponse {
+            status: None,
+            context_id,
+        }))
+    }
+
+    async fn pull_compact_task(
+        &self,
+        request: Request<PullCompactTaskRequest>,
+    ) -> Result<Response<PullCompactTaskResponse>, Status> {
+        let req = request.into_inner();
+        let compact_task = self
+            .hummock_manager
+            .pull_external_compact_task(req.context_id)
+            .await?;
+        Ok(Response::new(PullCompactTaskResponse {
+            status: None,
+            compact_task,
+        }))
+    }
+
+    async fn heartbeat(
+        &self,
+        request: Request<ExternalCompactionHeartbeatRequest>,
+    ) -> Result<Response<ExternalCompactionHeartbeatResponse>, Status> {
+        let req = request.into_inner();
+        if !self
+            .hummock_manager
+            .is_external_compaction_context(req.context_id)
+        {
+            return Err(Status::invalid_argument(format!(
+                "invalid external compaction session {}",
+                req.context_id
+            )));
+        }
+        self.compactor_manager
+            .update_task_heartbeats(req.context_id, &req.progress);
+        Ok(Response::new(ExternalCompactionHeartbeatResponse {
+            status: None,
+        }))
+    }
+
+    async fn lease_sstable_ids(
+        &self,
+        request: Request<LeaseSstableIdsRequest>,
+    ) -> Result<Response<LeaseSstableIdsResponse>, Status> {
+        let req = request.into_inner();
+        if !self
+            .hummock_manager
+            .is_external_compaction_context(req.context_id)
+        {
+            return Err(Status::invalid_argument(format!(
+                "invalid external compaction session {}",
+                req.context_id
+            )));
+        }
+        let sst_id_range = self.hummock_manager.get_new_sst_ids(req.number).await?;
+        Ok(Response::new(LeaseSstableIdsResponse {
+            status: None,
+            start_id: sst_id_range.start_id,
+            end_id: sst_id_range.end_id,
+        }))
+    }
+
+    async fn report_compact_task(
+        &self,
+        request: Request<ReportExternalCompactTaskRequest>,
+    ) -> Result<Response<ReportExternalCompactTaskResponse>, Status> {
+        let req = request.into_inner();
+        let mut compact_task = req
+            .compact_task
+            .ok_or_else(|| Status::invalid_argument("compact_task is required"))?;
+        self.hummock_manager
+            .report_compact_task(
+                req.context_id,
+                &mut compact_task,
+                Some(req.table_stats_change),
+            )
+            .await?;
+        Ok(Response::new(ReportExternalCompactTaskResponse {
+            status: None,
+        }))
+    }
+
+    async fn close_session(
+        &self,
+        request: Request<CloseExternalCompactionSessionRequest>,
+    ) -> Result<Response<CloseExternalCompactionSessionResponse>, Status> {
+        let req = request.into_inner();
+        self.hummock_manager
+            .unregister_external_compaction_context(req.context_id)
+            .await?;
+        Ok(Response::new(CloseExternalCompactionSessionResponse {
+            status: None,
+        }))
+    }
+}
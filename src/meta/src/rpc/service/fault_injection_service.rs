@@ -0,0 +1,137 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use anyhow::anyhow;
+use risingwave_pb::meta::fault_injection_service_server::FaultInjectionService;
+use risingwave_pb::meta::inject_failure_request::Failure;
+use risingwave_pb::meta::{InjectFailureRequest, InjectFailureResponse};
+use risingwave_pb::stream_service::DropActorsRequest;
+use tonic::{Request, Response, Status};
+use uuid::Uuid;
+
+use crate::barrier::BarrierManagerRef;
+use crate::hummock::CompactorManagerRef;
+use crate::manager::{ClusterManagerRef, FragmentManagerRef, MetaSrvEnv};
+use crate::model::ActorId;
+use crate::storage::MetaStore;
+use crate::MetaResult;
+
+/// Injects controlled failures into the cluster for chaos testing. All operations are gated
+/// behind the `meta.enable_unsafe_fault_injection` config flag.
+///
+/// Simulating object store errors (e.g. S3 503s) is deliberately out of scope: such a fault is
+/// client-side and would have to be toggled inside every object store client process, but meta
+/// has no control channel to them. The per-process `failpoints` mechanism already covers this
+/// use case, e.g. the `s3_read_err` / `s3_upload_err` fail points in `risingwave_object_store`.
+pub struct FaultInjectionServiceImpl<S: MetaStore> {
+    env: MetaSrvEnv<S>,
+    fragment_manager: FragmentManagerRef<S>,
+    cluster_manager: ClusterManagerRef<S>,
+    barrier_manager: BarrierManagerRef<S>,
+    compactor_manager: CompactorManagerRef,
+}
+
+impl<S> FaultInjectionServiceImpl<S>
+where
+    S: MetaStore,
+{
+    pub fn new(
+        env: MetaSrvEnv<S>,
+        fragment_manager: FragmentManagerRef<S>,
+        cluster_manager: ClusterManagerRef<S>,
+        barrier_manager: BarrierManagerRef<S>,
+        compactor_manager: CompactorManagerRef,
+    ) -> Self {
+        Self {
+            env,
+            fragment_manager,
+            cluster_manager,
+            barrier_manager,
+            compactor_manager,
+        }
+    }
+
+    /// Abruptly drop an actor on the compute node hosting it, which will fail the next barrier
+    /// and trigger recovery.
+    async fn kill_actor(&self, actor_id: ActorId) -> MetaResult<()> {
+        let table_fragments = self.fragment_manager.list_table_fragments().await?;
+        let worker_id = table_fragments
+            .iter()
+            .flat_map(|tf| tf.worker_actor_ids())
+            .find_map(|(worker_id, actor_ids)| actor_ids.contains(&actor_id).then_some(worker_id))
+            .ok_or_else(|| anyhow!("actor {} not found", actor_id))?;
+        let worker = self
+            .cluster_manager
+            .get_worker_by_id(worker_id)
+            .await
+            .ok_or_else(|| anyhow!("worker {} not found", worker_id))?;
+        let client = self.env.stream_client_pool().get(&worker.worker_node).await?;
+        client
+            .drop_actors(DropActorsRequest {
+                request_id: Uuid::new_v4().to_string(),
+                actor_ids: vec![actor_id],
+            })
+            .await?;
+        tracing::warn!(
+            "fault injection: killed actor {} on worker {}",
+            actor_id,
+            worker_id
+        );
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl<S> FaultInjectionService for FaultInjectionServiceImpl<S>
+where
+    S: MetaStore,
+{
+    async fn inject_failure(
+        &self,
+        request: Request<InjectFailureRequest>,
+    ) -> Result<Response<InjectFailureResponse>, Status> {
+        if !self.env.opts.enable_unsafe_fault_injection {
+            return Err(Status::permission_denied(
+                "fault injection is disabled, set `meta.enable_unsafe_fault_injection = true` to allow it",
+            ));
+        }
+
+        let failure = request
+            .into_inner()
+            .failure
+            .ok_or_else(|| Status::invalid_argument("no failure specified"))?;
+        match failure {
+            Failure::KillActor(kill_actor) => self.kill_actor(kill_actor.actor_id).await?,
+            Failure::DelayBarrier(delay_barrier) => {
+                tracing::warn!(
+                    "fault injection: delay barrier injection by {} ms",
+                    delay_barrier.delay_ms
+                );
+                self.barrier_manager
+                    .set_inject_barrier_delay(Duration::from_millis(delay_barrier.delay_ms));
+            }
+            Failure::DropCompactor(drop_compactor) => {
+                tracing::warn!(
+                    "fault injection: drop compactor {}",
+                    drop_compactor.context_id
+                );
+                self.compactor_manager
+                    .remove_compactor(drop_compactor.context_id);
+            }
+        }
+        Ok(Response::new(InjectFailureResponse {}))
+    }
+}
@@ -24,7 +24,8 @@ use tonic::{Request, Response, Status};
 
 use crate::hummock::compaction::ManualCompactionOption;
 use crate::hummock::{
-    CompactionResumeTrigger, CompactorManagerRef, HummockManagerRef, VacuumManagerRef,
+    CompactionResumeTrigger, CompactorCapability, CompactorManagerRef, HummockManagerRef,
+    ReportTask, VacuumManagerRef,
 };
 use crate::manager::FragmentManagerRef;
 use crate::rpc::service::RwReceiverStream;
@@ -124,6 +125,20 @@ where
         }))
     }
 
+    async fn truncate_above_epoch(
+        &self,
+        request: Request<TruncateAboveEpochRequest>,
+    ) -> Result<Response<TruncateAboveEpochResponse>, Status> {
+        let request = request.into_inner();
+        let version = self
+            .hummock_manager
+            .truncate_above_epoch(request.epoch)
+            .await?;
+        Ok(Response::new(TruncateAboveEpochResponse {
+            current_version: Some(version),
+        }))
+    }
+
     async fn list_version_deltas(
         &self,
         request: Request<ListVersionDeltasRequest>,
@@ -139,28 +154,39 @@ where
         Ok(Response::new(resp))
     }
 
+    async fn diff_versions(
+        &self,
+        request: Request<DiffVersionsRequest>,
+    ) -> Result<Response<DiffVersionsResponse>, Status> {
+        let req = request.into_inner();
+        let diff = self
+            .hummock_manager
+            .diff_versions(req.from_id, req.to_id)
+            .await?;
+        Ok(Response::new(DiffVersionsResponse { diff: Some(diff) }))
+    }
+
     async fn report_compaction_tasks(
         &self,
         request: Request<ReportCompactionTasksRequest>,
     ) -> Result<Response<ReportCompactionTasksResponse>, Status> {
         let req = request.into_inner();
-        match req.compact_task {
-            None => Ok(Response::new(ReportCompactionTasksResponse {
-                status: None,
-            })),
-            Some(mut compact_task) => {
-                self.hummock_manager
-                    .report_compact_task(
-                        req.context_id,
-                        &mut compact_task,
-                        Some(req.table_stats_change),
-                    )
-                    .await?;
-                Ok(Response::new(ReportCompactionTasksResponse {
-                    status: None,
-                }))
-            }
+        let mut report_tasks = req
+            .report_tasks
+            .into_iter()
+            .filter_map(|report_task| {
+                report_task.compact_task.map(|compact_task| ReportTask {
+                    compact_task,
+                    table_stats_change: Some(report_task.table_stats_change),
+                })
+            })
+            .collect_vec();
+        if !report_tasks.is_empty() {
+            self.hummock_manager
+                .report_compact_tasks(req.context_id, &mut report_tasks)
+                .await?;
         }
+        Ok(Response::new(ReportCompactionTasksResponse { status: None }))
     }
 
     async fn pin_specific_snapshot(
@@ -242,6 +268,15 @@ where
         let rx = self
             .compactor_manager
             .add_compactor(context_id, req.max_concurrent_task_number);
+        if req.cpu_core_num > 0 {
+            self.compactor_manager.set_compactor_capability(
+                context_id,
+                CompactorCapability {
+                    cpu_core_num: req.cpu_core_num,
+                    total_memory_bytes: req.total_memory_bytes,
+                },
+            );
+        }
         // Trigger compaction on all compaction groups.
         for cg_id in self.hummock_manager.compaction_group_ids().await {
             self.hummock_manager
@@ -351,6 +386,31 @@ where
         }))
     }
 
+    async fn compact_table(
+        &self,
+        request: Request<CompactTableRequest>,
+    ) -> Result<Response<CompactTableResponse>, Status> {
+        let request = request.into_inner();
+        let to_bound = |epoch: u64| if epoch == 0 { None } else { Some(epoch) };
+
+        tracing::info!(
+            "Try compact_table table_id {} epoch range [{}, {}]",
+            request.table_id,
+            request.start_epoch,
+            request.end_epoch
+        );
+
+        self.hummock_manager
+            .trigger_compaction_for_table(
+                request.table_id,
+                to_bound(request.start_epoch),
+                to_bound(request.end_epoch),
+            )
+            .await?;
+
+        Ok(Response::new(CompactTableResponse { status: None }))
+    }
+
     async fn get_epoch(
         &self,
         _request: Request<GetEpochRequest>,
@@ -362,6 +422,49 @@ where
         }))
     }
 
+    async fn list_epoch_commit_times(
+        &self,
+        _request: Request<ListEpochCommitTimesRequest>,
+    ) -> Result<Response<ListEpochCommitTimesResponse>, Status> {
+        let commit_times = self.hummock_manager.list_epoch_commit_times().await;
+        Ok(Response::new(ListEpochCommitTimesResponse { commit_times }))
+    }
+
+    async fn list_compact_task_summaries(
+        &self,
+        _request: Request<ListCompactTaskSummariesRequest>,
+    ) -> Result<Response<ListCompactTaskSummariesResponse>, Status> {
+        let task_summaries = self.hummock_manager.list_compact_task_summaries().await;
+        Ok(Response::new(ListCompactTaskSummariesResponse {
+            task_summaries,
+        }))
+    }
+
+    async fn list_level_summaries(
+        &self,
+        _request: Request<ListLevelSummariesRequest>,
+    ) -> Result<Response<ListLevelSummariesResponse>, Status> {
+        let level_summaries = self.hummock_manager.list_level_summaries().await;
+        Ok(Response::new(ListLevelSummariesResponse { level_summaries }))
+    }
+
+    async fn get_compaction_scores(
+        &self,
+        _request: Request<GetCompactionScoresRequest>,
+    ) -> Result<Response<GetCompactionScoresResponse>, Status> {
+        let group_scores = self.hummock_manager.get_compaction_scores().await;
+        Ok(Response::new(GetCompactionScoresResponse { group_scores }))
+    }
+
+    async fn report_corrupted_ssts(
+        &self,
+        request: Request<ReportCorruptedSstsRequest>,
+    ) -> Result<Response<ReportCorruptedSstsResponse>, Status> {
+        let req = request.into_inner();
+        self.hummock_manager.report_corrupted_ssts(req.sst_ids).await;
+        Ok(Response::new(ReportCorruptedSstsResponse { status: None }))
+    }
+
     async fn report_full_scan_task(
         &self,
         request: Request<ReportFullScanTaskRequest>,
@@ -397,6 +500,23 @@ where
         Ok(Response::new(TriggerFullGcResponse { status: None }))
     }
 
+    async fn get_dropped_table_ids(
+        &self,
+        request: Request<GetDroppedTableIdsRequest>,
+    ) -> Result<Response<GetDroppedTableIdsResponse>, Status> {
+        let registered_table_ids = self.hummock_manager.all_table_ids().await;
+        let dropped_table_ids = request
+            .into_inner()
+            .table_ids
+            .into_iter()
+            .filter(|table_id| !registered_table_ids.contains(table_id))
+            .collect_vec();
+        Ok(Response::new(GetDroppedTableIdsResponse {
+            status: None,
+            dropped_table_ids,
+        }))
+    }
+
     async fn rise_ctl_get_pinned_versions_summary(
         &self,
         _request: Request<RiseCtlGetPinnedVersionsSummaryRequest>,
@@ -414,6 +534,17 @@ where
         }))
     }
 
+    async fn rise_ctl_reset_compactor_blacklist(
+        &self,
+        request: Request<RiseCtlResetCompactorBlacklistRequest>,
+    ) -> Result<Response<RiseCtlResetCompactorBlacklistResponse>, Status> {
+        self.compactor_manager
+            .reset_compactor_blacklist(request.into_inner().context_id);
+        Ok(Response::new(RiseCtlResetCompactorBlacklistResponse {
+            status: None,
+        }))
+    }
+
     async fn rise_ctl_get_pinned_snapshots_summary(
         &self,
         _request: Request<RiseCtlGetPinnedSnapshotsSummaryRequest>,
@@ -458,6 +589,44 @@ where
         }))
     }
 
+    async fn rise_ctl_describe_compaction_group(
+        &self,
+        request: Request<RiseCtlDescribeCompactionGroupRequest>,
+    ) -> Result<Response<RiseCtlDescribeCompactionGroupResponse>, Status> {
+        let group_id = request.into_inner().compaction_group_id;
+        let compaction_group = self
+            .hummock_manager
+            .compaction_group(group_id)
+            .await
+            .ok_or_else(|| {
+                Status::new(
+                    tonic::Code::NotFound,
+                    format!("invalid compaction group {}", group_id),
+                )
+            })?;
+        let pending_compact_task_num = self
+            .hummock_manager
+            .get_assigned_compact_task_num_by_group(group_id)
+            .await;
+        Ok(Response::new(RiseCtlDescribeCompactionGroupResponse {
+            status: None,
+            compaction_group: Some((&compaction_group).into()),
+            pending_compact_task_num,
+        }))
+    }
+
+    async fn rise_ctl_get_lock_contention(
+        &self,
+        request: Request<RiseCtlGetLockContentionRequest>,
+    ) -> Result<Response<RiseCtlGetLockContentionResponse>, Status> {
+        let top_n = request.into_inner().top_n;
+        let contentions = self.hummock_manager.get_lock_contention(top_n as usize);
+        Ok(Response::new(RiseCtlGetLockContentionResponse {
+            status: None,
+            contentions,
+        }))
+    }
+
     async fn rise_ctl_update_compaction_config(
         &self,
         request: Request<RiseCtlUpdateCompactionConfigRequest>,
@@ -481,6 +650,37 @@ where
         }))
     }
 
+    async fn rise_ctl_split_compaction_group(
+        &self,
+        request: Request<RiseCtlSplitCompactionGroupRequest>,
+    ) -> Result<Response<RiseCtlSplitCompactionGroupResponse>, Status> {
+        let RiseCtlSplitCompactionGroupRequest {
+            group_id,
+            table_ids,
+        } = request.into_inner();
+        let new_group_id = self
+            .hummock_manager
+            .split_compaction_group(group_id, &table_ids)
+            .await?;
+        Ok(Response::new(RiseCtlSplitCompactionGroupResponse {
+            new_group_id,
+        }))
+    }
+
+    async fn rise_ctl_merge_compaction_group(
+        &self,
+        request: Request<RiseCtlMergeCompactionGroupRequest>,
+    ) -> Result<Response<RiseCtlMergeCompactionGroupResponse>, Status> {
+        let RiseCtlMergeCompactionGroupRequest {
+            left_group_id,
+            right_group_id,
+        } = request.into_inner();
+        self.hummock_manager
+            .merge_compaction_group(left_group_id, right_group_id)
+            .await?;
+        Ok(Response::new(RiseCtlMergeCompactionGroupResponse {}))
+    }
+
     async fn init_metadata_for_replay(
         &self,
         request: Request<InitMetadataForReplayRequest>,
@@ -521,4 +721,59 @@ where
             }
         }
     }
+
+    async fn get_compaction_scaling_hint(
+        &self,
+        _request: Request<GetCompactionScalingHintRequest>,
+    ) -> Result<Response<GetCompactionScalingHintResponse>, Status> {
+        let (group_stats, recommended_capacity) =
+            self.hummock_manager.get_compaction_scaling_hint().await;
+        Ok(Response::new(GetCompactionScalingHintResponse {
+            group_stats,
+            recommended_capacity,
+        }))
+    }
+
+    async fn acquire_iterator_lease(
+        &self,
+        request: Request<AcquireIteratorLeaseRequest>,
+    ) -> Result<Response<AcquireIteratorLeaseResponse>, Status> {
+        let req = request.into_inner();
+        let lease_id = self
+            .hummock_manager
+            .acquire_iterator_lease(req.epoch, Duration::from_secs(req.ttl_sec));
+        Ok(Response::new(AcquireIteratorLeaseResponse { lease_id }))
+    }
+
+    async fn renew_iterator_lease(
+        &self,
+        request: Request<RenewIteratorLeaseRequest>,
+    ) -> Result<Response<RenewIteratorLeaseResponse>, Status> {
+        let req = request.into_inner();
+        let renewed = self
+            .hummock_manager
+            .renew_iterator_lease(req.lease_id, Duration::from_secs(req.ttl_sec));
+        Ok(Response::new(RenewIteratorLeaseResponse { renewed }))
+    }
+
+    async fn release_iterator_lease(
+        &self,
+        request: Request<ReleaseIteratorLeaseRequest>,
+    ) -> Result<Response<ReleaseIteratorLeaseResponse>, Status> {
+        let req = request.into_inner();
+        self.hummock_manager.release_iterator_lease(req.lease_id);
+        Ok(Response::new(ReleaseIteratorLeaseResponse {}))
+    }
+
+    async fn get_iterator_lease(
+        &self,
+        request: Request<GetIteratorLeaseRequest>,
+    ) -> Result<Response<GetIteratorLeaseResponse>, Status> {
+        let req = request.into_inner();
+        let epoch = self.hummock_manager.get_iterator_lease_epoch(req.lease_id);
+        Ok(Response::new(GetIteratorLeaseResponse {
+            valid: epoch.is_some(),
+            epoch: epoch.unwrap_or(0),
+        }))
+    }
 }
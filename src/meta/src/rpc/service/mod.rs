@@ -15,6 +15,8 @@
 pub mod backup_service;
 pub mod cluster_service;
 pub mod ddl_service;
+pub mod external_compaction_service;
+pub mod fault_injection_service;
 pub mod health_service;
 pub mod heartbeat_service;
 pub mod hummock_service;
@@ -22,7 +22,7 @@ use risingwave_pb::meta::stream_manager_service_server::StreamManagerService;
 use risingwave_pb::meta::*;
 use tonic::{Request, Response, Status};
 
-use crate::barrier::BarrierScheduler;
+use crate::barrier::{BarrierManagerRef, BarrierScheduler, Command};
 use crate::manager::{FragmentManagerRef, MetaSrvEnv};
 use crate::storage::MetaStore;
 
@@ -35,6 +35,7 @@ where
 {
     env: MetaSrvEnv<S>,
     barrier_scheduler: BarrierScheduler<S>,
+    barrier_manager: BarrierManagerRef<S>,
     fragment_manager: FragmentManagerRef<S>,
 }
 
@@ -45,11 +46,13 @@ where
     pub fn new(
         env: MetaSrvEnv<S>,
         barrier_scheduler: BarrierScheduler<S>,
+        barrier_manager: BarrierManagerRef<S>,
         fragment_manager: FragmentManagerRef<S>,
     ) -> Self {
         StreamServiceImpl {
             env,
             barrier_scheduler,
+            barrier_manager,
             fragment_manager,
         }
     }
@@ -72,6 +75,20 @@ where
         }))
     }
 
+    #[cfg_attr(coverage, no_coverage)]
+    async fn update_streaming_config(
+        &self,
+        request: Request<UpdateStreamingConfigRequest>,
+    ) -> TonicResponse<UpdateStreamingConfigResponse> {
+        self.env.idle_manager().record_activity();
+        let req = request.into_inner();
+
+        self.barrier_scheduler
+            .run_command(Command::config_change(req.entries))
+            .await?;
+        Ok(Response::new(UpdateStreamingConfigResponse { status: None }))
+    }
+
     #[cfg_attr(coverage, no_coverage)]
     async fn list_table_fragments(
         &self,
@@ -84,21 +101,27 @@ where
             .into_iter()
             .filter(|tf| table_ids.contains(&tf.table_id().table_id))
             .map(|tf| {
+                let actor_status = &tf.actor_status;
                 (
                     tf.table_id().table_id,
                     TableFragmentInfo {
                         fragments: tf
                             .fragments
-                            .into_iter()
-                            .map(|(id, fragment)| FragmentInfo {
+                            .iter()
+                            .map(|(&id, fragment)| FragmentInfo {
                                 id,
                                 actors: fragment
                                     .actors
-                                    .into_iter()
+                                    .iter()
                                     .map(|actor| ActorInfo {
                                         id: actor.actor_id,
-                                        node: actor.nodes,
-                                        dispatcher: actor.dispatcher,
+                                        node: actor.nodes.clone(),
+                                        dispatcher: actor.dispatcher.clone(),
+                                        worker_id: actor_status
+                                            .get(&actor.actor_id)
+                                            .and_then(|status| status.parallel_unit.as_ref())
+                                            .map(|parallel_unit| parallel_unit.worker_node_id)
+                                            .unwrap_or_default(),
                                     })
                                     .collect_vec(),
                             })
@@ -113,4 +136,69 @@ where
             table_fragments: info,
         }))
     }
+
+    #[cfg_attr(coverage, no_coverage)]
+    async fn list_mv_status(
+        &self,
+        _request: Request<ListMvStatusRequest>,
+    ) -> Result<Response<ListMvStatusResponse>, Status> {
+        let mv_status = self.barrier_manager.list_mv_status().await?;
+        Ok(Response::new(ListMvStatusResponse {
+            status: None,
+            mv_status,
+        }))
+    }
+
+    #[cfg_attr(coverage, no_coverage)]
+    async fn list_sink_freshness(
+        &self,
+        _request: Request<ListSinkFreshnessRequest>,
+    ) -> Result<Response<ListSinkFreshnessResponse>, Status> {
+        let sink_freshness = self.barrier_manager.list_sink_freshness().await?;
+        Ok(Response::new(ListSinkFreshnessResponse {
+            status: None,
+            sink_freshness,
+        }))
+    }
+
+    #[cfg_attr(coverage, no_coverage)]
+    async fn list_backpressure(
+        &self,
+        request: Request<ListBackpressureRequest>,
+    ) -> Result<Response<ListBackpressureResponse>, Status> {
+        let req = request.into_inner();
+        let edges = self
+            .barrier_manager
+            .list_backpressure(req.limit as usize)
+            .await?;
+        Ok(Response::new(ListBackpressureResponse {
+            status: None,
+            edges,
+        }))
+    }
+
+    #[cfg_attr(coverage, no_coverage)]
+    async fn get_recovery_progress(
+        &self,
+        _request: Request<GetRecoveryProgressRequest>,
+    ) -> Result<Response<GetRecoveryProgressResponse>, Status> {
+        let progress = self.barrier_manager.get_recovery_progress().await;
+        Ok(Response::new(GetRecoveryProgressResponse {
+            status: None,
+            progress: Some(progress),
+        }))
+    }
+
+    #[cfg_attr(coverage, no_coverage)]
+    async fn get_barrier_trace(
+        &self,
+        request: Request<GetBarrierTraceRequest>,
+    ) -> Result<Response<GetBarrierTraceResponse>, Status> {
+        let req = request.into_inner();
+        let trace = self.barrier_manager.get_barrier_trace(req.prev_epoch).await?;
+        Ok(Response::new(GetBarrierTraceResponse {
+            status: None,
+            trace: Some(trace),
+        }))
+    }
 }
@@ -25,7 +25,9 @@ use risingwave_common::bail;
 use risingwave_common::buffer::{Bitmap, BitmapBuilder};
 use risingwave_common::hash::{ActorMapping, ParallelUnitId, VirtualNode};
 use risingwave_common::util::iter_util::ZipEqDebug;
-use risingwave_pb::common::{worker_node, ActorInfo, ParallelUnit, WorkerNode, WorkerType};
+use risingwave_pb::common::{
+    worker_node, ActorInfo, HostAddress, ParallelUnit, WorkerNode, WorkerType,
+};
 use risingwave_pb::meta::table_fragments::actor_status::ActorState;
 use risingwave_pb::meta::table_fragments::fragment::FragmentDistributionType;
 use risingwave_pb::meta::table_fragments::{self, ActorStatus, Fragment};
@@ -41,7 +43,7 @@ use uuid::Uuid;
 
 use crate::barrier::{Command, Reschedule};
 use crate::manager::{IdCategory, WorkerId};
-use crate::model::{ActorId, DispatcherId, FragmentId, TableFragments};
+use crate::model::{ActorId, DispatcherId, FragmentId, TableFragments, Worker};
 use crate::storage::MetaStore;
 use crate::stream::GlobalStreamManager;
 use crate::MetaResult;
@@ -578,6 +580,145 @@ where
         Ok(())
     }
 
+    /// Gracefully drain a compute node for decommission. The worker is first excluded from the
+    /// scheduling of new streaming jobs, then the in-flight barriers are flushed and all actors on
+    /// it are migrated to the remaining compute nodes through a regular reschedule, and finally
+    /// the worker is unregistered from the cluster. The worker process itself keeps running and
+    /// can be terminated afterwards.
+    pub async fn drain_worker_node(&self, host_address: HostAddress) -> MetaResult<()> {
+        let worker = self
+            .cluster_manager
+            .mark_worker_node_draining(host_address.clone())
+            .await?;
+
+        if let Err(e) = self.drain_worker_node_impl(&worker).await {
+            // Return the worker to scheduling so that a failed drain does not leave it idle.
+            self.cluster_manager
+                .cancel_worker_node_draining(host_address)
+                .await;
+            return Err(e);
+        }
+
+        self.cluster_manager
+            .delete_worker_node(host_address)
+            .await?;
+        Ok(())
+    }
+
+    async fn drain_worker_node_impl(&self, worker: &Worker) -> MetaResult<()> {
+        // Wait for the in-flight barriers to complete before computing the migration plan.
+        self.barrier_scheduler.flush(true).await?;
+
+        let draining_parallel_units: HashSet<ParallelUnitId> = worker
+            .worker_node
+            .parallel_units
+            .iter()
+            .map(|parallel_unit| parallel_unit.id)
+            .collect();
+
+        // Parallel units that can host the migrated actors. The draining worker is already
+        // excluded here.
+        let target_parallel_units: Vec<ParallelUnitId> = self
+            .cluster_manager
+            .list_active_parallel_units()
+            .await
+            .into_iter()
+            .map(|parallel_unit| parallel_unit.id)
+            .sorted()
+            .collect();
+        if target_parallel_units.is_empty() {
+            bail!(
+                "cannot drain worker {}: no other active compute node in the cluster",
+                worker.worker_id()
+            );
+        }
+        let mut next_target = 0;
+
+        let table_fragments = self.fragment_manager.list_table_fragments().await?;
+
+        // Reschedules for NoShuffle downstream fragments (e.g. Chain) are derived from their
+        // upstream fragments by the reschedule context, so they must be skipped here.
+        let mut actor_map = HashMap::new();
+        for table_fragments in &table_fragments {
+            actor_map.extend(table_fragments.actor_map());
+        }
+        let mut no_shuffle_target_fragment_ids = HashSet::new();
+        for actor in actor_map.values() {
+            for dispatcher in &actor.dispatcher {
+                if dispatcher.r#type() == DispatcherType::NoShuffle {
+                    for downstream_actor_id in &dispatcher.downstream_actor_id {
+                        if let Some(downstream_actor) = actor_map.get(downstream_actor_id) {
+                            no_shuffle_target_fragment_ids.insert(downstream_actor.fragment_id);
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut reschedules = HashMap::new();
+        for table_fragments in &table_fragments {
+            for (fragment_id, fragment) in &table_fragments.fragments {
+                if no_shuffle_target_fragment_ids.contains(fragment_id) {
+                    continue;
+                }
+                let current_parallel_units: BTreeSet<ParallelUnitId> = fragment
+                    .actors
+                    .iter()
+                    .map(|actor| {
+                        table_fragments.actor_status[&actor.actor_id]
+                            .get_parallel_unit()
+                            .unwrap()
+                            .id
+                    })
+                    .collect();
+                let removed_parallel_units: Vec<_> = current_parallel_units
+                    .iter()
+                    .copied()
+                    .filter(|parallel_unit_id| draining_parallel_units.contains(parallel_unit_id))
+                    .collect();
+                if removed_parallel_units.is_empty() {
+                    continue;
+                }
+
+                // Plain removal suffices when the fragment keeps actors on other workers. When
+                // all of its actors live on the draining worker, replacements are picked
+                // round-robin from the remaining parallel units, which also keeps single
+                // distribution fragments a one-to-one migration.
+                let added_parallel_units: Vec<_> =
+                    if removed_parallel_units.len() == current_parallel_units.len() {
+                        let count = removed_parallel_units.len().min(target_parallel_units.len());
+                        (0..count)
+                            .map(|_| {
+                                let idx = next_target % target_parallel_units.len();
+                                next_target += 1;
+                                target_parallel_units[idx]
+                            })
+                            .collect()
+                    } else {
+                        vec![]
+                    };
+
+                reschedules.insert(
+                    *fragment_id,
+                    ParallelUnitReschedule {
+                        added_parallel_units,
+                        removed_parallel_units,
+                    },
+                );
+            }
+        }
+
+        if !reschedules.is_empty() {
+            tracing::info!(
+                "draining worker {} with reschedule plan {:?}",
+                worker.worker_id(),
+                reschedules
+            );
+            self.reschedule_actors(reschedules).await?;
+        }
+        Ok(())
+    }
+
     async fn reschedule_actors_impl(
         &self,
         revert_funcs: &mut Vec<BoxFuture<'_, ()>>,
@@ -119,6 +119,9 @@ where
             NodeBody::GroupTopN(node) => {
                 always!(node.table, "GroupTopN");
             }
+            NodeBody::AppendOnlyDedup(node) => {
+                always!(node.state_table, "AppendOnlyDedup");
+            }
 
             // Source
             NodeBody::Source(node) => {
@@ -139,6 +142,14 @@ where
                 always!(node.table, "Arrange");
             }
 
+            // Sink
+            NodeBody::Sink(node) => {
+                // The log store table is only present when the sink log store is enabled.
+                if node.log_store_table.is_some() {
+                    always!(node.log_store_table, "SinkLogStore");
+                }
+            }
+
             // Note: add internal tables for new nodes here.
             _ => {}
         }
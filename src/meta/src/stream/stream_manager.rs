@@ -379,7 +379,7 @@ mod tests {
     use tonic::{Request, Response, Status};
 
     use super::*;
-    use crate::barrier::GlobalBarrierManager;
+    use crate::barrier::{GlobalBarrierManager, GLOBAL_BARRIER_DOMAIN};
     use crate::hummock::{CompactorManager, HummockManager};
     use crate::manager::{
         CatalogManager, CatalogManagerRef, ClusterManager, FragmentManager, MetaSrvEnv,
@@ -632,6 +632,7 @@ mod tests {
                 fragments,
                 &locations.actor_locations,
                 Default::default(),
+                GLOBAL_BARRIER_DOMAIN,
             );
             let ctx = CreateStreamingJobContext {
                 building_locations: locations,
@@ -65,6 +65,9 @@ pub trait HummockMetaClient: Send + Sync + 'static {
         table_id: u32,
         level: u32,
     ) -> Result<()>;
+    async fn report_corrupted_ssts(&self, sst_ids: Vec<HummockSstableId>) -> Result<()>;
     async fn report_full_scan_task(&self, sst_ids: Vec<HummockSstableId>) -> Result<()>;
     async fn trigger_full_gc(&self, sst_retention_time_sec: u64) -> Result<()>;
+    /// Returns the subset of `table_ids` whose state tables have been dropped.
+    async fn get_dropped_table_ids(&self, table_ids: Vec<u32>) -> Result<Vec<u32>>;
 }
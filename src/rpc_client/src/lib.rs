@@ -50,6 +50,7 @@ mod hummock_meta_client;
 mod meta_client;
 // mod sink_client;
 mod stream_client;
+pub mod tls;
 
 pub use compute_client::{ComputeClient, ComputeClientPool, ComputeClientPoolRef};
 pub use connector_client::ConnectorClient;
@@ -26,6 +26,7 @@ use itertools::Itertools;
 use risingwave_common::catalog::{CatalogVersion, FunctionId, IndexId, TableId};
 use risingwave_common::config::MAX_CONNECTION_WINDOW_SIZE;
 use risingwave_common::util::addr::HostAddr;
+use risingwave_common::util::resource_util;
 use risingwave_hummock_sdk::compact::CompactorRuntimeConfig;
 use risingwave_hummock_sdk::table_stats::to_prost_table_stats_map;
 use risingwave_hummock_sdk::{
@@ -37,13 +38,14 @@ use risingwave_pb::backup_service::*;
 use risingwave_pb::catalog::{
     Database as ProstDatabase, Function as ProstFunction, Index as ProstIndex,
     Schema as ProstSchema, Sink as ProstSink, Source as ProstSource, Table as ProstTable,
-    View as ProstView,
+    TableStats as ProstTableStats, View as ProstView,
 };
-use risingwave_pb::common::{HostAddress, WorkerType};
+use risingwave_pb::common::{HostAddress, WorkerNode, WorkerType};
 use risingwave_pb::ddl_service::ddl_service_client::DdlServiceClient;
 use risingwave_pb::ddl_service::drop_table_request::SourceId;
 use risingwave_pb::ddl_service::*;
 use risingwave_pb::hummock::hummock_manager_service_client::HummockManagerServiceClient;
+use risingwave_pb::hummock::report_compaction_tasks_request::ReportTask;
 use risingwave_pb::hummock::rise_ctl_update_compaction_config_request::mutable_config::MutableConfig;
 use risingwave_pb::hummock::*;
 use risingwave_pb::meta::cluster_service_client::ClusterServiceClient;
@@ -201,6 +203,16 @@ impl MetaClient {
         Ok(())
     }
 
+    /// Gracefully drain the current node: the meta service migrates all actors away and then
+    /// unregisters it from the cluster.
+    pub async fn drain(&self) -> Result<()> {
+        let request = DrainWorkerNodeRequest {
+            host: Some(self.host_addr.to_protobuf()),
+        };
+        self.inner.drain_worker_node(request).await?;
+        Ok(())
+    }
+
     /// Send heartbeat signal to meta service.
     pub async fn send_heartbeat(&self, node_id: u32, info: Vec<extra_info::Info>) -> Result<()> {
         let request = HeartbeatRequest {
@@ -460,6 +472,20 @@ impl MetaClient {
         Ok(resp.version)
     }
 
+    /// List all nodes of the given type in the cluster.
+    pub async fn list_all_nodes(
+        &self,
+        worker_type: WorkerType,
+        include_starting_nodes: bool,
+    ) -> Result<Vec<WorkerNode>> {
+        let request = ListAllNodesRequest {
+            worker_type: worker_type as i32,
+            include_starting_nodes,
+        };
+        let resp = self.inner.list_all_nodes(request).await?;
+        Ok(resp.nodes)
+    }
+
     /// Unregister the current node to the cluster.
     pub async fn unregister(&self, addr: HostAddr) -> Result<()> {
         let request = DeleteWorkerNodeRequest {
@@ -537,12 +563,37 @@ impl MetaClient {
         Ok(resp.tables)
     }
 
+    pub async fn update_table_stats(
+        &self,
+        table_id: u32,
+        stats: ProstTableStats,
+    ) -> Result<CatalogVersion> {
+        let request = UpdateTableStatsRequest {
+            table_id,
+            stats: Some(stats),
+        };
+        let resp = self.inner.update_table_stats(request).await?;
+        Ok(resp.version)
+    }
+
+    pub async fn export_catalog(&self) -> Result<Vec<String>> {
+        let request = ExportCatalogRequest {};
+        let resp = self.inner.export_catalog(request).await?;
+        Ok(resp.statements)
+    }
+
     pub async fn flush(&self, checkpoint: bool) -> Result<HummockSnapshot> {
         let request = FlushRequest { checkpoint };
         let resp = self.inner.flush(request).await?;
         Ok(resp.snapshot.unwrap())
     }
 
+    pub async fn update_streaming_config(&self, entries: HashMap<String, String>) -> Result<()> {
+        let request = UpdateStreamingConfigRequest { entries };
+        let _resp = self.inner.update_streaming_config(request).await?;
+        Ok(())
+    }
+
     pub async fn list_table_fragments(
         &self,
         table_ids: &[u32],
@@ -554,6 +605,36 @@ impl MetaClient {
         Ok(resp.table_fragments)
     }
 
+    pub async fn list_mv_status(&self) -> Result<Vec<MvStatus>> {
+        let request = ListMvStatusRequest {};
+        let resp = self.inner.list_mv_status(request).await?;
+        Ok(resp.mv_status)
+    }
+
+    pub async fn list_sink_freshness(&self) -> Result<Vec<SinkFreshness>> {
+        let request = ListSinkFreshnessRequest {};
+        let resp = self.inner.list_sink_freshness(request).await?;
+        Ok(resp.sink_freshness)
+    }
+
+    pub async fn list_backpressure(&self, limit: u32) -> Result<Vec<BackpressureEdge>> {
+        let request = ListBackpressureRequest { limit };
+        let resp = self.inner.list_backpressure(request).await?;
+        Ok(resp.edges)
+    }
+
+    pub async fn get_recovery_progress(&self) -> Result<RecoveryProgress> {
+        let request = GetRecoveryProgressRequest {};
+        let resp = self.inner.get_recovery_progress(request).await?;
+        Ok(resp.progress.unwrap())
+    }
+
+    pub async fn get_barrier_trace(&self, prev_epoch: u64) -> Result<BarrierTrace> {
+        let request = GetBarrierTraceRequest { prev_epoch };
+        let resp = self.inner.get_barrier_trace(request).await?;
+        Ok(resp.trace.unwrap())
+    }
+
     pub async fn pause(&self) -> Result<()> {
         let request = PauseRequest {};
         let _resp = self.inner.pause(request).await?;
@@ -596,6 +677,12 @@ impl MetaClient {
             .await
     }
 
+    pub async fn risectl_reset_compactor_blacklist(&self, context_id: u32) -> Result<()> {
+        let request = RiseCtlResetCompactorBlacklistRequest { context_id };
+        self.inner.rise_ctl_reset_compactor_blacklist(request).await?;
+        Ok(())
+    }
+
     pub async fn init_metadata_for_replay(
         &self,
         tables: Vec<ProstTable>,
@@ -661,6 +748,35 @@ impl MetaClient {
         Ok(())
     }
 
+    /// Summarizes what changed between two hummock versions. See
+    /// `HummockManager::diff_versions`.
+    pub async fn diff_versions(&self, from_id: u64, to_id: u64) -> Result<VersionDiff> {
+        let req = DiffVersionsRequest { from_id, to_id };
+        let resp = self.inner.diff_versions(req).await?;
+        Ok(resp.diff.unwrap_or_default())
+    }
+
+    /// Lists wall-clock commit times of recent epochs recorded by meta, oldest first.
+    pub async fn list_epoch_commit_times(&self) -> Result<Vec<EpochCommitTime>> {
+        let req = ListEpochCommitTimesRequest {};
+        let resp = self.inner.list_epoch_commit_times(req).await?;
+        Ok(resp.commit_times)
+    }
+
+    /// Lists summaries of compaction tasks currently assigned to compactors.
+    pub async fn list_compact_task_summaries(&self) -> Result<Vec<CompactTaskSummary>> {
+        let req = ListCompactTaskSummariesRequest {};
+        let resp = self.inner.list_compact_task_summaries(req).await?;
+        Ok(resp.task_summaries)
+    }
+
+    /// Lists per-level SST counts and sizes of the current hummock version.
+    pub async fn list_level_summaries(&self) -> Result<Vec<LevelSummary>> {
+        let req = ListLevelSummariesRequest {};
+        let resp = self.inner.list_level_summaries(req).await?;
+        Ok(resp.level_summaries)
+    }
+
     pub async fn disable_commit_epoch(&self) -> Result<HummockVersion> {
         let req = DisableCommitEpochRequest {};
         Ok(self
@@ -671,6 +787,18 @@ impl MetaClient {
             .unwrap())
     }
 
+    /// Truncates all hummock data above `epoch`. Commit epochs must be disabled beforehand via
+    /// [`Self::disable_commit_epoch`].
+    pub async fn truncate_above_epoch(&self, epoch: HummockEpoch) -> Result<HummockVersion> {
+        let req = TruncateAboveEpochRequest { epoch };
+        Ok(self
+            .inner
+            .truncate_above_epoch(req)
+            .await?
+            .current_version
+            .unwrap())
+    }
+
     pub async fn pin_specific_snapshot(&self, epoch: HummockEpoch) -> Result<HummockSnapshot> {
         let req = PinSpecificSnapshotRequest {
             context_id: self.worker_id(),
@@ -692,6 +820,40 @@ impl MetaClient {
         Ok(resp.compaction_groups)
     }
 
+    pub async fn risectl_describe_compaction_group(
+        &self,
+        compaction_group_id: CompactionGroupId,
+    ) -> Result<RiseCtlDescribeCompactionGroupResponse> {
+        let req = RiseCtlDescribeCompactionGroupRequest {
+            compaction_group_id,
+        };
+        let resp = self.inner.rise_ctl_describe_compaction_group(req).await?;
+        Ok(resp)
+    }
+
+    /// Triggers a manual compaction for a single state table. `start_epoch` / `end_epoch` of 0
+    /// mean unbounded.
+    pub async fn compact_table(
+        &self,
+        table_id: u32,
+        start_epoch: u64,
+        end_epoch: u64,
+    ) -> Result<()> {
+        let req = CompactTableRequest {
+            table_id,
+            start_epoch,
+            end_epoch,
+        };
+        let _resp = self.inner.compact_table(req).await?;
+        Ok(())
+    }
+
+    pub async fn risectl_get_lock_contention(&self, top_n: u32) -> Result<Vec<LockContention>> {
+        let req = RiseCtlGetLockContentionRequest { top_n };
+        let resp = self.inner.rise_ctl_get_lock_contention(req).await?;
+        Ok(resp.contentions)
+    }
+
     pub async fn risectl_update_compaction_config(
         &self,
         compaction_groups: &[CompactionGroupId],
@@ -712,6 +874,64 @@ impl MetaClient {
         Ok(())
     }
 
+    pub async fn split_compaction_group(
+        &self,
+        group_id: CompactionGroupId,
+        table_ids: &[u32],
+    ) -> Result<CompactionGroupId> {
+        let req = RiseCtlSplitCompactionGroupRequest {
+            group_id,
+            table_ids: table_ids.to_vec(),
+        };
+        let resp = self.inner.rise_ctl_split_compaction_group(req).await?;
+        Ok(resp.new_group_id)
+    }
+
+    pub async fn merge_compaction_group(
+        &self,
+        left_group_id: CompactionGroupId,
+        right_group_id: CompactionGroupId,
+    ) -> Result<()> {
+        let req = RiseCtlMergeCompactionGroupRequest {
+            left_group_id,
+            right_group_id,
+        };
+        let _resp = self.inner.rise_ctl_merge_compaction_group(req).await?;
+        Ok(())
+    }
+
+    pub async fn acquire_iterator_lease(&self, epoch: HummockEpoch, ttl_sec: u64) -> Result<u64> {
+        let req = AcquireIteratorLeaseRequest {
+            context_id: self.worker_id(),
+            epoch,
+            ttl_sec,
+        };
+        let resp = self.inner.acquire_iterator_lease(req).await?;
+        Ok(resp.lease_id)
+    }
+
+    /// Returns false if the lease has already expired, in which case the iterator must be
+    /// recreated.
+    pub async fn renew_iterator_lease(&self, lease_id: u64, ttl_sec: u64) -> Result<bool> {
+        let req = RenewIteratorLeaseRequest { lease_id, ttl_sec };
+        let resp = self.inner.renew_iterator_lease(req).await?;
+        Ok(resp.renewed)
+    }
+
+    pub async fn release_iterator_lease(&self, lease_id: u64) -> Result<()> {
+        let req = ReleaseIteratorLeaseRequest { lease_id };
+        let _resp = self.inner.release_iterator_lease(req).await?;
+        Ok(())
+    }
+
+    /// Returns the epoch pinned by an unexpired lease, or `None` if the lease has expired or is
+    /// unknown.
+    pub async fn get_iterator_lease(&self, lease_id: u64) -> Result<Option<HummockEpoch>> {
+        let req = GetIteratorLeaseRequest { lease_id };
+        let resp = self.inner.get_iterator_lease(req).await?;
+        Ok(resp.valid.then_some(resp.epoch))
+    }
+
     pub async fn backup_meta(&self) -> Result<u64> {
         let req = BackupMetaRequest {};
         let resp = self.inner.backup_meta(req).await?;
@@ -737,6 +957,24 @@ impl MetaClient {
         let resp = self.inner.get_meta_snapshot_manifest(req).await?;
         Ok(resp.manifest.expect("should exist"))
     }
+
+    pub async fn export_mv_snapshot(&self, mv_table_id: u32) -> Result<(u64, Vec<u8>)> {
+        let req = ExportMvSnapshotRequest { mv_table_id };
+        let resp = self.inner.export_mv_snapshot(req).await?;
+        Ok((resp.snapshot_id, resp.snapshot))
+    }
+
+    pub async fn import_mv_snapshot(&self, snapshot: Vec<u8>) -> Result<()> {
+        let req = ImportMvSnapshotRequest { snapshot };
+        let _resp = self.inner.import_mv_snapshot(req).await?;
+        Ok(())
+    }
+
+    pub async fn release_mv_snapshot(&self, snapshot_id: u64) -> Result<()> {
+        let req = ReleaseMvSnapshotRequest { snapshot_id };
+        let _resp = self.inner.release_mv_snapshot(req).await?;
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -810,8 +1048,10 @@ impl HummockMetaClient for MetaClient {
     ) -> Result<()> {
         let req = ReportCompactionTasksRequest {
             context_id: self.worker_id(),
-            compact_task: Some(compact_task),
-            table_stats_change: to_prost_table_stats_map(table_stats_change),
+            report_tasks: vec![ReportTask {
+                compact_task: Some(compact_task),
+                table_stats_change: to_prost_table_stats_map(table_stats_change),
+            }],
         };
         self.inner.report_compaction_tasks(req).await?;
         Ok(())
@@ -836,6 +1076,8 @@ impl HummockMetaClient for MetaClient {
         let req = SubscribeCompactTasksRequest {
             context_id: self.worker_id(),
             max_concurrent_task_number,
+            cpu_core_num: resource_util::cpu::total_cpu_available().ceil() as u32,
+            total_memory_bytes: resource_util::memory::total_memory_available_bytes() as u64,
         };
         let stream = self.inner.subscribe_compact_tasks(req).await?;
         Ok(Box::pin(stream))
@@ -861,6 +1103,12 @@ impl HummockMetaClient for MetaClient {
         Ok(())
     }
 
+    async fn report_corrupted_ssts(&self, sst_ids: Vec<HummockSstableId>) -> Result<()> {
+        let req = ReportCorruptedSstsRequest { sst_ids };
+        self.inner.report_corrupted_ssts(req).await?;
+        Ok(())
+    }
+
     async fn report_full_scan_task(&self, sst_ids: Vec<HummockSstableId>) -> Result<()> {
         let req = ReportFullScanTaskRequest { sst_ids };
         self.inner.report_full_scan_task(req).await?;
@@ -901,6 +1149,14 @@ impl HummockMetaClient for MetaClient {
             .await?;
         Ok(())
     }
+
+    async fn get_dropped_table_ids(&self, table_ids: Vec<u32>) -> Result<Vec<u32>> {
+        let resp = self
+            .inner
+            .get_dropped_table_ids(GetDroppedTableIdsRequest { table_ids })
+            .await?;
+        Ok(resp.dropped_table_ids)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -1284,10 +1540,17 @@ macro_rules! for_all_meta_rpc {
              { cluster_client, add_worker_node, AddWorkerNodeRequest, AddWorkerNodeResponse }
             ,{ cluster_client, activate_worker_node, ActivateWorkerNodeRequest, ActivateWorkerNodeResponse }
             ,{ cluster_client, delete_worker_node, DeleteWorkerNodeRequest, DeleteWorkerNodeResponse }
-            //(not used) ,{ cluster_client, list_all_nodes, ListAllNodesRequest, ListAllNodesResponse }
+            ,{ cluster_client, drain_worker_node, DrainWorkerNodeRequest, DrainWorkerNodeResponse }
+            ,{ cluster_client, list_all_nodes, ListAllNodesRequest, ListAllNodesResponse }
             ,{ heartbeat_client, heartbeat, HeartbeatRequest, HeartbeatResponse }
             ,{ stream_client, flush, FlushRequest, FlushResponse }
+            ,{ stream_client, update_streaming_config, UpdateStreamingConfigRequest, UpdateStreamingConfigResponse }
             ,{ stream_client, list_table_fragments, ListTableFragmentsRequest, ListTableFragmentsResponse }
+            ,{ stream_client, list_mv_status, ListMvStatusRequest, ListMvStatusResponse }
+            ,{ stream_client, list_sink_freshness, ListSinkFreshnessRequest, ListSinkFreshnessResponse }
+            ,{ stream_client, list_backpressure, ListBackpressureRequest, ListBackpressureResponse }
+            ,{ stream_client, get_recovery_progress, GetRecoveryProgressRequest, GetRecoveryProgressResponse }
+            ,{ stream_client, get_barrier_trace, GetBarrierTraceRequest, GetBarrierTraceResponse }
             ,{ ddl_client, create_table, CreateTableRequest, CreateTableResponse }
             ,{ ddl_client, create_materialized_view, CreateMaterializedViewRequest, CreateMaterializedViewResponse }
             ,{ ddl_client, create_view, CreateViewRequest, CreateViewResponse }
@@ -1307,13 +1570,20 @@ macro_rules! for_all_meta_rpc {
             ,{ ddl_client, drop_index, DropIndexRequest, DropIndexResponse }
             ,{ ddl_client, drop_function, DropFunctionRequest, DropFunctionResponse }
             ,{ ddl_client, risectl_list_state_tables, RisectlListStateTablesRequest, RisectlListStateTablesResponse }
+            ,{ ddl_client, update_table_stats, UpdateTableStatsRequest, UpdateTableStatsResponse }
+            ,{ ddl_client, export_catalog, ExportCatalogRequest, ExportCatalogResponse }
             ,{ hummock_client, unpin_version_before, UnpinVersionBeforeRequest, UnpinVersionBeforeResponse }
             ,{ hummock_client, get_current_version, GetCurrentVersionRequest, GetCurrentVersionResponse }
             ,{ hummock_client, replay_version_delta, ReplayVersionDeltaRequest, ReplayVersionDeltaResponse }
             ,{ hummock_client, list_version_deltas, ListVersionDeltasRequest, ListVersionDeltasResponse }
             ,{ hummock_client, get_assigned_compact_task_num, GetAssignedCompactTaskNumRequest, GetAssignedCompactTaskNumResponse }
             ,{ hummock_client, trigger_compaction_deterministic, TriggerCompactionDeterministicRequest, TriggerCompactionDeterministicResponse }
+            ,{ hummock_client, diff_versions, DiffVersionsRequest, DiffVersionsResponse }
+            ,{ hummock_client, list_epoch_commit_times, ListEpochCommitTimesRequest, ListEpochCommitTimesResponse }
+            ,{ hummock_client, list_compact_task_summaries, ListCompactTaskSummariesRequest, ListCompactTaskSummariesResponse }
+            ,{ hummock_client, list_level_summaries, ListLevelSummariesRequest, ListLevelSummariesResponse }
             ,{ hummock_client, disable_commit_epoch, DisableCommitEpochRequest, DisableCommitEpochResponse }
+            ,{ hummock_client, truncate_above_epoch, TruncateAboveEpochRequest, TruncateAboveEpochResponse }
             ,{ hummock_client, pin_snapshot, PinSnapshotRequest, PinSnapshotResponse }
             ,{ hummock_client, pin_specific_snapshot, PinSpecificSnapshotRequest, PinSnapshotResponse }
             ,{ hummock_client, get_epoch, GetEpochRequest, GetEpochResponse }
@@ -1326,14 +1596,26 @@ macro_rules! for_all_meta_rpc {
             ,{ hummock_client, report_vacuum_task, ReportVacuumTaskRequest, ReportVacuumTaskResponse }
             ,{ hummock_client, get_compaction_groups, GetCompactionGroupsRequest, GetCompactionGroupsResponse }
             ,{ hummock_client, trigger_manual_compaction, TriggerManualCompactionRequest, TriggerManualCompactionResponse }
+            ,{ hummock_client, compact_table, CompactTableRequest, CompactTableResponse }
+            ,{ hummock_client, report_corrupted_ssts, ReportCorruptedSstsRequest, ReportCorruptedSstsResponse }
             ,{ hummock_client, report_full_scan_task, ReportFullScanTaskRequest, ReportFullScanTaskResponse }
             ,{ hummock_client, trigger_full_gc, TriggerFullGcRequest, TriggerFullGcResponse }
+            ,{ hummock_client, get_dropped_table_ids, GetDroppedTableIdsRequest, GetDroppedTableIdsResponse }
             ,{ hummock_client, rise_ctl_get_pinned_versions_summary, RiseCtlGetPinnedVersionsSummaryRequest, RiseCtlGetPinnedVersionsSummaryResponse }
             ,{ hummock_client, rise_ctl_get_pinned_snapshots_summary, RiseCtlGetPinnedSnapshotsSummaryRequest, RiseCtlGetPinnedSnapshotsSummaryResponse }
+            ,{ hummock_client, rise_ctl_reset_compactor_blacklist, RiseCtlResetCompactorBlacklistRequest, RiseCtlResetCompactorBlacklistResponse }
             ,{ hummock_client, rise_ctl_list_compaction_group, RiseCtlListCompactionGroupRequest, RiseCtlListCompactionGroupResponse }
+            ,{ hummock_client, rise_ctl_describe_compaction_group, RiseCtlDescribeCompactionGroupRequest, RiseCtlDescribeCompactionGroupResponse }
+            ,{ hummock_client, rise_ctl_get_lock_contention, RiseCtlGetLockContentionRequest, RiseCtlGetLockContentionResponse }
             ,{ hummock_client, rise_ctl_update_compaction_config, RiseCtlUpdateCompactionConfigRequest, RiseCtlUpdateCompactionConfigResponse }
+            ,{ hummock_client, rise_ctl_split_compaction_group, RiseCtlSplitCompactionGroupRequest, RiseCtlSplitCompactionGroupResponse }
+            ,{ hummock_client, rise_ctl_merge_compaction_group, RiseCtlMergeCompactionGroupRequest, RiseCtlMergeCompactionGroupResponse }
             ,{ hummock_client, init_metadata_for_replay, InitMetadataForReplayRequest, InitMetadataForReplayResponse }
             ,{ hummock_client, set_compactor_runtime_config, SetCompactorRuntimeConfigRequest, SetCompactorRuntimeConfigResponse }
+            ,{ hummock_client, acquire_iterator_lease, AcquireIteratorLeaseRequest, AcquireIteratorLeaseResponse }
+            ,{ hummock_client, renew_iterator_lease, RenewIteratorLeaseRequest, RenewIteratorLeaseResponse }
+            ,{ hummock_client, release_iterator_lease, ReleaseIteratorLeaseRequest, ReleaseIteratorLeaseResponse }
+            ,{ hummock_client, get_iterator_lease, GetIteratorLeaseRequest, GetIteratorLeaseResponse }
             ,{ user_client, create_user, CreateUserRequest, CreateUserResponse }
             ,{ user_client, update_user, UpdateUserRequest, UpdateUserResponse }
             ,{ user_client, drop_user, DropUserRequest, DropUserResponse }
@@ -1348,6 +1630,9 @@ macro_rules! for_all_meta_rpc {
             ,{ backup_client, get_backup_job_status, GetBackupJobStatusRequest, GetBackupJobStatusResponse }
             ,{ backup_client, delete_meta_snapshot, DeleteMetaSnapshotRequest, DeleteMetaSnapshotResponse}
             ,{ backup_client, get_meta_snapshot_manifest, GetMetaSnapshotManifestRequest, GetMetaSnapshotManifestResponse}
+            ,{ backup_client, export_mv_snapshot, ExportMvSnapshotRequest, ExportMvSnapshotResponse }
+            ,{ backup_client, import_mv_snapshot, ImportMvSnapshotRequest, ImportMvSnapshotResponse }
+            ,{ backup_client, release_mv_snapshot, ReleaseMvSnapshotRequest, ReleaseMvSnapshotResponse }
         }
     };
 }
@@ -37,11 +37,13 @@ impl RpcClient for StreamClient {
 
 impl StreamClient {
     async fn new(host_addr: HostAddr) -> Result<Self> {
-        let channel = Endpoint::from_shared(format!("http://{}", &host_addr))?
+        let mut endpoint = Endpoint::from_shared(format!("http://{}", &host_addr))?
             .initial_connection_window_size(MAX_CONNECTION_WINDOW_SIZE)
-            .connect_timeout(Duration::from_secs(5))
-            .connect()
-            .await?;
+            .connect_timeout(Duration::from_secs(5));
+        if let Some(tls) = crate::tls::client_tls_config(&host_addr.host) {
+            endpoint = endpoint.tls_config(tls)?;
+        }
+        let channel = endpoint.connect().await?;
         Ok(Self(StreamServiceClient::new(channel)))
     }
 }
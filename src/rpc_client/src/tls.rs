@@ -0,0 +1,117 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Optional mutual TLS for node-to-node RPC connections, for deployments where the nodes cannot
+//! run on a trusted flat network.
+
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use anyhow::Context;
+use risingwave_common::config::TlsConfig;
+use tonic::transport::{Certificate, ClientTlsConfig, Identity, ServerTlsConfig};
+
+use crate::error::Result;
+
+/// The process-wide TLS context, initialized by [`init_tls`] at node startup.
+static TLS_CONTEXT: RwLock<Option<Arc<TlsContext>>> = RwLock::new(None);
+
+/// The certificates and private key read from the files in [`TlsConfig`].
+struct TlsIdentity {
+    cert: Vec<u8>,
+    key: Vec<u8>,
+    ca_cert: Vec<u8>,
+}
+
+impl TlsIdentity {
+    fn load(config: &TlsConfig) -> Result<Self> {
+        let read = |path: &str| {
+            std::fs::read(path).with_context(|| format!("failed to read tls file {}", path))
+        };
+        Ok(Self {
+            cert: read(&config.cert_file)?,
+            key: read(&config.key_file)?,
+            ca_cert: read(&config.ca_cert_file)?,
+        })
+    }
+}
+
+struct TlsContext {
+    config: TlsConfig,
+    identity: RwLock<Arc<TlsIdentity>>,
+}
+
+impl TlsContext {
+    fn current_identity(&self) -> Arc<TlsIdentity> {
+        self.identity.read().unwrap().clone()
+    }
+}
+
+/// Initialize the process-wide TLS context and spawn a task periodically re-reading the
+/// certificate files, so that rotated certificates are picked up by subsequently established
+/// connections without restarting the node. No-op if TLS is not enabled in `config`.
+pub fn init_tls(config: &TlsConfig) -> Result<()> {
+    if !config.enabled {
+        return Ok(());
+    }
+    let context = Arc::new(TlsContext {
+        config: config.clone(),
+        identity: RwLock::new(Arc::new(TlsIdentity::load(config)?)),
+    });
+    *TLS_CONTEXT.write().unwrap() = Some(context.clone());
+
+    tokio::spawn(async move {
+        let mut interval =
+            tokio::time::interval(Duration::from_secs(context.config.reload_interval_secs));
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            interval.tick().await;
+            match TlsIdentity::load(&context.config) {
+                Ok(identity) => *context.identity.write().unwrap() = Arc::new(identity),
+                // Keep the previous certificates on failure.
+                Err(e) => tracing::warn!("failed to reload tls certificates: {}", e),
+            }
+        }
+    });
+    Ok(())
+}
+
+/// Whether mutual TLS is enabled for this process.
+pub fn tls_enabled() -> bool {
+    TLS_CONTEXT.read().unwrap().is_some()
+}
+
+/// The TLS config for connecting to the peer node serving at `domain`, if TLS is enabled.
+pub fn client_tls_config(domain: &str) -> Option<ClientTlsConfig> {
+    let context = TLS_CONTEXT.read().unwrap().clone()?;
+    let identity = context.current_identity();
+    Some(
+        ClientTlsConfig::new()
+            .identity(Identity::from_pem(&identity.cert, &identity.key))
+            .ca_certificate(Certificate::from_pem(&identity.ca_cert))
+            .domain_name(domain),
+    )
+}
+
+/// The TLS config for serving RPCs, requiring clients to present a certificate signed by the
+/// trusted CA, if TLS is enabled.
+pub fn server_tls_config() -> Option<ServerTlsConfig> {
+    let context = TLS_CONTEXT.read().unwrap().clone()?;
+    let identity = context.current_identity();
+    Some(
+        ServerTlsConfig::new()
+            .identity(Identity::from_pem(&identity.cert, &identity.key))
+            .client_ca_root(Certificate::from_pem(&identity.ca_cert)),
+    )
+}
@@ -86,6 +86,14 @@ pub enum BinaryOperator {
     PGRegexIMatch,
     PGRegexNotMatch,
     PGRegexNotIMatch,
+    /// `->`, access JSON object field or array element (PostgreSQL-specific)
+    Arrow,
+    /// `->>`, access JSON object field or array element as text (PostgreSQL-specific)
+    LongArrow,
+    /// `#>`, extract JSON sub-object at the specified path (PostgreSQL-specific)
+    HashArrow,
+    /// `@>`, whether left JSON value contains the right one (PostgreSQL-specific)
+    Contains,
 }
 
 impl fmt::Display for BinaryOperator {
@@ -121,6 +129,10 @@ impl fmt::Display for BinaryOperator {
             BinaryOperator::PGRegexIMatch => "~*",
             BinaryOperator::PGRegexNotMatch => "!~",
             BinaryOperator::PGRegexNotIMatch => "!~*",
+            BinaryOperator::Arrow => "->",
+            BinaryOperator::LongArrow => "->>",
+            BinaryOperator::HashArrow => "#>",
+            BinaryOperator::Contains => "@>",
         })
     }
 }
@@ -82,6 +82,9 @@ pub struct CreateSourceStatement {
     pub with_properties: WithProperties,
     pub source_schema: SourceSchema,
     pub source_watermarks: Vec<SourceWatermark>,
+    /// `INFER SCHEMA`: let the frontend sample messages from the source and infer the columns,
+    /// instead of taking them from the statement.
+    pub infer_schema: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -288,6 +291,8 @@ impl ParseTo for CreateSourceStatement {
             SourceSchema::parse_to(p)?
         };
 
+        impl_parse_to!(infer_schema => [Keyword::INFER, Keyword::SCHEMA], p);
+
         Ok(Self {
             if_not_exists,
             columns,
@@ -296,6 +301,7 @@ impl ParseTo for CreateSourceStatement {
             with_properties,
             source_schema,
             source_watermarks,
+            infer_schema,
         })
     }
 }
@@ -338,6 +344,7 @@ impl fmt::Display for CreateSourceStatement {
         impl_fmt_display!(with_properties, v, self);
         impl_fmt_display!([Keyword::ROW, Keyword::FORMAT], v);
         impl_fmt_display!(source_schema, v, self);
+        impl_fmt_display!(infer_schema => [Keyword::INFER, Keyword::SCHEMA], v, self);
         v.iter().join(" ").fmt(f)
     }
 }
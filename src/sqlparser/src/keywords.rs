@@ -257,6 +257,7 @@ define_keywords!(
     INCLUDE,
     INDEX,
     INDICATOR,
+    INFER,
     INITIALLY,
     INNER,
     INOUT,
@@ -274,6 +275,7 @@ define_keywords!(
     ISOLATION,
     JOIN,
     JSON,
+    JSONB,
     KEY,
     LANGUAGE,
     LARGE,
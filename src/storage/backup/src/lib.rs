@@ -33,9 +33,11 @@
 
 pub mod error;
 pub mod meta_snapshot;
+pub mod mv_snapshot;
 pub mod storage;
 
 use std::hash::Hasher;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use itertools::Itertools;
 use risingwave_hummock_sdk::compaction_group::hummock_version_ext::HummockVersionExt;
@@ -60,6 +62,10 @@ pub struct MetaSnapshotMetadata {
     pub ssts: Vec<HummockSstableId>,
     pub max_committed_epoch: u64,
     pub safe_epoch: u64,
+    /// UNIX timestamp in seconds when the snapshot was taken. 0 for snapshots taken by versions
+    /// that predate this field, which the retention policy treats as the oldest.
+    #[serde(default)]
+    pub taken_at_sec: u64,
 }
 
 impl MetaSnapshotMetadata {
@@ -70,10 +76,62 @@ impl MetaSnapshotMetadata {
             ssts: v.get_sst_ids(),
             max_committed_epoch: v.max_committed_epoch,
             safe_epoch: v.safe_epoch,
+            taken_at_sec: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .expect("system clock set before UNIX epoch")
+                .as_secs(),
         }
     }
 }
 
+/// A time-based retention policy for meta snapshots.
+///
+/// It keeps the newest snapshot in each of the `keep_hourly` most recent hours and the
+/// `keep_daily` most recent days that contain snapshots, counted by distinct hour/day buckets of
+/// the snapshots' `taken_at_sec`. The newest snapshot is always kept, so applying the policy
+/// never leaves the cluster without a restore point.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MetaSnapshotRetentionPolicy {
+    pub keep_hourly: u64,
+    pub keep_daily: u64,
+}
+
+impl MetaSnapshotRetentionPolicy {
+    pub fn new(keep_hourly: u64, keep_daily: u64) -> Self {
+        Self {
+            keep_hourly,
+            keep_daily,
+        }
+    }
+
+    /// Returns ids of the snapshots that are expired per this policy, given `(id, taken_at_sec)`
+    /// of all existent snapshots.
+    pub fn expired_snapshots(
+        &self,
+        snapshots: impl IntoIterator<Item = (MetaSnapshotId, u64)>,
+    ) -> Vec<MetaSnapshotId> {
+        // Newest first. Ties in time are broken by id, as ids are monotonically assigned.
+        let snapshots = snapshots
+            .into_iter()
+            .sorted_by_key(|(id, taken_at_sec)| (*taken_at_sec, *id))
+            .rev()
+            .collect_vec();
+        let mut kept_hours = std::collections::HashSet::new();
+        let mut kept_days = std::collections::HashSet::new();
+        let mut expired = vec![];
+        for (idx, (id, taken_at_sec)) in snapshots.iter().enumerate() {
+            let keep_as_hourly = (kept_hours.len() as u64) < self.keep_hourly
+                && kept_hours.insert(taken_at_sec / 3600);
+            let keep_as_daily = (kept_days.len() as u64) < self.keep_daily
+                && kept_days.insert(taken_at_sec / 86400);
+            if idx != 0 && !keep_as_hourly && !keep_as_daily {
+                expired.push(*id);
+            }
+        }
+        expired
+    }
+}
+
 /// `MetaSnapshotManifest` is the source of truth for valid `MetaSnapshot`.
 #[derive(Serialize, Deserialize, Default, Clone)]
 pub struct MetaSnapshotManifest {
@@ -106,6 +164,7 @@ impl From<&MetaSnapshotMetadata> for ProstMetaSnapshotMetadata {
             hummock_version_id: m.hummock_version_id,
             max_committed_epoch: m.max_committed_epoch,
             safe_epoch: m.safe_epoch,
+            taken_at_sec: m.taken_at_sec,
         }
     }
 }
@@ -118,3 +177,47 @@ impl From<&MetaSnapshotManifest> for ProstMetaSnapshotManifest {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use crate::MetaSnapshotRetentionPolicy;
+
+    #[test]
+    fn test_expired_snapshots() {
+        let hour = 3600;
+        let day = 24 * hour;
+        let policy = MetaSnapshotRetentionPolicy::new(2, 2);
+        let snapshots = vec![
+            // Kept: newest of the second most recent day.
+            (1, 10 * day),
+            // Expired: its hour bucket is beyond the 2 most recent ones, and its day bucket is
+            // already covered by a newer snapshot.
+            (2, 20 * day + hour),
+            // Kept: newest of the second most recent hour.
+            (3, 20 * day + 2 * hour),
+            // Expired: superseded by a newer snapshot in the same hour.
+            (4, 20 * day + 3 * hour),
+            // Kept: newest of the most recent hour and day.
+            (5, 20 * day + 3 * hour + 1),
+        ];
+        let expired = policy
+            .expired_snapshots(snapshots)
+            .into_iter()
+            .sorted()
+            .collect_vec();
+        assert_eq!(expired, vec![2, 4]);
+    }
+
+    #[test]
+    fn test_newest_snapshot_is_always_kept() {
+        let policy = MetaSnapshotRetentionPolicy::new(0, 0);
+        let expired = policy
+            .expired_snapshots(vec![(1, 100), (2, 200), (3, 300)])
+            .into_iter()
+            .sorted()
+            .collect_vec();
+        assert_eq!(expired, vec![1, 2]);
+    }
+}
@@ -80,41 +80,41 @@ impl ClusterMetadata {
     pub fn encode_to(&self, buf: &mut Vec<u8>) {
         let default_cf_keys = self.default_cf.keys().collect_vec();
         let default_cf_values = self.default_cf.values().collect_vec();
-        Self::encode_prost_message_list(&default_cf_keys, buf);
-        Self::encode_prost_message_list(&default_cf_values, buf);
-        Self::encode_prost_message(&self.hummock_version, buf);
-        Self::encode_prost_message(&self.version_stats, buf);
-        Self::encode_prost_message_list(&self.compaction_groups.iter().collect_vec(), buf);
-        Self::encode_prost_message_list(&self.table_fragments.iter().collect_vec(), buf);
-        Self::encode_prost_message_list(&self.user_info.iter().collect_vec(), buf);
-        Self::encode_prost_message_list(&self.database.iter().collect_vec(), buf);
-        Self::encode_prost_message_list(&self.schema.iter().collect_vec(), buf);
-        Self::encode_prost_message_list(&self.table.iter().collect_vec(), buf);
-        Self::encode_prost_message_list(&self.index.iter().collect_vec(), buf);
-        Self::encode_prost_message_list(&self.sink.iter().collect_vec(), buf);
-        Self::encode_prost_message_list(&self.source.iter().collect_vec(), buf);
-        Self::encode_prost_message_list(&self.view.iter().collect_vec(), buf);
+        encode_prost_message_list(&default_cf_keys, buf);
+        encode_prost_message_list(&default_cf_values, buf);
+        encode_prost_message(&self.hummock_version, buf);
+        encode_prost_message(&self.version_stats, buf);
+        encode_prost_message_list(&self.compaction_groups.iter().collect_vec(), buf);
+        encode_prost_message_list(&self.table_fragments.iter().collect_vec(), buf);
+        encode_prost_message_list(&self.user_info.iter().collect_vec(), buf);
+        encode_prost_message_list(&self.database.iter().collect_vec(), buf);
+        encode_prost_message_list(&self.schema.iter().collect_vec(), buf);
+        encode_prost_message_list(&self.table.iter().collect_vec(), buf);
+        encode_prost_message_list(&self.index.iter().collect_vec(), buf);
+        encode_prost_message_list(&self.sink.iter().collect_vec(), buf);
+        encode_prost_message_list(&self.source.iter().collect_vec(), buf);
+        encode_prost_message_list(&self.view.iter().collect_vec(), buf);
     }
 
     pub fn decode(mut buf: &[u8]) -> BackupResult<Self> {
-        let default_cf_keys: Vec<Vec<u8>> = Self::decode_prost_message_list(&mut buf)?;
-        let default_cf_values: Vec<Vec<u8>> = Self::decode_prost_message_list(&mut buf)?;
+        let default_cf_keys: Vec<Vec<u8>> = decode_prost_message_list(&mut buf)?;
+        let default_cf_values: Vec<Vec<u8>> = decode_prost_message_list(&mut buf)?;
         let default_cf = default_cf_keys
             .into_iter()
             .zip_eq_fast(default_cf_values.into_iter())
             .collect();
-        let hummock_version = Self::decode_prost_message(&mut buf)?;
-        let version_stats = Self::decode_prost_message(&mut buf)?;
-        let compaction_groups: Vec<CompactionGroup> = Self::decode_prost_message_list(&mut buf)?;
-        let table_fragments: Vec<TableFragments> = Self::decode_prost_message_list(&mut buf)?;
-        let user_info: Vec<UserInfo> = Self::decode_prost_message_list(&mut buf)?;
-        let database: Vec<Database> = Self::decode_prost_message_list(&mut buf)?;
-        let schema: Vec<Schema> = Self::decode_prost_message_list(&mut buf)?;
-        let table: Vec<Table> = Self::decode_prost_message_list(&mut buf)?;
-        let index: Vec<Index> = Self::decode_prost_message_list(&mut buf)?;
-        let sink: Vec<Sink> = Self::decode_prost_message_list(&mut buf)?;
-        let source: Vec<Source> = Self::decode_prost_message_list(&mut buf)?;
-        let view: Vec<View> = Self::decode_prost_message_list(&mut buf)?;
+        let hummock_version = decode_prost_message(&mut buf)?;
+        let version_stats = decode_prost_message(&mut buf)?;
+        let compaction_groups: Vec<CompactionGroup> = decode_prost_message_list(&mut buf)?;
+        let table_fragments: Vec<TableFragments> = decode_prost_message_list(&mut buf)?;
+        let user_info: Vec<UserInfo> = decode_prost_message_list(&mut buf)?;
+        let database: Vec<Database> = decode_prost_message_list(&mut buf)?;
+        let schema: Vec<Schema> = decode_prost_message_list(&mut buf)?;
+        let table: Vec<Table> = decode_prost_message_list(&mut buf)?;
+        let index: Vec<Index> = decode_prost_message_list(&mut buf)?;
+        let sink: Vec<Sink> = decode_prost_message_list(&mut buf)?;
+        let source: Vec<Source> = decode_prost_message_list(&mut buf)?;
+        let view: Vec<View> = decode_prost_message_list(&mut buf)?;
 
         Ok(Self {
             default_cf,
@@ -133,41 +133,42 @@ impl ClusterMetadata {
         })
     }
 
-    fn encode_prost_message(message: &impl prost::Message, buf: &mut Vec<u8>) {
-        let encoded_message = message.encode_to_vec();
-        buf.put_u32_le(encoded_message.len() as u32);
-        buf.put_slice(&encoded_message);
-    }
+}
 
-    fn decode_prost_message<T>(buf: &mut &[u8]) -> BackupResult<T>
-    where
-        T: prost::Message + Default,
-    {
-        let len = buf.get_u32_le() as usize;
-        let v = buf[..len].to_vec();
-        buf.advance(len);
-        T::decode(v.as_slice()).map_err(|e| BackupError::Decoding(e.into()))
-    }
+pub(crate) fn encode_prost_message(message: &impl prost::Message, buf: &mut Vec<u8>) {
+    let encoded_message = message.encode_to_vec();
+    buf.put_u32_le(encoded_message.len() as u32);
+    buf.put_slice(&encoded_message);
+}
 
-    fn encode_prost_message_list(messages: &[&impl prost::Message], buf: &mut Vec<u8>) {
-        buf.put_u32_le(messages.len() as u32);
-        for message in messages {
-            Self::encode_prost_message(*message, buf);
-        }
+pub(crate) fn decode_prost_message<T>(buf: &mut &[u8]) -> BackupResult<T>
+where
+    T: prost::Message + Default,
+{
+    let len = buf.get_u32_le() as usize;
+    let v = buf[..len].to_vec();
+    buf.advance(len);
+    T::decode(v.as_slice()).map_err(|e| BackupError::Decoding(e.into()))
+}
+
+pub(crate) fn encode_prost_message_list(messages: &[&impl prost::Message], buf: &mut Vec<u8>) {
+    buf.put_u32_le(messages.len() as u32);
+    for message in messages {
+        encode_prost_message(*message, buf);
     }
+}
 
-    fn decode_prost_message_list<T>(buf: &mut &[u8]) -> BackupResult<Vec<T>>
-    where
-        T: prost::Message + Default,
-    {
-        let vec_len = buf.get_u32_le() as usize;
-        let mut result = vec![];
-        for _ in 0..vec_len {
-            let v: T = Self::decode_prost_message(buf)?;
-            result.push(v);
-        }
-        Ok(result)
+pub(crate) fn decode_prost_message_list<T>(buf: &mut &[u8]) -> BackupResult<Vec<T>>
+where
+    T: prost::Message + Default,
+{
+    let vec_len = buf.get_u32_le() as usize;
+    let mut result = vec![];
+    for _ in 0..vec_len {
+        let v: T = decode_prost_message(buf)?;
+        result.push(v);
     }
+    Ok(result)
 }
 
 #[cfg(test)]
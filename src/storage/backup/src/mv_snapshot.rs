@@ -0,0 +1,130 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bytes::{Buf, BufMut};
+use itertools::Itertools;
+use risingwave_hummock_sdk::HummockSstableId;
+use risingwave_pb::catalog::Table;
+use risingwave_pb::hummock::SstableInfo;
+use risingwave_pb::meta::TableFragments;
+
+use crate::error::BackupResult;
+use crate::meta_snapshot::{
+    decode_prost_message, decode_prost_message_list, encode_prost_message,
+    encode_prost_message_list,
+};
+use crate::{xxhash64_checksum, xxhash64_verify, MetaSnapshotId};
+
+/// `MvSnapshot` is a logical backup of a single materialized view: its definition, together with
+/// references to the SSTs that hold its state. Unlike [`crate::meta_snapshot::MetaSnapshot`], it
+/// doesn't copy any data, so it is only restorable as long as the referenced SSTs still exist in
+/// object store. The exporting cluster keeps a durable pin on the SSTs until the snapshot is
+/// imported or released.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct MvSnapshot {
+    pub id: MetaSnapshotId,
+    /// Catalog of the materialized view.
+    pub mv: Table,
+    /// Catalogs of the internal state tables of the materialized view.
+    pub internal_tables: Vec<Table>,
+    /// Fragment graph of the materialized view.
+    pub table_fragments: TableFragments,
+    /// SSTs referenced by the state tables at the time of export. The full info is kept so that
+    /// the import can re-add the SSTs to the hummock version.
+    pub ssts: Vec<SstableInfo>,
+}
+
+impl MvSnapshot {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut buf = vec![];
+        buf.put_u64_le(self.id);
+        encode_prost_message(&self.mv, &mut buf);
+        encode_prost_message_list(&self.internal_tables.iter().collect_vec(), &mut buf);
+        encode_prost_message(&self.table_fragments, &mut buf);
+        encode_prost_message_list(&self.ssts.iter().collect_vec(), &mut buf);
+        let checksum = xxhash64_checksum(&buf);
+        buf.put_u64_le(checksum);
+        buf
+    }
+
+    pub fn decode(mut buf: &[u8]) -> BackupResult<Self> {
+        let checksum = (&buf[buf.len() - 8..]).get_u64_le();
+        xxhash64_verify(&buf[..buf.len() - 8], checksum)?;
+        let id = buf.get_u64_le();
+        let mv = decode_prost_message(&mut buf)?;
+        let internal_tables = decode_prost_message_list(&mut buf)?;
+        let table_fragments = decode_prost_message(&mut buf)?;
+        let ssts = decode_prost_message_list(&mut buf)?;
+        Ok(Self {
+            id,
+            mv,
+            internal_tables,
+            table_fragments,
+            ssts,
+        })
+    }
+
+    /// Ids of the state tables of the materialized view, including the one of the materialized
+    /// view itself.
+    pub fn state_table_ids(&self) -> Vec<u32> {
+        std::iter::once(self.mv.id)
+            .chain(self.internal_tables.iter().map(|t| t.id))
+            .collect_vec()
+    }
+
+    /// Ids of the SSTs referenced by the snapshot.
+    pub fn sst_ids(&self) -> Vec<HummockSstableId> {
+        self.ssts.iter().map(|sst| sst.id).collect_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_pb::catalog::Table;
+    use risingwave_pb::hummock::SstableInfo;
+
+    use crate::mv_snapshot::MvSnapshot;
+
+    #[test]
+    fn test_mv_snapshot_encoding_decoding() {
+        let raw = MvSnapshot {
+            id: 1,
+            mv: Table {
+                id: 2,
+                name: "mv".to_string(),
+                ..Default::default()
+            },
+            internal_tables: vec![Table {
+                id: 3,
+                ..Default::default()
+            }],
+            table_fragments: Default::default(),
+            ssts: vec![
+                SstableInfo {
+                    id: 100,
+                    ..Default::default()
+                },
+                SstableInfo {
+                    id: 101,
+                    ..Default::default()
+                },
+            ],
+        };
+        let encoded = raw.encode();
+        let decoded = MvSnapshot::decode(&encoded).unwrap();
+        assert_eq!(raw, decoded);
+        assert_eq!(raw.state_table_ids(), vec![2, 3]);
+        assert_eq!(raw.sst_ids(), vec![100, 101]);
+    }
+}
@@ -60,6 +60,7 @@ pub fn default_writer_opts() -> SstableWriterOptions {
         capacity_hint: None,
         tracker: None,
         policy: CachePolicy::Fill,
+        io_limiter: None,
     }
 }
 
@@ -85,6 +86,7 @@ async fn build_table(
         restart_interval: 16,
         bloom_false_positive: 0.001,
         compression_algorithm: CompressionAlgorithm::None,
+        zstd_dict_capacity: 0,
     };
     let writer = sstable_store.create_sst_writer(
         sstable_id,
@@ -92,6 +94,7 @@ async fn build_table(
             capacity_hint: None,
             tracker: None,
             policy: CachePolicy::Fill,
+            io_limiter: None,
         },
     );
     let mut builder = SstableBuilder::for_test(sstable_id, writer, opt);
@@ -169,6 +172,7 @@ async fn compact<I: HummockIterator<Direction = Forward>>(iter: I, sstable_store
         restart_interval: 16,
         bloom_false_positive: 0.001,
         compression_algorithm: CompressionAlgorithm::None,
+        zstd_dict_capacity: 0,
     };
     let mut builder =
         CapacitySplitTableBuilder::for_test(LocalTableBuilderFactory::new(32, sstable_store, opt));
@@ -232,8 +236,18 @@ fn bench_merge_iterator_compactor(c: &mut Criterion) {
     c.bench_function("bench_merge_iterator", |b| {
         b.to_async(&runtime).iter(|| {
             let sub_iters = vec![
-                ConcatSstableIterator::new(level1.clone(), KeyRange::inf(), sstable_store.clone()),
-                ConcatSstableIterator::new(level2.clone(), KeyRange::inf(), sstable_store.clone()),
+                ConcatSstableIterator::new(
+                    level1.clone(),
+                    KeyRange::inf(),
+                    sstable_store.clone(),
+                    None,
+                ),
+                ConcatSstableIterator::new(
+                    level2.clone(),
+                    KeyRange::inf(),
+                    sstable_store.clone(),
+                    None,
+                ),
             ];
             let iter = UnorderedMergeIteratorInner::for_compactor(sub_iters);
             let sstable_store1 = sstable_store.clone();
@@ -70,6 +70,7 @@ impl<F: SstableWriterFactory> TableBuilderFactory for LocalTableBuilderFactory<F
             capacity_hint: Some(self.options.capacity),
             tracker: Some(tracker),
             policy: self.policy,
+            io_limiter: None,
         };
         let writer = self
             .writer_factory
@@ -88,6 +89,7 @@ fn get_builder_options(capacity_mb: usize) -> SstableBuilderOptions {
         restart_interval: 16,
         bloom_false_positive: 0.001,
         compression_algorithm: CompressionAlgorithm::None,
+        zstd_dict_capacity: 0,
     }
 }
 
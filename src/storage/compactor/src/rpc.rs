@@ -53,6 +53,9 @@ impl CompactorService for CompactorServiceImpl {
             .await
             .map_err(RwError::from)?;
         *local_config = new_config;
+        self.context
+            .io_limiter
+            .set_bytes_per_sec(local_config.max_io_bandwidth_bytes_per_sec);
         Ok(Response::new(SetRuntimeConfigResponse {}))
     }
 }
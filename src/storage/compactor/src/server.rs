@@ -31,7 +31,7 @@ use risingwave_rpc_client::MetaClient;
 use risingwave_storage::hummock::compactor::{CompactionExecutor, CompactorContext};
 use risingwave_storage::hummock::hummock_meta_client::MonitoredHummockMetaClient;
 use risingwave_storage::hummock::{
-    CompactorMemoryCollector, MemoryLimiter, SstableIdManager, SstableStore,
+    CompactorMemoryCollector, IoLimiter, MemoryLimiter, SstableIdManager, SstableStore,
 };
 use risingwave_storage::monitor::{
     monitor_cache, CompactorMetrics, HummockMetrics, ObjectStoreMetrics,
@@ -136,13 +136,15 @@ pub async fn compactor_serve(
         )),
         filter_key_extractor_manager: filter_key_extractor_manager.clone(),
         read_memory_limiter: memory_limiter,
+        io_limiter: IoLimiter::unlimited(),
         sstable_id_manager: sstable_id_manager.clone(),
         task_progress_manager: Default::default(),
         compactor_runtime_config: Arc::new(tokio::sync::Mutex::new(CompactorRuntimeConfig {
             max_concurrent_task_number,
+            max_io_bandwidth_bytes_per_sec: 0,
         })),
     });
-    let sub_tasks = vec![
+    let mut sub_tasks = vec![
         MetaClient::start_heartbeat_loop(
             meta_client.clone(),
             Duration::from_millis(config.server.heartbeat_interval_ms as u64),
@@ -154,6 +156,11 @@ pub async fn compactor_serve(
             hummock_meta_client,
         ),
     ];
+    if storage_opts.sst_scrub_interval_sec > 0 {
+        sub_tasks.push(risingwave_storage::hummock::compactor::start_scrub_service(
+            compactor_context.clone(),
+        ));
+    }
 
     let (shutdown_send, mut shutdown_recv) = tokio::sync::oneshot::channel();
     let join_handle = tokio::spawn(async move {
@@ -98,6 +98,9 @@ pub fn append_sstable_info_to_string(s: &mut String, sstable_info: &SstableInfo)
 #[derive(Clone, Default)]
 pub struct CompactorRuntimeConfig {
     pub max_concurrent_task_number: u64,
+    /// Upper bound on the total compaction read/write bandwidth in bytes per second. `0` means
+    /// unlimited.
+    pub max_io_bandwidth_bytes_per_sec: u64,
 }
 
 impl From<risingwave_pb::compactor::CompactorRuntimeConfig> for CompactorRuntimeConfig {
@@ -110,6 +113,7 @@ impl From<&risingwave_pb::compactor::CompactorRuntimeConfig> for CompactorRuntim
     fn from(value: &risingwave_pb::compactor::CompactorRuntimeConfig) -> Self {
         Self {
             max_concurrent_task_number: value.max_concurrent_task_number,
+            max_io_bandwidth_bytes_per_sec: value.max_io_bandwidth_bytes_per_sec,
         }
     }
 }
@@ -124,6 +128,7 @@ impl From<&CompactorRuntimeConfig> for risingwave_pb::compactor::CompactorRuntim
     fn from(value: &CompactorRuntimeConfig) -> Self {
         risingwave_pb::compactor::CompactorRuntimeConfig {
             max_concurrent_task_number: value.max_concurrent_task_number,
+            max_io_bandwidth_bytes_per_sec: value.max_io_bandwidth_bytes_per_sec,
         }
     }
 }
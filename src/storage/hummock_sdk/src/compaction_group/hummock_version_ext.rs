@@ -20,8 +20,8 @@ use risingwave_pb::hummock::group_delta::DeltaType;
 use risingwave_pb::hummock::hummock_version::Levels;
 use risingwave_pb::hummock::hummock_version_delta::GroupDeltas;
 use risingwave_pb::hummock::{
-    CompactionConfig, GroupConstruct, GroupDestroy, HummockVersion, HummockVersionDelta, Level,
-    LevelType, OverlappingLevel, SstableInfo,
+    CompactionConfig, GroupConstruct, GroupDestroy, GroupMerge, HummockVersion,
+    HummockVersionDelta, Level, LevelType, OverlappingLevel, SstableInfo,
 };
 
 use super::StateTableId;
@@ -37,6 +37,7 @@ pub struct GroupDeltasSummary {
     pub insert_table_infos: Vec<SstableInfo>,
     pub group_construct: Option<GroupConstruct>,
     pub group_destroy: Option<GroupDestroy>,
+    pub group_merge: Option<GroupMerge>,
 }
 
 pub fn summarize_group_deltas(group_deltas: &GroupDeltas) -> GroupDeltasSummary {
@@ -47,6 +48,7 @@ pub fn summarize_group_deltas(group_deltas: &GroupDeltas) -> GroupDeltasSummary
     let mut insert_table_infos = vec![];
     let mut group_construct = None;
     let mut group_destroy = None;
+    let mut group_merge = None;
     for group_delta in &group_deltas.group_deltas {
         match group_delta.get_delta_type().unwrap() {
             DeltaType::IntraLevel(intra_level) => {
@@ -68,6 +70,10 @@ pub fn summarize_group_deltas(group_deltas: &GroupDeltas) -> GroupDeltasSummary
                 assert!(group_destroy.is_none());
                 group_destroy = Some(destroy_delta.clone());
             }
+            DeltaType::GroupMerge(merge_delta) => {
+                assert!(group_merge.is_none());
+                group_merge = Some(merge_delta.clone());
+            }
         }
     }
 
@@ -79,6 +85,7 @@ pub fn summarize_group_deltas(group_deltas: &GroupDeltas) -> GroupDeltasSummary
         insert_table_infos,
         group_construct,
         group_destroy,
+        group_merge,
     }
 }
 
@@ -107,6 +114,9 @@ pub trait HummockVersionUpdateExt {
         group_id: CompactionGroupId,
         member_table_ids: &HashSet<StateTableId>,
     ) -> Vec<(HummockSstableId, u64, u32)>;
+    /// Moves all SSTs of `right_group_id` into `left_group_id` and removes `right_group_id`
+    /// from the version. SSTs of the two groups must be disjoint.
+    fn merge_levels(&mut self, right_group_id: CompactionGroupId, left_group_id: CompactionGroupId);
     fn apply_version_delta(&mut self, version_delta: &HummockVersionDelta);
 
     fn build_compaction_group_info(&self) -> HashMap<TableId, CompactionGroupId>;
@@ -250,6 +260,59 @@ impl HummockVersionUpdateExt for HummockVersion {
         split_id_vers
     }
 
+    fn merge_levels(
+        &mut self,
+        right_group_id: CompactionGroupId,
+        left_group_id: CompactionGroupId,
+    ) {
+        let right_levels = match self.levels.remove(&right_group_id) {
+            Some(levels) => levels,
+            None => {
+                return;
+            }
+        };
+        let left_levels = self
+            .levels
+            .get_mut(&left_group_id)
+            .unwrap_or_else(|| panic!("compaction group {} does not exist", left_group_id));
+        if let Some(right_l0) = right_levels.l0 {
+            let left_l0 = left_levels.l0.as_mut().unwrap();
+            for right_sub_level in right_l0.sub_levels {
+                // Sub levels are epoch-based in both groups, so levels with the same id can be
+                // combined. Keys of the two groups never overlap as they are prefixed by
+                // disjoint table ids.
+                match left_l0
+                    .sub_levels
+                    .iter_mut()
+                    .find(|sub_level| sub_level.sub_level_id == right_sub_level.sub_level_id)
+                {
+                    Some(left_sub_level) => {
+                        left_sub_level.total_file_size += right_sub_level.total_file_size;
+                        left_sub_level
+                            .table_infos
+                            .extend(right_sub_level.table_infos);
+                        left_sub_level.level_type = LevelType::Overlapping as i32;
+                    }
+                    None => {
+                        let index = left_l0
+                            .sub_levels
+                            .partition_point(|sub_level| {
+                                sub_level.sub_level_id < right_sub_level.sub_level_id
+                            });
+                        left_l0.sub_levels.insert(index, right_sub_level);
+                    }
+                }
+            }
+            left_l0.total_file_size += right_l0.total_file_size;
+        }
+        for (idx, right_level) in right_levels.levels.into_iter().enumerate() {
+            if right_level.table_infos.is_empty() {
+                continue;
+            }
+            level_insert_ssts(&mut left_levels.levels[idx], right_level.table_infos);
+        }
+    }
+
     fn apply_version_delta(&mut self, version_delta: &HummockVersionDelta) {
         for (compaction_group_id, group_deltas) in &version_delta.group_deltas {
             let summary = summarize_group_deltas(group_deltas);
@@ -267,6 +330,9 @@ impl HummockVersionUpdateExt for HummockVersion {
                     &HashSet::from_iter(group_construct.get_table_ids().iter().cloned()),
                 );
             }
+            if let Some(group_merge) = &summary.group_merge {
+                self.merge_levels(group_merge.right_group_id, *compaction_group_id);
+            }
             let has_destroy = summary.group_destroy.is_some();
             let levels = self
                 .levels
@@ -279,8 +345,13 @@ impl HummockVersionUpdateExt for HummockVersion {
                 version_delta.max_committed_epoch,
                 self.max_committed_epoch
             );
-            if self.max_committed_epoch < version_delta.max_committed_epoch {
-                // `max_committed_epoch` increases. It must be a `commit_epoch`
+            // A delta that increases `max_committed_epoch` or advances the committed epoch of
+            // some barrier domain is caused by a `commit_epoch`. Both have to be checked, since
+            // a commit of a lagging domain does not increase `max_committed_epoch`, which is the
+            // maximum over all domains.
+            if self.max_committed_epoch < version_delta.max_committed_epoch
+                || !version_delta.domain_committed_epochs.is_empty()
+            {
                 let GroupDeltasSummary {
                     delete_sst_levels,
                     delete_sst_ids_set,
@@ -315,6 +386,10 @@ impl HummockVersionUpdateExt for HummockVersion {
         }
         self.id = version_delta.id;
         self.max_committed_epoch = version_delta.max_committed_epoch;
+        for (domain, committed_epoch) in &version_delta.domain_committed_epochs {
+            self.domain_committed_epochs
+                .insert(*domain, *committed_epoch);
+        }
         self.safe_epoch = version_delta.safe_epoch;
     }
 
@@ -492,11 +567,16 @@ pub fn add_new_sub_level(
     if insert_sub_level_id == u64::MAX {
         return;
     }
-    if let Some(newest_level) = l0.sub_levels.last() {
+    // Barrier domains commit their epochs independently, so a commit of a lagging domain may
+    // insert a sub-level below ones already committed by the other domains. Keep the sub-levels
+    // ordered by their sub-level id, i.e. the epoch they were committed at.
+    let index = l0
+        .sub_levels
+        .partition_point(|level| level.sub_level_id < insert_sub_level_id);
+    if let Some(level) = l0.sub_levels.get(index) {
         assert!(
-            newest_level.sub_level_id < insert_sub_level_id,
-            "inserted new level is not the newest: prev newest: {}, insert: {}. L0: {:?}",
-            newest_level.sub_level_id,
+            level.sub_level_id != insert_sub_level_id,
+            "inserted sub-level already exists: insert: {}. L0: {:?}",
             insert_sub_level_id,
             l0,
         );
@@ -505,7 +585,7 @@ pub fn add_new_sub_level(
     // Nonoverlapping  after at least one compaction.
     let level = new_sub_level(insert_sub_level_id, level_type, insert_table_infos);
     l0.total_file_size += level.total_file_size;
-    l0.sub_levels.push(level);
+    l0.sub_levels.insert(index, level);
 }
 
 /// Delete sstables if the table id is in the id set.
@@ -609,6 +689,7 @@ mod tests {
             )]),
             max_committed_epoch: 0,
             safe_epoch: 0,
+            domain_committed_epochs: Default::default(),
         };
         assert_eq!(version.get_sst_ids().len(), 0);
 
@@ -663,6 +744,7 @@ mod tests {
             ]),
             max_committed_epoch: 0,
             safe_epoch: 0,
+            domain_committed_epochs: Default::default(),
         };
         let version_delta = HummockVersionDelta {
             id: 1,
@@ -737,6 +819,7 @@ mod tests {
                 ]),
                 max_committed_epoch: 0,
                 safe_epoch: 0,
+                domain_committed_epochs: Default::default(),
             }
         );
     }
@@ -54,6 +54,20 @@ impl FilterKeyExtractorImpl {
             FilterKeyExtractorImpl::Schema(SchemaFilterKeyExtractor::new(table_catalog))
         }
     }
+
+    /// The number of pk prefix columns whose serialization this extractor uses as the bloom
+    /// filter key for `table_id`, or `None` if the filter key is not a column prefix. Recorded
+    /// in the meta of each SST built with this extractor, so that readers can compute filter
+    /// keys consistently with the writer even after the prefix length has been adapted.
+    pub fn bloom_filter_prefix_columns(&self, table_id: u32) -> Option<usize> {
+        match self {
+            Self::Schema(schema) => Some(schema.read_prefix_len()),
+            Self::Multi(multi) => multi
+                .get(table_id)
+                .and_then(|extractor| extractor.bloom_filter_prefix_columns(table_id)),
+            Self::FullKey(_) | Self::Dummy(_) | Self::FixedLength(_) => None,
+        }
+    }
 }
 
 macro_rules! impl_filter_key_extractor {
@@ -184,6 +198,22 @@ impl SchemaFilterKeyExtractor {
             deserializer: OrderedRowSerde::new(data_types, order_types),
         }
     }
+
+    /// The number of pk prefix columns whose serialization is used as the bloom filter key.
+    pub fn read_prefix_len(&self) -> usize {
+        self.read_prefix_len
+    }
+
+    /// A copy of this extractor that extracts the first `read_prefix_len` pk columns instead.
+    /// Only shrinking the prefix is allowed: filter keys longer than the catalog hint would
+    /// never match the hints sent by readers.
+    pub fn with_read_prefix_len(&self, read_prefix_len: usize) -> Self {
+        assert!((1..=self.read_prefix_len).contains(&read_prefix_len));
+        Self {
+            read_prefix_len,
+            deserializer: self.deserializer.clone(),
+        }
+    }
 }
 
 #[derive(Default)]
@@ -202,6 +232,10 @@ impl MultiFilterKeyExtractor {
     pub fn size(&self) -> usize {
         self.id_to_filter_key_extractor.len()
     }
+
+    pub fn get(&self, table_id: u32) -> Option<&Arc<FilterKeyExtractorImpl>> {
+        self.id_to_filter_key_extractor.get(&table_id)
+    }
 }
 
 impl Debug for MultiFilterKeyExtractor {
@@ -227,6 +261,10 @@ impl FilterKeyExtractor for MultiFilterKeyExtractor {
 #[derive(Default)]
 struct FilterKeyExtractorManagerInner {
     table_id_to_filter_key_extractor: RwLock<HashMap<u32, Arc<FilterKeyExtractorImpl>>>,
+    /// The minimum pk prefix column count available in the reads observed per table. When it is
+    /// shorter than the catalog `read_prefix_len_hint`, newly built SSTs shrink their bloom
+    /// filter keys to it, so that the shorter reads can use the filters as well.
+    observed_read_prefix_lens: RwLock<HashMap<u32, usize>>,
     notify: Notify,
 }
 
@@ -250,10 +288,49 @@ impl FilterKeyExtractorManagerInner {
         self.table_id_to_filter_key_extractor
             .write()
             .remove(&table_id);
+        self.observed_read_prefix_lens.write().remove(&table_id);
 
         self.notify.notify_waiters();
     }
 
+    fn observe_read_prefix(&self, table_id: u32, available_columns: usize) {
+        if available_columns == 0 {
+            return;
+        }
+        {
+            let guard = self.observed_read_prefix_lens.read();
+            if guard
+                .get(&table_id)
+                .map_or(false, |min| *min <= available_columns)
+            {
+                return;
+            }
+        }
+        let mut guard = self.observed_read_prefix_lens.write();
+        let min = guard.entry(table_id).or_insert(available_columns);
+        *min = (*min).min(available_columns);
+    }
+
+    /// Shrinks the extractor of `table_id` to the minimum observed read prefix length. A shorter
+    /// filter key only makes the bloom filter coarser for reads with longer prefixes, while the
+    /// recorded per-SST prefix length keeps readers consistent with the writer, so this is
+    /// always sound.
+    fn adapted_filter_key_extractor(
+        &self,
+        table_id: u32,
+        extractor: Arc<FilterKeyExtractorImpl>,
+    ) -> Arc<FilterKeyExtractorImpl> {
+        let FilterKeyExtractorImpl::Schema(schema) = extractor.as_ref() else {
+            return extractor;
+        };
+        match self.observed_read_prefix_lens.read().get(&table_id) {
+            Some(observed) if *observed < schema.read_prefix_len() => Arc::new(
+                FilterKeyExtractorImpl::Schema(schema.with_read_prefix_len(*observed)),
+            ),
+            _ => extractor,
+        }
+    }
+
     async fn acquire(&self, mut table_id_set: HashSet<u32>) -> FilterKeyExtractorImpl {
         if table_id_set.is_empty() {
             // table_id_set is empty
@@ -271,8 +348,13 @@ impl FilterKeyExtractorManagerInner {
                 let guard = self.table_id_to_filter_key_extractor.read();
                 table_id_set.drain_filter(|table_id| match guard.get(table_id) {
                     Some(filter_key_extractor) => {
-                        multi_filter_key_extractor
-                            .register(*table_id, filter_key_extractor.clone());
+                        multi_filter_key_extractor.register(
+                            *table_id,
+                            self.adapted_filter_key_extractor(
+                                *table_id,
+                                filter_key_extractor.clone(),
+                            ),
+                        );
                         true
                     }
 
@@ -320,6 +402,13 @@ impl FilterKeyExtractorManager {
         self.inner.sync(filter_key_extractor_map)
     }
 
+    /// Record the number of pk prefix columns available in a read of `table_id`, so that
+    /// `acquire` can shrink the bloom filter key of newly built SSTs to the minimum observed
+    /// length
+    pub fn observe_read_prefix(&self, table_id: u32, available_columns: usize) {
+        self.inner.observe_read_prefix(table_id, available_columns)
+    }
+
     /// Acquire a `MultiFilterKeyExtractor` by `table_id_set`
     /// Internally, try to get all `filter_key_extractor` from `hashmap`. Will block the caller if
     /// `table_id` does not util version update (notify), and retry to get
@@ -463,6 +552,8 @@ mod tests {
             read_prefix_len_hint: 1,
             version: None,
             watermark_indices: vec![],
+            stats: None,
+            memory_only: false,
         }
     }
 
@@ -607,4 +698,47 @@ mod tests {
             }
         }
     }
+
+    #[tokio::test]
+    async fn test_observed_read_prefix_adapts_extractor() {
+        let filter_key_extractor_manager = FilterKeyExtractorManager::default();
+        let prost_table = build_table_with_prefix_column_num(2);
+        filter_key_extractor_manager.update(
+            1,
+            Arc::new(FilterKeyExtractorImpl::Schema(SchemaFilterKeyExtractor::new(
+                &prost_table,
+            ))),
+        );
+
+        // Without any observed reads, the catalog read prefix length is used.
+        let extractor = filter_key_extractor_manager.acquire(HashSet::from([1])).await;
+        assert_eq!(Some(2), extractor.bloom_filter_prefix_columns(1));
+
+        // A read constrained on fewer columns than the catalog hint shrinks the filter key of
+        // newly built SSTs, while a longer read does not grow it back.
+        filter_key_extractor_manager.observe_read_prefix(1, 1);
+        filter_key_extractor_manager.observe_read_prefix(1, 2);
+        let extractor = filter_key_extractor_manager.acquire(HashSet::from([1])).await;
+        assert_eq!(Some(1), extractor.bloom_filter_prefix_columns(1));
+
+        let order_types: Vec<OrderType> = vec![OrderType::Ascending, OrderType::Ascending];
+        let schema = vec![DataType::Int64, DataType::Varchar];
+        let serializer = OrderedRowSerde::new(schema, order_types);
+        let row = OwnedRow::new(vec![
+            Some(ScalarImpl::Int64(100)),
+            Some(ScalarImpl::Utf8("abc".into())),
+        ]);
+        let mut row_bytes = vec![];
+        serializer.serialize(&row, &mut row_bytes);
+
+        let table_prefix = {
+            let mut buf = BytesMut::with_capacity(TABLE_PREFIX_LEN);
+            buf.put_u32(1);
+            buf.to_vec()
+        };
+        let vnode_prefix = "v".as_bytes();
+        let full_key = [&table_prefix, vnode_prefix, &row_bytes].concat();
+        let output_key = extractor.extract(&full_key);
+        assert_eq!(1 + mem::size_of::<i64>(), output_key.len());
+    }
 }
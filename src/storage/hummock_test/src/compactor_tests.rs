@@ -47,13 +47,13 @@ pub(crate) mod tests {
     use risingwave_storage::hummock::iterator::test_utils::mock_sstable_store;
     use risingwave_storage::hummock::sstable_store::SstableStoreRef;
     use risingwave_storage::hummock::{
-        HummockStorage as GlobalHummockStorage, MemoryLimiter, SstableIdManager,
+        HummockStorage as GlobalHummockStorage, IoLimiter, MemoryLimiter, SstableIdManager,
     };
     use risingwave_storage::monitor::{CompactorMetrics, StoreLocalStatistic};
     use risingwave_storage::opts::StorageOpts;
     use risingwave_storage::storage_value::StorageValue;
     use risingwave_storage::store::{
-        ReadOptions, StateStoreReadExt, StateStoreWrite, WriteOptions,
+        PrefixHint, ReadOptions, StateStoreReadExt, StateStoreWrite, WriteOptions,
     };
 
     use crate::get_notification_client_for_test;
@@ -182,6 +182,7 @@ pub(crate) mod tests {
             is_share_buffer_compact: false,
             compaction_executor: Arc::new(CompactionExecutor::new(Some(1))),
             read_memory_limiter: MemoryLimiter::unlimit(),
+            io_limiter: IoLimiter::unlimited(),
             filter_key_extractor_manager,
             sstable_id_manager: Arc::new(SstableIdManager::new(
                 hummock_meta_client.clone(),
@@ -315,7 +316,7 @@ pub(crate) mod tests {
                 (31 * 1000) << 16,
                 ReadOptions {
                     ignore_range_tombstone: false,
-                    prefix_hint: Some(key.clone()),
+                    prefix_hint: Some(PrefixHint::from_unstructured(key.clone())),
                     table_id: Default::default(),
                     retention_seconds: None,
                     read_version_from_backup: false,
@@ -1144,7 +1145,7 @@ pub(crate) mod tests {
                 None,
                 ReadOptions {
                     ignore_range_tombstone: false,
-                    prefix_hint: Some(Bytes::from(bloom_filter_key)),
+                    prefix_hint: Some(PrefixHint::from_unstructured(Bytes::from(bloom_filter_key))),
                     table_id: TableId::from(existing_table_id),
                     retention_seconds: None,
                     read_version_from_backup: false,
@@ -26,7 +26,9 @@ use risingwave_storage::hummock::iterator::test_utils::mock_sstable_store;
 use risingwave_storage::hummock::test_utils::{count_stream, default_opts_for_test};
 use risingwave_storage::hummock::HummockStorage;
 use risingwave_storage::storage_value::StorageValue;
-use risingwave_storage::store::{ReadOptions, StateStoreRead, StateStoreWrite, WriteOptions};
+use risingwave_storage::store::{
+    PrefixHint, ReadOptions, StateStoreRead, StateStoreWrite, WriteOptions,
+};
 use risingwave_storage::StateStore;
 
 use crate::get_notification_client_for_test;
@@ -96,7 +98,7 @@ async fn test_failpoints_state_store_read_upload() {
             1,
             ReadOptions {
                 ignore_range_tombstone: false,
-                prefix_hint: Some(Bytes::from(anchor_prefix_hint)),
+                prefix_hint: Some(PrefixHint::from_unstructured(Bytes::from(anchor_prefix_hint))),
                 table_id: Default::default(),
                 retention_seconds: None,
                 read_version_from_backup: false,
@@ -147,7 +149,7 @@ async fn test_failpoints_state_store_read_upload() {
             2,
             ReadOptions {
                 ignore_range_tombstone: false,
-                prefix_hint: Some(Bytes::from(anchor_prefix_hint)),
+                prefix_hint: Some(PrefixHint::from_unstructured(Bytes::from(anchor_prefix_hint))),
                 table_id: Default::default(),
                 retention_seconds: None,
                 read_version_from_backup: false,
@@ -182,7 +184,7 @@ async fn test_failpoints_state_store_read_upload() {
             2,
             ReadOptions {
                 ignore_range_tombstone: false,
-                prefix_hint: Some(Bytes::from(bee_prefix_hint)),
+                prefix_hint: Some(PrefixHint::from_unstructured(Bytes::from(bee_prefix_hint))),
                 table_id: Default::default(),
                 retention_seconds: None,
                 read_version_from_backup: false,
@@ -222,7 +224,7 @@ async fn test_failpoints_state_store_read_upload() {
             5,
             ReadOptions {
                 ignore_range_tombstone: false,
-                prefix_hint: Some(Bytes::from(anchor_prefix_hint)),
+                prefix_hint: Some(PrefixHint::from_unstructured(Bytes::from(anchor_prefix_hint))),
                 table_id: Default::default(),
                 retention_seconds: None,
                 read_version_from_backup: false,
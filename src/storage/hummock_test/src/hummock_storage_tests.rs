@@ -23,7 +23,9 @@ use risingwave_hummock_sdk::key::{map_table_key_range, FullKey, UserKey, TABLE_P
 use risingwave_rpc_client::HummockMetaClient;
 use risingwave_storage::hummock::store::version::{read_filter_for_batch, read_filter_for_local};
 use risingwave_storage::storage_value::StorageValue;
-use risingwave_storage::store::{ReadOptions, StateStoreRead, StateStoreWrite, WriteOptions};
+use risingwave_storage::store::{
+    PrefixHint, ReadOptions, StateStoreRead, StateStoreWrite, WriteOptions,
+};
 use risingwave_storage::StateStore;
 
 use crate::test_utils::prepare_hummock_test_env;
@@ -1570,7 +1572,9 @@ async fn test_get_with_min_epoch() {
                         ignore_range_tombstone: false,
                         table_id: TEST_TABLE_ID,
                         retention_seconds: None,
-                        prefix_hint: Some(Bytes::from(prefix_hint.clone())),
+                        prefix_hint: Some(PrefixHint::from_unstructured(Bytes::from(
+                            prefix_hint.clone(),
+                        ))),
                         read_version_from_backup: false,
                     },
                 )
@@ -1588,7 +1592,9 @@ async fn test_get_with_min_epoch() {
                         ignore_range_tombstone: false,
                         table_id: TEST_TABLE_ID,
                         retention_seconds: None,
-                        prefix_hint: Some(Bytes::from(prefix_hint.clone())),
+                        prefix_hint: Some(PrefixHint::from_unstructured(Bytes::from(
+                            prefix_hint.clone(),
+                        ))),
                         read_version_from_backup: false,
                     },
                 )
@@ -1606,7 +1612,9 @@ async fn test_get_with_min_epoch() {
                         ignore_range_tombstone: false,
                         table_id: TEST_TABLE_ID,
                         retention_seconds: Some(1),
-                        prefix_hint: Some(Bytes::from(prefix_hint.clone())),
+                        prefix_hint: Some(PrefixHint::from_unstructured(Bytes::from(
+                            prefix_hint.clone(),
+                        ))),
                         read_version_from_backup: false,
                     },
                 )
@@ -1669,7 +1677,9 @@ async fn test_get_with_min_epoch() {
                     table_id: TEST_TABLE_ID,
                     retention_seconds: None,
 
-                    prefix_hint: Some(Bytes::from(prefix_hint.clone())),
+                    prefix_hint: Some(PrefixHint::from_unstructured(Bytes::from(
+                        prefix_hint.clone(),
+                    ))),
                     read_version_from_backup: false,
                 },
             )
@@ -1690,7 +1700,9 @@ async fn test_get_with_min_epoch() {
                     table_id: TEST_TABLE_ID,
                     retention_seconds: None,
 
-                    prefix_hint: Some(Bytes::from(prefix_hint.clone())),
+                    prefix_hint: Some(PrefixHint::from_unstructured(Bytes::from(
+                        prefix_hint.clone(),
+                    ))),
                     read_version_from_backup: false,
                 },
             )
@@ -1710,7 +1722,9 @@ async fn test_get_with_min_epoch() {
                     table_id: TEST_TABLE_ID,
                     retention_seconds: Some(1),
 
-                    prefix_hint: Some(Bytes::from(prefix_hint.clone())),
+                    prefix_hint: Some(PrefixHint::from_unstructured(Bytes::from(
+                        prefix_hint.clone(),
+                    ))),
                     read_version_from_backup: false,
                 },
             )
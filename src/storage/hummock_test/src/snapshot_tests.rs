@@ -24,14 +24,13 @@ use risingwave_rpc_client::HummockMetaClient;
 use risingwave_storage::hummock::iterator::test_utils::mock_sstable_store;
 use risingwave_storage::hummock::test_utils::default_opts_for_test;
 use risingwave_storage::hummock::*;
-use risingwave_storage::monitor::{CompactorMetrics, HummockStateStoreMetrics};
 use risingwave_storage::storage_value::StorageValue;
 use risingwave_storage::store::{ReadOptions, StateStoreWrite, WriteOptions};
 use risingwave_storage::StateStore;
 
 use crate::get_notification_client_for_test;
 use crate::test_utils::{
-    with_hummock_storage_v1, with_hummock_storage_v2, HummockStateStoreTestTrait,
+    with_hummock_storage_v2, HummockStateStoreTestTrait, HummockV2MixedStateStore,
 };
 
 macro_rules! assert_count_range_scan {
@@ -282,18 +281,18 @@ async fn test_snapshot_backward_range_scan_inner(enable_sync: bool, enable_commi
         worker_node.id,
     ));
 
-    // TODO: may also test for v2 when the unit test is enabled.
-    let hummock_storage = HummockStorageV1::new(
-        hummock_options,
-        sstable_store,
-        mock_hummock_meta_client.clone(),
-        get_notification_client_for_test(env, hummock_manager_ref, worker_node),
-        Arc::new(HummockStateStoreMetrics::unused()),
-        Arc::new(risingwave_tracing::RwTracingService::disabled()),
-        Arc::new(CompactorMetrics::unused()),
+    let hummock_storage = HummockV2MixedStateStore::new(
+        HummockStorage::for_test(
+            hummock_options,
+            sstable_store,
+            mock_hummock_meta_client.clone(),
+            get_notification_client_for_test(env, hummock_manager_ref, worker_node),
+        )
+        .await
+        .unwrap(),
+        Default::default(),
     )
-    .await
-    .unwrap();
+    .await;
 
     let epoch = 1;
     hummock_storage
@@ -383,72 +382,36 @@ async fn test_snapshot_backward_range_scan_inner(enable_sync: bool, enable_commi
     // assert_count_backward_range_scan!(hummock_storage, key!(7)..key!(2), 5, epoch + 1);
 }
 
-#[tokio::test]
-async fn test_snapshot_v1() {
-    let (storage, meta_client) = with_hummock_storage_v1().await;
-    test_snapshot_inner(storage, meta_client, false, false).await;
-}
-
 #[tokio::test]
 async fn test_snapshot_v2() {
     let (storage, meta_client) = with_hummock_storage_v2(Default::default()).await;
     test_snapshot_inner(storage, meta_client, false, false).await;
 }
 
-#[tokio::test]
-async fn test_snapshot_with_sync_v1() {
-    let (storage, meta_client) = with_hummock_storage_v1().await;
-    test_snapshot_inner(storage, meta_client, true, false).await;
-}
-
 #[tokio::test]
 async fn test_snapshot_with_sync_v2() {
     let (storage, meta_client) = with_hummock_storage_v2(Default::default()).await;
     test_snapshot_inner(storage, meta_client, true, false).await;
 }
 
-#[tokio::test]
-async fn test_snapshot_with_commit_v1() {
-    let (storage, meta_client) = with_hummock_storage_v1().await;
-    test_snapshot_inner(storage, meta_client, true, true).await;
-}
-
 #[tokio::test]
 async fn test_snapshot_with_commit_v2() {
     let (storage, meta_client) = with_hummock_storage_v2(Default::default()).await;
     test_snapshot_inner(storage, meta_client, true, true).await;
 }
 
-#[tokio::test]
-async fn test_snapshot_range_scan_v1() {
-    let (storage, meta_client) = with_hummock_storage_v1().await;
-    test_snapshot_range_scan_inner(storage, meta_client, false, false).await;
-}
-
 #[tokio::test]
 async fn test_snapshot_range_scan_v2() {
     let (storage, meta_client) = with_hummock_storage_v2(Default::default()).await;
     test_snapshot_range_scan_inner(storage, meta_client, false, false).await;
 }
 
-#[tokio::test]
-async fn test_snapshot_range_scan_with_sync_v1() {
-    let (storage, meta_client) = with_hummock_storage_v1().await;
-    test_snapshot_range_scan_inner(storage, meta_client, true, false).await;
-}
-
 #[tokio::test]
 async fn test_snapshot_range_scan_with_sync_v2() {
     let (storage, meta_client) = with_hummock_storage_v2(Default::default()).await;
     test_snapshot_range_scan_inner(storage, meta_client, true, false).await;
 }
 
-#[tokio::test]
-async fn test_snapshot_range_scan_with_commit_v1() {
-    let (storage, meta_client) = with_hummock_storage_v1().await;
-    test_snapshot_range_scan_inner(storage, meta_client, true, true).await;
-}
-
 #[tokio::test]
 async fn test_snapshot_range_scan_with_commit_v2() {
     let (storage, meta_client) = with_hummock_storage_v2(Default::default()).await;
@@ -26,8 +26,7 @@ use risingwave_meta::hummock::MockHummockMetaClient;
 use risingwave_rpc_client::HummockMetaClient;
 use risingwave_storage::hummock::iterator::test_utils::mock_sstable_store;
 use risingwave_storage::hummock::test_utils::{count_stream, default_opts_for_test};
-use risingwave_storage::hummock::{HummockStorage, HummockStorageV1};
-use risingwave_storage::monitor::{CompactorMetrics, HummockStateStoreMetrics};
+use risingwave_storage::hummock::HummockStorage;
 use risingwave_storage::storage_value::StorageValue;
 use risingwave_storage::store::{
     ReadOptions, StateStore, StateStoreRead, StateStoreWrite, SyncResult, WriteOptions,
@@ -35,8 +34,7 @@ use risingwave_storage::store::{
 
 use crate::get_notification_client_for_test;
 use crate::test_utils::{
-    with_hummock_storage_v1, with_hummock_storage_v2, HummockStateStoreTestTrait,
-    HummockV2MixedStateStore,
+    with_hummock_storage_v2, HummockStateStoreTestTrait, HummockV2MixedStateStore,
 };
 
 #[tokio::test]
@@ -75,12 +73,6 @@ async fn test_empty_read_v2() {
     assert!(stream.try_next().await.unwrap().is_none());
 }
 
-#[tokio::test]
-async fn test_basic_v1() {
-    let (hummock_storage, meta_client) = with_hummock_storage_v1().await;
-    test_basic_inner(hummock_storage, meta_client).await;
-}
-
 #[tokio::test]
 async fn test_basic_v2() {
     let (hummock_storage, meta_client) = with_hummock_storage_v2(Default::default()).await;
@@ -427,12 +419,6 @@ async fn test_basic_inner(
     assert!(value.is_none());
 }
 
-#[tokio::test]
-async fn test_state_store_sync_v1() {
-    let (hummock_storage, meta_client) = with_hummock_storage_v1().await;
-    test_state_store_sync_inner(hummock_storage, meta_client).await;
-}
-
 #[tokio::test]
 async fn test_state_store_sync_v2() {
     let (hummock_storage, meta_client) = with_hummock_storage_v2(Default::default()).await;
@@ -547,22 +533,22 @@ async fn test_reload_storage() {
         worker_node.id,
     ));
 
-    // TODO: may also test for v2 when the unit test is enabled.
-    let hummock_storage = HummockStorageV1::new(
-        hummock_options.clone(),
-        sstable_store.clone(),
-        meta_client.clone(),
-        get_notification_client_for_test(
-            env.clone(),
-            hummock_manager_ref.clone(),
-            worker_node.clone(),
-        ),
-        Arc::new(HummockStateStoreMetrics::unused()),
-        Arc::new(risingwave_tracing::RwTracingService::disabled()),
-        Arc::new(CompactorMetrics::unused()),
+    let hummock_storage = HummockV2MixedStateStore::new(
+        HummockStorage::for_test(
+            hummock_options.clone(),
+            sstable_store.clone(),
+            meta_client.clone(),
+            get_notification_client_for_test(
+                env.clone(),
+                hummock_manager_ref.clone(),
+                worker_node.clone(),
+            ),
+        )
+        .await
+        .unwrap(),
+        Default::default(),
     )
-    .await
-    .unwrap();
+    .await;
     let anchor = Bytes::from("aa");
 
     // First batch inserts the anchor and others.
@@ -758,12 +744,6 @@ async fn test_reload_storage() {
     assert_eq!(len, 3);
 }
 
-#[tokio::test]
-async fn test_write_anytime_v1() {
-    let (hummock_storage, meta_client) = with_hummock_storage_v1().await;
-    test_write_anytime_inner(hummock_storage, meta_client).await;
-}
-
 #[tokio::test]
 async fn test_write_anytime_v2() {
     let (hummock_storage, meta_client) = with_hummock_storage_v2(Default::default()).await;
@@ -1058,12 +1038,6 @@ async fn test_write_anytime_inner(
     assert!(!ssts2.is_empty());
 }
 
-#[tokio::test]
-async fn test_delete_get_v1() {
-    let (hummock_storage, meta_client) = with_hummock_storage_v1().await;
-    test_delete_get_inner(hummock_storage, meta_client).await;
-}
-
 #[tokio::test]
 async fn test_delete_get_v2() {
     let (hummock_storage, meta_client) = with_hummock_storage_v2(Default::default()).await;
@@ -1138,12 +1112,6 @@ async fn test_delete_get_inner(
         .is_none());
 }
 
-#[tokio::test]
-async fn test_multiple_epoch_sync_v1() {
-    let (hummock_storage, meta_client) = with_hummock_storage_v1().await;
-    test_multiple_epoch_sync_inner(hummock_storage, meta_client).await;
-}
-
 #[tokio::test]
 async fn test_multiple_epoch_sync_v2() {
     let (hummock_storage, meta_client) = with_hummock_storage_v2(Default::default()).await;
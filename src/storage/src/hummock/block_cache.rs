@@ -172,6 +172,11 @@ impl BlockCache {
         Ok(BlockHolder::from_cached_block(block))
     }
 
+    pub fn erase(&self, sst_id: HummockSstableId, block_idx: u64) {
+        self.inner
+            .erase(Self::hash(sst_id, block_idx), &(sst_id, block_idx));
+    }
+
     fn hash(sst_id: HummockSstableId, block_idx: u64) -> u64 {
         let mut hasher = DefaultHasher::default();
         sst_id.hash(&mut hasher);
@@ -33,7 +33,7 @@ use crate::hummock::compactor::{
 };
 use crate::hummock::multi_builder::TableBuilderFactory;
 use crate::hummock::{
-    CachePolicy, HummockResult, MemoryLimiter, SstableBuilder, SstableBuilderOptions,
+    CachePolicy, HummockResult, IoLimiter, MemoryLimiter, SstableBuilder, SstableBuilderOptions,
     SstableIdManagerRef, SstableWriterFactory, SstableWriterOptions,
 };
 use crate::monitor::StoreLocalStatistic;
@@ -41,6 +41,7 @@ use crate::monitor::StoreLocalStatistic;
 pub struct RemoteBuilderFactory<F: SstableWriterFactory> {
     pub sstable_id_manager: SstableIdManagerRef,
     pub limiter: Arc<MemoryLimiter>,
+    pub io_limiter: Arc<IoLimiter>,
     pub options: SstableBuilderOptions,
     pub policy: CachePolicy,
     pub remote_rpc_cost: Arc<AtomicU64>,
@@ -66,6 +67,7 @@ impl<F: SstableWriterFactory> TableBuilderFactory for RemoteBuilderFactory<F> {
             capacity_hint: Some(self.options.capacity + self.options.block_capacity),
             tracker: Some(tracker),
             policy: self.policy,
+            io_limiter: Some(self.io_limiter.clone()),
         };
         let writer = self
             .sstable_writer_factory
@@ -210,10 +212,14 @@ pub async fn generate_splits(compact_task: &mut CompactTask, context: Arc<Compac
         indexes.sort_by(|a, b| KeyComparator::compare_encoded_full_key(a.1.as_ref(), b.1.as_ref()));
         let mut splits: Vec<KeyRange_vec> = vec![];
         splits.push(KeyRange_vec::new(vec![], vec![]));
-        let parallelism = std::cmp::min(
-            indexes.len() as u64,
-            context.storage_opts.max_sub_compaction as u64,
-        );
+        // A task-level cap set by the meta node takes precedence over the local config, so
+        // that sub-compaction parallelism fits the smallest member of the compactor fleet.
+        let max_sub_compaction = if compact_task.max_sub_compaction > 0 {
+            compact_task.max_sub_compaction
+        } else {
+            context.storage_opts.max_sub_compaction
+        };
+        let parallelism = std::cmp::min(indexes.len() as u64, max_sub_compaction as u64);
         let sub_compaction_data_size = std::cmp::max(compaction_size / parallelism, sstable_size);
         let parallelism = compaction_size / sub_compaction_data_size;
 
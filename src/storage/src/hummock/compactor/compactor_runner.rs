@@ -180,6 +180,7 @@ impl CompactorRunner {
                     tables,
                     self.compactor.task_config.key_range.clone(),
                     self.sstable_store.clone(),
+                    Some(self.compactor.context.io_limiter.clone()),
                 ));
             } else {
                 for table_info in &level.table_infos {
@@ -191,6 +192,7 @@ impl CompactorRunner {
                         vec![table_info.clone()],
                         self.compactor.task_config.key_range.clone(),
                         self.sstable_store.clone(),
+                        Some(self.compactor.context.io_limiter.clone()),
                     ));
                 }
             }
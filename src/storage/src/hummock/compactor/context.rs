@@ -21,7 +21,7 @@ use risingwave_rpc_client::HummockMetaClient;
 use super::task_progress::TaskProgressManagerRef;
 use crate::hummock::compactor::CompactionExecutor;
 use crate::hummock::sstable_store::SstableStoreRef;
-use crate::hummock::{MemoryLimiter, SstableIdManagerRef};
+use crate::hummock::{IoLimiter, MemoryLimiter, SstableIdManagerRef};
 use crate::monitor::CompactorMetrics;
 use crate::opts::StorageOpts;
 
@@ -49,6 +49,9 @@ pub struct CompactorContext {
 
     pub read_memory_limiter: Arc<MemoryLimiter>,
 
+    /// Limits the total compaction read/write bandwidth.
+    pub io_limiter: Arc<IoLimiter>,
+
     pub sstable_id_manager: SstableIdManagerRef,
 
     pub task_progress_manager: TaskProgressManagerRef,
@@ -76,6 +79,9 @@ impl CompactorContext {
         };
         // not limit memory for local compact
         let memory_limiter = MemoryLimiter::unlimit();
+        let io_limiter = Arc::new(IoLimiter::new(
+            compactor_runtime_config.max_io_bandwidth_bytes_per_sec,
+        ));
         Self {
             storage_opts,
             hummock_meta_client,
@@ -85,6 +91,7 @@ impl CompactorContext {
             compaction_executor,
             filter_key_extractor_manager,
             read_memory_limiter: memory_limiter,
+            io_limiter,
             sstable_id_manager,
             task_progress_manager: Default::default(),
             compactor_runtime_config: Arc::new(tokio::sync::Mutex::new(compactor_runtime_config)),
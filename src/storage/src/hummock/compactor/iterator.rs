@@ -26,7 +26,7 @@ use risingwave_pb::hummock::SstableInfo;
 use crate::hummock::iterator::{Forward, HummockIterator};
 use crate::hummock::sstable_store::{BlockStream, SstableStoreRef};
 use crate::hummock::value::HummockValue;
-use crate::hummock::{Block, BlockHolder, BlockIterator, HummockResult};
+use crate::hummock::{Block, BlockHolder, BlockIterator, HummockResult, IoLimiter};
 use crate::monitor::StoreLocalStatistic;
 
 /// Iterates over the KV-pairs of an SST while downloading it.
@@ -182,6 +182,9 @@ pub struct ConcatSstableIterator {
 
     sstable_store: SstableStoreRef,
 
+    /// Throttles block downloads if a bandwidth limit is configured.
+    io_limiter: Option<Arc<IoLimiter>>,
+
     stats: StoreLocalStatistic,
 }
 
@@ -193,6 +196,7 @@ impl ConcatSstableIterator {
         tables: Vec<SstableInfo>,
         key_range: KeyRange,
         sstable_store: SstableStoreRef,
+        io_limiter: Option<Arc<IoLimiter>>,
     ) -> Self {
         Self {
             key_range,
@@ -200,6 +204,7 @@ impl ConcatSstableIterator {
             cur_idx: 0,
             tables,
             sstable_store,
+            io_limiter,
             stats: StoreLocalStatistic::default(),
         }
     }
@@ -257,7 +262,7 @@ impl ConcatSstableIterator {
 
             let block_stream = self
                 .sstable_store
-                .get_stream(table.value(), Some(start_index))
+                .get_stream(table.value(), Some(start_index), self.io_limiter.clone())
                 .await?;
 
             // Determine time needed to open stream.
@@ -387,8 +392,12 @@ mod tests {
             test_key_of(start_index).encode().into(),
             test_key_of(end_index).encode().into(),
         );
-        let mut iter =
-            ConcatSstableIterator::new(table_infos.clone(), kr.clone(), sstable_store.clone());
+        let mut iter = ConcatSstableIterator::new(
+            table_infos.clone(),
+            kr.clone(),
+            sstable_store.clone(),
+            None,
+        );
         iter.seek(FullKey::decode(&kr.left)).await.unwrap();
 
         for idx in start_index..end_index {
@@ -407,16 +416,24 @@ mod tests {
             test_key_of(30000).encode().into(),
             test_key_of(40000).encode().into(),
         );
-        let mut iter =
-            ConcatSstableIterator::new(table_infos.clone(), kr.clone(), sstable_store.clone());
+        let mut iter = ConcatSstableIterator::new(
+            table_infos.clone(),
+            kr.clone(),
+            sstable_store.clone(),
+            None,
+        );
         iter.seek(FullKey::decode(&kr.left)).await.unwrap();
         assert!(!iter.is_valid());
         let kr = KeyRange::new(
             test_key_of(start_index).encode().into(),
             test_key_of(40000).encode().into(),
         );
-        let mut iter =
-            ConcatSstableIterator::new(table_infos.clone(), kr.clone(), sstable_store.clone());
+        let mut iter = ConcatSstableIterator::new(
+            table_infos.clone(),
+            kr.clone(),
+            sstable_store.clone(),
+            None,
+        );
         iter.seek(FullKey::decode(&kr.left)).await.unwrap();
         for idx in start_index..30000 {
             let key = iter.key();
@@ -435,8 +452,12 @@ mod tests {
             test_key_of(0).encode().into(),
             test_key_of(40000).encode().into(),
         );
-        let mut iter =
-            ConcatSstableIterator::new(table_infos.clone(), kr.clone(), sstable_store.clone());
+        let mut iter = ConcatSstableIterator::new(
+            table_infos.clone(),
+            kr.clone(),
+            sstable_store.clone(),
+            None,
+        );
         iter.seek(test_key_of(10000).to_ref()).await.unwrap();
         assert!(iter.is_valid() && iter.cur_idx == 1 && iter.key() == test_key_of(10000).to_ref());
         iter.seek(test_key_of(10001).to_ref()).await.unwrap();
@@ -455,8 +476,12 @@ mod tests {
             test_key_of(6000).encode().into(),
             test_key_of(16000).encode().into(),
         );
-        let mut iter =
-            ConcatSstableIterator::new(table_infos.clone(), kr.clone(), sstable_store.clone());
+        let mut iter = ConcatSstableIterator::new(
+            table_infos.clone(),
+            kr.clone(),
+            sstable_store.clone(),
+            None,
+        );
         iter.seek(test_key_of(17000).to_ref()).await.unwrap();
         assert!(!iter.is_valid());
         iter.seek(test_key_of(1).to_ref()).await.unwrap();
@@ -486,8 +511,12 @@ mod tests {
             test_key_of(0).encode().into(),
             test_key_of(40000).encode().into(),
         );
-        let mut iter =
-            ConcatSstableIterator::new(table_infos.clone(), kr.clone(), sstable_store.clone());
+        let mut iter = ConcatSstableIterator::new(
+            table_infos.clone(),
+            kr.clone(),
+            sstable_store.clone(),
+            None,
+        );
         let sst = sstable_store
             .sstable(&iter.tables[0], &mut iter.stats)
             .await
@@ -518,8 +547,12 @@ mod tests {
             next_full_key(&block_1_smallest_key).into(),
             prev_full_key(&block_2_smallest_key).into(),
         );
-        let mut iter =
-            ConcatSstableIterator::new(table_infos.clone(), kr.clone(), sstable_store.clone());
+        let mut iter = ConcatSstableIterator::new(
+            table_infos.clone(),
+            kr.clone(),
+            sstable_store.clone(),
+            None,
+        );
         // Use block_2_smallest_key as seek key and result in invalid iterator.
         let seek_key = block_2_smallest_key.clone();
         assert!(KeyComparator::compare_encoded_full_key(&seek_key, &kr.right) == Ordering::Greater);
@@ -18,6 +18,7 @@ mod compaction_utils;
 mod compactor_runner;
 mod context;
 mod iterator;
+mod scrub;
 mod shared_buffer_compact;
 pub(super) mod task_progress;
 
@@ -35,6 +36,7 @@ pub use context::CompactorContext;
 use futures::future::try_join_all;
 use futures::{stream, StreamExt};
 pub use iterator::ConcatSstableIterator;
+pub use scrub::start_scrub_service;
 use itertools::Itertools;
 use risingwave_hummock_sdk::compact::compact_task_to_string;
 use risingwave_hummock_sdk::filter_key_extractor::FilterKeyExtractorImpl;
@@ -159,6 +161,37 @@ impl Compactor {
             need_quota
         );
 
+        // `existing_table_ids` was filled when the task was created. Tables may have been
+        // dropped since then, so confirm the set with meta before building the compaction
+        // filter, to drop data of dropped tables without waiting for a space-reclaim task.
+        match context
+            .hummock_meta_client
+            .get_dropped_table_ids(compact_task.existing_table_ids.clone())
+            .await
+        {
+            Ok(dropped_table_ids) if !dropped_table_ids.is_empty() => {
+                tracing::info!(
+                    "Compaction task {} drops data of dropped tables {:?}",
+                    compact_task.task_id,
+                    dropped_table_ids
+                );
+                let dropped_table_ids: HashSet<u32> = dropped_table_ids.into_iter().collect();
+                compact_task
+                    .existing_table_ids
+                    .retain(|table_id| !dropped_table_ids.contains(table_id));
+            }
+            Ok(_) => {}
+            Err(e) => {
+                // Fall back to the creation-time set. It only delays reclamation, so the
+                // task should not fail because of this.
+                tracing::warn!(
+                    "Failed to confirm dropped tables for compaction task {}: {:#?}",
+                    compact_task.task_id,
+                    e
+                );
+            }
+        }
+
         let mut multi_filter = build_multi_compaction_filter(&compact_task);
 
         let multi_filter_key_extractor = context
@@ -731,6 +764,7 @@ impl Compactor {
         let builder_factory = RemoteBuilderFactory {
             sstable_id_manager: self.context.sstable_id_manager.clone(),
             limiter: self.context.read_memory_limiter.clone(),
+            io_limiter: self.context.io_limiter.clone(),
             options: self.options.clone(),
             policy: self.task_config.cache_policy,
             remote_rpc_cost: self.get_id_time.clone(),
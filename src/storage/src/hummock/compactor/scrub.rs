@@ -0,0 +1,99 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use risingwave_hummock_sdk::compaction_group::hummock_version_ext::HummockVersionExt;
+use risingwave_pb::hummock::SstableInfo;
+use tokio::sync::oneshot::Sender;
+use tokio::task::JoinHandle;
+
+use crate::hummock::compactor::CompactorContext;
+use crate::hummock::{CachePolicy, HummockResult};
+use crate::monitor::StoreLocalStatistic;
+
+/// Verifies the integrity of a single SST. Loading the meta validates its checksum, which also
+/// covers the bloom filter, and decoding every block validates the per-block checksums.
+async fn scrub_sstable(context: &CompactorContext, sst: &SstableInfo) -> HummockResult<()> {
+    let mut stats = StoreLocalStatistic::default();
+    let holder = context.sstable_store.sstable(sst, &mut stats).await?;
+    for block_index in 0..holder.value().block_count() as u64 {
+        context
+            .sstable_store
+            .get(
+                holder.value(),
+                block_index,
+                CachePolicy::NotFill,
+                &mut stats,
+            )
+            .await?;
+    }
+    Ok(())
+}
+
+/// The background scrub thread that periodically iterates all SSTs referenced by the current
+/// hummock version, verifies block checksums and bloom filter integrity, and reports corrupted
+/// SST ids back to meta so they can be quarantined. Without scrubbing, corruption is only
+/// discovered at read time.
+#[cfg_attr(coverage, no_coverage)]
+pub fn start_scrub_service(context: Arc<CompactorContext>) -> (JoinHandle<()>, Sender<()>) {
+    let (shutdown_tx, mut shutdown_rx) = tokio::sync::oneshot::channel();
+    let scrub_interval = Duration::from_secs(context.storage_opts.sst_scrub_interval_sec);
+    let join_handle = tokio::spawn(async move {
+        let mut min_interval = tokio::time::interval(scrub_interval);
+        loop {
+            tokio::select! {
+                _ = min_interval.tick() => {},
+                _ = &mut shutdown_rx => {
+                    tracing::info!("Scrub service is shutting down");
+                    return;
+                }
+            }
+            let version = match context.hummock_meta_client.get_current_version().await {
+                Ok(version) => version,
+                Err(e) => {
+                    tracing::warn!("Scrub failed to get current version. {:#?}", e);
+                    continue;
+                }
+            };
+            let mut scrubbed_count: usize = 0;
+            let mut corrupted_sst_ids = vec![];
+            for level in version.get_combined_levels() {
+                for sst in &level.table_infos {
+                    if let Err(e) = scrub_sstable(&context, sst).await {
+                        tracing::error!("Scrub found corrupted SST {}. {:#?}", sst.id, e);
+                        corrupted_sst_ids.push(sst.id);
+                    }
+                    scrubbed_count += 1;
+                }
+            }
+            if !corrupted_sst_ids.is_empty() {
+                if let Err(e) = context
+                    .hummock_meta_client
+                    .report_corrupted_ssts(corrupted_sst_ids)
+                    .await
+                {
+                    tracing::warn!("Failed to report corrupted SSTs. {:#?}", e);
+                }
+            }
+            tracing::info!(
+                "Scrubbed {} SSTs of version {}",
+                scrubbed_count,
+                version.id
+            );
+        }
+    });
+    (join_handle, shutdown_tx)
+}
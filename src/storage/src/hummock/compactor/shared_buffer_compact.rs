@@ -22,7 +22,7 @@ use itertools::Itertools;
 use risingwave_common::catalog::TableId;
 use risingwave_hummock_sdk::compaction_group::StaticCompactionGroupId;
 use risingwave_hummock_sdk::filter_key_extractor::FilterKeyExtractorImpl;
-use risingwave_hummock_sdk::key::{FullKey, UserKey};
+use risingwave_hummock_sdk::key::{FullKey, TableKey, UserKey};
 use risingwave_hummock_sdk::key_range::KeyRange;
 use risingwave_hummock_sdk::{CompactionGroupId, HummockEpoch, LocalSstableInfo};
 use risingwave_pb::hummock::compact_task;
@@ -120,23 +120,32 @@ async fn compact_shared_buffer(
     let mut builder = DeleteRangeAggregatorBuilder::default();
     for data_list in &payload {
         for data in data_list {
-            let data_size = match data {
+            match data {
                 UncommittedData::Sst(LocalSstableInfo { sst_info, .. }) => {
                     let table = sstable_store.sstable(sst_info, &mut local_stats).await?;
                     // TODO: use reference to avoid memory allocation.
                     let tombstones = table.value().meta.range_tombstone_list.clone();
                     builder.add_tombstone(tombstones);
-                    sst_info.file_size
+                    compact_data_size += sst_info.file_size;
+                    size_and_start_user_keys.push((sst_info.file_size, data.start_user_key()));
                 }
                 UncommittedData::Batch(batch) => {
                     let tombstones = batch.get_delete_range_tombstones();
                     builder.add_tombstone(tombstones);
-                    // calculate encoded bytes of key var length
-                    (batch.get_payload().len() * 8 + batch.size()) as u64
+                    // A wide-state actor may sync one huge batch. Generate a split candidate
+                    // per key instead of per batch, so that such a batch can still be
+                    // partitioned among the sub-compactions.
+                    for (key, value) in batch.get_payload() {
+                        // calculate encoded bytes of key var length
+                        let data_size = (8 + key.len() + value.encoded_len()) as u64;
+                        compact_data_size += data_size;
+                        size_and_start_user_keys.push((
+                            data_size,
+                            UserKey::new(batch.table_id, TableKey(key.as_ref())),
+                        ));
+                    }
                 }
             };
-            compact_data_size += data_size;
-            size_and_start_user_keys.push((data_size, data.start_user_key()));
         }
     }
     size_and_start_user_keys.sort();
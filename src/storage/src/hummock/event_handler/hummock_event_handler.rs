@@ -12,18 +12,22 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::ops::DerefMut;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 
 use arc_swap::ArcSwap;
 use futures::future::{select, Either};
 use futures::FutureExt;
 use parking_lot::RwLock;
-use risingwave_hummock_sdk::compaction_group::hummock_version_ext::HummockVersionUpdateExt;
+use risingwave_hummock_sdk::compaction_group::hummock_version_ext::{
+    summarize_group_deltas, HummockVersionUpdateExt,
+};
 use risingwave_hummock_sdk::{HummockEpoch, LocalSstableInfo};
 use risingwave_pb::hummock::version_update_payload::Payload;
+use risingwave_pb::hummock::SstableInfo;
 use tokio::spawn;
 use tokio::sync::{mpsc, oneshot};
 use tracing::{error, info};
@@ -32,16 +36,18 @@ use super::{LocalInstanceGuard, LocalInstanceId, ReadVersionMappingType};
 use crate::hummock::compactor::{compact, CompactorContext};
 use crate::hummock::conflict_detector::ConflictDetector;
 use crate::hummock::event_handler::uploader::{
-    HummockUploader, UploadTaskInfo, UploadTaskPayload, UploaderEvent,
+    HummockUploader, UploadRateLimiter, UploadTaskInfo, UploadTaskPayload, UploaderEvent,
 };
 use crate::hummock::event_handler::HummockEvent;
 use crate::hummock::local_version::pinned_version::PinnedVersion;
 use crate::hummock::shared_buffer::UncommittedData;
+use crate::hummock::sstable_store::SstableStoreRef;
 use crate::hummock::store::version::{
     HummockReadVersion, StagingData, StagingSstableInfo, VersionUpdate,
 };
 use crate::hummock::utils::validate_table_key_range;
 use crate::hummock::{HummockError, HummockResult, MemoryLimiter, SstableIdManagerRef, TrackerId};
+use crate::monitor::{global_table_access_stats, StoreLocalStatistic};
 use crate::opts::StorageOpts;
 use crate::store::SyncResult;
 
@@ -106,7 +112,13 @@ pub struct HummockEventHandler {
 
     last_instance_id: LocalInstanceId,
 
+    sstable_store: SstableStoreRef,
     sstable_id_manager: SstableIdManagerRef,
+    sst_meta_preload_budget_bytes: u64,
+
+    /// The largest fence token observed in applied version deltas. A version delta carrying a
+    /// smaller token comes from a deposed meta leader and must not be applied.
+    max_fence_token: u64,
 }
 
 async fn flush_imms(
@@ -148,11 +160,24 @@ impl HummockEventHandler {
         let buffer_tracker = BufferTracker::from_storage_opts(&compactor_context.storage_opts);
         let write_conflict_detector =
             ConflictDetector::new_from_config(&compactor_context.storage_opts);
+        let sstable_store = compactor_context.sstable_store.clone();
         let sstable_id_manager = compactor_context.sstable_id_manager.clone();
+        let sst_meta_preload_budget_bytes =
+            (compactor_context.storage_opts.sst_meta_preload_budget_mb as u64) << 20;
+        let upload_rate_limiter = Arc::new(UploadRateLimiter::new(
+            compactor_context.storage_opts.sst_upload_max_bytes_per_sec,
+        ));
         let uploader = HummockUploader::new(
             pinned_version.clone(),
             Arc::new(move |payload, task_info| {
-                spawn(flush_imms(payload, task_info, compactor_context.clone()))
+                let compactor_context = compactor_context.clone();
+                let upload_rate_limiter = upload_rate_limiter.clone();
+                spawn(async move {
+                    // Delaying the upload is fine: the imms stay readable in the shared buffer,
+                    // and the buffer tracker backpressures writers if they outrun the limit.
+                    upload_rate_limiter.acquire(task_info.task_size as u64).await;
+                    flush_imms(payload, task_info, compactor_context).await
+                })
             }),
             buffer_tracker,
         );
@@ -167,7 +192,10 @@ impl HummockEventHandler {
             read_version_mapping,
             uploader,
             last_instance_id: 0,
+            sstable_store,
             sstable_id_manager,
+            sst_meta_preload_budget_bytes,
+            max_fence_token: 0,
         }
     }
 
@@ -359,13 +387,49 @@ impl HummockEventHandler {
         let pinned_version = self.pinned_version.load();
 
         let prev_max_committed_epoch = pinned_version.max_committed_epoch();
+        let mut preload_sst_infos = Vec::new();
+        let mut refill_sst_infos = Vec::new();
         let newly_pinned_version = match version_payload {
             Payload::VersionDeltas(version_deltas) => {
                 let mut version_to_apply = pinned_version.version();
+                let mut removed_sst_ids = HashSet::new();
+                let mut inserted_sst_ids = HashSet::new();
                 for version_delta in &version_deltas.version_deltas {
                     assert_eq!(version_to_apply.id, version_delta.prev_id);
+                    // Reject in-flight commits from a deposed meta leader.
+                    assert!(
+                        version_delta.fence_token >= self.max_fence_token,
+                        "fencing violation: version delta {} carries fence token {} but token {} has been observed",
+                        version_delta.id,
+                        version_delta.fence_token,
+                        self.max_fence_token
+                    );
+                    self.max_fence_token = version_delta.fence_token;
+                    for group_deltas in version_delta.group_deltas.values() {
+                        let summary = summarize_group_deltas(group_deltas);
+                        removed_sst_ids.extend(summary.delete_sst_ids_set);
+                        inserted_sst_ids
+                            .extend(summary.insert_table_infos.iter().map(|sst| sst.id));
+                        // Newly committed SSTs always land in L0, and are the ones first reads
+                        // after the checkpoint will hit.
+                        if summary.insert_sst_level_id == 0 {
+                            preload_sst_infos.extend(summary.insert_table_infos);
+                        } else {
+                            // Deeper-level inserts are compaction outputs: their meta is cold
+                            // while the compacted-away inputs may just have been evicted, so
+                            // they are candidates for the hot-table meta refill.
+                            refill_sst_infos.extend(summary.insert_table_infos);
+                        }
+                    }
                     version_to_apply.apply_version_delta(version_delta);
                 }
+                // The removed SSTs will never be read again, so proactively evict their cached
+                // meta and blocks to make room for the new SSTs, instead of waiting for LRU
+                // churn. SSTs moved between compaction groups appear in both sets and stay
+                // cached.
+                for sst_id in removed_sst_ids.difference(&inserted_sst_ids) {
+                    self.sstable_store.evict_removed_sst(*sst_id);
+                }
                 version_to_apply
             }
             Payload::PinnedVersion(version) => version,
@@ -405,6 +469,110 @@ impl HummockEventHandler {
             ));
 
         self.uploader.update_pinned_version(new_pinned_version);
+
+        self.preload_sst_meta(preload_sst_infos);
+        self.refill_sst_meta_for_hot_tables(refill_sst_infos);
+    }
+
+    /// Spawns a background task that preloads the meta of newly committed SSTs serving tables
+    /// with active read versions on this node, within the configured budget. Fetching the meta
+    /// populates the meta cache, so the first read of these SSTs doesn't pay a cold fetch.
+    fn preload_sst_meta(&self, mut sst_infos: Vec<SstableInfo>) {
+        if self.sst_meta_preload_budget_bytes == 0 || sst_infos.is_empty() {
+            return;
+        }
+
+        let served_table_ids: HashSet<u32> = self
+            .read_version_mapping
+            .read()
+            .keys()
+            .map(|table_id| table_id.table_id)
+            .collect();
+        let mut remaining_budget = self.sst_meta_preload_budget_bytes;
+        sst_infos.retain(|sst| {
+            if !sst
+                .table_ids
+                .iter()
+                .any(|table_id| served_table_ids.contains(table_id))
+            {
+                return false;
+            }
+            // The meta block is the tail of the SST object.
+            let meta_size = sst.file_size.saturating_sub(sst.meta_offset);
+            if meta_size > remaining_budget {
+                return false;
+            }
+            remaining_budget -= meta_size;
+            true
+        });
+        if sst_infos.is_empty() {
+            return;
+        }
+
+        let sstable_store = self.sstable_store.clone();
+        spawn(async move {
+            let mut stats = StoreLocalStatistic::default();
+            for sst in &sst_infos {
+                // Holding the returned handle is unnecessary: fetching the meta is enough to
+                // populate the meta cache.
+                if let Err(e) = sstable_store.sstable(sst, &mut stats).await {
+                    info!("failed to preload meta of SST {}: {:?}", sst.id, e);
+                }
+            }
+        });
+    }
+
+    /// Spawns a low-priority background task that refills the meta cache for compaction output
+    /// SSTs serving hot tables, within the same budget as the preload. A version switch with
+    /// heavy compaction churn evicts the meta of many input SSTs at once, and the first reads of
+    /// the outputs would otherwise pay cold meta fetches right after the checkpoint. Restricting
+    /// the refill to tables with recent accesses spends the budget on the SSTs that matter for
+    /// p99 latency.
+    fn refill_sst_meta_for_hot_tables(&self, mut sst_infos: Vec<SstableInfo>) {
+        /// Pacing between refill fetches, to keep the refill from competing with foreground
+        /// reads for object store bandwidth.
+        const REFILL_FETCH_INTERVAL: Duration = Duration::from_millis(10);
+
+        if self.sst_meta_preload_budget_bytes == 0 || sst_infos.is_empty() {
+            return;
+        }
+
+        let hot_table_ids = global_table_access_stats().hot_table_ids();
+        if hot_table_ids.is_empty() {
+            return;
+        }
+        let mut remaining_budget = self.sst_meta_preload_budget_bytes;
+        sst_infos.retain(|sst| {
+            if !sst
+                .table_ids
+                .iter()
+                .any(|table_id| hot_table_ids.contains(table_id))
+            {
+                return false;
+            }
+            let meta_size = sst.file_size.saturating_sub(sst.meta_offset);
+            if meta_size > remaining_budget {
+                return false;
+            }
+            remaining_budget -= meta_size;
+            true
+        });
+        if sst_infos.is_empty() {
+            return;
+        }
+
+        let sstable_store = self.sstable_store.clone();
+        spawn(async move {
+            let mut stats = StoreLocalStatistic::default();
+            for sst in &sst_infos {
+                tokio::time::sleep(REFILL_FETCH_INTERVAL).await;
+                // Fetching the meta is enough to populate the meta cache; SSTs still cached only
+                // cost a cache lookup here.
+                if let Err(e) = sstable_store.sstable(sst, &mut stats).await {
+                    info!("failed to refill meta of SST {}: {:?}", sst.id, e);
+                }
+            }
+        });
     }
 }
 
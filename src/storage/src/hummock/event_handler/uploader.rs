@@ -22,10 +22,12 @@ use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering::Relaxed;
 use std::sync::Arc;
 use std::task::{ready, Context, Poll};
+use std::time::{Duration, Instant};
 
 use futures::future::{try_join_all, TryJoinAll};
 use futures::FutureExt;
 use itertools::Itertools;
+use parking_lot::Mutex;
 use risingwave_common::catalog::TableId;
 use risingwave_hummock_sdk::{CompactionGroupId, HummockEpoch, LocalSstableInfo};
 use tokio::task::JoinHandle;
@@ -54,6 +56,47 @@ pub struct UploadTaskInfo {
     pub compaction_group_index: Arc<HashMap<TableId, CompactionGroupId>>,
 }
 
+/// Shapes the upload traffic to at most `max_bytes_per_sec`, so that syncing an epoch spreads its
+/// uploads over the checkpoint interval instead of bursting them at once and getting throttled by
+/// the object store.
+///
+/// It is a leaky bucket implemented with a single reservation timestamp: each task reserves a time
+/// slice proportional to its size starting from the end of the previous reservation, and sleeps
+/// until its slice begins.
+pub struct UploadRateLimiter {
+    max_bytes_per_sec: u64,
+    reserved_until: Mutex<Instant>,
+}
+
+impl UploadRateLimiter {
+    /// Create a rate limiter. `max_bytes_per_sec == 0` disables shaping.
+    pub fn new(max_bytes_per_sec: u64) -> Self {
+        Self {
+            max_bytes_per_sec,
+            reserved_until: Mutex::new(Instant::now()),
+        }
+    }
+
+    /// Wait until `bytes` are admitted under the configured rate. Returns immediately when shaping
+    /// is disabled.
+    pub async fn acquire(&self, bytes: u64) {
+        if self.max_bytes_per_sec == 0 {
+            return;
+        }
+        let start = self.reserve(bytes);
+        tokio::time::sleep_until(start.into()).await;
+    }
+
+    /// Reserve a time slice for `bytes` and return when it begins.
+    fn reserve(&self, bytes: u64) -> Instant {
+        let duration = Duration::from_secs_f64(bytes as f64 / self.max_bytes_per_sec as f64);
+        let mut reserved_until = self.reserved_until.lock();
+        let start = (*reserved_until).max(Instant::now());
+        *reserved_until = start + duration;
+        start
+    }
+}
+
 /// A wrapper for a uploading task that compacts and uploads the imm payload. Task context are
 /// stored so that when the task fails, it can be re-tried.
 struct UploadingTask {
@@ -704,6 +747,7 @@ mod tests {
     use std::sync::atomic::Ordering::SeqCst;
     use std::sync::Arc;
     use std::task::Poll;
+    use std::time::Duration;
 
     use bytes::Bytes;
     use futures::future::BoxFuture;
@@ -720,8 +764,8 @@ mod tests {
 
     use crate::hummock::event_handler::hummock_event_handler::BufferTracker;
     use crate::hummock::event_handler::uploader::{
-        HummockUploader, UploadTaskInfo, UploadTaskOutput, UploadTaskPayload, UploaderContext,
-        UploaderEvent, UploadingTask,
+        HummockUploader, UploadRateLimiter, UploadTaskInfo, UploadTaskOutput, UploadTaskPayload,
+        UploaderContext, UploaderEvent, UploadingTask,
     };
     use crate::hummock::local_version::pinned_version::PinnedVersion;
     use crate::hummock::shared_buffer::shared_buffer_batch::SharedBufferBatch;
@@ -743,6 +787,7 @@ mod tests {
             levels: Default::default(),
             max_committed_epoch: epoch,
             safe_epoch: 0,
+            domain_committed_epochs: Default::default(),
         }
     }
 
@@ -848,6 +893,17 @@ mod tests {
         Err(HummockError::other("failed"))
     }
 
+    #[test]
+    fn test_upload_rate_limiter_reserve() {
+        let limiter = UploadRateLimiter::new(1 << 20); // 1 MiB/s
+        let first = limiter.reserve(512 << 10);
+        // The second task starts only after the first one's time slice ends.
+        let second = limiter.reserve(512 << 10);
+        assert_eq!(second - first, Duration::from_millis(500));
+        let third = limiter.reserve(1 << 20);
+        assert_eq!(third - second, Duration::from_millis(500));
+    }
+
     #[tokio::test]
     pub async fn test_uploading_task_future() {
         let uploader_context = test_uploader_context(dummy_success_upload_future);
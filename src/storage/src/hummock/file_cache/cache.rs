@@ -35,6 +35,9 @@ pub struct FileCacheOptions {
     pub cache_file_fallocate_unit: usize,
     pub cache_meta_fallocate_unit: usize,
     pub cache_file_max_write_size: usize,
+    /// Whether to open the cache file with `O_DIRECT`, with fallback to buffered I/O if the file
+    /// system doesn't support it.
+    pub enable_direct_io: bool,
 
     pub flush_buffer_hooks: Vec<Arc<dyn FlushBufferHook>>,
 }
@@ -190,6 +193,7 @@ where
             cache_file_fallocate_unit: options.cache_file_fallocate_unit,
             cache_meta_fallocate_unit: options.cache_meta_fallocate_unit,
             cache_file_max_write_size: options.cache_file_max_write_size,
+            enable_direct_io: options.enable_direct_io,
             metrics: metrics.clone(),
         })
         .await?;
@@ -353,6 +357,7 @@ mod tests {
             cache_file_fallocate_unit: FALLOCATE_UNIT,
             cache_meta_fallocate_unit: 1024 * 1024, // 1 MiB
             cache_file_max_write_size: 4 * 1024 * 1024, // 4 MiB
+            enable_direct_io: true,
 
             flush_buffer_hooks,
         };
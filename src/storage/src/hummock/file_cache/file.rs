@@ -31,6 +31,9 @@ pub struct CacheFileOptions {
     /// NOTE: `block_size` must be a multiple of `fs_block_size`.
     pub block_size: usize,
     pub fallocate_unit: usize,
+    /// Whether to open the cache file with `O_DIRECT` to bypass the page cache. Falls back to
+    /// buffered I/O if the file system doesn't support `O_DIRECT`.
+    pub enable_direct_io: bool,
 }
 
 impl CacheFileOptions {
@@ -74,24 +77,43 @@ impl std::fmt::Debug for CacheFile {
 impl CacheFile {
     /// Opens the cache file.
     ///
-    /// The underlying file is opened with `O_DIRECT` flag. All I/O requests must be aligned with
-    /// the logical block size. Additionally, [`CacheFile`] requires I/O size must be a multiple of
-    /// `options.block_size` (which is required to be a multiple of the file system block size).
-    /// With this restriction, blocks can be directly reclaimed by the file system after hole
-    /// punching.
+    /// The underlying file is opened with `O_DIRECT` flag by default to bypass the page cache, and
+    /// falls back to buffered I/O if the file system doesn't support `O_DIRECT` (e.g. tmpfs). All
+    /// I/O requests must be aligned with the logical block size. Additionally, [`CacheFile`]
+    /// requires I/O size must be a multiple of `options.block_size` (which is required to be a
+    /// multiple of the file system block size). With this restriction, blocks can be directly
+    /// reclaimed by the file system after hole punching.
     pub async fn open(path: impl AsRef<Path>, options: CacheFileOptions) -> Result<Self> {
         options.assert();
 
         let path = path.as_ref().to_owned();
+        let enable_direct_io = options.enable_direct_io;
 
         let mut oopts = OpenOptions::new();
         oopts.create(true);
         oopts.read(true);
         oopts.write(true);
-        oopts.custom_flags(libc::O_DIRECT | libc::O_NOATIME);
+        oopts.custom_flags(libc::O_NOATIME);
 
         let (file, len, capacity) = asyncify(move || {
-            let file = oopts.open(path)?;
+            let file = if enable_direct_io {
+                let mut dopts = oopts.clone();
+                dopts.custom_flags(libc::O_DIRECT | libc::O_NOATIME);
+                match dopts.open(&path) {
+                    Ok(file) => file,
+                    // The file system doesn't support `O_DIRECT`, fall back to buffered I/O.
+                    Err(e) if e.raw_os_error() == Some(libc::EINVAL) => {
+                        tracing::warn!(
+                            "file system of {:?} doesn't support O_DIRECT, fall back to buffered I/O",
+                            path
+                        );
+                        oopts.open(&path)?
+                    }
+                    Err(e) => return Err(e.into()),
+                }
+            } else {
+                oopts.open(&path)?
+            };
             let fd = file.as_raw_fd();
             let stat = fstat(fd)?;
             fallocate(
@@ -289,6 +311,7 @@ mod tests {
         let options = CacheFileOptions {
             block_size: 4096,
             fallocate_unit: 4 * 4096,
+            enable_direct_io: true,
         };
         let cf = CacheFile::open(&path, options.clone()).await.unwrap();
         assert_eq!(cf.block_size(), 4096);
@@ -215,6 +215,7 @@ pub struct StoreOptions {
     pub cache_file_fallocate_unit: usize,
     pub cache_meta_fallocate_unit: usize,
     pub cache_file_max_write_size: usize,
+    pub enable_direct_io: bool,
 
     pub metrics: FileCacheMetricsRef,
 }
@@ -271,6 +272,7 @@ where
             // TODO: Make it configurable.
             block_size: fs_block_size,
             fallocate_unit: options.cache_file_fallocate_unit,
+            enable_direct_io: options.enable_direct_io,
         };
 
         let mf = MetaFile::open(
@@ -156,6 +156,10 @@ impl HummockMetaClient for MonitoredHummockMetaClient {
             .await
     }
 
+    async fn report_corrupted_ssts(&self, sst_ids: Vec<HummockSstableId>) -> Result<()> {
+        self.meta_client.report_corrupted_ssts(sst_ids).await
+    }
+
     async fn report_full_scan_task(&self, sst_ids: Vec<HummockSstableId>) -> Result<()> {
         self.meta_client.report_full_scan_task(sst_ids).await
     }
@@ -166,6 +170,10 @@ impl HummockMetaClient for MonitoredHummockMetaClient {
             .await
     }
 
+    async fn get_dropped_table_ids(&self, table_ids: Vec<u32>) -> Result<Vec<u32>> {
+        self.meta_client.get_dropped_table_ids(table_ids).await
+    }
+
     async fn update_current_epoch(&self, epoch: HummockEpoch) -> Result<()> {
         self.meta_client.update_current_epoch(epoch).await
     }
@@ -14,6 +14,7 @@
 
 use std::collections::HashMap;
 use std::ops::RangeBounds;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 
 use bytes::Bytes;
@@ -52,6 +53,9 @@ pub struct LocalVersionManager {
     buffer_tracker: BufferTracker,
     shared_buffer_uploader: Arc<SharedBufferUploader>,
     sstable_id_manager: SstableIdManagerRef,
+    /// The largest fence token observed in applied version deltas. A version delta carrying a
+    /// smaller token comes from a deposed meta leader and must not be applied.
+    max_fence_token: AtomicU64,
 }
 
 impl LocalVersionManager {
@@ -68,6 +72,7 @@ impl LocalVersionManager {
             buffer_tracker,
             shared_buffer_uploader: Arc::new(SharedBufferUploader::new(compactor_context)),
             sstable_id_manager,
+            max_fence_token: AtomicU64::new(0),
         })
     }
 
@@ -101,6 +106,17 @@ impl LocalVersionManager {
                 let mut version_to_apply = old_version.pinned_version().version();
                 for version_delta in &version_deltas.version_deltas {
                     assert_eq!(version_to_apply.id, version_delta.prev_id);
+                    // Reject in-flight commits from a deposed meta leader.
+                    let max_fence_token = self
+                        .max_fence_token
+                        .fetch_max(version_delta.fence_token, Ordering::Relaxed);
+                    assert!(
+                        version_delta.fence_token >= max_fence_token,
+                        "fencing violation: version delta {} carries fence token {} but token {} has been observed",
+                        version_delta.id,
+                        version_delta.fence_token,
+                        max_fence_token
+                    );
                     version_to_apply.apply_version_delta(version_delta);
                 }
                 version_to_apply
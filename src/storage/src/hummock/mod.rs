@@ -29,7 +29,6 @@ use risingwave_pb::hummock::HummockVersion;
 use risingwave_pb::hummock::{version_update_payload, SstableInfo};
 use risingwave_rpc_client::HummockMetaClient;
 use tokio::sync::mpsc::{unbounded_channel, UnboundedSender};
-use tokio::sync::watch;
 use tracing::log::error;
 
 mod block_cache;
@@ -55,11 +54,10 @@ pub mod iterator;
 pub mod shared_buffer;
 pub mod sstable_store;
 mod state_store;
-mod state_store_v1;
 #[cfg(any(test, feature = "test"))]
 pub mod test_utils;
 pub mod utils;
-pub use utils::MemoryLimiter;
+pub use utils::{IoLimiter, MemoryLimiter};
 pub mod backup_reader;
 pub mod event_handler;
 pub mod local_version;
@@ -70,7 +68,6 @@ mod validator;
 pub mod value;
 
 pub use error::*;
-use local_version::local_version_manager::{LocalVersionManager, LocalVersionManagerRef};
 pub use risingwave_common::cache::{CacheableEntry, LookupResult, LruCache};
 use risingwave_common_service::observer_manager::{NotificationClient, ObserverManager};
 use risingwave_hummock_sdk::filter_key_extractor::{
@@ -80,27 +77,25 @@ pub use validator::*;
 use value::*;
 
 use self::event_handler::ReadVersionMappingType;
-use self::iterator::{BackwardUserIterator, HummockIterator, UserIterator};
+use self::iterator::{HummockIterator, UserIterator};
 pub use self::sstable_store::*;
 use super::monitor::HummockStateStoreMetrics;
-use crate::error::StorageResult;
 use crate::hummock::backup_reader::{BackupReader, BackupReaderRef};
 use crate::hummock::compactor::CompactorContext;
 use crate::hummock::event_handler::hummock_event_handler::BufferTracker;
 use crate::hummock::event_handler::{HummockEvent, HummockEventHandler};
 use crate::hummock::iterator::{
-    Backward, BackwardUserIteratorType, DirectedUserIteratorBuilder, DirectionEnum, Forward,
-    ForwardUserIteratorType, HummockIteratorDirection,
+    DirectedUserIteratorBuilder, DirectionEnum, Forward, ForwardUserIteratorType,
+    HummockIteratorDirection,
 };
 use crate::hummock::local_version::pinned_version::{start_pinned_version_worker, PinnedVersion};
 use crate::hummock::observer_manager::HummockObserverNode;
 use crate::hummock::shared_buffer::shared_buffer_batch::SharedBufferBatch;
-use crate::hummock::shared_buffer::{OrderSortedUncommittedData, UncommittedData};
 use crate::hummock::sstable::SstableIteratorReadOptions;
 use crate::hummock::sstable_store::{SstableStoreRef, TableHolder};
 use crate::hummock::store::version::HummockVersionReader;
 use crate::monitor::{CompactorMetrics, StoreLocalStatistic};
-use crate::store::{gen_min_epoch, ReadOptions};
+use crate::store::{gen_min_epoch, PrefixHint, ReadOptions};
 
 struct HummockStorageShutdownGuard {
     shutdown_sender: UnboundedSender<HummockEvent>,
@@ -216,6 +211,7 @@ impl HummockStorage {
             pinned_version: hummock_event_handler.pinned_version(),
             hummock_version_reader: HummockVersionReader::new(
                 sstable_store,
+                filter_key_extractor_manager,
                 state_store_metrics.clone(),
             ),
             _shutdown_guard: Arc::new(HummockStorageShutdownGuard {
@@ -249,6 +245,7 @@ impl HummockStorage {
             self.hummock_event_sender.clone(),
             self.buffer_tracker.get_memory_limiter().clone(),
             self.tracing.clone(),
+            self.context.storage_opts.write_batch_dedup_enabled,
         )
     }
 
@@ -350,7 +347,6 @@ pub async fn get_from_sstable_info(
     sstable_info: &SstableInfo,
     full_key: FullKey<&[u8]>,
     read_options: &ReadOptions,
-    dist_key_hash: Option<u32>,
     local_stats: &mut StoreLocalStatistic,
 ) -> HummockResult<Option<HummockValue<Bytes>>> {
     let sstable = sstable_store_ref.sstable(sstable_info, local_stats).await?;
@@ -362,9 +358,14 @@ pub async fn get_from_sstable_info(
         get_delete_range_epoch_from_sstable(sstable.value().as_ref(), &full_key)
     };
 
-    // Bloom filter key is the distribution key, which is no need to be the prefix of pk, and do not
-    // contain `TablePrefix` and `VnodePrefix`.
-    if let Some(hash) = dist_key_hash && !hit_sstable_bloom_filter(sstable.value(), hash, local_stats) {
+    if let Some(prefix_hint) = &read_options.prefix_hint
+        && !hit_sstable_bloom_filter(
+            sstable.value(),
+            prefix_hint,
+            read_options.table_id.table_id(),
+            local_stats,
+        )
+    {
         if delete_epoch.is_some() {
             return Ok(Some(HummockValue::Delete));
         }
@@ -377,7 +378,10 @@ pub async fn get_from_sstable_info(
     let mut iter = SstableIterator::create(
         sstable,
         sstable_store_ref.clone(),
-        Arc::new(SstableIteratorReadOptions::default()),
+        Arc::new(SstableIteratorReadOptions {
+            filter_table_id: Some(read_options.table_id.table_id()),
+            ..Default::default()
+        }),
     );
     iter.seek(full_key).await?;
     // Iterator has sought passed the borders.
@@ -411,14 +415,25 @@ pub async fn get_from_sstable_info(
     Ok(value)
 }
 
+/// Checks the bloom filter of the SST against `prefix_hint`. The hint is truncated to the
+/// bloom filter prefix length recorded in the SST, so that the filter key matches what the
+/// writer hashed. Returns `true` (may contain) if the hint is too short to compute a
+/// consistent filter key.
 pub fn hit_sstable_bloom_filter(
     sstable_info_ref: &Sstable,
-    prefix_hash: u32,
+    prefix_hint: &PrefixHint,
+    table_id: u32,
     local_stats: &mut StoreLocalStatistic,
 ) -> bool {
+    let Some(filter_key) =
+        prefix_hint.filter_key_for(sstable_info_ref.bloom_filter_prefix_columns(table_id))
+    else {
+        return true;
+    };
     local_stats.bloom_filter_check_counts += 1;
 
-    let surely_not_have = sstable_info_ref.surely_not_have_hashvalue(prefix_hash);
+    let surely_not_have = sstable_info_ref
+        .surely_not_have_hashvalue(Sstable::hash_for_bloom_filter(filter_key, table_id));
 
     if surely_not_have {
         local_stats.bloom_filter_true_negative_counts += 1;
@@ -426,61 +441,6 @@ pub fn hit_sstable_bloom_filter(
     !surely_not_have
 }
 
-/// Get `user_value` from `OrderSortedUncommittedData`. If not get successful, return None.
-pub async fn get_from_order_sorted_uncommitted_data(
-    sstable_store_ref: SstableStoreRef,
-    order_sorted_uncommitted_data: OrderSortedUncommittedData,
-    full_key: FullKey<&[u8]>,
-    local_stats: &mut StoreLocalStatistic,
-    read_options: &ReadOptions,
-) -> StorageResult<(Option<HummockValue<Bytes>>, i32)> {
-    let mut table_counts = 0;
-    let epoch = full_key.epoch;
-    let dist_key_hash = read_options.prefix_hint.as_ref().map(|dist_key| {
-        Sstable::hash_for_bloom_filter(dist_key.as_ref(), read_options.table_id.table_id())
-    });
-
-    let min_epoch = gen_min_epoch(epoch, read_options.retention_seconds.as_ref());
-
-    for data_list in order_sorted_uncommitted_data {
-        for data in data_list {
-            match data {
-                UncommittedData::Batch(batch) => {
-                    assert!(batch.epoch() <= epoch, "batch'epoch greater than epoch");
-
-                    if batch.epoch() <= min_epoch {
-                        continue;
-                    }
-
-                    if let Some(data) =
-                        get_from_batch(&batch, full_key.user_key.table_key, local_stats)
-                    {
-                        return Ok((Some(data), table_counts));
-                    }
-                }
-
-                UncommittedData::Sst(LocalSstableInfo { sst_info, .. }) => {
-                    table_counts += 1;
-
-                    if let Some(data) = get_from_sstable_info(
-                        sstable_store_ref.clone(),
-                        &sst_info,
-                        full_key,
-                        read_options,
-                        dist_key_hash,
-                        local_stats,
-                    )
-                    .await?
-                    {
-                        return Ok((Some(data), table_counts));
-                    }
-                }
-            }
-        }
-    }
-    Ok((None, table_counts))
-}
-
 /// Get `user_value` from `SharedBufferBatch`
 pub fn get_from_batch(
     batch: &SharedBufferBatch,
@@ -496,163 +456,6 @@ pub fn get_from_batch(
     })
 }
 
-#[derive(Clone)]
-pub struct HummockStorageV1 {
-    options: Arc<StorageOpts>,
-
-    local_version_manager: LocalVersionManagerRef,
-
-    sstable_store: SstableStoreRef,
-
-    /// Statistics
-    state_store_metrics: Arc<HummockStateStoreMetrics>,
-
-    sstable_id_manager: SstableIdManagerRef,
-
-    filter_key_extractor_manager: FilterKeyExtractorManagerRef,
-
-    hummock_event_sender: UnboundedSender<HummockEvent>,
-
-    _shutdown_guard: Arc<HummockStorageShutdownGuard>,
-
-    version_update_notifier_tx: Arc<tokio::sync::watch::Sender<HummockEpoch>>,
-
-    tracing: Arc<risingwave_tracing::RwTracingService>,
-}
-
-impl HummockStorageV1 {
-    /// Creates a [`HummockStorageV1`].
-    pub async fn new(
-        options: Arc<StorageOpts>,
-        sstable_store: SstableStoreRef,
-        hummock_meta_client: Arc<dyn HummockMetaClient>,
-        notification_client: impl NotificationClient,
-        // TODO: separate `HummockStats` from `HummockStateStoreMetrics`.
-        state_store_metrics: Arc<HummockStateStoreMetrics>,
-        tracing: Arc<risingwave_tracing::RwTracingService>,
-        compactor_metrics: Arc<CompactorMetrics>,
-    ) -> HummockResult<Self> {
-        // For conflict key detection. Enabled by setting `write_conflict_detection_enabled` to
-        // true in `StorageConfig`
-        let sstable_id_manager = Arc::new(SstableIdManager::new(
-            hummock_meta_client.clone(),
-            options.sstable_id_remote_fetch_number,
-        ));
-
-        let filter_key_extractor_manager = Arc::new(FilterKeyExtractorManager::default());
-        let (event_tx, mut event_rx) = unbounded_channel();
-        let backup_manager = BackupReader::unused();
-        let observer_manager = ObserverManager::new(
-            notification_client,
-            HummockObserverNode::new(
-                filter_key_extractor_manager.clone(),
-                backup_manager,
-                event_tx.clone(),
-            ),
-        )
-        .await;
-        observer_manager.start().await;
-
-        let hummock_version = match event_rx.recv().await {
-            Some(HummockEvent::VersionUpdate(version_update_payload::Payload::PinnedVersion(version))) => version,
-            _ => unreachable!("the hummock observer manager is the first one to take the event tx. Should be full hummock version")
-        };
-
-        let (pin_version_tx, pin_version_rx) = unbounded_channel();
-        let pinned_version = PinnedVersion::new(hummock_version, pin_version_tx);
-        tokio::spawn(start_pinned_version_worker(
-            pin_version_rx,
-            hummock_meta_client.clone(),
-        ));
-
-        let compactor_context = Arc::new(CompactorContext::new_local_compact_context(
-            options.clone(),
-            sstable_store.clone(),
-            hummock_meta_client.clone(),
-            compactor_metrics.clone(),
-            sstable_id_manager.clone(),
-            filter_key_extractor_manager.clone(),
-            CompactorRuntimeConfig::default(),
-        ));
-
-        let buffer_tracker = BufferTracker::from_storage_opts(&options);
-
-        let local_version_manager =
-            LocalVersionManager::new(pinned_version.clone(), compactor_context, buffer_tracker);
-
-        let local_version_manager_clone = local_version_manager.clone();
-        let (epoch_update_tx, _) = watch::channel(pinned_version.max_committed_epoch());
-        let epoch_update_tx = Arc::new(epoch_update_tx);
-        let epoch_update_tx_clone = epoch_update_tx.clone();
-
-        tokio::spawn(async move {
-            while let Some(event) = event_rx.recv().await {
-                match event {
-                    HummockEvent::Shutdown => {
-                        break;
-                    }
-                    HummockEvent::VersionUpdate(version_update) => {
-                        local_version_manager_clone.try_update_pinned_version(version_update);
-                        // TODO: this is
-                        epoch_update_tx.send_replace(
-                            local_version_manager_clone
-                                .get_pinned_version()
-                                .max_committed_epoch(),
-                        );
-                    }
-                    _ => {
-                        unreachable!("for hummock v1, there should only be shutdown and version update event");
-                    }
-                }
-            }
-        });
-
-        let instance = Self {
-            options,
-            local_version_manager,
-            sstable_store,
-            state_store_metrics,
-            sstable_id_manager,
-            filter_key_extractor_manager,
-            _shutdown_guard: Arc::new(HummockStorageShutdownGuard {
-                shutdown_sender: event_tx.clone(),
-            }),
-            version_update_notifier_tx: epoch_update_tx_clone,
-            hummock_event_sender: event_tx,
-            tracing,
-        };
-
-        Ok(instance)
-    }
-
-    pub fn options(&self) -> &Arc<StorageOpts> {
-        &self.options
-    }
-
-    pub fn sstable_store(&self) -> SstableStoreRef {
-        self.sstable_store.clone()
-    }
-
-    pub fn sstable_id_manager(&self) -> &SstableIdManagerRef {
-        &self.sstable_id_manager
-    }
-
-    pub fn filter_key_extractor_manager(&self) -> &FilterKeyExtractorManagerRef {
-        &self.filter_key_extractor_manager
-    }
-
-    pub fn get_memory_limiter(&self) -> Arc<MemoryLimiter> {
-        self.local_version_manager
-            .buffer_tracker()
-            .get_memory_limiter()
-            .clone()
-    }
-
-    pub fn get_pinned_version(&self) -> PinnedVersion {
-        self.local_version_manager.get_pinned_version()
-    }
-}
-
 pub(crate) trait HummockIteratorType: 'static {
     type Direction: HummockIteratorDirection;
     type SstableIteratorType: SstableIteratorType<Direction = Self::Direction>;
@@ -667,16 +470,9 @@ pub(crate) trait HummockIteratorType: 'static {
 }
 
 pub(crate) struct ForwardIter;
-pub(crate) struct BackwardIter;
 
 impl HummockIteratorType for ForwardIter {
     type Direction = Forward;
     type SstableIteratorType = SstableIterator;
     type UserIteratorBuilder = UserIterator<ForwardUserIteratorType>;
 }
-
-impl HummockIteratorType for BackwardIter {
-    type Direction = Backward;
-    type SstableIteratorType = BackwardSstableIterator;
-    type UserIteratorBuilder = BackwardUserIterator<BackwardUserIteratorType>;
-}
@@ -281,6 +281,27 @@ impl SharedBufferBatch {
             .collect()
     }
 
+    /// Like [`Self::build_shared_buffer_item_batches`], but deduplicates writes to the same key
+    /// by keeping the last value. Returns the items and the number of dropped entries, so that
+    /// callers can report the dedup ratio.
+    pub fn build_shared_buffer_item_batches_dedup(
+        kv_pairs: Vec<(Bytes, StorageValue)>,
+    ) -> (Vec<SharedBufferItem>, usize) {
+        let input_count = kv_pairs.len();
+        let mut items = Self::build_shared_buffer_item_batches(kv_pairs);
+        // The items are already sorted by key with duplicates in write order, so within a run of
+        // equal keys the last one wins. `dedup_by` keeps the first item of a run, so swap the
+        // later value into the kept slot before dropping it.
+        items.dedup_by(|later, earlier| {
+            later.0 == earlier.0 && {
+                std::mem::swap(later, earlier);
+                true
+            }
+        });
+        let dropped_count = input_count - items.len();
+        (items, dropped_count)
+    }
+
     pub fn build_shared_buffer_batch(
         epoch: HummockEpoch,
         sorted_items: Vec<SharedBufferItem>,
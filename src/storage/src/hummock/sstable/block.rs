@@ -40,6 +40,16 @@ pub struct Block {
 
 impl Block {
     pub fn decode(buf: Bytes, uncompressed_capacity: usize) -> HummockResult<Self> {
+        Self::decode_with_dict(buf, uncompressed_capacity, &[])
+    }
+
+    /// Decodes a block, decompressing [`CompressionAlgorithm::ZstdDict`] blocks with the zstd
+    /// dictionary embedded in the SST meta. `dict` may be empty for SSTs without a dictionary.
+    pub fn decode_with_dict(
+        buf: Bytes,
+        uncompressed_capacity: usize,
+        dict: &[u8],
+    ) -> HummockResult<Self> {
         // Verify checksum.
         let xxhash64_checksum = (&buf[buf.len() - 8..]).get_u64_le();
         xxhash64_verify(&buf[..buf.len() - 8], xxhash64_checksum)?;
@@ -69,6 +79,21 @@ impl Block {
                 debug_assert_eq!(decoded.capacity(), uncompressed_capacity);
                 Bytes::from(decoded)
             }
+            CompressionAlgorithm::ZstdDict => {
+                if dict.is_empty() {
+                    return Err(HummockError::decode_error(
+                        "block is compressed with a zstd dictionary but none is available",
+                    ));
+                }
+                let mut decoder = zstd::Decoder::with_dictionary(compressed_data.reader(), dict)
+                    .map_err(HummockError::decode_error)?;
+                let mut decoded = Vec::with_capacity(uncompressed_capacity);
+                decoder
+                    .read_to_end(&mut decoded)
+                    .map_err(HummockError::decode_error)?;
+                debug_assert_eq!(decoded.capacity(), uncompressed_capacity);
+                Bytes::from(decoded)
+            }
         };
 
         Ok(Self::decode_from_raw(buf))
@@ -291,6 +316,11 @@ impl BlockBuilder {
         &self.last_key
     }
 
+    /// Uncompressed entry data added so far, used as a sample for zstd dictionary training.
+    pub fn raw_entries(&self) -> &[u8] {
+        &self.buf
+    }
+
     pub fn is_empty(&self) -> bool {
         self.buf.is_empty()
     }
@@ -320,11 +350,19 @@ impl BlockBuilder {
     ///
     /// Panic if there is compression error.
     pub fn build(&mut self) -> &[u8] {
+        self.build_with_dict(&[])
+    }
+
+    /// Finishes building block, compressing with the given zstd dictionary if one is provided
+    /// and the block is configured for zstd compression. Blocks compressed with a dictionary
+    /// are tagged [`CompressionAlgorithm::ZstdDict`] so that mixed SSTs decode correctly.
+    pub fn build_with_dict(&mut self, dict: &[u8]) -> &[u8] {
         assert!(self.entry_count > 0);
         for restart_point in &self.restart_points {
             self.buf.put_u32_le(*restart_point);
         }
         self.buf.put_u32_le(self.restart_points.len() as u32);
+        let mut compression_algorithm = self.compression_algorithm;
         match self.compression_algorithm {
             CompressionAlgorithm::None => (),
             CompressionAlgorithm::Lz4 => {
@@ -342,10 +380,15 @@ impl BlockBuilder {
                 self.buf = writer.into_inner();
             }
             CompressionAlgorithm::Zstd => {
-                let mut encoder =
-                    zstd::Encoder::new(BytesMut::with_capacity(self.buf.len()).writer(), 4)
-                        .map_err(HummockError::encode_error)
-                        .unwrap();
+                let buf_writer = BytesMut::with_capacity(self.buf.len()).writer();
+                let mut encoder = if dict.is_empty() {
+                    zstd::Encoder::new(buf_writer, 4)
+                } else {
+                    compression_algorithm = CompressionAlgorithm::ZstdDict;
+                    zstd::Encoder::with_dictionary(buf_writer, 4, dict)
+                }
+                .map_err(HummockError::encode_error)
+                .unwrap();
                 encoder
                     .write_all(&self.buf[..])
                     .map_err(HummockError::encode_error)
@@ -356,8 +399,11 @@ impl BlockBuilder {
                     .unwrap();
                 self.buf = writer.into_inner();
             }
+            CompressionAlgorithm::ZstdDict => {
+                unreachable!("builders are configured with CompressionAlgorithm::Zstd")
+            }
         };
-        self.compression_algorithm.encode(&mut self.buf);
+        compression_algorithm.encode(&mut self.buf);
         let checksum = xxhash64_checksum(&self.buf);
         self.buf.put_u64_le(checksum);
         self.buf.as_ref()
@@ -459,6 +505,37 @@ mod tests {
         assert!(!bi.is_valid());
     }
 
+    #[test]
+    fn test_block_enc_dec_with_dict() {
+        let options = BlockBuilderOptions {
+            compression_algorithm: CompressionAlgorithm::Zstd,
+            ..Default::default()
+        };
+        let mut builder = BlockBuilder::new(options);
+        builder.add(&full_key(b"k1", 1), b"v01");
+        builder.add(&full_key(b"k2", 2), b"v02");
+        let samples = vec![builder.raw_entries().to_vec(); 512];
+        let dict = zstd::dict::from_samples(&samples, 1024).unwrap();
+        let capacity = builder.uncompressed_block_size();
+        let buf = builder.build_with_dict(&dict).to_vec();
+
+        // The dictionary tag is encoded into the block, so decoding without it must fail.
+        assert!(Block::decode(Bytes::from(buf.clone()), capacity).is_err());
+
+        let block = Box::new(Block::decode_with_dict(buf.into(), capacity, &dict).unwrap());
+        let mut bi = BlockIterator::new(BlockHolder::from_owned_block(block));
+        bi.seek_to_first();
+        assert!(bi.is_valid());
+        assert_eq!(&full_key(b"k1", 1)[..], bi.key());
+        assert_eq!(b"v01", bi.value());
+        bi.next();
+        assert!(bi.is_valid());
+        assert_eq!(&full_key(b"k2", 2)[..], bi.key());
+        assert_eq!(b"v02", bi.value());
+        bi.next();
+        assert!(!bi.is_valid());
+    }
+
     pub fn full_key(user_key: &[u8], epoch: u64) -> Bytes {
         let mut buf = BytesMut::with_capacity(user_key.len() + 8);
         buf.put_slice(user_key);
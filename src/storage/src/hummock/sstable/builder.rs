@@ -15,7 +15,7 @@
 use std::collections::BTreeSet;
 use std::sync::Arc;
 
-use bytes::BytesMut;
+use bytes::{Bytes, BytesMut};
 use risingwave_common::catalog::TableId;
 use risingwave_hummock_sdk::filter_key_extractor::{
     FilterKeyExtractorImpl, FullKeyFilterKeyExtractor,
@@ -37,6 +37,9 @@ use crate::opts::StorageOpts;
 
 pub const DEFAULT_SSTABLE_SIZE: usize = 4 * 1024 * 1024;
 pub const DEFAULT_BLOOM_FALSE_POSITIVE: f64 = 0.001;
+/// Ratio of sampled block payload bytes to the dictionary capacity collected before training a
+/// zstd dictionary, following the zstd recommendation of roughly 100x the dictionary size.
+const ZSTD_DICT_SAMPLE_FACTOR: usize = 100;
 #[derive(Clone, Debug)]
 pub struct SstableBuilderOptions {
     /// Approximate sstable capacity.
@@ -49,6 +52,9 @@ pub struct SstableBuilderOptions {
     pub bloom_false_positive: f64,
     /// Compression algorithm.
     pub compression_algorithm: CompressionAlgorithm,
+    /// Capacity of the zstd dictionary trained and embedded per SST. 0 disables dictionary
+    /// compression. Only effective with [`CompressionAlgorithm::Zstd`].
+    pub zstd_dict_capacity: usize,
 }
 
 impl From<&StorageOpts> for SstableBuilderOptions {
@@ -60,6 +66,7 @@ impl From<&StorageOpts> for SstableBuilderOptions {
             restart_interval: DEFAULT_RESTART_INTERVAL,
             bloom_false_positive: options.bloom_false_positive,
             compression_algorithm: CompressionAlgorithm::None,
+            zstd_dict_capacity: (options.sstable_zstd_dict_size_kb as usize) * (1 << 10),
         }
     }
 }
@@ -72,6 +79,7 @@ impl Default for SstableBuilderOptions {
             restart_interval: DEFAULT_RESTART_INTERVAL,
             bloom_false_positive: DEFAULT_BLOOM_FALSE_POSITIVE,
             compression_algorithm: CompressionAlgorithm::None,
+            zstd_dict_capacity: 0,
         }
     }
 }
@@ -98,6 +106,8 @@ pub struct SstableBuilder<W: SstableWriter> {
     range_tombstones: Vec<DeleteRangeTombstone>,
     /// `table_id` of added keys.
     table_ids: BTreeSet<u32>,
+    /// The index of the first block containing each table's keys, in ascending table id order.
+    table_block_offsets: Vec<(u32, u32)>,
     /// Hashes of user keys.
     user_key_hashes: Vec<u32>,
     last_full_key: Vec<u8>,
@@ -117,6 +127,11 @@ pub struct SstableBuilder<W: SstableWriter> {
     /// `last_table_stats` accumulates stats for `last_table_id` and finalizes it in `table_stats`
     /// by `finalize_last_table_stats`
     last_table_stats: TableStats,
+    /// Samples of uncompressed block payloads for zstd dictionary training. `None` once the
+    /// dictionary has been trained or if dictionary compression is disabled.
+    zstd_dict_samples: Option<Vec<Vec<u8>>>,
+    /// The zstd dictionary trained for this SST, if any.
+    zstd_dict: Option<Vec<u8>>,
 }
 
 impl<W: SstableWriter> SstableBuilder<W> {
@@ -147,6 +162,7 @@ impl<W: SstableWriter> SstableBuilder<W> {
             }),
             block_metas: Vec::with_capacity(options.capacity / options.block_capacity + 1),
             table_ids: BTreeSet::new(),
+            table_block_offsets: vec![],
             user_key_hashes: Vec::with_capacity(options.capacity / DEFAULT_ENTRY_SIZE + 1),
             last_table_id: None,
             raw_key: BytesMut::new(),
@@ -160,6 +176,14 @@ impl<W: SstableWriter> SstableBuilder<W> {
             total_key_count: 0,
             table_stats: Default::default(),
             last_table_stats: Default::default(),
+            zstd_dict_samples: if options.zstd_dict_capacity > 0
+                && options.compression_algorithm == CompressionAlgorithm::Zstd
+            {
+                Some(vec![])
+            } else {
+                None
+            },
+            zstd_dict: None,
         }
     }
 
@@ -200,6 +224,8 @@ impl<W: SstableWriter> SstableBuilder<W> {
             let table_id = full_key.user_key.table_id.table_id();
             if self.last_table_id.is_none() || self.last_table_id.unwrap() != table_id {
                 self.table_ids.insert(table_id);
+                self.table_block_offsets
+                    .push((table_id, self.block_metas.len() as u32 - 1));
                 self.finalize_last_table_stats();
                 self.last_table_id = Some(table_id);
                 self.last_extract_key.clear();
@@ -294,6 +320,16 @@ impl<W: SstableWriter> SstableBuilder<W> {
         self.total_key_count += self.range_tombstones.len() as u64;
         self.stale_key_count += self.range_tombstones.len() as u64;
 
+        // `table_ids` is ordered, so the record can be binary-searched by readers.
+        let bloom_filter_prefix_columns = self
+            .table_ids
+            .iter()
+            .filter_map(|table_id| {
+                self.filter_key_extractor
+                    .bloom_filter_prefix_columns(*table_id)
+                    .map(|prefix_columns| (*table_id, prefix_columns as u32))
+            })
+            .collect();
         let mut meta = SstableMeta {
             block_metas: self.block_metas,
             bloom_filter: if self.options.bloom_false_positive > 0.0 {
@@ -312,6 +348,9 @@ impl<W: SstableWriter> SstableBuilder<W> {
             version: VERSION,
             meta_offset,
             range_tombstone_list: self.range_tombstones,
+            table_block_offsets: self.table_block_offsets,
+            zstd_dict: self.zstd_dict.unwrap_or_default(),
+            bloom_filter_prefix_columns,
         };
         meta.estimated_size = meta.encoded_size() as u32 + meta_offset as u32;
         let sst_info = SstableInfo {
@@ -374,9 +413,34 @@ impl<W: SstableWriter> SstableBuilder<W> {
             return Ok(());
         }
 
+        if let Some(samples) = &mut self.zstd_dict_samples {
+            samples.push(self.block_builder.raw_entries().to_vec());
+            let sample_size = samples.iter().map(|sample| sample.len()).sum::<usize>();
+            if sample_size >= self.options.zstd_dict_capacity * ZSTD_DICT_SAMPLE_FACTOR {
+                // Blocks built so far are tagged plain zstd and stay readable; only blocks built
+                // from now on use the dictionary.
+                match zstd::dict::from_samples(samples, self.options.zstd_dict_capacity) {
+                    Ok(dict) => {
+                        self.writer.set_zstd_dict(Bytes::from(dict.clone()));
+                        self.zstd_dict = Some(dict);
+                    }
+                    Err(e) => {
+                        tracing::warn!(
+                            "failed to train zstd dictionary for SST {}: {:?}",
+                            self.sstable_id,
+                            e
+                        );
+                    }
+                }
+                self.zstd_dict_samples = None;
+            }
+        }
+
         let mut block_meta = self.block_metas.last_mut().unwrap();
         block_meta.uncompressed_size = self.block_builder.uncompressed_block_size() as u32;
-        let block = self.block_builder.build();
+        let block = self
+            .block_builder
+            .build_with_dict(self.zstd_dict.as_deref().unwrap_or(&[]));
         self.writer.write_block(block, block_meta).await?;
         block_meta.len = self.writer.data_len() as u32 - block_meta.offset;
         self.block_builder.clear();
@@ -428,6 +492,7 @@ pub(super) mod tests {
             restart_interval: 16,
             bloom_false_positive: 0.001,
             compression_algorithm: CompressionAlgorithm::None,
+            zstd_dict_capacity: 0,
         };
 
         let b = SstableBuilder::for_test(0, mock_sst_writer(&opt), opt);
@@ -443,6 +508,7 @@ pub(super) mod tests {
             restart_interval: 16,
             bloom_false_positive: 0.1,
             compression_algorithm: CompressionAlgorithm::None,
+            zstd_dict_capacity: 0,
         };
         let table_id = TableId::default();
         let mut b = SstableBuilder::for_test(0, mock_sst_writer(&opt), opt);
@@ -502,6 +568,7 @@ pub(super) mod tests {
             restart_interval: 16,
             bloom_false_positive: if with_blooms { 0.01 } else { 0.0 },
             compression_algorithm: CompressionAlgorithm::None,
+            zstd_dict_capacity: 0,
         };
 
         // build remote table
@@ -41,6 +41,12 @@ pub struct SstableIterator {
     /// Current block index.
     cur_idx: usize,
 
+    /// The range of block indexes to iterate on. Restricted to the blocks of a single state
+    /// table if `filter_table_id` is given in the read options, to avoid scanning the blocks of
+    /// unrelated tables in an SST shared by multiple state tables.
+    first_block_idx: usize,
+    end_block_idx: usize,
+
     /// Reference to the sst
     pub sst: TableHolder,
 
@@ -52,11 +58,17 @@ impl SstableIterator {
     pub fn new(
         sstable: TableHolder,
         sstable_store: SstableStoreRef,
-        _options: Arc<SstableIteratorReadOptions>,
+        options: Arc<SstableIteratorReadOptions>,
     ) -> Self {
+        let (first_block_idx, end_block_idx) = match options.filter_table_id {
+            Some(table_id) => sstable.value().meta.table_block_range(table_id),
+            None => (0, sstable.value().block_count()),
+        };
         Self {
             block_iter: None,
             cur_idx: 0,
+            first_block_idx,
+            end_block_idx,
             sst: sstable,
             sstable_store,
             stats: StoreLocalStatistic::default(),
@@ -77,7 +89,7 @@ impl SstableIterator {
         // do cooperative scheduling.
         tokio::task::consume_budget().await;
 
-        if idx >= self.sst.value().block_count() {
+        if idx >= self.end_block_idx {
             self.block_iter = None;
         } else {
             let block = self
@@ -139,7 +151,7 @@ impl HummockIterator for SstableIterator {
     }
 
     fn rewind(&mut self) -> Self::RewindFuture<'_> {
-        async move { self.seek_idx(0, None).await }
+        async move { self.seek_idx(self.first_block_idx, None).await }
     }
 
     fn seek<'a>(&'a mut self, key: FullKey<&'a [u8]>) -> Self::SeekFuture<'a> {
@@ -160,7 +172,8 @@ impl HummockIterator for SstableIterator {
                     );
                     ord == Less || ord == Equal
                 })
-                .saturating_sub(1); // considering the boundary of 0
+                .saturating_sub(1) // considering the boundary of 0
+                .max(self.first_block_idx);
 
             self.seek_idx(block_idx, Some(encoded_key.as_slice()))
                 .await?;
@@ -59,7 +59,117 @@ use super::{HummockError, HummockResult};
 
 const DEFAULT_META_BUFFER_CAPACITY: usize = 4096;
 const MAGIC: u32 = 0x5785ab73;
-const VERSION: u32 = 1;
+const VERSION: u32 = 5;
+/// The minimum format version that can still be decoded, for backward compatibility.
+const MIN_SUPPORTED_VERSION: u32 = 1;
+
+/// Incompatible feature flags that this build understands. An SST with any other incompatible
+/// flag set cannot be decoded correctly and is rejected.
+const KNOWN_INCOMPATIBLE_FLAGS: u32 = 0;
+
+/// The filter recorded in the footer descriptor.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FilterType {
+    None = 0,
+    Bloom = 1,
+}
+
+/// The block index recorded in the footer descriptor. Only a plain in-meta block index exists
+/// today; a partitioned index can be added as a new variant.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum IndexType {
+    BlockMeta = 0,
+}
+
+/// Self-describing footer descriptor, introduced with format version 4 ("footer v2"). It is
+/// encoded at the front of the meta so that readers learn how to interpret the rest of the meta
+/// before parsing it, and it carries feature flags so that future format changes can roll out
+/// with mixed-version clusters reading old and new SSTs safely:
+///
+/// - A reader may ignore unknown *compatible* flags: the feature only adds information it does
+///   not need (e.g. zone maps).
+/// - A reader must reject unknown *incompatible* flags: the feature changes how existing parts
+///   are encoded (e.g. a partitioned block index).
+///
+/// The descriptor is length-prefixed, so fields appended by future versions are skipped by older
+/// readers.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct SstableFooter {
+    pub filter_type: FilterType,
+    pub index_type: IndexType,
+    pub has_compression_dict: bool,
+    /// Flags of features that readers not understanding them may safely ignore.
+    pub compatible_flags: u32,
+    /// Flags of features that readers must understand to decode the SST.
+    pub incompatible_flags: u32,
+}
+
+impl SstableFooter {
+    const ENCODED_SIZE: usize = 15;
+
+    /// Format:
+    ///
+    /// ```plain
+    /// | len (4B) | filter (1B) | index (1B) | dict (1B) |
+    /// | compatible flags (4B) | incompatible flags (4B) |
+    /// ```
+    fn encode(&self, buf: &mut Vec<u8>) {
+        buf.put_u32_le((Self::ENCODED_SIZE - 4) as u32);
+        buf.put_u8(self.filter_type as u8);
+        buf.put_u8(self.index_type as u8);
+        buf.put_u8(self.has_compression_dict as u8);
+        buf.put_u32_le(self.compatible_flags);
+        buf.put_u32_le(self.incompatible_flags);
+    }
+
+    fn decode(buf: &mut &[u8]) -> HummockResult<Self> {
+        let len = buf.get_u32_le() as usize;
+        if len < Self::ENCODED_SIZE - 4 {
+            return Err(HummockError::decode_error(format!(
+                "footer descriptor too short: {} bytes",
+                len
+            )));
+        }
+        let filter_type = match buf.get_u8() {
+            0 => FilterType::None,
+            1 => FilterType::Bloom,
+            t => {
+                return Err(HummockError::decode_error(format!(
+                    "unknown filter type: {}",
+                    t
+                )))
+            }
+        };
+        let index_type = match buf.get_u8() {
+            0 => IndexType::BlockMeta,
+            t => {
+                return Err(HummockError::decode_error(format!(
+                    "unknown index type: {}",
+                    t
+                )))
+            }
+        };
+        let has_compression_dict = buf.get_u8() != 0;
+        let compatible_flags = buf.get_u32_le();
+        let incompatible_flags = buf.get_u32_le();
+        if incompatible_flags & !KNOWN_INCOMPATIBLE_FLAGS != 0 {
+            return Err(HummockError::decode_error(format!(
+                "SST was written with incompatible features (flags {:#x}), reading it requires a \
+                 newer version",
+                incompatible_flags & !KNOWN_INCOMPATIBLE_FLAGS
+            )));
+        }
+        // Skip fields appended by a future version; unknown compatible flags are ignored.
+        buf.advance(len - (Self::ENCODED_SIZE - 4));
+        Ok(Self {
+            filter_type,
+            index_type,
+            has_compression_dict,
+            compatible_flags,
+            incompatible_flags,
+        })
+    }
+}
 
 #[derive(Clone, PartialEq, Eq, Debug)]
 // delete keys located in [start_user_key, end_user_key)
@@ -166,6 +276,17 @@ impl Sstable {
         bloom.surely_not_have_hash(hash)
     }
 
+    /// The number of pk prefix columns recorded as the bloom filter key of `table_id`, or
+    /// `None` if the SST does not record one, i.e. it was written by an old format version or
+    /// the table's filter key is not a column prefix.
+    pub fn bloom_filter_prefix_columns(&self, table_id: u32) -> Option<usize> {
+        self.meta
+            .bloom_filter_prefix_columns
+            .binary_search_by_key(&table_id, |(table_id, _)| *table_id)
+            .ok()
+            .map(|idx| self.meta.bloom_filter_prefix_columns[idx].1 as usize)
+    }
+
     pub fn block_count(&self) -> usize {
         self.meta.block_metas.len()
     }
@@ -244,6 +365,20 @@ pub struct SstableMeta {
     pub largest_key: Vec<u8>,
     pub meta_offset: u64,
     pub range_tombstone_list: Vec<DeleteRangeTombstone>,
+    /// The index of the first block containing keys of each state table, in ascending table id
+    /// order. Used to skip the blocks of unrelated tables when reading a single table from an
+    /// SST shared by multiple state tables. Empty for SSTs of an old format version.
+    pub table_block_offsets: Vec<(u32, u32)>,
+    /// The zstd dictionary trained for this SST's blocks. Empty if the SST was built without
+    /// dictionary compression or by an old format version.
+    pub zstd_dict: Vec<u8>,
+    /// The number of pk prefix columns used as the bloom filter key of each state table, in
+    /// ascending table id order. Readers truncate their prefix hints to the recorded length, so
+    /// that filter keys are computed consistently with the writer even when the length has been
+    /// adapted to the observed read patterns. Tables without an entry were written with the
+    /// catalog-configured length, either because their filter key is not a column prefix or
+    /// because the SST predates this record.
+    pub bloom_filter_prefix_columns: Vec<(u32, u32)>,
     /// Format version, for further compatibility.
     pub version: u32,
 }
@@ -252,6 +387,7 @@ impl SstableMeta {
     /// Format:
     ///
     /// ```plain
+    /// | footer descriptor |
     /// | N (4B) |
     /// | block meta 0 | ... | block meta N-1 |
     /// | bloom filter len (4B) | bloom filter |
@@ -259,6 +395,9 @@ impl SstableMeta {
     /// | smallest key len (4B) | smallest key |
     /// | largest key len (4B) | largest key |
     /// | range-tombstone 0 | ... | range-tombstone M-1 |
+    /// | K (4B) | table id 0 (4B) | block offset 0 (4B) | ... | table id K-1 | block offset K-1 |
+    /// | zstd dict len (4B) | zstd dict |
+    /// | L (4B) | table id 0 (4B) | prefix cols 0 (4B) | ... | table id L-1 | prefix cols L-1 |
     /// | checksum (8B) | version (4B) | magic (4B) |
     /// ```
     pub fn encode_to_bytes(&self) -> Vec<u8> {
@@ -267,8 +406,24 @@ impl SstableMeta {
         buf
     }
 
+    /// The footer descriptor describing this meta, derived from its content.
+    pub fn footer(&self) -> SstableFooter {
+        SstableFooter {
+            filter_type: if self.bloom_filter.is_empty() {
+                FilterType::None
+            } else {
+                FilterType::Bloom
+            },
+            index_type: IndexType::BlockMeta,
+            has_compression_dict: !self.zstd_dict.is_empty(),
+            compatible_flags: 0,
+            incompatible_flags: 0,
+        }
+    }
+
     pub fn encode_to(&self, buf: &mut Vec<u8>) {
         let start_offset = buf.len();
+        self.footer().encode(buf);
         buf.put_u32_le(self.block_metas.len() as u32);
         for block_meta in &self.block_metas {
             block_meta.encode(buf);
@@ -283,6 +438,17 @@ impl SstableMeta {
         for tombstone in &self.range_tombstone_list {
             tombstone.encode(buf);
         }
+        buf.put_u32_le(self.table_block_offsets.len() as u32);
+        for (table_id, block_offset) in &self.table_block_offsets {
+            buf.put_u32_le(*table_id);
+            buf.put_u32_le(*block_offset);
+        }
+        put_length_prefixed_slice(buf, &self.zstd_dict);
+        buf.put_u32_le(self.bloom_filter_prefix_columns.len() as u32);
+        for (table_id, prefix_columns) in &self.bloom_filter_prefix_columns {
+            buf.put_u32_le(*table_id);
+            buf.put_u32_le(*prefix_columns);
+        }
         let checksum = xxhash64_checksum(&buf[start_offset..]);
         buf.put_u64_le(checksum);
         buf.put_u32_le(VERSION);
@@ -300,7 +466,7 @@ impl SstableMeta {
 
         cursor -= 4;
         let version = (&buf[cursor..cursor + 4]).get_u32_le();
-        if version != VERSION {
+        if !(MIN_SUPPORTED_VERSION..=VERSION).contains(&version) {
             return Err(HummockError::invalid_format_version(version));
         }
 
@@ -309,6 +475,11 @@ impl SstableMeta {
         let buf = &mut &buf[..cursor];
         xxhash64_verify(buf, checksum)?;
 
+        if version >= 4 {
+            // Rejects SSTs written with incompatible features by a newer version.
+            SstableFooter::decode(buf)?;
+        }
+
         let block_meta_count = buf.get_u32_le() as usize;
         let mut block_metas = Vec::with_capacity(block_meta_count);
         for _ in 0..block_meta_count {
@@ -326,6 +497,35 @@ impl SstableMeta {
             let tombstone = DeleteRangeTombstone::decode(buf);
             range_tombstone_list.push(tombstone);
         }
+        let table_block_offsets = if version >= 2 {
+            let count = buf.get_u32_le() as usize;
+            let mut table_block_offsets = Vec::with_capacity(count);
+            for _ in 0..count {
+                let table_id = buf.get_u32_le();
+                let block_offset = buf.get_u32_le();
+                table_block_offsets.push((table_id, block_offset));
+            }
+            table_block_offsets
+        } else {
+            vec![]
+        };
+        let zstd_dict = if version >= 3 {
+            get_length_prefixed_slice(buf)
+        } else {
+            vec![]
+        };
+        let bloom_filter_prefix_columns = if version >= 5 {
+            let count = buf.get_u32_le() as usize;
+            let mut bloom_filter_prefix_columns = Vec::with_capacity(count);
+            for _ in 0..count {
+                let table_id = buf.get_u32_le();
+                let prefix_columns = buf.get_u32_le();
+                bloom_filter_prefix_columns.push((table_id, prefix_columns));
+            }
+            bloom_filter_prefix_columns
+        } else {
+            vec![]
+        };
 
         Ok(Self {
             block_metas,
@@ -336,13 +536,44 @@ impl SstableMeta {
             largest_key,
             meta_offset,
             range_tombstone_list,
+            table_block_offsets,
+            zstd_dict,
+            bloom_filter_prefix_columns,
             version,
         })
     }
 
+    /// The range of block indexes that may contain keys of `table_id`, by the recorded per-table
+    /// block offsets. Covers all blocks if the offsets are not available, i.e. the meta is
+    /// decoded from an old format version.
+    pub fn table_block_range(&self, table_id: u32) -> (usize, usize) {
+        if self.table_block_offsets.is_empty() {
+            return (0, self.block_metas.len());
+        }
+        match self
+            .table_block_offsets
+            .binary_search_by_key(&table_id, |(table_id, _)| *table_id)
+        {
+            Ok(idx) => {
+                let start = self.table_block_offsets[idx].1 as usize;
+                // The block where the next table starts may still contain keys of this table.
+                let end = match self.table_block_offsets.get(idx + 1) {
+                    Some((_, block_offset)) => {
+                        (*block_offset as usize + 1).min(self.block_metas.len())
+                    }
+                    None => self.block_metas.len(),
+                };
+                (start, end)
+            }
+            // The table has no keys in this SST at all.
+            Err(_) => (0, 0),
+        }
+    }
+
     #[inline]
     pub fn encoded_size(&self) -> usize {
-        4 // block meta count
+        SstableFooter::ENCODED_SIZE
+            + 4 // block meta count
             + self
             .block_metas
             .iter()
@@ -354,6 +585,12 @@ impl SstableMeta {
             .iter()
             .map(| tombstone| 16 + tombstone.start_user_key.encoded_len() + tombstone.end_user_key.encoded_len())
             .sum::<usize>()
+            + 4 // table block offsets len
+            + self.table_block_offsets.len() * 8
+            + 4 // zstd dict len
+            + self.zstd_dict.len()
+            + 4 // bloom filter prefix columns len
+            + self.bloom_filter_prefix_columns.len() * 8
             + 4 // bloom filter len
             + self.bloom_filter.len()
             + 4 // estimated size
@@ -372,6 +609,9 @@ impl SstableMeta {
 #[derive(Default)]
 pub struct SstableIteratorReadOptions {
     pub prefetch: bool,
+    /// When reading a single state table from an SST shared by multiple state tables, restrict
+    /// the iterator to the blocks of that table by the per-table block offsets in the meta.
+    pub filter_table_id: Option<u32>,
 }
 
 #[cfg(test)]
@@ -402,6 +642,9 @@ mod tests {
             largest_key: b"9-largest-key".to_vec(),
             meta_offset: 123,
             range_tombstone_list: vec![],
+            table_block_offsets: vec![(1, 0), (2, 1)],
+            zstd_dict: b"a-trained-dictionary".to_vec(),
+            bloom_filter_prefix_columns: vec![(1, 2), (2, 1)],
             version: VERSION,
         };
         let sz = meta.encoded_size();
@@ -410,4 +653,72 @@ mod tests {
         let decoded_meta = SstableMeta::decode(&mut &buf[..]).unwrap();
         assert_eq!(decoded_meta, meta);
     }
+
+    #[test]
+    pub fn test_footer_enc_dec() {
+        let footer = SstableFooter {
+            filter_type: FilterType::Bloom,
+            index_type: IndexType::BlockMeta,
+            has_compression_dict: true,
+            compatible_flags: 0,
+            incompatible_flags: 0,
+        };
+        let mut buf = vec![];
+        footer.encode(&mut buf);
+        assert_eq!(buf.len(), SstableFooter::ENCODED_SIZE);
+        assert_eq!(SstableFooter::decode(&mut &buf[..]).unwrap(), footer);
+
+        // Unknown compatible flags and appended fields are ignored.
+        let mut buf = vec![];
+        SstableFooter {
+            compatible_flags: 0xdead,
+            ..footer
+        }
+        .encode(&mut buf);
+        buf.push(0xff);
+        buf[0] += 1; // bump the length prefix to cover the appended byte
+        let decoded = SstableFooter::decode(&mut &buf[..]).unwrap();
+        assert_eq!(decoded.compatible_flags, 0xdead);
+
+        // Unknown incompatible flags are rejected.
+        let mut buf = vec![];
+        SstableFooter {
+            incompatible_flags: 0x1,
+            ..footer
+        }
+        .encode(&mut buf);
+        assert!(SstableFooter::decode(&mut &buf[..]).is_err());
+    }
+
+    #[test]
+    pub fn test_table_block_range() {
+        let block_meta = BlockMeta {
+            smallest_key: vec![],
+            offset: 0,
+            len: 0,
+            uncompressed_size: 0,
+        };
+        let mut meta = SstableMeta {
+            block_metas: vec![block_meta; 4],
+            bloom_filter: vec![],
+            estimated_size: 0,
+            key_count: 0,
+            smallest_key: vec![],
+            largest_key: vec![],
+            meta_offset: 0,
+            range_tombstone_list: vec![],
+            table_block_offsets: vec![(1, 0), (2, 1), (5, 3)],
+            zstd_dict: vec![],
+            bloom_filter_prefix_columns: vec![],
+            version: VERSION,
+        };
+        assert_eq!(meta.table_block_range(1), (0, 2));
+        assert_eq!(meta.table_block_range(2), (1, 4));
+        assert_eq!(meta.table_block_range(5), (3, 4));
+        // The table has no keys in this SST.
+        assert_eq!(meta.table_block_range(3), (0, 0));
+        // Decoded from an old format version without per-table offsets.
+        meta.table_block_offsets.clear();
+        assert_eq!(meta.table_block_range(1), (0, 4));
+    }
 }
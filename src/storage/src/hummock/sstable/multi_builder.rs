@@ -260,6 +260,7 @@ impl TableBuilderFactory for LocalTableBuilderFactory {
             capacity_hint: Some(self.options.capacity),
             tracker: Some(tracker),
             policy: self.policy,
+            io_limiter: None,
         };
         let writer = self
             .sstable_store
@@ -293,6 +294,7 @@ mod tests {
             restart_interval: DEFAULT_RESTART_INTERVAL,
             bloom_false_positive: 0.1,
             compression_algorithm: CompressionAlgorithm::None,
+            zstd_dict_capacity: 0,
         };
         let builder_factory = LocalTableBuilderFactory::new(1001, mock_sstable_store(), opts);
         let builder = CapacitySplitTableBuilder::for_test(builder_factory);
@@ -310,6 +312,7 @@ mod tests {
             restart_interval: DEFAULT_RESTART_INTERVAL,
             bloom_false_positive: 0.1,
             compression_algorithm: CompressionAlgorithm::None,
+            zstd_dict_capacity: 0,
         };
         let builder_factory = LocalTableBuilderFactory::new(1001, mock_sstable_store(), opts);
         let mut builder = CapacitySplitTableBuilder::for_test(builder_factory);
@@ -442,6 +445,7 @@ mod tests {
             restart_interval: DEFAULT_RESTART_INTERVAL,
             bloom_false_positive: 0.1,
             compression_algorithm: CompressionAlgorithm::None,
+            zstd_dict_capacity: 0,
         };
         let table_id = TableId::new(1);
         let mut builder = DeleteRangeAggregatorBuilder::default();
@@ -88,6 +88,10 @@ pub enum CompressionAlgorithm {
     None,
     Lz4,
     Zstd,
+    /// Zstd with a dictionary embedded in the SST meta. Only used as the per-block compression
+    /// tag; builders are configured with [`CompressionAlgorithm::Zstd`] and switch to this tag
+    /// once a dictionary has been trained.
+    ZstdDict,
 }
 
 impl CompressionAlgorithm {
@@ -96,6 +100,7 @@ impl CompressionAlgorithm {
             Self::None => 0,
             Self::Lz4 => 1,
             Self::Zstd => 2,
+            Self::ZstdDict => 3,
         };
         buf.put_u8(v);
     }
@@ -105,6 +110,7 @@ impl CompressionAlgorithm {
             0 => Ok(Self::None),
             1 => Ok(Self::Lz4),
             2 => Ok(Self::Zstd),
+            3 => Ok(Self::ZstdDict),
             _ => Err(HummockError::decode_error(
                 "not valid compression algorithm",
             )),
@@ -118,6 +124,7 @@ impl From<CompressionAlgorithm> for u8 {
             CompressionAlgorithm::None => 0,
             CompressionAlgorithm::Lz4 => 1,
             CompressionAlgorithm::Zstd => 2,
+            CompressionAlgorithm::ZstdDict => 3,
         }
     }
 }
@@ -128,6 +135,7 @@ impl From<CompressionAlgorithm> for u64 {
             CompressionAlgorithm::None => 0,
             CompressionAlgorithm::Lz4 => 1,
             CompressionAlgorithm::Zstd => 2,
+            CompressionAlgorithm::ZstdDict => 3,
         }
     }
 }
@@ -140,6 +148,7 @@ impl TryFrom<u8> for CompressionAlgorithm {
             0 => Ok(Self::None),
             1 => Ok(Self::Lz4),
             2 => Ok(Self::Zstd),
+            3 => Ok(Self::ZstdDict),
             _ => Err(HummockError::decode_error(
                 "not valid compression algorithm",
             )),
@@ -28,6 +28,10 @@ pub trait SstableWriter: Send + Sync {
     /// Finish writing the SST.
     async fn finish(self, meta: SstableMeta) -> HummockResult<Self::Output>;
 
+    /// Informs the writer of the zstd dictionary that subsequent blocks are compressed with, so
+    /// that writers which decode blocks (e.g. to fill the block cache) can decompress them.
+    fn set_zstd_dict(&mut self, _dict: Bytes) {}
+
     /// Get the length of data that has already been written.
     fn data_len(&self) -> usize;
 }
@@ -107,6 +111,9 @@ mod tests {
             largest_key: Vec::new(),
             meta_offset: data.len() as u64,
             range_tombstone_list: vec![],
+            table_block_offsets: vec![],
+            zstd_dict: vec![],
+            bloom_filter_prefix_columns: vec![],
             version: VERSION,
         };
 
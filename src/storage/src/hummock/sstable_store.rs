@@ -36,7 +36,7 @@ use super::{
 };
 use crate::hummock::multi_builder::UploadJoinHandle;
 use crate::hummock::{
-    BlockHolder, CacheableEntry, HummockError, HummockResult, LruCache, MemoryLimiter,
+    BlockHolder, CacheableEntry, HummockError, HummockResult, IoLimiter, LruCache, MemoryLimiter,
 };
 use crate::monitor::{MemoryCollector, StoreLocalStatistic};
 
@@ -198,6 +198,21 @@ impl SstableStore {
         self.meta_cache.erase(sst_id, &sst_id);
     }
 
+    /// Evicts the cached meta and data blocks of an SST that has been removed from the hummock
+    /// version, e.g. as a compaction input, so that the cache space can be reused by the new SSTs
+    /// immediately instead of after LRU churn.
+    ///
+    /// The number of data blocks is only recorded in the SST meta, so if the meta is no longer
+    /// cached, the data blocks (if any) are left to normal LRU eviction.
+    pub fn evict_removed_sst(&self, sst_id: HummockSstableId) {
+        if let Some(entry) = self.meta_cache.lookup(sst_id, &sst_id) {
+            for block_idx in 0..entry.value().block_count() as u64 {
+                self.block_cache.erase(sst_id, block_idx);
+            }
+        }
+        self.meta_cache.erase(sst_id, &sst_id);
+    }
+
     async fn put_sst_data(&self, sst_id: HummockSstableId, data: Bytes) -> HummockResult<()> {
         let data_path = self.get_sst_data_path(sst_id);
         self.store
@@ -232,6 +247,7 @@ impl SstableStore {
             let sst_id = sst.id;
             let use_tiered_cache = !matches!(policy, CachePolicy::Disable);
             let uncompressed_capacity = block_meta.uncompressed_size as usize;
+            let zstd_dict = Bytes::from(sst.meta.zstd_dict.clone());
 
             async move {
                 if use_tiered_cache && let Some(holder) = tiered_cache
@@ -244,7 +260,7 @@ impl SstableStore {
                 }
 
                 let block_data = store.read(&data_path, Some(block_loc)).await?;
-                let block = Block::decode(block_data, uncompressed_capacity)?;
+                let block = Block::decode_with_dict(block_data, uncompressed_capacity, &zstd_dict)?;
                 Ok(Box::new(block))
             }
         };
@@ -412,6 +428,7 @@ impl SstableStore {
         &self,
         sst: &Sstable,
         block_index: Option<usize>,
+        io_limiter: Option<Arc<IoLimiter>>,
     ) -> HummockResult<BlockStream> {
         let start_pos = match block_index {
             None => None,
@@ -436,6 +453,7 @@ impl SstableStore {
                 .map_err(HummockError::object_io_error)?,
             block_index.unwrap_or(0),
             &sst.meta,
+            io_limiter,
         ))
     }
 }
@@ -475,6 +493,8 @@ pub struct SstableWriterOptions {
     pub capacity_hint: Option<usize>,
     pub tracker: Option<MemoryTracker>,
     pub policy: CachePolicy,
+    /// Throttles `write_block` if a bandwidth limit is configured.
+    pub io_limiter: Option<Arc<IoLimiter>>,
 }
 
 pub trait SstableWriterFactory: Send + Sync {
@@ -522,6 +542,8 @@ pub struct BatchUploadWriter {
     buf: Vec<u8>,
     block_info: Vec<Block>,
     tracker: Option<MemoryTracker>,
+    io_limiter: Option<Arc<IoLimiter>>,
+    zstd_dict: Bytes,
 }
 
 impl BatchUploadWriter {
@@ -537,6 +559,8 @@ impl BatchUploadWriter {
             buf: Vec::with_capacity(options.capacity_hint.unwrap_or(0)),
             block_info: Vec::new(),
             tracker: options.tracker,
+            io_limiter: options.io_limiter,
+            zstd_dict: Bytes::new(),
         }
     }
 }
@@ -545,12 +569,20 @@ impl BatchUploadWriter {
 impl SstableWriter for BatchUploadWriter {
     type Output = JoinHandle<HummockResult<()>>;
 
+    fn set_zstd_dict(&mut self, dict: Bytes) {
+        self.zstd_dict = dict;
+    }
+
     async fn write_block(&mut self, block: &[u8], meta: &BlockMeta) -> HummockResult<()> {
+        if let Some(limiter) = &self.io_limiter {
+            limiter.acquire(block.len() as u64).await;
+        }
         self.buf.extend_from_slice(block);
         if let CachePolicy::Fill = self.policy {
-            self.block_info.push(Block::decode(
+            self.block_info.push(Block::decode_with_dict(
                 Bytes::from(block.to_vec()),
                 meta.uncompressed_size as usize,
+                &self.zstd_dict,
             )?);
         }
         Ok(())
@@ -608,6 +640,8 @@ pub struct StreamingUploadWriter {
     blocks: Vec<Block>,
     data_len: usize,
     tracker: Option<MemoryTracker>,
+    io_limiter: Option<Arc<IoLimiter>>,
+    zstd_dict: Bytes,
 }
 
 impl StreamingUploadWriter {
@@ -625,6 +659,8 @@ impl StreamingUploadWriter {
             blocks: Vec::new(),
             data_len: 0,
             tracker: options.tracker,
+            io_limiter: options.io_limiter,
+            zstd_dict: Bytes::new(),
         }
     }
 }
@@ -633,11 +669,22 @@ impl StreamingUploadWriter {
 impl SstableWriter for StreamingUploadWriter {
     type Output = JoinHandle<HummockResult<()>>;
 
+    fn set_zstd_dict(&mut self, dict: Bytes) {
+        self.zstd_dict = dict;
+    }
+
     async fn write_block(&mut self, block_data: &[u8], meta: &BlockMeta) -> HummockResult<()> {
+        if let Some(limiter) = &self.io_limiter {
+            limiter.acquire(block_data.len() as u64).await;
+        }
         self.data_len += block_data.len();
         let block_data = Bytes::from(block_data.to_vec());
         if let CachePolicy::Fill = self.policy {
-            let block = Block::decode(block_data.clone(), meta.uncompressed_size as usize)?;
+            let block = Block::decode_with_dict(
+                block_data.clone(),
+                meta.uncompressed_size as usize,
+                &self.zstd_dict,
+            )?;
             self.blocks.push(block);
         }
         self.object_uploader
@@ -771,6 +818,12 @@ pub struct BlockStream {
     /// streaming starts at block 2 of a given SST, then the list does not contain information
     /// about block 0 and block 1.
     block_size_vec: Vec<(usize, usize)>,
+
+    /// The zstd dictionary of the streamed SST, if it has one.
+    zstd_dict: Bytes,
+
+    /// Throttles reads if a bandwidth limit is configured.
+    io_limiter: Option<Arc<IoLimiter>>,
 }
 
 impl BlockStream {
@@ -788,6 +841,9 @@ impl BlockStream {
 
         // Meta data of the SST that is streamed.
         sst_meta: &SstableMeta,
+
+        // Throttles reads if a bandwidth limit is configured.
+        io_limiter: Option<Arc<IoLimiter>>,
     ) -> Self {
         let metas = &sst_meta.block_metas;
 
@@ -805,6 +861,8 @@ impl BlockStream {
             byte_stream,
             block_idx: 0,
             block_size_vec: block_len_vec,
+            zstd_dict: Bytes::from(sst_meta.zstd_dict.clone()),
+            io_limiter,
         }
     }
 
@@ -817,6 +875,9 @@ impl BlockStream {
 
         let (block_stream_size, block_full_size) =
             *self.block_size_vec.get(self.block_idx).unwrap();
+        if let Some(limiter) = &self.io_limiter {
+            limiter.acquire(block_stream_size as u64).await;
+        }
         let mut buffer = vec![0; block_stream_size];
 
         let bytes_read = self
@@ -834,7 +895,11 @@ impl BlockStream {
             )));
         }
 
-        let boxed_block = Box::new(Block::decode(Bytes::from(buffer), block_full_size)?);
+        let boxed_block = Box::new(Block::decode_with_dict(
+            Bytes::from(buffer),
+            block_full_size,
+            &self.zstd_dict,
+        )?);
         self.block_idx += 1;
 
         Ok(Some(boxed_block))
@@ -907,6 +972,7 @@ mod tests {
             capacity_hint: None,
             tracker: None,
             policy: CachePolicy::Disable,
+            io_limiter: None,
         };
         let info = put_sst(
             SST_ID,
@@ -937,6 +1003,7 @@ mod tests {
             capacity_hint: None,
             tracker: None,
             policy: CachePolicy::Disable,
+            io_limiter: None,
         };
         let info = put_sst(
             SST_ID,
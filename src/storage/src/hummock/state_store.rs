@@ -142,6 +142,78 @@ impl HummockStorage {
 
         Ok(read_version_tuple)
     }
+
+    /// Verifies that all `table_ids` read by one batch query can be served from a single
+    /// snapshot at `wait_epoch`, waiting for a version update if the local version has not
+    /// caught up yet.
+    ///
+    /// All tables currently commit through the global `max_committed_epoch`, so one wait covers
+    /// every table. The per-table validation below is the extension point for barrier domains,
+    /// where tables in different domains are checkpointed unevenly and each table has to be
+    /// checked against the committed epoch of its own domain instead.
+    pub async fn try_wait_consistent_snapshot(
+        &self,
+        table_ids: impl IntoIterator<Item = TableId>,
+        wait_epoch: HummockReadEpoch,
+    ) -> StorageResult<()> {
+        self.validate_read_epoch(wait_epoch.clone())?;
+        let wait_epoch = match wait_epoch {
+            HummockReadEpoch::Committed(epoch) => epoch,
+            _ => return Ok(()),
+        };
+        {
+            // Fail fast on epochs that have already been vacuumed: waiting would never make
+            // them readable again. All tables currently share the version-level safe epoch;
+            // once barrier domains allow vacuuming tables unevenly, each table has to be
+            // validated against the safe epoch of its own domain here.
+            let pinned_version = self.pinned_version.load();
+            for _table_id in table_ids {
+                validate_safe_epoch(pinned_version.safe_epoch(), wait_epoch)?;
+            }
+        }
+        self.wait_committed_epoch(wait_epoch).await
+    }
+
+    /// Waits until the locally pinned version has committed `wait_epoch`.
+    async fn wait_committed_epoch(&self, wait_epoch: HummockEpoch) -> StorageResult<()> {
+        assert_ne!(wait_epoch, HummockEpoch::MAX, "epoch should not be u64::MAX");
+        let mut receiver = self.version_update_notifier_tx.subscribe();
+        // avoid unnecessary check in the loop if the value does not change
+        let max_committed_epoch = *receiver.borrow_and_update();
+        if max_committed_epoch >= wait_epoch {
+            return Ok(());
+        }
+        loop {
+            match tokio::time::timeout(Duration::from_secs(30), receiver.changed()).await {
+                Err(elapsed) => {
+                    // The reason that we need to retry here is batch scan in
+                    // chain/rearrange_chain is waiting for an
+                    // uncommitted epoch carried by the CreateMV barrier, which
+                    // can take unbounded time to become committed and propagate
+                    // to the CN. We should consider removing the retry as well as wait_epoch
+                    // for chain/rearrange_chain if we enforce
+                    // chain/rearrange_chain to be scheduled on the same
+                    // CN with the same distribution as the upstream MV.
+                    // See #3845 for more details.
+                    tracing::warn!(
+                        "wait_epoch {:?} timeout when waiting for version update elapsed {:?}s",
+                        wait_epoch,
+                        elapsed
+                    );
+                    continue;
+                }
+                Ok(Err(_)) => {
+                    return Err(HummockError::wait_epoch("tx dropped").into());
+                }
+                Ok(Ok(_)) => {
+                    let max_committed_epoch = *receiver.borrow();
+                    if max_committed_epoch >= wait_epoch {
+                        return Ok(());
+                    }
+                }
+            }
+        }
+    }
 }
 
 impl StateStoreRead for HummockStorage {
@@ -181,48 +253,10 @@ impl StateStore for HummockStorage {
         async move {
             self.validate_read_epoch(wait_epoch.clone())?;
             let wait_epoch = match wait_epoch {
-                HummockReadEpoch::Committed(epoch) => {
-                    assert_ne!(epoch, HummockEpoch::MAX, "epoch should not be u64::MAX");
-                    epoch
-                }
+                HummockReadEpoch::Committed(epoch) => epoch,
                 _ => return Ok(()),
             };
-            let mut receiver = self.version_update_notifier_tx.subscribe();
-            // avoid unnecessary check in the loop if the value does not change
-            let max_committed_epoch = *receiver.borrow_and_update();
-            if max_committed_epoch >= wait_epoch {
-                return Ok(());
-            }
-            loop {
-                match tokio::time::timeout(Duration::from_secs(30), receiver.changed()).await {
-                    Err(elapsed) => {
-                        // The reason that we need to retry here is batch scan in
-                        // chain/rearrange_chain is waiting for an
-                        // uncommitted epoch carried by the CreateMV barrier, which
-                        // can take unbounded time to become committed and propagate
-                        // to the CN. We should consider removing the retry as well as wait_epoch
-                        // for chain/rearrange_chain if we enforce
-                        // chain/rearrange_chain to be scheduled on the same
-                        // CN with the same distribution as the upstream MV.
-                        // See #3845 for more details.
-                        tracing::warn!(
-                            "wait_epoch {:?} timeout when waiting for version update elapsed {:?}s",
-                            wait_epoch,
-                            elapsed
-                        );
-                        continue;
-                    }
-                    Ok(Err(_)) => {
-                        return Err(HummockError::wait_epoch("tx dropped").into());
-                    }
-                    Ok(Ok(_)) => {
-                        let max_committed_epoch = *receiver.borrow();
-                        if max_committed_epoch >= wait_epoch {
-                            return Ok(());
-                        }
-                    }
-                }
-            }
+            self.wait_committed_epoch(wait_epoch).await
         }
     }
 
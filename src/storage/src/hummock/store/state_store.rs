@@ -59,6 +59,9 @@ pub struct LocalHummockStorage {
     hummock_version_reader: HummockVersionReader,
 
     tracing: Arc<risingwave_tracing::RwTracingService>,
+
+    /// Whether to deduplicate writes to the same key within one write batch.
+    write_batch_dedup_enabled: bool,
 }
 
 impl LocalHummockStorage {
@@ -151,7 +154,24 @@ impl StateStoreWrite for LocalHummockStorage {
             let epoch = write_options.epoch;
             let table_id = write_options.table_id;
 
-            let sorted_items = SharedBufferBatch::build_shared_buffer_item_batches(kv_pairs);
+            let sorted_items = if self.write_batch_dedup_enabled {
+                let input_count = kv_pairs.len();
+                let (sorted_items, dropped_count) =
+                    SharedBufferBatch::build_shared_buffer_item_batches_dedup(kv_pairs);
+                let stats = self.hummock_version_reader.stats();
+                let table_id_label = table_id.to_string();
+                stats
+                    .write_batch_dedup_input_item_counts
+                    .with_label_values(&[table_id_label.as_str()])
+                    .inc_by(input_count as u64);
+                stats
+                    .write_batch_dedup_dropped_item_counts
+                    .with_label_values(&[table_id_label.as_str()])
+                    .inc_by(dropped_count as u64);
+                sorted_items
+            } else {
+                SharedBufferBatch::build_shared_buffer_item_batches(kv_pairs)
+            };
             let size = SharedBufferBatch::measure_batch_size(&sorted_items);
             let limiter = self.memory_limiter.as_ref();
             let tracker = if let Some(tracker) = limiter.try_require_memory(size as u64) {
@@ -205,6 +225,7 @@ impl LocalHummockStorage {
         event_sender: mpsc::UnboundedSender<HummockEvent>,
         memory_limiter: Arc<MemoryLimiter>,
         tracing: Arc<risingwave_tracing::RwTracingService>,
+        write_batch_dedup_enabled: bool,
     ) -> Self {
         Self {
             instance_guard,
@@ -213,6 +234,7 @@ impl LocalHummockStorage {
             memory_limiter,
             hummock_version_reader,
             tracing,
+            write_batch_dedup_enabled,
         }
     }
 
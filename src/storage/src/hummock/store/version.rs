@@ -26,6 +26,7 @@ use minitrace::future::FutureExt;
 use minitrace::Span;
 use parking_lot::RwLock;
 use risingwave_common::catalog::TableId;
+use risingwave_hummock_sdk::filter_key_extractor::FilterKeyExtractorManagerRef;
 use risingwave_hummock_sdk::key::{
     bound_table_key_range, user_key, FullKey, TableKey, TableKeyRange, UserKey,
 };
@@ -50,7 +51,7 @@ use crate::hummock::utils::{
 };
 use crate::hummock::{
     get_from_batch, get_from_sstable_info, hit_sstable_bloom_filter, DeleteRangeAggregator,
-    Sstable, SstableDeleteRangeIterator, SstableIterator,
+    SstableDeleteRangeIterator, SstableIterator,
 };
 use crate::monitor::{GetLocalMetricsGuard, HummockStateStoreMetrics, StoreLocalStatistic};
 use crate::store::{gen_min_epoch, ReadOptions, StateStoreIterExt, StreamTypeOfIter};
@@ -383,6 +384,9 @@ pub fn read_filter_for_local(
 #[derive(Clone)]
 pub struct HummockVersionReader {
     sstable_store: SstableStoreRef,
+    /// Records the pk prefix lengths of the observed reads, so that the bloom filter keys of
+    /// newly built SSTs can be adapted to them.
+    filter_key_extractor_manager: FilterKeyExtractorManagerRef,
 
     /// Statistics
     state_store_metrics: Arc<HummockStateStoreMetrics>,
@@ -393,13 +397,19 @@ pub struct HummockVersionReader {
 impl HummockVersionReader {
     pub fn new(
         sstable_store: SstableStoreRef,
+        filter_key_extractor_manager: FilterKeyExtractorManagerRef,
         state_store_metrics: Arc<HummockStateStoreMetrics>,
     ) -> Self {
         Self {
             sstable_store,
+            filter_key_extractor_manager,
             state_store_metrics,
         }
     }
+
+    pub fn stats(&self) -> &Arc<HummockStateStoreMetrics> {
+        &self.state_store_metrics
+    }
 }
 
 impl HummockVersionReader {
@@ -428,9 +438,12 @@ impl HummockVersionReader {
         }
 
         // 2. order guarantee: imm -> sst
-        let dist_key_hash = read_options.prefix_hint.as_ref().map(|dist_key| {
-            Sstable::hash_for_bloom_filter(dist_key.as_ref(), read_options.table_id.table_id())
-        });
+        if let Some(prefix_hint) = &read_options.prefix_hint {
+            self.filter_key_extractor_manager.observe_read_prefix(
+                read_options.table_id.table_id(),
+                prefix_hint.column_ends.len(),
+            );
+        }
 
         let full_key = FullKey::new(read_options.table_id, table_key, epoch);
         for local_sst in &uncommitted_ssts {
@@ -440,7 +453,6 @@ impl HummockVersionReader {
                 local_sst,
                 full_key,
                 &read_options,
-                dist_key_hash,
                 &mut stats_guard.local_stats,
             )
             .await?
@@ -473,7 +485,6 @@ impl HummockVersionReader {
                             sstable_info,
                             full_key,
                             &read_options,
-                            dist_key_hash,
                             &mut stats_guard.local_stats,
                         )
                         .await?
@@ -509,7 +520,6 @@ impl HummockVersionReader {
                         &level.table_infos[table_info_idx],
                         full_key,
                         &read_options,
-                        dist_key_hash,
                         &mut stats_guard.local_stats,
                     )
                     .await?
@@ -535,6 +545,12 @@ impl HummockVersionReader {
         let (imms, uncommitted_ssts, committed) = read_version_tuple;
 
         let mut local_stats = StoreLocalStatistic::default();
+        // Restrict the sstable iterators to the blocks of the table we're reading, in case the
+        // SSTs are shared by multiple state tables in the same compaction group.
+        let sst_read_options = Arc::new(SstableIteratorReadOptions {
+            filter_table_id: Some(read_options.table_id.table_id()),
+            ..Default::default()
+        });
         let mut staging_iters = Vec::with_capacity(imms.len() + uncommitted_ssts.len());
         let mut delete_range_iter = ForwardMergeRangeIterator::default();
         local_stats.staging_imm_iter_count = imms.len() as u64;
@@ -545,11 +561,12 @@ impl HummockVersionReader {
             staging_iters.push(HummockIteratorUnion::First(imm.into_forward_iter()));
         }
         let mut staging_sst_iter_count = 0;
-        // encode once
-        let bloom_filter_prefix_hash = read_options
-            .prefix_hint
-            .as_ref()
-            .map(|hint| Sstable::hash_for_bloom_filter(hint, read_options.table_id.table_id()));
+        if let Some(prefix_hint) = &read_options.prefix_hint {
+            self.filter_key_extractor_manager.observe_read_prefix(
+                read_options.table_id.table_id(),
+                prefix_hint.column_ends.len(),
+            );
+        }
 
         for sstable_info in &uncommitted_ssts {
             let table_holder = self
@@ -557,8 +574,13 @@ impl HummockVersionReader {
                 .sstable(sstable_info, &mut local_stats)
                 .in_span(Span::enter_with_local_parent("get_sstable"))
                 .await?;
-            if let Some(prefix_hash) = bloom_filter_prefix_hash.as_ref() {
-                if !hit_sstable_bloom_filter(table_holder.value(), *prefix_hash, &mut local_stats) {
+            if let Some(prefix_hint) = read_options.prefix_hint.as_ref() {
+                if !hit_sstable_bloom_filter(
+                    table_holder.value(),
+                    prefix_hint,
+                    read_options.table_id.table_id(),
+                    &mut local_stats,
+                ) {
                     continue;
                 }
             }
@@ -573,7 +595,7 @@ impl HummockVersionReader {
             staging_iters.push(HummockIteratorUnion::Second(SstableIterator::new(
                 table_holder,
                 self.sstable_store.clone(),
-                Arc::new(SstableIteratorReadOptions::default()),
+                sst_read_options.clone(),
             )));
         }
         local_stats.staging_sst_iter_count = staging_sst_iter_count;
@@ -680,8 +702,13 @@ impl HummockVersionReader {
                         delete_range_iter
                             .add_sst_iter(SstableDeleteRangeIterator::new(sstable.clone()));
                     }
-                    if let Some(key_hash) = bloom_filter_prefix_hash.as_ref() {
-                        if !hit_sstable_bloom_filter(sstable.value(), *key_hash, &mut local_stats) {
+                    if let Some(prefix_hint) = read_options.prefix_hint.as_ref() {
+                        if !hit_sstable_bloom_filter(
+                            sstable.value(),
+                            prefix_hint,
+                            read_options.table_id.table_id(),
+                            &mut local_stats,
+                        ) {
                             continue;
                         }
                     }
@@ -691,7 +718,7 @@ impl HummockVersionReader {
                 non_overlapping_iters.push(ConcatIterator::new_with_prefetch(
                     sstables,
                     self.sstable_store.clone(),
-                    Arc::new(SstableIteratorReadOptions::default()),
+                    sst_read_options.clone(),
                 ));
             } else {
                 let mut iters = Vec::new();
@@ -706,16 +733,20 @@ impl HummockVersionReader {
                         delete_range_iter
                             .add_sst_iter(SstableDeleteRangeIterator::new(sstable.clone()));
                     }
-                    if let Some(dist_hash) = bloom_filter_prefix_hash.as_ref() {
-                        if !hit_sstable_bloom_filter(sstable.value(), *dist_hash, &mut local_stats)
-                        {
+                    if let Some(prefix_hint) = read_options.prefix_hint.as_ref() {
+                        if !hit_sstable_bloom_filter(
+                            sstable.value(),
+                            prefix_hint,
+                            read_options.table_id.table_id(),
+                            &mut local_stats,
+                        ) {
                             continue;
                         }
                     }
                     iters.push(SstableIterator::new(
                         sstable,
                         self.sstable_store.clone(),
-                        Arc::new(SstableIteratorReadOptions::default()),
+                        sst_read_options.clone(),
                     ));
                     overlapping_iter_count += 1;
                 }
@@ -123,6 +123,7 @@ pub fn default_builder_opt_for_test() -> SstableBuilderOptions {
         restart_interval: DEFAULT_RESTART_INTERVAL,
         bloom_false_positive: 0.1,
         compression_algorithm: CompressionAlgorithm::None,
+        zstd_dict_capacity: 0,
     }
 }
 
@@ -131,6 +132,7 @@ pub fn default_writer_opt_for_test() -> SstableWriterOptions {
         capacity_hint: None,
         tracker: None,
         policy: CachePolicy::Disable,
+        io_limiter: None,
     }
 }
 
@@ -201,6 +203,7 @@ pub async fn gen_test_sstable_inner<B: AsRef<[u8]>>(
         capacity_hint: None,
         tracker: None,
         policy,
+        io_limiter: None,
     };
     let writer = sstable_store.clone().create_sst_writer(sst_id, writer_opts);
     let mut b = SstableBuilder::for_test(sst_id, writer, opts);
@@ -18,6 +18,9 @@ use std::ops::Bound::{Excluded, Included, Unbounded};
 use std::ops::RangeBounds;
 use std::sync::atomic::{AtomicU64, Ordering as AtomicOrdering};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use parking_lot::Mutex;
 
 use risingwave_common::catalog::TableId;
 use risingwave_hummock_sdk::key::{bound_table_key_range, user_key, TableKey, UserKey};
@@ -291,6 +294,73 @@ impl Drop for MemoryTracker {
     }
 }
 
+struct IoTokenBucket {
+    /// May go negative: an oversized request is granted immediately and pays for itself by
+    /// delaying subsequent requests.
+    available_bytes: i64,
+    last_refill: Instant,
+}
+
+/// Limits the bandwidth of a group of I/O operations with a token bucket. The limit can be
+/// adjusted at runtime without interrupting waiters, and a zero limit disables throttling.
+pub struct IoLimiter {
+    bytes_per_sec: AtomicU64,
+    bucket: Mutex<IoTokenBucket>,
+}
+
+impl IoLimiter {
+    pub fn new(bytes_per_sec: u64) -> Self {
+        Self {
+            bytes_per_sec: AtomicU64::new(bytes_per_sec),
+            bucket: Mutex::new(IoTokenBucket {
+                available_bytes: bytes_per_sec as i64,
+                last_refill: Instant::now(),
+            }),
+        }
+    }
+
+    pub fn unlimited() -> Arc<Self> {
+        Arc::new(Self::new(0))
+    }
+
+    pub fn bytes_per_sec(&self) -> u64 {
+        self.bytes_per_sec.load(AtomicOrdering::Relaxed)
+    }
+
+    /// Sets a new bandwidth limit. `0` means unlimited.
+    pub fn set_bytes_per_sec(&self, bytes_per_sec: u64) {
+        self.bytes_per_sec
+            .store(bytes_per_sec, AtomicOrdering::Relaxed);
+    }
+
+    /// Accounts `bytes` of I/O against the budget, sleeping until the bucket catches up if it is
+    /// exhausted. The burst capacity is one second worth of bandwidth.
+    pub async fn acquire(&self, bytes: u64) {
+        let bytes_per_sec = self.bytes_per_sec();
+        if bytes_per_sec == 0 {
+            return;
+        }
+        let wait = {
+            let mut bucket = self.bucket.lock();
+            let now = Instant::now();
+            let refill = now.duration_since(bucket.last_refill).as_secs_f64();
+            let refilled = bucket
+                .available_bytes
+                .saturating_add((refill * bytes_per_sec as f64) as i64);
+            bucket.available_bytes = std::cmp::min(refilled, bytes_per_sec as i64) - bytes as i64;
+            bucket.last_refill = now;
+            if bucket.available_bytes < 0 {
+                Duration::from_secs_f64(-bucket.available_bytes as f64 / bytes_per_sec as f64)
+            } else {
+                Duration::ZERO
+            }
+        };
+        if !wait.is_zero() {
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
 /// Check whether the items in `sub_iter` is a subset of the items in `full_iter`, and meanwhile
 /// preserve the order.
 pub fn check_subset_preserve_order<T: Eq>(
@@ -319,7 +389,7 @@ mod tests {
 
     use futures::FutureExt;
 
-    use crate::hummock::utils::MemoryLimiter;
+    use crate::hummock::utils::{IoLimiter, MemoryLimiter};
 
     async fn assert_pending(future: &mut (impl Future + Unpin)) {
         for _ in 0..10 {
@@ -349,4 +419,18 @@ mod tests {
         drop(tracker3);
         assert_eq!(0, memory_limiter.get_memory_usage());
     }
+
+    #[tokio::test]
+    async fn test_io_limiter() {
+        // A zero limit never throttles.
+        let limiter = IoLimiter::unlimited();
+        limiter.acquire(u64::MAX).await;
+
+        let limiter = IoLimiter::new(1024);
+        // The burst budget admits small requests immediately.
+        limiter.acquire(128).await;
+        // A request exceeding the remaining budget has to wait.
+        let mut future = limiter.acquire(1 << 20).boxed();
+        assert_pending(&mut future).await;
+    }
 }
@@ -39,6 +39,9 @@ pub struct HummockStateStoreMetrics {
     pub read_req_bloom_filter_positive_counts: GenericCounterVec<AtomicU64>,
     pub read_req_positive_but_non_exist_counts: GenericCounterVec<AtomicU64>,
     pub read_req_check_bloom_filter_counts: GenericCounterVec<AtomicU64>,
+
+    pub write_batch_dedup_input_item_counts: GenericCounterVec<AtomicU64>,
+    pub write_batch_dedup_dropped_item_counts: GenericCounterVec<AtomicU64>,
 }
 
 impl HummockStateStoreMetrics {
@@ -133,6 +136,22 @@ impl HummockStateStoreMetrics {
         )
         .unwrap();
 
+        let write_batch_dedup_input_item_counts = register_int_counter_vec_with_registry!(
+            "state_store_write_batch_dedup_input_item_counts",
+            "Total number of items that entered write batch dedup",
+            &["table_id"],
+            registry
+        )
+        .unwrap();
+
+        let write_batch_dedup_dropped_item_counts = register_int_counter_vec_with_registry!(
+            "state_store_write_batch_dedup_dropped_item_counts",
+            "Total number of items dropped by write batch dedup as overwritten within the batch",
+            &["table_id"],
+            registry
+        )
+        .unwrap();
+
         Self {
             bloom_filter_true_negative_counts,
             bloom_filter_check_counts,
@@ -145,6 +164,8 @@ impl HummockStateStoreMetrics {
             read_req_bloom_filter_positive_counts,
             read_req_positive_but_non_exist_counts,
             read_req_check_bloom_filter_counts,
+            write_batch_dedup_input_item_counts,
+            write_batch_dedup_dropped_item_counts,
         }
     }
 
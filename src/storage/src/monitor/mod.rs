@@ -28,4 +28,10 @@ pub use compactor_metrics::*;
 
 mod local_metrics;
 pub use local_metrics::*;
+
+mod operation_tracer;
+pub use operation_tracer::*;
+
+mod table_access_stats;
+pub use table_access_stats::*;
 pub use risingwave_object_store::object::object_metrics::ObjectStoreMetrics;
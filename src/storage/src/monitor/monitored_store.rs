@@ -23,7 +23,9 @@ use risingwave_common::catalog::TableId;
 use risingwave_hummock_sdk::HummockReadEpoch;
 use tracing::error;
 
-use super::MonitoredStorageMetrics;
+use super::{
+    global_operation_tracer, global_table_access_stats, MonitoredStorageMetrics, TracedOpType,
+};
 use crate::error::{StorageError, StorageResult};
 use crate::hummock::sstable_store::SstableStoreRef;
 use crate::hummock::{HummockStorage, SstableIdManagerRef};
@@ -56,6 +58,7 @@ impl<S: StateStoreRead> MonitoredStateStore<S> {
     async fn monitored_iter(
         &self,
         table_id: TableId,
+        trace_key: Option<Vec<u8>>,
         iter_stream_future: impl Future<Output = StorageResult<S::IterStream>>,
     ) -> StorageResult<MonitoredStateStoreIterStream<S>> {
         // start time takes iterator build time into account
@@ -72,6 +75,16 @@ impl<S: StateStoreRead> MonitoredStateStore<S> {
             .iter_duration
             .with_label_values(&[table_id_label.as_str()])
             .observe(start_time.elapsed().as_secs_f64());
+        if let Some(key) = trace_key {
+            // The traced duration only covers iterator creation; the scan itself is accounted in
+            // the iterator metrics.
+            global_operation_tracer().record(
+                table_id.table_id(),
+                TracedOpType::Iter,
+                start_time.elapsed(),
+                &key,
+            );
+        }
         // statistics of iter in process count to estimate the read ops in the same time
         self.storage_metrics
             .iter_in_process_counts
@@ -109,7 +122,12 @@ impl<S: StateStoreRead> StateStoreRead for MonitoredStateStore<S> {
         read_options: ReadOptions,
     ) -> Self::GetFuture<'_> {
         async move {
+            let table_id = read_options.table_id.table_id();
             let table_id_label = read_options.table_id.to_string();
+            global_table_access_stats().record_read(table_id);
+            let trace_start = global_operation_tracer()
+                .should_sample(table_id)
+                .then(minstant::Instant::now);
             let timer = self
                 .storage_metrics
                 .get_duration
@@ -122,6 +140,14 @@ impl<S: StateStoreRead> StateStoreRead for MonitoredStateStore<S> {
                 .await
                 .inspect_err(|e| error!("Failed in get: {:?}", e))?;
             timer.observe_duration();
+            if let Some(start) = trace_start {
+                global_operation_tracer().record(
+                    table_id,
+                    TracedOpType::Get,
+                    start.elapsed(),
+                    key,
+                );
+            }
 
             self.storage_metrics
                 .get_key_size
@@ -144,8 +170,16 @@ impl<S: StateStoreRead> StateStoreRead for MonitoredStateStore<S> {
         epoch: u64,
         read_options: ReadOptions,
     ) -> Self::IterFuture<'_> {
+        global_table_access_stats().record_read(read_options.table_id.table_id());
+        let trace_key = global_operation_tracer()
+            .should_sample(read_options.table_id.table_id())
+            .then(|| match &key_range.0 {
+                Bound::Included(key) | Bound::Excluded(key) => key.clone(),
+                Bound::Unbounded => vec![],
+            });
         self.monitored_iter(
             read_options.table_id,
+            trace_key,
             self.inner.iter(key_range, epoch, read_options),
         )
     }
@@ -161,7 +195,16 @@ impl<S: StateStoreWrite> StateStoreWrite for MonitoredStateStore<S> {
         write_options: WriteOptions,
     ) -> Self::IngestBatchFuture<'_> {
         async move {
+            let table_id = write_options.table_id.table_id();
             let table_id_label = write_options.table_id.to_string();
+            let trace = global_operation_tracer().should_sample(table_id).then(|| {
+                let key = kv_pairs
+                    .first()
+                    .map(|(key, _)| key.to_vec())
+                    .or_else(|| delete_ranges.first().map(|(key, _)| key.to_vec()))
+                    .unwrap_or_default();
+                (key, minstant::Instant::now())
+            });
             self.storage_metrics
                 .write_batch_tuple_counts
                 .with_label_values(&[table_id_label.as_str()])
@@ -178,6 +221,14 @@ impl<S: StateStoreWrite> StateStoreWrite for MonitoredStateStore<S> {
                 .await
                 .inspect_err(|e| error!("Failed in ingest_batch: {:?}", e))?;
             timer.observe_duration();
+            if let Some((key, start)) = trace {
+                global_operation_tracer().record(
+                    table_id,
+                    TracedOpType::IngestBatch,
+                    start.elapsed(),
+                    &key,
+                );
+            }
 
             self.storage_metrics
                 .write_batch_size
@@ -0,0 +1,183 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashSet, VecDeque};
+use std::hash::Hasher;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::LazyLock;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use parking_lot::RwLock;
+use rand::Rng;
+
+/// Maximum number of records kept in the ring buffer. The oldest records are evicted first.
+const TRACE_BUFFER_CAPACITY: usize = 4096;
+/// Number of leading key bytes that contribute to the redacted key hash.
+const TRACE_KEY_PREFIX_LEN: usize = 16;
+
+/// Kind of a traced storage operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TracedOpType {
+    Get,
+    Iter,
+    IngestBatch,
+}
+
+impl TracedOpType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TracedOpType::Get => "get",
+            TracedOpType::Iter => "iter",
+            TracedOpType::IngestBatch => "ingest_batch",
+        }
+    }
+}
+
+/// One sampled storage operation on a traced table. The key is redacted: only a hash of its
+/// prefix is kept.
+#[derive(Debug, Clone)]
+pub struct TraceRecord {
+    pub table_id: u32,
+    pub op: TracedOpType,
+    pub duration_us: u64,
+    /// Hash of the first [`TRACE_KEY_PREFIX_LEN`] bytes of the key involved.
+    pub key_prefix_hash: u64,
+    /// Unix timestamp in milliseconds when the operation finished.
+    pub recorded_at_ms: u64,
+}
+
+/// A sampling tracer for storage operations on a configured set of state tables.
+///
+/// For get/iter/write on a traced table, a configured fraction of operations is recorded with
+/// timing and a hash-redacted key prefix into a fixed-size ring buffer, which can be fetched
+/// through the compute node `StorageTrace` RPC. This enables targeted debugging of a single
+/// misbehaving MV state table without turning on full tracing.
+pub struct OperationTracer {
+    /// Fast path: when no table is traced, `should_sample` returns without taking the lock.
+    enabled: AtomicBool,
+    inner: RwLock<OperationTracerInner>,
+}
+
+struct OperationTracerInner {
+    traced_table_ids: HashSet<u32>,
+    sample_ratio: f64,
+    buffer: VecDeque<TraceRecord>,
+}
+
+impl OperationTracer {
+    fn new() -> Self {
+        Self {
+            enabled: AtomicBool::new(false),
+            inner: RwLock::new(OperationTracerInner {
+                traced_table_ids: HashSet::new(),
+                sample_ratio: 0.0,
+                buffer: VecDeque::new(),
+            }),
+        }
+    }
+
+    /// Replaces the traced table set and sample ratio. Existing records are kept.
+    pub fn configure(&self, traced_table_ids: HashSet<u32>, sample_ratio: f64) {
+        let sample_ratio = sample_ratio.clamp(0.0, 1.0);
+        let mut inner = self.inner.write();
+        self.enabled.store(
+            !traced_table_ids.is_empty() && sample_ratio > 0.0,
+            Ordering::Relaxed,
+        );
+        inner.traced_table_ids = traced_table_ids;
+        inner.sample_ratio = sample_ratio;
+    }
+
+    /// Returns whether an operation on `table_id` should be recorded this time.
+    pub fn should_sample(&self, table_id: u32) -> bool {
+        if !self.enabled.load(Ordering::Relaxed) {
+            return false;
+        }
+        let inner = self.inner.read();
+        inner.traced_table_ids.contains(&table_id)
+            && rand::thread_rng().gen_bool(inner.sample_ratio)
+    }
+
+    /// Records one sampled operation. `key` is redacted to a hash of its prefix.
+    pub fn record(&self, table_id: u32, op: TracedOpType, duration: Duration, key: &[u8]) {
+        let record = TraceRecord {
+            table_id,
+            op,
+            duration_us: duration.as_micros() as u64,
+            key_prefix_hash: redact_key(key),
+            recorded_at_ms: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_millis() as u64)
+                .unwrap_or(0),
+        };
+        let mut inner = self.inner.write();
+        if inner.buffer.len() == TRACE_BUFFER_CAPACITY {
+            inner.buffer.pop_front();
+        }
+        inner.buffer.push_back(record);
+    }
+
+    /// Takes a snapshot of all buffered records, oldest first.
+    pub fn collect(&self) -> Vec<TraceRecord> {
+        self.inner.read().buffer.iter().cloned().collect()
+    }
+}
+
+fn redact_key(key: &[u8]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    hasher.write(&key[..key.len().min(TRACE_KEY_PREFIX_LEN)]);
+    hasher.finish()
+}
+
+/// The process-wide operation tracer, configured from `StorageOpts` when the state store is
+/// created.
+pub fn global_operation_tracer() -> &'static OperationTracer {
+    static GLOBAL_OPERATION_TRACER: LazyLock<OperationTracer> = LazyLock::new(OperationTracer::new);
+    &GLOBAL_OPERATION_TRACER
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sampling_and_ring_buffer() {
+        let tracer = OperationTracer::new();
+        // Not configured: never samples.
+        assert!(!tracer.should_sample(1));
+
+        tracer.configure(HashSet::from_iter([1]), 1.0);
+        assert!(tracer.should_sample(1));
+        assert!(!tracer.should_sample(2));
+
+        for i in 0..TRACE_BUFFER_CAPACITY + 10 {
+            tracer.record(
+                1,
+                TracedOpType::Get,
+                Duration::from_micros(i as u64),
+                b"some_key",
+            );
+        }
+        let records = tracer.collect();
+        assert_eq!(records.len(), TRACE_BUFFER_CAPACITY);
+        // The oldest 10 records have been evicted.
+        assert_eq!(records[0].duration_us, 10);
+        // Same key prefix hashes to the same redacted value.
+        assert_eq!(records[0].key_prefix_hash, records[1].key_prefix_hash);
+
+        tracer.configure(HashSet::new(), 1.0);
+        assert!(!tracer.should_sample(1));
+    }
+}
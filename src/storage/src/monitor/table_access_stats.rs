@@ -0,0 +1,118 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::LazyLock;
+use std::time::{Duration, Instant};
+
+use parking_lot::RwLock;
+
+/// Interval after which the access counters are halved, so that the statistics reflect recent
+/// traffic rather than lifetime totals.
+const ACCESS_STATS_DECAY_INTERVAL: Duration = Duration::from_secs(60);
+/// Minimum decayed read count for a table to be considered hot.
+const HOT_TABLE_ACCESS_THRESHOLD: u64 = 100;
+
+/// Per-table read counters with periodic decay, used to identify the tables that are hot right
+/// now, e.g. to decide which SST metas are worth refilling into the cache after a large version
+/// switch.
+pub struct TableAccessStats {
+    inner: RwLock<TableAccessStatsInner>,
+}
+
+struct TableAccessStatsInner {
+    counters: HashMap<u32, AtomicU64>,
+    last_decay: Instant,
+}
+
+impl TableAccessStats {
+    fn new() -> Self {
+        Self {
+            inner: RwLock::new(TableAccessStatsInner {
+                counters: HashMap::new(),
+                last_decay: Instant::now(),
+            }),
+        }
+    }
+
+    /// Records one read (get or iter) on a table. Takes the write lock only the first time a
+    /// table is seen.
+    pub fn record_read(&self, table_id: u32) {
+        if let Some(counter) = self.inner.read().counters.get(&table_id) {
+            counter.fetch_add(1, Ordering::Relaxed);
+            return;
+        }
+        self.inner
+            .write()
+            .counters
+            .entry(table_id)
+            .or_default()
+            .fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Returns the tables whose decayed read count exceeds the hot threshold. The pending decay
+    /// is applied lazily here, and counters that decayed to zero are dropped to keep the map
+    /// bounded.
+    pub fn hot_table_ids(&self) -> HashSet<u32> {
+        let mut inner = self.inner.write();
+        let periods =
+            (inner.last_decay.elapsed().as_secs() / ACCESS_STATS_DECAY_INTERVAL.as_secs()) as u32;
+        if periods > 0 {
+            let shift = periods.min(u64::BITS - 1);
+            for counter in inner.counters.values_mut() {
+                let decayed = counter.load(Ordering::Relaxed) >> shift;
+                counter.store(decayed, Ordering::Relaxed);
+            }
+            inner.counters.retain(|_, c| c.load(Ordering::Relaxed) > 0);
+            inner.last_decay = Instant::now();
+        }
+        inner
+            .counters
+            .iter()
+            .filter(|(_, c)| c.load(Ordering::Relaxed) >= HOT_TABLE_ACCESS_THRESHOLD)
+            .map(|(table_id, _)| *table_id)
+            .collect()
+    }
+}
+
+/// The process-wide table access statistics, fed by the monitored state store.
+pub fn global_table_access_stats() -> &'static TableAccessStats {
+    static GLOBAL_TABLE_ACCESS_STATS: LazyLock<TableAccessStats> =
+        LazyLock::new(TableAccessStats::new);
+    &GLOBAL_TABLE_ACCESS_STATS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hot_table_ids() {
+        let stats = TableAccessStats::new();
+        assert!(stats.hot_table_ids().is_empty());
+
+        for _ in 0..HOT_TABLE_ACCESS_THRESHOLD {
+            stats.record_read(1);
+        }
+        for _ in 0..10 {
+            stats.record_read(2);
+        }
+        assert_eq!(stats.hot_table_ids(), HashSet::from_iter([1]));
+
+        // After two decay periods the counter of table 1 drops below the threshold.
+        stats.inner.write().last_decay = Instant::now() - 2 * ACCESS_STATS_DECAY_INTERVAL;
+        assert!(stats.hot_table_ids().is_empty());
+    }
+}
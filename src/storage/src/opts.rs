@@ -22,6 +22,9 @@ pub struct StorageOpts {
     pub block_size_kb: u32,
     /// False positive probability of bloom filter.
     pub bloom_false_positive: f64,
+    /// Maximum size in KB of the zstd dictionary trained and embedded per SST. 0 disables
+    /// dictionary compression.
+    pub sstable_zstd_dict_size_kb: u32,
     /// parallelism while syncing share buffers into L0 SST. Should NOT be 0.
     pub share_buffers_sync_parallelism: u32,
     /// Worker threads number of dedicated tokio runtime for share buffer compaction. 0 means use
@@ -36,16 +39,24 @@ pub struct StorageOpts {
     pub data_directory: String,
     /// Whether to enable write conflict detection
     pub write_conflict_detection_enabled: bool,
+    /// Whether to deduplicate writes to the same key within one write batch, keeping the last
+    /// value.
+    pub write_batch_dedup_enabled: bool,
     /// Capacity of sstable block cache.
     pub block_cache_capacity_mb: usize,
     /// Capacity of sstable meta cache.
     pub meta_cache_capacity_mb: usize,
+    /// Budget for preloading the meta of newly committed SSTs on each version update. 0 disables
+    /// preloading.
+    pub sst_meta_preload_budget_mb: usize,
     pub disable_remote_compactor: bool,
     pub enable_local_spill: bool,
     /// Local object store root. We should call `get_local_object_store` to get the object store.
     pub local_object_store: String,
     /// Number of tasks shared buffer can upload in parallel.
     pub share_buffer_upload_concurrency: usize,
+    /// Maximum bytes per second admitted to SST upload. 0 disables shaping.
+    pub sst_upload_max_bytes_per_sec: u64,
     /// Capacity of sstable meta cache.
     pub compactor_memory_limit_mb: usize,
     /// Number of SST ids fetched from meta per RPC
@@ -55,7 +66,13 @@ pub struct StorageOpts {
     /// Max sub compaction task numbers
     pub max_sub_compaction: u32,
     pub max_concurrent_compaction_task_number: u64,
-    pub enable_state_store_v1: bool,
+    /// Interval of the background SST scrub in the compactor. 0 disables scrubbing.
+    pub sst_scrub_interval_sec: u64,
+    /// State table ids whose storage operations are sampled into the in-memory trace ring
+    /// buffer. Empty disables operation tracing.
+    pub traced_table_ids: Vec<u32>,
+    /// Fraction of get/iter/write operations on traced tables to record, in `[0, 1]`.
+    pub trace_sample_ratio: f64,
 
     pub file_cache_dir: String,
     pub file_cache_capacity_mb: usize,
@@ -63,6 +80,7 @@ pub struct StorageOpts {
     pub file_cache_file_fallocate_unit_mb: usize,
     pub file_cache_meta_fallocate_unit_mb: usize,
     pub file_cache_file_max_write_size_mb: usize,
+    pub file_cache_enable_direct_io: bool,
 
     /// The storage url for storing backups.
     pub backup_storage_url: String,
@@ -82,6 +100,7 @@ impl From<&RwConfig> for StorageOpts {
             sstable_size_mb: c.storage.sstable_size_mb,
             block_size_kb: c.storage.block_size_kb,
             bloom_false_positive: c.storage.bloom_false_positive,
+            sstable_zstd_dict_size_kb: c.storage.sstable_zstd_dict_size_kb,
             share_buffers_sync_parallelism: c.storage.share_buffers_sync_parallelism,
             share_buffer_compaction_worker_threads_number: c
                 .storage
@@ -90,24 +109,30 @@ impl From<&RwConfig> for StorageOpts {
             state_store: c.storage.state_store.clone(),
             data_directory: c.storage.data_directory.clone(),
             write_conflict_detection_enabled: c.storage.write_conflict_detection_enabled,
+            write_batch_dedup_enabled: c.storage.write_batch_dedup_enabled,
             block_cache_capacity_mb: c.storage.block_cache_capacity_mb,
             meta_cache_capacity_mb: c.storage.meta_cache_capacity_mb,
+            sst_meta_preload_budget_mb: c.storage.sst_meta_preload_budget_mb,
             disable_remote_compactor: c.storage.disable_remote_compactor,
             enable_local_spill: c.storage.enable_local_spill,
             local_object_store: c.storage.local_object_store.clone(),
             share_buffer_upload_concurrency: c.storage.share_buffer_upload_concurrency,
+            sst_upload_max_bytes_per_sec: c.storage.sst_upload_max_bytes_per_sec,
             compactor_memory_limit_mb: c.storage.compactor_memory_limit_mb,
             sstable_id_remote_fetch_number: c.storage.sstable_id_remote_fetch_number,
             min_sst_size_for_streaming_upload: c.storage.min_sst_size_for_streaming_upload,
             max_sub_compaction: c.storage.max_sub_compaction,
             max_concurrent_compaction_task_number: c.storage.max_concurrent_compaction_task_number,
-            enable_state_store_v1: c.storage.enable_state_store_v1,
+            sst_scrub_interval_sec: c.storage.sst_scrub_interval_sec,
+            traced_table_ids: c.storage.traced_table_ids.clone(),
+            trace_sample_ratio: c.storage.trace_sample_ratio,
             file_cache_dir: c.storage.file_cache.dir.clone(),
             file_cache_capacity_mb: c.storage.file_cache.capacity_mb,
             file_cache_total_buffer_capacity_mb: c.storage.file_cache.total_buffer_capacity_mb,
             file_cache_file_fallocate_unit_mb: c.storage.file_cache.cache_file_fallocate_unit_mb,
             file_cache_meta_fallocate_unit_mb: c.storage.file_cache.cache_meta_fallocate_unit_mb,
             file_cache_file_max_write_size_mb: c.storage.file_cache.cache_file_max_write_size_mb,
+            file_cache_enable_direct_io: c.storage.file_cache.enable_direct_io,
             backup_storage_url: c.backup.storage_url.clone(),
             backup_storage_directory: c.backup.storage_directory.clone(),
         }
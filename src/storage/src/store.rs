@@ -21,6 +21,7 @@ use futures::{Stream, StreamExt, TryStreamExt};
 use futures_async_stream::try_stream;
 use risingwave_common::catalog::TableId;
 use risingwave_common::util::epoch::Epoch;
+use risingwave_common::util::ordered::OrderedRowSerde;
 use risingwave_hummock_sdk::key::FullKey;
 use risingwave_hummock_sdk::{HummockReadEpoch, LocalSstableInfo};
 
@@ -273,12 +274,67 @@ pub trait LocalStateStore: StateStoreRead + StateStoreWrite + StaticSendSync {
     }
 }
 
+/// A prefix of the primary key shared by all keys selected by a read, used to prune SSTs by
+/// their bloom filters.
+#[derive(Clone, Debug)]
+pub struct PrefixHint {
+    /// The serialized prefix columns, excluding the table and vnode prefixes.
+    pub bytes: Bytes,
+    /// The byte offset past the end of each serialized column in `bytes`. The offsets allow
+    /// truncating the hint to the bloom filter prefix length recorded in each SST, so that the
+    /// filter key is computed consistently with the SST's writer.
+    pub column_ends: Vec<usize>,
+    /// The catalog-configured `read_prefix_len_hint` of the table. SSTs that do not record a
+    /// bloom filter prefix length were written with this many prefix columns.
+    pub read_prefix_len_hint: usize,
+}
+
+impl PrefixHint {
+    /// Builds a hint from the serialization of the first `columns` pk columns in `encoded`,
+    /// which must not be zero. Trailing bytes of `encoded` beyond these columns are dropped.
+    pub fn new(
+        encoded: Bytes,
+        serializer: &OrderedRowSerde,
+        columns: usize,
+        read_prefix_len_hint: usize,
+    ) -> memcomparable::Result<Self> {
+        let column_ends = serializer.deserialize_prefix_column_ends(&encoded, columns)?;
+        let bytes = encoded.slice(..*column_ends.last().unwrap());
+        Ok(Self {
+            bytes,
+            column_ends,
+            read_prefix_len_hint,
+        })
+    }
+
+    /// A hint of a single opaque key column covering the whole byte string, for hints that are
+    /// not the serialization of pk columns, e.g. raw byte keys in tests.
+    pub fn from_unstructured(bytes: Bytes) -> Self {
+        Self {
+            column_ends: vec![bytes.len()],
+            read_prefix_len_hint: 1,
+            bytes,
+        }
+    }
+
+    /// The filter key consistent with what the writer of an SST recording
+    /// `bloom_filter_prefix_columns` hashed for this table, or `None` if the hint has fewer
+    /// columns, in which case the bloom filter cannot be used for this read.
+    pub fn filter_key_for(&self, bloom_filter_prefix_columns: Option<usize>) -> Option<&[u8]> {
+        let columns = bloom_filter_prefix_columns.unwrap_or(self.read_prefix_len_hint);
+        if columns == 0 || columns > self.column_ends.len() {
+            return None;
+        }
+        Some(&self.bytes[..self.column_ends[columns - 1]])
+    }
+}
+
 #[derive(Default, Clone)]
 pub struct ReadOptions {
     /// A hint for prefix key to check bloom filter.
     /// If the `prefix_hint` is not None, it should be included in
     /// `key` or `key_range` in the read API.
-    pub prefix_hint: Option<Bytes>,
+    pub prefix_hint: Option<PrefixHint>,
     pub ignore_range_tombstone: bool,
 
     pub retention_seconds: Option<u32>,
@@ -42,7 +42,7 @@ use crate::row_serde::row_serde_util::{
     parse_raw_key_to_vnode_and_key, serialize_pk, serialize_pk_with_vnode,
 };
 use crate::row_serde::{find_columns_by_ids, ColumnMapping};
-use crate::store::ReadOptions;
+use crate::store::{PrefixHint, ReadOptions};
 use crate::table::{compute_vnode, Distribution, TableIter, DEFAULT_VNODE};
 use crate::StateStore;
 
@@ -294,9 +294,14 @@ impl<S: StateStore> StorageTable<S> {
             serialize_pk_with_vnode(&pk, &self.pk_serializer, self.compute_vnode_by_pk(&pk));
         assert!(pk.len() <= self.pk_indices.len());
 
-        let prefix_hint = if self.read_prefix_len_hint != 0 && self.read_prefix_len_hint == pk.len()
-        {
-            Some(serialized_pk.slice(VirtualNode::SIZE..))
+        let prefix_hint_columns = self.read_prefix_len_hint.min(pk.len());
+        let prefix_hint = if prefix_hint_columns != 0 {
+            Some(PrefixHint::new(
+                serialized_pk.slice(VirtualNode::SIZE..),
+                &self.pk_serializer,
+                prefix_hint_columns,
+                self.read_prefix_len_hint,
+            )?)
         } else {
             None
         };
@@ -370,7 +375,7 @@ impl<S: StateStore> StorageTable<S> {
     /// `vnode_hint`, and merge or concat them by given `ordered`.
     async fn iter_with_encoded_key_range<R, B>(
         &self,
-        prefix_hint: Option<Bytes>,
+        prefix_hint: Option<PrefixHint>,
         encoded_key_range: R,
         wait_epoch: HummockReadEpoch,
         vnode_hint: Option<VirtualNode>,
@@ -537,18 +542,22 @@ impl<S: StateStore> StorageTable<S> {
             .map(|index| self.pk_indices[index])
             .collect_vec();
 
-        let prefix_hint = if self.read_prefix_len_hint != 0
-            && self.read_prefix_len_hint <= pk_prefix.len()
-        {
+        // A hint of fewer columns than `read_prefix_len_hint` can still be checked against SSTs
+        // recording a bloom filter prefix length it covers, and its length is observed by the
+        // storage layer to adapt the filter keys of newly built SSTs.
+        let prefix_hint_columns = self.read_prefix_len_hint.min(pk_prefix.len());
+        let prefix_hint = if prefix_hint_columns != 0 {
             let encoded_prefix = if let Bound::Included(start_key) = start_key.as_ref() {
                 start_key
             } else {
                 unreachable!()
             };
-            let prefix_len = self
-                .pk_serializer
-                .deserialize_prefix_len(encoded_prefix, self.read_prefix_len_hint)?;
-            Some(Bytes::from(encoded_prefix[..prefix_len].to_vec()))
+            Some(PrefixHint::new(
+                Bytes::from(encoded_prefix.clone()),
+                &self.pk_serializer,
+                prefix_hint_columns,
+                self.read_prefix_len_hint,
+            )?)
         } else {
             trace!(
                     "iter_with_pk_bounds dist_key_indices table_id {} not match prefix pk_prefix {:?} dist_key_indices {:?} pk_prefix_indices {:?}",
@@ -0,0 +1,192 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use futures::{Stream, TryStreamExt};
+use risingwave_common::array::stream_chunk::Op;
+use risingwave_common::array::StreamChunk;
+use risingwave_common::buffer::Bitmap;
+use risingwave_common::row::{OwnedRow, Row};
+use risingwave_common::types::{ScalarImpl, ToOwnedDatum};
+use risingwave_common::util::epoch::EpochPair;
+use risingwave_storage::StateStore;
+
+use crate::common::table::state_table::StateTable;
+use crate::executor::StreamExecutorResult;
+
+/// Number of columns prepended to the payload in the log store table, i.e. `vnode`, `epoch`,
+/// `seq_id` and `op`.
+pub const LOG_STORE_PREFIX_COLUMNS: usize = 4;
+
+/// Position of a log entry, used to resume a replay from where it stopped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct LogStoreCursor {
+    pub epoch: u64,
+    pub seq_id: i64,
+}
+
+/// A log entry read back from the log store.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogStoreRow {
+    pub cursor: LogStoreCursor,
+    pub op: Op,
+    pub row: OwnedRow,
+}
+
+/// [`SinkLogStore`] buffers the change stream consumed by a sink in a state table, so that the
+/// changes can be replayed to the sink target without re-running the upstream computation. This
+/// is the foundation for decoupling the sink from the streaming graph, e.g. delivering
+/// exactly-once to a slow target without back-pressuring the upstream.
+///
+/// Each change is logged as `vnode | epoch | seq_id | op | payload columns` with
+/// `(vnode, epoch, seq_id)` as the primary key. The leading vnode column partitions the table
+/// among the parallel sink actors: each actor writes under one of its own vnodes and reads back
+/// the entries of all vnodes it currently owns, so the log follows scaling like any other
+/// state table. Delivered entries must be pruned with [`Self::truncate`], otherwise the log
+/// grows with every epoch.
+pub struct SinkLogStore<S: StateStore> {
+    state_table: StateTable<S>,
+    actor_id: u32,
+    /// The vnode under which this actor logs its entries: the first vnode it owns.
+    own_vnode: i16,
+    /// The upper 32 bits of every sequence number. Derived from the actor id so that the
+    /// sequence numbers written by parallel sink actors never collide.
+    seq_id_base: i64,
+    /// The lower 32 bits of the next sequence number, reset on every epoch.
+    next_seq_id: i64,
+}
+
+impl<S: StateStore> SinkLogStore<S> {
+    pub fn new(state_table: StateTable<S>, actor_id: u32) -> Self {
+        let own_vnode = Self::first_own_vnode(&state_table);
+        Self {
+            state_table,
+            actor_id,
+            own_vnode,
+            seq_id_base: (actor_id as i64) << 32,
+            next_seq_id: 0,
+        }
+    }
+
+    fn first_own_vnode(state_table: &StateTable<S>) -> i16 {
+        state_table
+            .vnodes()
+            .iter_ones()
+            .next()
+            .expect("a sink actor must own at least one vnode") as i16
+    }
+
+    pub fn actor_id(&self) -> u32 {
+        self.actor_id
+    }
+
+    pub fn init_epoch(&mut self, epoch: EpochPair) {
+        self.state_table.init_epoch(epoch);
+    }
+
+    /// Update the vnode bitmap of the underlying state table after scaling.
+    pub fn update_vnode_bitmap(&mut self, new_vnodes: Arc<Bitmap>) {
+        let _ = self.state_table.update_vnode_bitmap(new_vnodes);
+        self.own_vnode = Self::first_own_vnode(&self.state_table);
+    }
+
+    /// Append all visible rows of the chunk to the log of the current epoch.
+    pub fn write_chunk(&mut self, chunk: &StreamChunk) {
+        let epoch = self.state_table.epoch() as i64;
+        for (op, row) in chunk.rows() {
+            let seq_id = self.seq_id_base | self.next_seq_id;
+            self.next_seq_id += 1;
+
+            let mut datums = Vec::with_capacity(LOG_STORE_PREFIX_COLUMNS + row.len());
+            datums.push(Some(ScalarImpl::Int16(self.own_vnode)));
+            datums.push(Some(ScalarImpl::Int64(epoch)));
+            datums.push(Some(ScalarImpl::Int64(seq_id)));
+            datums.push(Some(ScalarImpl::Int16(op.to_protobuf() as i16)));
+            datums.extend(row.iter().map(|d| d.to_owned_datum()));
+            self.state_table.insert(OwnedRow::new(datums));
+        }
+    }
+
+    /// Persist the log entries of the current epoch.
+    pub async fn commit(&mut self, new_epoch: EpochPair) -> StreamExecutorResult<()> {
+        self.next_seq_id = 0;
+        self.state_table.commit(new_epoch).await
+    }
+
+    /// Update the epoch of the underlying state table when no entry is logged and none is
+    /// truncated in the current epoch.
+    pub fn commit_no_data_expected(&mut self, new_epoch: EpochPair) {
+        self.state_table.commit_no_data_expected(new_epoch);
+    }
+
+    /// Replay the logged changes after the given cursor, or from the beginning if `None`. The
+    /// entries are yielded in `(epoch, seq_id)` order per vnode.
+    pub async fn replay(
+        &self,
+        after: Option<LogStoreCursor>,
+    ) -> StreamExecutorResult<impl Stream<Item = StreamExecutorResult<LogStoreRow>> + '_> {
+        let stream = self.state_table.iter().await?;
+        Ok(stream
+            .and_then(|row| futures::future::ready(Self::deserialize(row)))
+            .try_filter(move |entry| {
+                futures::future::ready(after.map_or(true, |cursor| entry.cursor > cursor))
+            }))
+    }
+
+    /// Remove the log entries at or before the given cursor, after they are durably delivered
+    /// to the sink target. The deletion takes effect on the next `commit`.
+    ///
+    /// The iteration is vnode-major, so entries beyond the cursor in one vnode say nothing
+    /// about the next vnode and the whole range of owned vnodes is scanned.
+    pub async fn truncate(&mut self, up_to: LogStoreCursor) -> StreamExecutorResult<()> {
+        let consumed = {
+            let stream = self.state_table.iter().await?;
+            futures::pin_mut!(stream);
+            let mut consumed = vec![];
+            while let Some(row) = stream.try_next().await? {
+                if Self::deserialize(row.clone())?.cursor <= up_to {
+                    consumed.push(row);
+                }
+            }
+            consumed
+        };
+        for row in consumed {
+            self.state_table.delete(row);
+        }
+        Ok(())
+    }
+
+    fn deserialize(row: OwnedRow) -> StreamExecutorResult<LogStoreRow> {
+        let mut datums = row.into_inner();
+        let payload = OwnedRow::new(datums.split_off(LOG_STORE_PREFIX_COLUMNS));
+        let [_vnode, epoch, seq_id, op]: [_; LOG_STORE_PREFIX_COLUMNS] =
+            datums.try_into().unwrap();
+        let (Some(ScalarImpl::Int64(epoch)), Some(ScalarImpl::Int64(seq_id))) = (epoch, seq_id)
+        else {
+            unreachable!("`epoch` and `seq_id` of a log entry must be non-null int64")
+        };
+        let Some(ScalarImpl::Int16(op)) = op else {
+            unreachable!("`op` of a log entry must be a non-null int16")
+        };
+        Ok(LogStoreRow {
+            cursor: LogStoreCursor {
+                epoch: epoch as u64,
+                seq_id,
+            },
+            op: Op::from_protobuf(&(op as i32))?,
+            row: payload,
+        })
+    }
+}
@@ -45,7 +45,7 @@ use risingwave_storage::row_serde::row_serde_util::{
 };
 use risingwave_storage::storage_value::StorageValue;
 use risingwave_storage::store::{
-    LocalStateStore, ReadOptions, StateStoreRead, StateStoreWrite, WriteOptions,
+    LocalStateStore, PrefixHint, ReadOptions, StateStoreRead, StateStoreWrite, WriteOptions,
 };
 use risingwave_storage::table::{compute_chunk_vnode, compute_vnode, Distribution};
 use risingwave_storage::StateStore;
@@ -546,8 +546,14 @@ impl<S: StateStore> StateTable<S> {
                     debug_assert_eq!(self.prefix_hint_len, pk.len());
                 }
 
-                let prefix_hint = if self.prefix_hint_len != 0 && self.prefix_hint_len == pk.len() {
-                    Some(serialized_pk.slice(VirtualNode::SIZE..))
+                let prefix_hint_columns = self.prefix_hint_len.min(pk.len());
+                let prefix_hint = if prefix_hint_columns != 0 {
+                    Some(PrefixHint::new(
+                        serialized_pk.slice(VirtualNode::SIZE..),
+                        &self.pk_serde,
+                        prefix_hint_columns,
+                        self.prefix_hint_len,
+                    )?)
                 } else {
                     None
                 };
@@ -1069,14 +1075,16 @@ impl<S: StateStore> StateTable<S> {
             debug_assert_eq!(self.prefix_hint_len, pk_prefix.len());
         }
         let prefix_hint = {
-            if self.prefix_hint_len == 0 || self.prefix_hint_len > pk_prefix.len() {
+            let prefix_hint_columns = self.prefix_hint_len.min(pk_prefix.len());
+            if prefix_hint_columns == 0 {
                 None
             } else {
-                let encoded_prefix_len = self
-                    .pk_serde
-                    .deserialize_prefix_len(&encoded_prefix, self.prefix_hint_len)?;
-
-                Some(Bytes::from(encoded_prefix[..encoded_prefix_len].to_vec()))
+                Some(PrefixHint::new(
+                    Bytes::from(encoded_prefix.clone()),
+                    &self.pk_serde,
+                    prefix_hint_columns,
+                    self.prefix_hint_len,
+                )?)
             }
         };
 
@@ -1094,7 +1102,7 @@ impl<S: StateStore> StateTable<S> {
     async fn iter_inner(
         &self,
         key_range: (Bound<Vec<u8>>, Bound<Vec<u8>>),
-        prefix_hint: Option<Bytes>,
+        prefix_hint: Option<PrefixHint>,
         epoch: u64,
     ) -> StreamExecutorResult<(MemTableIter<'_>, StorageIterInner<S::Local>)> {
         let (l, r) = key_range.clone();
@@ -39,6 +39,10 @@ pub struct ActorContext {
     // TODO: report errors and prompt the user.
     pub errors: Mutex<HashMap<String, Vec<ExprError>>>,
 
+    /// Dynamic executor configs broadcast by the meta service. Updated atomically at barrier
+    /// boundaries by [`Mutation::ConfigChange`](super::Mutation::ConfigChange).
+    dynamic_config: Mutex<HashMap<String, String>>,
+
     last_mem_val: Arc<AtomicUsize>,
     cur_mem_val: Arc<AtomicUsize>,
     total_mem_val: Arc<TrAdder<i64>>,
@@ -51,6 +55,7 @@ impl ActorContext {
         Arc::new(Self {
             id,
             errors: Default::default(),
+            dynamic_config: Default::default(),
             cur_mem_val: Arc::new(0.into()),
             last_mem_val: Arc::new(0.into()),
             total_mem_val: Arc::new(TrAdder::new()),
@@ -61,12 +66,26 @@ impl ActorContext {
         Arc::new(Self {
             id,
             errors: Default::default(),
+            dynamic_config: Default::default(),
             cur_mem_val: Arc::new(0.into()),
             last_mem_val: Arc::new(0.into()),
             total_mem_val,
         })
     }
 
+    /// Merge the config entries carried by a config-change barrier into the dynamic configs of
+    /// this actor.
+    pub fn apply_config_change(&self, entries: &HashMap<String, String>) {
+        self.dynamic_config
+            .lock()
+            .extend(entries.iter().map(|(k, v)| (k.clone(), v.clone())));
+    }
+
+    /// Get the current value of a dynamic config entry, if it has ever been set.
+    pub fn dynamic_config(&self, key: &str) -> Option<String> {
+        self.dynamic_config.lock().get(key).cloned()
+    }
+
     pub fn on_compute_error(&self, err: ExprError, identity: &str) {
         tracing::error!("Compute error: {}, executor: {identity}", err);
         self.errors
@@ -166,6 +185,12 @@ where
         {
             last_epoch = Some(barrier.epoch);
 
+            // Apply new configs atomically at the barrier boundary, so that all operators of this
+            // actor observe the change at the same epoch.
+            if let Some(entries) = barrier.as_config_change() {
+                self.actor_context.apply_config_change(entries);
+            }
+
             // Collect barriers to local barrier manager
             self.context.lock_barrier_manager().collect(id, &barrier);
 
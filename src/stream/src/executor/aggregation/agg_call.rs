@@ -64,6 +64,11 @@ pub struct AggCall {
     /// Order requirements specified in order by clause of agg call
     pub order_pairs: Vec<OrderPair>,
 
+    /// Whether the input rows should be deduplicated on the agg argument before
+    /// aggregation. For now only effective for `string_agg` with materialized
+    /// input state, other distinct aggregations are rewritten in the frontend.
+    pub distinct: bool,
+
     /// Whether the stream is append-only.
     /// Specific streaming aggregator may optimize its implementation
     /// based on this knowledge.
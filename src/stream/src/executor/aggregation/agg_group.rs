@@ -77,6 +77,29 @@ impl<S: StateStore> AggGroup<S> {
         input_schema: &Schema,
     ) -> StreamExecutorResult<AggGroup<S>> {
         let prev_outputs: Option<OwnedRow> = result_table.get_row(&group_key).await?;
+        Self::create_from_prev_outputs(
+            group_key,
+            agg_calls,
+            storages,
+            prev_outputs,
+            pk_indices,
+            extreme_cache_size,
+            input_schema,
+        )
+        .await
+    }
+
+    /// Create [`AggGroup`] from known `prev_outputs`, e.g. reloaded from the spill file, skipping
+    /// the result table read.
+    pub async fn create_from_prev_outputs(
+        group_key: Option<OwnedRow>,
+        agg_calls: &[AggCall],
+        storages: &[AggStateStorage<S>],
+        prev_outputs: Option<OwnedRow>,
+        pk_indices: &PkIndices,
+        extreme_cache_size: usize,
+        input_schema: &Schema,
+    ) -> StreamExecutorResult<AggGroup<S>> {
         if let Some(prev_outputs) = &prev_outputs {
             assert_eq!(prev_outputs.len(), agg_calls.len());
         }
@@ -106,6 +129,13 @@ impl<S: StateStore> AggGroup<S> {
         self.group_key.as_ref()
     }
 
+    /// Previous outputs of all managed states, i.e. the row in the result table as of the last
+    /// flush. This is all the in-memory state that cannot be rebuilt from the agg state storages,
+    /// so it is what gets spilled when the group is evicted to disk.
+    pub fn prev_outputs(&self) -> &Option<OwnedRow> {
+        &self.prev_outputs
+    }
+
     fn prev_row_count(&self) -> usize {
         match &self.prev_outputs {
             Some(states) => states[ROW_COUNT_COLUMN]
@@ -0,0 +1,349 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use futures::{pin_mut, StreamExt};
+use itertools::Itertools;
+use risingwave_common::array::stream_chunk::{Op, Ops};
+use risingwave_common::array::{ArrayImpl, ListValue, StructValue};
+use risingwave_common::buffer::Bitmap;
+use risingwave_common::row::{OwnedRow, Row, RowExt};
+use risingwave_common::types::{Datum, ScalarImpl, ScalarRefImpl};
+use risingwave_common::util::iter_util::ZipEqDebug;
+use risingwave_common::{must_match, row};
+use risingwave_storage::StateStore;
+
+use crate::common::table::state_table::StateTable;
+use crate::executor::aggregation::table::TableStateImpl;
+use crate::executor::StreamExecutorResult;
+
+/// Sketch size used before the constant `compression` argument has been seen.
+const DEFAULT_COMPRESSION: usize = 100;
+
+/// Streaming approximate percentile with a t-digest sketch.
+///
+/// The sketch maintains at most `2 * compression` centroids of `(mean, weight)`. Insertions
+/// add a unit-weight centroid and the sketch is compressed once it grows past that bound, by
+/// merging adjacent centroids as long as the merged weight stays below the t-digest size limit
+/// `4 * total * q * (1 - q) / compression`. The limit is smallest at the distribution tails,
+/// which bounds the relative rank error at extreme percentiles.
+///
+/// Deletions are folded into the centroid with the nearest mean, so they are approximate as
+/// well, but keep the total weight exact. This allows the sketch to run on retractable streams.
+#[derive(Clone, Debug, Default)]
+pub struct StreamingApproxPercentile {
+    /// Requested fraction. `None` means the constant argument has not been seen yet.
+    fraction: Option<f64>,
+
+    /// Sketch size factor. `0` means the constant argument has not been seen yet.
+    compression: usize,
+
+    /// Centroids of the sketch as `(mean, weight)`, sorted by mean.
+    centroids: Vec<(f64, i64)>,
+}
+
+impl StreamingApproxPercentile {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn compression(&self) -> usize {
+        if self.compression == 0 {
+            DEFAULT_COMPRESSION
+        } else {
+            self.compression
+        }
+    }
+
+    fn total_weight(&self) -> i64 {
+        self.centroids.iter().map(|(_, weight)| weight).sum()
+    }
+
+    /// Add one occurrence of `value`, compressing the sketch if it grows too large.
+    fn insert(&mut self, value: f64) {
+        let pos = self
+            .centroids
+            .partition_point(|(mean, _)| *mean < value);
+        self.centroids.insert(pos, (value, 1));
+        if self.centroids.len() > 2 * self.compression() {
+            self.compress();
+        }
+    }
+
+    /// Remove one occurrence of `value` by decrementing the weight of the nearest centroid.
+    fn delete(&mut self, value: f64) {
+        let pos = self
+            .centroids
+            .partition_point(|(mean, _)| *mean < value)
+            .min(self.centroids.len().saturating_sub(1));
+        let nearest = if pos > 0
+            && (self.centroids[pos - 1].0 - value).abs() < (self.centroids[pos].0 - value).abs()
+        {
+            pos - 1
+        } else {
+            pos
+        };
+        if let Some((_, weight)) = self.centroids.get_mut(nearest) {
+            *weight -= 1;
+            if *weight == 0 {
+                self.centroids.remove(nearest);
+            }
+        }
+    }
+
+    /// Merge adjacent centroids as long as the merged weight stays below the t-digest size
+    /// limit for the quantile they cover.
+    fn compress(&mut self) {
+        let total = self.total_weight();
+        if total == 0 {
+            return;
+        }
+        let compression = self.compression() as f64;
+        let mut compressed = Vec::with_capacity(self.compression() + 1);
+        let mut iter = self.centroids.drain(..);
+        let mut current = iter.next().unwrap();
+        // Weight of all centroids before `current`.
+        let mut weight_before = 0;
+        for (mean, weight) in iter {
+            let q = (weight_before as f64 + current.1 as f64 / 2.0) / total as f64;
+            let limit = (4.0 * total as f64 * q * (1.0 - q) / compression).max(1.0);
+            if (current.1 + weight) as f64 <= limit {
+                let merged_weight = current.1 + weight;
+                current = (
+                    (current.0 * current.1 as f64 + mean * weight as f64) / merged_weight as f64,
+                    merged_weight,
+                );
+            } else {
+                weight_before += current.1;
+                compressed.push(current);
+                current = (mean, weight);
+            }
+        }
+        compressed.push(current);
+        self.centroids = compressed;
+    }
+
+    /// Estimate the value at `fraction` by interpolating between centroid means, treating each
+    /// centroid as centered in the weight range it covers.
+    fn quantile(&self, fraction: f64) -> Option<f64> {
+        let total = self.total_weight();
+        if total == 0 {
+            return None;
+        }
+        let target = fraction * total as f64;
+        let mut weight_before = 0;
+        let mut prev: Option<(f64, f64)> = None;
+        for &(mean, weight) in &self.centroids {
+            let center = weight_before as f64 + weight as f64 / 2.0;
+            if target <= center {
+                return Some(match prev {
+                    Some((prev_mean, prev_center)) => {
+                        prev_mean
+                            + (mean - prev_mean) * (target - prev_center)
+                                / (center - prev_center)
+                    }
+                    // Before the center of the first centroid.
+                    None => mean,
+                });
+            }
+            weight_before += weight;
+            prev = Some((mean, center));
+        }
+        // After the center of the last centroid.
+        self.centroids.last().map(|(mean, _)| *mean)
+    }
+
+    fn apply_batch_inner(
+        &mut self,
+        ops: Ops<'_>,
+        visibility: Option<&Bitmap>,
+        data: &[&ArrayImpl],
+    ) -> StreamExecutorResult<()> {
+        for (i, (op, datum)) in ops.iter().zip_eq_debug(data[0].iter()).enumerate() {
+            if let Some(visibility) = visibility {
+                if !visibility.is_set(i) {
+                    continue;
+                }
+            }
+            if self.fraction.is_none() {
+                // The constant arguments are checked by the frontend, read them from the
+                // first row.
+                let fraction = must_match!(
+                    data[1].value_at(i),
+                    Some(ScalarRefImpl::Float64(fraction)) => fraction.0
+                );
+                self.fraction = Some(fraction);
+                if let Some(compression_col) = data.get(2) {
+                    let compression = must_match!(
+                        compression_col.value_at(i),
+                        Some(ScalarRefImpl::Int32(compression)) => compression
+                    );
+                    self.compression = compression as usize;
+                }
+            }
+            let Some(ScalarRefImpl::Float64(value)) = datum else {
+                // rows with null value are ignored, following PostgreSQL
+                continue;
+            };
+            match op {
+                Op::Insert | Op::UpdateInsert => self.insert(value.0),
+                Op::Delete | Op::UpdateDelete => self.delete(value.0),
+            }
+        }
+        Ok(())
+    }
+
+    fn get_output_inner(&self) -> Datum {
+        let fraction = self.fraction?;
+        self.quantile(fraction).map(Into::into)
+    }
+}
+
+#[async_trait::async_trait]
+impl<S: StateStore> TableStateImpl<S> for StreamingApproxPercentile {
+    fn apply_batch(
+        &mut self,
+        ops: Ops<'_>,
+        visibility: Option<&Bitmap>,
+        data: &[&ArrayImpl],
+    ) -> StreamExecutorResult<()> {
+        self.apply_batch_inner(ops, visibility, data)
+    }
+
+    fn get_output(&mut self) -> StreamExecutorResult<Datum> {
+        Ok(self.get_output_inner())
+    }
+
+    async fn update_from_state_table(
+        &mut self,
+        state_table: &StateTable<S>,
+        group_key: Option<&OwnedRow>,
+    ) -> StreamExecutorResult<()> {
+        let state_row = {
+            let data_iter = state_table.iter_with_pk_prefix(&group_key).await?;
+            pin_mut!(data_iter);
+            if let Some(state_row) = data_iter.next().await {
+                Some(state_row?)
+            } else {
+                None
+            }
+        };
+        if let Some(state_row) = state_row {
+            let list = must_match!(
+                state_row[group_key.len()].as_ref(),
+                Some(ScalarImpl::List(list)) => list
+            );
+            self.centroids = list
+                .values()
+                .iter()
+                .map(|centroid| {
+                    let fields =
+                        must_match!(centroid.as_ref(), Some(ScalarImpl::Struct(sv)) => sv.fields());
+                    let mean = must_match!(fields[0].as_ref(), Some(ScalarImpl::Float64(v)) => v.0);
+                    let weight =
+                        must_match!(fields[1].as_ref(), Some(ScalarImpl::Int64(v)) => *v);
+                    (mean, weight)
+                })
+                .collect();
+        }
+        Ok(())
+    }
+
+    async fn flush_state_if_needed(
+        &self,
+        state_table: &mut StateTable<S>,
+        group_key: Option<&OwnedRow>,
+    ) -> StreamExecutorResult<()> {
+        let centroids = self
+            .centroids
+            .iter()
+            .map(|(mean, weight)| {
+                Some(ScalarImpl::Struct(StructValue::new(vec![
+                    Some(ScalarImpl::Float64((*mean).into())),
+                    Some(ScalarImpl::Int64(*weight)),
+                ])))
+            })
+            .collect_vec();
+        let list = Some(ScalarImpl::List(ListValue::new(centroids)));
+        let current_row = group_key.chain(row::once(list));
+
+        let state_row = {
+            let data_iter = state_table.iter_with_pk_prefix(&group_key).await?;
+            pin_mut!(data_iter);
+            if let Some(state_row) = data_iter.next().await {
+                Some(state_row?)
+            } else {
+                None
+            }
+        };
+        match state_row {
+            Some(state_row) => {
+                state_table.update(state_row, current_row);
+            }
+            None => {
+                state_table.insert(current_row);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sketch_with_fraction(fraction: f64) -> StreamingApproxPercentile {
+        StreamingApproxPercentile {
+            fraction: Some(fraction),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_small_input_is_exact() {
+        let mut sketch = sketch_with_fraction(0.5);
+        for value in [4.0, 1.0, 2.0, 3.0] {
+            sketch.insert(value);
+        }
+        // No compression happened, so the median is interpolated from unit centroids.
+        assert_eq!(sketch.get_output_inner(), Some(2.5.into()));
+    }
+
+    #[test]
+    fn test_compression_bounds_state_and_stays_accurate() {
+        let mut sketch = sketch_with_fraction(0.9);
+        for i in 0..10_000 {
+            sketch.insert(i as f64);
+        }
+        assert!(sketch.centroids.len() <= 2 * DEFAULT_COMPRESSION);
+        assert_eq!(sketch.total_weight(), 10_000);
+        let estimate = must_match!(
+            sketch.get_output_inner(),
+            Some(ScalarImpl::Float64(v)) => v.0
+        );
+        // The relative rank error of the t-digest is bounded by the compression factor.
+        assert!((estimate - 9_000.0).abs() < 100.0);
+    }
+
+    #[test]
+    fn test_deletion() {
+        let mut sketch = sketch_with_fraction(1.0);
+        for value in [1.0, 2.0, 3.0] {
+            sketch.insert(value);
+        }
+        sketch.delete(3.0);
+        assert_eq!(sketch.total_weight(), 2);
+        assert_eq!(sketch.get_output_inner(), Some(2.0.into()));
+    }
+}
@@ -0,0 +1,325 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use futures::{pin_mut, StreamExt};
+use itertools::Itertools;
+use risingwave_common::array::stream_chunk::{Op, Ops};
+use risingwave_common::array::{ArrayImpl, ListValue, StructValue};
+use risingwave_common::buffer::Bitmap;
+use risingwave_common::row::{OwnedRow, Row, RowExt};
+use risingwave_common::types::{Datum, ScalarImpl, ScalarRefImpl};
+use risingwave_common::util::iter_util::ZipEqDebug;
+use risingwave_common::{bail, must_match, row};
+use risingwave_storage::StateStore;
+
+use crate::common::table::state_table::StateTable;
+use crate::executor::aggregation::table::TableStateImpl;
+use crate::executor::StreamExecutorResult;
+
+/// Number of sketch entries maintained per requested top-k entry. A larger factor gives more
+/// accurate counts at the cost of more state.
+const CAPACITY_FACTOR: usize = 8;
+
+/// Streaming approximate top-k (heavy hitters) with the space-saving sketch.
+///
+/// The sketch maintains at most `k * CAPACITY_FACTOR` counters. When a value not in the sketch
+/// arrives and the sketch is full, the entry with the minimal count is replaced by the new value,
+/// inheriting its count as the error bound of the new entry. This guarantees that any value with
+/// a true count larger than `total_count / capacity` is present in the sketch.
+///
+/// The sketch cannot handle deletions, so it requires an append-only input.
+#[derive(Clone, Debug, Default)]
+pub struct StreamingApproxTopK {
+    /// Number of entries to output. `0` means the constant `k` argument has not been seen yet.
+    k: usize,
+
+    /// Maps each tracked value to `(count, error)`, where `error` is the maximum possible
+    /// overestimation of `count`.
+    entries: HashMap<String, (i64, i64)>,
+}
+
+impl StreamingApproxTopK {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn capacity(&self) -> usize {
+        self.k * CAPACITY_FACTOR
+    }
+
+    /// Count one occurrence of `value` with the space-saving algorithm.
+    fn insert(&mut self, value: &str) {
+        if let Some((count, _)) = self.entries.get_mut(value) {
+            *count += 1;
+        } else if self.entries.len() < self.capacity() {
+            self.entries.insert(value.to_owned(), (1, 0));
+        } else {
+            // Evict the entry with the minimal count and inherit its count as the error bound
+            // of the new entry. Break ties by value to keep the sketch deterministic.
+            let (min_value, min_count) = self
+                .entries
+                .iter()
+                .map(|(value, (count, _))| (value.clone(), *count))
+                .min_by(|(v1, c1), (v2, c2)| (c1, v1).cmp(&(c2, v2)))
+                .unwrap();
+            self.entries.remove(&min_value);
+            self.entries.insert(value.to_owned(), (min_count + 1, min_count));
+        }
+    }
+
+    /// Merge another sketch into this one. The error bounds of both sketches add up.
+    ///
+    /// This is to be used for merging partial sketches from multiple actors once 2-phase
+    /// `approx_top_k` is supported, and for testing.
+    #[allow(dead_code)]
+    fn merge(&mut self, other: &Self) {
+        for (value, (count, error)) in &other.entries {
+            if let Some((this_count, this_error)) = self.entries.get_mut(value) {
+                *this_count += count;
+                *this_error += error;
+            } else {
+                self.entries.insert(value.clone(), (*count, *error));
+            }
+        }
+        while self.entries.len() > self.capacity() {
+            let (min_value, min_count) = self
+                .entries
+                .iter()
+                .map(|(value, (count, _))| (value.clone(), *count))
+                .min_by(|(v1, c1), (v2, c2)| (c1, v1).cmp(&(c2, v2)))
+                .unwrap();
+            self.entries.remove(&min_value);
+            self.entries
+                .iter_mut()
+                .for_each(|(_, (_, error))| *error += min_count);
+        }
+    }
+
+    fn apply_batch_inner(
+        &mut self,
+        ops: Ops<'_>,
+        visibility: Option<&Bitmap>,
+        data: &[&ArrayImpl],
+    ) -> StreamExecutorResult<()> {
+        for (i, (op, datum)) in ops.iter().zip_eq_debug(data[0].iter()).enumerate() {
+            if let Some(visibility) = visibility {
+                if !visibility.is_set(i) {
+                    continue;
+                }
+            }
+            match op {
+                Op::Insert | Op::UpdateInsert => {}
+                Op::Delete | Op::UpdateDelete => {
+                    bail!("ApproxTopK does not support deletion")
+                }
+            }
+            if self.k == 0 {
+                // The `k` argument is a checked positive constant, read it from the first row.
+                let k = must_match!(
+                    data[1].value_at(i),
+                    Some(ScalarRefImpl::Int32(k)) => k
+                );
+                self.k = k as usize;
+            }
+            if let Some(ScalarRefImpl::Utf8(value)) = datum {
+                self.insert(value);
+            }
+        }
+        Ok(())
+    }
+
+    fn get_output_inner(&self) -> Datum {
+        let top_k = self
+            .entries
+            .iter()
+            .sorted_by(|(v1, (c1, _)), (v2, (c2, _))| (-c1, v1).cmp(&(-c2, v2)))
+            .take(self.k)
+            .map(|(value, (count, _))| {
+                Some(ScalarImpl::Struct(StructValue::new(vec![
+                    Some(ScalarImpl::Utf8(value.clone().into())),
+                    Some(ScalarImpl::Int64(*count)),
+                ])))
+            })
+            .collect_vec();
+        Some(ScalarImpl::List(ListValue::new(top_k)))
+    }
+}
+
+#[async_trait::async_trait]
+impl<S: StateStore> TableStateImpl<S> for StreamingApproxTopK {
+    fn apply_batch(
+        &mut self,
+        ops: Ops<'_>,
+        visibility: Option<&Bitmap>,
+        data: &[&ArrayImpl],
+    ) -> StreamExecutorResult<()> {
+        self.apply_batch_inner(ops, visibility, data)
+    }
+
+    fn get_output(&mut self) -> StreamExecutorResult<Datum> {
+        Ok(self.get_output_inner())
+    }
+
+    async fn update_from_state_table(
+        &mut self,
+        state_table: &StateTable<S>,
+        group_key: Option<&OwnedRow>,
+    ) -> StreamExecutorResult<()> {
+        let state_row = {
+            let data_iter = state_table.iter_with_pk_prefix(&group_key).await?;
+            pin_mut!(data_iter);
+            if let Some(state_row) = data_iter.next().await {
+                Some(state_row?)
+            } else {
+                None
+            }
+        };
+        if let Some(state_row) = state_row {
+            let list = must_match!(
+                state_row[group_key.len()].as_ref(),
+                Some(ScalarImpl::List(list)) => list
+            );
+            self.entries = list
+                .values()
+                .iter()
+                .map(|entry| {
+                    let fields =
+                        must_match!(entry.as_ref(), Some(ScalarImpl::Struct(sv)) => sv.fields());
+                    let value = must_match!(fields[0].as_ref(), Some(ScalarImpl::Utf8(v)) => v);
+                    let count = must_match!(fields[1].as_ref(), Some(ScalarImpl::Int64(v)) => *v);
+                    let error = must_match!(fields[2].as_ref(), Some(ScalarImpl::Int64(v)) => *v);
+                    (value.to_string(), (count, error))
+                })
+                .collect();
+        }
+        Ok(())
+    }
+
+    async fn flush_state_if_needed(
+        &self,
+        state_table: &mut StateTable<S>,
+        group_key: Option<&OwnedRow>,
+    ) -> StreamExecutorResult<()> {
+        let entries = self
+            .entries
+            .iter()
+            .sorted_by(|(v1, _), (v2, _)| v1.cmp(v2))
+            .map(|(value, (count, error))| {
+                Some(ScalarImpl::Struct(StructValue::new(vec![
+                    Some(ScalarImpl::Utf8(value.clone().into())),
+                    Some(ScalarImpl::Int64(*count)),
+                    Some(ScalarImpl::Int64(*error)),
+                ])))
+            })
+            .collect_vec();
+        let list = Some(ScalarImpl::List(ListValue::new(entries)));
+        let current_row = group_key.chain(row::once(list));
+
+        let state_row = {
+            let data_iter = state_table.iter_with_pk_prefix(&group_key).await?;
+            pin_mut!(data_iter);
+            if let Some(state_row) = data_iter.next().await {
+                Some(state_row?)
+            } else {
+                None
+            }
+        };
+        match state_row {
+            Some(state_row) => {
+                state_table.update(state_row, current_row);
+            }
+            None => {
+                state_table.insert(current_row);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sketch_with_k(k: usize) -> StreamingApproxTopK {
+        StreamingApproxTopK {
+            k,
+            entries: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_top_k_output() {
+        let mut sketch = sketch_with_k(2);
+        for value in ["a", "b", "a", "c", "a", "b"] {
+            sketch.insert(value);
+        }
+        let output = sketch.get_output_inner();
+        let list = must_match!(output, Some(ScalarImpl::List(list)) => list);
+        let entries = list
+            .values()
+            .iter()
+            .map(|entry| {
+                let fields =
+                    must_match!(entry.as_ref(), Some(ScalarImpl::Struct(sv)) => sv.fields());
+                let value = must_match!(fields[0].as_ref(), Some(ScalarImpl::Utf8(v)) => v);
+                let count = must_match!(fields[1].as_ref(), Some(ScalarImpl::Int64(v)) => *v);
+                (value.to_string(), count)
+            })
+            .collect_vec();
+        assert_eq!(entries, vec![("a".to_string(), 3), ("b".to_string(), 2)]);
+    }
+
+    #[test]
+    fn test_eviction_keeps_heavy_hitters() {
+        let mut sketch = sketch_with_k(1);
+        // Capacity is `CAPACITY_FACTOR`, overflow it with distinct values, then make one value
+        // dominant.
+        for i in 0..CAPACITY_FACTOR + 4 {
+            sketch.insert(&format!("v{}", i));
+        }
+        assert_eq!(sketch.entries.len(), CAPACITY_FACTOR);
+        for _ in 0..100 {
+            sketch.insert("heavy");
+        }
+        let output = sketch.get_output_inner();
+        let list = must_match!(output, Some(ScalarImpl::List(list)) => list);
+        let fields = must_match!(
+            list.values()[0].as_ref(),
+            Some(ScalarImpl::Struct(sv)) => sv.fields()
+        );
+        assert_eq!(
+            fields[0].as_ref(),
+            Some(&ScalarImpl::Utf8("heavy".into()))
+        );
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut lhs = sketch_with_k(2);
+        let mut rhs = sketch_with_k(2);
+        for value in ["a", "a", "b"] {
+            lhs.insert(value);
+        }
+        for value in ["a", "c", "c", "c"] {
+            rhs.insert(value);
+        }
+        lhs.merge(&rhs);
+        assert_eq!(lhs.entries["a"], (3, 0));
+        assert_eq!(lhs.entries["c"], (3, 0));
+        assert_eq!(lhs.entries["b"], (1, 0));
+    }
+}
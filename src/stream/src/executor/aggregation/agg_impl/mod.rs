@@ -20,6 +20,8 @@ use std::any::Any;
 pub use approx_count_distinct::*;
 pub use approx_distinct_append::AppendOnlyStreamingApproxCountDistinct;
 use approx_distinct_utils::StreamingApproxCountDistinct;
+pub use approx_percentile::StreamingApproxPercentile;
+pub use approx_top_k::StreamingApproxTopK;
 use dyn_clone::DynClone;
 pub use foldable::*;
 use risingwave_common::array::stream_chunk::Ops;
@@ -39,6 +41,8 @@ use crate::executor::{StreamExecutorError, StreamExecutorResult};
 mod approx_count_distinct;
 mod approx_distinct_append;
 mod approx_distinct_utils;
+mod approx_percentile;
+mod approx_top_k;
 mod foldable;
 mod row_count;
 
@@ -31,6 +31,7 @@ use smallvec::SmallVec;
 
 use super::state_cache::array_agg::ArrayAgg;
 use super::state_cache::extreme::ExtremeAgg;
+use super::state_cache::percentile::{PercentileCont, PercentileDisc};
 use super::state_cache::string_agg::StringAgg;
 use super::state_cache::{CacheKey, SortedStateCache, StateCache, TopNStateCache};
 use super::AggCall;
@@ -127,8 +128,12 @@ impl<S: StateStore> MaterializedInputState<S> {
             AggKind::Min | AggKind::Max | AggKind::FirstValue => {
                 Box::new(TopNStateCache::new(ExtremeAgg, extreme_cache_size))
             }
-            AggKind::StringAgg => Box::new(SortedStateCache::new(StringAgg)),
+            AggKind::StringAgg => {
+                Box::new(SortedStateCache::new(StringAgg::new(agg_call.distinct)))
+            }
             AggKind::ArrayAgg => Box::new(SortedStateCache::new(ArrayAgg)),
+            AggKind::PercentileCont => Box::new(SortedStateCache::new(PercentileCont)),
+            AggKind::PercentileDisc => Box::new(SortedStateCache::new(PercentileDisc)),
             _ => panic!(
                 "Agg kind `{}` is not expected to have materialized input state",
                 agg_call.kind
@@ -342,6 +347,7 @@ mod tests {
             args: AggArgs::Unary(arg_type.clone(), arg_idx),
             return_type: arg_type,
             order_pairs: vec![],
+            distinct: false,
             append_only: false,
             filter: None,
         }
@@ -1038,6 +1044,7 @@ mod tests {
                 OrderPair::new(2, OrderType::Ascending),  // b ASC
                 OrderPair::new(0, OrderType::Descending), // a DESC
             ],
+            distinct: false,
             append_only: false,
             filter: None,
         };
@@ -1118,6 +1125,110 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_string_agg_state_distinct() -> StreamExecutorResult<()> {
+        // Assumption of input schema:
+        // (a: varchar, _delim: varchar, b: int32, c: int32, _row_id: int64)
+        // where `a` is the column to aggregate
+
+        let input_pk_indices = vec![4];
+        let field1 = Field::unnamed(DataType::Varchar);
+        let field2 = Field::unnamed(DataType::Varchar);
+        let field3 = Field::unnamed(DataType::Int32);
+        let field4 = Field::unnamed(DataType::Int32);
+        let field5 = Field::unnamed(DataType::Int64);
+        let input_schema = Schema::new(vec![field1, field2, field3, field4, field5]);
+
+        let agg_call = AggCall {
+            kind: AggKind::StringAgg,
+            args: AggArgs::Binary([DataType::Varchar, DataType::Varchar], [0, 1]),
+            return_type: DataType::Varchar,
+            order_pairs: vec![
+                OrderPair::new(2, OrderType::Ascending),  // b ASC
+                OrderPair::new(0, OrderType::Descending), // a DESC
+            ],
+            distinct: true,
+            append_only: false,
+            filter: None,
+        };
+        let group_key = None;
+
+        let (mut table, mapping) = create_mem_state_table(
+            &input_schema,
+            vec![2, 0, 4, 1],
+            vec![
+                OrderType::Ascending,  // b ASC
+                OrderType::Descending, // a DESC
+                OrderType::Ascending,  // _row_id ASC
+            ],
+        )
+        .await;
+
+        let mut state = MaterializedInputState::new(
+            &agg_call,
+            &input_pk_indices,
+            &mapping,
+            usize::MAX,
+            &input_schema,
+        );
+
+        let mut epoch = EpochPair::new_test_epoch(1);
+        table.init_epoch(epoch);
+
+        {
+            let chunk = create_chunk(
+                " T T i i I
+                + a , 1 8 123
+                + b / 5 2 128
+                + a - 3 4 129
+                + c _ 1 3 130",
+                &mut table,
+                &mapping,
+            );
+            let (ops, columns, visibility) = chunk.into_inner();
+            let columns: Vec<_> = columns.iter().map(|col| col.array_ref()).collect();
+            state.apply_chunk(&ops, visibility.as_ref(), &columns)?;
+
+            epoch.inc();
+            table.commit(epoch).await.unwrap();
+
+            // the second `a` is deduplicated
+            let res = state.get_output(&table, group_key.as_ref()).await?;
+            match res {
+                Some(ScalarImpl::Utf8(s)) => {
+                    assert_eq!(s.as_ref(), "c,a/b".to_string());
+                }
+                _ => panic!("unexpected output"),
+            }
+        }
+
+        {
+            let chunk = create_chunk(
+                " T T i i I
+                - a , 1 8 123",
+                &mut table,
+                &mapping,
+            );
+            let (ops, columns, visibility) = chunk.into_inner();
+            let columns: Vec<_> = columns.iter().map(|col| col.array_ref()).collect();
+            state.apply_chunk(&ops, visibility.as_ref(), &columns)?;
+
+            epoch.inc();
+            table.commit(epoch).await.unwrap();
+
+            // the remaining `a` is still aggregated after the first one is retracted
+            let res = state.get_output(&table, group_key.as_ref()).await?;
+            match res {
+                Some(ScalarImpl::Utf8(s)) => {
+                    assert_eq!(s.as_ref(), "c-a/b".to_string());
+                }
+                _ => panic!("unexpected output"),
+            }
+        }
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_array_agg_state() -> StreamExecutorResult<()> {
         // Assumption of input schema:
@@ -1139,6 +1250,7 @@ mod tests {
                 OrderPair::new(2, OrderType::Ascending),  // c ASC
                 OrderPair::new(0, OrderType::Descending), // a DESC
             ],
+            distinct: false,
             append_only: false,
             filter: None,
         };
@@ -15,6 +15,7 @@
 pub use agg_call::*;
 pub use agg_group::*;
 pub use agg_state::*;
+pub use spill::*;
 use risingwave_common::array::column::Column;
 use risingwave_common::array::ArrayImpl::Bool;
 use risingwave_common::array::DataChunk;
@@ -35,6 +36,7 @@ mod agg_group;
 pub mod agg_impl;
 mod agg_state;
 mod minput;
+mod spill;
 mod state_cache;
 mod table;
 mod value;
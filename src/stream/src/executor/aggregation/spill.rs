@@ -0,0 +1,279 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::PathBuf;
+
+use anyhow::Context;
+use risingwave_common::hash::HashKey;
+use risingwave_common::row::{OwnedRow, Row, RowDeserializer};
+use risingwave_common::types::DataType;
+
+use crate::executor::StreamExecutorResult;
+
+/// [`AggGroupSpillManager`] persists the previous outputs of cold agg groups to a file in the
+/// local temporary directory, so that they can be dropped from the in-memory cache under memory
+/// pressure and transparently reloaded on the next access without a state table read.
+///
+/// Only the `prev_outputs` row of a flushed [`super::AggGroup`] is spilled: it is all that is
+/// needed to rebuild the group, since the remaining in-memory state is merely a cache over the
+/// state tables. Records are appended to a single file and indexed in memory. A record becomes
+/// dead once its group is reloaded or spilled again; dead records are reclaimed by compacting
+/// the file in place once they take up at least half of it, and the file size is capped by
+/// `max_file_size`. Since spilling is merely a cache optimization, a group that cannot be
+/// spilled can always be dropped instead and rebuilt from the state tables.
+pub struct AggGroupSpillManager<K: HashKey> {
+    path: PathBuf,
+    file: File,
+
+    /// Offset and length of the live record for each spilled group.
+    index: HashMap<K, (u64, u32)>,
+
+    /// End offset of the last record, i.e. where the next record will be written.
+    write_offset: u64,
+
+    /// Total length of the live records, i.e. `write_offset` minus the dead bytes.
+    live_bytes: u64,
+
+    /// The maximum file size in bytes. [`Self::at_capacity`] turns true once `write_offset`
+    /// reaches it and compaction cannot reclaim enough dead bytes.
+    max_file_size: u64,
+
+    /// Deserializer for the spilled `prev_outputs` rows, whose schema is the output types of all
+    /// agg calls.
+    row_deserializer: RowDeserializer,
+}
+
+impl<K: HashKey> AggGroupSpillManager<K> {
+    pub fn new(
+        actor_id: u32,
+        executor_id: u64,
+        output_data_types: Vec<DataType>,
+        max_file_size: u64,
+    ) -> StreamExecutorResult<Self> {
+        let path = std::env::temp_dir().join(format!(
+            "risingwave-agg-spill-{}-{:X}",
+            actor_id, executor_id
+        ));
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(&path)
+            .with_context(|| format!("failed to create agg spill file {}", path.display()))?;
+        Ok(Self {
+            path,
+            file,
+            index: HashMap::new(),
+            write_offset: 0,
+            live_bytes: 0,
+            max_file_size,
+            row_deserializer: RowDeserializer::new(output_data_types),
+        })
+    }
+
+    /// Number of groups currently spilled.
+    pub fn len(&self) -> usize {
+        self.index.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.index.is_empty()
+    }
+
+    /// Returns whether the spill file has reached its size cap, compacting it first if dead
+    /// records take up at least half of it. Callers should drop further cold groups instead of
+    /// spilling them while at capacity.
+    pub fn at_capacity(&mut self) -> StreamExecutorResult<bool> {
+        if self.write_offset < self.max_file_size {
+            return Ok(false);
+        }
+        if self.write_offset - self.live_bytes >= self.write_offset / 2 {
+            self.compact()?;
+        }
+        Ok(self.write_offset >= self.max_file_size)
+    }
+
+    /// Spill the previous outputs of a group evicted from the cache. `None` means the group has
+    /// no row in the result table yet, encoded as an empty record: this is unambiguous since a
+    /// serialized row occupies at least one byte per datum and there is always at least the row
+    /// count agg call.
+    pub fn spill(&mut self, key: K, prev_outputs: &Option<OwnedRow>) -> StreamExecutorResult<()> {
+        let buf = match prev_outputs {
+            Some(row) => row.value_serialize(),
+            None => Vec::new(),
+        };
+        self.file
+            .seek(SeekFrom::Start(self.write_offset))
+            .and_then(|_| self.file.write_all(&buf))
+            .with_context(|| format!("failed to write agg spill file {}", self.path.display()))?;
+        if let Some((_, old_len)) = self
+            .index
+            .insert(key, (self.write_offset, buf.len() as u32))
+        {
+            // The old record of a re-spilled group is dead from now on.
+            self.live_bytes -= old_len as u64;
+        }
+        self.write_offset += buf.len() as u64;
+        self.live_bytes += buf.len() as u64;
+        Ok(())
+    }
+
+    /// Reload the previous outputs of a spilled group, removing it from the spill file. Returns
+    /// `None` if the group has never been spilled or has already been reloaded.
+    pub fn unspill(&mut self, key: &K) -> StreamExecutorResult<Option<Option<OwnedRow>>> {
+        let Some((offset, len)) = self.index.remove(key) else {
+            return Ok(None);
+        };
+        self.live_bytes -= len as u64;
+        let mut buf = vec![0; len as usize];
+        self.file
+            .seek(SeekFrom::Start(offset))
+            .and_then(|_| self.file.read_exact(&mut buf))
+            .with_context(|| format!("failed to read agg spill file {}", self.path.display()))?;
+        let prev_outputs = if buf.is_empty() {
+            None
+        } else {
+            Some(self.row_deserializer.deserialize(buf.as_slice())?)
+        };
+        if self.index.is_empty() {
+            self.truncate()?;
+        }
+        Ok(Some(prev_outputs))
+    }
+
+    /// Drop all spilled records, e.g. when the cache may be stale after a scaling.
+    pub fn clear(&mut self) -> StreamExecutorResult<()> {
+        self.index.clear();
+        self.truncate()
+    }
+
+    fn truncate(&mut self) -> StreamExecutorResult<()> {
+        self.file
+            .set_len(0)
+            .with_context(|| format!("failed to truncate agg spill file {}", self.path.display()))?;
+        self.write_offset = 0;
+        self.live_bytes = 0;
+        Ok(())
+    }
+
+    /// Rewrite the live records towards the beginning of the file, reclaiming the space of dead
+    /// ones. Records are moved in offset order, so every move targets an offset at or before the
+    /// record's current one and the compaction is safe in place.
+    fn compact(&mut self) -> StreamExecutorResult<()> {
+        let mut entries: Vec<_> = self.index.values_mut().collect();
+        entries.sort_unstable_by_key(|(offset, _)| *offset);
+        let mut compact_offset = 0;
+        for entry in entries {
+            let (offset, len) = *entry;
+            if offset != compact_offset {
+                let mut buf = vec![0; len as usize];
+                self.file
+                    .seek(SeekFrom::Start(offset))
+                    .and_then(|_| self.file.read_exact(&mut buf))
+                    .and_then(|_| self.file.seek(SeekFrom::Start(compact_offset)))
+                    .and_then(|_| self.file.write_all(&buf))
+                    .with_context(|| {
+                        format!("failed to compact agg spill file {}", self.path.display())
+                    })?;
+                entry.0 = compact_offset;
+            }
+            compact_offset += len as u64;
+        }
+        self.file
+            .set_len(compact_offset)
+            .with_context(|| format!("failed to truncate agg spill file {}", self.path.display()))?;
+        self.write_offset = compact_offset;
+        debug_assert_eq!(self.live_bytes, compact_offset);
+        Ok(())
+    }
+}
+
+impl<K: HashKey> Drop for AggGroupSpillManager<K> {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::DataChunk;
+    use risingwave_common::hash::Key64;
+    use risingwave_common::test_prelude::DataChunkTestExt;
+    use risingwave_common::types::ScalarImpl;
+
+    use super::*;
+
+    #[test]
+    fn test_spill_and_unspill() {
+        let chunk = DataChunk::from_pretty(
+            "I
+             1
+             2",
+        );
+        let keys = Key64::build(&[0], &chunk).unwrap();
+        let mut manager: AggGroupSpillManager<Key64> =
+            AggGroupSpillManager::new(1, 1, vec![DataType::Int64], 1 << 20).unwrap();
+
+        let outputs = Some(OwnedRow::new(vec![Some(ScalarImpl::Int64(42))]));
+        manager.spill(keys[0].clone(), &outputs).unwrap();
+        manager.spill(keys[1].clone(), &None).unwrap();
+        assert_eq!(manager.len(), 2);
+
+        assert_eq!(manager.unspill(&keys[0]).unwrap(), Some(outputs));
+        assert_eq!(manager.unspill(&keys[1]).unwrap(), Some(None));
+        assert_eq!(manager.unspill(&keys[0]).unwrap(), None);
+        assert!(manager.is_empty());
+
+        // The file is truncated once all records are reloaded.
+        assert_eq!(manager.file.metadata().unwrap().len(), 0);
+    }
+
+    #[test]
+    fn test_compaction_and_capacity() {
+        let chunk = DataChunk::from_pretty(
+            "I
+             1
+             2",
+        );
+        let keys = Key64::build(&[0], &chunk).unwrap();
+        // A record of one non-null int64 datum occupies 9 bytes, so the cap fits two records.
+        let mut manager: AggGroupSpillManager<Key64> =
+            AggGroupSpillManager::new(1, 2, vec![DataType::Int64], 20).unwrap();
+
+        let outputs = |v| Some(OwnedRow::new(vec![Some(ScalarImpl::Int64(v))]));
+        manager.spill(keys[0].clone(), &outputs(1)).unwrap();
+        manager.spill(keys[1].clone(), &outputs(2)).unwrap();
+        assert!(!manager.at_capacity().unwrap());
+
+        // Each re-spill of a group makes its previous record dead. The file is over the cap and
+        // less than half dead, so compaction does not kick in yet.
+        manager.spill(keys[0].clone(), &outputs(3)).unwrap();
+        assert!(manager.at_capacity().unwrap());
+        assert_eq!(manager.write_offset, 27);
+
+        // One more dead record makes the file half dead: compaction reclaims the space and the
+        // file is below the cap again.
+        manager.spill(keys[0].clone(), &outputs(4)).unwrap();
+        assert!(!manager.at_capacity().unwrap());
+        assert_eq!(manager.write_offset, 18);
+
+        // The records survive the compaction.
+        assert_eq!(manager.unspill(&keys[0]).unwrap(), Some(outputs(4)));
+        assert_eq!(manager.unspill(&keys[1]).unwrap(), Some(outputs(2)));
+    }
+}
@@ -24,6 +24,7 @@ use super::minput::StateCacheInputBatch;
 pub mod array_agg;
 mod cache;
 pub mod extreme;
+pub mod percentile;
 pub mod string_agg;
 
 /// Cache key type.
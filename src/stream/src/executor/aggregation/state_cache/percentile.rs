@@ -0,0 +1,154 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::types::{Datum, DatumRef, ScalarRefImpl};
+use smallvec::SmallVec;
+
+use super::StateCacheAggregator;
+
+pub struct PercentileContData {
+    fraction: Option<f64>,
+    value: Option<f64>,
+}
+
+/// Ordered-set aggregator for `percentile_cont`. The cache entries are already sorted by
+/// the `WITHIN GROUP (ORDER BY ...)` clause, so the output is interpolated between the two
+/// values adjacent to the requested fraction of the cache order.
+pub struct PercentileCont;
+
+impl StateCacheAggregator for PercentileCont {
+    type Value = PercentileContData;
+
+    fn convert_cache_value(&self, value: SmallVec<[DatumRef<'_>; 2]>) -> Self::Value {
+        PercentileContData {
+            fraction: value[0].map(|f| f.into_float64().0),
+            value: value[1].map(|v| v.into_float64().0),
+        }
+    }
+
+    fn aggregate<'a>(&'a self, values: impl Iterator<Item = &'a Self::Value>) -> Datum {
+        let mut fraction = None;
+        // rows with null value are ignored, following PostgreSQL
+        let values: Vec<f64> = values
+            .filter_map(|data| {
+                fraction = data.fraction;
+                data.value
+            })
+            .collect();
+        let fraction = fraction?;
+        if values.is_empty() {
+            return None;
+        }
+        let rn = fraction * (values.len() - 1) as f64;
+        let (lo, hi) = (rn.floor() as usize, rn.ceil() as usize);
+        let result = if lo == hi {
+            values[lo]
+        } else {
+            values[lo] * (hi as f64 - rn) + values[hi] * (rn - lo as f64)
+        };
+        Some(result.into())
+    }
+}
+
+pub struct PercentileDiscData {
+    fraction: Option<f64>,
+    value: Datum,
+}
+
+/// Ordered-set aggregator for `percentile_disc`. It returns the first cached value whose
+/// position in the cache order reaches the requested fraction, without interpolation.
+pub struct PercentileDisc;
+
+impl StateCacheAggregator for PercentileDisc {
+    type Value = PercentileDiscData;
+
+    fn convert_cache_value(&self, value: SmallVec<[DatumRef<'_>; 2]>) -> Self::Value {
+        PercentileDiscData {
+            fraction: value[0].map(|f| f.into_float64().0),
+            value: value[1].map(ScalarRefImpl::into_scalar_impl),
+        }
+    }
+
+    fn aggregate<'a>(&'a self, values: impl Iterator<Item = &'a Self::Value>) -> Datum {
+        let mut fraction = None;
+        // rows with null value are ignored, following PostgreSQL
+        let values: Vec<&Datum> = values
+            .filter_map(|data| {
+                fraction = data.fraction;
+                data.value.as_ref().map(|_| &data.value)
+            })
+            .collect();
+        let fraction = fraction?;
+        if values.is_empty() {
+            return None;
+        }
+        let rn = fraction * values.len() as f64;
+        let idx = (rn.ceil() as usize).max(1) - 1;
+        values[idx].clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::executor::aggregation::state_cache::cache::OrderedCache;
+
+    #[test]
+    fn test_percentile_cont_aggregate() {
+        let agg = PercentileCont;
+
+        let mut cache = OrderedCache::new(10);
+        assert_eq!(agg.aggregate(cache.iter_values()), None);
+
+        for (key, value) in [(1, 10.0), (2, 20.0), (3, 30.0), (4, 40.0)] {
+            cache.insert(
+                vec![key],
+                PercentileContData {
+                    fraction: Some(0.5),
+                    value: Some(value),
+                },
+            );
+        }
+        assert_eq!(agg.aggregate(cache.iter_values()), Some(25.0.into()));
+
+        cache.insert(
+            vec![0],
+            PercentileContData {
+                fraction: Some(0.5),
+                value: None,
+            },
+        );
+        // null values are ignored
+        assert_eq!(agg.aggregate(cache.iter_values()), Some(25.0.into()));
+    }
+
+    #[test]
+    fn test_percentile_disc_aggregate() {
+        let agg = PercentileDisc;
+
+        let mut cache = OrderedCache::new(10);
+        assert_eq!(agg.aggregate(cache.iter_values()), None);
+
+        for (key, value) in [(1, 10i32), (2, 20), (3, 30)] {
+            cache.insert(
+                vec![key],
+                PercentileDiscData {
+                    fraction: Some(0.5),
+                    value: Some(value.into()),
+                },
+            );
+        }
+        assert_eq!(agg.aggregate(cache.iter_values()), Some(20.into()));
+    }
+}
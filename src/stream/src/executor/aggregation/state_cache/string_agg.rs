@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
+
 use risingwave_common::types::{Datum, DatumRef, ScalarRefImpl};
 use smallvec::SmallVec;
 
@@ -22,7 +24,16 @@ pub struct StringAggData {
     value: String,
 }
 
-pub struct StringAgg;
+pub struct StringAgg {
+    /// Whether to deduplicate the agg values before aggregation.
+    distinct: bool,
+}
+
+impl StringAgg {
+    pub fn new(distinct: bool) -> Self {
+        Self { distinct }
+    }
+}
 
 impl StateCacheAggregator for StringAgg {
     type Value = StringAggData;
@@ -40,16 +51,24 @@ impl StateCacheAggregator for StringAgg {
         }
     }
 
-    fn aggregate<'a>(&'a self, mut values: impl Iterator<Item = &'a Self::Value>) -> Datum {
-        let mut result = match values.next() {
-            Some(data) => data.value.clone(),
-            None => return None, // return NULL if no rows to aggregate
-        };
+    fn aggregate<'a>(&'a self, values: impl Iterator<Item = &'a Self::Value>) -> Datum {
+        let mut seen = HashSet::new();
+        let mut result: Option<String> = None;
         for StringAggData { value, delim } in values {
-            result.push_str(delim);
-            result.push_str(value);
+            if self.distinct && !seen.insert(value.clone()) {
+                // skip duplicate values, the first one in cache order wins
+                continue;
+            }
+            match &mut result {
+                Some(result) => {
+                    result.push_str(delim);
+                    result.push_str(value);
+                }
+                None => result = Some(value.clone()),
+            }
         }
-        Some(result.into())
+        // return NULL if no rows to aggregate
+        result.map(Into::into)
     }
 }
 
@@ -60,7 +79,7 @@ mod tests {
 
     #[test]
     fn test_string_agg_aggregate() {
-        let agg = StringAgg;
+        let agg = StringAgg::new(false);
 
         let mut cache = OrderedCache::new(10);
         assert_eq!(agg.aggregate(cache.iter_values()), None);
@@ -97,9 +116,43 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_string_agg_aggregate_distinct() {
+        let agg = StringAgg::new(true);
+
+        let mut cache = OrderedCache::new(10);
+        assert_eq!(agg.aggregate(cache.iter_values()), None);
+
+        cache.insert(
+            vec![1, 2, 3],
+            StringAggData {
+                delim: "_".to_string(),
+                value: "hello".to_string(),
+            },
+        );
+        cache.insert(
+            vec![1, 3, 4],
+            StringAggData {
+                delim: ",".to_string(),
+                value: "world".to_string(),
+            },
+        );
+        cache.insert(
+            vec![2, 0, 8],
+            StringAggData {
+                delim: "/".to_string(),
+                value: "hello".to_string(),
+            },
+        );
+        assert_eq!(
+            agg.aggregate(cache.iter_values()),
+            Some("hello,world".to_string().into())
+        );
+    }
+
     #[test]
     fn test_string_agg_convert() {
-        let agg = StringAgg;
+        let agg = StringAgg::new(false);
         let args = SmallVec::from_vec(vec![Some("hello".into()), Some("world".into())]);
         let value = agg.convert_cache_value(args);
         assert_eq!(value.value, "hello".to_string());
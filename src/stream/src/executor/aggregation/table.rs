@@ -21,7 +21,9 @@ use risingwave_common::types::Datum;
 use risingwave_expr::expr::AggKind;
 use risingwave_storage::StateStore;
 
-use super::agg_impl::AppendOnlyStreamingApproxCountDistinct;
+use super::agg_impl::{
+    AppendOnlyStreamingApproxCountDistinct, StreamingApproxPercentile, StreamingApproxTopK,
+};
 use super::AggCall;
 use crate::common::table::state_table::StateTable;
 use crate::executor::StreamExecutorResult;
@@ -78,6 +80,8 @@ impl<S: StateStore> TableState<S> {
                 AggKind::ApproxCountDistinct => {
                     Box::new(AppendOnlyStreamingApproxCountDistinct::new())
                 }
+                AggKind::ApproxTopK => Box::new(StreamingApproxTopK::new()),
+                AggKind::ApproxPercentile => Box::new(StreamingApproxPercentile::new()),
                 _ => panic!(
                     "Agg kind `{}` is not expected to have table state",
                     agg_call.kind
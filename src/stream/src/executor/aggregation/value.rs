@@ -91,6 +91,7 @@ mod tests {
             args: AggArgs::Unary(DataType::Int64, 0),
             return_type: DataType::Int64,
             order_pairs: vec![],
+            distinct: false,
             append_only: false,
             filter: None,
         }
@@ -133,6 +134,7 @@ mod tests {
             args: AggArgs::Unary(DataType::Int64, 0),
             return_type: DataType::Int64,
             order_pairs: vec![],
+            distinct: false,
             append_only: true,
             filter: None,
         }
@@ -0,0 +1,352 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use futures::StreamExt;
+use futures_async_stream::try_stream;
+use risingwave_common::array::StreamChunk;
+use risingwave_common::buffer::BitmapBuilder;
+use risingwave_common::catalog::Schema;
+use risingwave_common::row::{OwnedRow, Row, RowExt};
+use risingwave_storage::StateStore;
+
+use super::error::StreamExecutorError;
+use super::{
+    expect_append_only, expect_first_barrier, ActorContextRef, BoxedExecutor, BoxedMessageStream,
+    Executor, Message, PkIndices, StreamExecutorResult,
+};
+use crate::cache::{cache_may_stale, new_unbounded, ExecutorCache};
+use crate::common::table::state_table::StateTable;
+use crate::task::AtomicU64Ref;
+
+/// [`AppendOnlyDedupExecutor`] deduplicates an append-only input on the `dedup_cols`, keeping only
+/// the first row of each distinct key. It is primarily used for `DISTINCT ON` queries where any
+/// row of a key is acceptable, so the output is append-only as well.
+///
+/// The state table stores exactly one row per key, with the `dedup_cols` as its primary key. When
+/// a watermark arrives on the first dedup column, the rows whose keys are below the watermark are
+/// cleaned from the state table, since those keys will never appear in the input again.
+pub struct AppendOnlyDedupExecutor<S: StateStore> {
+    ctx: ActorContextRef,
+
+    /// We make it `Option` here due to lifetime restrictions. It will be taken (`Option.take()`)
+    /// after executing.
+    input: Option<BoxedExecutor>,
+
+    pk_indices: PkIndices,
+
+    identity: String,
+
+    schema: Schema,
+
+    /// The state table storing one row per distinct key, whose primary key is `dedup_cols`.
+    state_table: StateTable<S>,
+
+    /// The indices of the columns to deduplicate on.
+    dedup_cols: Vec<usize>,
+
+    /// Cache of the keys that are known to exist in the state table, to avoid fetching from
+    /// storage for every row.
+    cache: ExecutorCache<OwnedRow, ()>,
+}
+
+impl<S: StateStore> AppendOnlyDedupExecutor<S> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        ctx: ActorContextRef,
+        input: BoxedExecutor,
+        pk_indices: PkIndices,
+        executor_id: u64,
+        state_table: StateTable<S>,
+        dedup_cols: Vec<usize>,
+        watermark_epoch: AtomicU64Ref,
+    ) -> Self {
+        let schema = input.schema().clone();
+        Self {
+            ctx,
+            input: Some(input),
+            pk_indices,
+            identity: format!("AppendOnlyDedupExecutor {:X}", executor_id),
+            schema,
+            state_table,
+            dedup_cols,
+            cache: ExecutorCache::new(new_unbounded(watermark_epoch)),
+        }
+    }
+
+    #[try_stream(ok = Message, error = StreamExecutorError)]
+    async fn execute_inner(mut self) {
+        let mut input = self.input.take().unwrap().execute();
+
+        // Consume the first barrier message and initialize state table.
+        let barrier = expect_first_barrier(&mut input).await?;
+        self.state_table.init_epoch(barrier.epoch);
+        self.cache.update_epoch(barrier.epoch.curr);
+
+        // The first barrier message should be propagated.
+        yield Message::Barrier(barrier);
+
+        #[for_await]
+        for msg in input {
+            match msg? {
+                Message::Chunk(chunk) => {
+                    expect_append_only(&chunk, &self.identity)?;
+                    let chunk = chunk.compact();
+
+                    // Only keep the rows whose key is seen for the first time. Note that we add
+                    // each new key to the cache immediately, so duplicates within the same chunk
+                    // are also filtered out.
+                    let mut vis_builder = BitmapBuilder::zeroed(chunk.capacity());
+                    for (i, (_op, row_ref)) in chunk.rows().enumerate() {
+                        let key = row_ref.project(&self.dedup_cols).into_owned_row();
+                        if self.key_seen(&key).await? {
+                            continue;
+                        }
+                        self.state_table.insert(row_ref);
+                        self.cache.put(key, ());
+                        vis_builder.set(i, true);
+                    }
+
+                    let vis = vis_builder.finish();
+                    if vis.count_ones() > 0 {
+                        let (ops, columns, _) = chunk.into_inner();
+                        yield Message::Chunk(StreamChunk::new(ops, columns, Some(vis)));
+                    }
+                }
+
+                Message::Watermark(watermark) => {
+                    if watermark.col_idx == self.dedup_cols[0] {
+                        // The watermark guarantees that no row with a smaller key prefix will
+                        // arrive again, so the rows stored for those keys can be cleaned from
+                        // the state table. Stale cache entries are harmless for the same reason,
+                        // hence the cache is left to normal LRU eviction.
+                        self.state_table.update_watermark(watermark.val.clone());
+                    }
+                    yield Message::Watermark(watermark);
+                }
+
+                Message::Barrier(barrier) => {
+                    self.state_table.commit(barrier.epoch).await?;
+
+                    // Update the vnode bitmap for the state table if asked. The cache entries of
+                    // the vnodes that are no longer owned may have been updated by executors on
+                    // other parallel units, so clear the cache if it may be stale.
+                    if let Some(vnode_bitmap) = barrier.as_update_vnode_bitmap(self.ctx.id) {
+                        let prev_vnode_bitmap =
+                            self.state_table.update_vnode_bitmap(vnode_bitmap.clone());
+                        if cache_may_stale(&prev_vnode_bitmap, &vnode_bitmap) {
+                            self.cache.clear();
+                        }
+                    }
+
+                    self.cache.evict();
+                    self.cache.update_epoch(barrier.epoch.curr);
+
+                    yield Message::Barrier(barrier);
+                }
+            }
+        }
+    }
+
+    /// Returns whether the key has been seen before, i.e. exists in the cache or in the state
+    /// table.
+    async fn key_seen(&mut self, key: &OwnedRow) -> StreamExecutorResult<bool> {
+        if self.cache.contains(key) {
+            Ok(true)
+        } else if self.state_table.get_row(key).await?.is_some() {
+            // The key is not cached but exists in storage, e.g. after recovery or cache
+            // eviction. Refill the cache.
+            self.cache.put(key.clone(), ());
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+}
+
+impl<S: StateStore> Executor for AppendOnlyDedupExecutor<S> {
+    fn execute(self: Box<Self>) -> BoxedMessageStream {
+        self.execute_inner().boxed()
+    }
+
+    fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    fn pk_indices(&self) -> super::PkIndicesRef<'_> {
+        &self.pk_indices
+    }
+
+    fn identity(&self) -> &str {
+        self.identity.as_str()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicU64;
+    use std::sync::Arc;
+
+    use risingwave_common::array::stream_chunk::StreamChunkTestExt;
+    use risingwave_common::catalog::{ColumnDesc, ColumnId, Field, TableId};
+    use risingwave_common::types::DataType;
+    use risingwave_common::util::sort_util::OrderType;
+    use risingwave_storage::memory::MemoryStateStore;
+
+    use super::*;
+    use crate::executor::test_utils::{MessageSender, MockSource};
+    use crate::executor::ActorContext;
+
+    async fn create_state_table(
+        memory_state_store: MemoryStateStore,
+    ) -> StateTable<MemoryStateStore> {
+        let table_id = TableId::new(1);
+        let column_descs = vec![
+            ColumnDesc::unnamed(ColumnId::new(0), DataType::Int64),
+            ColumnDesc::unnamed(ColumnId::new(1), DataType::Int64),
+        ];
+        let order_types = vec![OrderType::Ascending];
+        let pk_indices = vec![0];
+        StateTable::new_without_distribution(
+            memory_state_store,
+            table_id,
+            column_descs,
+            order_types,
+            pk_indices,
+        )
+        .await
+    }
+
+    fn create_executor(
+        state_table: StateTable<MemoryStateStore>,
+    ) -> (MessageSender, BoxedMessageStream) {
+        let schema = Schema::new(vec![
+            Field::unnamed(DataType::Int64),
+            Field::unnamed(DataType::Int64),
+        ]);
+        let pk_indices = vec![0];
+        let (tx, source) = MockSource::channel(schema, pk_indices.clone());
+        let dedup_executor = AppendOnlyDedupExecutor::new(
+            ActorContext::create(123),
+            Box::new(source),
+            pk_indices,
+            1,
+            state_table,
+            vec![0],
+            Arc::new(AtomicU64::new(0)),
+        );
+        (tx, Box::new(dedup_executor).execute())
+    }
+
+    #[tokio::test]
+    async fn test_dedup_executor() {
+        let state_store = MemoryStateStore::new();
+        let state_table = create_state_table(state_store.clone()).await;
+        let (mut tx, mut dedup_executor) = create_executor(state_table);
+
+        // Init barrier
+        tx.push_barrier(1, false);
+
+        // Consume the barrier
+        dedup_executor.next().await.unwrap().unwrap();
+
+        // Push a chunk with duplicates, both within the chunk and across chunks later.
+        tx.push_chunk(StreamChunk::from_pretty(
+            " I I
+            + 1 1
+            + 2 2
+            + 1 7",
+        ));
+
+        // Only the first row of each key should be kept.
+        let chunk_msg = dedup_executor.next().await.unwrap().unwrap();
+        assert_eq!(
+            chunk_msg.into_chunk().unwrap().compact(),
+            StreamChunk::from_pretty(
+                " I I
+                + 1 1
+                + 2 2"
+            )
+        );
+
+        // Push barrier
+        tx.push_barrier(2, false);
+
+        // Consume the barrier
+        dedup_executor.next().await.unwrap().unwrap();
+
+        // Push a chunk with an existing key and a new key.
+        tx.push_chunk(StreamChunk::from_pretty(
+            " I I
+            + 2 8
+            + 3 3",
+        ));
+
+        let chunk_msg = dedup_executor.next().await.unwrap().unwrap();
+        assert_eq!(
+            chunk_msg.into_chunk().unwrap().compact(),
+            StreamChunk::from_pretty(
+                " I I
+                + 3 3"
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn test_dedup_executor_fail_over() {
+        let state_store = MemoryStateStore::new();
+        let state_table = create_state_table(state_store.clone()).await;
+        let (mut tx, mut dedup_executor) = create_executor(state_table);
+
+        // Init barrier
+        tx.push_barrier(1, false);
+
+        // Consume the barrier
+        dedup_executor.next().await.unwrap().unwrap();
+
+        // Push a chunk and persist it with a barrier.
+        tx.push_chunk(StreamChunk::from_pretty(
+            " I I
+            + 1 1",
+        ));
+        dedup_executor.next().await.unwrap().unwrap();
+        tx.push_barrier(2, false);
+        dedup_executor.next().await.unwrap().unwrap();
+
+        // Mock fail over
+        let state_table = create_state_table(state_store.clone()).await;
+        let (mut recovered_tx, mut recovered_dedup_executor) = create_executor(state_table);
+
+        // Push barrier
+        recovered_tx.push_barrier(3, false);
+
+        // Consume the barrier
+        recovered_dedup_executor.next().await.unwrap().unwrap();
+
+        // The key deduplicated before the failure should still be deduplicated after recovery.
+        recovered_tx.push_chunk(StreamChunk::from_pretty(
+            " I I
+            + 1 9
+            + 4 4",
+        ));
+
+        let chunk_msg = recovered_dedup_executor.next().await.unwrap().unwrap();
+        assert_eq!(
+            chunk_msg.into_chunk().unwrap().compact(),
+            StreamChunk::from_pretty(
+                " I I
+                + 4 4"
+            )
+        );
+    }
+}
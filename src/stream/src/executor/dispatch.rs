@@ -17,6 +17,7 @@ use std::fmt::Debug;
 use std::future::Future;
 use std::iter::repeat_with;
 use std::sync::Arc;
+use std::time::Duration;
 
 use async_stack_trace::StackTrace;
 use futures::Stream;
@@ -36,8 +37,13 @@ use super::exchange::output::{new_output, BoxedOutput};
 use super::Watermark;
 use crate::error::StreamResult;
 use crate::executor::monitor::StreamingMetrics;
-use crate::executor::{Barrier, BoxedExecutor, Message, Mutation, StreamConsumer};
-use crate::task::{ActorId, DispatcherId, SharedContext};
+use crate::executor::{ActorContextRef, Barrier, BoxedExecutor, Message, Mutation, StreamConsumer};
+use crate::task::{ActorId, BackpressureReporter, DispatcherId, SharedContext};
+
+/// How often a suspended actor re-checks its memory usage.
+const MEMORY_SUSPENSION_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+/// How long an actor may stay suspended before an event is reported.
+const MEMORY_SUSPENSION_REPORT_THRESHOLD: Duration = Duration::from_secs(10);
 
 /// [`DispatchExecutor`] consumes messages and send them into downstream actors. Usually,
 /// data chunks will be dispatched with some specified policy, while control message
@@ -51,8 +57,18 @@ struct DispatchExecutorInner {
     dispatchers: Vec<DispatcherImpl>,
     actor_id: u32,
     actor_id_str: String,
+    fragment_id_str: String,
+    actor_context: ActorContextRef,
+    /// The hard limit of memory usage for this actor in bytes. 0 means no limit.
+    memory_hard_limit_bytes: usize,
     context: Arc<SharedContext>,
     metrics: Arc<StreamingMetrics>,
+    /// Time spent blocked on dispatching per downstream fragment since the last barrier, in
+    /// nanoseconds. The id of a dispatcher is the id of its downstream fragment.
+    blocked_ns: HashMap<DispatcherId, u64>,
+    /// When the last barrier was dispatched, for measuring the interval `blocked_ns` covers.
+    last_barrier_at: minstant::Instant,
+    backpressure_reporter: BackpressureReporter,
 }
 
 impl DispatchExecutorInner {
@@ -66,11 +82,14 @@ impl DispatchExecutorInner {
     }
 
     async fn dispatch(&mut self, msg: Message) -> StreamResult<()> {
-        let start_time = minstant::Instant::now();
         match msg {
             Message::Watermark(watermark) => {
                 for dispatcher in &mut self.dispatchers {
+                    let downstream_fragment_id = dispatcher.dispatcher_id();
+                    let start_time = minstant::Instant::now();
                     dispatcher.dispatch_watermark(watermark.clone()).await?;
+                    *self.blocked_ns.entry(downstream_fragment_id).or_default() +=
+                        start_time.elapsed().as_nanos() as u64;
                 }
             }
             Message::Chunk(chunk) => {
@@ -80,10 +99,19 @@ impl DispatchExecutorInner {
                     .inc_by(chunk.cardinality() as _);
                 if self.dispatchers.len() == 1 {
                     // special clone optimization when there is only one downstream dispatcher
-                    self.single_inner_mut().dispatch_data(chunk).await?;
+                    let dispatcher = self.single_inner_mut();
+                    let downstream_fragment_id = dispatcher.dispatcher_id();
+                    let start_time = minstant::Instant::now();
+                    dispatcher.dispatch_data(chunk).await?;
+                    *self.blocked_ns.entry(downstream_fragment_id).or_default() +=
+                        start_time.elapsed().as_nanos() as u64;
                 } else {
                     for dispatcher in &mut self.dispatchers {
+                        let downstream_fragment_id = dispatcher.dispatcher_id();
+                        let start_time = minstant::Instant::now();
                         dispatcher.dispatch_data(chunk.clone()).await?;
+                        *self.blocked_ns.entry(downstream_fragment_id).or_default() +=
+                            start_time.elapsed().as_nanos() as u64;
                     }
                 }
             }
@@ -91,16 +119,72 @@ impl DispatchExecutorInner {
                 let mutation = barrier.mutation.clone();
                 self.pre_mutate_dispatchers(&mutation)?;
                 for dispatcher in &mut self.dispatchers {
+                    let downstream_fragment_id = dispatcher.dispatcher_id();
+                    let start_time = minstant::Instant::now();
                     dispatcher.dispatch_barrier(barrier.clone()).await?;
+                    *self.blocked_ns.entry(downstream_fragment_id).or_default() +=
+                        start_time.elapsed().as_nanos() as u64;
                 }
                 self.post_mutate_dispatchers(&mutation)?;
+                self.report_backpressure();
             }
         };
+        Ok(())
+    }
+
+    /// Flush the per-edge blocked time accumulated since the last barrier to the metrics and
+    /// report it to the local barrier manager, to be piggybacked to the meta service for the
+    /// fragment-level backpressure roll-up.
+    fn report_backpressure(&mut self) {
+        let elapsed_ns = self.last_barrier_at.elapsed().as_nanos() as u64;
+        self.last_barrier_at = minstant::Instant::now();
+        let mut blocked_ns = Vec::with_capacity(self.blocked_ns.len());
+        for (downstream_fragment_id, blocked) in self.blocked_ns.drain() {
+            self.metrics
+                .actor_output_buffer_blocking_duration_ns
+                .with_label_values(&[
+                    &self.actor_id_str,
+                    &self.fragment_id_str,
+                    &downstream_fragment_id.to_string(),
+                ])
+                .inc_by(blocked);
+            blocked_ns.push((downstream_fragment_id as u32, blocked));
+        }
+        self.backpressure_reporter.update(blocked_ns, elapsed_ns);
+    }
+
+    /// Suspend at a chunk boundary while the actor's memory usage exceeds the configured hard
+    /// limit, backpressuring upstream instead of risking a node-wide OOM. An event is reported
+    /// once if the suspension persists beyond [`MEMORY_SUSPENSION_REPORT_THRESHOLD`].
+    async fn wait_for_memory_quota(&self) {
+        if self.memory_hard_limit_bytes == 0
+            || self.actor_context.mem_usage() <= self.memory_hard_limit_bytes
+        {
+            return;
+        }
         self.metrics
-            .actor_output_buffer_blocking_duration_ns
+            .actor_memory_suspension_count
             .with_label_values(&[&self.actor_id_str])
-            .inc_by(start_time.elapsed().as_nanos() as u64);
-        Ok(())
+            .inc();
+        let suspended_since = minstant::Instant::now();
+        let mut reported = false;
+        while self.actor_context.mem_usage() > self.memory_hard_limit_bytes {
+            tokio::time::sleep(MEMORY_SUSPENSION_CHECK_INTERVAL).await;
+            if !reported && suspended_since.elapsed() >= MEMORY_SUSPENSION_REPORT_THRESHOLD {
+                tracing::warn!(
+                    actor_id = self.actor_id,
+                    mem_usage = self.actor_context.mem_usage(),
+                    memory_hard_limit_bytes = self.memory_hard_limit_bytes,
+                    "actor has been suspended over its memory hard limit for more than {:?}",
+                    MEMORY_SUSPENSION_REPORT_THRESHOLD,
+                );
+                reported = true;
+            }
+        }
+        self.metrics
+            .actor_memory_suspension_duration_ns
+            .with_label_values(&[&self.actor_id_str])
+            .inc_by(suspended_since.elapsed().as_nanos() as u64);
     }
 
     /// Add new dispatchers to the executor. Will check whether their ids are unique.
@@ -235,17 +319,27 @@ impl DispatchExecutor {
         input: BoxedExecutor,
         dispatchers: Vec<DispatcherImpl>,
         actor_id: u32,
+        fragment_id: u32,
+        actor_context: ActorContextRef,
+        memory_hard_limit_bytes: usize,
         context: Arc<SharedContext>,
         metrics: Arc<StreamingMetrics>,
     ) -> Self {
+        let backpressure_reporter = context.register_backpressure(actor_id, fragment_id);
         Self {
             input,
             inner: DispatchExecutorInner {
                 dispatchers,
                 actor_id,
                 actor_id_str: actor_id.to_string(),
+                fragment_id_str: fragment_id.to_string(),
+                actor_context,
+                memory_hard_limit_bytes,
                 context,
                 metrics,
+                blocked_ns: Default::default(),
+                last_barrier_at: minstant::Instant::now(),
+                backpressure_reporter,
             },
         }
     }
@@ -267,6 +361,12 @@ impl StreamConsumer for DispatchExecutor {
                     Message::Barrier(ref barrier) => (Some(barrier.clone()), false),
                     Message::Watermark(_) => (None, true),
                 };
+                if matches!(msg, Message::Chunk(_)) {
+                    self.inner
+                        .wait_for_memory_quota()
+                        .verbose_stack_trace("actor_memory_suspend")
+                        .await;
+                }
                 self.inner
                     .dispatch(msg)
                     .verbose_stack_trace(if barrier.is_some() {
@@ -866,6 +966,7 @@ mod tests {
     use crate::executor::exchange::output::Output;
     use crate::executor::exchange::permit::channel_for_test;
     use crate::executor::receiver::ReceiverExecutor;
+    use crate::executor::ActorContext;
     use crate::task::test_utils::{add_local_channels, helper_make_local_actor};
 
     #[derive(Debug)]
@@ -1026,6 +1127,9 @@ mod tests {
             input,
             vec![broadcast_dispatcher, simple_dispatcher],
             actor_id,
+            514,
+            ActorContext::create(actor_id),
+            0,
             ctx.clone(),
             metrics,
         ))
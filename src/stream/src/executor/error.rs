@@ -55,6 +55,9 @@ enum Inner {
     #[error("Failed to align barrier: expected {0:?} but got {1:?}")]
     AlignBarrier(Box<Barrier>, Box<Barrier>),
 
+    #[error("Append-only violation: {0} received a retraction from upstream")]
+    AppendOnlyViolation(String),
+
     #[error("Connector error: {0}")]
     ConnectorError(BoxedError),
 
@@ -78,6 +81,10 @@ impl StreamExecutorError {
         Inner::AlignBarrier(expected.into(), received.into()).into()
     }
 
+    pub fn append_only_violation(identity: impl Into<String>) -> Self {
+        Inner::AppendOnlyViolation(identity.into()).into()
+    }
+
     pub fn connector_error(error: impl Error) -> Self {
         Inner::ConnectorError(error.into()).into()
     }
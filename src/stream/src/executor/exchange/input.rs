@@ -14,7 +14,7 @@
 
 use std::pin::Pin;
 use std::task::{Context, Poll};
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use anyhow::Context as _;
 use async_stack_trace::{SpanValue, StackTrace};
@@ -159,7 +159,11 @@ impl RemoteInput {
         const SAMPLING_FREQUENCY: u64 = 100;
         let span: SpanValue = format!("RemoteInput (actor {up_actor_id})").into();
 
+        // The longest time permits may be withheld from the upstream for batching.
+        const PERMIT_FLUSH_INTERVAL: Duration = Duration::from_millis(100);
+
         let mut batched_permits_accumulated = 0;
+        let mut last_permits_flush = Instant::now();
 
         pin_mut!(stream);
         while let Some(data_res) = stream.next().verbose_stack_trace(span.clone()).await {
@@ -187,12 +191,21 @@ impl RemoteInput {
                     };
                     rr += 1;
 
-                    // Batch the permits we received to reduce the backward `AddPermits` messages.
+                    // Batch the permits we received to reduce the backward `AddPermits`
+                    // messages. Flush not only when the batch is full, but also when we have
+                    // withheld the permits for too long, so that the effective batch size
+                    // adapts to the consumption rate: a fast consumer returns permits in full
+                    // batches, while a slow one returns them (almost) per message instead of
+                    // starving the upstream of permits.
                     batched_permits_accumulated += permits;
-                    if batched_permits_accumulated >= batched_permits_limit as Permits {
+                    if batched_permits_accumulated >= batched_permits_limit as Permits
+                        || (batched_permits_accumulated > 0
+                            && last_permits_flush.elapsed() >= PERMIT_FLUSH_INTERVAL)
+                    {
                         permits_tx
                             .send(std::mem::take(&mut batched_permits_accumulated))
                             .context("RemoteInput backward permits channel closed.")?;
+                        last_permits_flush = Instant::now();
                     }
 
                     match msg_res {
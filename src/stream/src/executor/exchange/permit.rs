@@ -13,9 +13,20 @@
 // limitations under the License.
 
 //! Channel implementation for permit-based back-pressure.
+//!
+//! The channel is strictly FIFO: in particular, barriers are never delivered ahead of the data
+//! chunks buffered before them, even though that would decouple checkpoint latency from the
+//! queue depth under back-pressure. The position of a barrier in the stream is what attributes
+//! each chunk to an epoch: a chunk overtaken by the barrier ending epoch `N` would be
+//! checkpointed upstream as part of `N` but processed downstream in `N + 1`, so after a
+//! recovery to `N` it would be applied twice. Prioritized barrier delivery would thus require
+//! persisting the overtaken in-flight messages as part of the checkpoint, which we deliberately
+//! do not support.
 
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 
+use prometheus::core::{AtomicI64, GenericGauge};
 use tokio::sync::{mpsc, Semaphore};
 
 use crate::executor::Message;
@@ -32,43 +43,82 @@ pub struct MessageWithPermits {
     pub permits: Permits,
 }
 
+/// Statistics of an exchange channel, shared by the sender and the receiver.
+#[derive(Default)]
+pub struct ChannelStats {
+    /// Permits (roughly rows) currently buffered in the channel.
+    occupancy_rows: AtomicUsize,
+    /// Total number of sends so far that had to wait for permits.
+    blocked_sends: AtomicUsize,
+}
+
+impl ChannelStats {
+    pub fn occupancy_rows(&self) -> usize {
+        self.occupancy_rows.load(Ordering::Relaxed)
+    }
+
+    pub fn blocked_sends(&self) -> usize {
+        self.blocked_sends.load(Ordering::Relaxed)
+    }
+}
+
+/// Per-channel gauges reported by the receiver, labeled with the upstream and downstream actor
+/// ids.
+pub struct ChannelMetrics {
+    pub occupancy_rows: GenericGauge<AtomicI64>,
+    pub capacity: GenericGauge<AtomicI64>,
+}
+
 /// Create a channel for the exchange service
-pub fn channel(initial_permits: usize, batched_permits: usize) -> (Sender, Receiver) {
+pub fn channel(
+    initial_permits: usize,
+    batched_permits: usize,
+    adaptive: bool,
+    metrics: Option<ChannelMetrics>,
+) -> (Sender, Receiver) {
     // Use an unbounded channel since we manage the permits manually.
     let (tx, rx) = mpsc::unbounded_channel();
     let permits = Arc::new(Semaphore::new(initial_permits));
     let max_chunk_permits: usize = initial_permits - batched_permits;
+    let stats = Arc::new(ChannelStats::default());
+    let auto_tuner = adaptive.then(|| {
+        PermitAutoTuner::new(
+            stats.clone(),
+            permits.clone(),
+            initial_permits,
+            batched_permits,
+        )
+    });
     (
         Sender {
             tx,
             permits: permits.clone(),
+            stats: stats.clone(),
             max_chunk_permits,
         },
-        Receiver { rx, permits },
+        Receiver {
+            rx,
+            permits,
+            stats,
+            auto_tuner,
+            metrics,
+            initial_permits,
+            batched_permits,
+        },
     )
 }
 
 pub fn channel_for_test() -> (Sender, Receiver) {
-    // Use an unbounded channel since we manage the permits manually.
-    let (tx, rx) = mpsc::unbounded_channel();
     const INITIAL_PERMITS: usize = 8192;
     const BATCHED_PERMITS: usize = 1024;
-    let permits = Arc::new(Semaphore::new(INITIAL_PERMITS));
-    let max_chunk_permits: usize = INITIAL_PERMITS - BATCHED_PERMITS;
-    (
-        Sender {
-            tx,
-            permits: permits.clone(),
-            max_chunk_permits,
-        },
-        Receiver { rx, permits },
-    )
+    channel(INITIAL_PERMITS, BATCHED_PERMITS, false, None)
 }
 
 /// The sender of the exchange service with permit-based back-pressure.
 pub struct Sender {
     tx: mpsc::UnboundedSender<MessageWithPermits>,
     permits: Arc<Semaphore>,
+    stats: Arc<ChannelStats>,
     /// The maximum permits required by a chunk. If there're too many rows in a chunk, we only
     /// acquire these permits. [`BATCHED_PERMITS`] is subtracted to avoid deadlock with
     /// batching.
@@ -91,8 +141,14 @@ impl Sender {
             Message::Barrier(_) | Message::Watermark(_) => 0,
         } as Permits;
 
+        if self.permits.available_permits() < permits as usize {
+            self.stats.blocked_sends.fetch_add(1, Ordering::Relaxed);
+        }
         // The semaphore should never be closed.
         self.permits.acquire_many(permits).await.unwrap().forget();
+        self.stats
+            .occupancy_rows
+            .fetch_add(permits as usize, Ordering::Relaxed);
 
         self.tx
             .send(MessageWithPermits { message, permits })
@@ -104,6 +160,14 @@ impl Sender {
 pub struct Receiver {
     rx: mpsc::UnboundedReceiver<MessageWithPermits>,
     permits: Arc<Semaphore>,
+    stats: Arc<ChannelStats>,
+    /// Auto-tunes the permit budget based on the observed occupancy. `None` if the adaptive
+    /// mode is disabled.
+    auto_tuner: Option<PermitAutoTuner>,
+    /// Per-channel gauges. `None` for channels created without metrics, e.g. in tests.
+    metrics: Option<ChannelMetrics>,
+    initial_permits: usize,
+    batched_permits: usize,
 }
 
 impl Receiver {
@@ -114,6 +178,19 @@ impl Receiver {
     pub async fn recv(&mut self) -> Option<Message> {
         let MessageWithPermits { message, permits } = self.recv_raw().await?;
         self.permits.add_permits(permits as usize);
+        if let Some(auto_tuner) = &mut self.auto_tuner {
+            auto_tuner.observe();
+        }
+        if let Some(metrics) = &self.metrics {
+            metrics
+                .occupancy_rows
+                .set(self.stats.occupancy_rows() as i64);
+            metrics.capacity.set(
+                self.auto_tuner
+                    .as_ref()
+                    .map_or(self.initial_permits, |t| t.capacity()) as i64,
+            );
+        }
         Some(message)
     }
 
@@ -123,6 +200,9 @@ impl Receiver {
     /// Returns error if the channel is currently empty.
     pub fn try_recv(&mut self) -> Result<Message, mpsc::error::TryRecvError> {
         let MessageWithPermits { message, permits } = self.rx.try_recv()?;
+        self.stats
+            .occupancy_rows
+            .fetch_sub(permits as usize, Ordering::Relaxed);
         self.permits.add_permits(permits as usize);
         Ok(message)
     }
@@ -133,11 +213,341 @@ impl Receiver {
     ///
     /// Returns `None` if the channel has been closed.
     pub async fn recv_raw(&mut self) -> Option<MessageWithPermits> {
-        self.rx.recv().await
+        let msg = self.rx.recv().await?;
+        self.stats
+            .occupancy_rows
+            .fetch_sub(msg.permits as usize, Ordering::Relaxed);
+        Some(msg)
+    }
+
+    /// Get a reference to the statistics of this channel.
+    pub fn stats(&self) -> Arc<ChannelStats> {
+        self.stats.clone()
     }
 
     /// Get a reference to the `permits` semaphore.
     pub fn permits(&self) -> Arc<Semaphore> {
         self.permits.clone()
     }
+
+    /// Create a [`PermitAdjuster`] for this channel. Used for remote exchange where the
+    /// serialized size of each message is known.
+    pub fn permit_adjuster(&self) -> PermitAdjuster {
+        PermitAdjuster::new(self.permits.clone(), self.initial_permits, self.batched_permits)
+    }
+}
+
+/// Grows or shrinks a semaphore towards a target capacity.
+///
+/// Shrinking is lazy: we can only take permits that are not held by in-flight messages, so the
+/// remainder is recorded as a deficit and reclaimed as they are added back.
+struct SemaphoreCapacity {
+    permits: Arc<Semaphore>,
+    /// The capacity the semaphore is currently sized to.
+    capacity: usize,
+    /// Permits yet to be reclaimed from the semaphore to reach a previously decided smaller
+    /// capacity.
+    deficit: usize,
+}
+
+impl SemaphoreCapacity {
+    fn new(permits: Arc<Semaphore>, capacity: usize) -> Self {
+        Self {
+            permits,
+            capacity,
+            deficit: 0,
+        }
+    }
+
+    fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    fn set_target(&mut self, target: usize) {
+        if target >= self.capacity {
+            let grown = target - self.capacity;
+            // Growth first cancels out any pending shrink.
+            let cancelled = self.deficit.min(grown);
+            self.deficit -= cancelled;
+            self.permits.add_permits(grown - cancelled);
+        } else {
+            self.deficit += self.capacity - target;
+        }
+        self.capacity = target;
+    }
+
+    fn reclaim(&mut self) {
+        while self.deficit > 0 {
+            match self.permits.try_acquire() {
+                Ok(permit) => {
+                    permit.forget();
+                    self.deficit -= 1;
+                }
+                Err(_) => break,
+            }
+        }
+    }
+}
+
+/// Adaptively resizes the permit budget of an exchange channel based on the observed message
+/// sizes.
+///
+/// The configured initial permits are row-based and implicitly assume rows of roughly
+/// [`PermitAdjuster::REFERENCE_BYTES_PER_ROW`] bytes. With wider rows the same number of
+/// buffered rows takes proportionally more memory, while with narrow rows the row budget
+/// throttles throughput long before memory becomes a concern. The adjuster tracks a rolling
+/// average of the bytes per row and resizes the semaphore towards a capacity that keeps the
+/// buffered bytes roughly constant, clamped so that the largest chunk can always make progress
+/// and the capacity never exceeds [`PermitAdjuster::MAX_GROWTH`] times the configured one.
+pub struct PermitAdjuster {
+    capacity: SemaphoreCapacity,
+    /// Rolling average of the observed bytes per row.
+    avg_bytes_per_row: usize,
+    initial_permits: usize,
+    batched_permits: usize,
+}
+
+impl PermitAdjuster {
+    /// The weight of the rolling average: a new observation contributes `1 / EWMA_WEIGHT`.
+    const EWMA_WEIGHT: usize = 8;
+    /// The maximum factor by which the capacity may exceed the configured initial permits, for
+    /// small-row workloads.
+    const MAX_GROWTH: usize = 4;
+    /// The assumed width of a row when the initial permits were configured.
+    const REFERENCE_BYTES_PER_ROW: usize = 128;
+
+    fn new(permits: Arc<Semaphore>, initial_permits: usize, batched_permits: usize) -> Self {
+        Self {
+            capacity: SemaphoreCapacity::new(permits, initial_permits),
+            avg_bytes_per_row: Self::REFERENCE_BYTES_PER_ROW,
+            initial_permits,
+            batched_permits,
+        }
+    }
+
+    /// The capacity the channel is currently sized to.
+    pub fn capacity(&self) -> usize {
+        self.capacity.capacity()
+    }
+
+    /// Record one message of `bytes` serialized bytes that acquired `permits` permits, and
+    /// resize the channel capacity accordingly.
+    pub fn observe(&mut self, bytes: usize, permits: Permits) {
+        if permits == 0 {
+            // Barriers and watermarks do not consume permits.
+            return;
+        }
+        let bytes_per_row = (bytes / permits as usize).max(1);
+        self.avg_bytes_per_row = (self.avg_bytes_per_row * (Self::EWMA_WEIGHT - 1)
+            + bytes_per_row)
+            / Self::EWMA_WEIGHT;
+
+        // Shrinking below the maximum chunk permits would block the sender forever, even with
+        // all permits available.
+        let min_capacity = self.initial_permits - self.batched_permits;
+        let target = (self.initial_permits * Self::REFERENCE_BYTES_PER_ROW
+            / self.avg_bytes_per_row)
+            .clamp(min_capacity, self.initial_permits * Self::MAX_GROWTH);
+
+        self.capacity.set_target(target);
+        self.capacity.reclaim();
+    }
+}
+
+/// Auto-tunes the permit budget of a local exchange channel based on the observed occupancy
+/// and sender blocking, so that e.g. wide joins with many exchange channels do not require
+/// manual tuning of the permit configuration.
+///
+/// Two situations are distinguished:
+/// - The sender blocks although the channel is mostly empty on average: the traffic is bursty
+///   and a larger buffer absorbs the bursts, so the capacity is grown.
+/// - The channel stays mostly full: the consumer lags behind and extra buffering only adds
+///   latency without improving throughput, so the capacity is shrunk back.
+pub struct PermitAutoTuner {
+    stats: Arc<ChannelStats>,
+    capacity: SemaphoreCapacity,
+    /// Rolling average of the observed channel occupancy, in permits.
+    avg_occupancy: usize,
+    /// Messages received since the capacity was last reconsidered.
+    received: usize,
+    /// The blocked-send count at the last adjustment, to derive the delta.
+    last_blocked_sends: usize,
+    initial_permits: usize,
+    batched_permits: usize,
+}
+
+impl PermitAutoTuner {
+    /// The number of received messages between two capacity adjustments.
+    const ADJUST_INTERVAL_MESSAGES: usize = 256;
+    /// The weight of the rolling average: a new observation contributes `1 / EWMA_WEIGHT`.
+    const EWMA_WEIGHT: usize = 8;
+    /// The maximum factor by which the capacity may exceed the configured initial permits.
+    const MAX_GROWTH: usize = 4;
+
+    fn new(
+        stats: Arc<ChannelStats>,
+        permits: Arc<Semaphore>,
+        initial_permits: usize,
+        batched_permits: usize,
+    ) -> Self {
+        Self {
+            stats,
+            capacity: SemaphoreCapacity::new(permits, initial_permits),
+            avg_occupancy: 0,
+            received: 0,
+            last_blocked_sends: 0,
+            initial_permits,
+            batched_permits,
+        }
+    }
+
+    /// The capacity the channel is currently sized to.
+    pub fn capacity(&self) -> usize {
+        self.capacity.capacity()
+    }
+
+    /// Record one received message and resize the channel capacity every
+    /// [`Self::ADJUST_INTERVAL_MESSAGES`] messages.
+    pub fn observe(&mut self) {
+        self.avg_occupancy = (self.avg_occupancy * (Self::EWMA_WEIGHT - 1)
+            + self.stats.occupancy_rows())
+            / Self::EWMA_WEIGHT;
+        self.capacity.reclaim();
+
+        self.received += 1;
+        if self.received < Self::ADJUST_INTERVAL_MESSAGES {
+            return;
+        }
+        self.received = 0;
+        let blocked_sends = self.stats.blocked_sends();
+        let newly_blocked = blocked_sends - self.last_blocked_sends;
+        self.last_blocked_sends = blocked_sends;
+
+        let capacity = self.capacity.capacity();
+        // Shrinking below the maximum chunk permits would block the sender forever, even with
+        // all permits available.
+        let min_capacity = self.initial_permits - self.batched_permits;
+        let max_capacity = self.initial_permits * Self::MAX_GROWTH;
+        if newly_blocked > 0 && self.avg_occupancy * 2 <= capacity {
+            self.capacity.set_target((capacity * 2).min(max_capacity));
+        } else if self.avg_occupancy * 4 >= capacity * 3 {
+            self.capacity.set_target((capacity / 2).max(min_capacity));
+        }
+        self.capacity.reclaim();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INITIAL_PERMITS: usize = 1024;
+    const BATCHED_PERMITS: usize = 256;
+
+    fn adjuster() -> PermitAdjuster {
+        PermitAdjuster::new(
+            Arc::new(Semaphore::new(INITIAL_PERMITS)),
+            INITIAL_PERMITS,
+            BATCHED_PERMITS,
+        )
+    }
+
+    #[test]
+    fn test_auto_tuner_grow_and_shrink() {
+        let stats = Arc::new(ChannelStats::default());
+        let permits = Arc::new(Semaphore::new(INITIAL_PERMITS));
+        let mut tuner = PermitAutoTuner::new(
+            stats.clone(),
+            permits.clone(),
+            INITIAL_PERMITS,
+            BATCHED_PERMITS,
+        );
+
+        // Bursty traffic: the sender blocked while the channel is mostly empty on average.
+        stats.blocked_sends.fetch_add(1, Ordering::Relaxed);
+        for _ in 0..PermitAutoTuner::ADJUST_INTERVAL_MESSAGES {
+            tuner.observe();
+        }
+        assert_eq!(tuner.capacity(), INITIAL_PERMITS * 2);
+        assert_eq!(permits.available_permits(), INITIAL_PERMITS * 2);
+
+        // Consumer lag: the channel stays mostly full, so the capacity shrinks back towards
+        // the minimum.
+        stats
+            .occupancy_rows
+            .store(INITIAL_PERMITS * 2, Ordering::Relaxed);
+        for _ in 0..PermitAutoTuner::ADJUST_INTERVAL_MESSAGES * 10 {
+            tuner.observe();
+        }
+        assert_eq!(tuner.capacity(), INITIAL_PERMITS - BATCHED_PERMITS);
+        assert_eq!(
+            permits.available_permits(),
+            INITIAL_PERMITS - BATCHED_PERMITS
+        );
+    }
+
+    #[test]
+    fn test_grow_on_small_rows() {
+        let mut adjuster = adjuster();
+        // 8-byte rows, far below the reference width.
+        for _ in 0..100 {
+            adjuster.observe(8 * 64, 64);
+        }
+        assert_eq!(
+            adjuster.capacity(),
+            INITIAL_PERMITS * PermitAdjuster::MAX_GROWTH
+        );
+        assert_eq!(
+            adjuster.capacity.permits.available_permits(),
+            INITIAL_PERMITS * PermitAdjuster::MAX_GROWTH
+        );
+    }
+
+    #[test]
+    fn test_shrink_on_wide_rows() {
+        let mut adjuster = adjuster();
+        // 4-KB rows, far above the reference width.
+        for _ in 0..100 {
+            adjuster.observe(4096 * 64, 64);
+        }
+        // Never below the maximum chunk permits, so the sender can always make progress.
+        assert_eq!(adjuster.capacity(), INITIAL_PERMITS - BATCHED_PERMITS);
+        assert_eq!(
+            adjuster.capacity.permits.available_permits(),
+            INITIAL_PERMITS - BATCHED_PERMITS
+        );
+    }
+
+    #[test]
+    fn test_shrink_reclaims_lazily() {
+        const IN_FLIGHT: usize = 900;
+
+        let mut adjuster = adjuster();
+        // Most of the permits are held by in-flight messages.
+        adjuster
+            .capacity
+            .permits
+            .try_acquire_many(IN_FLIGHT as u32)
+            .unwrap()
+            .forget();
+        for _ in 0..100 {
+            adjuster.observe(4096 * 64, 64);
+        }
+        // Only the available permits could be reclaimed so far.
+        assert_eq!(adjuster.capacity(), INITIAL_PERMITS - BATCHED_PERMITS);
+        assert_eq!(adjuster.capacity.permits.available_permits(), 0);
+        assert_eq!(
+            adjuster.capacity.deficit,
+            BATCHED_PERMITS - (INITIAL_PERMITS - IN_FLIGHT)
+        );
+
+        // Permits added back by the downstream are reclaimed first.
+        adjuster.capacity.permits.add_permits(IN_FLIGHT);
+        adjuster.observe(4096 * 64, 64);
+        assert_eq!(adjuster.capacity.deficit, 0);
+        assert_eq!(
+            adjuster.capacity.permits.available_permits(),
+            INITIAL_PERMITS - BATCHED_PERMITS
+        );
+    }
 }
@@ -137,6 +137,11 @@ impl<S: StateStore> GlobalSimpleAggExecutor<S> {
         extreme_cache_size: usize,
         state_changed: &mut bool,
     ) -> StreamExecutorResult<()> {
+        // Runtime guard for agg calls planned with the append-only optimization.
+        if agg_calls.iter().any(|agg_call| agg_call.append_only) {
+            expect_append_only(&chunk, identity)?;
+        }
+
         // Create `AggGroup` if not exists. This will fetch previous agg result
         // from the result table.
         if agg_group.is_none() {
@@ -393,6 +398,7 @@ mod tests {
                 args: AggArgs::None,
                 return_type: DataType::Int64,
                 order_pairs: vec![],
+                distinct: false,
                 append_only,
                 filter: None,
             },
@@ -401,6 +407,7 @@ mod tests {
                 args: AggArgs::Unary(DataType::Int64, 0),
                 return_type: DataType::Int64,
                 order_pairs: vec![],
+                distinct: false,
                 append_only,
                 filter: None,
             },
@@ -409,6 +416,7 @@ mod tests {
                 args: AggArgs::Unary(DataType::Int64, 1),
                 return_type: DataType::Int64,
                 order_pairs: vec![],
+                distinct: false,
                 append_only,
                 filter: None,
             },
@@ -417,6 +425,7 @@ mod tests {
                 args: AggArgs::Unary(DataType::Int64, 0),
                 return_type: DataType::Int64,
                 order_pairs: vec![],
+                distinct: false,
                 append_only,
                 filter: None,
             },
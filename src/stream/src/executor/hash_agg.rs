@@ -21,7 +21,7 @@ use futures::{stream, StreamExt, TryStreamExt};
 use futures_async_stream::try_stream;
 use iter_chunks::IterChunks;
 use itertools::Itertools;
-use risingwave_common::array::StreamChunk;
+use risingwave_common::array::{Op, StreamChunk};
 use risingwave_common::buffer::Bitmap;
 use risingwave_common::catalog::Schema;
 use risingwave_common::hash::{HashCode, HashKey, PrecomputedBuildHasher};
@@ -31,7 +31,9 @@ use risingwave_common::util::hash_util::Crc32FastBuilder;
 use risingwave_common::util::iter_util::ZipEqFast;
 use risingwave_storage::StateStore;
 
-use super::aggregation::{agg_call_filter_res, iter_table_storage, AggStateStorage};
+use super::aggregation::{
+    agg_call_filter_res, iter_table_storage, AggGroupSpillManager, AggStateStorage,
+};
 use super::{
     expect_first_barrier, ActorContextRef, Executor, PkIndicesRef, StreamExecutorResult, Watermark,
 };
@@ -41,8 +43,8 @@ use crate::error::StreamResult;
 use crate::executor::aggregation::{generate_agg_schema, AggCall, AggChangesInfo, AggGroup};
 use crate::executor::error::StreamExecutorError;
 use crate::executor::monitor::StreamingMetrics;
-use crate::executor::{BoxedMessageStream, Message, PkIndices};
-use crate::task::AtomicU64Ref;
+use crate::executor::{expect_append_only, BoxedMessageStream, Message, PkIndices};
+use crate::task::{AggKeyCountReporter, AtomicU64Ref};
 
 type BoxedAggGroup<S> = Box<AggGroup<S>>;
 type AggGroupCache<K, S> = ExecutorCache<K, BoxedAggGroup<S>, PrecomputedBuildHasher>;
@@ -102,6 +104,13 @@ struct HashAggExecutorExtra<K: HashKey, S: StateStore> {
     /// Lru manager. None if using local eviction.
     watermark_epoch: AtomicU64Ref,
 
+    /// Spill manager for cold agg groups. `None` if spilling to disk is disabled.
+    spill_manager: Option<AggGroupSpillManager<K>>,
+
+    /// The eviction watermark observed at the previous flush. Spilling only happens while the
+    /// memory manager is actively advancing the watermark, i.e. under current memory pressure.
+    last_spill_watermark: u64,
+
     /// How many times have we hit the cache of join executor for the lookup of each key
     lookup_miss_count: AtomicU64,
 
@@ -121,6 +130,14 @@ struct HashAggExecutorExtra<K: HashKey, S: StateStore> {
     /// Changed group keys in the current epoch (before next flush).
     group_change_set: HashSet<K>,
 
+    /// Number of distinct group keys materialized in the result table, maintained by inspecting
+    /// the ops appended on flush.
+    distinct_key_count: u64,
+
+    /// Reports `distinct_key_count` to the local barrier manager on every barrier, so that meta
+    /// can observe skewed group key distributions among the parallel actors.
+    key_count_reporter: AggKeyCountReporter,
+
     /// The maximum size of the chunk produced by executor at a time.
     chunk_size: usize,
 
@@ -163,11 +180,26 @@ impl<K: HashKey, S: StateStore> HashAggExecutor<K, S> {
         group_key_indices: Vec<usize>,
         watermark_epoch: AtomicU64Ref,
         metrics: Arc<StreamingMetrics>,
+        key_count_reporter: AggKeyCountReporter,
+        enable_spill: bool,
+        spill_max_bytes: u64,
         chunk_size: usize,
     ) -> StreamResult<Self> {
         let input_info = input.info();
         let schema = generate_agg_schema(input.as_ref(), &agg_calls, Some(&group_key_indices));
 
+        let spill_manager = if enable_spill {
+            let output_data_types = schema.data_types()[group_key_indices.len()..].to_vec();
+            Some(AggGroupSpillManager::new(
+                ctx.id,
+                executor_id,
+                output_data_types,
+                spill_max_bytes,
+            )?)
+        } else {
+            None
+        };
+
         let mut group_key_invert_idx = vec![None; input.info().schema.len()];
         for (group_key_seq, group_key_idx) in group_key_indices.iter().enumerate() {
             group_key_invert_idx[*group_key_idx] = Some(group_key_seq);
@@ -188,7 +220,11 @@ impl<K: HashKey, S: StateStore> HashAggExecutor<K, S> {
                 result_table,
                 group_key_indices,
                 watermark_epoch,
+                spill_manager,
+                last_spill_watermark: 0,
                 group_change_set: HashSet::new(),
+                distinct_key_count: 0,
+                key_count_reporter,
                 lookup_miss_count: AtomicU64::new(0),
                 total_lookup_count: AtomicU64::new(0),
                 chunk_lookup_miss_count: 0,
@@ -266,6 +302,7 @@ impl<K: HashKey, S: StateStore> HashAggExecutor<K, S> {
             ref input_pk_indices,
             ref extreme_cache_size,
             ref mut group_change_set,
+            ref mut spill_manager,
             ref schema,
             lookup_miss_count,
             total_lookup_count,
@@ -276,6 +313,11 @@ impl<K: HashKey, S: StateStore> HashAggExecutor<K, S> {
         agg_group_cache: &mut AggGroupCache<K, S>,
         chunk: StreamChunk,
     ) -> StreamExecutorResult<()> {
+        // Runtime guard for agg calls planned with the append-only optimization.
+        if agg_calls.iter().any(|agg_call| agg_call.append_only) {
+            expect_append_only(&chunk, identity)?;
+        }
+
         // Compute hash code here before serializing keys to avoid duplicate hash code computation.
         let hash_codes = chunk
             .data_chunk()
@@ -289,6 +331,31 @@ impl<K: HashKey, S: StateStore> HashAggExecutor<K, S> {
 
         let group_key_types = &schema.data_types()[..group_key_indices.len()];
 
+        // Reload spilled groups first, so that the cache misses below do not hit the state store
+        // for groups that were merely spilled to the local disk.
+        if let Some(spill_manager) = spill_manager {
+            for (key, _, _) in &unique_keys {
+                if agg_group_cache.contains(key) {
+                    continue;
+                }
+                if let Some(prev_outputs) = spill_manager.unspill(key)? {
+                    let agg_group = Box::new(
+                        AggGroup::create_from_prev_outputs(
+                            Some(key.deserialize(group_key_types)?),
+                            agg_calls,
+                            storages,
+                            prev_outputs,
+                            input_pk_indices,
+                            *extreme_cache_size,
+                            input_schema,
+                        )
+                        .await?,
+                    );
+                    agg_group_cache.put(key.clone(), agg_group);
+                }
+            }
+        }
+
         let futs = unique_keys
             .iter()
             .filter_map(|(key, _, _)| {
@@ -394,6 +461,10 @@ impl<K: HashKey, S: StateStore> HashAggExecutor<K, S> {
             ref mut storages,
             ref mut result_table,
             ref mut group_change_set,
+            ref mut spill_manager,
+            ref mut last_spill_watermark,
+            ref watermark_epoch,
+            ref mut distinct_key_count,
             ref lookup_miss_count,
             ref total_lookup_count,
             ref mut chunk_lookup_miss_count,
@@ -476,6 +547,7 @@ impl<K: HashKey, S: StateStore> HashAggExecutor<K, S> {
                     .await?;
 
                 for (key, mut agg_group, curr_outputs) in outputs_in_batch {
+                    let first_op_idx = new_ops.len();
                     let AggChangesInfo {
                         n_appended_ops,
                         result_row,
@@ -486,6 +558,17 @@ impl<K: HashKey, S: StateStore> HashAggExecutor<K, S> {
                         &mut new_ops,
                     );
 
+                    // The first op appended for a group is `Insert` iff the group newly
+                    // materializes, and `Delete` iff its row count drops to zero, so this exactly
+                    // maintains the number of distinct group keys in the result table.
+                    match new_ops.get(first_op_idx) {
+                        Some(Op::Insert) => *distinct_key_count += 1,
+                        Some(Op::Delete) => {
+                            *distinct_key_count = distinct_key_count.saturating_sub(1)
+                        }
+                        _ => {}
+                    }
+
                     if n_appended_ops != 0 {
                         for _ in 0..n_appended_ops {
                             key.deserialize_to_builders(
@@ -529,8 +612,28 @@ impl<K: HashKey, S: StateStore> HashAggExecutor<K, S> {
             };
             result_table.commit(epoch).await?;
 
-            // Evict cache to target capacity.
-            agg_group_cache.evict();
+            // Evict cache to target capacity. With spilling enabled, the cold half of the cache
+            // is spilled to the local disk instead of being dropped, but only while the memory
+            // manager is actively advancing the eviction watermark, i.e. under current memory
+            // pressure. All groups have been flushed above, so their `prev_outputs` are
+            // consistent with the result table.
+            let watermark = watermark_epoch.load(Ordering::Relaxed);
+            let under_pressure = watermark > *last_spill_watermark;
+            *last_spill_watermark = watermark;
+            match spill_manager {
+                Some(spill_manager) if under_pressure => {
+                    let target = agg_group_cache.len() / 2;
+                    while agg_group_cache.len() > target && !spill_manager.at_capacity()? {
+                        let (key, agg_group) =
+                            agg_group_cache.pop_lru().expect("cache is not empty");
+                        spill_manager.spill(key, agg_group.prev_outputs())?;
+                    }
+                    // Once the spill file is at its size cap, the remaining cold groups are
+                    // dropped like a plain eviction.
+                    agg_group_cache.evict();
+                }
+                _ => agg_group_cache.evict(),
+            }
         } else {
             // Nothing to flush.
             // Call commit on state table to increment the epoch.
@@ -594,6 +697,8 @@ impl<K: HashKey, S: StateStore> HashAggExecutor<K, S> {
                         yield Message::Chunk(chunk?);
                     }
 
+                    extra.key_count_reporter.update(extra.distinct_key_count);
+
                     for buffered_watermark in &mut extra.buffered_watermarks {
                         if let Some(watermark) = buffered_watermark.take() {
                             yield Message::Watermark(watermark);
@@ -611,6 +716,9 @@ impl<K: HashKey, S: StateStore> HashAggExecutor<K, S> {
                         // Manipulate the cache if necessary.
                         if cache_may_stale(&previous_vnode_bitmap, &vnode_bitmap) {
                             agg_group_cache.clear();
+                            if let Some(spill_manager) = &mut extra.spill_manager {
+                                spill_manager.clear()?;
+                            }
                         }
                     }
 
@@ -648,6 +756,7 @@ mod tests {
     use crate::executor::test_utils::agg_executor::{create_agg_state_table, create_result_table};
     use crate::executor::test_utils::*;
     use crate::executor::{ActorContext, Executor, HashAggExecutor, Message, PkIndices};
+    use crate::task::{AggKeyCountReporter, LocalBarrierManager};
 
     #[allow(clippy::too_many_arguments)]
     async fn new_boxed_hash_agg_executor<S: StateStore>(
@@ -695,6 +804,11 @@ mod tests {
             group_key_indices,
             Arc::new(AtomicU64::new(0)),
             Arc::new(StreamingMetrics::unused()),
+            AggKeyCountReporter::for_test(Arc::new(parking_lot::Mutex::new(
+                LocalBarrierManager::for_test(),
+            ))),
+            false,
+            1 << 30,
             1024,
         )
         .unwrap()
@@ -753,6 +867,7 @@ mod tests {
                 args: AggArgs::None,
                 return_type: DataType::Int64,
                 order_pairs: vec![],
+                distinct: false,
                 append_only,
                 filter: None,
             },
@@ -761,6 +876,7 @@ mod tests {
                 args: AggArgs::Unary(DataType::Int64, 0),
                 return_type: DataType::Int64,
                 order_pairs: vec![],
+                distinct: false,
                 append_only,
                 filter: None,
             },
@@ -769,6 +885,7 @@ mod tests {
                 args: AggArgs::None,
                 return_type: DataType::Int64,
                 order_pairs: vec![],
+                distinct: false,
                 append_only,
                 filter: None,
             },
@@ -854,6 +971,7 @@ mod tests {
                 args: AggArgs::None,
                 return_type: DataType::Int64,
                 order_pairs: vec![],
+                distinct: false,
                 append_only,
                 filter: None,
             },
@@ -862,6 +980,7 @@ mod tests {
                 args: AggArgs::Unary(DataType::Int64, 1),
                 return_type: DataType::Int64,
                 order_pairs: vec![],
+                distinct: false,
                 append_only,
                 filter: None,
             },
@@ -871,6 +990,7 @@ mod tests {
                 args: AggArgs::Unary(DataType::Int64, 2),
                 return_type: DataType::Int64,
                 order_pairs: vec![],
+                distinct: false,
                 append_only,
                 filter: None,
             },
@@ -957,6 +1077,7 @@ mod tests {
                 args: AggArgs::None,
                 return_type: DataType::Int64,
                 order_pairs: vec![],
+                distinct: false,
                 append_only: false,
                 filter: None,
             },
@@ -965,6 +1086,7 @@ mod tests {
                 args: AggArgs::Unary(DataType::Int64, 1),
                 return_type: DataType::Int64,
                 order_pairs: vec![],
+                distinct: false,
                 append_only: false,
                 filter: None,
             },
@@ -1056,6 +1178,7 @@ mod tests {
                 args: AggArgs::None,
                 return_type: DataType::Int64,
                 order_pairs: vec![],
+                distinct: false,
                 append_only,
                 filter: None,
             },
@@ -1064,6 +1187,7 @@ mod tests {
                 args: AggArgs::Unary(DataType::Int64, 1),
                 return_type: DataType::Int64,
                 order_pairs: vec![],
+                distinct: false,
                 append_only,
                 filter: None,
             },
@@ -44,7 +44,7 @@ use super::{
 };
 use crate::common::table::state_table::StateTable;
 use crate::common::{InfallibleExpression, StreamChunkBuilder};
-use crate::executor::expect_first_barrier_from_aligned_stream;
+use crate::executor::{expect_append_only, expect_first_barrier_from_aligned_stream};
 use crate::executor::JoinType::LeftAnti;
 use crate::task::AtomicU64Ref;
 
@@ -848,6 +848,13 @@ impl<K: HashKey, S: StateStore, const T: JoinTypePrimitive> HashJoinExecutor<K,
     ) {
         let chunk = chunk.compact();
 
+        // Runtime guard for the append-only optimization: the planner enables it only when both
+        // inputs are proved append-only, so a retraction here must fail the actor before it
+        // corrupts the join state.
+        if append_only_optimize {
+            expect_append_only(&chunk, identity)?;
+        }
+
         let (side_update, side_match) = if SIDE == SideType::Left {
             (side_l, side_r)
         } else {
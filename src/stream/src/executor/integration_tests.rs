@@ -59,6 +59,7 @@ async fn test_merger_sum_aggr() {
                     args: AggArgs::None,
                     return_type: DataType::Int64,
                     order_pairs: vec![],
+                    distinct: false,
                     append_only,
                     filter: None,
                 },
@@ -67,6 +68,7 @@ async fn test_merger_sum_aggr() {
                     args: AggArgs::Unary(DataType::Int64, 0),
                     return_type: DataType::Int64,
                     order_pairs: vec![],
+                    distinct: false,
                     append_only,
                     filter: None,
                 },
@@ -122,6 +124,9 @@ async fn test_merger_sum_aggr() {
             inputs, 0,
         ))],
         0,
+        0,
+        actor_ctx.clone(),
+        0,
         ctx,
         metrics,
     );
@@ -150,6 +155,7 @@ async fn test_merger_sum_aggr() {
                 args: AggArgs::Unary(DataType::Int64, 0),
                 return_type: DataType::Int64,
                 order_pairs: vec![],
+                distinct: false,
                 append_only,
                 filter: None,
             },
@@ -158,6 +164,7 @@ async fn test_merger_sum_aggr() {
                 args: AggArgs::Unary(DataType::Int64, 1),
                 return_type: DataType::Int64,
                 order_pairs: vec![],
+                distinct: false,
                 append_only,
                 filter: None,
             },
@@ -0,0 +1,570 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::ops::Bound;
+use std::sync::Arc;
+
+use futures::{pin_mut, StreamExt};
+use futures_async_stream::try_stream;
+use risingwave_common::array::Op;
+use risingwave_common::catalog::Schema;
+use risingwave_common::hash::VirtualNode;
+use risingwave_common::row::{once, OwnedRow, Row, RowExt};
+use risingwave_common::types::{
+    DataType, DatumRef, NaiveDateTimeWrapper, ScalarImpl, ScalarRefImpl,
+};
+use risingwave_common::util::iter_util::ZipEqDebug;
+use risingwave_storage::StateStore;
+
+use super::barrier_align::*;
+use super::error::{StreamExecutorError, StreamExecutorResult};
+use super::monitor::StreamingMetrics;
+use super::{
+    ActorContextRef, BoxedExecutor, BoxedMessageStream, Executor, Message, PkIndices, PkIndicesRef,
+    Watermark,
+};
+use crate::common::table::state_table::StateTable;
+use crate::common::StreamChunkBuilder;
+use crate::executor::expect_first_barrier_from_aligned_stream;
+
+/// [`IntervalJoinExecutor`] joins two streams on event time: a left row and a right row match
+/// iff `left.ts - right.ts` is within `[lower_bound_ms, upper_bound_ms]`. Only inner join is
+/// supported, and both event time columns must carry watermarks.
+///
+/// The state of each side is the input rows keyed by `(bucket, ts, input pk)`, where `bucket` is
+/// the event time divided by `bucket_size_ms`. Probing the other side is a pk range scan over the
+/// buckets that overlap the matching time range, and cleaning up state under a watermark is a
+/// single range delete on the bucket column per vnode, rather than a tombstone per expired row.
+pub struct IntervalJoinExecutor<S: StateStore> {
+    ctx: ActorContextRef,
+    source_l: Option<BoxedExecutor>,
+    source_r: Option<BoxedExecutor>,
+    left_ts_idx: usize,
+    right_ts_idx: usize,
+    /// Inclusive bounds of `left.ts - right.ts`, in milliseconds.
+    lower_bound_ms: i64,
+    upper_bound_ms: i64,
+    /// Width of a state time bucket, in milliseconds.
+    bucket_size_ms: i64,
+    pk_indices: PkIndices,
+    identity: String,
+    left_table: StateTable<S>,
+    right_table: StateTable<S>,
+    /// Concatenation of the left and the right input schema.
+    schema: Schema,
+    metrics: Arc<StreamingMetrics>,
+    /// The maximum size of the chunk produced by executor at a time.
+    chunk_size: usize,
+}
+
+impl<S: StateStore> IntervalJoinExecutor<S> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        ctx: ActorContextRef,
+        source_l: BoxedExecutor,
+        source_r: BoxedExecutor,
+        left_ts_idx: usize,
+        right_ts_idx: usize,
+        lower_bound_ms: i64,
+        upper_bound_ms: i64,
+        bucket_size_ms: i64,
+        pk_indices: PkIndices,
+        executor_id: u64,
+        state_table_l: StateTable<S>,
+        state_table_r: StateTable<S>,
+        metrics: Arc<StreamingMetrics>,
+        chunk_size: usize,
+    ) -> Self {
+        assert!(lower_bound_ms <= upper_bound_ms);
+        assert!(bucket_size_ms > 0);
+        let mut fields = source_l.schema().fields.clone();
+        fields.extend(source_r.schema().fields.clone());
+        let schema = Schema::new(fields);
+        Self {
+            ctx,
+            source_l: Some(source_l),
+            source_r: Some(source_r),
+            left_ts_idx,
+            right_ts_idx,
+            lower_bound_ms,
+            upper_bound_ms,
+            bucket_size_ms,
+            pk_indices,
+            identity: format!("IntervalJoinExecutor {:X}", executor_id),
+            left_table: state_table_l,
+            right_table: state_table_r,
+            schema,
+            metrics,
+            chunk_size,
+        }
+    }
+
+    fn ts_micros(datum: DatumRef<'_>) -> Option<i64> {
+        match datum {
+            Some(ScalarRefImpl::NaiveDateTime(ts)) => Some(ts.0.timestamp_micros()),
+            _ => None,
+        }
+    }
+
+    fn bucket_of(&self, ts_us: i64) -> i64 {
+        ts_us.div_euclid(self.bucket_size_ms * 1000)
+    }
+
+    /// Collects the rows of `table` whose event time lies in `[lo_us, hi_us]`, with the bucket
+    /// column stripped. The scan visits only the buckets overlapping the range, which is at most
+    /// two of them as long as the bucket width is no less than the range width.
+    async fn scan_matches(
+        &self,
+        table: &StateTable<S>,
+        ts_idx_in_state: usize,
+        lo_us: i64,
+        hi_us: i64,
+    ) -> StreamExecutorResult<Vec<OwnedRow>> {
+        let mut matched = vec![];
+        if lo_us > hi_us {
+            return Ok(matched);
+        }
+        let range = (
+            Bound::Included(once(Some(ScalarImpl::Int64(self.bucket_of(lo_us))))),
+            Bound::Included(once(Some(ScalarImpl::Int64(self.bucket_of(hi_us))))),
+        );
+        for vnode in table.vnodes().iter_ones() {
+            let row_stream = table
+                .iter_with_pk_range(&range, VirtualNode::from_index(vnode))
+                .await?;
+            pin_mut!(row_stream);
+            while let Some(res) = row_stream.next().await {
+                let row = res?;
+                if let Some(ts_us) = Self::ts_micros(row.datum_at(ts_idx_in_state)) {
+                    if (lo_us..=hi_us).contains(&ts_us) {
+                        let mut datums = row.into_inner();
+                        datums.remove(0);
+                        matched.push(OwnedRow::new(datums));
+                    }
+                }
+            }
+        }
+        Ok(matched)
+    }
+
+    #[try_stream(ok = Message, error = StreamExecutorError)]
+    async fn into_stream(mut self) {
+        let input_l = self.source_l.take().unwrap();
+        let input_r = self.source_r.take().unwrap();
+
+        let left_len = input_l.schema().len();
+        let right_len = input_r.schema().len();
+
+        let aligned_stream = barrier_align(
+            input_l.execute(),
+            input_r.execute(),
+            self.ctx.id,
+            self.metrics.clone(),
+        );
+
+        pin_mut!(aligned_stream);
+
+        let barrier = expect_first_barrier_from_aligned_stream(&mut aligned_stream).await?;
+        self.left_table.init_epoch(barrier.epoch);
+        self.right_table.init_epoch(barrier.epoch);
+
+        // The first barrier message should be propagated.
+        yield Message::Barrier(barrier);
+
+        let (left_to_output, right_to_output) =
+            StreamChunkBuilder::get_i2o_mapping(0..self.schema.len(), left_len, right_len);
+        let mut left_builder = StreamChunkBuilder::new(
+            self.chunk_size,
+            &self.schema.data_types(),
+            left_to_output.clone(),
+            right_to_output.clone(),
+        );
+        let mut right_builder = StreamChunkBuilder::new(
+            self.chunk_size,
+            &self.schema.data_types(),
+            right_to_output,
+            left_to_output,
+        );
+
+        let lower_us = self.lower_bound_ms * 1000;
+        let upper_us = self.upper_bound_ms * 1000;
+
+        // The latest watermark of each input event time column.
+        let mut left_wm_us: Option<i64> = None;
+        let mut right_wm_us: Option<i64> = None;
+        // The last emitted watermark of each output event time column.
+        let mut emitted_left_wm_us: Option<i64> = None;
+        let mut emitted_right_wm_us: Option<i64> = None;
+        // The last bucket bound each state table has been cleaned up to.
+        let mut left_clean_bucket: Option<i64> = None;
+        let mut right_clean_bucket: Option<i64> = None;
+
+        #[for_await]
+        for msg in aligned_stream {
+            match msg? {
+                AlignedMessage::Left(chunk) => {
+                    let chunk = chunk.compact();
+                    let (data_chunk, ops) = chunk.into_parts();
+                    for (row, op) in data_chunk.rows().zip_eq_debug(ops.iter()) {
+                        let ts_us = match Self::ts_micros(row.datum_at(self.left_ts_idx)) {
+                            Some(ts_us) => ts_us,
+                            // A NULL event time never matches, so the row is neither stored nor
+                            // probed.
+                            None => continue,
+                        };
+                        // `Update*` pairs are degraded to `Delete` + `Insert`, as their halves
+                        // may join with different numbers of rows from the other side.
+                        let op = match *op {
+                            Op::Insert | Op::UpdateInsert => Op::Insert,
+                            Op::Delete | Op::UpdateDelete => Op::Delete,
+                        };
+                        let bucket = self.bucket_of(ts_us);
+                        let state_row = once(Some(ScalarImpl::Int64(bucket))).chain(row);
+                        match op {
+                            Op::Insert => self.left_table.insert(state_row),
+                            Op::Delete => self.left_table.delete(state_row),
+                            _ => unreachable!(),
+                        }
+                        // A left row at `l` matches right rows at `r` iff
+                        // `r` is in `[l - upper, l - lower]`.
+                        let matched = self
+                            .scan_matches(
+                                &self.right_table,
+                                1 + self.right_ts_idx,
+                                ts_us - upper_us,
+                                ts_us - lower_us,
+                            )
+                            .await?;
+                        for row_matched in matched {
+                            if let Some(chunk) = left_builder.append_row(op, row, &row_matched) {
+                                yield Message::Chunk(chunk);
+                            }
+                        }
+                    }
+                    if let Some(chunk) = left_builder.take() {
+                        yield Message::Chunk(chunk);
+                    }
+                }
+                AlignedMessage::Right(chunk) => {
+                    let chunk = chunk.compact();
+                    let (data_chunk, ops) = chunk.into_parts();
+                    for (row, op) in data_chunk.rows().zip_eq_debug(ops.iter()) {
+                        let ts_us = match Self::ts_micros(row.datum_at(self.right_ts_idx)) {
+                            Some(ts_us) => ts_us,
+                            None => continue,
+                        };
+                        let op = match *op {
+                            Op::Insert | Op::UpdateInsert => Op::Insert,
+                            Op::Delete | Op::UpdateDelete => Op::Delete,
+                        };
+                        let bucket = self.bucket_of(ts_us);
+                        let state_row = once(Some(ScalarImpl::Int64(bucket))).chain(row);
+                        match op {
+                            Op::Insert => self.right_table.insert(state_row),
+                            Op::Delete => self.right_table.delete(state_row),
+                            _ => unreachable!(),
+                        }
+                        // A right row at `r` matches left rows at `l` iff
+                        // `l` is in `[r + lower, r + upper]`.
+                        let matched = self
+                            .scan_matches(
+                                &self.left_table,
+                                1 + self.left_ts_idx,
+                                ts_us + lower_us,
+                                ts_us + upper_us,
+                            )
+                            .await?;
+                        for row_matched in matched {
+                            if let Some(chunk) = right_builder.append_row(op, row, &row_matched) {
+                                yield Message::Chunk(chunk);
+                            }
+                        }
+                    }
+                    if let Some(chunk) = right_builder.take() {
+                        yield Message::Chunk(chunk);
+                    }
+                }
+                AlignedMessage::WatermarkLeft(watermark) => {
+                    if watermark.col_idx == self.left_ts_idx {
+                        if let ScalarImpl::NaiveDateTime(ts) = &watermark.val {
+                            left_wm_us = Some(ts.0.timestamp_micros());
+                        }
+                    }
+                }
+                AlignedMessage::WatermarkRight(watermark) => {
+                    if watermark.col_idx == self.right_ts_idx {
+                        if let ScalarImpl::NaiveDateTime(ts) = &watermark.val {
+                            right_wm_us = Some(ts.0.timestamp_micros());
+                        }
+                    }
+                }
+                AlignedMessage::Barrier(barrier) => {
+                    // Future left rows arrive at `l >= left_wm` and only match right rows at
+                    // `r >= l - upper`, so right buckets strictly below
+                    // `bucket(left_wm - upper)` are dead, and symmetrically for the left state.
+                    // The cleanup is a range delete on the bucket column per vnode.
+                    if let Some(wm_us) = left_wm_us {
+                        let bound = self.bucket_of(wm_us - upper_us);
+                        if right_clean_bucket < Some(bound) {
+                            right_clean_bucket = Some(bound);
+                            self.right_table.update_watermark(ScalarImpl::Int64(bound));
+                        }
+                    }
+                    if let Some(wm_us) = right_wm_us {
+                        let bound = self.bucket_of(wm_us + lower_us);
+                        if left_clean_bucket < Some(bound) {
+                            left_clean_bucket = Some(bound);
+                            self.left_table.update_watermark(ScalarImpl::Int64(bound));
+                        }
+                    }
+
+                    self.left_table.commit(barrier.epoch).await?;
+                    self.right_table.commit(barrier.epoch).await?;
+
+                    // An output pair can still be produced by a future arrival on either side,
+                    // so each output event time column is bounded by both input watermarks.
+                    if let (Some(l_wm_us), Some(r_wm_us)) = (left_wm_us, right_wm_us) {
+                        let out_l_us = l_wm_us.min(r_wm_us + lower_us);
+                        if emitted_left_wm_us < Some(out_l_us) {
+                            emitted_left_wm_us = Some(out_l_us);
+                            if let Ok(val) = NaiveDateTimeWrapper::with_macros(out_l_us) {
+                                yield Message::Watermark(Watermark::new(
+                                    self.left_ts_idx,
+                                    DataType::Timestamp,
+                                    ScalarImpl::NaiveDateTime(val),
+                                ));
+                            }
+                        }
+                        let out_r_us = r_wm_us.min(l_wm_us - upper_us);
+                        if emitted_right_wm_us < Some(out_r_us) {
+                            emitted_right_wm_us = Some(out_r_us);
+                            if let Ok(val) = NaiveDateTimeWrapper::with_macros(out_r_us) {
+                                yield Message::Watermark(Watermark::new(
+                                    left_len + self.right_ts_idx,
+                                    DataType::Timestamp,
+                                    ScalarImpl::NaiveDateTime(val),
+                                ));
+                            }
+                        }
+                    }
+
+                    // Update the vnode bitmap for the state tables if asked.
+                    if let Some(vnode_bitmap) = barrier.as_update_vnode_bitmap(self.ctx.id) {
+                        let _ = self.left_table.update_vnode_bitmap(vnode_bitmap.clone());
+                        let _ = self.right_table.update_vnode_bitmap(vnode_bitmap);
+                    }
+
+                    yield Message::Barrier(barrier);
+                }
+            }
+        }
+    }
+}
+
+impl<S: StateStore> Executor for IntervalJoinExecutor<S> {
+    fn execute(self: Box<Self>) -> BoxedMessageStream {
+        self.into_stream().boxed()
+    }
+
+    fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    fn pk_indices(&self) -> PkIndicesRef<'_> {
+        &self.pk_indices
+    }
+
+    fn identity(&self) -> &str {
+        self.identity.as_str()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::stream_chunk::StreamChunkTestExt;
+    use risingwave_common::array::StreamChunk;
+    use risingwave_common::catalog::{ColumnDesc, ColumnId, Field, TableId};
+    use risingwave_common::util::sort_util::OrderType;
+    use risingwave_storage::memory::MemoryStateStore;
+
+    use super::*;
+    use crate::executor::test_utils::{MessageSender, MockSource, StreamExecutorTestExt};
+    use crate::executor::ActorContext;
+
+    async fn create_state_table(
+        mem_state: MemoryStateStore,
+        table_id: u32,
+    ) -> StateTable<MemoryStateStore> {
+        // [bucket, ts, id], with pk [bucket, ts, id].
+        let column_descs = vec![
+            ColumnDesc::unnamed(ColumnId::new(0), DataType::Int64),
+            ColumnDesc::unnamed(ColumnId::new(1), DataType::Timestamp),
+            ColumnDesc::unnamed(ColumnId::new(2), DataType::Int64),
+        ];
+        StateTable::new_without_distribution(
+            mem_state,
+            TableId::new(table_id),
+            column_descs,
+            vec![
+                OrderType::Ascending,
+                OrderType::Ascending,
+                OrderType::Ascending,
+            ],
+            vec![0, 1, 2],
+        )
+        .await
+    }
+
+    async fn create_executor() -> (MessageSender, MessageSender, BoxedMessageStream) {
+        let mem_state = MemoryStateStore::new();
+        let state_table_l = create_state_table(mem_state.clone(), 0).await;
+        let state_table_r = create_state_table(mem_state, 1).await;
+        let schema = Schema {
+            fields: vec![
+                Field::unnamed(DataType::Timestamp),
+                Field::unnamed(DataType::Int64),
+            ],
+        };
+        let (tx_l, source_l) = MockSource::channel(schema.clone(), vec![1]);
+        let (tx_r, source_r) = MockSource::channel(schema, vec![1]);
+
+        // `|left.ts - right.ts| <= 10s`, with 10s buckets.
+        let executor = IntervalJoinExecutor::<MemoryStateStore>::new(
+            ActorContext::create(123),
+            Box::new(source_l),
+            Box::new(source_r),
+            0,
+            0,
+            -10_000,
+            10_000,
+            10_000,
+            vec![1, 3],
+            1,
+            state_table_l,
+            state_table_r,
+            Arc::new(StreamingMetrics::unused()),
+            1024,
+        );
+        (tx_l, tx_r, Box::new(executor).execute())
+    }
+
+    #[tokio::test]
+    async fn test_interval_join() {
+        let (mut tx_l, mut tx_r, mut interval_join) = create_executor().await;
+
+        tx_l.push_barrier(1, false);
+        tx_r.push_barrier(1, false);
+        interval_join.next_unwrap_ready_barrier().unwrap();
+
+        // No rows on the right side yet, so nothing is emitted.
+        tx_l.push_chunk(StreamChunk::from_pretty(
+            " TS                  I
+            + 2023-01-01T00:00:05 1
+            + 2023-01-01T00:01:00 2",
+        ));
+        tx_l.push_barrier(2, false);
+        tx_r.push_barrier(2, false);
+        interval_join.next_unwrap_ready_barrier().unwrap();
+
+        // The first right row is within 10s of the first left row only.
+        tx_r.push_chunk(StreamChunk::from_pretty(
+            " TS                  I
+            + 2023-01-01T00:00:10 3",
+        ));
+        let chunk = interval_join.next_unwrap_ready_chunk().unwrap();
+        assert_eq!(
+            chunk,
+            StreamChunk::from_pretty(
+                " TS                  I TS                  I
+                + 2023-01-01T00:00:05 1 2023-01-01T00:00:10 3"
+            )
+        );
+
+        // A left row matching both right-side buckets adjacent to it.
+        tx_r.push_chunk(StreamChunk::from_pretty(
+            " TS                  I
+            + 2023-01-01T00:00:55 4",
+        ));
+        let chunk = interval_join.next_unwrap_ready_chunk().unwrap();
+        assert_eq!(
+            chunk,
+            StreamChunk::from_pretty(
+                " TS                  I TS                  I
+                + 2023-01-01T00:01:00 2 2023-01-01T00:00:55 4"
+            )
+        );
+
+        // Deleting a stored row retracts the pairs it produced.
+        tx_l.push_chunk(StreamChunk::from_pretty(
+            " TS                  I
+            - 2023-01-01T00:00:05 1",
+        ));
+        let chunk = interval_join.next_unwrap_ready_chunk().unwrap();
+        assert_eq!(
+            chunk,
+            StreamChunk::from_pretty(
+                " TS                  I TS                  I
+                - 2023-01-01T00:00:05 1 2023-01-01T00:00:10 3"
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn test_interval_join_watermark() {
+        let (mut tx_l, mut tx_r, mut interval_join) = create_executor().await;
+
+        tx_l.push_barrier(1, false);
+        tx_r.push_barrier(1, false);
+        interval_join.next_unwrap_ready_barrier().unwrap();
+
+        tx_l.push_watermark(
+            0,
+            DataType::Timestamp,
+            ScalarImpl::NaiveDateTime(NaiveDateTimeWrapper::with_macros(60_000_000).unwrap()),
+        );
+        tx_l.push_barrier(2, false);
+        tx_r.push_barrier(2, false);
+        // A single-sided watermark produces no output watermark.
+        interval_join.next_unwrap_ready_barrier().unwrap();
+
+        tx_r.push_watermark(
+            0,
+            DataType::Timestamp,
+            ScalarImpl::NaiveDateTime(NaiveDateTimeWrapper::with_macros(30_000_000).unwrap()),
+        );
+        tx_l.push_barrier(3, false);
+        tx_r.push_barrier(3, false);
+
+        // Left: min(60s, 30s - 10s) = 20s. Right: min(30s, 60s - 10s) = 30s.
+        let watermark = interval_join.next_unwrap_ready().unwrap();
+        assert_eq!(
+            watermark.as_watermark().unwrap(),
+            &Watermark::new(
+                0,
+                DataType::Timestamp,
+                ScalarImpl::NaiveDateTime(NaiveDateTimeWrapper::with_macros(20_000_000).unwrap()),
+            )
+        );
+        let watermark = interval_join.next_unwrap_ready().unwrap();
+        assert_eq!(
+            watermark.as_watermark().unwrap(),
+            &Watermark::new(
+                2,
+                DataType::Timestamp,
+                ScalarImpl::NaiveDateTime(NaiveDateTimeWrapper::with_macros(30_000_000).unwrap()),
+            )
+        );
+        interval_join.next_unwrap_ready_barrier().unwrap();
+    }
+}
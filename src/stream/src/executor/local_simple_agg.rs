@@ -59,6 +59,11 @@ impl LocalSimpleAggExecutor {
         aggregators: &mut [Box<dyn StreamingAggImpl>],
         chunk: StreamChunk,
     ) -> StreamExecutorResult<()> {
+        // Runtime guard for agg calls planned with the append-only optimization.
+        if agg_calls.iter().any(|agg_call| agg_call.append_only) {
+            expect_append_only(&chunk, identity)?;
+        }
+
         let capacity = chunk.capacity();
         let (ops, columns, visibility) = chunk.into_inner();
         let visibilities: Vec<_> = agg_calls
@@ -207,6 +212,7 @@ mod tests {
             args: AggArgs::None,
             return_type: DataType::Int64,
             order_pairs: vec![],
+            distinct: false,
             append_only: false,
             filter: None,
         }];
@@ -265,6 +271,7 @@ mod tests {
                 args: AggArgs::None,
                 return_type: DataType::Int64,
                 order_pairs: vec![],
+                distinct: false,
                 append_only: false,
                 filter: None,
             },
@@ -273,6 +280,7 @@ mod tests {
                 args: AggArgs::Unary(DataType::Int64, 0),
                 return_type: DataType::Int64,
                 order_pairs: vec![],
+                distinct: false,
                 append_only: false,
                 filter: None,
             },
@@ -281,6 +289,7 @@ mod tests {
                 args: AggArgs::Unary(DataType::Int64, 1),
                 return_type: DataType::Int64,
                 order_pairs: vec![],
+                distinct: false,
                 append_only: false,
                 filter: None,
             },
@@ -23,7 +23,7 @@ use futures::{Stream, StreamExt};
 use itertools::Itertools;
 use minitrace::prelude::*;
 use risingwave_common::array::column::Column;
-use risingwave_common::array::StreamChunk;
+use risingwave_common::array::{Op, StreamChunk};
 use risingwave_common::buffer::Bitmap;
 use risingwave_common::catalog::Schema;
 use risingwave_common::row::OwnedRow;
@@ -39,9 +39,9 @@ use risingwave_pb::stream_plan::barrier::Mutation as ProstMutation;
 use risingwave_pb::stream_plan::stream_message::StreamMessage;
 use risingwave_pb::stream_plan::update_mutation::{DispatcherUpdate, MergeUpdate};
 use risingwave_pb::stream_plan::{
-    AddMutation, Barrier as ProstBarrier, Dispatcher as ProstDispatcher, PauseMutation,
-    ResumeMutation, SourceChangeSplitMutation, StopMutation, StreamMessage as ProstStreamMessage,
-    UpdateMutation, Watermark as ProstWatermark,
+    AddMutation, Barrier as ProstBarrier, ConfigChangeMutation, Dispatcher as ProstDispatcher,
+    PauseMutation, ResumeMutation, SourceChangeSplitMutation, StopMutation,
+    StreamMessage as ProstStreamMessage, UpdateMutation, Watermark as ProstWatermark,
 };
 use smallvec::SmallVec;
 
@@ -58,6 +58,7 @@ pub mod aggregation;
 mod batch_query;
 mod chain;
 mod dispatch;
+mod dedup;
 pub mod dml;
 mod dynamic_filter;
 mod error;
@@ -67,6 +68,7 @@ mod global_simple_agg;
 mod hash_agg;
 pub mod hash_join;
 mod hop_window;
+mod interval_join;
 mod local_simple_agg;
 mod lookup;
 mod lookup_union;
@@ -102,6 +104,7 @@ use anyhow::Context;
 pub use backfill::*;
 pub use batch_query::BatchQueryExecutor;
 pub use chain::ChainExecutor;
+pub use dedup::AppendOnlyDedupExecutor;
 pub use dispatch::{DispatchExecutor, DispatcherImpl};
 pub use dynamic_filter::DynamicFilterExecutor;
 pub use error::{StreamExecutorError, StreamExecutorResult};
@@ -111,12 +114,13 @@ pub use global_simple_agg::GlobalSimpleAggExecutor;
 pub use hash_agg::HashAggExecutor;
 pub use hash_join::*;
 pub use hop_window::HopWindowExecutor;
+pub use interval_join::IntervalJoinExecutor;
 pub use local_simple_agg::LocalSimpleAggExecutor;
 pub use lookup::*;
 pub use lookup_union::LookupUnionExecutor;
 pub use merge::MergeExecutor;
 pub use mview::*;
-pub use now::NowExecutor;
+pub use now::{NowExecutor, NowStateBackend};
 pub use project::ProjectExecutor;
 pub use project_set::*;
 pub use rearranged_chain::RearrangedChainExecutor;
@@ -222,6 +226,7 @@ pub enum Mutation {
     SourceChangeSplit(HashMap<ActorId, Vec<SplitImpl>>),
     Pause,
     Resume,
+    ConfigChange(HashMap<String, String>),
 }
 
 #[derive(Debug, Clone)]
@@ -314,6 +319,18 @@ impl Barrier {
         matches!(self.mutation.as_deref(), Some(Mutation::Resume))
     }
 
+    /// Returns the config entries if this barrier is to change some executor configs. The entries
+    /// should be applied atomically on barrier receipt, so that all actors switch to the new
+    /// configs at the same epoch.
+    pub fn as_config_change(&self) -> Option<&HashMap<String, String>> {
+        self.mutation
+            .as_deref()
+            .and_then(|mutation| match mutation {
+                Mutation::ConfigChange(entries) => Some(entries),
+                _ => None,
+            })
+    }
+
     /// Returns the [`MergeUpdate`] if this barrier is to update the merge executors for the actor
     /// with `actor_id`.
     pub fn as_update_merge(
@@ -425,6 +442,9 @@ impl Mutation {
             }
             Mutation::Pause => ProstMutation::Pause(PauseMutation {}),
             Mutation::Resume => ProstMutation::Resume(ResumeMutation {}),
+            Mutation::ConfigChange(entries) => ProstMutation::ConfigChange(ConfigChangeMutation {
+                entries: entries.clone(),
+            }),
         }
     }
 
@@ -510,6 +530,7 @@ impl Mutation {
             }
             ProstMutation::Pause(_) => Mutation::Pause,
             ProstMutation::Resume(_) => Mutation::Resume,
+            ProstMutation::ConfigChange(c) => Mutation::ConfigChange(c.entries.clone()),
         };
         Ok(mutation)
     }
@@ -739,6 +760,18 @@ pub async fn expect_first_barrier_from_aligned_stream(
     Ok(barrier)
 }
 
+/// Expect the given `chunk` to contain only insertions.
+///
+/// Executors planned with the append-only optimization call this before applying a chunk. A
+/// retraction from upstream there indicates a planning bug or corrupted input, and must fail the
+/// actor instead of corrupting its state silently.
+pub fn expect_append_only(chunk: &StreamChunk, identity: &str) -> StreamExecutorResult<()> {
+    if chunk.ops().iter().any(|op| *op != Op::Insert) {
+        return Err(StreamExecutorError::append_only_violation(identity));
+    }
+    Ok(())
+}
+
 /// `StreamConsumer` is the last step in an actor.
 pub trait StreamConsumer: Send + 'static {
     type BarrierStream: Stream<Item = StreamResult<Barrier>> + Send;
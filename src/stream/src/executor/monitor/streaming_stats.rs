@@ -38,6 +38,8 @@ pub struct StreamingMetrics {
     pub actor_idle_duration: GenericGaugeVec<AtomicF64>,
     pub actor_idle_cnt: GenericGaugeVec<AtomicI64>,
     pub actor_memory_usage: GenericGaugeVec<AtomicI64>,
+    pub actor_memory_suspension_count: GenericCounterVec<AtomicU64>,
+    pub actor_memory_suspension_duration_ns: GenericCounterVec<AtomicU64>,
     pub actor_in_record_cnt: GenericCounterVec<AtomicU64>,
     pub actor_out_record_cnt: GenericCounterVec<AtomicU64>,
     pub actor_sampled_deserialize_duration_ns: GenericCounterVec<AtomicU64>,
@@ -46,6 +48,8 @@ pub struct StreamingMetrics {
 
     // Exchange (see also `compute::ExchangeServiceMetrics`)
     pub exchange_frag_recv_size: GenericCounterVec<AtomicU64>,
+    pub exchange_channel_occupancy_rows: GenericGaugeVec<AtomicI64>,
+    pub exchange_channel_capacity: GenericGaugeVec<AtomicI64>,
     pub stream_total_mem_usage: IntGauge,
 
     // Streaming Join
@@ -74,6 +78,8 @@ pub struct StreamingMetrics {
     pub barrier_sync_latency: Histogram,
 
     pub sink_commit_duration: HistogramVec,
+    /// Rows rejected by the sink target and routed to the dead-letter-queue sink.
+    pub sink_rejected_rows_count: GenericCounterVec<AtomicU64>,
 
     // Memory management
     // FIXME(yuhao): use u64 here
@@ -121,7 +127,7 @@ impl StreamingMetrics {
         let actor_output_buffer_blocking_duration_ns = register_int_counter_vec_with_registry!(
             "stream_actor_output_buffer_blocking_duration_ns",
             "Total blocking duration (ns) of output buffer",
-            &["actor_id"],
+            &["actor_id", "fragment_id", "downstream_fragment_id"],
             registry
         )
         .unwrap();
@@ -142,6 +148,22 @@ impl StreamingMetrics {
         )
         .unwrap();
 
+        let exchange_channel_occupancy_rows = register_int_gauge_vec_with_registry!(
+            "stream_exchange_channel_occupancy_rows",
+            "Number of rows currently buffered in the local exchange channel",
+            &["up_actor_id", "down_actor_id"],
+            registry
+        )
+        .unwrap();
+
+        let exchange_channel_capacity = register_int_gauge_vec_with_registry!(
+            "stream_exchange_channel_capacity",
+            "Current permit capacity of the local exchange channel",
+            &["up_actor_id", "down_actor_id"],
+            registry
+        )
+        .unwrap();
+
         let stream_total_mem_usage = register_int_gauge_with_registry!(
             "stream_total_mem_usage",
             "The memory allocated by streaming jobs, get from TaskLocalAlloc",
@@ -261,6 +283,22 @@ impl StreamingMetrics {
         )
         .unwrap();
 
+        let actor_memory_suspension_count = register_int_counter_vec_with_registry!(
+            "actor_memory_suspension_count",
+            "Total number of times an actor has been suspended for exceeding its memory hard limit",
+            &["actor_id"],
+            registry,
+        )
+        .unwrap();
+
+        let actor_memory_suspension_duration_ns = register_int_counter_vec_with_registry!(
+            "actor_memory_suspension_duration_ns",
+            "Total time (ns) an actor has spent suspended for exceeding its memory hard limit",
+            &["actor_id"],
+            registry,
+        )
+        .unwrap();
+
         let join_lookup_miss_count = register_int_counter_vec_with_registry!(
             "stream_join_lookup_miss_count",
             "Join executor lookup miss duration",
@@ -395,6 +433,14 @@ impl StreamingMetrics {
         )
         .unwrap();
 
+        let sink_rejected_rows_count = register_int_counter_vec_with_registry!(
+            "sink_rejected_rows_count",
+            "Total number of rows rejected by the sink target and routed to the DLQ",
+            &["executor_id", "connector"],
+            registry
+        )
+        .unwrap();
+
         let lru_current_watermark_time_ms = register_int_gauge_with_registry!(
             "lru_current_watermark_time_ms",
             "Current LRU manager watermark time(ms)",
@@ -447,12 +493,16 @@ impl StreamingMetrics {
             actor_idle_duration,
             actor_idle_cnt,
             actor_memory_usage,
+            actor_memory_suspension_count,
+            actor_memory_suspension_duration_ns,
             actor_in_record_cnt,
             actor_out_record_cnt,
             actor_sampled_deserialize_duration_ns,
             source_output_row_count,
             source_row_per_barrier,
             exchange_frag_recv_size,
+            exchange_channel_occupancy_rows,
+            exchange_channel_capacity,
             stream_total_mem_usage,
             join_lookup_miss_count,
             join_total_lookup_count,
@@ -471,6 +521,7 @@ impl StreamingMetrics {
             barrier_inflight_latency,
             barrier_sync_latency,
             sink_commit_duration,
+            sink_rejected_rows_count,
             lru_current_watermark_time_ms,
             lru_physical_now_ms,
             lru_runtime_loop_count,
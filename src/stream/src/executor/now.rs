@@ -20,6 +20,7 @@ use risingwave_common::catalog::{Field, Schema};
 use risingwave_common::row;
 use risingwave_common::types::{DataType, ScalarImpl, ToDatumRef};
 use risingwave_common::util::epoch::Epoch;
+use risingwave_common::util::value_encoding::{deserialize_datum, serialize_datum};
 use risingwave_storage::StateStore;
 use tokio::sync::mpsc::UnboundedReceiver;
 
@@ -28,6 +29,16 @@ use super::{
     StreamExecutorError,
 };
 use crate::common::table::state_table::StateTable;
+use crate::task::EphemeralStateHandle;
+
+/// Where the single timestamp held by [`NowExecutor`] is persisted across restarts.
+pub enum NowStateBackend<S: StateStore> {
+    /// A regular Hummock-backed state table.
+    StateTable(StateTable<S>),
+    /// The state table is marked `memory_only` in the catalog: the timestamp is kept in memory
+    /// and snapshotted into the meta store at barriers, avoiding tiny-SST churn in Hummock.
+    MemoryOnly(EphemeralStateHandle),
+}
 
 pub struct NowExecutor<S: StateStore> {
     /// Receiver of barrier channel.
@@ -36,14 +47,14 @@ pub struct NowExecutor<S: StateStore> {
     pk_indices: PkIndices,
     identity: String,
     schema: Schema,
-    state_table: StateTable<S>,
+    state: NowStateBackend<S>,
 }
 
 impl<S: StateStore> NowExecutor<S> {
     pub fn new(
         barrier_receiver: UnboundedReceiver<Barrier>,
         executor_id: u64,
-        state_table: StateTable<S>,
+        state: NowStateBackend<S>,
     ) -> Self {
         let schema = Schema::new(vec![Field {
             data_type: DataType::Timestamptz,
@@ -56,7 +67,7 @@ impl<S: StateStore> NowExecutor<S> {
             pk_indices: vec![],
             identity: format!("NowExecutor {:X}", executor_id),
             schema,
-            state_table,
+            state,
         }
     }
 
@@ -64,7 +75,7 @@ impl<S: StateStore> NowExecutor<S> {
     async fn into_stream(self) {
         let Self {
             mut barrier_receiver,
-            mut state_table,
+            mut state,
             schema,
             ..
         } = self;
@@ -77,23 +88,34 @@ impl<S: StateStore> NowExecutor<S> {
             .unwrap();
         let mut is_pausing = barrier.is_pause() || barrier.is_update();
 
-        state_table.init_epoch(barrier.epoch);
+        if let NowStateBackend::StateTable(state_table) = &mut state {
+            state_table.init_epoch(barrier.epoch);
+        }
 
         // The first barrier message should be propagated.
         yield Message::Barrier(barrier);
 
-        let state_row = {
-            let data_iter = state_table.iter().await?;
-            pin_mut!(data_iter);
-            if let Some(state_row) = data_iter.next().await {
-                Some(state_row?)
-            } else {
-                None
+        let mut last_timestamp = match &mut state {
+            NowStateBackend::StateTable(state_table) => {
+                let state_row = {
+                    let data_iter = state_table.iter().await?;
+                    pin_mut!(data_iter);
+                    if let Some(state_row) = data_iter.next().await {
+                        Some(state_row?)
+                    } else {
+                        None
+                    }
+                };
+                state_row.and_then(|row| row[0].clone())
             }
+            NowStateBackend::MemoryOnly(handle) => match handle.restore() {
+                Some(snapshot) => {
+                    deserialize_datum(snapshot.as_slice(), &DataType::Timestamptz)?
+                }
+                None => None,
+            },
         };
 
-        let mut last_timestamp = state_row.and_then(|row| row[0].clone());
-
         while let Some(barrier) = barrier_receiver.recv().await {
             if !is_pausing {
                 let time_millis = Epoch::from(barrier.epoch.curr).as_unix_millis();
@@ -129,14 +151,21 @@ impl<S: StateStore> NowExecutor<S> {
                 // timestamp.as_ref().unwrap().clone(),
                 // ));
 
-                if last_timestamp.is_some() {
-                    state_table.delete(row::once(last_timestamp));
+                match &mut state {
+                    NowStateBackend::StateTable(state_table) => {
+                        if last_timestamp.is_some() {
+                            state_table.delete(row::once(last_timestamp));
+                        }
+                        state_table.insert(row::once(timestamp.to_datum_ref()));
+                        state_table.commit(barrier.epoch).await?;
+                    }
+                    NowStateBackend::MemoryOnly(handle) => {
+                        let snapshot = serialize_datum(timestamp.to_datum_ref());
+                        handle.update(barrier.epoch.curr, snapshot);
+                    }
                 }
-                state_table.insert(row::once(timestamp.to_datum_ref()));
                 last_timestamp = timestamp;
-
-                state_table.commit(barrier.epoch).await?;
-            } else {
+            } else if let NowStateBackend::StateTable(state_table) = &mut state {
                 state_table.commit_no_data_expected(barrier.epoch);
             }
             if let Some(mutation) = barrier.mutation.as_deref() {
@@ -172,6 +201,8 @@ impl<S: StateStore> Executor for NowExecutor<S> {
 
 #[cfg(test)]
 mod tests {
+    use std::sync::Arc;
+
     use futures::StreamExt;
     use risingwave_common::array::StreamChunk;
     use risingwave_common::catalog::{ColumnDesc, ColumnId, TableId};
@@ -181,14 +212,15 @@ mod tests {
     use risingwave_storage::memory::MemoryStateStore;
     use tokio::sync::mpsc::{unbounded_channel, UnboundedSender};
 
-    use super::NowExecutor;
+    use super::{NowExecutor, NowStateBackend};
     use crate::common::table::state_table::StateTable;
     use crate::executor::{Barrier, BoxedMessageStream, Executor, PkIndices};
+    use crate::task::{EphemeralStateHandle, LocalBarrierManager};
 
     #[tokio::test]
     async fn test_now() {
         let state_table = create_state_table().await;
-        let (tx, mut now_executor) = create_executor(state_table);
+        let (tx, mut now_executor) = create_executor(NowStateBackend::StateTable(state_table));
 
         // Init barrier
         tx.send(Barrier::new_test_barrier(1)).unwrap();
@@ -256,6 +288,55 @@ mod tests {
         now_executor.next().await.unwrap().unwrap();
     }
 
+    #[tokio::test]
+    async fn test_now_memory_only() {
+        let barrier_manager = Arc::new(parking_lot::Mutex::new(LocalBarrierManager::for_test()));
+        let handle = EphemeralStateHandle::for_test(barrier_manager);
+        let (tx, mut now_executor) =
+            create_executor::<MemoryStateStore>(NowStateBackend::MemoryOnly(handle));
+
+        // Init barrier
+        tx.send(Barrier::new_test_barrier(1)).unwrap();
+
+        // Consume the barrier
+        now_executor.next().await.unwrap().unwrap();
+
+        tx.send(Barrier::with_prev_epoch_for_test(1 << 16, 1))
+            .unwrap();
+
+        // Consume the data chunk
+        let chunk_msg = now_executor.next().await.unwrap().unwrap();
+
+        assert_eq!(
+            chunk_msg.into_chunk().unwrap().compact(),
+            StreamChunk::from_pretty(
+                " TSZ
+                + 1617235200001000"
+            )
+        );
+
+        // Consume the barrier
+        now_executor.next().await.unwrap().unwrap();
+
+        tx.send(Barrier::with_prev_epoch_for_test(2 << 16, 1 << 16))
+            .unwrap();
+
+        // Consume the data chunk
+        let chunk_msg = now_executor.next().await.unwrap().unwrap();
+
+        assert_eq!(
+            chunk_msg.into_chunk().unwrap().compact(),
+            StreamChunk::from_pretty(
+                " TSZ
+                - 1617235200001000
+                + 1617235200002000"
+            )
+        );
+
+        // Consume the barrier
+        now_executor.next().await.unwrap().unwrap();
+    }
+
     #[inline]
     fn create_pk_indices() -> PkIndices {
         vec![]
@@ -282,11 +363,11 @@ mod tests {
         .await
     }
 
-    fn create_executor(
-        state_table: StateTable<MemoryStateStore>,
+    fn create_executor<S: risingwave_storage::StateStore>(
+        state: NowStateBackend<S>,
     ) -> (UnboundedSender<Barrier>, BoxedMessageStream) {
         let (sender, barrier_receiver) = unbounded_channel();
-        let now_executor = NowExecutor::new(barrier_receiver, 1, state_table);
+        let now_executor = NowExecutor::new(barrier_receiver, 1, state);
         (sender, Box::new(now_executor).execute())
     }
 }
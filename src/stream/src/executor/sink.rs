@@ -13,27 +13,43 @@
 // limitations under the License.
 
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
 
 use futures::StreamExt;
 use futures_async_stream::try_stream;
 use risingwave_common::catalog::Schema;
+use risingwave_common::row::Row;
+use risingwave_common::types::{DataType, ScalarRefImpl};
+use risingwave_common::util::epoch::Epoch;
 use risingwave_connector::sink::{Sink, SinkConfig, SinkImpl};
 use risingwave_connector::ConnectorParams;
+use risingwave_storage::StateStore;
 
 use super::error::{StreamExecutorError, StreamExecutorResult};
-use super::{BoxedExecutor, Executor, Message};
+use super::{expect_first_barrier, BoxedExecutor, Executor, Message};
+use crate::common::log_store::{LogStoreCursor, SinkLogStore};
 use crate::executor::monitor::StreamingMetrics;
 use crate::executor::PkIndices;
+use crate::task::SinkFreshnessReporter;
 
-pub struct SinkExecutor {
+pub struct SinkExecutor<S: StateStore> {
     input: BoxedExecutor,
     metrics: Arc<StreamingMetrics>,
     config: SinkConfig,
+    /// Config of the dead-letter-queue sink. Chunks rejected by the sink target are written
+    /// there instead of failing the whole sink.
+    dlq_config: Option<SinkConfig>,
+    /// Buffers the change stream consumed by the sink, so that it can be replayed to the
+    /// sink target without re-running the upstream computation. `None` unless the sink log
+    /// store is enabled for this sink.
+    log_store: Option<SinkLogStore<S>>,
     identity: String,
     connector_params: ConnectorParams,
     schema: Schema,
     pk_indices: Vec<usize>,
+    /// Reports the freshness of each completed commit to the local barrier manager, from which
+    /// the meta service derives an end-to-end freshness number per sink.
+    freshness_reporter: SinkFreshnessReporter,
 }
 
 async fn build_sink(
@@ -47,24 +63,31 @@ async fn build_sink(
     ))
 }
 
-impl SinkExecutor {
+impl<S: StateStore> SinkExecutor<S> {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         materialize_executor: BoxedExecutor,
         metrics: Arc<StreamingMetrics>,
         config: SinkConfig,
+        dlq_config: Option<SinkConfig>,
+        log_store: Option<SinkLogStore<S>>,
         executor_id: u64,
         connector_params: ConnectorParams,
         schema: Schema,
         pk_indices: Vec<usize>,
+        freshness_reporter: SinkFreshnessReporter,
     ) -> Self {
         Self {
             input: materialize_executor,
             metrics,
             config,
+            dlq_config,
+            log_store,
             identity: format!("SinkExecutor_{:?}", executor_id),
             pk_indices,
             schema,
             connector_params,
+            freshness_reporter,
         }
     }
 
@@ -74,17 +97,54 @@ impl SinkExecutor {
         // message, so we should abort the transaction if the flag is true.
         let mut empty_epoch_flag = true;
         let mut in_transaction = false;
-        let mut epoch = 0;
+        let mut log_store = self.log_store;
+        let mut freshness_reporter = self.freshness_reporter;
+
+        // Sample event timestamps from the first timestamp-typed column to measure the
+        // end-to-end freshness of the sink. Without such a column, the epoch time of the
+        // committed barrier serves as a proxy for when the rows were ingested.
+        let event_ts_col = self
+            .schema
+            .fields
+            .iter()
+            .position(|f| matches!(f.data_type, DataType::Timestamp | DataType::Timestamptz));
+        let mut sampled_event_ts_ms: u64 = 0;
 
         let mut sink = build_sink(
             self.config.clone(),
-            self.schema,
-            self.pk_indices,
-            self.connector_params,
+            self.schema.clone(),
+            self.pk_indices.clone(),
+            self.connector_params.clone(),
         )
         .await?;
 
-        let input = self.input.execute();
+        // The DLQ sink shares the schema of the primary sink. The error context of a rejected
+        // chunk is recorded in the log and the rejection metrics.
+        let mut dlq_sink = match &self.dlq_config {
+            Some(dlq_config) => Some(
+                build_sink(
+                    dlq_config.clone(),
+                    self.schema,
+                    self.pk_indices,
+                    self.connector_params,
+                )
+                .await?,
+            ),
+            None => None,
+        };
+        let mut empty_dlq_epoch_flag = true;
+
+        let mut input = self.input.execute();
+
+        // Consume the first barrier message and initialize the log store.
+        let barrier = expect_first_barrier(&mut input).await?;
+        if let Some(log_store) = &mut log_store {
+            log_store.init_epoch(barrier.epoch);
+        }
+        let mut epoch = barrier.epoch.curr;
+
+        // The first barrier message should be propagated.
+        yield Message::Barrier(barrier);
 
         #[for_await]
         for msg in input {
@@ -95,13 +155,57 @@ impl SinkExecutor {
                 Message::Chunk(chunk) => {
                     if !in_transaction {
                         sink.begin_epoch(epoch).await?;
+                        if let Some(dlq) = &mut dlq_sink {
+                            dlq.begin_epoch(epoch).await?;
+                        }
                         in_transaction = true;
                     }
 
                     let visible_chunk = chunk.clone().compact();
-                    if let Err(e) = sink.write_batch(visible_chunk).await {
-                        sink.abort().await?;
-                        return Err(e.into());
+                    if let Some(col_idx) = event_ts_col {
+                        if let Some((_, row)) = visible_chunk.rows().next() {
+                            let event_ts_ms = match row.datum_at(col_idx) {
+                                Some(ScalarRefImpl::NaiveDateTime(ts)) => {
+                                    ts.0.timestamp_millis().max(0) as u64
+                                }
+                                // `timestamptz` is microseconds since the unix epoch.
+                                Some(ScalarRefImpl::Int64(us)) => (us / 1000).max(0) as u64,
+                                _ => 0,
+                            };
+                            sampled_event_ts_ms = sampled_event_ts_ms.max(event_ts_ms);
+                        }
+                    }
+                    if let Some(log_store) = &mut log_store {
+                        log_store.write_chunk(&visible_chunk);
+                    }
+                    if let Err(e) = sink.write_batch(visible_chunk.clone()).await {
+                        match &mut dlq_sink {
+                            Some(dlq) => {
+                                tracing::warn!(
+                                    "sink rejected chunk with {} rows, routing to DLQ: {}",
+                                    visible_chunk.cardinality(),
+                                    e
+                                );
+                                self.metrics
+                                    .sink_rejected_rows_count
+                                    .with_label_values(&[
+                                        self.identity.as_str(),
+                                        self.config.get_connector(),
+                                    ])
+                                    .inc_by(visible_chunk.cardinality() as u64);
+                                // If the DLQ sink rejects the chunk as well, fail the sink.
+                                if let Err(e) = dlq.write_batch(visible_chunk).await {
+                                    sink.abort().await?;
+                                    dlq.abort().await?;
+                                    return Err(e.into());
+                                }
+                                empty_dlq_epoch_flag = false;
+                            }
+                            None => {
+                                sink.abort().await?;
+                                return Err(e.into());
+                            }
+                        }
                     }
                     empty_epoch_flag = false;
 
@@ -125,10 +229,52 @@ impl SinkExecutor {
                                     self.config.get_connector(),
                                 ])
                                 .observe(start_time.elapsed().as_millis() as f64);
+
+                            let commit_at_ms = SystemTime::now()
+                                .duration_since(UNIX_EPOCH)
+                                .expect("Clock may have gone backwards")
+                                .as_millis() as u64;
+                            let event_ts_ms = if sampled_event_ts_ms > 0 {
+                                sampled_event_ts_ms
+                            } else {
+                                Epoch::from(epoch).physical_time()
+                            };
+                            freshness_reporter.update(event_ts_ms, commit_at_ms);
+                        }
+                        if let Some(dlq) = &mut dlq_sink {
+                            if empty_dlq_epoch_flag {
+                                dlq.abort().await?;
+                            } else {
+                                dlq.commit().await?;
+                            }
+                        }
+                    }
+                    if let Some(log_store) = &mut log_store {
+                        if empty_epoch_flag {
+                            log_store.commit_no_data_expected(barrier.epoch);
+                        } else {
+                            // All chunks of the epoch have been delivered to the sink target
+                            // (or diverted to the DLQ) at this point, so their log entries
+                            // will never be replayed again and are pruned right away. The log
+                            // thus only retains the changes that are still in flight.
+                            log_store
+                                .truncate(LogStoreCursor {
+                                    epoch,
+                                    seq_id: i64::MAX,
+                                })
+                                .await?;
+                            log_store.commit(barrier.epoch).await?;
+                        }
+                        if let Some(vnode_bitmap) =
+                            barrier.as_update_vnode_bitmap(log_store.actor_id())
+                        {
+                            log_store.update_vnode_bitmap(vnode_bitmap);
                         }
                     }
                     in_transaction = false;
                     empty_epoch_flag = true;
+                    empty_dlq_epoch_flag = true;
+                    sampled_event_ts_ms = 0;
                     epoch = barrier.epoch.curr;
                     yield Message::Barrier(barrier);
                 }
@@ -137,7 +283,7 @@ impl SinkExecutor {
     }
 }
 
-impl Executor for SinkExecutor {
+impl<S: StateStore> Executor for SinkExecutor<S> {
     fn execute(self: Box<Self>) -> super::BoxedMessageStream {
         self.execute_inner().boxed()
     }
@@ -165,9 +311,12 @@ mod test {
     async fn test_mysqlsink() {
         use risingwave_common::array::stream_chunk::StreamChunk;
         use risingwave_common::array::StreamChunkTestExt;
-        use risingwave_common::catalog::Field;
+        use risingwave_common::catalog::{ColumnDesc, ColumnId, Field, TableId};
         use risingwave_common::types::DataType;
+        use risingwave_common::util::sort_util::OrderType;
+        use risingwave_storage::memory::MemoryStateStore;
 
+        use crate::common::table::state_table::StateTable;
         use crate::executor::Barrier;
 
         let properties = maplit::hashmap! {
@@ -189,11 +338,12 @@ mod test {
             schema.clone(),
             pk.clone(),
             vec![
+                Message::Barrier(Barrier::new_test_barrier(1)),
                 Message::Chunk(std::mem::take(&mut StreamChunk::from_pretty(
                     " I I I
             +  3 2 1",
                 ))),
-                Message::Barrier(Barrier::new_test_barrier(1)),
+                Message::Barrier(Barrier::new_test_barrier(2)),
                 Message::Chunk(std::mem::take(&mut StreamChunk::from_pretty(
                     " I I I
             +  6 5 4",
@@ -201,15 +351,42 @@ mod test {
             ],
         );
 
+        // The log store table is `vnode | epoch | seq_id | op` followed by the payload columns.
+        let log_store_table = StateTable::new_without_distribution(
+            MemoryStateStore::new(),
+            TableId::new(1),
+            vec![
+                ColumnDesc::unnamed(ColumnId::new(0), DataType::Int16),
+                ColumnDesc::unnamed(ColumnId::new(1), DataType::Int64),
+                ColumnDesc::unnamed(ColumnId::new(2), DataType::Int64),
+                ColumnDesc::unnamed(ColumnId::new(3), DataType::Int16),
+                ColumnDesc::unnamed(ColumnId::new(4), DataType::Int32),
+                ColumnDesc::unnamed(ColumnId::new(5), DataType::Int32),
+                ColumnDesc::unnamed(ColumnId::new(6), DataType::Int32),
+            ],
+            vec![
+                OrderType::Ascending,
+                OrderType::Ascending,
+                OrderType::Ascending,
+            ],
+            vec![0, 1, 2],
+        )
+        .await;
+
         let config = SinkConfig::from_hashmap(properties).unwrap();
         let sink_executor = SinkExecutor::new(
             Box::new(mock),
             Arc::new(StreamingMetrics::unused()),
             config,
+            None,
+            Some(SinkLogStore::new(log_store_table, 0)),
             0,
             Default::default(),
             schema.clone(),
             pk.clone(),
+            SinkFreshnessReporter::for_test(Arc::new(parking_lot::Mutex::new(
+                crate::task::LocalBarrierManager::for_test(),
+            ))),
         );
 
         let mut executor = SinkExecutor::execute(Box::new(sink_executor));
@@ -29,7 +29,7 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use risingwave_common::array::{Op, StreamChunk};
+use risingwave_common::array::StreamChunk;
 use risingwave_common::buffer::Bitmap;
 use risingwave_common::hash::HashKey;
 use risingwave_common::row::{RowDeserializer, RowExt};
@@ -47,7 +47,7 @@ use crate::common::table::state_table::StateTable;
 use crate::error::StreamResult;
 use crate::executor::error::StreamExecutorResult;
 use crate::executor::managed_state::top_n::ManagedTopNState;
-use crate::executor::{ActorContextRef, Executor, ExecutorInfo, PkIndices};
+use crate::executor::{expect_append_only, ActorContextRef, Executor, ExecutorInfo, PkIndices};
 use crate::task::AtomicU64Ref;
 
 /// If the input contains only append, `AppendOnlyGroupTopNExecutor` does not need
@@ -158,6 +158,7 @@ where
     TopNCache<WITH_TIES>: AppendOnlyTopNCacheTrait,
 {
     async fn apply_chunk(&mut self, chunk: StreamChunk) -> StreamExecutorResult<StreamChunk> {
+        expect_append_only(&chunk, &self.info.identity)?;
         let mut res_ops = Vec::with_capacity(self.limit);
         let mut res_rows = Vec::with_capacity(self.limit);
         let chunk = chunk.compact();
@@ -166,7 +167,7 @@ where
         let data_types = self.schema().data_types();
         let row_deserializer = RowDeserializer::new(data_types);
 
-        for ((op, row_ref), group_cache_key) in chunk.rows().zip_eq_debug(keys.iter()) {
+        for ((_op, row_ref), group_cache_key) in chunk.rows().zip_eq_debug(keys.iter()) {
             // The pk without group by
             let pk_row = row_ref.project(&self.storage_key_indices[self.group_by.len()..]);
             let cache_key = serialize_pk_to_cache_key(pk_row, &self.cache_key_serde);
@@ -184,7 +185,6 @@ where
             }
             let cache = self.caches.get_mut(group_cache_key).unwrap();
 
-            debug_assert_eq!(op, Op::Insert);
             cache.insert(
                 cache_key,
                 row_ref,
@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use async_trait::async_trait;
-use risingwave_common::array::{Op, StreamChunk};
+use risingwave_common::array::StreamChunk;
 use risingwave_common::row::{RowDeserializer, RowExt};
 use risingwave_common::util::epoch::EpochPair;
 use risingwave_common::util::sort_util::OrderPair;
@@ -26,7 +26,7 @@ use crate::common::table::state_table::StateTable;
 use crate::error::StreamResult;
 use crate::executor::error::StreamExecutorResult;
 use crate::executor::managed_state::top_n::{ManagedTopNState, NO_GROUP_KEY};
-use crate::executor::{ActorContextRef, Executor, ExecutorInfo, PkIndices};
+use crate::executor::{expect_append_only, ActorContextRef, Executor, ExecutorInfo, PkIndices};
 
 /// If the input contains only append, `AppendOnlyTopNExecutor` does not need
 /// to keep all the data records/rows that have been seen. As long as a record
@@ -151,13 +151,13 @@ where
     TopNCache<WITH_TIES>: AppendOnlyTopNCacheTrait,
 {
     async fn apply_chunk(&mut self, chunk: StreamChunk) -> StreamExecutorResult<StreamChunk> {
+        expect_append_only(&chunk, &self.info.identity)?;
         let mut res_ops = Vec::with_capacity(self.cache.limit);
         let mut res_rows = Vec::with_capacity(self.cache.limit);
         let data_types = self.schema().data_types();
         let row_deserializer = RowDeserializer::new(data_types);
         // apply the chunk to state table
-        for (op, row_ref) in chunk.rows() {
-            debug_assert_eq!(op, Op::Insert);
+        for (_op, row_ref) in chunk.rows() {
             let pk_row = row_ref.project(&self.storage_key_indices);
             let cache_key = serialize_pk_to_cache_key(pk_row, &self.cache_key_serde);
             self.cache.insert(
@@ -356,6 +356,48 @@ mod tests {
         // Now (1, 1, 1, 1, 2)
     }
 
+    #[tokio::test]
+    async fn test_append_only_top_n_executor_rejects_retraction() {
+        let schema = create_schema();
+        let source = Box::new(MockSource::with_messages(
+            schema,
+            pk_indices(),
+            vec![
+                Message::Barrier(Barrier::new_test_barrier(1)),
+                Message::Chunk(StreamChunk::from_pretty(
+                    " I I
+                    + 1 0
+                    - 1 0",
+                )),
+            ],
+        ));
+        let state_table = create_in_memory_state_table(
+            &[DataType::Int64, DataType::Int64],
+            &[OrderType::Ascending, OrderType::Ascending],
+            &pk_indices(),
+        )
+        .await;
+
+        let top_n_executor = Box::new(
+            AppendOnlyTopNExecutor::new_without_ties(
+                source as Box<dyn Executor>,
+                ActorContext::create(0),
+                storage_key(),
+                (0, 5),
+                order_by(),
+                1,
+                state_table,
+            )
+            .unwrap(),
+        );
+        let mut top_n_executor = top_n_executor.execute();
+
+        // consume the init epoch
+        top_n_executor.next().await.unwrap().unwrap();
+        // The chunk contains a retraction, which violates the append-only assumption.
+        assert!(top_n_executor.next().await.unwrap().is_err());
+    }
+
     #[tokio::test]
     async fn test_append_only_top_n_executor_with_offset_and_limit() {
         let source = create_source();
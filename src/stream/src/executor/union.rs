@@ -12,13 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use futures::StreamExt;
 use futures_async_stream::try_stream;
+use parking_lot::Mutex;
 use risingwave_common::catalog::Schema;
 use risingwave_common::util::select_all;
 
+use super::watermark::BufferedWatermarks;
 use super::*;
 use crate::executor::{BoxedMessageStream, ExecutorInfo};
 
@@ -73,19 +76,35 @@ impl Executor for UnionExecutor {
     }
 }
 
-/// Merges input streams and aligns with barriers.
+/// Merges input streams and aligns with barriers. A watermark is only emitted once it has been
+/// received from every input, i.e. the minimum watermark among all inputs is propagated.
 pub fn merge(inputs: Vec<BoxedMessageStream>) -> BoxedMessageStream {
-    let barrier = Arc::new(tokio::sync::Barrier::new(inputs.len()));
+    let input_num = inputs.len();
+    let barrier = Arc::new(tokio::sync::Barrier::new(input_num));
+    // Watermark column index -> `BufferedWatermarks`. Shared between all input streams, which
+    // are polled from the same actor task, so the mutex is never contended.
+    let watermark_buffers: Arc<Mutex<BTreeMap<usize, BufferedWatermarks<usize>>>> =
+        Arc::new(Mutex::new(BTreeMap::new()));
     let mut streams = vec![];
-    for input in inputs {
+    for (input_id, input) in inputs.into_iter().enumerate() {
         let barrier = barrier.clone();
+        let watermark_buffers = watermark_buffers.clone();
         let stream = #[try_stream]
         async move {
             #[for_await]
             for item in input {
                 match item? {
-                    Message::Watermark(_) => {
-                        todo!("https://github.com/risingwavelabs/risingwave/issues/6042")
+                    Message::Watermark(watermark) => {
+                        let watermark = watermark_buffers
+                            .lock()
+                            .entry(watermark.col_idx)
+                            .or_insert_with(|| {
+                                BufferedWatermarks::with_ids((0..input_num).collect())
+                            })
+                            .handle_watermark(input_id, watermark);
+                        if let Some(watermark) = watermark {
+                            yield Message::Watermark(watermark);
+                        }
                     }
                     msg @ Message::Chunk(_) => yield msg,
                     msg @ Message::Barrier(_) => {
@@ -108,6 +127,7 @@ mod tests {
     use futures::TryStreamExt;
     use risingwave_common::array::stream_chunk::StreamChunkTestExt;
     use risingwave_common::array::StreamChunk;
+    use risingwave_common::types::{DataType, ScalarImpl};
 
     use super::*;
 
@@ -142,4 +162,56 @@ mod tests {
             ]
         );
     }
+
+    #[tokio::test]
+    async fn union_watermark() {
+        let new_watermark = |val: i64| {
+            Message::Watermark(Watermark {
+                col_idx: 0,
+                data_type: DataType::Int64,
+                val: ScalarImpl::Int64(val),
+            })
+        };
+        let streams = vec![
+            try_stream! {
+                yield new_watermark(1);
+                yield Message::Barrier(Barrier::new_test_barrier(1));
+                yield new_watermark(4);
+                yield Message::Barrier(Barrier::new_test_barrier(2));
+            }
+            .boxed(),
+            try_stream! {
+                yield new_watermark(2);
+                yield Message::Barrier(Barrier::new_test_barrier(1));
+                yield new_watermark(3);
+                yield Message::Barrier(Barrier::new_test_barrier(2));
+            }
+            .boxed(),
+        ];
+        let output: Vec<_> = merge(streams).try_collect().await.unwrap();
+        // Only watermarks that advance the minimum among all inputs are propagated, so the
+        // last watermark `4` stays buffered.
+        let watermarks: Vec<_> = output
+            .iter()
+            .filter_map(|msg| match msg {
+                Message::Watermark(watermark) => Some(watermark.val.clone()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            watermarks,
+            vec![
+                ScalarImpl::Int64(1),
+                ScalarImpl::Int64(2),
+                ScalarImpl::Int64(3)
+            ]
+        );
+        assert_eq!(
+            output
+                .iter()
+                .filter(|msg| matches!(msg, Message::Barrier(_)))
+                .count(),
+            2
+        );
+    }
 }
@@ -74,6 +74,7 @@ pub fn build_agg_call_from_prost(
         args,
         return_type: DataType::from(agg_call_proto.get_return_type()?),
         order_pairs,
+        distinct: agg_call_proto.distinct,
         append_only,
         filter,
     })
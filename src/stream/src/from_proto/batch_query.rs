@@ -34,7 +34,7 @@ impl ExecutorBuilder for BatchQueryExecutorBuilder {
         params: ExecutorParams,
         node: &Self::Node,
         state_store: impl StateStore,
-        stream: &mut LocalStreamManagerCore,
+        _stream: &mut LocalStreamManagerCore,
     ) -> StreamResult<BoxedExecutor> {
         let table_desc: &StorageTableDesc = node.get_table_desc()?;
         let table_id = TableId {
@@ -104,7 +104,7 @@ impl ExecutorBuilder for BatchQueryExecutorBuilder {
         let schema = table.schema().clone();
         let executor = BatchQueryExecutor::new(
             table,
-            stream.config.developer.stream_chunk_size,
+            params.chunk_size(),
             ExecutorInfo {
                 schema,
                 pk_indices: params.pk_indices,
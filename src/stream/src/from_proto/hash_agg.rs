@@ -26,7 +26,7 @@ use crate::common::table::state_table::StateTable;
 use crate::executor::aggregation::{AggCall, AggStateStorage};
 use crate::executor::monitor::StreamingMetrics;
 use crate::executor::{ActorContextRef, HashAggExecutor, PkIndices};
-use crate::task::AtomicU64Ref;
+use crate::task::{AggKeyCountReporter, AtomicU64Ref};
 
 pub struct HashAggExecutorDispatcherArgs<S: StateStore> {
     ctx: ActorContextRef,
@@ -41,6 +41,9 @@ pub struct HashAggExecutorDispatcherArgs<S: StateStore> {
     executor_id: u64,
     watermark_epoch: AtomicU64Ref,
     metrics: Arc<StreamingMetrics>,
+    key_count_reporter: AggKeyCountReporter,
+    enable_spill: bool,
+    spill_max_bytes: u64,
     chunk_size: usize,
 }
 
@@ -60,6 +63,9 @@ impl<S: StateStore> HashKeyDispatcher for HashAggExecutorDispatcherArgs<S> {
             self.group_key_indices,
             self.watermark_epoch,
             self.metrics,
+            self.key_count_reporter,
+            self.enable_spill,
+            self.spill_max_bytes,
             self.chunk_size,
         )?
         .boxed())
@@ -109,9 +115,14 @@ impl ExecutorBuilder for HashAggExecutorBuilder {
         )
         .await;
 
+        let result_table_id = node.get_result_table().unwrap().id;
         let result_table =
             StateTable::from_table_catalog(node.get_result_table().unwrap(), store, vnodes).await;
 
+        let key_count_reporter = stream
+            .context
+            .register_agg_key_count(params.actor_context.id, result_table_id);
+
         let args = HashAggExecutorDispatcherArgs {
             ctx: params.actor_context,
             input,
@@ -125,7 +136,10 @@ impl ExecutorBuilder for HashAggExecutorBuilder {
             executor_id: params.executor_id,
             watermark_epoch: stream.get_watermark_epoch(),
             metrics: params.executor_stats,
-            chunk_size: params.env.config().developer.stream_chunk_size,
+            key_count_reporter,
+            enable_spill: stream.config.developer.stream_hash_agg_spill_enabled,
+            spill_max_bytes: stream.config.developer.stream_hash_agg_spill_max_bytes,
+            chunk_size: params.chunk_size(),
         };
         args.dispatch()
     }
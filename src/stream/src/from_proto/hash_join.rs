@@ -121,7 +121,7 @@ impl ExecutorBuilder for HashJoinExecutorBuilder {
             metrics: params.executor_stats,
             join_type_proto: node.get_join_type()?,
             join_key_data_types,
-            chunk_size: params.env.config().developer.stream_chunk_size,
+            chunk_size: params.chunk_size(),
         };
 
         args.dispatch()
@@ -0,0 +1,58 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_pb::stream_plan::IntervalJoinNode;
+
+use super::*;
+use crate::common::table::state_table::StateTable;
+use crate::executor::IntervalJoinExecutor;
+
+pub struct IntervalJoinExecutorBuilder;
+
+#[async_trait::async_trait]
+impl ExecutorBuilder for IntervalJoinExecutorBuilder {
+    type Node = IntervalJoinNode;
+
+    async fn new_boxed_executor(
+        params: ExecutorParams,
+        node: &Self::Node,
+        store: impl StateStore,
+        _stream: &mut LocalStreamManagerCore,
+    ) -> StreamResult<BoxedExecutor> {
+        let [source_l, source_r]: [_; 2] = params.input.try_into().unwrap();
+
+        // Both state tables have distribution `Single`.
+        let state_table_l =
+            StateTable::from_table_catalog(node.get_left_table()?, store.clone(), None).await;
+        let state_table_r =
+            StateTable::from_table_catalog(node.get_right_table()?, store, None).await;
+
+        Ok(Box::new(IntervalJoinExecutor::new(
+            params.actor_context,
+            source_l,
+            source_r,
+            node.get_left_time_column() as usize,
+            node.get_right_time_column() as usize,
+            node.get_lower_bound_ms(),
+            node.get_upper_bound_ms(),
+            node.get_bucket_size_ms(),
+            params.pk_indices,
+            params.executor_id,
+            state_table_l,
+            state_table_r,
+            params.executor_stats,
+            params.chunk_size(),
+        )))
+    }
+}
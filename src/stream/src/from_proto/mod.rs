@@ -17,6 +17,7 @@
 mod agg_common;
 mod batch_query;
 mod chain;
+mod dedup;
 mod dml;
 mod dynamic_filter;
 mod expand;
@@ -27,6 +28,7 @@ mod group_top_n_appendonly;
 mod hash_agg;
 mod hash_join;
 mod hop_window;
+mod interval_join;
 mod local_simple_agg;
 mod lookup;
 mod lookup_union;
@@ -52,6 +54,7 @@ use risingwave_storage::StateStore;
 
 use self::batch_query::*;
 use self::chain::*;
+use self::dedup::AppendOnlyDedupExecutorBuilder;
 use self::dml::*;
 use self::dynamic_filter::*;
 use self::expand::*;
@@ -62,6 +65,7 @@ use self::group_top_n_appendonly::AppendOnlyGroupTopNExecutorBuilder;
 use self::hash_agg::*;
 use self::hash_join::*;
 use self::hop_window::*;
+use self::interval_join::*;
 use self::local_simple_agg::*;
 use self::lookup::*;
 use self::lookup_union::*;
@@ -141,9 +145,11 @@ pub async fn create_executor(
         NodeBody::LookupUnion => LookupUnionExecutorBuilder,
         NodeBody::Expand => ExpandExecutorBuilder,
         NodeBody::DynamicFilter => DynamicFilterExecutorBuilder,
+        NodeBody::IntervalJoin => IntervalJoinExecutorBuilder,
         NodeBody::ProjectSet => ProjectSetExecutorBuilder,
         NodeBody::GroupTopN => GroupTopNExecutorBuilder,
         NodeBody::AppendOnlyGroupTopN => AppendOnlyGroupTopNExecutorBuilder,
+        NodeBody::AppendOnlyDedup => AppendOnlyDedupExecutorBuilder,
         NodeBody::Sort => SortExecutorBuilder,
         NodeBody::WatermarkFilter => WatermarkFilterBuilder,
         NodeBody::Dml => DmlExecutorBuilder,
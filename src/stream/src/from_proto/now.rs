@@ -19,7 +19,7 @@ use tokio::sync::mpsc::unbounded_channel;
 use super::ExecutorBuilder;
 use crate::common::table::state_table::StateTable;
 use crate::error::StreamResult;
-use crate::executor::{BoxedExecutor, NowExecutor};
+use crate::executor::{BoxedExecutor, NowExecutor, NowStateBackend};
 use crate::task::{ExecutorParams, LocalStreamManagerCore};
 
 pub struct NowExecutorBuilder;
@@ -40,13 +40,19 @@ impl ExecutorBuilder for NowExecutorBuilder {
             .lock_barrier_manager()
             .register_sender(params.actor_context.id, sender);
 
-        let state_table =
-            StateTable::from_table_catalog(node.get_state_table()?, store, None).await;
+        let table = node.get_state_table()?;
+        let state = if table.memory_only {
+            // The state is kept in executor memory and snapshotted into the meta store at
+            // barriers, instead of being written to Hummock.
+            NowStateBackend::MemoryOnly(stream.context.register_ephemeral_state(table.id))
+        } else {
+            NowStateBackend::StateTable(StateTable::from_table_catalog(table, store, None).await)
+        };
 
         Ok(Box::new(NowExecutor::new(
             barrier_receiver,
             params.executor_id,
-            state_table,
+            state,
         )))
     }
 }
@@ -37,11 +37,11 @@ impl ExecutorBuilder for ProjectSetExecutorBuilder {
             .map(|proto| {
                 ProjectSetSelectItem::from_prost(
                     proto,
-                    params.env.config().developer.stream_chunk_size,
+                    params.chunk_size(),
                 )
             })
             .try_collect()?;
-        let chunk_size = params.env.config().developer.stream_chunk_size;
+        let chunk_size = params.chunk_size();
         Ok(ProjectSetExecutor::new(
             input,
             params.pk_indices,
@@ -12,10 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use risingwave_connector::sink::SinkConfig;
 use risingwave_pb::stream_plan::SinkNode;
 
 use super::*;
+use crate::common::log_store::SinkLogStore;
+use crate::common::table::state_table::StateTable;
 use crate::executor::{SinkExecutor, StreamExecutorError};
 
 pub struct SinkExecutorBuilder;
@@ -27,7 +31,7 @@ impl ExecutorBuilder for SinkExecutorBuilder {
     async fn new_boxed_executor(
         params: ExecutorParams,
         node: &Self::Node,
-        _store: impl StateStore,
+        store: impl StateStore,
         stream: &mut LocalStreamManagerCore,
     ) -> StreamResult<BoxedExecutor> {
         let [materialize_executor]: [_; 1] = params.input.try_into().unwrap();
@@ -40,22 +44,50 @@ impl ExecutorBuilder for SinkExecutorBuilder {
             .map(|pk| pk.index as usize)
             .collect::<Vec<_>>();
         let schema = sink_desc.columns.iter().map(Into::into).collect();
+        let mut dlq_properties = SinkConfig::split_dlq_properties(&mut properties);
         // This field can be used to distinguish a specific actor in parallelism to prevent
         // transaction execution errors
         properties.insert(
             "identifier".to_string(),
             format!("sink-{:?}", params.executor_id),
         );
+        if let Some(dlq_properties) = &mut dlq_properties {
+            dlq_properties.insert(
+                "identifier".to_string(),
+                format!("sink-dlq-{:?}", params.executor_id),
+            );
+        }
         let config = SinkConfig::from_hashmap(properties).map_err(StreamExecutorError::from)?;
+        let dlq_config = dlq_properties
+            .map(SinkConfig::from_hashmap)
+            .transpose()
+            .map_err(StreamExecutorError::from)?;
+
+        // The log store table is only present when the sink log store is enabled.
+        let log_store = match &node.log_store_table {
+            Some(table) => {
+                let vnodes = params.vnode_bitmap.map(Arc::new);
+                let log_store_table = StateTable::from_table_catalog(table, store, vnodes).await;
+                Some(SinkLogStore::new(log_store_table, params.actor_context.id))
+            }
+            None => None,
+        };
+
+        let freshness_reporter = stream
+            .context
+            .register_sink_freshness(params.actor_context.id, sink_desc.id);
 
         Ok(Box::new(SinkExecutor::new(
             materialize_executor,
             stream.streaming_metrics.clone(),
             config,
+            dlq_config,
+            log_store,
             params.executor_id,
             params.env.connector_params(),
             schema,
             pk_indices,
+            freshness_reporter,
         )))
     }
 }
@@ -16,7 +16,12 @@ use std::collections::{HashMap, HashSet};
 
 use anyhow::anyhow;
 use prometheus::HistogramTimer;
-use risingwave_pb::stream_service::barrier_complete_response::CreateMviewProgress as ProstCreateMviewProgress;
+use risingwave_pb::stream_service::barrier_complete_response::{
+    ActorCollectTrace as ProstActorCollectTrace, AggKeyCount as ProstAggKeyCount,
+    BackpressureStat as ProstBackpressureStat, CreateMviewProgress as ProstCreateMviewProgress,
+    SinkFreshness as ProstSinkFreshness,
+};
+use risingwave_pb::stream_service::EphemeralStateSnapshot;
 use tokio::sync::mpsc::UnboundedSender;
 use tokio::sync::oneshot;
 use tokio::sync::oneshot::Receiver;
@@ -26,12 +31,16 @@ use crate::error::{StreamError, StreamResult};
 use crate::executor::*;
 use crate::task::ActorId;
 
+mod ephemeral;
 mod managed_state;
 mod progress;
+mod stats;
 #[cfg(test)]
 mod tests;
 
+pub use ephemeral::EphemeralStateHandle;
 pub use progress::CreateMviewProgress;
+pub use stats::{AggKeyCountReporter, BackpressureReporter, SinkFreshnessReporter};
 use risingwave_common::bail;
 use risingwave_storage::StateStoreImpl;
 
@@ -43,6 +52,11 @@ pub const ENABLE_BARRIER_AGGREGATION: bool = false;
 #[derive(Debug)]
 pub struct CollectResult {
     pub create_mview_progress: Vec<ProstCreateMviewProgress>,
+    pub agg_key_counts: Vec<ProstAggKeyCount>,
+    pub sink_freshness: Vec<ProstSinkFreshness>,
+    pub backpressure_stats: Vec<ProstBackpressureStat>,
+    pub collect_traces: Vec<ProstActorCollectTrace>,
+    pub ephemeral_snapshots: Vec<EphemeralStateSnapshot>,
 }
 
 enum BarrierState {
@@ -72,6 +86,10 @@ pub struct LocalBarrierManager {
 
     /// Save collect `CompleteReceiver`.
     collect_complete_receiver: HashMap<u64, CompleteReceiver>,
+
+    /// Persisted snapshots of `memory_only` state tables, stashed from the inject barrier
+    /// request after actor (re)creation and taken by [`EphemeralStateHandle::restore`].
+    ephemeral_restore: HashMap<u32, Vec<u8>>,
 }
 
 /// Information used after collection.
@@ -91,6 +109,7 @@ impl LocalBarrierManager {
             span: tracing::Span::none(),
             state,
             collect_complete_receiver: HashMap::default(),
+            ephemeral_restore: HashMap::default(),
         }
     }
 
@@ -171,6 +190,18 @@ impl LocalBarrierManager {
             for actor in actors {
                 self.senders.remove(actor);
             }
+            match &mut self.state {
+                #[cfg(test)]
+                BarrierState::Local => {}
+
+                BarrierState::Managed(managed_state) => {
+                    for actor in actors {
+                        managed_state.agg_key_counts.remove(actor);
+                        managed_state.sink_freshness.remove(actor);
+                        managed_state.backpressure_stats.remove(actor);
+                    }
+                }
+            }
         }
 
         self.collect_complete_receiver.insert(
@@ -184,6 +215,15 @@ impl LocalBarrierManager {
         Ok(())
     }
 
+    /// Stash persisted snapshots of `memory_only` state tables sent along with an inject barrier
+    /// request, to be taken by the executors on their first barrier.
+    pub fn stash_ephemeral_restore(&mut self, snapshots: Vec<EphemeralStateSnapshot>) {
+        for snapshot in snapshots {
+            self.ephemeral_restore
+                .insert(snapshot.table_id, snapshot.snapshot);
+        }
+    }
+
     /// Use `prev_epoch` to remove collect rx and return rx.
     pub fn remove_collect_rx(&mut self, prev_epoch: u64) -> StreamResult<CompleteReceiver> {
         // It's still possible that `collect_complete_receiver` does not contain the target epoch
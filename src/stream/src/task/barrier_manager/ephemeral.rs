@@ -0,0 +1,114 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use super::{BarrierState, LocalBarrierManager};
+use crate::task::SharedContext;
+
+impl LocalBarrierManager {
+    fn update_ephemeral_snapshot(&mut self, table_id: u32, epoch: u64, snapshot: Vec<u8>) {
+        match &mut self.state {
+            #[cfg(test)]
+            BarrierState::Local => {}
+
+            BarrierState::Managed(managed_state) => {
+                managed_state
+                    .ephemeral_snapshots
+                    .entry(table_id)
+                    .or_default()
+                    .insert(epoch, snapshot);
+            }
+        }
+    }
+
+    fn remove_ephemeral_snapshot(&mut self, table_id: u32) {
+        self.ephemeral_restore.remove(&table_id);
+        match &mut self.state {
+            #[cfg(test)]
+            BarrierState::Local => {}
+
+            BarrierState::Managed(managed_state) => {
+                managed_state.ephemeral_snapshots.remove(&table_id);
+            }
+        }
+    }
+}
+
+/// The handle held by executors whose state table is marked `memory_only` in the catalog. Instead
+/// of writing to a [`StateTable`](crate::common::table::state_table::StateTable), such executors
+/// keep their (tiny) state in memory and snapshot it through this handle, and the snapshot is
+/// piggybacked on the collection report of the following barriers and persisted by the meta
+/// service at checkpoints.
+pub struct EphemeralStateHandle {
+    barrier_manager: Arc<parking_lot::Mutex<LocalBarrierManager>>,
+
+    /// The id of the `memory_only` state table in the catalog.
+    table_id: u32,
+}
+
+impl EphemeralStateHandle {
+    pub fn new(
+        barrier_manager: Arc<parking_lot::Mutex<LocalBarrierManager>>,
+        table_id: u32,
+    ) -> Self {
+        Self {
+            barrier_manager,
+            table_id,
+        }
+    }
+
+    #[cfg(test)]
+    pub fn for_test(barrier_manager: Arc<parking_lot::Mutex<LocalBarrierManager>>) -> Self {
+        Self::new(barrier_manager, 0)
+    }
+
+    /// Report the snapshot of the state as of the barrier of `epoch` (its curr epoch). Should be
+    /// called on barriers whenever the state has changed since the last call, before the barrier
+    /// is yielded downstream.
+    pub fn update(&mut self, epoch: u64, snapshot: Vec<u8>) {
+        self.barrier_manager
+            .lock()
+            .update_ephemeral_snapshot(self.table_id, epoch, snapshot);
+    }
+
+    /// Take the snapshot persisted by the meta service, if any. Stashed by the stream manager when
+    /// the first barrier after actor creation is injected, so should be called before that barrier
+    /// is yielded downstream. Returns `None` on a fresh start.
+    pub fn restore(&mut self) -> Option<Vec<u8>> {
+        self.barrier_manager
+            .lock()
+            .ephemeral_restore
+            .remove(&self.table_id)
+    }
+}
+
+impl Drop for EphemeralStateHandle {
+    fn drop(&mut self) {
+        // Stop reporting the snapshot once the executor is dropped, so that the meta service
+        // does not keep persisting the state of a dropped streaming job.
+        self.barrier_manager
+            .lock()
+            .remove_ephemeral_snapshot(self.table_id);
+    }
+}
+
+impl SharedContext {
+    /// Create a handle for snapshotting a `memory_only` state table into the meta store at
+    /// barriers, instead of writing it to Hummock.
+    pub fn register_ephemeral_state(&self, table_id: u32) -> EphemeralStateHandle {
+        trace!("register ephemeral state: table {}", table_id);
+        EphemeralStateHandle::new(self.barrier_manager.clone(), table_id)
+    }
+}
@@ -14,10 +14,14 @@
 
 use std::collections::{BTreeMap, HashMap, HashSet};
 use std::iter::once;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use anyhow::anyhow;
 use risingwave_common::bail;
-use risingwave_pb::stream_service::barrier_complete_response::CreateMviewProgress;
+use risingwave_pb::stream_service::barrier_complete_response::{
+    ActorCollectTrace, AggKeyCount, BackpressureStat, CreateMviewProgress, SinkFreshness,
+};
+use risingwave_pb::stream_service::EphemeralStateSnapshot;
 use risingwave_storage::{dispatch_state_store, StateStore, StateStoreImpl};
 use tokio::sync::oneshot;
 
@@ -64,6 +68,33 @@ pub(super) struct ManagedBarrierState {
     /// Record the progress updates of creating mviews for each epoch of concurrent checkpoints.
     pub(super) create_mview_progress: HashMap<u64, HashMap<ActorId, ChainState>>,
 
+    /// Record the latest distinct key count reported by each hash agg actor, along with the id of
+    /// its result table. Unlike `create_mview_progress`, this is not tracked per epoch: the
+    /// snapshot at collection time is reported with every barrier.
+    pub(super) agg_key_counts: HashMap<ActorId, (u32, u64)>,
+
+    /// Record the freshness of the last completed commit of each sink actor, as
+    /// `(sink_id, sampled_event_ts_ms, commit_at_ms)`. Like `agg_key_counts`, the snapshot at
+    /// collection time is reported with every barrier.
+    pub(super) sink_freshness: HashMap<ActorId, (u32, u64, u64)>,
+
+    /// Record the time each dispatching actor spent blocked on its output exchange channels, as
+    /// `(fragment_id, [(downstream_fragment_id, blocked_ns)], elapsed_ns)` over the last barrier
+    /// interval. Like `agg_key_counts`, the snapshot at collection time is reported with every
+    /// barrier.
+    pub(super) backpressure_stats: HashMap<ActorId, (u32, Vec<(u32, u64)>, u64)>,
+
+    /// Record when each actor collected the barrier for each epoch of concurrent checkpoints, to
+    /// be reported to the meta service for barrier tracing. The key is curr_epoch.
+    collect_traces: HashMap<u64, Vec<ActorCollectTrace>>,
+
+    /// Record the snapshots of each `memory_only` state table on this worker, keyed by table id
+    /// and then by the curr_epoch of the barrier the snapshot was taken at. Keeping snapshots
+    /// per epoch ensures that each barrier reports the state as of exactly its own epoch, even
+    /// with multiple barriers in flight. Reported with every barrier and persisted by the meta
+    /// service at checkpoints.
+    pub(super) ephemeral_snapshots: HashMap<u32, BTreeMap<u64, Vec<u8>>>,
+
     /// Record all unexpected exited actors.
     failure_actors: HashMap<ActorId, StreamError>,
 
@@ -76,6 +107,11 @@ impl ManagedBarrierState {
         Self {
             epoch_barrier_state_map: BTreeMap::default(),
             create_mview_progress: Default::default(),
+            agg_key_counts: Default::default(),
+            sink_freshness: Default::default(),
+            backpressure_stats: Default::default(),
+            collect_traces: Default::default(),
+            ephemeral_snapshots: Default::default(),
             failure_actors: Default::default(),
             state_store,
         }
@@ -128,6 +164,66 @@ impl ManagedBarrierState {
                         },
                     })
                     .collect();
+                let agg_key_counts = self
+                    .agg_key_counts
+                    .iter()
+                    .map(|(actor, (table_id, count))| AggKeyCount {
+                        actor_id: *actor,
+                        table_id: *table_id,
+                        distinct_key_count: *count,
+                    })
+                    .collect();
+                let sink_freshness = self
+                    .sink_freshness
+                    .iter()
+                    .map(
+                        |(actor, (sink_id, sampled_event_ts_ms, commit_at_ms))| SinkFreshness {
+                            actor_id: *actor,
+                            sink_id: *sink_id,
+                            sampled_event_ts_ms: *sampled_event_ts_ms,
+                            commit_at_ms: *commit_at_ms,
+                        },
+                    )
+                    .collect();
+                let backpressure_stats = self
+                    .backpressure_stats
+                    .iter()
+                    .flat_map(|(actor, (fragment_id, blocked_ns, elapsed_ns))| {
+                        blocked_ns.iter().map(
+                            move |(downstream_fragment_id, blocked_duration_ns)| BackpressureStat {
+                                actor_id: *actor,
+                                fragment_id: *fragment_id,
+                                downstream_fragment_id: *downstream_fragment_id,
+                                blocked_duration_ns: *blocked_duration_ns,
+                                elapsed_duration_ns: *elapsed_ns,
+                            },
+                        )
+                    })
+                    .collect();
+                let collect_traces = self.collect_traces.remove(&epoch).unwrap_or_default();
+                // For each `memory_only` state table, report the latest snapshot taken at or
+                // before this epoch, i.e. the state as of exactly this epoch. Earlier snapshots
+                // will never be reported again as barriers complete in epoch order, so prune
+                // them.
+                for snapshots in self.ephemeral_snapshots.values_mut() {
+                    while snapshots.len() > 1 && *snapshots.iter().nth(1).unwrap().0 <= epoch {
+                        snapshots.pop_first();
+                    }
+                }
+                let ephemeral_snapshots = self
+                    .ephemeral_snapshots
+                    .iter()
+                    .filter_map(|(table_id, snapshots)| {
+                        snapshots
+                            .range(..=epoch)
+                            .next_back()
+                            .map(|(_, snapshot)| EphemeralStateSnapshot {
+                                table_id: *table_id,
+                                snapshot: snapshot.clone(),
+                                epoch,
+                            })
+                    })
+                    .collect();
 
                 dispatch_state_store!(&self.state_store, state_store, {
                     state_store.seal_epoch(barrier_state.prev_epoch, barrier_state.checkpoint);
@@ -140,6 +236,11 @@ impl ManagedBarrierState {
                         // Notify about barrier finishing.
                         let result = CollectResult {
                             create_mview_progress,
+                            agg_key_counts,
+                            sink_freshness,
+                            backpressure_stats,
+                            collect_traces,
+                            ephemeral_snapshots,
                         };
                         if collect_notifier.unwrap().send(Ok(result)).is_err() {
                             warn!("failed to notify barrier collection with epoch {}", epoch)
@@ -156,6 +257,11 @@ impl ManagedBarrierState {
         tracing::debug!("clear all states in local barrier manager");
         self.epoch_barrier_state_map.clear();
         self.create_mview_progress.clear();
+        self.agg_key_counts.clear();
+        self.sink_freshness.clear();
+        self.backpressure_stats.clear();
+        self.collect_traces.clear();
+        self.ephemeral_snapshots.clear();
         self.failure_actors.clear();
     }
 
@@ -194,6 +300,18 @@ impl ManagedBarrierState {
             self
         );
 
+        let collect_at_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Clock may have gone backwards")
+            .as_millis() as u64;
+        self.collect_traces
+            .entry(barrier.epoch.curr)
+            .or_default()
+            .push(ActorCollectTrace {
+                actor_id,
+                collect_at_ms,
+            });
+
         match self.epoch_barrier_state_map.get_mut(&barrier.epoch.curr) {
             Some(&mut BarrierState {
                 prev_epoch,
@@ -0,0 +1,228 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use super::{BarrierState, LocalBarrierManager};
+use crate::task::{ActorId, SharedContext};
+
+impl LocalBarrierManager {
+    fn update_agg_key_count(&mut self, actor: ActorId, table_id: u32, count: u64) {
+        match &mut self.state {
+            #[cfg(test)]
+            BarrierState::Local => {}
+
+            BarrierState::Managed(managed_state) => {
+                managed_state.agg_key_counts.insert(actor, (table_id, count));
+            }
+        }
+    }
+
+    fn update_backpressure(
+        &mut self,
+        actor: ActorId,
+        fragment_id: u32,
+        blocked_ns: Vec<(u32, u64)>,
+        elapsed_ns: u64,
+    ) {
+        match &mut self.state {
+            #[cfg(test)]
+            BarrierState::Local => {}
+
+            BarrierState::Managed(managed_state) => {
+                managed_state
+                    .backpressure_stats
+                    .insert(actor, (fragment_id, blocked_ns, elapsed_ns));
+            }
+        }
+    }
+
+    fn update_sink_freshness(
+        &mut self,
+        actor: ActorId,
+        sink_id: u32,
+        sampled_event_ts_ms: u64,
+        commit_at_ms: u64,
+    ) {
+        match &mut self.state {
+            #[cfg(test)]
+            BarrierState::Local => {}
+
+            BarrierState::Managed(managed_state) => {
+                managed_state
+                    .sink_freshness
+                    .insert(actor, (sink_id, sampled_event_ts_ms, commit_at_ms));
+            }
+        }
+    }
+}
+
+/// The handle held by hash agg executors to report the number of distinct group keys they hold to
+/// the local barrier manager.
+pub struct AggKeyCountReporter {
+    barrier_manager: Arc<parking_lot::Mutex<LocalBarrierManager>>,
+
+    /// The id of the actor containing the hash agg node.
+    actor_id: ActorId,
+
+    /// The id of the agg result table, identifying the aggregation across its parallel actors.
+    table_id: u32,
+}
+
+impl AggKeyCountReporter {
+    pub fn new(
+        barrier_manager: Arc<parking_lot::Mutex<LocalBarrierManager>>,
+        actor_id: ActorId,
+        table_id: u32,
+    ) -> Self {
+        Self {
+            barrier_manager,
+            actor_id,
+            table_id,
+        }
+    }
+
+    #[cfg(test)]
+    pub fn for_test(barrier_manager: Arc<parking_lot::Mutex<LocalBarrierManager>>) -> Self {
+        Self::new(barrier_manager, 0, 0)
+    }
+
+    /// Report the latest distinct key count. The count will be piggybacked on the collection
+    /// report of the following barriers.
+    pub fn update(&mut self, count: u64) {
+        self.barrier_manager
+            .lock()
+            .update_agg_key_count(self.actor_id, self.table_id, count);
+    }
+}
+
+/// The handle held by dispatch executors to report the time they spent blocked on their output
+/// exchange channels to the local barrier manager.
+pub struct BackpressureReporter {
+    barrier_manager: Arc<parking_lot::Mutex<LocalBarrierManager>>,
+
+    /// The id of the dispatching actor.
+    actor_id: ActorId,
+
+    /// The id of the fragment the actor belongs to.
+    fragment_id: u32,
+}
+
+impl BackpressureReporter {
+    pub fn new(
+        barrier_manager: Arc<parking_lot::Mutex<LocalBarrierManager>>,
+        actor_id: ActorId,
+        fragment_id: u32,
+    ) -> Self {
+        Self {
+            barrier_manager,
+            actor_id,
+            fragment_id,
+        }
+    }
+
+    #[cfg(test)]
+    pub fn for_test(barrier_manager: Arc<parking_lot::Mutex<LocalBarrierManager>>) -> Self {
+        Self::new(barrier_manager, 0, 0)
+    }
+
+    /// Report the time the actor spent blocked on dispatching to each downstream fragment since
+    /// the last barrier, together with the wall time elapsed over the same interval. The report
+    /// will be piggybacked on the collection report of the following barriers.
+    pub fn update(&mut self, blocked_ns: Vec<(u32, u64)>, elapsed_ns: u64) {
+        self.barrier_manager.lock().update_backpressure(
+            self.actor_id,
+            self.fragment_id,
+            blocked_ns,
+            elapsed_ns,
+        );
+    }
+}
+
+/// The handle held by sink executors to report the freshness of their last completed commit to
+/// the local barrier manager.
+pub struct SinkFreshnessReporter {
+    barrier_manager: Arc<parking_lot::Mutex<LocalBarrierManager>>,
+
+    /// The id of the actor containing the sink node.
+    actor_id: ActorId,
+
+    /// The id of the sink, identifying it across its parallel actors.
+    sink_id: u32,
+}
+
+impl SinkFreshnessReporter {
+    pub fn new(
+        barrier_manager: Arc<parking_lot::Mutex<LocalBarrierManager>>,
+        actor_id: ActorId,
+        sink_id: u32,
+    ) -> Self {
+        Self {
+            barrier_manager,
+            actor_id,
+            sink_id,
+        }
+    }
+
+    #[cfg(test)]
+    pub fn for_test(barrier_manager: Arc<parking_lot::Mutex<LocalBarrierManager>>) -> Self {
+        Self::new(barrier_manager, 0, 0)
+    }
+
+    /// Report the newest sampled event timestamp committed to the sink target and the wall time
+    /// at which the commit completed. The report will be piggybacked on the collection report of
+    /// the following barriers.
+    pub fn update(&mut self, sampled_event_ts_ms: u64, commit_at_ms: u64) {
+        self.barrier_manager.lock().update_sink_freshness(
+            self.actor_id,
+            self.sink_id,
+            sampled_event_ts_ms,
+            commit_at_ms,
+        );
+    }
+}
+
+impl SharedContext {
+    /// Create a struct for reporting the distinct key count of a hash aggregation. The hash agg
+    /// executors should report their key count on every barrier using this, so that the meta
+    /// service can observe skewed distributions of group keys among the parallel actors.
+    pub fn register_agg_key_count(&self, actor_id: ActorId, table_id: u32) -> AggKeyCountReporter {
+        trace!("register agg key count: actor {}, table {}", actor_id, table_id);
+        AggKeyCountReporter::new(self.barrier_manager.clone(), actor_id, table_id)
+    }
+
+    /// Create a struct for reporting blocked output time. The dispatch executors should report
+    /// their per-edge blocked time on every barrier using this, so that the meta service can
+    /// roll up backpressure ratios per fragment edge.
+    pub fn register_backpressure(
+        &self,
+        actor_id: ActorId,
+        fragment_id: u32,
+    ) -> BackpressureReporter {
+        trace!("register backpressure: actor {}, fragment {}", actor_id, fragment_id);
+        BackpressureReporter::new(self.barrier_manager.clone(), actor_id, fragment_id)
+    }
+
+    /// Create a struct for reporting sink freshness. The sink executors should report the
+    /// sampled event time and commit time on every completed commit using this, so that the meta
+    /// service can derive an end-to-end freshness number per sink.
+    pub fn register_sink_freshness(
+        &self,
+        actor_id: ActorId,
+        sink_id: u32,
+    ) -> SinkFreshnessReporter {
+        trace!("register sink freshness: actor {}, sink {}", actor_id, sink_id);
+        SinkFreshnessReporter::new(self.barrier_manager.clone(), actor_id, sink_id)
+    }
+}
@@ -23,7 +23,8 @@ use risingwave_pb::common::ActorInfo;
 use risingwave_rpc_client::ComputeClientPool;
 
 use crate::error::StreamResult;
-use crate::executor::exchange::permit::{self, Receiver, Sender};
+use crate::executor::exchange::permit::{self, ChannelMetrics, Receiver, Sender};
+use crate::executor::monitor::StreamingMetrics;
 
 mod barrier_manager;
 mod env;
@@ -80,6 +81,8 @@ pub struct SharedContext {
     pub(crate) barrier_manager: Arc<Mutex<LocalBarrierManager>>,
 
     pub(crate) config: StreamingConfig,
+
+    pub(crate) streaming_metrics: Arc<StreamingMetrics>,
 }
 
 impl std::fmt::Debug for SharedContext {
@@ -91,7 +94,12 @@ impl std::fmt::Debug for SharedContext {
 }
 
 impl SharedContext {
-    pub fn new(addr: HostAddr, state_store: StateStoreImpl, config: &StreamingConfig) -> Self {
+    pub fn new(
+        addr: HostAddr,
+        state_store: StateStoreImpl,
+        config: &StreamingConfig,
+        streaming_metrics: Arc<StreamingMetrics>,
+    ) -> Self {
         Self {
             channel_map: Default::default(),
             actor_infos: Default::default(),
@@ -99,6 +107,7 @@ impl SharedContext {
             compute_client_pool: ComputeClientPool::default(),
             barrier_manager: Arc::new(Mutex::new(LocalBarrierManager::new(state_store))),
             config: config.clone(),
+            streaming_metrics,
         }
     }
 
@@ -113,6 +122,7 @@ impl SharedContext {
                 StateStoreImpl::for_test(),
             ))),
             config: StreamingConfig::default(),
+            streaming_metrics: Arc::new(StreamingMetrics::unused()),
         }
     }
 
@@ -147,9 +157,23 @@ impl SharedContext {
 
     #[inline]
     pub fn add_channel_pairs(&self, ids: UpDownActorIds) {
+        let up_actor_id = ids.0.to_string();
+        let down_actor_id = ids.1.to_string();
+        let metrics = ChannelMetrics {
+            occupancy_rows: self
+                .streaming_metrics
+                .exchange_channel_occupancy_rows
+                .with_label_values(&[&up_actor_id, &down_actor_id]),
+            capacity: self
+                .streaming_metrics
+                .exchange_channel_capacity
+                .with_label_values(&[&up_actor_id, &down_actor_id]),
+        };
         let (tx, rx) = permit::channel(
             self.config.developer.stream_exchange_initial_permits,
             self.config.developer.stream_exchange_batched_permits,
+            self.config.developer.stream_exchange_adaptive_permits,
+            Some(metrics),
         );
         assert!(
             self.lock_channel_map()
@@ -32,9 +32,14 @@ use risingwave_common::config::StreamingConfig;
 use risingwave_common::util::addr::HostAddr;
 use risingwave_hummock_sdk::LocalSstableInfo;
 use risingwave_pb::common::ActorInfo;
+use risingwave_pb::meta::actor_usage_info::ActorUsage;
+use risingwave_pb::meta::heartbeat_request::extra_info::Info;
+use risingwave_pb::meta::ActorUsageInfo;
 use risingwave_pb::stream_plan::stream_node::NodeBody;
 use risingwave_pb::stream_plan::StreamNode;
+use risingwave_pb::stream_service::EphemeralStateSnapshot;
 use risingwave_pb::{stream_plan, stream_service};
+use risingwave_rpc_client::ExtraInfoSource;
 use risingwave_storage::{dispatch_state_store, StateStore, StateStoreImpl};
 use tokio::sync::Mutex;
 use tokio::task::JoinHandle;
@@ -89,6 +94,10 @@ pub struct LocalStreamManagerCore {
     watermark_epoch: AtomicU64Ref,
 
     total_mem_val: Arc<TrAdder<i64>>,
+
+    /// Latest per-actor poll time and memory usage samples, reported to the meta service via
+    /// heartbeat so the dashboard can attribute compute-node resources to actors.
+    actor_usages: Arc<parking_lot::Mutex<HashMap<ActorId, ActorUsage>>>,
 }
 
 /// `LocalStreamManager` manages all stream executors in this project.
@@ -101,6 +110,8 @@ pub struct LocalStreamManager {
     streaming_metrics: Arc<StreamingMetrics>,
 
     total_mem_val: Arc<TrAdder<i64>>,
+
+    actor_usages: Arc<parking_lot::Mutex<HashMap<ActorId, ActorUsage>>>,
 }
 
 pub struct ExecutorParams {
@@ -137,6 +148,26 @@ pub struct ExecutorParams {
     pub vnode_bitmap: Option<Bitmap>,
 }
 
+impl ExecutorParams {
+    /// The maximum chunk cardinality for this executor: the per-fragment override if one is
+    /// configured, otherwise `stream_chunk_size` scaled down so that a full chunk of this
+    /// executor's schema stays within `stream_chunk_target_bytes`.
+    pub fn chunk_size(&self) -> usize {
+        let developer = &self.env.config().developer;
+        if let Some(chunk_size) = developer.stream_chunk_size_per_fragment.get(&self.fragment_id) {
+            return (*chunk_size).max(1);
+        }
+        let row_width: usize = self
+            .schema
+            .fields
+            .iter()
+            .map(|field| field.data_type.estimated_width())
+            .sum();
+        (developer.stream_chunk_target_bytes / row_width.max(1))
+            .clamp(1, developer.stream_chunk_size)
+    }
+}
+
 impl Debug for ExecutorParams {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("ExecutorParams")
@@ -158,6 +189,7 @@ impl LocalStreamManager {
             context: core.context.clone(),
             streaming_metrics: core.streaming_metrics.clone(),
             total_mem_val: core.total_mem_val.clone(),
+            actor_usages: core.actor_usages.clone(),
             core: Mutex::new(core),
         }
     }
@@ -233,6 +265,14 @@ impl LocalStreamManager {
         Ok(())
     }
 
+    /// Stash persisted snapshots of `memory_only` state tables, sent by the meta service along
+    /// with the first barrier after actor (re)creation.
+    pub fn stash_ephemeral_restore(&self, snapshots: Vec<EphemeralStateSnapshot>) {
+        self.context
+            .lock_barrier_manager()
+            .stash_ephemeral_restore(snapshots);
+    }
+
     /// Clear all senders and collect rx in barrier manager.
     pub fn clear_all_senders_and_collect_rx(&self) {
         let mut barrier_manager = self.context.lock_barrier_manager();
@@ -374,6 +414,14 @@ impl LocalStreamManager {
     }
 }
 
+#[async_trait::async_trait]
+impl ExtraInfoSource for LocalStreamManager {
+    async fn get_extra_info(&self) -> Option<Info> {
+        let usages = self.actor_usages.lock().values().cloned().collect();
+        Some(Info::ActorUsage(ActorUsageInfo { usages }))
+    }
+}
+
 fn update_upstreams(context: &SharedContext, ids: &[UpDownActorIds]) {
     ids.iter()
         .map(|&id| {
@@ -390,7 +438,12 @@ impl LocalStreamManagerCore {
         config: StreamingConfig,
         async_stack_trace_config: Option<TraceConfig>,
     ) -> Self {
-        let context = SharedContext::new(addr, state_store.clone(), &config);
+        let context = SharedContext::new(
+            addr,
+            state_store.clone(),
+            &config,
+            streaming_metrics.clone(),
+        );
         Self::new_inner(
             state_store,
             context,
@@ -432,6 +485,7 @@ impl LocalStreamManagerCore {
             stack_trace_manager: async_stack_trace_config.map(StackTraceManager::new),
             watermark_epoch: Arc::new(AtomicU64::new(0)),
             total_mem_val: Arc::new(TrAdder::new()),
+            actor_usages: Arc::new(parking_lot::Mutex::new(HashMap::new())),
         }
     }
 
@@ -456,6 +510,8 @@ impl LocalStreamManagerCore {
         input: BoxedExecutor,
         dispatchers: &[stream_plan::Dispatcher],
         actor_id: ActorId,
+        fragment_id: FragmentId,
+        actor_context: &ActorContextRef,
     ) -> StreamResult<DispatchExecutor> {
         let dispatcher_impls = dispatchers
             .iter()
@@ -466,6 +522,9 @@ impl LocalStreamManagerCore {
             input,
             dispatcher_impls,
             actor_id,
+            fragment_id,
+            actor_context.clone(),
+            self.config.actor_memory_hard_limit_mb << 20,
             self.context.clone(),
             self.streaming_metrics.clone(),
         ))
@@ -633,7 +692,13 @@ impl LocalStreamManagerCore {
                 )
                 .await?;
 
-            let dispatcher = self.create_dispatcher(executor, &actor.dispatcher, actor_id)?;
+            let dispatcher = self.create_dispatcher(
+                executor,
+                &actor.dispatcher,
+                actor_id,
+                actor.fragment_id,
+                &actor_context,
+            )?;
             let actor = Actor::new(
                 dispatcher,
                 subtasks,
@@ -646,6 +711,7 @@ impl LocalStreamManagerCore {
 
             let metrics = self.streaming_metrics.clone();
             let actor_id_str = actor_id.to_string();
+            let monitor_context = actor_context.clone();
 
             let handle = {
                 let context = self.context.clone();
@@ -683,6 +749,7 @@ impl LocalStreamManagerCore {
             let actor_id_str = actor_id.to_string();
 
             let metrics = self.streaming_metrics.clone();
+            let actor_usages = self.actor_usages.clone();
             let actor_monitor_task = self.runtime.spawn(async move {
                 loop {
                     let task_metrics = monitor.cumulative();
@@ -730,6 +797,14 @@ impl LocalStreamManagerCore {
                         .actor_scheduled_cnt
                         .with_label_values(&[&actor_id_str])
                         .set(task_metrics.total_scheduled_count as i64);
+                    actor_usages.lock().insert(
+                        actor_id,
+                        ActorUsage {
+                            actor_id,
+                            poll_duration_ns: task_metrics.total_poll_duration.as_nanos() as u64,
+                            memory_bytes: monitor_context.mem_usage() as u64,
+                        },
+                    );
                     tokio::time::sleep(Duration::from_secs(1)).await;
                 }
             });
@@ -779,6 +854,7 @@ impl LocalStreamManagerCore {
         self.actor_monitor_tasks
             .remove(&actor_id)
             .inspect(|handle| handle.abort());
+        self.actor_usages.lock().remove(&actor_id);
         self.context.actor_infos.write().remove(&actor_id);
         self.actors.remove(&actor_id);
         // Task should have already stopped when this method is invoked.
@@ -804,6 +880,7 @@ impl LocalStreamManagerCore {
             m.reset()
         }
         self.actor_monitor_tasks.clear();
+        self.actor_usages.lock().clear();
         self.context.actor_infos.write().clear();
     }
 
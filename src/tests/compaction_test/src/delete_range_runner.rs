@@ -44,7 +44,7 @@ use risingwave_storage::hummock::compactor::{CompactionExecutor, CompactorContex
 use risingwave_storage::hummock::sstable_store::SstableStoreRef;
 use risingwave_storage::hummock::store::state_store::LocalHummockStorage;
 use risingwave_storage::hummock::{
-    HummockStorage, MemoryLimiter, SstableIdManager, SstableStore, TieredCache,
+    HummockStorage, IoLimiter, MemoryLimiter, SstableIdManager, SstableStore, TieredCache,
 };
 use risingwave_storage::monitor::{CompactorMetrics, HummockStateStoreMetrics};
 use risingwave_storage::opts::StorageOpts;
@@ -84,8 +84,7 @@ pub fn start_delete_range(opts: CompactionTestOpts) -> Pin<Box<dyn Future<Output
     })
 }
 pub async fn compaction_test_main(opts: CompactionTestOpts) -> anyhow::Result<()> {
-    let mut config = load_config(&opts.config_path, NO_OVERRIDE);
-    config.storage.enable_state_store_v1 = false;
+    let config = load_config(&opts.config_path, NO_OVERRIDE);
     let compaction_config = CompactionConfigBuilder::new().build();
     compaction_test(
         compaction_config,
@@ -135,6 +134,8 @@ async fn compaction_test(
         row_id_index: None,
         version: None,
         watermark_indices: vec![],
+        stats: None,
+        memory_only: false,
     };
     let mut delete_range_table = delete_key_table.clone();
     delete_range_table.id = 2;
@@ -592,10 +593,12 @@ fn run_compactor_thread(
         compaction_executor: Arc::new(CompactionExecutor::new(None)),
         filter_key_extractor_manager,
         read_memory_limiter: MemoryLimiter::unlimit(),
+        io_limiter: IoLimiter::unlimited(),
         sstable_id_manager,
         task_progress_manager: Default::default(),
         compactor_runtime_config: Arc::new(tokio::sync::Mutex::new(CompactorRuntimeConfig {
             max_concurrent_task_number: 4,
+            max_io_bandwidth_bytes_per_sec: 0,
         })),
     });
     risingwave_storage::hummock::compactor::Compactor::start_compactor(
@@ -614,10 +617,7 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 3)]
     async fn test_small_data() {
-        let storage_opts = StorageOpts {
-            enable_state_store_v1: false,
-            ..Default::default()
-        };
+        let storage_opts = StorageOpts::default();
         let mut compaction_config = CompactionConfigBuilder::new().build();
         compaction_config.max_sub_compaction = 1;
         compaction_config.level0_tier_compact_file_number = 2;
@@ -468,6 +468,9 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                 }
             }
             A::FirstValue => None,
+            // The second argument must be a constant, which the sig-driven generator cannot
+            // produce.
+            A::ApproxTopK => None,
             A::ApproxCountDistinct => {
                 if self.is_distinct_allowed {
                     None
@@ -38,6 +38,7 @@ pub(super) fn data_type_to_ast_data_type(data_type: &DataType) -> AstDataType {
         DataType::Float64 => AstDataType::Double,
         DataType::Varchar => AstDataType::Varchar,
         DataType::Bytea => AstDataType::Bytea,
+        DataType::Jsonb => AstDataType::Jsonb,
         DataType::Date => AstDataType::Date,
         DataType::Timestamp => AstDataType::Timestamp(false),
         DataType::Timestamptz => AstDataType::Timestamp(true),
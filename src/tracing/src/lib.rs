@@ -21,7 +21,7 @@ use futures::channel::mpsc::{unbounded, UnboundedReceiver, UnboundedSender};
 use minitrace::prelude::*;
 
 pub struct RwTracingService {
-    tx: UnboundedSender<Collector>,
+    tx: UnboundedSender<(u64, Collector)>,
     _join_handle: Option<JoinHandle<()>>,
     enabled: bool,
 }
@@ -87,9 +87,16 @@ impl RwTracingService {
 
     /// Create a new tracing event.
     pub fn new_tracer(&self, event: &'static str) -> Span {
+        self.new_tracer_with_trace_id(event, 0)
+    }
+
+    /// Create a new tracing event with a pre-assigned trace id. Spans reported with the same
+    /// trace id are merged into a single trace, even when they are reported by different nodes.
+    /// A zero trace id lets the reporter assign a random one.
+    pub fn new_tracer_with_trace_id(&self, event: &'static str, trace_id: u64) -> Span {
         if self.enabled {
             let (span, collector) = Span::root(event);
-            self.tx.unbounded_send(collector).unwrap();
+            self.tx.unbounded_send((trace_id, collector)).unwrap();
             span
         } else {
             Span::new_noop()
@@ -98,7 +105,7 @@ impl RwTracingService {
 
     #[cfg(madsim)]
     fn start_tracing_listener(
-        _rx: UnboundedReceiver<Collector>,
+        _rx: UnboundedReceiver<(u64, Collector)>,
         _print_to_console: bool,
         _slow_request_threshold_ms: u64,
         _jaeger_addr: Option<SocketAddr>,
@@ -108,7 +115,7 @@ impl RwTracingService {
 
     #[cfg(not(madsim))]
     fn start_tracing_listener(
-        rx: UnboundedReceiver<Collector>,
+        rx: UnboundedReceiver<(u64, Collector)>,
         print_to_console: bool,
         slow_request_threshold_ms: u64,
         jaeger_addr: Option<SocketAddr>,
@@ -125,7 +132,7 @@ impl RwTracingService {
             .spawn(move || {
                 let func = move || {
                     let rt = tokio::runtime::Builder::new_current_thread().build()?;
-                    let stream = rx.for_each_concurrent(None, |collector| async {
+                    let stream = rx.for_each_concurrent(None, |(trace_id, collector)| async {
                         let spans = collector.collect().await;
                         if !spans.is_empty() {
                             // print slow requests
@@ -137,7 +144,11 @@ impl RwTracingService {
                             }
                             // report spans to jaeger
                             if let Some(ref jaeger_addr) = jaeger_addr {
-                                let trace_id = rand::thread_rng().gen::<u64>();
+                                let trace_id = if trace_id != 0 {
+                                    trace_id
+                                } else {
+                                    rand::thread_rng().gen::<u64>()
+                                };
                                 let span_id = rand::thread_rng().gen::<u32>();
                                 let encoded = minitrace_jaeger::encode(
                                     "risingwave".to_string(),
@@ -12,6 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#![feature(once_cell)]
+
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock, Mutex};
+use std::time::Duration;
+
 use arrow_array::RecordBatch;
 use arrow_flight::decode::FlightRecordBatchStream;
 use arrow_flight::encode::FlightDataEncoderBuilder;
@@ -22,6 +28,32 @@ use arrow_schema::Schema;
 use futures_util::{stream, Stream, StreamExt, TryStreamExt};
 use tonic::transport::Channel;
 
+/// Timeout applied to each UDF call attempt.
+const CALL_TIMEOUT: Duration = Duration::from_secs(5);
+/// How many times a failed UDF call is attempted in total before the error is surfaced.
+const CALL_MAX_ATTEMPTS: usize = 3;
+/// Delay before retrying a failed UDF call.
+const CALL_RETRY_DELAY: Duration = Duration::from_millis(100);
+
+/// Returns a client to the UDF service at `addr`, connecting if none exists yet. Expressions of
+/// different executors referencing functions served by the same endpoint share one underlying
+/// gRPC channel.
+pub async fn get_or_connect(addr: &str) -> Result<Arc<ArrowFlightUdfClient>> {
+    static CLIENTS: LazyLock<Mutex<HashMap<String, Arc<ArrowFlightUdfClient>>>> =
+        LazyLock::new(Default::default);
+    if let Some(client) = CLIENTS.lock().unwrap().get(addr) {
+        return Ok(client.clone());
+    }
+    let client = Arc::new(ArrowFlightUdfClient::connect(addr).await?);
+    // A concurrent connect to the same address may have won the race; keep the first client.
+    Ok(CLIENTS
+        .lock()
+        .unwrap()
+        .entry(addr.to_string())
+        .or_insert(client)
+        .clone())
+}
+
 /// Client for external function service based on Arrow Flight.
 #[derive(Debug)]
 pub struct ArrowFlightUdfClient {
@@ -61,8 +93,29 @@ impl ArrowFlightUdfClient {
         Ok(FunctionId(path))
     }
 
-    /// Call a function.
+    /// Call a function. Each attempt is bounded by [`CALL_TIMEOUT`], and transiently failed
+    /// calls are retried a few times before the error is surfaced, to ride over restarts of the
+    /// UDF service.
     pub async fn call(&self, id: &FunctionId, input: RecordBatch) -> Result<RecordBatch> {
+        let mut attempt = 1;
+        loop {
+            let result = match tokio::time::timeout(CALL_TIMEOUT, self.call_once(id, &input)).await
+            {
+                Ok(result) => result,
+                Err(_) => Err(Error::Timeout(CALL_TIMEOUT)),
+            };
+            match result {
+                Err(err) if err.is_transient() && attempt < CALL_MAX_ATTEMPTS => {
+                    attempt += 1;
+                    tokio::time::sleep(CALL_RETRY_DELAY).await;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    async fn call_once(&self, id: &FunctionId, input: &RecordBatch) -> Result<RecordBatch> {
+        let input = input.clone();
         let mut output_stream = self.call_stream(id, stream::once(async { input })).await?;
         output_stream.next().await.ok_or(Error::NoReturned)?
     }
@@ -150,8 +203,21 @@ pub enum Error {
         expected: String,
         actual: String,
     },
+    #[error("UDF call timed out after {0:?}")]
+    Timeout(Duration),
     #[error("UDF service returned no data")]
     NoReturned,
     #[error("UDF service returned a batch with no column")]
     NoColumn,
 }
+
+impl Error {
+    /// Whether the error may be resolved by retrying the call, e.g. the service being
+    /// temporarily unreachable.
+    fn is_transient(&self) -> bool {
+        matches!(
+            self,
+            Error::Connect(_) | Error::Tonic(_) | Error::Flight(_) | Error::Timeout(_)
+        )
+    }
+}
@@ -17,9 +17,29 @@ use std::io::Error as IoError;
 use anyhow::anyhow;
 use thiserror::Error;
 
+use crate::error_or_notice::SqlState;
 use crate::pg_server::BoxedError;
 pub type PsqlResult<T> = std::result::Result<T, PsqlError>;
 
+/// An error that is reported to the client with a specific SQLSTATE code instead of the default
+/// `XX000` internal error.
+#[derive(Error, Debug)]
+#[error("{message}")]
+pub struct SqlStateError {
+    state: SqlState,
+    message: String,
+}
+
+impl SqlStateError {
+    pub fn new(state: SqlState, message: String) -> Self {
+        Self { state, message }
+    }
+
+    pub fn state(&self) -> SqlState {
+        self.state.clone()
+    }
+}
+
 /// Error type used in pgwire crates.
 #[derive(Error, Debug)]
 pub enum PsqlError {
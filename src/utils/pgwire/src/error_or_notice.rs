@@ -21,9 +21,13 @@ pub struct ErrorOrNoticeMessage<'a> {
 
 impl<'a> ErrorOrNoticeMessage<'a> {
     pub fn internal_error(message: &'a str) -> Self {
+        Self::error(SqlState::INTERNAL_ERROR, message)
+    }
+
+    pub fn error(state: SqlState, message: &'a str) -> Self {
         Self {
             severity: Severity::Error,
-            state: SqlState::INTERNAL_ERROR,
+            state,
             message,
         }
     }
@@ -74,6 +78,8 @@ impl Severity {
 pub enum Code {
     E00000,
     E01000,
+    E54000,
+    E57014,
     EXX000,
 }
 
@@ -86,6 +92,10 @@ impl SqlState {
     pub const INTERNAL_ERROR: SqlState = SqlState(Code::EXX000);
     /// Class 00 — Successful Completion
     pub const SUCCESSFUL_COMPLETION: SqlState = SqlState(Code::E00000);
+    /// Class 54 — Program Limit Exceeded
+    pub const PROGRAM_LIMIT_EXCEEDED: SqlState = SqlState(Code::E54000);
+    /// Class 57 — Operator Intervention (`query_canceled`)
+    pub const QUERY_CANCELED: SqlState = SqlState(Code::E57014);
     /// Class 01 — Warning
     pub const WARNING: SqlState = SqlState(Code::E01000);
 
@@ -93,6 +103,8 @@ impl SqlState {
         match &self.0 {
             Code::E00000 => "00000",
             Code::E01000 => "01000",
+            Code::E54000 => "54000",
+            Code::E57014 => "57014",
             Code::EXX000 => "XX000",
         }
     }
@@ -161,10 +161,17 @@ where
         let result = if let Some(result) = &mut self.result {
             result
         } else {
-            let result = session
-                .run_statement(self.query_string.as_str(), self.result_formats.clone())
-                .await
-                .map_err(|err| PsqlError::ExecuteError(err))?;
+            // Execute the statement while monitoring the connection, so that a client
+            // disconnect terminates the connection (and thus cancels the query) immediately,
+            // instead of being noticed at the next write.
+            let result = tokio::select! {
+                result = session
+                    .run_statement(self.query_string.as_str(), self.result_formats.clone()) =>
+                {
+                    result.map_err(|err| PsqlError::ExecuteError(err))?
+                }
+                err = msg_stream.wait_closed() => return Err(PsqlError::IoError(err)),
+            };
             self.result = Some(result);
             self.result.as_mut().unwrap()
         };
@@ -196,12 +203,13 @@ where
                         }
                     }
                 } else {
-                    self.row_cache = if let Some(rows) = result
-                        .values_stream()
-                        .try_next()
-                        .await
-                        .map_err(|err| PsqlError::ExecuteError(err))?
-                    {
+                    let rows = tokio::select! {
+                        rows = result.values_stream().try_next() => {
+                            rows.map_err(|err| PsqlError::ExecuteError(err))?
+                        }
+                        err = msg_stream.wait_closed() => return Err(PsqlError::IoError(err)),
+                    };
+                    self.row_cache = if let Some(rows) = rows {
                         rows.into_iter()
                     } else {
                         query_end = true;
@@ -397,7 +405,7 @@ impl PreparedStatement {
             .zip_eq_debug(format_iter)
         {
             let str = match type_oid {
-                DataType::Varchar | DataType::Bytea => {
+                DataType::Varchar | DataType::Bytea | DataType::Jsonb => {
                     format!("'{}'", cstr_to_str(raw_param).unwrap().replace('\'', "''"))
                 }
                 DataType::Boolean => match param_format {
@@ -527,6 +535,7 @@ impl PreparedStatement {
                     params.push("'2022-10-01 12:00:00+01:00'::timestamptz".to_string())
                 }
                 DataType::Interval => params.push("'2 months ago'::interval".to_string()),
+                DataType::Jsonb => params.push("'null'::JSONB".to_string()),
                 DataType::Struct(_) | DataType::List { .. } => {
                     return Err(PsqlError::Internal(anyhow!(
                         "Unsupported param type {:?}",
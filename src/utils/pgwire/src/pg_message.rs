@@ -21,7 +21,8 @@ use byteorder::{BigEndian, ByteOrder};
 use bytes::{Buf, BufMut, Bytes, BytesMut};
 use tokio::io::{AsyncRead, AsyncReadExt};
 
-use crate::error_or_notice::ErrorOrNoticeMessage;
+use crate::error::{PsqlError, SqlStateError};
+use crate::error_or_notice::{ErrorOrNoticeMessage, SqlState};
 use crate::pg_field_descriptor::PgFieldDescriptor;
 use crate::pg_response::StatementType;
 use crate::pg_server::BoxedError;
@@ -627,13 +628,14 @@ impl<'a> BeMessage<'a> {
             }
 
             BeMessage::ErrorResponse(error) => {
-                // For all the errors set Severity to Error and error code to
-                // 'internal error'.
+                // Set Severity to Error for all the errors. Errors wrapping a [`SqlStateError`]
+                // report its SQLSTATE code, everything else is reported as 'internal error'.
 
                 // 'E' signalizes ErrorResponse messages
                 buf.put_u8(b'E');
+                let state = sql_state_of(&**error);
                 let msg = error.to_string();
-                write_err_or_notice(buf, &ErrorOrNoticeMessage::internal_error(&msg));
+                write_err_or_notice(buf, &ErrorOrNoticeMessage::error(state, &msg));
             }
 
             BeMessage::BackendKeyData((process_id, secret_key)) => {
@@ -702,6 +704,27 @@ fn write_cstr(buf: &mut BytesMut, s: &[u8]) -> Result<()> {
     Ok(())
 }
 
+/// Resolve the SQLSTATE code to report for an error by looking for a [`SqlStateError`] in the
+/// error it wraps, falling back to the generic internal error code.
+fn sql_state_of(error: &(dyn std::error::Error + 'static)) -> SqlState {
+    if let Some(error) = error.downcast_ref::<SqlStateError>() {
+        return error.state();
+    }
+    if let Some(error) = error.downcast_ref::<PsqlError>() {
+        match error {
+            PsqlError::StartupError(inner)
+            | PsqlError::QueryError(inner)
+            | PsqlError::ParseError(inner)
+            | PsqlError::ExecuteError(inner) => return sql_state_of(inner.as_ref()),
+            _ => {}
+        }
+    }
+    match error.source() {
+        Some(source) => sql_state_of(source),
+        None => SqlState::INTERNAL_ERROR,
+    }
+}
+
 /// Safe write error or notice message.
 fn write_err_or_notice(buf: &mut BytesMut, msg: &ErrorOrNoticeMessage<'_>) {
     write_body(buf, |buf| {
@@ -25,7 +25,7 @@ use futures::stream::StreamExt;
 use futures::Stream;
 use openssl::ssl::{SslAcceptor, SslContext, SslContextRef, SslMethod};
 use risingwave_sqlparser::parser::Parser;
-use tokio::io::{AsyncRead, AsyncWrite, AsyncWriteExt};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
 use tokio_openssl::SslStream;
 use tracing::log::trace;
 use tracing::{error, warn};
@@ -136,6 +136,7 @@ where
             stream: Conn::Unencrypted(PgStream {
                 stream: Some(stream),
                 write_buf: BytesMut::with_capacity(10 * 1024),
+                peeked: Vec::new(),
             }),
             is_terminate: false,
             state: PgProtocolState::Startup,
@@ -162,7 +163,15 @@ where
             Err(e) => {
                 match e {
                     PsqlError::IoError(io_err) => {
-                        if io_err.kind() == std::io::ErrorKind::UnexpectedEof {
+                        // A failed read or write on the socket means the client has gone away.
+                        // Terminate the connection so that the session is ended and any query
+                        // still running in it is cancelled.
+                        if matches!(
+                            io_err.kind(),
+                            std::io::ErrorKind::UnexpectedEof
+                                | std::io::ErrorKind::BrokenPipe
+                                | std::io::ErrorKind::ConnectionReset
+                        ) {
                             return true;
                         }
                     }
@@ -334,11 +343,15 @@ where
         for stmt in stmts {
             let session = session.clone();
 
-            // execute query
-            let mut res = session
-                .run_one_query(stmt, Format::Text)
-                .await
-                .map_err(|err| PsqlError::QueryError(err))?;
+            // Execute the query while monitoring the connection, so that a client disconnect
+            // terminates the connection (and thus cancels the query) immediately, instead of
+            // being noticed at the next write.
+            let mut res = tokio::select! {
+                res = session.run_one_query(stmt, Format::Text) => {
+                    res.map_err(|err| PsqlError::QueryError(err))?
+                }
+                err = self.stream.wait_closed() => return Err(PsqlError::IoError(err)),
+            };
 
             if let Some(notice) = res.get_notice() {
                 self.stream
@@ -351,7 +364,14 @@ where
 
                 let mut rows_cnt = 0;
 
-                while let Some(row_set) = res.values_stream().next().await {
+                loop {
+                    let row_set = tokio::select! {
+                        row_set = res.values_stream().next() => match row_set {
+                            Some(row_set) => row_set,
+                            None => break,
+                        },
+                        err = self.stream.wait_closed() => return Err(PsqlError::IoError(err)),
+                    };
                     let row_set = row_set.map_err(|err| PsqlError::QueryError(err))?;
                     for row in row_set {
                         self.stream.write_no_flush(&BeMessage::DataRow(&row))?;
@@ -599,6 +619,9 @@ pub struct PgStream<S> {
     stream: Option<S>,
     /// Write into buffer before flush to stream.
     write_buf: BytesMut,
+    /// Bytes received while waiting for connection closure in [`Self::wait_closed`]. They are
+    /// prepended to the next read so that waiting never consumes protocol data.
+    peeked: Vec<u8>,
 }
 
 impl<S> PgStream<S>
@@ -610,7 +633,37 @@ where
     }
 
     async fn read(&mut self) -> io::Result<FeMessage> {
-        FeMessage::read(self.stream()).await
+        if self.peeked.is_empty() {
+            FeMessage::read(self.stream()).await
+        } else {
+            let peeked = io::Cursor::new(std::mem::take(&mut self.peeked));
+            let mut stream = peeked.chain(self.stream.as_mut().unwrap());
+            let message = FeMessage::read(&mut stream).await;
+            // Keep the bytes the message did not consume, e.g. further pipelined messages.
+            let (peeked, _) = stream.into_inner();
+            let consumed = peeked.position() as usize;
+            self.peeked = peeked.into_inner().split_off(consumed);
+            message
+        }
+    }
+
+    /// Waits until the client closes the connection, resolving to the error describing the
+    /// closure. Used to monitor the connection while a query is running, so that abandoned
+    /// queries are cancelled without waiting for the next write to fail.
+    ///
+    /// Bytes that arrive in the meantime (e.g. pipelined extended-protocol messages) are
+    /// stashed and prepended to the next read, so waiting never consumes protocol data.
+    async fn wait_closed(&mut self) -> IoError {
+        let mut buf = [0u8; 4096];
+        loop {
+            match self.stream.as_mut().unwrap().read(&mut buf).await {
+                Ok(0) => {
+                    return IoError::new(ErrorKind::UnexpectedEof, "client closed the connection")
+                }
+                Ok(n) => self.peeked.extend_from_slice(&buf[..n]),
+                Err(e) => return e,
+            }
+        }
     }
 
     fn write_parameter_status_msg_no_flush(&mut self) -> io::Result<()> {
@@ -679,6 +732,7 @@ where
         Ok(PgStream {
             stream: Some(stream),
             write_buf: BytesMut::with_capacity(10 * 1024),
+            peeked: Vec::new(),
         })
     }
 }
@@ -701,6 +755,13 @@ where
         }
     }
 
+    pub async fn wait_closed(&mut self) -> IoError {
+        match self {
+            Conn::Unencrypted(s) => s.wait_closed().await,
+            Conn::Ssl(s) => s.wait_closed().await,
+        }
+    }
+
     fn write_parameter_status_msg_no_flush(&mut self) -> io::Result<()> {
         match self {
             Conn::Unencrypted(s) => s.write_parameter_status_msg_no_flush(),
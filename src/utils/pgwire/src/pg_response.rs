@@ -63,6 +63,7 @@ pub enum StatementType {
     DROP_DATABASE,
     DROP_USER,
     ALTER_TABLE,
+    ANALYZE,
     REVOKE_PRIVILEGE,
     // Introduce ORDER_BY statement type cuz Calcite unvalidated AST has SqlKind.ORDER_BY. Note
     // that Statement Type is not designed to be one to one mapping with SqlKind.